@@ -0,0 +1,102 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+pub const _5TH_ACCOUNT_ACCOUNT_DISCM: [u8; 8] = [137, 227, 55, 109, 233, 205, 212, 45];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct _5ThAccount {
+    pub _6th: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct _5ThAccountAccount(pub _5ThAccount);
+impl _5ThAccountAccount {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != _5TH_ACCOUNT_ACCOUNT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        _5TH_ACCOUNT_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(_5ThAccount::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&_5TH_ACCOUNT_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, _5TH_ACCOUNT_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for _5ThAccount {
+    const DISCRIMINATOR: &'static [u8] = &_5TH_ACCOUNT_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for _5ThAccount {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for _5ThAccount {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&_5TH_ACCOUNT_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for _5ThAccount {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < _5TH_ACCOUNT_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&_5TH_ACCOUNT_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(_5TH_ACCOUNT_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for _5ThAccount {
+    const NAME: &'static str = "5thAccount";
+    const DISCM: &'static [u8] = &_5TH_ACCOUNT_ACCOUNT_DISCM;
+}
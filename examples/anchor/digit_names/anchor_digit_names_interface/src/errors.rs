@@ -0,0 +1,37 @@
+use solana_program::{
+    decode_error::DecodeError, msg, program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum AnchorDigitNamesError {
+    #[error("x")]
+    _10ThError = 6000,
+}
+impl From<AnchorDigitNamesError> for ProgramError {
+    fn from(e: AnchorDigitNamesError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramErrorCode for AnchorDigitNamesError {
+    fn code(&self) -> u32 {
+        *self as u32
+    }
+    fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
+impl<T> DecodeError<T> for AnchorDigitNamesError {
+    fn type_of() -> &'static str {
+        "AnchorDigitNamesError"
+    }
+}
+impl PrintProgramError for AnchorDigitNamesError {
+    fn print<E>(&self)
+    where
+        E: 'static + std::error::Error + DecodeError<E> + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(& self.to_string());
+    }
+}
@@ -0,0 +1,36 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+pub const _9TH_EVENT_EVENT_DISCM: [u8; 8] = [10, 185, 246, 61, 23, 64, 105, 115];
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct _9ThEvent {
+    pub a: u8,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct _9ThEventEvent(pub _9ThEvent);
+impl BorshSerialize for _9ThEventEvent {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        _9TH_EVENT_EVENT_DISCM.serialize(writer)?;
+        self.0.serialize(writer)
+    }
+}
+impl _9ThEventEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> borsh::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        if maybe_discm != _9TH_EVENT_EVENT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        _9TH_EVENT_EVENT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(_9ThEvent::deserialize(buf)?))
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramEvent for _9ThEvent {
+    const NAME: &'static str = "9thEvent";
+    const DISCM: &'static [u8] = &_9TH_EVENT_EVENT_DISCM;
+}
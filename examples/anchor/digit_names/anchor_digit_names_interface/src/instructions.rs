@@ -0,0 +1,265 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+};
+use borsh::io::Read;
+use crate::*;
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorDigitNamesProgramIx {
+    _1StIx(_1StIxIxArgs),
+}
+impl AnchorDigitNamesProgramIx {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            _1ST_IX_IX_DISCM => Ok(Self::_1StIx(_1StIxIxArgs::deserialize(&mut reader)?)),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        match self {
+            Self::_1StIx(args) => {
+                writer.write_all(&_1ST_IX_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+pub const _1ST_IX_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
+pub struct _1StIxAccounts<'me, 'info> {
+    pub _2nd_acc: &'me AccountInfo<'info>,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct _1StIxKeys {
+    pub _2nd_acc: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<_1StIxAccounts<'_, '_>> for _1StIxKeys {
+    fn from(accounts: _1StIxAccounts) -> Self {
+        Self {
+            _2nd_acc: *accounts._2nd_acc.key,
+        }
+    }
+}
+impl From<_1StIxKeys> for [AccountMeta; _1ST_IX_IX_ACCOUNTS_LEN] {
+    fn from(keys: _1StIxKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys._2nd_acc,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; _1ST_IX_IX_ACCOUNTS_LEN]> for _1StIxKeys {
+    fn from(pubkeys: [Pubkey; _1ST_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self { _2nd_acc: pubkeys[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'info> From<_1StIxAccounts<'_, 'info>>
+for [AccountInfo<'info>; _1ST_IX_IX_ACCOUNTS_LEN] {
+    fn from(accounts: _1StIxAccounts<'_, 'info>) -> Self {
+        [accounts._2nd_acc.clone()]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me, 'info> From<&'me [AccountInfo<'info>; _1ST_IX_IX_ACCOUNTS_LEN]>
+for _1StIxAccounts<'me, 'info> {
+    fn from(arr: &'me [AccountInfo<'info>; _1ST_IX_IX_ACCOUNTS_LEN]) -> Self {
+        Self { _2nd_acc: &arr[0] }
+    }
+}
+pub const _1ST_IX_IX_DISCM: [u8; 8] = [150, 161, 19, 181, 159, 13, 78, 76];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct _1StIxIxArgs {
+    pub _3rd: _4ThType,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct _1StIxIxData(pub _1StIxIxArgs);
+impl From<_1StIxIxArgs> for _1StIxIxData {
+    fn from(args: _1StIxIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl _1StIxIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != _1ST_IX_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        _1ST_IX_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(_1StIxIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&_1ST_IX_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for _1StIxIxData {
+    const DISCRIMINATOR: &'static [u8] = &_1ST_IX_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for _1StIxIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for _1StIxIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for _1StIxIxData {
+    const NAME: &'static str = "1stIx";
+    const DISCM: &'static [u8] = &_1ST_IX_IX_DISCM;
+    const ACCOUNTS_LEN: usize = _1ST_IX_IX_ACCOUNTS_LEN;
+    type Keys = _1StIxKeys;
+    type Args = _1StIxIxArgs;
+}
+pub fn _1st_ix_ix_with_program_id(
+    program_id: Pubkey,
+    keys: _1StIxKeys,
+    args: _1StIxIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; _1ST_IX_IX_ACCOUNTS_LEN] = keys.into();
+    let data: _1StIxIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn _1st_ix_ix(
+    keys: _1StIxKeys,
+    args: _1StIxIxArgs,
+) -> borsh::io::Result<Instruction> {
+    _1st_ix_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: _1StIxAccounts<'_, '_>,
+    args: _1StIxIxArgs,
+) -> ProgramResult {
+    let keys: _1StIxKeys = accounts.into();
+    let ix = _1st_ix_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction(&ix, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_invoke(
+    accounts: _1StIxAccounts<'_, '_>,
+    args: _1StIxIxArgs,
+) -> ProgramResult {
+    _1st_ix_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: _1StIxAccounts<'_, '_>,
+    args: _1StIxIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let keys: _1StIxKeys = accounts.into();
+    let ix = _1st_ix_ix_with_program_id(program_id, keys, args)?;
+    invoke_instruction_signed(&ix, accounts, seeds)
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_invoke_signed(
+    accounts: _1StIxAccounts<'_, '_>,
+    args: _1StIxIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    _1st_ix_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_verify_account_keys(
+    accounts: _1StIxAccounts<'_, '_>,
+    keys: _1StIxKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [(*accounts._2nd_acc.key, keys._2nd_acc)] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_verify_writable_privileges<'me, 'info>(
+    accounts: _1StIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    for should_be_writable in [accounts._2nd_acc] {
+        if !should_be_writable.is_writable {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn _1st_ix_verify_account_privileges<'me, 'info>(
+    accounts: _1StIxAccounts<'me, 'info>,
+) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+    _1st_ix_verify_writable_privileges(accounts)?;
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
 use solana_program::pubkey::Pubkey;
-pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [233, 45, 62, 40, 35, 129, 48, 72];
+use crate::*;
+pub const PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [
+    233, 45, 62, 40, 35, 129, 48, 72,
+];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PhoenixV1FulfillmentConfig {
@@ -19,34 +23,102 @@ pub struct PhoenixV1FulfillmentConfig {
 #[derive(Clone, Debug, PartialEq)]
 pub struct PhoenixV1FulfillmentConfigAccount(pub PhoenixV1FulfillmentConfig);
 impl PhoenixV1FulfillmentConfigAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(PhoenixV1FulfillmentConfig::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(
+                0,
+                PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec(),
+            ),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for PhoenixV1FulfillmentConfig {
+    const DISCRIMINATOR: &'static [u8] = &PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for PhoenixV1FulfillmentConfig {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for PhoenixV1FulfillmentConfig {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for PhoenixV1FulfillmentConfig {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for PhoenixV1FulfillmentConfig {
+    const NAME: &'static str = "PhoenixV1FulfillmentConfig";
+    const DISCM: &'static [u8] = &PHOENIX_V1_FULFILLMENT_CONFIG_ACCOUNT_DISCM;
 }
-pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] =
-    [65, 160, 197, 112, 239, 168, 103, 185];
+pub const SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM: [u8; 8] = [
+    65, 160, 197, 112, 239, 168, 103, 185,
+];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SerumV3FulfillmentConfig {
@@ -69,33 +141,99 @@ pub struct SerumV3FulfillmentConfig {
 #[derive(Clone, Debug, PartialEq)]
 pub struct SerumV3FulfillmentConfigAccount(pub SerumV3FulfillmentConfig);
 impl SerumV3FulfillmentConfigAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SerumV3FulfillmentConfig::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SerumV3FulfillmentConfig {
+    const DISCRIMINATOR: &'static [u8] = &SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for SerumV3FulfillmentConfig {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for SerumV3FulfillmentConfig {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for SerumV3FulfillmentConfig {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
 }
-pub const INSURANCE_FUND_STAKE_ACCOUNT_DISCM: [u8; 8] = [110, 202, 14, 42, 95, 73, 90, 95];
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for SerumV3FulfillmentConfig {
+    const NAME: &'static str = "SerumV3FulfillmentConfig";
+    const DISCM: &'static [u8] = &SERUM_V3_FULFILLMENT_CONFIG_ACCOUNT_DISCM;
+}
+pub const INSURANCE_FUND_STAKE_ACCOUNT_DISCM: [u8; 8] = [
+    110, 202, 14, 42, 95, 73, 90, 95,
+];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsuranceFundStake {
@@ -113,31 +251,95 @@ pub struct InsuranceFundStake {
 #[derive(Clone, Debug, PartialEq)]
 pub struct InsuranceFundStakeAccount(pub InsuranceFundStake);
 impl InsuranceFundStakeAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INSURANCE_FUND_STAKE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INSURANCE_FUND_STAKE_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INSURANCE_FUND_STAKE_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(InsuranceFundStake::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&INSURANCE_FUND_STAKE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, INSURANCE_FUND_STAKE_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for InsuranceFundStake {
+    const DISCRIMINATOR: &'static [u8] = &INSURANCE_FUND_STAKE_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for InsuranceFundStake {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for InsuranceFundStake {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&INSURANCE_FUND_STAKE_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for InsuranceFundStake {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < INSURANCE_FUND_STAKE_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&INSURANCE_FUND_STAKE_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(INSURANCE_FUND_STAKE_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for InsuranceFundStake {
+    const NAME: &'static str = "InsuranceFundStake";
+    const DISCM: &'static [u8] = &INSURANCE_FUND_STAKE_ACCOUNT_DISCM;
 }
 pub const PERP_MARKET_ACCOUNT_DISCM: [u8; 8] = [10, 223, 12, 44, 107, 245, 55, 247];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -170,36 +372,104 @@ pub struct PerpMarket {
     pub contract_tier: ContractTier,
     pub padding1: bool,
     pub quote_spot_market_index: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; 48]>")
+    )]
     pub padding: [u8; 48],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct PerpMarketAccount(pub PerpMarket);
 impl PerpMarketAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != PERP_MARKET_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PERP_MARKET_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PERP_MARKET_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(PerpMarket::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&PERP_MARKET_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, PERP_MARKET_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for PerpMarket {
+    const DISCRIMINATOR: &'static [u8] = &PERP_MARKET_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for PerpMarket {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for PerpMarket {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&PERP_MARKET_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for PerpMarket {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < PERP_MARKET_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&PERP_MARKET_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(PERP_MARKET_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for PerpMarket {
+    const NAME: &'static str = "PerpMarket";
+    const DISCM: &'static [u8] = &PERP_MARKET_ACCOUNT_DISCM;
 }
 pub const SPOT_MARKET_ACCOUNT_DISCM: [u8; 8] = [100, 177, 8, 107, 168, 65, 65, 39];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -256,36 +526,104 @@ pub struct SpotMarket {
     pub flash_loan_amount: u64,
     pub flash_loan_initial_token_amount: u64,
     pub total_swap_fee: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; 56]>")
+    )]
     pub padding: [u8; 56],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct SpotMarketAccount(pub SpotMarket);
 impl SpotMarketAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SPOT_MARKET_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SPOT_MARKET_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SPOT_MARKET_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SpotMarket::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SPOT_MARKET_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, SPOT_MARKET_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SpotMarket {
+    const DISCRIMINATOR: &'static [u8] = &SPOT_MARKET_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for SpotMarket {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for SpotMarket {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&SPOT_MARKET_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for SpotMarket {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < SPOT_MARKET_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&SPOT_MARKET_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(SPOT_MARKET_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for SpotMarket {
+    const NAME: &'static str = "SpotMarket";
+    const DISCM: &'static [u8] = &SPOT_MARKET_ACCOUNT_DISCM;
 }
 pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -318,31 +656,93 @@ pub struct State {
 #[derive(Clone, Debug, PartialEq)]
 pub struct StateAccount(pub State);
 impl StateAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STATE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STATE_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        STATE_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(State::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&STATE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, STATE_ACCOUNT_DISCM.to_vec()))
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for State {
+    const DISCRIMINATOR: &'static [u8] = &STATE_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for State {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for State {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&STATE_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for State {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < STATE_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&STATE_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(STATE_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for State {
+    const NAME: &'static str = "State";
+    const DISCM: &'static [u8] = &STATE_ACCOUNT_DISCM;
 }
 pub const USER_ACCOUNT_DISCM: [u8; 8] = [159, 117, 95, 227, 239, 151, 58, 236];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -379,31 +779,93 @@ pub struct User {
 #[derive(Clone, Debug, PartialEq)]
 pub struct UserAccount(pub User);
 impl UserAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != USER_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    USER_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        USER_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(User::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&USER_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, USER_ACCOUNT_DISCM.to_vec()))
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for User {
+    const DISCRIMINATOR: &'static [u8] = &USER_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for User {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for User {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&USER_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for User {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < USER_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&USER_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(USER_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for User {
+    const NAME: &'static str = "User";
+    const DISCM: &'static [u8] = &USER_ACCOUNT_DISCM;
 }
 pub const USER_STATS_ACCOUNT_DISCM: [u8; 8] = [176, 223, 136, 27, 122, 79, 32, 227];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -423,36 +885,104 @@ pub struct UserStats {
     pub number_of_sub_accounts: u16,
     pub number_of_sub_accounts_created: u16,
     pub is_referrer: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; 51]>")
+    )]
     pub padding: [u8; 51],
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct UserStatsAccount(pub UserStats);
 impl UserStatsAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != USER_STATS_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    USER_STATS_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        USER_STATS_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(UserStats::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&USER_STATS_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, USER_STATS_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UserStats {
+    const DISCRIMINATOR: &'static [u8] = &USER_STATS_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for UserStats {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for UserStats {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&USER_STATS_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for UserStats {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < USER_STATS_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&USER_STATS_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(USER_STATS_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for UserStats {
+    const NAME: &'static str = "UserStats";
+    const DISCM: &'static [u8] = &USER_STATS_ACCOUNT_DISCM;
 }
 pub const REFERRER_NAME_ACCOUNT_DISCM: [u8; 8] = [105, 133, 170, 110, 52, 42, 28, 182];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -466,29 +996,93 @@ pub struct ReferrerName {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ReferrerNameAccount(pub ReferrerName);
 impl ReferrerNameAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REFERRER_NAME_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REFERRER_NAME_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REFERRER_NAME_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ReferrerName::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&REFERRER_NAME_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, REFERRER_NAME_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ReferrerName {
+    const DISCRIMINATOR: &'static [u8] = &REFERRER_NAME_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for ReferrerName {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for ReferrerName {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&REFERRER_NAME_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for ReferrerName {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < REFERRER_NAME_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&REFERRER_NAME_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(REFERRER_NAME_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ReferrerName {
+    const NAME: &'static str = "ReferrerName";
+    const DISCM: &'static [u8] = &REFERRER_NAME_ACCOUNT_DISCM;
 }
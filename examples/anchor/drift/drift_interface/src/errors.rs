@@ -1,7 +1,5 @@
 use solana_program::{
-    decode_error::DecodeError,
-    msg,
-    program_error::{PrintProgramError, ProgramError},
+    decode_error::DecodeError, msg, program_error::{PrintProgramError, ProgramError},
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
@@ -516,6 +514,15 @@ impl From<DriftError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramErrorCode for DriftError {
+    fn code(&self) -> u32 {
+        *self as u32
+    }
+    fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
 impl<T> DecodeError<T> for DriftError {
     fn type_of() -> &'static str {
         "DriftError"
@@ -524,12 +531,9 @@ impl<T> DecodeError<T> for DriftError {
 impl PrintProgramError for DriftError {
     fn print<E>(&self)
     where
-        E: 'static
-            + std::error::Error
-            + DecodeError<E>
-            + PrintProgramError
+        E: 'static + std::error::Error + DecodeError<E> + PrintProgramError
             + num_traits::FromPrimitive,
     {
-        msg!(&self.to_string());
+        msg!(& self.to_string());
     }
 }
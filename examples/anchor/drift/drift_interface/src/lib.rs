@@ -1,4 +1,13 @@
 solana_program::declare_id!("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH");
+/// Version of solores that generated this crate
+pub const SOLORES_VERSION: &str = "0.8.0";
+/// Hex-encoded SHA-256 of the IDL this crate was generated from
+pub const IDL_SHA256: &str = "4d894b917071a438c96ec51493cb7db2526e3b9d88e4f08fe3fd5ef821fad2e2";
+/// The solores options this crate was generated with, in `solores.toml` format
+pub const SOLORES_OPTIONS: &str = "borsh-vers = \"workspace=true\"\nbytemuck-vers = \"workspace=true\"\nnum-derive-vers = \"workspace=true\"\nnum-traits-vers = \"workspace=true\"\nprogram-id = \"dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH\"\nserde-vers = \"workspace=true\"\nserde-with-vers = \"workspace=true\"\nsolana-program-vers = \"workspace=true\"\nsolores-runtime-vers = \"workspace=true\"\nthiserror-vers = \"workspace=true\"\n";
+/// The IDL this crate was generated from
+#[cfg(feature = "idl")]
+pub const IDL_JSON: &str = include_str!("../idl.json");
 pub mod accounts;
 pub use accounts::*;
 pub mod typedefs;
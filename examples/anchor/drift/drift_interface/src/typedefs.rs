@@ -216,6 +216,10 @@ pub struct AMM {
     pub oracle_source: OracleSource,
     pub last_oracle_valid: bool,
     pub target_base_asset_amount_per_lp: i32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::Same; 44]>")
+    )]
     pub padding: [u8; 44],
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -1,3 +1,12 @@
 solana_program::declare_id!("TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111");
+/// Version of solores that generated this crate
+pub const SOLORES_VERSION: &str = "0.8.0";
+/// Hex-encoded SHA-256 of the IDL this crate was generated from
+pub const IDL_SHA256: &str = "def0b1f126612d535f97869b5104165e90658c039b6d1853102447c17f658e2d";
+/// The solores options this crate was generated with, in `solores.toml` format
+pub const SOLORES_OPTIONS: &str = "borsh-vers = \"workspace=true\"\nbytemuck-vers = \"workspace=true\"\nnum-derive-vers = \"workspace=true\"\nnum-traits-vers = \"workspace=true\"\nserde-vers = \"workspace=true\"\nserde-with-vers = \"workspace=true\"\nsolana-program-vers = \"workspace=true\"\nsolores-runtime-vers = \"workspace=true\"\nthiserror-vers = \"workspace=true\"\n";
+/// The IDL this crate was generated from
+#[cfg(feature = "idl")]
+pub const IDL_JSON: &str = include_str!("../idl.json");
 pub mod instructions;
 pub use instructions::*;
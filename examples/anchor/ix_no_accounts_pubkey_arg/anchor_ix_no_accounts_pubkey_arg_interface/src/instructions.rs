@@ -1,31 +1,39 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{
-    entrypoint::ProgramResult,
-    instruction::Instruction,
-    program::{invoke, invoke_signed},
-    pubkey::Pubkey,
-};
-use std::io::Read;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+#[cfg(feature = "cpi")]
+use solana_program::{entrypoint::ProgramResult, program::{invoke, invoke_signed}};
+use borsh::io::Read;
 #[derive(Clone, Debug, PartialEq)]
 pub enum AnchorIxNoAccountsPubkeyArgProgramIx {
     NoAccountsPubkeyArgIx(NoAccountsPubkeyArgIxIxArgs),
 }
 impl AnchorIxNoAccountsPubkeyArgProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
-            NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM => Ok(Self::NoAccountsPubkeyArgIx(
-                NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?,
-            )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM => {
+                Ok(
+                    Self::NoAccountsPubkeyArgIx(
+                        NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::NoAccountsPubkeyArgIx(args) => {
                 writer.write_all(&NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM)?;
@@ -33,13 +41,15 @@ impl AnchorIxNoAccountsPubkeyArgProgramIx {
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
-pub const NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM: [u8; 8] = [184, 145, 219, 5, 131, 41, 20, 197];
+pub const NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM: [u8; 8] = [
+    184, 145, 219, 5, 131, 41, 20, 197,
+];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoAccountsPubkeyArgIxIxArgs {
@@ -53,35 +63,60 @@ impl From<NoAccountsPubkeyArgIxIxArgs> for NoAccountsPubkeyArgIxIxData {
     }
 }
 impl NoAccountsPubkeyArgIxIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for NoAccountsPubkeyArgIxIxData {
+    const DISCRIMINATOR: &'static [u8] = &NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for NoAccountsPubkeyArgIxIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for NoAccountsPubkeyArgIxIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NoAccountsPubkeyArgIxIxData {
+    const NAME: &'static str = "noAccountsPubkeyArgIx";
+    const DISCM: &'static [u8] = &NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM;
+    const ACCOUNTS_LEN: usize = 0;
+    type Keys = ();
+    type Args = NoAccountsPubkeyArgIxIxArgs;
+}
 pub fn no_accounts_pubkey_arg_ix_ix_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let data: NoAccountsPubkeyArgIxIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -91,9 +126,10 @@ pub fn no_accounts_pubkey_arg_ix_ix_with_program_id(
 }
 pub fn no_accounts_pubkey_arg_ix_ix(
     args: NoAccountsPubkeyArgIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     no_accounts_pubkey_arg_ix_ix_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
@@ -101,9 +137,13 @@ pub fn no_accounts_pubkey_arg_ix_invoke_with_program_id(
     let ix = no_accounts_pubkey_arg_ix_ix_with_program_id(program_id, args)?;
     invoke(&ix, &[])
 }
-pub fn no_accounts_pubkey_arg_ix_invoke(args: NoAccountsPubkeyArgIxIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn no_accounts_pubkey_arg_ix_invoke(
+    args: NoAccountsPubkeyArgIxIxArgs,
+) -> ProgramResult {
     no_accounts_pubkey_arg_ix_invoke_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_signed_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
@@ -112,6 +152,7 @@ pub fn no_accounts_pubkey_arg_ix_invoke_signed_with_program_id(
     let ix = no_accounts_pubkey_arg_ix_ix_with_program_id(program_id, args)?;
     invoke_signed(&ix, &[], seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_signed(
     args: NoAccountsPubkeyArgIxIxArgs,
     seeds: &[&[&[u8]]],
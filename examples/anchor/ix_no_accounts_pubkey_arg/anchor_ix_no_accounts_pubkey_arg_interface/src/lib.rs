@@ -1,3 +1,12 @@
 solana_program::declare_id!("TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111");
+/// Version of solores that generated this crate
+pub const SOLORES_VERSION: &str = "0.8.0";
+/// Hex-encoded SHA-256 of the IDL this crate was generated from
+pub const IDL_SHA256: &str = "212a36cdf108e1e232ea63271b08afe7096b66bc1a0a938be69f12601d701273";
+/// The solores options this crate was generated with, in `solores.toml` format
+pub const SOLORES_OPTIONS: &str = "borsh-vers = \"workspace=true\"\nbytemuck-vers = \"workspace=true\"\nnum-derive-vers = \"workspace=true\"\nnum-traits-vers = \"workspace=true\"\nserde-vers = \"workspace=true\"\nserde-with-vers = \"workspace=true\"\nsolana-program-vers = \"workspace=true\"\nsolores-runtime-vers = \"workspace=true\"\nthiserror-vers = \"workspace=true\"\n";
+/// The IDL this crate was generated from
+#[cfg(feature = "idl")]
+pub const IDL_JSON: &str = include_str!("../idl.json");
 pub mod instructions;
 pub use instructions::*;
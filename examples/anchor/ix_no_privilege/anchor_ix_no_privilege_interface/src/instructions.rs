@@ -1,32 +1,45 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
-    program::{invoke, invoke_signed},
     pubkey::Pubkey,
 };
-use std::io::Read;
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+};
+use borsh::io::Read;
 #[derive(Clone, Debug, PartialEq)]
 pub enum AnchorIxNoPrivilegeProgramIx {
     NoPrivilegedAccountIx(NoPrivilegedAccountIxIxArgs),
 }
 impl AnchorIxNoPrivilegeProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
-            NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM => Ok(Self::NoPrivilegedAccountIx(
-                NoPrivilegedAccountIxIxArgs::deserialize(&mut reader)?,
-            )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM => {
+                Ok(
+                    Self::NoPrivilegedAccountIx(
+                        NoPrivilegedAccountIxIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::NoPrivilegedAccountIx(args) => {
                 writer.write_all(&NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM)?;
@@ -34,12 +47,13 @@ impl AnchorIxNoPrivilegeProgramIx {
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -47,6 +61,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -57,6 +72,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct NoPrivilegedAccountIxAccounts<'me, 'info> {
     pub a: &'me AccountInfo<'info>,
 }
@@ -64,40 +80,52 @@ pub struct NoPrivilegedAccountIxAccounts<'me, 'info> {
 pub struct NoPrivilegedAccountIxKeys {
     pub a: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<NoPrivilegedAccountIxAccounts<'_, '_>> for NoPrivilegedAccountIxKeys {
     fn from(accounts: NoPrivilegedAccountIxAccounts) -> Self {
         Self { a: *accounts.a.key }
     }
 }
-impl From<NoPrivilegedAccountIxKeys> for [AccountMeta; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] {
+impl From<NoPrivilegedAccountIxKeys>
+for [AccountMeta; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] {
     fn from(keys: NoPrivilegedAccountIxKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.a,
-            is_signer: false,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.a,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
     }
 }
-impl From<[Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]> for NoPrivilegedAccountIxKeys {
+impl From<[Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]>
+for NoPrivilegedAccountIxKeys {
     fn from(pubkeys: [Pubkey; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]) -> Self {
         Self { a: pubkeys[0] }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<NoPrivilegedAccountIxAccounts<'_, 'info>>
-    for [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] {
     fn from(accounts: NoPrivilegedAccountIxAccounts<'_, 'info>) -> Self {
         [accounts.a.clone()]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]>
-    for NoPrivilegedAccountIxAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN]>
+for NoPrivilegedAccountIxAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self { a: &arr[0] }
     }
 }
-pub const NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM: [u8; 8] = [137, 46, 202, 239, 43, 151, 145, 176];
+pub const NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM: [u8; 8] = [
+    137, 46, 202, 239, 43, 151, 145, 176,
+];
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NoPrivilegedAccountIxIxArgs {
@@ -111,36 +139,61 @@ impl From<NoPrivilegedAccountIxIxArgs> for NoPrivilegedAccountIxIxData {
     }
 }
 impl NoPrivilegedAccountIxIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(NoPrivilegedAccountIxIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for NoPrivilegedAccountIxIxData {
+    const DISCRIMINATOR: &'static [u8] = &NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for NoPrivilegedAccountIxIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for NoPrivilegedAccountIxIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NoPrivilegedAccountIxIxData {
+    const NAME: &'static str = "noPrivilegedAccountIx";
+    const DISCM: &'static [u8] = &NO_PRIVILEGED_ACCOUNT_IX_IX_DISCM;
+    const ACCOUNTS_LEN: usize = NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN;
+    type Keys = NoPrivilegedAccountIxKeys;
+    type Args = NoPrivilegedAccountIxIxArgs;
+}
 pub fn no_privileged_account_ix_ix_with_program_id(
     program_id: Pubkey,
     keys: NoPrivilegedAccountIxKeys,
     args: NoPrivilegedAccountIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; NO_PRIVILEGED_ACCOUNT_IX_IX_ACCOUNTS_LEN] = keys.into();
     let data: NoPrivilegedAccountIxIxData = args.into();
     Ok(Instruction {
@@ -152,9 +205,10 @@ pub fn no_privileged_account_ix_ix_with_program_id(
 pub fn no_privileged_account_ix_ix(
     keys: NoPrivilegedAccountIxKeys,
     args: NoPrivilegedAccountIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     no_privileged_account_ix_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_privileged_account_ix_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
@@ -164,12 +218,14 @@ pub fn no_privileged_account_ix_invoke_with_program_id(
     let ix = no_privileged_account_ix_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn no_privileged_account_ix_invoke(
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
     args: NoPrivilegedAccountIxIxArgs,
 ) -> ProgramResult {
     no_privileged_account_ix_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_privileged_account_ix_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
@@ -180,13 +236,20 @@ pub fn no_privileged_account_ix_invoke_signed_with_program_id(
     let ix = no_privileged_account_ix_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn no_privileged_account_ix_invoke_signed(
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
     args: NoPrivilegedAccountIxIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    no_privileged_account_ix_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    no_privileged_account_ix_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn no_privileged_account_ix_verify_account_keys(
     accounts: NoPrivilegedAccountIxAccounts<'_, '_>,
     keys: NoPrivilegedAccountIxKeys,
@@ -1,6 +1,8 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
 use solana_program::pubkey::Pubkey;
+use crate::*;
 pub const STATE_ACCOUNT_DISCM: [u8; 8] = [216, 146, 107, 94, 104, 75, 182, 177];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,31 +32,93 @@ pub struct State {
 #[derive(Clone, Debug, PartialEq)]
 pub struct StateAccount(pub State);
 impl StateAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STATE_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STATE_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        STATE_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(State::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&STATE_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, STATE_ACCOUNT_DISCM.to_vec()))
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for State {
+    const DISCRIMINATOR: &'static [u8] = &STATE_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for State {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for State {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&STATE_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for State {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < STATE_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&STATE_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(STATE_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for State {
+    const NAME: &'static str = "State";
+    const DISCM: &'static [u8] = &STATE_ACCOUNT_DISCM;
 }
 pub const TICKET_ACCOUNT_DATA_ACCOUNT_DISCM: [u8; 8] = [133, 77, 18, 98, 211, 1, 231, 3];
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -68,29 +132,93 @@ pub struct TicketAccountData {
 #[derive(Clone, Debug, PartialEq)]
 pub struct TicketAccountDataAccount(pub TicketAccountData);
 impl TicketAccountDataAccount {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-        use std::io::Read;
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != TICKET_ACCOUNT_DATA_ACCOUNT_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    TICKET_ACCOUNT_DATA_ACCOUNT_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        TICKET_ACCOUNT_DATA_ACCOUNT_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(TicketAccountData::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&TICKET_ACCOUNT_DATA_ACCOUNT_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, TICKET_ACCOUNT_DATA_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for TicketAccountData {
+    const DISCRIMINATOR: &'static [u8] = &TICKET_ACCOUNT_DATA_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for TicketAccountData {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for TicketAccountData {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&TICKET_ACCOUNT_DATA_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for TicketAccountData {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < TICKET_ACCOUNT_DATA_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&TICKET_ACCOUNT_DATA_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(TICKET_ACCOUNT_DATA_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for TicketAccountData {
+    const NAME: &'static str = "TicketAccountData";
+    const DISCM: &'static [u8] = &TICKET_ACCOUNT_DATA_ACCOUNT_DISCM;
 }
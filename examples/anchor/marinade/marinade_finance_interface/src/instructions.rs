@@ -1,14 +1,16 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
-use std::io::Read;
+use borsh::io::Read;
+use crate::*;
 #[derive(Clone, Debug, PartialEq)]
 pub enum MarinadeFinanceProgramIx {
     Initialize(InitializeIxArgs),
@@ -34,77 +36,122 @@ pub enum MarinadeFinanceProgramIx {
     MergeStakes(MergeStakesIxArgs),
 }
 impl MarinadeFinanceProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
-            INITIALIZE_IX_DISCM => Ok(Self::Initialize(InitializeIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            CHANGE_AUTHORITY_IX_DISCM => Ok(Self::ChangeAuthority(
-                ChangeAuthorityIxArgs::deserialize(&mut reader)?,
-            )),
-            ADD_VALIDATOR_IX_DISCM => Ok(Self::AddValidator(AddValidatorIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            REMOVE_VALIDATOR_IX_DISCM => Ok(Self::RemoveValidator(
-                RemoveValidatorIxArgs::deserialize(&mut reader)?,
-            )),
-            SET_VALIDATOR_SCORE_IX_DISCM => Ok(Self::SetValidatorScore(
-                SetValidatorScoreIxArgs::deserialize(&mut reader)?,
-            )),
-            CONFIG_VALIDATOR_SYSTEM_IX_DISCM => Ok(Self::ConfigValidatorSystem(
-                ConfigValidatorSystemIxArgs::deserialize(&mut reader)?,
-            )),
-            DEPOSIT_IX_DISCM => Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?)),
-            DEPOSIT_STAKE_ACCOUNT_IX_DISCM => Ok(Self::DepositStakeAccount(
-                DepositStakeAccountIxArgs::deserialize(&mut reader)?,
-            )),
-            LIQUID_UNSTAKE_IX_DISCM => Ok(Self::LiquidUnstake(LiquidUnstakeIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            ADD_LIQUIDITY_IX_DISCM => Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            REMOVE_LIQUIDITY_IX_DISCM => Ok(Self::RemoveLiquidity(
-                RemoveLiquidityIxArgs::deserialize(&mut reader)?,
-            )),
-            SET_LP_PARAMS_IX_DISCM => Ok(Self::SetLpParams(SetLpParamsIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            CONFIG_MARINADE_IX_DISCM => Ok(Self::ConfigMarinade(
-                ConfigMarinadeIxArgs::deserialize(&mut reader)?,
-            )),
-            ORDER_UNSTAKE_IX_DISCM => Ok(Self::OrderUnstake(OrderUnstakeIxArgs::deserialize(
-                &mut reader,
-            )?)),
+            INITIALIZE_IX_DISCM => {
+                Ok(Self::Initialize(InitializeIxArgs::deserialize(&mut reader)?))
+            }
+            CHANGE_AUTHORITY_IX_DISCM => {
+                Ok(
+                    Self::ChangeAuthority(
+                        ChangeAuthorityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            ADD_VALIDATOR_IX_DISCM => {
+                Ok(Self::AddValidator(AddValidatorIxArgs::deserialize(&mut reader)?))
+            }
+            REMOVE_VALIDATOR_IX_DISCM => {
+                Ok(
+                    Self::RemoveValidator(
+                        RemoveValidatorIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_VALIDATOR_SCORE_IX_DISCM => {
+                Ok(
+                    Self::SetValidatorScore(
+                        SetValidatorScoreIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CONFIG_VALIDATOR_SYSTEM_IX_DISCM => {
+                Ok(
+                    Self::ConfigValidatorSystem(
+                        ConfigValidatorSystemIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEPOSIT_IX_DISCM => {
+                Ok(Self::Deposit(DepositIxArgs::deserialize(&mut reader)?))
+            }
+            DEPOSIT_STAKE_ACCOUNT_IX_DISCM => {
+                Ok(
+                    Self::DepositStakeAccount(
+                        DepositStakeAccountIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            LIQUID_UNSTAKE_IX_DISCM => {
+                Ok(Self::LiquidUnstake(LiquidUnstakeIxArgs::deserialize(&mut reader)?))
+            }
+            ADD_LIQUIDITY_IX_DISCM => {
+                Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(&mut reader)?))
+            }
+            REMOVE_LIQUIDITY_IX_DISCM => {
+                Ok(
+                    Self::RemoveLiquidity(
+                        RemoveLiquidityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_LP_PARAMS_IX_DISCM => {
+                Ok(Self::SetLpParams(SetLpParamsIxArgs::deserialize(&mut reader)?))
+            }
+            CONFIG_MARINADE_IX_DISCM => {
+                Ok(Self::ConfigMarinade(ConfigMarinadeIxArgs::deserialize(&mut reader)?))
+            }
+            ORDER_UNSTAKE_IX_DISCM => {
+                Ok(Self::OrderUnstake(OrderUnstakeIxArgs::deserialize(&mut reader)?))
+            }
             CLAIM_IX_DISCM => Ok(Self::Claim),
-            STAKE_RESERVE_IX_DISCM => Ok(Self::StakeReserve(StakeReserveIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            UPDATE_ACTIVE_IX_DISCM => Ok(Self::UpdateActive(UpdateActiveIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            UPDATE_DEACTIVATED_IX_DISCM => Ok(Self::UpdateDeactivated(
-                UpdateDeactivatedIxArgs::deserialize(&mut reader)?,
-            )),
-            DEACTIVATE_STAKE_IX_DISCM => Ok(Self::DeactivateStake(
-                DeactivateStakeIxArgs::deserialize(&mut reader)?,
-            )),
-            EMERGENCY_UNSTAKE_IX_DISCM => Ok(Self::EmergencyUnstake(
-                EmergencyUnstakeIxArgs::deserialize(&mut reader)?,
-            )),
-            MERGE_STAKES_IX_DISCM => Ok(Self::MergeStakes(MergeStakesIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
-        }
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+            STAKE_RESERVE_IX_DISCM => {
+                Ok(Self::StakeReserve(StakeReserveIxArgs::deserialize(&mut reader)?))
+            }
+            UPDATE_ACTIVE_IX_DISCM => {
+                Ok(Self::UpdateActive(UpdateActiveIxArgs::deserialize(&mut reader)?))
+            }
+            UPDATE_DEACTIVATED_IX_DISCM => {
+                Ok(
+                    Self::UpdateDeactivated(
+                        UpdateDeactivatedIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEACTIVATE_STAKE_IX_DISCM => {
+                Ok(
+                    Self::DeactivateStake(
+                        DeactivateStakeIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            EMERGENCY_UNSTAKE_IX_DISCM => {
+                Ok(
+                    Self::EmergencyUnstake(
+                        EmergencyUnstakeIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            MERGE_STAKES_IX_DISCM => {
+                Ok(Self::MergeStakes(MergeStakesIxArgs::deserialize(&mut reader)?))
+            }
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::Initialize(args) => {
                 writer.write_all(&INITIALIZE_IX_DISCM)?;
@@ -189,12 +236,13 @@ impl MarinadeFinanceProgramIx {
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -202,6 +250,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -212,6 +261,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitializeAccounts<'me, 'info> {
     pub creator_authority: &'me AccountInfo<'info>,
     pub state: &'me AccountInfo<'info>,
@@ -243,6 +293,7 @@ pub struct InitializeKeys {
     pub clock: Pubkey,
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
     fn from(accounts: InitializeAccounts) -> Self {
         Self {
@@ -352,9 +403,9 @@ impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitializeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
         [
             accounts.creator_authority.clone(),
@@ -373,9 +424,9 @@ impl<'info> From<InitializeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
-    for InitializeAccounts<'me, 'info>
-{
+for InitializeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             creator_authority: &arr[0],
@@ -408,36 +459,61 @@ impl From<InitializeIxArgs> for InitializeIxData {
     }
 }
 impl InitializeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INITIALIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INITIALIZE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(InitializeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&INITIALIZE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for InitializeIxData {
+    const DISCRIMINATOR: &'static [u8] = &INITIALIZE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for InitializeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for InitializeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitializeIxData {
+    const NAME: &'static str = "initialize";
+    const DISCM: &'static [u8] = &INITIALIZE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INITIALIZE_IX_ACCOUNTS_LEN;
+    type Keys = InitializeKeys;
+    type Args = InitializeIxArgs;
+}
 pub fn initialize_ix_with_program_id(
     program_id: Pubkey,
     keys: InitializeKeys,
     args: InitializeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
     let data: InitializeIxData = args.into();
     Ok(Instruction {
@@ -446,9 +522,13 @@ pub fn initialize_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> std::io::Result<Instruction> {
+pub fn initialize_ix(
+    keys: InitializeKeys,
+    args: InitializeIxArgs,
+) -> borsh::io::Result<Instruction> {
     initialize_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -458,12 +538,14 @@ pub fn initialize_invoke_with_program_id(
     let ix = initialize_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke(
     accounts: InitializeAccounts<'_, '_>,
     args: InitializeIxArgs,
 ) -> ProgramResult {
     initialize_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -474,6 +556,7 @@ pub fn initialize_invoke_signed_with_program_id(
     let ix = initialize_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_signed(
     accounts: InitializeAccounts<'_, '_>,
     args: InitializeIxArgs,
@@ -481,6 +564,7 @@ pub fn initialize_invoke_signed(
 ) -> ProgramResult {
     initialize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_account_keys(
     accounts: InitializeAccounts<'_, '_>,
     keys: InitializeKeys,
@@ -492,20 +576,11 @@ pub fn initialize_verify_account_keys(
         (*accounts.stake_list.key, keys.stake_list),
         (*accounts.validator_list.key, keys.validator_list),
         (*accounts.msol_mint.key, keys.msol_mint),
-        (
-            *accounts.operational_sol_account.key,
-            keys.operational_sol_account,
-        ),
+        (*accounts.operational_sol_account.key, keys.operational_sol_account),
         (*accounts.liq_pool_lp_mint.key, keys.liq_pool_lp_mint),
-        (
-            *accounts.liq_pool_sol_leg_pda.key,
-            keys.liq_pool_sol_leg_pda,
-        ),
+        (*accounts.liq_pool_sol_leg_pda.key, keys.liq_pool_sol_leg_pda),
         (*accounts.liq_pool_msol_leg.key, keys.liq_pool_msol_leg),
-        (
-            *accounts.treasury_msol_account.key,
-            keys.treasury_msol_account,
-        ),
+        (*accounts.treasury_msol_account.key, keys.treasury_msol_account),
         (*accounts.clock.key, keys.clock),
         (*accounts.rent.key, keys.rent),
     ] {
@@ -515,16 +590,22 @@ pub fn initialize_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_writable_privileges<'me, 'info>(
     accounts: InitializeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.state, accounts.stake_list, accounts.validator_list] {
+    for should_be_writable in [
+        accounts.state,
+        accounts.stake_list,
+        accounts.validator_list,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_signer_privileges<'me, 'info>(
     accounts: InitializeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -535,6 +616,7 @@ pub fn initialize_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_account_privileges<'me, 'info>(
     accounts: InitializeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -544,6 +626,7 @@ pub fn initialize_verify_account_privileges<'me, 'info>(
 }
 pub const CHANGE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ChangeAuthorityAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub admin_authority: &'me AccountInfo<'info>,
@@ -553,6 +636,7 @@ pub struct ChangeAuthorityKeys {
     pub state: Pubkey,
     pub admin_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ChangeAuthorityAccounts<'_, '_>> for ChangeAuthorityKeys {
     fn from(accounts: ChangeAuthorityAccounts) -> Self {
         Self {
@@ -585,16 +669,16 @@ impl From<[Pubkey; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]> for ChangeAuthorityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ChangeAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: ChangeAuthorityAccounts<'_, 'info>) -> Self {
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for ChangeAuthorityAccounts<'me, 'info>
-{
+for ChangeAuthorityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -616,36 +700,61 @@ impl From<ChangeAuthorityIxArgs> for ChangeAuthorityIxData {
     }
 }
 impl ChangeAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CHANGE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CHANGE_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHANGE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ChangeAuthorityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&CHANGE_AUTHORITY_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ChangeAuthorityIxData {
+    const DISCRIMINATOR: &'static [u8] = &CHANGE_AUTHORITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ChangeAuthorityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ChangeAuthorityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ChangeAuthorityIxData {
+    const NAME: &'static str = "changeAuthority";
+    const DISCM: &'static [u8] = &CHANGE_AUTHORITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CHANGE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ChangeAuthorityKeys;
+    type Args = ChangeAuthorityIxArgs;
+}
 pub fn change_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: ChangeAuthorityKeys,
     args: ChangeAuthorityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: ChangeAuthorityIxData = args.into();
     Ok(Instruction {
@@ -657,9 +766,10 @@ pub fn change_authority_ix_with_program_id(
 pub fn change_authority_ix(
     keys: ChangeAuthorityKeys,
     args: ChangeAuthorityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     change_authority_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeAuthorityAccounts<'_, '_>,
@@ -669,12 +779,14 @@ pub fn change_authority_invoke_with_program_id(
     let ix = change_authority_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_invoke(
     accounts: ChangeAuthorityAccounts<'_, '_>,
     args: ChangeAuthorityIxArgs,
 ) -> ProgramResult {
     change_authority_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ChangeAuthorityAccounts<'_, '_>,
@@ -685,6 +797,7 @@ pub fn change_authority_invoke_signed_with_program_id(
     let ix = change_authority_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_invoke_signed(
     accounts: ChangeAuthorityAccounts<'_, '_>,
     args: ChangeAuthorityIxArgs,
@@ -692,6 +805,7 @@ pub fn change_authority_invoke_signed(
 ) -> ProgramResult {
     change_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_verify_account_keys(
     accounts: ChangeAuthorityAccounts<'_, '_>,
     keys: ChangeAuthorityKeys,
@@ -706,6 +820,7 @@ pub fn change_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_verify_writable_privileges<'me, 'info>(
     accounts: ChangeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -716,6 +831,7 @@ pub fn change_authority_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_verify_signer_privileges<'me, 'info>(
     accounts: ChangeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -726,6 +842,7 @@ pub fn change_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_authority_verify_account_privileges<'me, 'info>(
     accounts: ChangeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -735,6 +852,7 @@ pub fn change_authority_verify_account_privileges<'me, 'info>(
 }
 pub const ADD_VALIDATOR_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AddValidatorAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub manager_authority: &'me AccountInfo<'info>,
@@ -758,6 +876,7 @@ pub struct AddValidatorKeys {
     pub rent: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AddValidatorAccounts<'_, '_>> for AddValidatorKeys {
     fn from(accounts: AddValidatorAccounts) -> Self {
         Self {
@@ -839,9 +958,9 @@ impl From<[Pubkey; ADD_VALIDATOR_IX_ACCOUNTS_LEN]> for AddValidatorKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AddValidatorAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] {
     fn from(accounts: AddValidatorAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -856,9 +975,9 @@ impl<'info> From<AddValidatorAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]>
-    for AddValidatorAccounts<'me, 'info>
-{
+for AddValidatorAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -887,36 +1006,61 @@ impl From<AddValidatorIxArgs> for AddValidatorIxData {
     }
 }
 impl AddValidatorIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_VALIDATOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_VALIDATOR_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ADD_VALIDATOR_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(AddValidatorIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&ADD_VALIDATOR_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for AddValidatorIxData {
+    const DISCRIMINATOR: &'static [u8] = &ADD_VALIDATOR_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for AddValidatorIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for AddValidatorIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AddValidatorIxData {
+    const NAME: &'static str = "addValidator";
+    const DISCM: &'static [u8] = &ADD_VALIDATOR_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ADD_VALIDATOR_IX_ACCOUNTS_LEN;
+    type Keys = AddValidatorKeys;
+    type Args = AddValidatorIxArgs;
+}
 pub fn add_validator_ix_with_program_id(
     program_id: Pubkey,
     keys: AddValidatorKeys,
     args: AddValidatorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
     let data: AddValidatorIxData = args.into();
     Ok(Instruction {
@@ -928,9 +1072,10 @@ pub fn add_validator_ix_with_program_id(
 pub fn add_validator_ix(
     keys: AddValidatorKeys,
     args: AddValidatorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     add_validator_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddValidatorAccounts<'_, '_>,
@@ -940,12 +1085,14 @@ pub fn add_validator_invoke_with_program_id(
     let ix = add_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_invoke(
     accounts: AddValidatorAccounts<'_, '_>,
     args: AddValidatorIxArgs,
 ) -> ProgramResult {
     add_validator_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AddValidatorAccounts<'_, '_>,
@@ -956,6 +1103,7 @@ pub fn add_validator_invoke_signed_with_program_id(
     let ix = add_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_invoke_signed(
     accounts: AddValidatorAccounts<'_, '_>,
     args: AddValidatorIxArgs,
@@ -963,6 +1111,7 @@ pub fn add_validator_invoke_signed(
 ) -> ProgramResult {
     add_validator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_verify_account_keys(
     accounts: AddValidatorAccounts<'_, '_>,
     keys: AddValidatorKeys,
@@ -984,6 +1133,7 @@ pub fn add_validator_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_verify_writable_privileges<'me, 'info>(
     accounts: AddValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -999,6 +1149,7 @@ pub fn add_validator_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_verify_signer_privileges<'me, 'info>(
     accounts: AddValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1009,6 +1160,7 @@ pub fn add_validator_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_validator_verify_account_privileges<'me, 'info>(
     accounts: AddValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1018,6 +1170,7 @@ pub fn add_validator_verify_account_privileges<'me, 'info>(
 }
 pub const REMOVE_VALIDATOR_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RemoveValidatorAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub manager_authority: &'me AccountInfo<'info>,
@@ -1033,6 +1186,7 @@ pub struct RemoveValidatorKeys {
     pub duplication_flag: Pubkey,
     pub operational_sol_account: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RemoveValidatorAccounts<'_, '_>> for RemoveValidatorKeys {
     fn from(accounts: RemoveValidatorAccounts) -> Self {
         Self {
@@ -1086,9 +1240,9 @@ impl From<[Pubkey; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]> for RemoveValidatorKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RemoveValidatorAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] {
     fn from(accounts: RemoveValidatorAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -1099,9 +1253,9 @@ impl<'info> From<RemoveValidatorAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]>
-    for RemoveValidatorAccounts<'me, 'info>
-{
+for RemoveValidatorAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -1127,36 +1281,61 @@ impl From<RemoveValidatorIxArgs> for RemoveValidatorIxData {
     }
 }
 impl RemoveValidatorIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_VALIDATOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_VALIDATOR_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_VALIDATOR_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(RemoveValidatorIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&REMOVE_VALIDATOR_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for RemoveValidatorIxData {
+    const DISCRIMINATOR: &'static [u8] = &REMOVE_VALIDATOR_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for RemoveValidatorIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for RemoveValidatorIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveValidatorIxData {
+    const NAME: &'static str = "removeValidator";
+    const DISCM: &'static [u8] = &REMOVE_VALIDATOR_IX_DISCM;
+    const ACCOUNTS_LEN: usize = REMOVE_VALIDATOR_IX_ACCOUNTS_LEN;
+    type Keys = RemoveValidatorKeys;
+    type Args = RemoveValidatorIxArgs;
+}
 pub fn remove_validator_ix_with_program_id(
     program_id: Pubkey,
     keys: RemoveValidatorKeys,
     args: RemoveValidatorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
     let data: RemoveValidatorIxData = args.into();
     Ok(Instruction {
@@ -1168,9 +1347,10 @@ pub fn remove_validator_ix_with_program_id(
 pub fn remove_validator_ix(
     keys: RemoveValidatorKeys,
     args: RemoveValidatorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     remove_validator_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveValidatorAccounts<'_, '_>,
@@ -1180,12 +1360,14 @@ pub fn remove_validator_invoke_with_program_id(
     let ix = remove_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_invoke(
     accounts: RemoveValidatorAccounts<'_, '_>,
     args: RemoveValidatorIxArgs,
 ) -> ProgramResult {
     remove_validator_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RemoveValidatorAccounts<'_, '_>,
@@ -1196,6 +1378,7 @@ pub fn remove_validator_invoke_signed_with_program_id(
     let ix = remove_validator_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_invoke_signed(
     accounts: RemoveValidatorAccounts<'_, '_>,
     args: RemoveValidatorIxArgs,
@@ -1203,6 +1386,7 @@ pub fn remove_validator_invoke_signed(
 ) -> ProgramResult {
     remove_validator_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_verify_account_keys(
     accounts: RemoveValidatorAccounts<'_, '_>,
     keys: RemoveValidatorKeys,
@@ -1212,10 +1396,7 @@ pub fn remove_validator_verify_account_keys(
         (*accounts.manager_authority.key, keys.manager_authority),
         (*accounts.validator_list.key, keys.validator_list),
         (*accounts.duplication_flag.key, keys.duplication_flag),
-        (
-            *accounts.operational_sol_account.key,
-            keys.operational_sol_account,
-        ),
+        (*accounts.operational_sol_account.key, keys.operational_sol_account),
     ] {
         if actual != expected {
             return Err((actual, expected));
@@ -1223,6 +1404,7 @@ pub fn remove_validator_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_verify_writable_privileges<'me, 'info>(
     accounts: RemoveValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1238,6 +1420,7 @@ pub fn remove_validator_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_verify_signer_privileges<'me, 'info>(
     accounts: RemoveValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1248,6 +1431,7 @@ pub fn remove_validator_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_validator_verify_account_privileges<'me, 'info>(
     accounts: RemoveValidatorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1257,6 +1441,7 @@ pub fn remove_validator_verify_account_privileges<'me, 'info>(
 }
 pub const SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetValidatorScoreAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub manager_authority: &'me AccountInfo<'info>,
@@ -1268,6 +1453,7 @@ pub struct SetValidatorScoreKeys {
     pub manager_authority: Pubkey,
     pub validator_list: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetValidatorScoreAccounts<'_, '_>> for SetValidatorScoreKeys {
     fn from(accounts: SetValidatorScoreAccounts) -> Self {
         Self {
@@ -1307,9 +1493,9 @@ impl From<[Pubkey; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]> for SetValidatorScoreKe
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetValidatorScoreAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetValidatorScoreAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -1318,10 +1504,12 @@ impl<'info> From<SetValidatorScoreAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]>
-    for SetValidatorScoreAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN]) -> Self {
+for SetValidatorScoreAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             state: &arr[0],
             manager_authority: &arr[1],
@@ -1345,36 +1533,61 @@ impl From<SetValidatorScoreIxArgs> for SetValidatorScoreIxData {
     }
 }
 impl SetValidatorScoreIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_VALIDATOR_SCORE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_VALIDATOR_SCORE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_VALIDATOR_SCORE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SetValidatorScoreIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SET_VALIDATOR_SCORE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetValidatorScoreIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_VALIDATOR_SCORE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetValidatorScoreIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetValidatorScoreIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetValidatorScoreIxData {
+    const NAME: &'static str = "setValidatorScore";
+    const DISCM: &'static [u8] = &SET_VALIDATOR_SCORE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN;
+    type Keys = SetValidatorScoreKeys;
+    type Args = SetValidatorScoreIxArgs;
+}
 pub fn set_validator_score_ix_with_program_id(
     program_id: Pubkey,
     keys: SetValidatorScoreKeys,
     args: SetValidatorScoreIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = keys.into();
     let data: SetValidatorScoreIxData = args.into();
     Ok(Instruction {
@@ -1386,9 +1599,10 @@ pub fn set_validator_score_ix_with_program_id(
 pub fn set_validator_score_ix(
     keys: SetValidatorScoreKeys,
     args: SetValidatorScoreIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     set_validator_score_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetValidatorScoreAccounts<'_, '_>,
@@ -1398,12 +1612,14 @@ pub fn set_validator_score_invoke_with_program_id(
     let ix = set_validator_score_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_invoke(
     accounts: SetValidatorScoreAccounts<'_, '_>,
     args: SetValidatorScoreIxArgs,
 ) -> ProgramResult {
     set_validator_score_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetValidatorScoreAccounts<'_, '_>,
@@ -1414,6 +1630,7 @@ pub fn set_validator_score_invoke_signed_with_program_id(
     let ix = set_validator_score_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_invoke_signed(
     accounts: SetValidatorScoreAccounts<'_, '_>,
     args: SetValidatorScoreIxArgs,
@@ -1421,6 +1638,7 @@ pub fn set_validator_score_invoke_signed(
 ) -> ProgramResult {
     set_validator_score_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_verify_account_keys(
     accounts: SetValidatorScoreAccounts<'_, '_>,
     keys: SetValidatorScoreKeys,
@@ -1436,6 +1654,7 @@ pub fn set_validator_score_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_verify_writable_privileges<'me, 'info>(
     accounts: SetValidatorScoreAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1446,6 +1665,7 @@ pub fn set_validator_score_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_verify_signer_privileges<'me, 'info>(
     accounts: SetValidatorScoreAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1456,6 +1676,7 @@ pub fn set_validator_score_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_validator_score_verify_account_privileges<'me, 'info>(
     accounts: SetValidatorScoreAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1465,6 +1686,7 @@ pub fn set_validator_score_verify_account_privileges<'me, 'info>(
 }
 pub const CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ConfigValidatorSystemAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub manager_authority: &'me AccountInfo<'info>,
@@ -1474,6 +1696,7 @@ pub struct ConfigValidatorSystemKeys {
     pub state: Pubkey,
     pub manager_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ConfigValidatorSystemAccounts<'_, '_>> for ConfigValidatorSystemKeys {
     fn from(accounts: ConfigValidatorSystemAccounts) -> Self {
         Self {
@@ -1482,7 +1705,8 @@ impl From<ConfigValidatorSystemAccounts<'_, '_>> for ConfigValidatorSystemKeys {
         }
     }
 }
-impl From<ConfigValidatorSystemKeys> for [AccountMeta; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] {
+impl From<ConfigValidatorSystemKeys>
+for [AccountMeta; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] {
     fn from(keys: ConfigValidatorSystemKeys) -> Self {
         [
             AccountMeta {
@@ -1498,7 +1722,8 @@ impl From<ConfigValidatorSystemKeys> for [AccountMeta; CONFIG_VALIDATOR_SYSTEM_I
         ]
     }
 }
-impl From<[Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]> for ConfigValidatorSystemKeys {
+impl From<[Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]>
+for ConfigValidatorSystemKeys {
     fn from(pubkeys: [Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: pubkeys[0],
@@ -1506,17 +1731,19 @@ impl From<[Pubkey; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]> for ConfigValidator
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ConfigValidatorSystemAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] {
     fn from(accounts: ConfigValidatorSystemAccounts<'_, 'info>) -> Self {
         [accounts.state.clone(), accounts.manager_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]>
-    for ConfigValidatorSystemAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN]) -> Self {
+for ConfigValidatorSystemAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             state: &arr[0],
             manager_authority: &arr[1],
@@ -1537,36 +1764,61 @@ impl From<ConfigValidatorSystemIxArgs> for ConfigValidatorSystemIxData {
     }
 }
 impl ConfigValidatorSystemIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CONFIG_VALIDATOR_SYSTEM_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CONFIG_VALIDATOR_SYSTEM_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CONFIG_VALIDATOR_SYSTEM_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ConfigValidatorSystemIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&CONFIG_VALIDATOR_SYSTEM_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ConfigValidatorSystemIxData {
+    const DISCRIMINATOR: &'static [u8] = &CONFIG_VALIDATOR_SYSTEM_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ConfigValidatorSystemIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ConfigValidatorSystemIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ConfigValidatorSystemIxData {
+    const NAME: &'static str = "configValidatorSystem";
+    const DISCM: &'static [u8] = &CONFIG_VALIDATOR_SYSTEM_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN;
+    type Keys = ConfigValidatorSystemKeys;
+    type Args = ConfigValidatorSystemIxArgs;
+}
 pub fn config_validator_system_ix_with_program_id(
     program_id: Pubkey,
     keys: ConfigValidatorSystemKeys,
     args: ConfigValidatorSystemIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] = keys.into();
     let data: ConfigValidatorSystemIxData = args.into();
     Ok(Instruction {
@@ -1578,9 +1830,10 @@ pub fn config_validator_system_ix_with_program_id(
 pub fn config_validator_system_ix(
     keys: ConfigValidatorSystemKeys,
     args: ConfigValidatorSystemIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     config_validator_system_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
@@ -1590,12 +1843,14 @@ pub fn config_validator_system_invoke_with_program_id(
     let ix = config_validator_system_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_invoke(
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
     args: ConfigValidatorSystemIxArgs,
 ) -> ProgramResult {
     config_validator_system_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
@@ -1606,13 +1861,20 @@ pub fn config_validator_system_invoke_signed_with_program_id(
     let ix = config_validator_system_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_invoke_signed(
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
     args: ConfigValidatorSystemIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    config_validator_system_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
-}
+    config_validator_system_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
+}
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_verify_account_keys(
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
     keys: ConfigValidatorSystemKeys,
@@ -1627,6 +1889,7 @@ pub fn config_validator_system_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_verify_writable_privileges<'me, 'info>(
     accounts: ConfigValidatorSystemAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1637,6 +1900,7 @@ pub fn config_validator_system_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_verify_signer_privileges<'me, 'info>(
     accounts: ConfigValidatorSystemAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1647,6 +1911,7 @@ pub fn config_validator_system_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_validator_system_verify_account_privileges<'me, 'info>(
     accounts: ConfigValidatorSystemAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1656,6 +1921,7 @@ pub fn config_validator_system_verify_account_privileges<'me, 'info>(
 }
 pub const DEPOSIT_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DepositAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub msol_mint: &'me AccountInfo<'info>,
@@ -1683,6 +1949,7 @@ pub struct DepositKeys {
     pub system_program: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DepositAccounts<'_, '_>> for DepositKeys {
     fn from(accounts: DepositAccounts) -> Self {
         Self {
@@ -1778,7 +2045,9 @@ impl From<[Pubkey; DEPOSIT_IX_ACCOUNTS_LEN]> for DepositKeys {
         }
     }
 }
-impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<DepositAccounts<'_, 'info>>
+for [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] {
     fn from(accounts: DepositAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -1795,9 +2064,9 @@ impl<'info> From<DepositAccounts<'_, 'info>> for [AccountInfo<'info>; DEPOSIT_IX
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
-    for DepositAccounts<'me, 'info>
-{
+for DepositAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -1828,36 +2097,61 @@ impl From<DepositIxArgs> for DepositIxData {
     }
 }
 impl DepositIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPOSIT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(DepositIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&DEPOSIT_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for DepositIxData {
+    const DISCRIMINATOR: &'static [u8] = &DEPOSIT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for DepositIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for DepositIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DepositIxData {
+    const NAME: &'static str = "deposit";
+    const DISCM: &'static [u8] = &DEPOSIT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = DEPOSIT_IX_ACCOUNTS_LEN;
+    type Keys = DepositKeys;
+    type Args = DepositIxArgs;
+}
 pub fn deposit_ix_with_program_id(
     program_id: Pubkey,
     keys: DepositKeys,
     args: DepositIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
     let data: DepositIxData = args.into();
     Ok(Instruction {
@@ -1866,9 +2160,13 @@ pub fn deposit_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn deposit_ix(keys: DepositKeys, args: DepositIxArgs) -> std::io::Result<Instruction> {
+pub fn deposit_ix(
+    keys: DepositKeys,
+    args: DepositIxArgs,
+) -> borsh::io::Result<Instruction> {
     deposit_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositAccounts<'_, '_>,
@@ -1878,9 +2176,14 @@ pub fn deposit_invoke_with_program_id(
     let ix = deposit_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn deposit_invoke(accounts: DepositAccounts<'_, '_>, args: DepositIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn deposit_invoke(
+    accounts: DepositAccounts<'_, '_>,
+    args: DepositIxArgs,
+) -> ProgramResult {
     deposit_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DepositAccounts<'_, '_>,
@@ -1891,6 +2194,7 @@ pub fn deposit_invoke_signed_with_program_id(
     let ix = deposit_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_invoke_signed(
     accounts: DepositAccounts<'_, '_>,
     args: DepositIxArgs,
@@ -1898,6 +2202,7 @@ pub fn deposit_invoke_signed(
 ) -> ProgramResult {
     deposit_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_verify_account_keys(
     accounts: DepositAccounts<'_, '_>,
     keys: DepositKeys,
@@ -1905,15 +2210,9 @@ pub fn deposit_verify_account_keys(
     for (actual, expected) in [
         (*accounts.state.key, keys.state),
         (*accounts.msol_mint.key, keys.msol_mint),
-        (
-            *accounts.liq_pool_sol_leg_pda.key,
-            keys.liq_pool_sol_leg_pda,
-        ),
+        (*accounts.liq_pool_sol_leg_pda.key, keys.liq_pool_sol_leg_pda),
         (*accounts.liq_pool_msol_leg.key, keys.liq_pool_msol_leg),
-        (
-            *accounts.liq_pool_msol_leg_authority.key,
-            keys.liq_pool_msol_leg_authority,
-        ),
+        (*accounts.liq_pool_msol_leg_authority.key, keys.liq_pool_msol_leg_authority),
         (*accounts.reserve_pda.key, keys.reserve_pda),
         (*accounts.transfer_from.key, keys.transfer_from),
         (*accounts.mint_to.key, keys.mint_to),
@@ -1927,6 +2226,7 @@ pub fn deposit_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_verify_writable_privileges<'me, 'info>(
     accounts: DepositAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1945,6 +2245,7 @@ pub fn deposit_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_verify_signer_privileges<'me, 'info>(
     accounts: DepositAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1955,6 +2256,7 @@ pub fn deposit_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_verify_account_privileges<'me, 'info>(
     accounts: DepositAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1964,6 +2266,7 @@ pub fn deposit_verify_account_privileges<'me, 'info>(
 }
 pub const DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 15;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DepositStakeAccountAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub validator_list: &'me AccountInfo<'info>,
@@ -1999,6 +2302,7 @@ pub struct DepositStakeAccountKeys {
     pub token_program: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DepositStakeAccountAccounts<'_, '_>> for DepositStakeAccountKeys {
     fn from(accounts: DepositStakeAccountAccounts) -> Self {
         Self {
@@ -2020,7 +2324,8 @@ impl From<DepositStakeAccountAccounts<'_, '_>> for DepositStakeAccountKeys {
         }
     }
 }
-impl From<DepositStakeAccountKeys> for [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<DepositStakeAccountKeys>
+for [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: DepositStakeAccountKeys) -> Self {
         [
             AccountMeta {
@@ -2122,9 +2427,9 @@ impl From<[Pubkey; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for DepositStakeAccou
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DepositStakeAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: DepositStakeAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -2145,10 +2450,12 @@ impl<'info> From<DepositStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for DepositStakeAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for DepositStakeAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             state: &arr[0],
             validator_list: &arr[1],
@@ -2182,36 +2489,61 @@ impl From<DepositStakeAccountIxArgs> for DepositStakeAccountIxData {
     }
 }
 impl DepositStakeAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEPOSIT_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPOSIT_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(DepositStakeAccountIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&DEPOSIT_STAKE_ACCOUNT_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for DepositStakeAccountIxData {
+    const DISCRIMINATOR: &'static [u8] = &DEPOSIT_STAKE_ACCOUNT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for DepositStakeAccountIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for DepositStakeAccountIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DepositStakeAccountIxData {
+    const NAME: &'static str = "depositStakeAccount";
+    const DISCM: &'static [u8] = &DEPOSIT_STAKE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = DepositStakeAccountKeys;
+    type Args = DepositStakeAccountIxArgs;
+}
 pub fn deposit_stake_account_ix_with_program_id(
     program_id: Pubkey,
     keys: DepositStakeAccountKeys,
     args: DepositStakeAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     let data: DepositStakeAccountIxData = args.into();
     Ok(Instruction {
@@ -2223,9 +2555,10 @@ pub fn deposit_stake_account_ix_with_program_id(
 pub fn deposit_stake_account_ix(
     keys: DepositStakeAccountKeys,
     args: DepositStakeAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deposit_stake_account_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositStakeAccountAccounts<'_, '_>,
@@ -2235,12 +2568,14 @@ pub fn deposit_stake_account_invoke_with_program_id(
     let ix = deposit_stake_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_invoke(
     accounts: DepositStakeAccountAccounts<'_, '_>,
     args: DepositStakeAccountIxArgs,
 ) -> ProgramResult {
     deposit_stake_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DepositStakeAccountAccounts<'_, '_>,
@@ -2251,6 +2586,7 @@ pub fn deposit_stake_account_invoke_signed_with_program_id(
     let ix = deposit_stake_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_invoke_signed(
     accounts: DepositStakeAccountAccounts<'_, '_>,
     args: DepositStakeAccountIxArgs,
@@ -2258,6 +2594,7 @@ pub fn deposit_stake_account_invoke_signed(
 ) -> ProgramResult {
     deposit_stake_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_verify_account_keys(
     accounts: DepositStakeAccountAccounts<'_, '_>,
     keys: DepositStakeAccountKeys,
@@ -2285,6 +2622,7 @@ pub fn deposit_stake_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_verify_writable_privileges<'me, 'info>(
     accounts: DepositStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2304,6 +2642,7 @@ pub fn deposit_stake_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_verify_signer_privileges<'me, 'info>(
     accounts: DepositStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2314,6 +2653,7 @@ pub fn deposit_stake_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_stake_account_verify_account_privileges<'me, 'info>(
     accounts: DepositStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2323,6 +2663,7 @@ pub fn deposit_stake_account_verify_account_privileges<'me, 'info>(
 }
 pub const LIQUID_UNSTAKE_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct LiquidUnstakeAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub msol_mint: &'me AccountInfo<'info>,
@@ -2348,6 +2689,7 @@ pub struct LiquidUnstakeKeys {
     pub system_program: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<LiquidUnstakeAccounts<'_, '_>> for LiquidUnstakeKeys {
     fn from(accounts: LiquidUnstakeAccounts) -> Self {
         Self {
@@ -2436,9 +2778,9 @@ impl From<[Pubkey; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]> for LiquidUnstakeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<LiquidUnstakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: LiquidUnstakeAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -2454,9 +2796,9 @@ impl<'info> From<LiquidUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]>
-    for LiquidUnstakeAccounts<'me, 'info>
-{
+for LiquidUnstakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -2486,36 +2828,61 @@ impl From<LiquidUnstakeIxArgs> for LiquidUnstakeIxData {
     }
 }
 impl LiquidUnstakeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != LIQUID_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LIQUID_UNSTAKE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LIQUID_UNSTAKE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(LiquidUnstakeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&LIQUID_UNSTAKE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for LiquidUnstakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &LIQUID_UNSTAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for LiquidUnstakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for LiquidUnstakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for LiquidUnstakeIxData {
+    const NAME: &'static str = "liquidUnstake";
+    const DISCM: &'static [u8] = &LIQUID_UNSTAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = LIQUID_UNSTAKE_IX_ACCOUNTS_LEN;
+    type Keys = LiquidUnstakeKeys;
+    type Args = LiquidUnstakeIxArgs;
+}
 pub fn liquid_unstake_ix_with_program_id(
     program_id: Pubkey,
     keys: LiquidUnstakeKeys,
     args: LiquidUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let data: LiquidUnstakeIxData = args.into();
     Ok(Instruction {
@@ -2527,9 +2894,10 @@ pub fn liquid_unstake_ix_with_program_id(
 pub fn liquid_unstake_ix(
     keys: LiquidUnstakeKeys,
     args: LiquidUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     liquid_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LiquidUnstakeAccounts<'_, '_>,
@@ -2539,12 +2907,14 @@ pub fn liquid_unstake_invoke_with_program_id(
     let ix = liquid_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_invoke(
     accounts: LiquidUnstakeAccounts<'_, '_>,
     args: LiquidUnstakeIxArgs,
 ) -> ProgramResult {
     liquid_unstake_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: LiquidUnstakeAccounts<'_, '_>,
@@ -2555,6 +2925,7 @@ pub fn liquid_unstake_invoke_signed_with_program_id(
     let ix = liquid_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_invoke_signed(
     accounts: LiquidUnstakeAccounts<'_, '_>,
     args: LiquidUnstakeIxArgs,
@@ -2562,6 +2933,7 @@ pub fn liquid_unstake_invoke_signed(
 ) -> ProgramResult {
     liquid_unstake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_verify_account_keys(
     accounts: LiquidUnstakeAccounts<'_, '_>,
     keys: LiquidUnstakeKeys,
@@ -2569,20 +2941,11 @@ pub fn liquid_unstake_verify_account_keys(
     for (actual, expected) in [
         (*accounts.state.key, keys.state),
         (*accounts.msol_mint.key, keys.msol_mint),
-        (
-            *accounts.liq_pool_sol_leg_pda.key,
-            keys.liq_pool_sol_leg_pda,
-        ),
+        (*accounts.liq_pool_sol_leg_pda.key, keys.liq_pool_sol_leg_pda),
         (*accounts.liq_pool_msol_leg.key, keys.liq_pool_msol_leg),
-        (
-            *accounts.treasury_msol_account.key,
-            keys.treasury_msol_account,
-        ),
+        (*accounts.treasury_msol_account.key, keys.treasury_msol_account),
         (*accounts.get_msol_from.key, keys.get_msol_from),
-        (
-            *accounts.get_msol_from_authority.key,
-            keys.get_msol_from_authority,
-        ),
+        (*accounts.get_msol_from_authority.key, keys.get_msol_from_authority),
         (*accounts.transfer_sol_to.key, keys.transfer_sol_to),
         (*accounts.system_program.key, keys.system_program),
         (*accounts.token_program.key, keys.token_program),
@@ -2593,6 +2956,7 @@ pub fn liquid_unstake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_verify_writable_privileges<'me, 'info>(
     accounts: LiquidUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2611,6 +2975,7 @@ pub fn liquid_unstake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_verify_signer_privileges<'me, 'info>(
     accounts: LiquidUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2621,6 +2986,7 @@ pub fn liquid_unstake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn liquid_unstake_verify_account_privileges<'me, 'info>(
     accounts: LiquidUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2630,6 +2996,7 @@ pub fn liquid_unstake_verify_account_privileges<'me, 'info>(
 }
 pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AddLiquidityAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub lp_mint: &'me AccountInfo<'info>,
@@ -2653,6 +3020,7 @@ pub struct AddLiquidityKeys {
     pub system_program: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AddLiquidityAccounts<'_, '_>> for AddLiquidityKeys {
     fn from(accounts: AddLiquidityAccounts) -> Self {
         Self {
@@ -2734,9 +3102,9 @@ impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AddLiquidityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: AddLiquidityAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -2751,9 +3119,9 @@ impl<'info> From<AddLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
-    for AddLiquidityAccounts<'me, 'info>
-{
+for AddLiquidityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -2782,36 +3150,61 @@ impl From<AddLiquidityIxArgs> for AddLiquidityIxData {
     }
 }
 impl AddLiquidityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_LIQUIDITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ADD_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(AddLiquidityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for AddLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for AddLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for AddLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AddLiquidityIxData {
+    const NAME: &'static str = "addLiquidity";
+    const DISCM: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ADD_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = AddLiquidityKeys;
+    type Args = AddLiquidityIxArgs;
+}
 pub fn add_liquidity_ix_with_program_id(
     program_id: Pubkey,
     keys: AddLiquidityKeys,
     args: AddLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: AddLiquidityIxData = args.into();
     Ok(Instruction {
@@ -2823,9 +3216,10 @@ pub fn add_liquidity_ix_with_program_id(
 pub fn add_liquidity_ix(
     keys: AddLiquidityKeys,
     args: AddLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     add_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -2835,12 +3229,14 @@ pub fn add_liquidity_invoke_with_program_id(
     let ix = add_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke(
     accounts: AddLiquidityAccounts<'_, '_>,
     args: AddLiquidityIxArgs,
 ) -> ProgramResult {
     add_liquidity_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -2851,6 +3247,7 @@ pub fn add_liquidity_invoke_signed_with_program_id(
     let ix = add_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_signed(
     accounts: AddLiquidityAccounts<'_, '_>,
     args: AddLiquidityIxArgs,
@@ -2858,6 +3255,7 @@ pub fn add_liquidity_invoke_signed(
 ) -> ProgramResult {
     add_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_account_keys(
     accounts: AddLiquidityAccounts<'_, '_>,
     keys: AddLiquidityKeys,
@@ -2867,10 +3265,7 @@ pub fn add_liquidity_verify_account_keys(
         (*accounts.lp_mint.key, keys.lp_mint),
         (*accounts.lp_mint_authority.key, keys.lp_mint_authority),
         (*accounts.liq_pool_msol_leg.key, keys.liq_pool_msol_leg),
-        (
-            *accounts.liq_pool_sol_leg_pda.key,
-            keys.liq_pool_sol_leg_pda,
-        ),
+        (*accounts.liq_pool_sol_leg_pda.key, keys.liq_pool_sol_leg_pda),
         (*accounts.transfer_from.key, keys.transfer_from),
         (*accounts.mint_to.key, keys.mint_to),
         (*accounts.system_program.key, keys.system_program),
@@ -2882,6 +3277,7 @@ pub fn add_liquidity_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_writable_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2898,6 +3294,7 @@ pub fn add_liquidity_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_signer_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2908,6 +3305,7 @@ pub fn add_liquidity_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_account_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2917,6 +3315,7 @@ pub fn add_liquidity_verify_account_privileges<'me, 'info>(
 }
 pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RemoveLiquidityAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub lp_mint: &'me AccountInfo<'info>,
@@ -2944,6 +3343,7 @@ pub struct RemoveLiquidityKeys {
     pub system_program: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RemoveLiquidityAccounts<'_, '_>> for RemoveLiquidityKeys {
     fn from(accounts: RemoveLiquidityAccounts) -> Self {
         Self {
@@ -3039,9 +3439,9 @@ impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: RemoveLiquidityAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -3058,9 +3458,9 @@ impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
-    for RemoveLiquidityAccounts<'me, 'info>
-{
+for RemoveLiquidityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -3091,36 +3491,61 @@ impl From<RemoveLiquidityIxArgs> for RemoveLiquidityIxData {
     }
 }
 impl RemoveLiquidityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(RemoveLiquidityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for RemoveLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for RemoveLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for RemoveLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveLiquidityIxData {
+    const NAME: &'static str = "removeLiquidity";
+    const DISCM: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = RemoveLiquidityKeys;
+    type Args = RemoveLiquidityIxArgs;
+}
 pub fn remove_liquidity_ix_with_program_id(
     program_id: Pubkey,
     keys: RemoveLiquidityKeys,
     args: RemoveLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: RemoveLiquidityIxData = args.into();
     Ok(Instruction {
@@ -3132,9 +3557,10 @@ pub fn remove_liquidity_ix_with_program_id(
 pub fn remove_liquidity_ix(
     keys: RemoveLiquidityKeys,
     args: RemoveLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     remove_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -3144,12 +3570,14 @@ pub fn remove_liquidity_invoke_with_program_id(
     let ix = remove_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     args: RemoveLiquidityIxArgs,
 ) -> ProgramResult {
     remove_liquidity_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -3160,6 +3588,7 @@ pub fn remove_liquidity_invoke_signed_with_program_id(
     let ix = remove_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_signed(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     args: RemoveLiquidityIxArgs,
@@ -3167,6 +3596,7 @@ pub fn remove_liquidity_invoke_signed(
 ) -> ProgramResult {
     remove_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_account_keys(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     keys: RemoveLiquidityKeys,
@@ -3178,15 +3608,9 @@ pub fn remove_liquidity_verify_account_keys(
         (*accounts.burn_from_authority.key, keys.burn_from_authority),
         (*accounts.transfer_sol_to.key, keys.transfer_sol_to),
         (*accounts.transfer_msol_to.key, keys.transfer_msol_to),
-        (
-            *accounts.liq_pool_sol_leg_pda.key,
-            keys.liq_pool_sol_leg_pda,
-        ),
+        (*accounts.liq_pool_sol_leg_pda.key, keys.liq_pool_sol_leg_pda),
         (*accounts.liq_pool_msol_leg.key, keys.liq_pool_msol_leg),
-        (
-            *accounts.liq_pool_msol_leg_authority.key,
-            keys.liq_pool_msol_leg_authority,
-        ),
+        (*accounts.liq_pool_msol_leg_authority.key, keys.liq_pool_msol_leg_authority),
         (*accounts.system_program.key, keys.system_program),
         (*accounts.token_program.key, keys.token_program),
     ] {
@@ -3196,6 +3620,7 @@ pub fn remove_liquidity_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_writable_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3214,6 +3639,7 @@ pub fn remove_liquidity_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_signer_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3224,6 +3650,7 @@ pub fn remove_liquidity_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_account_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3233,6 +3660,7 @@ pub fn remove_liquidity_verify_account_privileges<'me, 'info>(
 }
 pub const SET_LP_PARAMS_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetLpParamsAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub admin_authority: &'me AccountInfo<'info>,
@@ -3242,6 +3670,7 @@ pub struct SetLpParamsKeys {
     pub state: Pubkey,
     pub admin_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetLpParamsAccounts<'_, '_>> for SetLpParamsKeys {
     fn from(accounts: SetLpParamsAccounts) -> Self {
         Self {
@@ -3274,16 +3703,16 @@ impl From<[Pubkey; SET_LP_PARAMS_IX_ACCOUNTS_LEN]> for SetLpParamsKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetLpParamsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetLpParamsAccounts<'_, 'info>) -> Self {
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]>
-    for SetLpParamsAccounts<'me, 'info>
-{
+for SetLpParamsAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_LP_PARAMS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -3307,36 +3736,61 @@ impl From<SetLpParamsIxArgs> for SetLpParamsIxData {
     }
 }
 impl SetLpParamsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_LP_PARAMS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_LP_PARAMS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_LP_PARAMS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SetLpParamsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SET_LP_PARAMS_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetLpParamsIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_LP_PARAMS_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetLpParamsIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetLpParamsIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetLpParamsIxData {
+    const NAME: &'static str = "setLpParams";
+    const DISCM: &'static [u8] = &SET_LP_PARAMS_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_LP_PARAMS_IX_ACCOUNTS_LEN;
+    type Keys = SetLpParamsKeys;
+    type Args = SetLpParamsIxArgs;
+}
 pub fn set_lp_params_ix_with_program_id(
     program_id: Pubkey,
     keys: SetLpParamsKeys,
     args: SetLpParamsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_LP_PARAMS_IX_ACCOUNTS_LEN] = keys.into();
     let data: SetLpParamsIxData = args.into();
     Ok(Instruction {
@@ -3348,9 +3802,10 @@ pub fn set_lp_params_ix_with_program_id(
 pub fn set_lp_params_ix(
     keys: SetLpParamsKeys,
     args: SetLpParamsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     set_lp_params_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetLpParamsAccounts<'_, '_>,
@@ -3360,12 +3815,14 @@ pub fn set_lp_params_invoke_with_program_id(
     let ix = set_lp_params_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_invoke(
     accounts: SetLpParamsAccounts<'_, '_>,
     args: SetLpParamsIxArgs,
 ) -> ProgramResult {
     set_lp_params_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetLpParamsAccounts<'_, '_>,
@@ -3376,6 +3833,7 @@ pub fn set_lp_params_invoke_signed_with_program_id(
     let ix = set_lp_params_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_invoke_signed(
     accounts: SetLpParamsAccounts<'_, '_>,
     args: SetLpParamsIxArgs,
@@ -3383,6 +3841,7 @@ pub fn set_lp_params_invoke_signed(
 ) -> ProgramResult {
     set_lp_params_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_verify_account_keys(
     accounts: SetLpParamsAccounts<'_, '_>,
     keys: SetLpParamsKeys,
@@ -3397,6 +3856,7 @@ pub fn set_lp_params_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_verify_writable_privileges<'me, 'info>(
     accounts: SetLpParamsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3407,6 +3867,7 @@ pub fn set_lp_params_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_verify_signer_privileges<'me, 'info>(
     accounts: SetLpParamsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3417,6 +3878,7 @@ pub fn set_lp_params_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lp_params_verify_account_privileges<'me, 'info>(
     accounts: SetLpParamsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3426,6 +3888,7 @@ pub fn set_lp_params_verify_account_privileges<'me, 'info>(
 }
 pub const CONFIG_MARINADE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ConfigMarinadeAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub admin_authority: &'me AccountInfo<'info>,
@@ -3435,6 +3898,7 @@ pub struct ConfigMarinadeKeys {
     pub state: Pubkey,
     pub admin_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ConfigMarinadeAccounts<'_, '_>> for ConfigMarinadeKeys {
     fn from(accounts: ConfigMarinadeAccounts) -> Self {
         Self {
@@ -3467,16 +3931,16 @@ impl From<[Pubkey; CONFIG_MARINADE_IX_ACCOUNTS_LEN]> for ConfigMarinadeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ConfigMarinadeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] {
     fn from(accounts: ConfigMarinadeAccounts<'_, 'info>) -> Self {
         [accounts.state.clone(), accounts.admin_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]>
-    for ConfigMarinadeAccounts<'me, 'info>
-{
+for ConfigMarinadeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -3498,36 +3962,61 @@ impl From<ConfigMarinadeIxArgs> for ConfigMarinadeIxData {
     }
 }
 impl ConfigMarinadeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CONFIG_MARINADE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CONFIG_MARINADE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CONFIG_MARINADE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ConfigMarinadeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&CONFIG_MARINADE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ConfigMarinadeIxData {
+    const DISCRIMINATOR: &'static [u8] = &CONFIG_MARINADE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ConfigMarinadeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ConfigMarinadeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ConfigMarinadeIxData {
+    const NAME: &'static str = "configMarinade";
+    const DISCM: &'static [u8] = &CONFIG_MARINADE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CONFIG_MARINADE_IX_ACCOUNTS_LEN;
+    type Keys = ConfigMarinadeKeys;
+    type Args = ConfigMarinadeIxArgs;
+}
 pub fn config_marinade_ix_with_program_id(
     program_id: Pubkey,
     keys: ConfigMarinadeKeys,
     args: ConfigMarinadeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = keys.into();
     let data: ConfigMarinadeIxData = args.into();
     Ok(Instruction {
@@ -3539,9 +4028,10 @@ pub fn config_marinade_ix_with_program_id(
 pub fn config_marinade_ix(
     keys: ConfigMarinadeKeys,
     args: ConfigMarinadeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     config_marinade_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConfigMarinadeAccounts<'_, '_>,
@@ -3551,12 +4041,14 @@ pub fn config_marinade_invoke_with_program_id(
     let ix = config_marinade_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_invoke(
     accounts: ConfigMarinadeAccounts<'_, '_>,
     args: ConfigMarinadeIxArgs,
 ) -> ProgramResult {
     config_marinade_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ConfigMarinadeAccounts<'_, '_>,
@@ -3567,6 +4059,7 @@ pub fn config_marinade_invoke_signed_with_program_id(
     let ix = config_marinade_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_invoke_signed(
     accounts: ConfigMarinadeAccounts<'_, '_>,
     args: ConfigMarinadeIxArgs,
@@ -3574,6 +4067,7 @@ pub fn config_marinade_invoke_signed(
 ) -> ProgramResult {
     config_marinade_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_verify_account_keys(
     accounts: ConfigMarinadeAccounts<'_, '_>,
     keys: ConfigMarinadeKeys,
@@ -3588,6 +4082,7 @@ pub fn config_marinade_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_verify_writable_privileges<'me, 'info>(
     accounts: ConfigMarinadeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3598,6 +4093,7 @@ pub fn config_marinade_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_verify_signer_privileges<'me, 'info>(
     accounts: ConfigMarinadeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3608,6 +4104,7 @@ pub fn config_marinade_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn config_marinade_verify_account_privileges<'me, 'info>(
     accounts: ConfigMarinadeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3617,6 +4114,7 @@ pub fn config_marinade_verify_account_privileges<'me, 'info>(
 }
 pub const ORDER_UNSTAKE_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct OrderUnstakeAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub msol_mint: &'me AccountInfo<'info>,
@@ -3638,6 +4136,7 @@ pub struct OrderUnstakeKeys {
     pub rent: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<OrderUnstakeAccounts<'_, '_>> for OrderUnstakeKeys {
     fn from(accounts: OrderUnstakeAccounts) -> Self {
         Self {
@@ -3712,9 +4211,9 @@ impl From<[Pubkey; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]> for OrderUnstakeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<OrderUnstakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: OrderUnstakeAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -3728,9 +4227,9 @@ impl<'info> From<OrderUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]>
-    for OrderUnstakeAccounts<'me, 'info>
-{
+for OrderUnstakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -3758,36 +4257,61 @@ impl From<OrderUnstakeIxArgs> for OrderUnstakeIxData {
     }
 }
 impl OrderUnstakeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ORDER_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ORDER_UNSTAKE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ORDER_UNSTAKE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(OrderUnstakeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&ORDER_UNSTAKE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for OrderUnstakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &ORDER_UNSTAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for OrderUnstakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for OrderUnstakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for OrderUnstakeIxData {
+    const NAME: &'static str = "orderUnstake";
+    const DISCM: &'static [u8] = &ORDER_UNSTAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ORDER_UNSTAKE_IX_ACCOUNTS_LEN;
+    type Keys = OrderUnstakeKeys;
+    type Args = OrderUnstakeIxArgs;
+}
 pub fn order_unstake_ix_with_program_id(
     program_id: Pubkey,
     keys: OrderUnstakeKeys,
     args: OrderUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let data: OrderUnstakeIxData = args.into();
     Ok(Instruction {
@@ -3799,9 +4323,10 @@ pub fn order_unstake_ix_with_program_id(
 pub fn order_unstake_ix(
     keys: OrderUnstakeKeys,
     args: OrderUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     order_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: OrderUnstakeAccounts<'_, '_>,
@@ -3811,12 +4336,14 @@ pub fn order_unstake_invoke_with_program_id(
     let ix = order_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_invoke(
     accounts: OrderUnstakeAccounts<'_, '_>,
     args: OrderUnstakeIxArgs,
 ) -> ProgramResult {
     order_unstake_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: OrderUnstakeAccounts<'_, '_>,
@@ -3827,6 +4354,7 @@ pub fn order_unstake_invoke_signed_with_program_id(
     let ix = order_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_invoke_signed(
     accounts: OrderUnstakeAccounts<'_, '_>,
     args: OrderUnstakeIxArgs,
@@ -3834,6 +4362,7 @@ pub fn order_unstake_invoke_signed(
 ) -> ProgramResult {
     order_unstake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_verify_account_keys(
     accounts: OrderUnstakeAccounts<'_, '_>,
     keys: OrderUnstakeKeys,
@@ -3854,6 +4383,7 @@ pub fn order_unstake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_verify_writable_privileges<'me, 'info>(
     accounts: OrderUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3869,6 +4399,7 @@ pub fn order_unstake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_verify_signer_privileges<'me, 'info>(
     accounts: OrderUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3879,6 +4410,7 @@ pub fn order_unstake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn order_unstake_verify_account_privileges<'me, 'info>(
     accounts: OrderUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3888,6 +4420,7 @@ pub fn order_unstake_verify_account_privileges<'me, 'info>(
 }
 pub const CLAIM_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ClaimAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub reserve_pda: &'me AccountInfo<'info>,
@@ -3905,6 +4438,7 @@ pub struct ClaimKeys {
     pub clock: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ClaimAccounts<'_, '_>> for ClaimKeys {
     fn from(accounts: ClaimAccounts) -> Self {
         Self {
@@ -3965,7 +4499,9 @@ impl From<[Pubkey; CLAIM_IX_ACCOUNTS_LEN]> for ClaimKeys {
         }
     }
 }
-impl<'info> From<ClaimAccounts<'_, 'info>> for [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<ClaimAccounts<'_, 'info>>
+for [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] {
     fn from(accounts: ClaimAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -3977,9 +4513,9 @@ impl<'info> From<ClaimAccounts<'_, 'info>> for [AccountInfo<'info>; CLAIM_IX_ACC
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN]>
-    for ClaimAccounts<'me, 'info>
-{
+for ClaimAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -3995,34 +4531,59 @@ pub const CLAIM_IX_DISCM: [u8; 8] = [62, 198, 214, 193, 213, 159, 108, 210];
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClaimIxData;
 impl ClaimIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CLAIM_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CLAIM_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CLAIM_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&CLAIM_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ClaimIxData {
+    const DISCRIMINATOR: &'static [u8] = &CLAIM_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ClaimIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ClaimIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ClaimIxData {
+    const NAME: &'static str = "claim";
+    const DISCM: &'static [u8] = &CLAIM_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CLAIM_IX_ACCOUNTS_LEN;
+    type Keys = ClaimKeys;
+    type Args = ();
+}
 pub fn claim_ix_with_program_id(
     program_id: Pubkey,
     keys: ClaimKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CLAIM_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -4030,9 +4591,10 @@ pub fn claim_ix_with_program_id(
         data: ClaimIxData.try_to_vec()?,
     })
 }
-pub fn claim_ix(keys: ClaimKeys) -> std::io::Result<Instruction> {
+pub fn claim_ix(keys: ClaimKeys) -> borsh::io::Result<Instruction> {
     claim_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAccounts<'_, '_>,
@@ -4041,9 +4603,11 @@ pub fn claim_invoke_with_program_id(
     let ix = claim_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_invoke(accounts: ClaimAccounts<'_, '_>) -> ProgramResult {
     claim_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAccounts<'_, '_>,
@@ -4053,9 +4617,14 @@ pub fn claim_invoke_signed_with_program_id(
     let ix = claim_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn claim_invoke_signed(accounts: ClaimAccounts<'_, '_>, seeds: &[&[&[u8]]]) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn claim_invoke_signed(
+    accounts: ClaimAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
     claim_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_verify_account_keys(
     accounts: ClaimAccounts<'_, '_>,
     keys: ClaimKeys,
@@ -4074,6 +4643,7 @@ pub fn claim_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn claim_verify_writable_privileges<'me, 'info>(
     accounts: ClaimAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4089,6 +4659,7 @@ pub fn claim_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn claim_verify_account_privileges<'me, 'info>(
     accounts: ClaimAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4097,6 +4668,7 @@ pub fn claim_verify_account_privileges<'me, 'info>(
 }
 pub const STAKE_RESERVE_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct StakeReserveAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub validator_list: &'me AccountInfo<'info>,
@@ -4130,6 +4702,7 @@ pub struct StakeReserveKeys {
     pub system_program: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<StakeReserveAccounts<'_, '_>> for StakeReserveKeys {
     fn from(accounts: StakeReserveAccounts) -> Self {
         Self {
@@ -4246,9 +4819,9 @@ impl From<[Pubkey; STAKE_RESERVE_IX_ACCOUNTS_LEN]> for StakeReserveKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<StakeReserveAccounts<'_, 'info>>
-    for [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] {
     fn from(accounts: StakeReserveAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -4268,9 +4841,9 @@ impl<'info> From<StakeReserveAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]>
-    for StakeReserveAccounts<'me, 'info>
-{
+for StakeReserveAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -4304,36 +4877,61 @@ impl From<StakeReserveIxArgs> for StakeReserveIxData {
     }
 }
 impl StakeReserveIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != STAKE_RESERVE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    STAKE_RESERVE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        STAKE_RESERVE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(StakeReserveIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&STAKE_RESERVE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for StakeReserveIxData {
+    const DISCRIMINATOR: &'static [u8] = &STAKE_RESERVE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for StakeReserveIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for StakeReserveIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for StakeReserveIxData {
+    const NAME: &'static str = "stakeReserve";
+    const DISCM: &'static [u8] = &STAKE_RESERVE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = STAKE_RESERVE_IX_ACCOUNTS_LEN;
+    type Keys = StakeReserveKeys;
+    type Args = StakeReserveIxArgs;
+}
 pub fn stake_reserve_ix_with_program_id(
     program_id: Pubkey,
     keys: StakeReserveKeys,
     args: StakeReserveIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; STAKE_RESERVE_IX_ACCOUNTS_LEN] = keys.into();
     let data: StakeReserveIxData = args.into();
     Ok(Instruction {
@@ -4345,9 +4943,10 @@ pub fn stake_reserve_ix_with_program_id(
 pub fn stake_reserve_ix(
     keys: StakeReserveKeys,
     args: StakeReserveIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     stake_reserve_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_invoke_with_program_id(
     program_id: Pubkey,
     accounts: StakeReserveAccounts<'_, '_>,
@@ -4357,12 +4956,14 @@ pub fn stake_reserve_invoke_with_program_id(
     let ix = stake_reserve_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_invoke(
     accounts: StakeReserveAccounts<'_, '_>,
     args: StakeReserveIxArgs,
 ) -> ProgramResult {
     stake_reserve_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: StakeReserveAccounts<'_, '_>,
@@ -4373,6 +4974,7 @@ pub fn stake_reserve_invoke_signed_with_program_id(
     let ix = stake_reserve_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_invoke_signed(
     accounts: StakeReserveAccounts<'_, '_>,
     args: StakeReserveIxArgs,
@@ -4380,6 +4982,7 @@ pub fn stake_reserve_invoke_signed(
 ) -> ProgramResult {
     stake_reserve_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_verify_account_keys(
     accounts: StakeReserveAccounts<'_, '_>,
     keys: StakeReserveKeys,
@@ -4391,10 +4994,7 @@ pub fn stake_reserve_verify_account_keys(
         (*accounts.validator_vote.key, keys.validator_vote),
         (*accounts.reserve_pda.key, keys.reserve_pda),
         (*accounts.stake_account.key, keys.stake_account),
-        (
-            *accounts.stake_deposit_authority.key,
-            keys.stake_deposit_authority,
-        ),
+        (*accounts.stake_deposit_authority.key, keys.stake_deposit_authority),
         (*accounts.clock.key, keys.clock),
         (*accounts.epoch_schedule.key, keys.epoch_schedule),
         (*accounts.rent.key, keys.rent),
@@ -4409,6 +5009,7 @@ pub fn stake_reserve_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_verify_writable_privileges<'me, 'info>(
     accounts: StakeReserveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4426,6 +5027,7 @@ pub fn stake_reserve_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn stake_reserve_verify_account_privileges<'me, 'info>(
     accounts: StakeReserveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4434,6 +5036,7 @@ pub fn stake_reserve_verify_account_privileges<'me, 'info>(
 }
 pub const UPDATE_ACTIVE_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpdateActiveAccounts<'me, 'info> {
     pub common_state: &'me AccountInfo<'info>,
     pub common_stake_list: &'me AccountInfo<'info>,
@@ -4465,13 +5068,16 @@ pub struct UpdateActiveKeys {
     pub common_token_program: Pubkey,
     pub validator_list: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpdateActiveAccounts<'_, '_>> for UpdateActiveKeys {
     fn from(accounts: UpdateActiveAccounts) -> Self {
         Self {
             common_state: *accounts.common_state.key,
             common_stake_list: *accounts.common_stake_list.key,
             common_stake_account: *accounts.common_stake_account.key,
-            common_stake_withdraw_authority: *accounts.common_stake_withdraw_authority.key,
+            common_stake_withdraw_authority: *accounts
+                .common_stake_withdraw_authority
+                .key,
             common_reserve_pda: *accounts.common_reserve_pda.key,
             common_msol_mint: *accounts.common_msol_mint.key,
             common_msol_mint_authority: *accounts.common_msol_mint_authority.key,
@@ -4574,9 +5180,9 @@ impl From<[Pubkey; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]> for UpdateActiveKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpdateActiveAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpdateActiveAccounts<'_, 'info>) -> Self {
         [
             accounts.common_state.clone(),
@@ -4595,9 +5201,9 @@ impl<'info> From<UpdateActiveAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]>
-    for UpdateActiveAccounts<'me, 'info>
-{
+for UpdateActiveAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             common_state: &arr[0],
@@ -4631,36 +5237,61 @@ impl From<UpdateActiveIxArgs> for UpdateActiveIxData {
     }
 }
 impl UpdateActiveIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_ACTIVE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_ACTIVE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_ACTIVE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(UpdateActiveIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&UPDATE_ACTIVE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UpdateActiveIxData {
+    const DISCRIMINATOR: &'static [u8] = &UPDATE_ACTIVE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UpdateActiveIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UpdateActiveIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateActiveIxData {
+    const NAME: &'static str = "updateActive";
+    const DISCM: &'static [u8] = &UPDATE_ACTIVE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UPDATE_ACTIVE_IX_ACCOUNTS_LEN;
+    type Keys = UpdateActiveKeys;
+    type Args = UpdateActiveIxArgs;
+}
 pub fn update_active_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdateActiveKeys,
     args: UpdateActiveIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = keys.into();
     let data: UpdateActiveIxData = args.into();
     Ok(Instruction {
@@ -4672,9 +5303,10 @@ pub fn update_active_ix_with_program_id(
 pub fn update_active_ix(
     keys: UpdateActiveKeys,
     args: UpdateActiveIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     update_active_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateActiveAccounts<'_, '_>,
@@ -4684,12 +5316,14 @@ pub fn update_active_invoke_with_program_id(
     let ix = update_active_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_invoke(
     accounts: UpdateActiveAccounts<'_, '_>,
     args: UpdateActiveIxArgs,
 ) -> ProgramResult {
     update_active_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpdateActiveAccounts<'_, '_>,
@@ -4700,6 +5334,7 @@ pub fn update_active_invoke_signed_with_program_id(
     let ix = update_active_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_invoke_signed(
     accounts: UpdateActiveAccounts<'_, '_>,
     args: UpdateActiveIxArgs,
@@ -4707,6 +5342,7 @@ pub fn update_active_invoke_signed(
 ) -> ProgramResult {
     update_active_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_verify_account_keys(
     accounts: UpdateActiveAccounts<'_, '_>,
     keys: UpdateActiveKeys,
@@ -4714,37 +5350,19 @@ pub fn update_active_verify_account_keys(
     for (actual, expected) in [
         (*accounts.common_state.key, keys.common_state),
         (*accounts.common_stake_list.key, keys.common_stake_list),
-        (
-            *accounts.common_stake_account.key,
-            keys.common_stake_account,
-        ),
+        (*accounts.common_stake_account.key, keys.common_stake_account),
         (
             *accounts.common_stake_withdraw_authority.key,
             keys.common_stake_withdraw_authority,
         ),
         (*accounts.common_reserve_pda.key, keys.common_reserve_pda),
         (*accounts.common_msol_mint.key, keys.common_msol_mint),
-        (
-            *accounts.common_msol_mint_authority.key,
-            keys.common_msol_mint_authority,
-        ),
-        (
-            *accounts.common_treasury_msol_account.key,
-            keys.common_treasury_msol_account,
-        ),
+        (*accounts.common_msol_mint_authority.key, keys.common_msol_mint_authority),
+        (*accounts.common_treasury_msol_account.key, keys.common_treasury_msol_account),
         (*accounts.common_clock.key, keys.common_clock),
-        (
-            *accounts.common_stake_history.key,
-            keys.common_stake_history,
-        ),
-        (
-            *accounts.common_stake_program.key,
-            keys.common_stake_program,
-        ),
-        (
-            *accounts.common_token_program.key,
-            keys.common_token_program,
-        ),
+        (*accounts.common_stake_history.key, keys.common_stake_history),
+        (*accounts.common_stake_program.key, keys.common_stake_program),
+        (*accounts.common_token_program.key, keys.common_token_program),
         (*accounts.validator_list.key, keys.validator_list),
     ] {
         if actual != expected {
@@ -4753,6 +5371,7 @@ pub fn update_active_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_verify_writable_privileges<'me, 'info>(
     accounts: UpdateActiveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4771,6 +5390,7 @@ pub fn update_active_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_active_verify_account_privileges<'me, 'info>(
     accounts: UpdateActiveAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4779,6 +5399,7 @@ pub fn update_active_verify_account_privileges<'me, 'info>(
 }
 pub const UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpdateDeactivatedAccounts<'me, 'info> {
     pub common_state: &'me AccountInfo<'info>,
     pub common_stake_list: &'me AccountInfo<'info>,
@@ -4812,13 +5433,16 @@ pub struct UpdateDeactivatedKeys {
     pub operational_sol_account: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpdateDeactivatedAccounts<'_, '_>> for UpdateDeactivatedKeys {
     fn from(accounts: UpdateDeactivatedAccounts) -> Self {
         Self {
             common_state: *accounts.common_state.key,
             common_stake_list: *accounts.common_stake_list.key,
             common_stake_account: *accounts.common_stake_account.key,
-            common_stake_withdraw_authority: *accounts.common_stake_withdraw_authority.key,
+            common_stake_withdraw_authority: *accounts
+                .common_stake_withdraw_authority
+                .key,
             common_reserve_pda: *accounts.common_reserve_pda.key,
             common_msol_mint: *accounts.common_msol_mint.key,
             common_msol_mint_authority: *accounts.common_msol_mint_authority.key,
@@ -4928,9 +5552,9 @@ impl From<[Pubkey; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]> for UpdateDeactivatedKey
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpdateDeactivatedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpdateDeactivatedAccounts<'_, 'info>) -> Self {
         [
             accounts.common_state.clone(),
@@ -4950,9 +5574,9 @@ impl<'info> From<UpdateDeactivatedAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]>
-    for UpdateDeactivatedAccounts<'me, 'info>
-{
+for UpdateDeactivatedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             common_state: &arr[0],
@@ -4986,36 +5610,61 @@ impl From<UpdateDeactivatedIxArgs> for UpdateDeactivatedIxData {
     }
 }
 impl UpdateDeactivatedIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UPDATE_DEACTIVATED_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_DEACTIVATED_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_DEACTIVATED_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(UpdateDeactivatedIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&UPDATE_DEACTIVATED_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UpdateDeactivatedIxData {
+    const DISCRIMINATOR: &'static [u8] = &UPDATE_DEACTIVATED_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UpdateDeactivatedIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UpdateDeactivatedIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateDeactivatedIxData {
+    const NAME: &'static str = "updateDeactivated";
+    const DISCM: &'static [u8] = &UPDATE_DEACTIVATED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN;
+    type Keys = UpdateDeactivatedKeys;
+    type Args = UpdateDeactivatedIxArgs;
+}
 pub fn update_deactivated_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdateDeactivatedKeys,
     args: UpdateDeactivatedIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = keys.into();
     let data: UpdateDeactivatedIxData = args.into();
     Ok(Instruction {
@@ -5027,9 +5676,10 @@ pub fn update_deactivated_ix_with_program_id(
 pub fn update_deactivated_ix(
     keys: UpdateDeactivatedKeys,
     args: UpdateDeactivatedIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     update_deactivated_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateDeactivatedAccounts<'_, '_>,
@@ -5039,12 +5689,14 @@ pub fn update_deactivated_invoke_with_program_id(
     let ix = update_deactivated_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_invoke(
     accounts: UpdateDeactivatedAccounts<'_, '_>,
     args: UpdateDeactivatedIxArgs,
 ) -> ProgramResult {
     update_deactivated_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpdateDeactivatedAccounts<'_, '_>,
@@ -5055,6 +5707,7 @@ pub fn update_deactivated_invoke_signed_with_program_id(
     let ix = update_deactivated_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_invoke_signed(
     accounts: UpdateDeactivatedAccounts<'_, '_>,
     args: UpdateDeactivatedIxArgs,
@@ -5062,6 +5715,7 @@ pub fn update_deactivated_invoke_signed(
 ) -> ProgramResult {
     update_deactivated_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_verify_account_keys(
     accounts: UpdateDeactivatedAccounts<'_, '_>,
     keys: UpdateDeactivatedKeys,
@@ -5069,41 +5723,20 @@ pub fn update_deactivated_verify_account_keys(
     for (actual, expected) in [
         (*accounts.common_state.key, keys.common_state),
         (*accounts.common_stake_list.key, keys.common_stake_list),
-        (
-            *accounts.common_stake_account.key,
-            keys.common_stake_account,
-        ),
+        (*accounts.common_stake_account.key, keys.common_stake_account),
         (
             *accounts.common_stake_withdraw_authority.key,
             keys.common_stake_withdraw_authority,
         ),
         (*accounts.common_reserve_pda.key, keys.common_reserve_pda),
         (*accounts.common_msol_mint.key, keys.common_msol_mint),
-        (
-            *accounts.common_msol_mint_authority.key,
-            keys.common_msol_mint_authority,
-        ),
-        (
-            *accounts.common_treasury_msol_account.key,
-            keys.common_treasury_msol_account,
-        ),
+        (*accounts.common_msol_mint_authority.key, keys.common_msol_mint_authority),
+        (*accounts.common_treasury_msol_account.key, keys.common_treasury_msol_account),
         (*accounts.common_clock.key, keys.common_clock),
-        (
-            *accounts.common_stake_history.key,
-            keys.common_stake_history,
-        ),
-        (
-            *accounts.common_stake_program.key,
-            keys.common_stake_program,
-        ),
-        (
-            *accounts.common_token_program.key,
-            keys.common_token_program,
-        ),
-        (
-            *accounts.operational_sol_account.key,
-            keys.operational_sol_account,
-        ),
+        (*accounts.common_stake_history.key, keys.common_stake_history),
+        (*accounts.common_stake_program.key, keys.common_stake_program),
+        (*accounts.common_token_program.key, keys.common_token_program),
+        (*accounts.operational_sol_account.key, keys.operational_sol_account),
         (*accounts.system_program.key, keys.system_program),
     ] {
         if actual != expected {
@@ -5112,6 +5745,7 @@ pub fn update_deactivated_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_verify_writable_privileges<'me, 'info>(
     accounts: UpdateDeactivatedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5130,6 +5764,7 @@ pub fn update_deactivated_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_deactivated_verify_account_privileges<'me, 'info>(
     accounts: UpdateDeactivatedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5138,6 +5773,7 @@ pub fn update_deactivated_verify_account_privileges<'me, 'info>(
 }
 pub const DEACTIVATE_STAKE_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeactivateStakeAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub reserve_pda: &'me AccountInfo<'info>,
@@ -5171,6 +5807,7 @@ pub struct DeactivateStakeKeys {
     pub system_program: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeactivateStakeAccounts<'_, '_>> for DeactivateStakeKeys {
     fn from(accounts: DeactivateStakeAccounts) -> Self {
         Self {
@@ -5287,9 +5924,9 @@ impl From<[Pubkey; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]> for DeactivateStakeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeactivateStakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeactivateStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -5309,9 +5946,9 @@ impl<'info> From<DeactivateStakeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]>
-    for DeactivateStakeAccounts<'me, 'info>
-{
+for DeactivateStakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -5346,36 +5983,61 @@ impl From<DeactivateStakeIxArgs> for DeactivateStakeIxData {
     }
 }
 impl DeactivateStakeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEACTIVATE_STAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEACTIVATE_STAKE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEACTIVATE_STAKE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(DeactivateStakeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&DEACTIVATE_STAKE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for DeactivateStakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &DEACTIVATE_STAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for DeactivateStakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for DeactivateStakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeactivateStakeIxData {
+    const NAME: &'static str = "deactivateStake";
+    const DISCM: &'static [u8] = &DEACTIVATE_STAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = DEACTIVATE_STAKE_IX_ACCOUNTS_LEN;
+    type Keys = DeactivateStakeKeys;
+    type Args = DeactivateStakeIxArgs;
+}
 pub fn deactivate_stake_ix_with_program_id(
     program_id: Pubkey,
     keys: DeactivateStakeKeys,
     args: DeactivateStakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = keys.into();
     let data: DeactivateStakeIxData = args.into();
     Ok(Instruction {
@@ -5387,9 +6049,10 @@ pub fn deactivate_stake_ix_with_program_id(
 pub fn deactivate_stake_ix(
     keys: DeactivateStakeKeys,
     args: DeactivateStakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deactivate_stake_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccounts<'_, '_>,
@@ -5399,12 +6062,14 @@ pub fn deactivate_stake_invoke_with_program_id(
     let ix = deactivate_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_invoke(
     accounts: DeactivateStakeAccounts<'_, '_>,
     args: DeactivateStakeIxArgs,
 ) -> ProgramResult {
     deactivate_stake_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccounts<'_, '_>,
@@ -5415,6 +6080,7 @@ pub fn deactivate_stake_invoke_signed_with_program_id(
     let ix = deactivate_stake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_invoke_signed(
     accounts: DeactivateStakeAccounts<'_, '_>,
     args: DeactivateStakeIxArgs,
@@ -5422,6 +6088,7 @@ pub fn deactivate_stake_invoke_signed(
 ) -> ProgramResult {
     deactivate_stake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_verify_account_keys(
     accounts: DeactivateStakeAccounts<'_, '_>,
     keys: DeactivateStakeKeys,
@@ -5432,15 +6099,9 @@ pub fn deactivate_stake_verify_account_keys(
         (*accounts.validator_list.key, keys.validator_list),
         (*accounts.stake_list.key, keys.stake_list),
         (*accounts.stake_account.key, keys.stake_account),
-        (
-            *accounts.stake_deposit_authority.key,
-            keys.stake_deposit_authority,
-        ),
+        (*accounts.stake_deposit_authority.key, keys.stake_deposit_authority),
         (*accounts.split_stake_account.key, keys.split_stake_account),
-        (
-            *accounts.split_stake_rent_payer.key,
-            keys.split_stake_rent_payer,
-        ),
+        (*accounts.split_stake_rent_payer.key, keys.split_stake_rent_payer),
         (*accounts.clock.key, keys.clock),
         (*accounts.rent.key, keys.rent),
         (*accounts.epoch_schedule.key, keys.epoch_schedule),
@@ -5454,6 +6115,7 @@ pub fn deactivate_stake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_verify_writable_privileges<'me, 'info>(
     accounts: DeactivateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5471,6 +6133,7 @@ pub fn deactivate_stake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_verify_signer_privileges<'me, 'info>(
     accounts: DeactivateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5484,6 +6147,7 @@ pub fn deactivate_stake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_verify_account_privileges<'me, 'info>(
     accounts: DeactivateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5493,6 +6157,7 @@ pub fn deactivate_stake_verify_account_privileges<'me, 'info>(
 }
 pub const EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct EmergencyUnstakeAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub validator_manager_authority: &'me AccountInfo<'info>,
@@ -5514,6 +6179,7 @@ pub struct EmergencyUnstakeKeys {
     pub clock: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<EmergencyUnstakeAccounts<'_, '_>> for EmergencyUnstakeKeys {
     fn from(accounts: EmergencyUnstakeAccounts) -> Self {
         Self {
@@ -5588,9 +6254,9 @@ impl From<[Pubkey; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]> for EmergencyUnstakeKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<EmergencyUnstakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: EmergencyUnstakeAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -5604,9 +6270,9 @@ impl<'info> From<EmergencyUnstakeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]>
-    for EmergencyUnstakeAccounts<'me, 'info>
-{
+for EmergencyUnstakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -5635,36 +6301,61 @@ impl From<EmergencyUnstakeIxArgs> for EmergencyUnstakeIxData {
     }
 }
 impl EmergencyUnstakeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != EMERGENCY_UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    EMERGENCY_UNSTAKE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        EMERGENCY_UNSTAKE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(EmergencyUnstakeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&EMERGENCY_UNSTAKE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for EmergencyUnstakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &EMERGENCY_UNSTAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for EmergencyUnstakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for EmergencyUnstakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for EmergencyUnstakeIxData {
+    const NAME: &'static str = "emergencyUnstake";
+    const DISCM: &'static [u8] = &EMERGENCY_UNSTAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN;
+    type Keys = EmergencyUnstakeKeys;
+    type Args = EmergencyUnstakeIxArgs;
+}
 pub fn emergency_unstake_ix_with_program_id(
     program_id: Pubkey,
     keys: EmergencyUnstakeKeys,
     args: EmergencyUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let data: EmergencyUnstakeIxData = args.into();
     Ok(Instruction {
@@ -5676,9 +6367,10 @@ pub fn emergency_unstake_ix_with_program_id(
 pub fn emergency_unstake_ix(
     keys: EmergencyUnstakeKeys,
     args: EmergencyUnstakeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     emergency_unstake_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: EmergencyUnstakeAccounts<'_, '_>,
@@ -5688,12 +6380,14 @@ pub fn emergency_unstake_invoke_with_program_id(
     let ix = emergency_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_invoke(
     accounts: EmergencyUnstakeAccounts<'_, '_>,
     args: EmergencyUnstakeIxArgs,
 ) -> ProgramResult {
     emergency_unstake_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: EmergencyUnstakeAccounts<'_, '_>,
@@ -5704,6 +6398,7 @@ pub fn emergency_unstake_invoke_signed_with_program_id(
     let ix = emergency_unstake_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_invoke_signed(
     accounts: EmergencyUnstakeAccounts<'_, '_>,
     args: EmergencyUnstakeIxArgs,
@@ -5711,23 +6406,18 @@ pub fn emergency_unstake_invoke_signed(
 ) -> ProgramResult {
     emergency_unstake_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_verify_account_keys(
     accounts: EmergencyUnstakeAccounts<'_, '_>,
     keys: EmergencyUnstakeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.state.key, keys.state),
-        (
-            *accounts.validator_manager_authority.key,
-            keys.validator_manager_authority,
-        ),
+        (*accounts.validator_manager_authority.key, keys.validator_manager_authority),
         (*accounts.validator_list.key, keys.validator_list),
         (*accounts.stake_list.key, keys.stake_list),
         (*accounts.stake_account.key, keys.stake_account),
-        (
-            *accounts.stake_deposit_authority.key,
-            keys.stake_deposit_authority,
-        ),
+        (*accounts.stake_deposit_authority.key, keys.stake_deposit_authority),
         (*accounts.clock.key, keys.clock),
         (*accounts.stake_program.key, keys.stake_program),
     ] {
@@ -5737,6 +6427,7 @@ pub fn emergency_unstake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_verify_writable_privileges<'me, 'info>(
     accounts: EmergencyUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5752,6 +6443,7 @@ pub fn emergency_unstake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_verify_signer_privileges<'me, 'info>(
     accounts: EmergencyUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5762,6 +6454,7 @@ pub fn emergency_unstake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn emergency_unstake_verify_account_privileges<'me, 'info>(
     accounts: EmergencyUnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5771,6 +6464,7 @@ pub fn emergency_unstake_verify_account_privileges<'me, 'info>(
 }
 pub const MERGE_STAKES_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct MergeStakesAccounts<'me, 'info> {
     pub state: &'me AccountInfo<'info>,
     pub stake_list: &'me AccountInfo<'info>,
@@ -5798,6 +6492,7 @@ pub struct MergeStakesKeys {
     pub stake_history: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<MergeStakesAccounts<'_, '_>> for MergeStakesKeys {
     fn from(accounts: MergeStakesAccounts) -> Self {
         Self {
@@ -5893,9 +6588,9 @@ impl From<[Pubkey; MERGE_STAKES_IX_ACCOUNTS_LEN]> for MergeStakesKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<MergeStakesAccounts<'_, 'info>>
-    for [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] {
     fn from(accounts: MergeStakesAccounts<'_, 'info>) -> Self {
         [
             accounts.state.clone(),
@@ -5912,9 +6607,9 @@ impl<'info> From<MergeStakesAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]>
-    for MergeStakesAccounts<'me, 'info>
-{
+for MergeStakesAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             state: &arr[0],
@@ -5947,36 +6642,61 @@ impl From<MergeStakesIxArgs> for MergeStakesIxData {
     }
 }
 impl MergeStakesIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != MERGE_STAKES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MERGE_STAKES_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MERGE_STAKES_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(MergeStakesIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&MERGE_STAKES_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for MergeStakesIxData {
+    const DISCRIMINATOR: &'static [u8] = &MERGE_STAKES_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for MergeStakesIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for MergeStakesIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for MergeStakesIxData {
+    const NAME: &'static str = "mergeStakes";
+    const DISCM: &'static [u8] = &MERGE_STAKES_IX_DISCM;
+    const ACCOUNTS_LEN: usize = MERGE_STAKES_IX_ACCOUNTS_LEN;
+    type Keys = MergeStakesKeys;
+    type Args = MergeStakesIxArgs;
+}
 pub fn merge_stakes_ix_with_program_id(
     program_id: Pubkey,
     keys: MergeStakesKeys,
     args: MergeStakesIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; MERGE_STAKES_IX_ACCOUNTS_LEN] = keys.into();
     let data: MergeStakesIxData = args.into();
     Ok(Instruction {
@@ -5988,9 +6708,10 @@ pub fn merge_stakes_ix_with_program_id(
 pub fn merge_stakes_ix(
     keys: MergeStakesKeys,
     args: MergeStakesIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     merge_stakes_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MergeStakesAccounts<'_, '_>,
@@ -6000,12 +6721,14 @@ pub fn merge_stakes_invoke_with_program_id(
     let ix = merge_stakes_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_invoke(
     accounts: MergeStakesAccounts<'_, '_>,
     args: MergeStakesIxArgs,
 ) -> ProgramResult {
     merge_stakes_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: MergeStakesAccounts<'_, '_>,
@@ -6016,6 +6739,7 @@ pub fn merge_stakes_invoke_signed_with_program_id(
     let ix = merge_stakes_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_invoke_signed(
     accounts: MergeStakesAccounts<'_, '_>,
     args: MergeStakesIxArgs,
@@ -6023,6 +6747,7 @@ pub fn merge_stakes_invoke_signed(
 ) -> ProgramResult {
     merge_stakes_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_verify_account_keys(
     accounts: MergeStakesAccounts<'_, '_>,
     keys: MergeStakesKeys,
@@ -6033,18 +6758,9 @@ pub fn merge_stakes_verify_account_keys(
         (*accounts.validator_list.key, keys.validator_list),
         (*accounts.destination_stake.key, keys.destination_stake),
         (*accounts.source_stake.key, keys.source_stake),
-        (
-            *accounts.stake_deposit_authority.key,
-            keys.stake_deposit_authority,
-        ),
-        (
-            *accounts.stake_withdraw_authority.key,
-            keys.stake_withdraw_authority,
-        ),
-        (
-            *accounts.operational_sol_account.key,
-            keys.operational_sol_account,
-        ),
+        (*accounts.stake_deposit_authority.key, keys.stake_deposit_authority),
+        (*accounts.stake_withdraw_authority.key, keys.stake_withdraw_authority),
+        (*accounts.operational_sol_account.key, keys.operational_sol_account),
         (*accounts.clock.key, keys.clock),
         (*accounts.stake_history.key, keys.stake_history),
         (*accounts.stake_program.key, keys.stake_program),
@@ -6055,6 +6771,7 @@ pub fn merge_stakes_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_verify_writable_privileges<'me, 'info>(
     accounts: MergeStakesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6072,6 +6789,7 @@ pub fn merge_stakes_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn merge_stakes_verify_account_privileges<'me, 'info>(
     accounts: MergeStakesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1,7 +1,5 @@
 use solana_program::{
-    decode_error::DecodeError,
-    msg,
-    program_error::{PrintProgramError, ProgramError},
+    decode_error::DecodeError, msg, program_error::{PrintProgramError, ProgramError},
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
@@ -46,6 +44,15 @@ impl From<UnstakeError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramErrorCode for UnstakeError {
+    fn code(&self) -> u32 {
+        *self as u32
+    }
+    fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
 impl<T> DecodeError<T> for UnstakeError {
     fn type_of() -> &'static str {
         "UnstakeError"
@@ -54,12 +61,9 @@ impl<T> DecodeError<T> for UnstakeError {
 impl PrintProgramError for UnstakeError {
     fn print<E>(&self)
     where
-        E: 'static
-            + std::error::Error
-            + DecodeError<E>
-            + PrintProgramError
+        E: 'static + std::error::Error + DecodeError<E> + PrintProgramError
             + num_traits::FromPrimitive,
     {
-        msg!(&self.to_string());
+        msg!(& self.to_string());
     }
 }
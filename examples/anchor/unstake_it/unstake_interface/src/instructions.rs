@@ -1,14 +1,16 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
-use std::io::Read;
+use borsh::io::Read;
+use crate::*;
 #[derive(Clone, Debug, PartialEq)]
 pub enum UnstakeProgramIx {
     InitProtocolFee,
@@ -24,37 +26,48 @@ pub enum UnstakeProgramIx {
     UnstakeWsol,
 }
 impl UnstakeProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         match maybe_discm {
             INIT_PROTOCOL_FEE_IX_DISCM => Ok(Self::InitProtocolFee),
-            SET_PROTOCOL_FEE_IX_DISCM => Ok(Self::SetProtocolFee(
-                SetProtocolFeeIxArgs::deserialize(&mut reader)?,
-            )),
-            CREATE_POOL_IX_DISCM => Ok(Self::CreatePool(CreatePoolIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            ADD_LIQUIDITY_IX_DISCM => Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            REMOVE_LIQUIDITY_IX_DISCM => Ok(Self::RemoveLiquidity(
-                RemoveLiquidityIxArgs::deserialize(&mut reader)?,
-            )),
+            SET_PROTOCOL_FEE_IX_DISCM => {
+                Ok(Self::SetProtocolFee(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
+            }
+            CREATE_POOL_IX_DISCM => {
+                Ok(Self::CreatePool(CreatePoolIxArgs::deserialize(&mut reader)?))
+            }
+            ADD_LIQUIDITY_IX_DISCM => {
+                Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(&mut reader)?))
+            }
+            REMOVE_LIQUIDITY_IX_DISCM => {
+                Ok(
+                    Self::RemoveLiquidity(
+                        RemoveLiquidityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             SET_FEE_IX_DISCM => Ok(Self::SetFee(SetFeeIxArgs::deserialize(&mut reader)?)),
             SET_FEE_AUTHORITY_IX_DISCM => Ok(Self::SetFeeAuthority),
             DEACTIVATE_STAKE_ACCOUNT_IX_DISCM => Ok(Self::DeactivateStakeAccount),
             RECLAIM_STAKE_ACCOUNT_IX_DISCM => Ok(Self::ReclaimStakeAccount),
             UNSTAKE_IX_DISCM => Ok(Self::Unstake),
             UNSTAKE_WSOL_IX_DISCM => Ok(Self::UnstakeWsol),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::InitProtocolFee => writer.write_all(&INIT_PROTOCOL_FEE_IX_DISCM),
             Self::SetProtocolFee(args) => {
@@ -78,18 +91,23 @@ impl UnstakeProgramIx {
                 args.serialize(&mut writer)
             }
             Self::SetFeeAuthority => writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM),
-            Self::DeactivateStakeAccount => writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM),
-            Self::ReclaimStakeAccount => writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM),
+            Self::DeactivateStakeAccount => {
+                writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)
+            }
+            Self::ReclaimStakeAccount => {
+                writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)
+            }
             Self::Unstake => writer.write_all(&UNSTAKE_IX_DISCM),
             Self::UnstakeWsol => writer.write_all(&UNSTAKE_WSOL_IX_DISCM),
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -97,6 +115,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -107,6 +126,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitProtocolFeeAccounts<'me, 'info> {
     pub payer: &'me AccountInfo<'info>,
     pub protocol_fee_account: &'me AccountInfo<'info>,
@@ -118,6 +138,7 @@ pub struct InitProtocolFeeKeys {
     pub protocol_fee_account: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitProtocolFeeAccounts<'_, '_>> for InitProtocolFeeKeys {
     fn from(accounts: InitProtocolFeeAccounts) -> Self {
         Self {
@@ -157,9 +178,9 @@ impl From<[Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for InitProtocolFeeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitProtocolFeeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitProtocolFeeAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -168,9 +189,9 @@ impl<'info> From<InitProtocolFeeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
-    for InitProtocolFeeAccounts<'me, 'info>
-{
+for InitProtocolFeeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -183,34 +204,59 @@ pub const INIT_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [225, 155, 167, 170, 29, 145, 16
 #[derive(Clone, Debug, PartialEq)]
 pub struct InitProtocolFeeIxData;
 impl InitProtocolFeeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != INIT_PROTOCOL_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INIT_PROTOCOL_FEE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INIT_PROTOCOL_FEE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&INIT_PROTOCOL_FEE_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for InitProtocolFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &INIT_PROTOCOL_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for InitProtocolFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for InitProtocolFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitProtocolFeeIxData {
+    const NAME: &'static str = "initProtocolFee";
+    const DISCM: &'static [u8] = &INIT_PROTOCOL_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    type Keys = InitProtocolFeeKeys;
+    type Args = ();
+}
 pub fn init_protocol_fee_ix_with_program_id(
     program_id: Pubkey,
     keys: InitProtocolFeeKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -218,9 +264,12 @@ pub fn init_protocol_fee_ix_with_program_id(
         data: InitProtocolFeeIxData.try_to_vec()?,
     })
 }
-pub fn init_protocol_fee_ix(keys: InitProtocolFeeKeys) -> std::io::Result<Instruction> {
+pub fn init_protocol_fee_ix(
+    keys: InitProtocolFeeKeys,
+) -> borsh::io::Result<Instruction> {
     init_protocol_fee_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitProtocolFeeAccounts<'_, '_>,
@@ -229,9 +278,13 @@ pub fn init_protocol_fee_invoke_with_program_id(
     let ix = init_protocol_fee_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn init_protocol_fee_invoke(accounts: InitProtocolFeeAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_invoke(
+    accounts: InitProtocolFeeAccounts<'_, '_>,
+) -> ProgramResult {
     init_protocol_fee_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitProtocolFeeAccounts<'_, '_>,
@@ -241,22 +294,21 @@ pub fn init_protocol_fee_invoke_signed_with_program_id(
     let ix = init_protocol_fee_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_invoke_signed(
     accounts: InitProtocolFeeAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     init_protocol_fee_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_verify_account_keys(
     accounts: InitProtocolFeeAccounts<'_, '_>,
     keys: InitProtocolFeeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.payer.key, keys.payer),
-        (
-            *accounts.protocol_fee_account.key,
-            keys.protocol_fee_account,
-        ),
+        (*accounts.protocol_fee_account.key, keys.protocol_fee_account),
         (*accounts.system_program.key, keys.system_program),
     ] {
         if actual != expected {
@@ -265,6 +317,7 @@ pub fn init_protocol_fee_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_verify_writable_privileges<'me, 'info>(
     accounts: InitProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -275,6 +328,7 @@ pub fn init_protocol_fee_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_verify_signer_privileges<'me, 'info>(
     accounts: InitProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -285,6 +339,7 @@ pub fn init_protocol_fee_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn init_protocol_fee_verify_account_privileges<'me, 'info>(
     accounts: InitProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -294,6 +349,7 @@ pub fn init_protocol_fee_verify_account_privileges<'me, 'info>(
 }
 pub const SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetProtocolFeeAccounts<'me, 'info> {
     pub authority: &'me AccountInfo<'info>,
     pub protocol_fee_account: &'me AccountInfo<'info>,
@@ -303,6 +359,7 @@ pub struct SetProtocolFeeKeys {
     pub authority: Pubkey,
     pub protocol_fee_account: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetProtocolFeeAccounts<'_, '_>> for SetProtocolFeeKeys {
     fn from(accounts: SetProtocolFeeAccounts) -> Self {
         Self {
@@ -335,19 +392,16 @@ impl From<[Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for SetProtocolFeeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetProtocolFeeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetProtocolFeeAccounts<'_, 'info>) -> Self {
-        [
-            accounts.authority.clone(),
-            accounts.protocol_fee_account.clone(),
-        ]
+        [accounts.authority.clone(), accounts.protocol_fee_account.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
-    for SetProtocolFeeAccounts<'me, 'info>
-{
+for SetProtocolFeeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             authority: &arr[0],
@@ -369,36 +423,61 @@ impl From<SetProtocolFeeIxArgs> for SetProtocolFeeIxData {
     }
 }
 impl SetProtocolFeeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_PROTOCOL_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_PROTOCOL_FEE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_PROTOCOL_FEE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SET_PROTOCOL_FEE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetProtocolFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_PROTOCOL_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetProtocolFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetProtocolFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetProtocolFeeIxData {
+    const NAME: &'static str = "setProtocolFee";
+    const DISCM: &'static [u8] = &SET_PROTOCOL_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    type Keys = SetProtocolFeeKeys;
+    type Args = SetProtocolFeeIxArgs;
+}
 pub fn set_protocol_fee_ix_with_program_id(
     program_id: Pubkey,
     keys: SetProtocolFeeKeys,
     args: SetProtocolFeeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
     let data: SetProtocolFeeIxData = args.into();
     Ok(Instruction {
@@ -410,9 +489,10 @@ pub fn set_protocol_fee_ix_with_program_id(
 pub fn set_protocol_fee_ix(
     keys: SetProtocolFeeKeys,
     args: SetProtocolFeeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     set_protocol_fee_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetProtocolFeeAccounts<'_, '_>,
@@ -422,12 +502,14 @@ pub fn set_protocol_fee_invoke_with_program_id(
     let ix = set_protocol_fee_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_invoke(
     accounts: SetProtocolFeeAccounts<'_, '_>,
     args: SetProtocolFeeIxArgs,
 ) -> ProgramResult {
     set_protocol_fee_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetProtocolFeeAccounts<'_, '_>,
@@ -438,6 +520,7 @@ pub fn set_protocol_fee_invoke_signed_with_program_id(
     let ix = set_protocol_fee_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_invoke_signed(
     accounts: SetProtocolFeeAccounts<'_, '_>,
     args: SetProtocolFeeIxArgs,
@@ -445,16 +528,14 @@ pub fn set_protocol_fee_invoke_signed(
 ) -> ProgramResult {
     set_protocol_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_verify_account_keys(
     accounts: SetProtocolFeeAccounts<'_, '_>,
     keys: SetProtocolFeeKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (*accounts.authority.key, keys.authority),
-        (
-            *accounts.protocol_fee_account.key,
-            keys.protocol_fee_account,
-        ),
+        (*accounts.protocol_fee_account.key, keys.protocol_fee_account),
     ] {
         if actual != expected {
             return Err((actual, expected));
@@ -462,6 +543,7 @@ pub fn set_protocol_fee_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_verify_writable_privileges<'me, 'info>(
     accounts: SetProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -472,6 +554,7 @@ pub fn set_protocol_fee_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_verify_signer_privileges<'me, 'info>(
     accounts: SetProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -482,6 +565,7 @@ pub fn set_protocol_fee_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_protocol_fee_verify_account_privileges<'me, 'info>(
     accounts: SetProtocolFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -491,6 +575,7 @@ pub fn set_protocol_fee_verify_account_privileges<'me, 'info>(
 }
 pub const CREATE_POOL_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreatePoolAccounts<'me, 'info> {
     pub payer: &'me AccountInfo<'info>,
     pub fee_authority: &'me AccountInfo<'info>,
@@ -514,6 +599,7 @@ pub struct CreatePoolKeys {
     pub system_program: Pubkey,
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreatePoolAccounts<'_, '_>> for CreatePoolKeys {
     fn from(accounts: CreatePoolAccounts) -> Self {
         Self {
@@ -595,9 +681,9 @@ impl From<[Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN]> for CreatePoolKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreatePoolAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreatePoolAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -612,9 +698,9 @@ impl<'info> From<CreatePoolAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]>
-    for CreatePoolAccounts<'me, 'info>
-{
+for CreatePoolAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CREATE_POOL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -643,36 +729,61 @@ impl From<CreatePoolIxArgs> for CreatePoolIxData {
     }
 }
 impl CreatePoolIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != CREATE_POOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_POOL_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_POOL_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(CreatePoolIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&CREATE_POOL_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for CreatePoolIxData {
+    const DISCRIMINATOR: &'static [u8] = &CREATE_POOL_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for CreatePoolIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for CreatePoolIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreatePoolIxData {
+    const NAME: &'static str = "createPool";
+    const DISCM: &'static [u8] = &CREATE_POOL_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_POOL_IX_ACCOUNTS_LEN;
+    type Keys = CreatePoolKeys;
+    type Args = CreatePoolIxArgs;
+}
 pub fn create_pool_ix_with_program_id(
     program_id: Pubkey,
     keys: CreatePoolKeys,
     args: CreatePoolIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] = keys.into();
     let data: CreatePoolIxData = args.into();
     Ok(Instruction {
@@ -684,9 +795,10 @@ pub fn create_pool_ix_with_program_id(
 pub fn create_pool_ix(
     keys: CreatePoolKeys,
     args: CreatePoolIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     create_pool_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreatePoolAccounts<'_, '_>,
@@ -696,12 +808,14 @@ pub fn create_pool_invoke_with_program_id(
     let ix = create_pool_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_invoke(
     accounts: CreatePoolAccounts<'_, '_>,
     args: CreatePoolIxArgs,
 ) -> ProgramResult {
     create_pool_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreatePoolAccounts<'_, '_>,
@@ -712,6 +826,7 @@ pub fn create_pool_invoke_signed_with_program_id(
     let ix = create_pool_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_invoke_signed(
     accounts: CreatePoolAccounts<'_, '_>,
     args: CreatePoolIxArgs,
@@ -719,6 +834,7 @@ pub fn create_pool_invoke_signed(
 ) -> ProgramResult {
     create_pool_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_verify_account_keys(
     accounts: CreatePoolAccounts<'_, '_>,
     keys: CreatePoolKeys,
@@ -740,6 +856,7 @@ pub fn create_pool_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_verify_writable_privileges<'me, 'info>(
     accounts: CreatePoolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -755,6 +872,7 @@ pub fn create_pool_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_verify_signer_privileges<'me, 'info>(
     accounts: CreatePoolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -770,6 +888,7 @@ pub fn create_pool_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_pool_verify_account_privileges<'me, 'info>(
     accounts: CreatePoolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -779,6 +898,7 @@ pub fn create_pool_verify_account_privileges<'me, 'info>(
 }
 pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AddLiquidityAccounts<'me, 'info> {
     pub from: &'me AccountInfo<'info>,
     pub pool_account: &'me AccountInfo<'info>,
@@ -798,6 +918,7 @@ pub struct AddLiquidityKeys {
     pub token_program: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AddLiquidityAccounts<'_, '_>> for AddLiquidityKeys {
     fn from(accounts: AddLiquidityAccounts) -> Self {
         Self {
@@ -865,9 +986,9 @@ impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AddLiquidityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: AddLiquidityAccounts<'_, 'info>) -> Self {
         [
             accounts.from.clone(),
@@ -880,9 +1001,9 @@ impl<'info> From<AddLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
-    for AddLiquidityAccounts<'me, 'info>
-{
+for AddLiquidityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             from: &arr[0],
@@ -909,36 +1030,61 @@ impl From<AddLiquidityIxArgs> for AddLiquidityIxData {
     }
 }
 impl AddLiquidityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != ADD_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    ADD_LIQUIDITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ADD_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(AddLiquidityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for AddLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for AddLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for AddLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AddLiquidityIxData {
+    const NAME: &'static str = "addLiquidity";
+    const DISCM: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ADD_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = AddLiquidityKeys;
+    type Args = AddLiquidityIxArgs;
+}
 pub fn add_liquidity_ix_with_program_id(
     program_id: Pubkey,
     keys: AddLiquidityKeys,
     args: AddLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: AddLiquidityIxData = args.into();
     Ok(Instruction {
@@ -950,9 +1096,10 @@ pub fn add_liquidity_ix_with_program_id(
 pub fn add_liquidity_ix(
     keys: AddLiquidityKeys,
     args: AddLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     add_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -962,12 +1109,14 @@ pub fn add_liquidity_invoke_with_program_id(
     let ix = add_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke(
     accounts: AddLiquidityAccounts<'_, '_>,
     args: AddLiquidityIxArgs,
 ) -> ProgramResult {
     add_liquidity_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AddLiquidityAccounts<'_, '_>,
@@ -978,6 +1127,7 @@ pub fn add_liquidity_invoke_signed_with_program_id(
     let ix = add_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_invoke_signed(
     accounts: AddLiquidityAccounts<'_, '_>,
     args: AddLiquidityIxArgs,
@@ -985,6 +1135,7 @@ pub fn add_liquidity_invoke_signed(
 ) -> ProgramResult {
     add_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_account_keys(
     accounts: AddLiquidityAccounts<'_, '_>,
     keys: AddLiquidityKeys,
@@ -1004,6 +1155,7 @@ pub fn add_liquidity_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_writable_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1020,6 +1172,7 @@ pub fn add_liquidity_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_signer_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1030,6 +1183,7 @@ pub fn add_liquidity_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn add_liquidity_verify_account_privileges<'me, 'info>(
     accounts: AddLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1039,6 +1193,7 @@ pub fn add_liquidity_verify_account_privileges<'me, 'info>(
 }
 pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RemoveLiquidityAccounts<'me, 'info> {
     pub burn_lp_tokens_from_authority: &'me AccountInfo<'info>,
     pub to: &'me AccountInfo<'info>,
@@ -1060,6 +1215,7 @@ pub struct RemoveLiquidityKeys {
     pub token_program: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RemoveLiquidityAccounts<'_, '_>> for RemoveLiquidityKeys {
     fn from(accounts: RemoveLiquidityAccounts) -> Self {
         Self {
@@ -1134,9 +1290,9 @@ impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: RemoveLiquidityAccounts<'_, 'info>) -> Self {
         [
             accounts.burn_lp_tokens_from_authority.clone(),
@@ -1150,9 +1306,9 @@ impl<'info> From<RemoveLiquidityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
-    for RemoveLiquidityAccounts<'me, 'info>
-{
+for RemoveLiquidityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             burn_lp_tokens_from_authority: &arr[0],
@@ -1180,36 +1336,61 @@ impl From<RemoveLiquidityIxArgs> for RemoveLiquidityIxData {
     }
 }
 impl RemoveLiquidityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != REMOVE_LIQUIDITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(RemoveLiquidityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for RemoveLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for RemoveLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for RemoveLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveLiquidityIxData {
+    const NAME: &'static str = "removeLiquidity";
+    const DISCM: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = RemoveLiquidityKeys;
+    type Args = RemoveLiquidityIxArgs;
+}
 pub fn remove_liquidity_ix_with_program_id(
     program_id: Pubkey,
     keys: RemoveLiquidityKeys,
     args: RemoveLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: RemoveLiquidityIxData = args.into();
     Ok(Instruction {
@@ -1221,9 +1402,10 @@ pub fn remove_liquidity_ix_with_program_id(
 pub fn remove_liquidity_ix(
     keys: RemoveLiquidityKeys,
     args: RemoveLiquidityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     remove_liquidity_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -1233,12 +1415,14 @@ pub fn remove_liquidity_invoke_with_program_id(
     let ix = remove_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     args: RemoveLiquidityIxArgs,
 ) -> ProgramResult {
     remove_liquidity_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RemoveLiquidityAccounts<'_, '_>,
@@ -1249,6 +1433,7 @@ pub fn remove_liquidity_invoke_signed_with_program_id(
     let ix = remove_liquidity_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_invoke_signed(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     args: RemoveLiquidityIxArgs,
@@ -1256,6 +1441,7 @@ pub fn remove_liquidity_invoke_signed(
 ) -> ProgramResult {
     remove_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_account_keys(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     keys: RemoveLiquidityKeys,
@@ -1279,6 +1465,7 @@ pub fn remove_liquidity_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_writable_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1295,6 +1482,7 @@ pub fn remove_liquidity_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_signer_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1305,6 +1493,7 @@ pub fn remove_liquidity_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_liquidity_verify_account_privileges<'me, 'info>(
     accounts: RemoveLiquidityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1314,6 +1503,7 @@ pub fn remove_liquidity_verify_account_privileges<'me, 'info>(
 }
 pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetFeeAccounts<'me, 'info> {
     pub fee_authority: &'me AccountInfo<'info>,
     pub pool_account: &'me AccountInfo<'info>,
@@ -1329,6 +1519,7 @@ pub struct SetFeeKeys {
     pub system_program: Pubkey,
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetFeeAccounts<'_, '_>> for SetFeeKeys {
     fn from(accounts: SetFeeAccounts) -> Self {
         Self {
@@ -1382,7 +1573,9 @@ impl From<[Pubkey; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeKeys {
         }
     }
 }
-impl<'info> From<SetFeeAccounts<'_, 'info>> for [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<SetFeeAccounts<'_, 'info>>
+for [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetFeeAccounts<'_, 'info>) -> Self {
         [
             accounts.fee_authority.clone(),
@@ -1393,9 +1586,9 @@ impl<'info> From<SetFeeAccounts<'_, 'info>> for [AccountInfo<'info>; SET_FEE_IX_
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]>
-    for SetFeeAccounts<'me, 'info>
-{
+for SetFeeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             fee_authority: &arr[0],
@@ -1420,36 +1613,61 @@ impl From<SetFeeIxArgs> for SetFeeIxData {
     }
 }
 impl SetFeeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_FEE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FEE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_FEE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SetFeeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SET_FEE_IX_DISCM)?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetFeeIxData {
+    const NAME: &'static str = "setFee";
+    const DISCM: &'static [u8] = &SET_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_FEE_IX_ACCOUNTS_LEN;
+    type Keys = SetFeeKeys;
+    type Args = SetFeeIxArgs;
+}
 pub fn set_fee_ix_with_program_id(
     program_id: Pubkey,
     keys: SetFeeKeys,
     args: SetFeeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = keys.into();
     let data: SetFeeIxData = args.into();
     Ok(Instruction {
@@ -1458,9 +1676,13 @@ pub fn set_fee_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn set_fee_ix(keys: SetFeeKeys, args: SetFeeIxArgs) -> std::io::Result<Instruction> {
+pub fn set_fee_ix(
+    keys: SetFeeKeys,
+    args: SetFeeIxArgs,
+) -> borsh::io::Result<Instruction> {
     set_fee_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAccounts<'_, '_>,
@@ -1470,9 +1692,14 @@ pub fn set_fee_invoke_with_program_id(
     let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_fee_invoke(accounts: SetFeeAccounts<'_, '_>, args: SetFeeIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn set_fee_invoke(
+    accounts: SetFeeAccounts<'_, '_>,
+    args: SetFeeIxArgs,
+) -> ProgramResult {
     set_fee_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAccounts<'_, '_>,
@@ -1483,6 +1710,7 @@ pub fn set_fee_invoke_signed_with_program_id(
     let ix = set_fee_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_invoke_signed(
     accounts: SetFeeAccounts<'_, '_>,
     args: SetFeeIxArgs,
@@ -1490,6 +1718,7 @@ pub fn set_fee_invoke_signed(
 ) -> ProgramResult {
     set_fee_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_verify_account_keys(
     accounts: SetFeeAccounts<'_, '_>,
     keys: SetFeeKeys,
@@ -1507,6 +1736,7 @@ pub fn set_fee_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_verify_writable_privileges<'me, 'info>(
     accounts: SetFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1517,6 +1747,7 @@ pub fn set_fee_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_verify_signer_privileges<'me, 'info>(
     accounts: SetFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1527,6 +1758,7 @@ pub fn set_fee_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_verify_account_privileges<'me, 'info>(
     accounts: SetFeeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1536,6 +1768,7 @@ pub fn set_fee_verify_account_privileges<'me, 'info>(
 }
 pub const SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetFeeAuthorityAccounts<'me, 'info> {
     pub fee_authority: &'me AccountInfo<'info>,
     pub pool_account: &'me AccountInfo<'info>,
@@ -1547,6 +1780,7 @@ pub struct SetFeeAuthorityKeys {
     pub pool_account: Pubkey,
     pub new_fee_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetFeeAuthorityAccounts<'_, '_>> for SetFeeAuthorityKeys {
     fn from(accounts: SetFeeAuthorityAccounts) -> Self {
         Self {
@@ -1586,9 +1820,9 @@ impl From<[Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]> for SetFeeAuthorityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetFeeAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetFeeAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.fee_authority.clone(),
@@ -1597,9 +1831,9 @@ impl<'info> From<SetFeeAuthorityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for SetFeeAuthorityAccounts<'me, 'info>
-{
+for SetFeeAuthorityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             fee_authority: &arr[0],
@@ -1612,34 +1846,59 @@ pub const SET_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [31, 1, 50, 87, 237, 101, 97, 13
 #[derive(Clone, Debug, PartialEq)]
 pub struct SetFeeAuthorityIxData;
 impl SetFeeAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != SET_FEE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_FEE_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_FEE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetFeeAuthorityIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_FEE_AUTHORITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetFeeAuthorityIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetFeeAuthorityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetFeeAuthorityIxData {
+    const NAME: &'static str = "setFeeAuthority";
+    const DISCM: &'static [u8] = &SET_FEE_AUTHORITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = SetFeeAuthorityKeys;
+    type Args = ();
+}
 pub fn set_fee_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: SetFeeAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -1647,9 +1906,12 @@ pub fn set_fee_authority_ix_with_program_id(
         data: SetFeeAuthorityIxData.try_to_vec()?,
     })
 }
-pub fn set_fee_authority_ix(keys: SetFeeAuthorityKeys) -> std::io::Result<Instruction> {
+pub fn set_fee_authority_ix(
+    keys: SetFeeAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
     set_fee_authority_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAuthorityAccounts<'_, '_>,
@@ -1658,9 +1920,13 @@ pub fn set_fee_authority_invoke_with_program_id(
     let ix = set_fee_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn set_fee_authority_invoke(accounts: SetFeeAuthorityAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_invoke(
+    accounts: SetFeeAuthorityAccounts<'_, '_>,
+) -> ProgramResult {
     set_fee_authority_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetFeeAuthorityAccounts<'_, '_>,
@@ -1670,12 +1936,14 @@ pub fn set_fee_authority_invoke_signed_with_program_id(
     let ix = set_fee_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_invoke_signed(
     accounts: SetFeeAuthorityAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     set_fee_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_verify_account_keys(
     accounts: SetFeeAuthorityAccounts<'_, '_>,
     keys: SetFeeAuthorityKeys,
@@ -1691,6 +1959,7 @@ pub fn set_fee_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_verify_writable_privileges<'me, 'info>(
     accounts: SetFeeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1701,6 +1970,7 @@ pub fn set_fee_authority_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_verify_signer_privileges<'me, 'info>(
     accounts: SetFeeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1711,6 +1981,7 @@ pub fn set_fee_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_fee_authority_verify_account_privileges<'me, 'info>(
     accounts: SetFeeAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1720,6 +1991,7 @@ pub fn set_fee_authority_verify_account_privileges<'me, 'info>(
 }
 pub const DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeactivateStakeAccountAccounts<'me, 'info> {
     pub stake_account: &'me AccountInfo<'info>,
     pub pool_account: &'me AccountInfo<'info>,
@@ -1735,6 +2007,7 @@ pub struct DeactivateStakeAccountKeys {
     pub clock: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeactivateStakeAccountAccounts<'_, '_>> for DeactivateStakeAccountKeys {
     fn from(accounts: DeactivateStakeAccountAccounts) -> Self {
         Self {
@@ -1746,7 +2019,8 @@ impl From<DeactivateStakeAccountAccounts<'_, '_>> for DeactivateStakeAccountKeys
         }
     }
 }
-impl From<DeactivateStakeAccountKeys> for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<DeactivateStakeAccountKeys>
+for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: DeactivateStakeAccountKeys) -> Self {
         [
             AccountMeta {
@@ -1777,7 +2051,8 @@ impl From<DeactivateStakeAccountKeys> for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT
         ]
     }
 }
-impl From<[Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for DeactivateStakeAccountKeys {
+impl From<[Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for DeactivateStakeAccountKeys {
     fn from(pubkeys: [Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake_account: pubkeys[0],
@@ -1788,9 +2063,9 @@ impl From<[Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for DeactivateStak
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeactivateStakeAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeactivateStakeAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_account.clone(),
@@ -1801,10 +2076,15 @@ impl<'info> From<DeactivateStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for DeactivateStakeAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for DeactivateStakeAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake_account: &arr[0],
             pool_account: &arr[1],
@@ -1814,38 +2094,65 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCO
         }
     }
 }
-pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [217, 64, 76, 16, 216, 77, 123, 226];
+pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [
+    217, 64, 76, 16, 216, 77, 123, 226,
+];
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeactivateStakeAccountIxData;
 impl DeactivateStakeAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != DEACTIVATE_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEACTIVATE_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEACTIVATE_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for DeactivateStakeAccountIxData {
+    const DISCRIMINATOR: &'static [u8] = &DEACTIVATE_STAKE_ACCOUNT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for DeactivateStakeAccountIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for DeactivateStakeAccountIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeactivateStakeAccountIxData {
+    const NAME: &'static str = "deactivateStakeAccount";
+    const DISCM: &'static [u8] = &DEACTIVATE_STAKE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = DeactivateStakeAccountKeys;
+    type Args = ();
+}
 pub fn deactivate_stake_account_ix_with_program_id(
     program_id: Pubkey,
     keys: DeactivateStakeAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -1855,9 +2162,10 @@ pub fn deactivate_stake_account_ix_with_program_id(
 }
 pub fn deactivate_stake_account_ix(
     keys: DeactivateStakeAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deactivate_stake_account_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
@@ -1866,11 +2174,13 @@ pub fn deactivate_stake_account_invoke_with_program_id(
     let ix = deactivate_stake_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_invoke(
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     deactivate_stake_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
@@ -1880,12 +2190,14 @@ pub fn deactivate_stake_account_invoke_signed_with_program_id(
     let ix = deactivate_stake_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_invoke_signed(
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     deactivate_stake_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_verify_account_keys(
     accounts: DeactivateStakeAccountAccounts<'_, '_>,
     keys: DeactivateStakeAccountKeys,
@@ -1903,6 +2215,7 @@ pub fn deactivate_stake_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_verify_writable_privileges<'me, 'info>(
     accounts: DeactivateStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1913,6 +2226,7 @@ pub fn deactivate_stake_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_stake_account_verify_account_privileges<'me, 'info>(
     accounts: DeactivateStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1921,6 +2235,7 @@ pub fn deactivate_stake_account_verify_account_privileges<'me, 'info>(
 }
 pub const RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ReclaimStakeAccountAccounts<'me, 'info> {
     pub stake_account: &'me AccountInfo<'info>,
     pub pool_account: &'me AccountInfo<'info>,
@@ -1940,6 +2255,7 @@ pub struct ReclaimStakeAccountKeys {
     pub stake_history: Pubkey,
     pub stake_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ReclaimStakeAccountAccounts<'_, '_>> for ReclaimStakeAccountKeys {
     fn from(accounts: ReclaimStakeAccountAccounts) -> Self {
         Self {
@@ -1953,7 +2269,8 @@ impl From<ReclaimStakeAccountAccounts<'_, '_>> for ReclaimStakeAccountKeys {
         }
     }
 }
-impl From<ReclaimStakeAccountKeys> for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<ReclaimStakeAccountKeys>
+for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: ReclaimStakeAccountKeys) -> Self {
         [
             AccountMeta {
@@ -2007,9 +2324,9 @@ impl From<[Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for ReclaimStakeAccou
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ReclaimStakeAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: ReclaimStakeAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.stake_account.clone(),
@@ -2022,10 +2339,12 @@ impl<'info> From<ReclaimStakeAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for ReclaimStakeAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for ReclaimStakeAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake_account: &arr[0],
             pool_account: &arr[1],
@@ -2037,38 +2356,65 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNT
         }
     }
 }
-pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [47, 127, 90, 221, 10, 160, 183, 117];
+pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [
+    47, 127, 90, 221, 10, 160, 183, 117,
+];
 #[derive(Clone, Debug, PartialEq)]
 pub struct ReclaimStakeAccountIxData;
 impl ReclaimStakeAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != RECLAIM_STAKE_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    RECLAIM_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        RECLAIM_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ReclaimStakeAccountIxData {
+    const DISCRIMINATOR: &'static [u8] = &RECLAIM_STAKE_ACCOUNT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ReclaimStakeAccountIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ReclaimStakeAccountIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ReclaimStakeAccountIxData {
+    const NAME: &'static str = "reclaimStakeAccount";
+    const DISCM: &'static [u8] = &RECLAIM_STAKE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = ReclaimStakeAccountKeys;
+    type Args = ();
+}
 pub fn reclaim_stake_account_ix_with_program_id(
     program_id: Pubkey,
     keys: ReclaimStakeAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -2076,9 +2422,12 @@ pub fn reclaim_stake_account_ix_with_program_id(
         data: ReclaimStakeAccountIxData.try_to_vec()?,
     })
 }
-pub fn reclaim_stake_account_ix(keys: ReclaimStakeAccountKeys) -> std::io::Result<Instruction> {
+pub fn reclaim_stake_account_ix(
+    keys: ReclaimStakeAccountKeys,
+) -> borsh::io::Result<Instruction> {
     reclaim_stake_account_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
@@ -2087,11 +2436,13 @@ pub fn reclaim_stake_account_invoke_with_program_id(
     let ix = reclaim_stake_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_invoke(
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     reclaim_stake_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
@@ -2101,12 +2452,14 @@ pub fn reclaim_stake_account_invoke_signed_with_program_id(
     let ix = reclaim_stake_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_invoke_signed(
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     reclaim_stake_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_verify_account_keys(
     accounts: ReclaimStakeAccountAccounts<'_, '_>,
     keys: ReclaimStakeAccountKeys,
@@ -2115,10 +2468,7 @@ pub fn reclaim_stake_account_verify_account_keys(
         (*accounts.stake_account.key, keys.stake_account),
         (*accounts.pool_account.key, keys.pool_account),
         (*accounts.pool_sol_reserves.key, keys.pool_sol_reserves),
-        (
-            *accounts.stake_account_record_account.key,
-            keys.stake_account_record_account,
-        ),
+        (*accounts.stake_account_record_account.key, keys.stake_account_record_account),
         (*accounts.clock.key, keys.clock),
         (*accounts.stake_history.key, keys.stake_history),
         (*accounts.stake_program.key, keys.stake_program),
@@ -2129,6 +2479,7 @@ pub fn reclaim_stake_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_verify_writable_privileges<'me, 'info>(
     accounts: ReclaimStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2144,6 +2495,7 @@ pub fn reclaim_stake_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reclaim_stake_account_verify_account_privileges<'me, 'info>(
     accounts: ReclaimStakeAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2152,6 +2504,7 @@ pub fn reclaim_stake_account_verify_account_privileges<'me, 'info>(
 }
 pub const UNSTAKE_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UnstakeAccounts<'me, 'info> {
     pub payer: &'me AccountInfo<'info>,
     pub unstaker: &'me AccountInfo<'info>,
@@ -2183,6 +2536,7 @@ pub struct UnstakeKeys {
     pub stake_program: Pubkey,
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UnstakeAccounts<'_, '_>> for UnstakeKeys {
     fn from(accounts: UnstakeAccounts) -> Self {
         Self {
@@ -2292,7 +2646,9 @@ impl From<[Pubkey; UNSTAKE_IX_ACCOUNTS_LEN]> for UnstakeKeys {
         }
     }
 }
-impl<'info> From<UnstakeAccounts<'_, 'info>> for [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<UnstakeAccounts<'_, 'info>>
+for [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: UnstakeAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -2311,9 +2667,9 @@ impl<'info> From<UnstakeAccounts<'_, 'info>> for [AccountInfo<'info>; UNSTAKE_IX
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN]>
-    for UnstakeAccounts<'me, 'info>
-{
+for UnstakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -2336,34 +2692,59 @@ pub const UNSTAKE_IX_DISCM: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnstakeIxData;
 impl UnstakeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UNSTAKE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UNSTAKE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNSTAKE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&UNSTAKE_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UnstakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &UNSTAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UnstakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UnstakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnstakeIxData {
+    const NAME: &'static str = "unstake";
+    const DISCM: &'static [u8] = &UNSTAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UNSTAKE_IX_ACCOUNTS_LEN;
+    type Keys = UnstakeKeys;
+    type Args = ();
+}
 pub fn unstake_ix_with_program_id(
     program_id: Pubkey,
     keys: UnstakeKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -2371,9 +2752,10 @@ pub fn unstake_ix_with_program_id(
         data: UnstakeIxData.try_to_vec()?,
     })
 }
-pub fn unstake_ix(keys: UnstakeKeys) -> std::io::Result<Instruction> {
+pub fn unstake_ix(keys: UnstakeKeys) -> borsh::io::Result<Instruction> {
     unstake_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeAccounts<'_, '_>,
@@ -2382,9 +2764,11 @@ pub fn unstake_invoke_with_program_id(
     let ix = unstake_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_invoke(accounts: UnstakeAccounts<'_, '_>) -> ProgramResult {
     unstake_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeAccounts<'_, '_>,
@@ -2394,12 +2778,14 @@ pub fn unstake_invoke_signed_with_program_id(
     let ix = unstake_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_invoke_signed(
     accounts: UnstakeAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     unstake_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_verify_account_keys(
     accounts: UnstakeAccounts<'_, '_>,
     keys: UnstakeKeys,
@@ -2412,18 +2798,9 @@ pub fn unstake_verify_account_keys(
         (*accounts.pool_account.key, keys.pool_account),
         (*accounts.pool_sol_reserves.key, keys.pool_sol_reserves),
         (*accounts.fee_account.key, keys.fee_account),
-        (
-            *accounts.stake_account_record_account.key,
-            keys.stake_account_record_account,
-        ),
-        (
-            *accounts.protocol_fee_account.key,
-            keys.protocol_fee_account,
-        ),
-        (
-            *accounts.protocol_fee_destination.key,
-            keys.protocol_fee_destination,
-        ),
+        (*accounts.stake_account_record_account.key, keys.stake_account_record_account),
+        (*accounts.protocol_fee_account.key, keys.protocol_fee_account),
+        (*accounts.protocol_fee_destination.key, keys.protocol_fee_destination),
         (*accounts.clock.key, keys.clock),
         (*accounts.stake_program.key, keys.stake_program),
         (*accounts.system_program.key, keys.system_program),
@@ -2434,6 +2811,7 @@ pub fn unstake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_verify_writable_privileges<'me, 'info>(
     accounts: UnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2452,6 +2830,7 @@ pub fn unstake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_verify_signer_privileges<'me, 'info>(
     accounts: UnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2462,6 +2841,7 @@ pub fn unstake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_verify_account_privileges<'me, 'info>(
     accounts: UnstakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2471,6 +2851,7 @@ pub fn unstake_verify_account_privileges<'me, 'info>(
 }
 pub const UNSTAKE_WSOL_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UnstakeWsolAccounts<'me, 'info> {
     pub payer: &'me AccountInfo<'info>,
     pub unstaker: &'me AccountInfo<'info>,
@@ -2504,6 +2885,7 @@ pub struct UnstakeWsolKeys {
     pub system_program: Pubkey,
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UnstakeWsolAccounts<'_, '_>> for UnstakeWsolKeys {
     fn from(accounts: UnstakeWsolAccounts) -> Self {
         Self {
@@ -2620,9 +3002,9 @@ impl From<[Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]> for UnstakeWsolKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UnstakeWsolAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
     fn from(accounts: UnstakeWsolAccounts<'_, 'info>) -> Self {
         [
             accounts.payer.clone(),
@@ -2642,9 +3024,9 @@ impl<'info> From<UnstakeWsolAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]>
-    for UnstakeWsolAccounts<'me, 'info>
-{
+for UnstakeWsolAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             payer: &arr[0],
@@ -2668,34 +3050,59 @@ pub const UNSTAKE_WSOL_IX_DISCM: [u8; 8] = [125, 93, 190, 135, 89, 174, 142, 149
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnstakeWsolIxData;
 impl UnstakeWsolIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm = [0u8; 8];
         reader.read_exact(&mut maybe_discm)?;
         if maybe_discm != UNSTAKE_WSOL_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UNSTAKE_WSOL_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNSTAKE_WSOL_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&UNSTAKE_WSOL_IX_DISCM)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UnstakeWsolIxData {
+    const DISCRIMINATOR: &'static [u8] = &UNSTAKE_WSOL_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UnstakeWsolIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UnstakeWsolIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnstakeWsolIxData {
+    const NAME: &'static str = "unstakeWsol";
+    const DISCM: &'static [u8] = &UNSTAKE_WSOL_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UNSTAKE_WSOL_IX_ACCOUNTS_LEN;
+    type Keys = UnstakeWsolKeys;
+    type Args = ();
+}
 pub fn unstake_wsol_ix_with_program_id(
     program_id: Pubkey,
     keys: UnstakeWsolKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -2703,9 +3110,10 @@ pub fn unstake_wsol_ix_with_program_id(
         data: UnstakeWsolIxData.try_to_vec()?,
     })
 }
-pub fn unstake_wsol_ix(keys: UnstakeWsolKeys) -> std::io::Result<Instruction> {
+pub fn unstake_wsol_ix(keys: UnstakeWsolKeys) -> borsh::io::Result<Instruction> {
     unstake_wsol_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeWsolAccounts<'_, '_>,
@@ -2714,9 +3122,11 @@ pub fn unstake_wsol_invoke_with_program_id(
     let ix = unstake_wsol_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_invoke(accounts: UnstakeWsolAccounts<'_, '_>) -> ProgramResult {
     unstake_wsol_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UnstakeWsolAccounts<'_, '_>,
@@ -2726,12 +3136,14 @@ pub fn unstake_wsol_invoke_signed_with_program_id(
     let ix = unstake_wsol_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_invoke_signed(
     accounts: UnstakeWsolAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     unstake_wsol_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_verify_account_keys(
     accounts: UnstakeWsolAccounts<'_, '_>,
     keys: UnstakeWsolKeys,
@@ -2744,18 +3156,9 @@ pub fn unstake_wsol_verify_account_keys(
         (*accounts.pool_account.key, keys.pool_account),
         (*accounts.pool_sol_reserves.key, keys.pool_sol_reserves),
         (*accounts.fee_account.key, keys.fee_account),
-        (
-            *accounts.stake_account_record_account.key,
-            keys.stake_account_record_account,
-        ),
-        (
-            *accounts.protocol_fee_account.key,
-            keys.protocol_fee_account,
-        ),
-        (
-            *accounts.protocol_fee_destination.key,
-            keys.protocol_fee_destination,
-        ),
+        (*accounts.stake_account_record_account.key, keys.stake_account_record_account),
+        (*accounts.protocol_fee_account.key, keys.protocol_fee_account),
+        (*accounts.protocol_fee_destination.key, keys.protocol_fee_destination),
         (*accounts.clock.key, keys.clock),
         (*accounts.stake_program.key, keys.stake_program),
         (*accounts.system_program.key, keys.system_program),
@@ -2767,6 +3170,7 @@ pub fn unstake_wsol_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_verify_writable_privileges<'me, 'info>(
     accounts: UnstakeWsolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2785,6 +3189,7 @@ pub fn unstake_wsol_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_verify_signer_privileges<'me, 'info>(
     accounts: UnstakeWsolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2795,6 +3200,7 @@ pub fn unstake_wsol_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unstake_wsol_verify_account_privileges<'me, 'info>(
     accounts: UnstakeWsolAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1,13 +1,15 @@
-use crate::*;
-use serde::{Deserialize, Serialize};
+use serde::{Serialize, Deserialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
+use crate::*;
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum StakeProgramProgramIx {
     Initialize(InitializeIxArgs),
@@ -27,6 +29,7 @@ pub enum StakeProgramProgramIx {
     DeactivateDelinquent,
     Redelegate,
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -34,6 +37,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -60,6 +64,7 @@ pub fn initialize_ix(keys: InitializeKeys, args: InitializeIxArgs) -> Instructio
 }
 pub const INITIALIZE_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitializeAccounts<'me, 'info> {
     ///The stake account to initialize
     pub stake: &'me AccountInfo<'info>,
@@ -73,6 +78,7 @@ pub struct InitializeKeys {
     ///Rent sysvar
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitializeAccounts<'_, '_>> for InitializeKeys {
     fn from(accounts: InitializeAccounts) -> Self {
         Self {
@@ -105,16 +111,16 @@ impl From<[Pubkey; INITIALIZE_IX_ACCOUNTS_LEN]> for InitializeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitializeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitializeAccounts<'_, 'info>) -> Self {
         [accounts.stake.clone(), accounts.rent.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
-    for InitializeAccounts<'me, 'info>
-{
+for InitializeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -127,6 +133,7 @@ pub struct InitializeIxArgs {
     pub authorized: Authorized,
     pub lockup: Lockup,
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -136,12 +143,14 @@ pub fn initialize_invoke_with_program_id(
     let ix = initialize_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke(
     accounts: InitializeAccounts<'_, '_>,
     args: InitializeIxArgs,
 ) -> ProgramResult {
     initialize_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitializeAccounts<'_, '_>,
@@ -152,6 +161,7 @@ pub fn initialize_invoke_signed_with_program_id(
     let ix = initialize_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_invoke_signed(
     accounts: InitializeAccounts<'_, '_>,
     args: InitializeIxArgs,
@@ -159,6 +169,7 @@ pub fn initialize_invoke_signed(
 ) -> ProgramResult {
     initialize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_account_keys(
     accounts: InitializeAccounts<'_, '_>,
     keys: InitializeKeys,
@@ -173,6 +184,7 @@ pub fn initialize_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_writable_privileges<'me, 'info>(
     accounts: InitializeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -183,12 +195,21 @@ pub fn initialize_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_verify_account_privileges<'me, 'info>(
     accounts: InitializeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     initialize_verify_writable_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitializeIxArgs {
+    const NAME: &'static str = "Initialize";
+    const DISCM: &'static [u8] = &INITIALIZE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INITIALIZE_IX_ACCOUNTS_LEN;
+    type Keys = InitializeKeys;
+    type Args = Self;
+}
 pub const AUTHORIZE_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
 pub fn authorize_ix_with_program_id(
     program_id: Pubkey,
@@ -207,6 +228,7 @@ pub fn authorize_ix(keys: AuthorizeKeys, args: AuthorizeIxArgs) -> Instruction {
 }
 pub const AUTHORIZE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AuthorizeAccounts<'me, 'info> {
     ///The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
@@ -224,6 +246,7 @@ pub struct AuthorizeKeys {
     ///stake's current stake or withdraw authority to change away from. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AuthorizeAccounts<'_, '_>> for AuthorizeKeys {
     fn from(accounts: AuthorizeAccounts) -> Self {
         Self {
@@ -263,18 +286,16 @@ impl From<[Pubkey; AUTHORIZE_IX_ACCOUNTS_LEN]> for AuthorizeKeys {
         }
     }
 }
-impl<'info> From<AuthorizeAccounts<'_, 'info>> for [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<AuthorizeAccounts<'_, 'info>>
+for [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN] {
     fn from(accounts: AuthorizeAccounts<'_, 'info>) -> Self {
-        [
-            accounts.stake.clone(),
-            accounts.clock.clone(),
-            accounts.authority.clone(),
-        ]
+        [accounts.stake.clone(), accounts.clock.clone(), accounts.authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN]>
-    for AuthorizeAccounts<'me, 'info>
-{
+for AuthorizeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -288,6 +309,7 @@ pub struct AuthorizeIxArgs {
     pub new_authority: Pubkey,
     pub stake_authorize: StakeAuthorize,
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeAccounts<'_, '_>,
@@ -297,12 +319,14 @@ pub fn authorize_invoke_with_program_id(
     let ix = authorize_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_invoke(
     accounts: AuthorizeAccounts<'_, '_>,
     args: AuthorizeIxArgs,
 ) -> ProgramResult {
     authorize_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeAccounts<'_, '_>,
@@ -313,6 +337,7 @@ pub fn authorize_invoke_signed_with_program_id(
     let ix = authorize_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_invoke_signed(
     accounts: AuthorizeAccounts<'_, '_>,
     args: AuthorizeIxArgs,
@@ -320,6 +345,7 @@ pub fn authorize_invoke_signed(
 ) -> ProgramResult {
     authorize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_verify_account_keys(
     accounts: AuthorizeAccounts<'_, '_>,
     keys: AuthorizeKeys,
@@ -335,6 +361,7 @@ pub fn authorize_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_verify_writable_privileges<'me, 'info>(
     accounts: AuthorizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -345,6 +372,7 @@ pub fn authorize_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_verify_signer_privileges<'me, 'info>(
     accounts: AuthorizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -355,6 +383,7 @@ pub fn authorize_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_verify_account_privileges<'me, 'info>(
     accounts: AuthorizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -362,6 +391,14 @@ pub fn authorize_verify_account_privileges<'me, 'info>(
     authorize_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeIxArgs {
+    const NAME: &'static str = "Authorize";
+    const DISCM: &'static [u8] = &AUTHORIZE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeKeys;
+    type Args = Self;
+}
 pub const DELEGATE_STAKE_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
 pub fn delegate_stake_ix_with_program_id(
     program_id: Pubkey,
@@ -379,6 +416,7 @@ pub fn delegate_stake_ix(keys: DelegateStakeKeys) -> Instruction {
 }
 pub const DELEGATE_STAKE_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DelegateStakeAccounts<'me, 'info> {
     ///The stake account to be delegated
     pub stake: &'me AccountInfo<'info>,
@@ -408,6 +446,7 @@ pub struct DelegateStakeKeys {
     ///stake's stake authority
     pub stake_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DelegateStakeAccounts<'_, '_>> for DelegateStakeKeys {
     fn from(accounts: DelegateStakeAccounts) -> Self {
         Self {
@@ -468,9 +507,9 @@ impl From<[Pubkey; DELEGATE_STAKE_IX_ACCOUNTS_LEN]> for DelegateStakeKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DelegateStakeAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN] {
     fn from(accounts: DelegateStakeAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -482,9 +521,9 @@ impl<'info> From<DelegateStakeAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN]>
-    for DelegateStakeAccounts<'me, 'info>
-{
+for DelegateStakeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -496,6 +535,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DELEGATE_STAKE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DelegateStakeAccounts<'_, '_>,
@@ -504,9 +544,11 @@ pub fn delegate_stake_invoke_with_program_id(
     let ix = delegate_stake_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_invoke(accounts: DelegateStakeAccounts<'_, '_>) -> ProgramResult {
     delegate_stake_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DelegateStakeAccounts<'_, '_>,
@@ -516,12 +558,14 @@ pub fn delegate_stake_invoke_signed_with_program_id(
     let ix = delegate_stake_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_invoke_signed(
     accounts: DelegateStakeAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     delegate_stake_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_verify_account_keys(
     accounts: DelegateStakeAccounts<'_, '_>,
     keys: DelegateStakeKeys,
@@ -540,6 +584,7 @@ pub fn delegate_stake_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_verify_writable_privileges<'me, 'info>(
     accounts: DelegateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -550,6 +595,7 @@ pub fn delegate_stake_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_verify_signer_privileges<'me, 'info>(
     accounts: DelegateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -560,6 +606,7 @@ pub fn delegate_stake_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn delegate_stake_verify_account_privileges<'me, 'info>(
     accounts: DelegateStakeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -585,6 +632,7 @@ pub fn split_ix(keys: SplitKeys, args: SplitIxArgs) -> Instruction {
 }
 pub const SPLIT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SplitAccounts<'me, 'info> {
     ///The stake account to split. Must be in the Initialized or Stake state
     pub from: &'me AccountInfo<'info>,
@@ -602,6 +650,7 @@ pub struct SplitKeys {
     ///from's stake authority
     pub stake_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SplitAccounts<'_, '_>> for SplitKeys {
     fn from(accounts: SplitAccounts) -> Self {
         Self {
@@ -641,18 +690,16 @@ impl From<[Pubkey; SPLIT_IX_ACCOUNTS_LEN]> for SplitKeys {
         }
     }
 }
-impl<'info> From<SplitAccounts<'_, 'info>> for [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<SplitAccounts<'_, 'info>>
+for [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN] {
     fn from(accounts: SplitAccounts<'_, 'info>) -> Self {
-        [
-            accounts.from.clone(),
-            accounts.to.clone(),
-            accounts.stake_authority.clone(),
-        ]
+        [accounts.from.clone(), accounts.to.clone(), accounts.stake_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN]>
-    for SplitAccounts<'me, 'info>
-{
+for SplitAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             from: &arr[0],
@@ -665,6 +712,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SPLIT_IX_ACCOUNTS_LEN]>
 pub struct SplitIxArgs {
     pub lamports: u64,
 }
+#[cfg(feature = "cpi")]
 pub fn split_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SplitAccounts<'_, '_>,
@@ -674,9 +722,14 @@ pub fn split_invoke_with_program_id(
     let ix = split_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
-pub fn split_invoke(accounts: SplitAccounts<'_, '_>, args: SplitIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn split_invoke(
+    accounts: SplitAccounts<'_, '_>,
+    args: SplitIxArgs,
+) -> ProgramResult {
     split_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn split_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SplitAccounts<'_, '_>,
@@ -687,6 +740,7 @@ pub fn split_invoke_signed_with_program_id(
     let ix = split_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn split_invoke_signed(
     accounts: SplitAccounts<'_, '_>,
     args: SplitIxArgs,
@@ -694,6 +748,7 @@ pub fn split_invoke_signed(
 ) -> ProgramResult {
     split_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn split_verify_account_keys(
     accounts: SplitAccounts<'_, '_>,
     keys: SplitKeys,
@@ -709,6 +764,7 @@ pub fn split_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn split_verify_writable_privileges<'me, 'info>(
     accounts: SplitAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -719,6 +775,7 @@ pub fn split_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn split_verify_signer_privileges<'me, 'info>(
     accounts: SplitAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -729,6 +786,7 @@ pub fn split_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn split_verify_account_privileges<'me, 'info>(
     accounts: SplitAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -736,6 +794,14 @@ pub fn split_verify_account_privileges<'me, 'info>(
     split_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SplitIxArgs {
+    const NAME: &'static str = "Split";
+    const DISCM: &'static [u8] = &SPLIT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SPLIT_IX_ACCOUNTS_LEN;
+    type Keys = SplitKeys;
+    type Args = Self;
+}
 pub const WITHDRAW_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
 pub fn withdraw_ix_with_program_id(
     program_id: Pubkey,
@@ -754,6 +820,7 @@ pub fn withdraw_ix(keys: WithdrawKeys, args: WithdrawIxArgs) -> Instruction {
 }
 pub const WITHDRAW_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct WithdrawAccounts<'me, 'info> {
     ///The stake account to withdraw from
     pub from: &'me AccountInfo<'info>,
@@ -779,6 +846,7 @@ pub struct WithdrawKeys {
     ///from's withdraw authority. If stake Lockup is active, the signing lockup authority must follow.
     pub withdraw_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<WithdrawAccounts<'_, '_>> for WithdrawKeys {
     fn from(accounts: WithdrawAccounts) -> Self {
         Self {
@@ -832,7 +900,9 @@ impl From<[Pubkey; WITHDRAW_IX_ACCOUNTS_LEN]> for WithdrawKeys {
         }
     }
 }
-impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<WithdrawAccounts<'_, 'info>>
+for [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN] {
     fn from(accounts: WithdrawAccounts<'_, 'info>) -> Self {
         [
             accounts.from.clone(),
@@ -843,9 +913,9 @@ impl<'info> From<WithdrawAccounts<'_, 'info>> for [AccountInfo<'info>; WITHDRAW_
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
-    for WithdrawAccounts<'me, 'info>
-{
+for WithdrawAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             from: &arr[0],
@@ -860,6 +930,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_IX_ACCOUNTS_LEN]>
 pub struct WithdrawIxArgs {
     pub lamports: u64,
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_invoke_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawAccounts<'_, '_>,
@@ -869,9 +940,14 @@ pub fn withdraw_invoke_with_program_id(
     let ix = withdraw_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
-pub fn withdraw_invoke(accounts: WithdrawAccounts<'_, '_>, args: WithdrawIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn withdraw_invoke(
+    accounts: WithdrawAccounts<'_, '_>,
+    args: WithdrawIxArgs,
+) -> ProgramResult {
     withdraw_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawAccounts<'_, '_>,
@@ -882,6 +958,7 @@ pub fn withdraw_invoke_signed_with_program_id(
     let ix = withdraw_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_invoke_signed(
     accounts: WithdrawAccounts<'_, '_>,
     args: WithdrawIxArgs,
@@ -889,6 +966,7 @@ pub fn withdraw_invoke_signed(
 ) -> ProgramResult {
     withdraw_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_verify_account_keys(
     accounts: WithdrawAccounts<'_, '_>,
     keys: WithdrawKeys,
@@ -906,6 +984,7 @@ pub fn withdraw_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_verify_writable_privileges<'me, 'info>(
     accounts: WithdrawAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -916,6 +995,7 @@ pub fn withdraw_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_verify_signer_privileges<'me, 'info>(
     accounts: WithdrawAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -926,6 +1006,7 @@ pub fn withdraw_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_verify_account_privileges<'me, 'info>(
     accounts: WithdrawAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -933,8 +1014,19 @@ pub fn withdraw_verify_account_privileges<'me, 'info>(
     withdraw_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for WithdrawIxArgs {
+    const NAME: &'static str = "Withdraw";
+    const DISCM: &'static [u8] = &WITHDRAW_IX_DISCM;
+    const ACCOUNTS_LEN: usize = WITHDRAW_IX_ACCOUNTS_LEN;
+    type Keys = WithdrawKeys;
+    type Args = Self;
+}
 pub const DEACTIVATE_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
-pub fn deactivate_ix_with_program_id(program_id: Pubkey, keys: DeactivateKeys) -> Instruction {
+pub fn deactivate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeactivateKeys,
+) -> Instruction {
     let metas: [AccountMeta; DEACTIVATE_IX_ACCOUNTS_LEN] = keys.into();
     Instruction::new_with_bincode(
         program_id,
@@ -947,6 +1039,7 @@ pub fn deactivate_ix(keys: DeactivateKeys) -> Instruction {
 }
 pub const DEACTIVATE_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeactivateAccounts<'me, 'info> {
     ///The stake account to deactivate
     pub stake: &'me AccountInfo<'info>,
@@ -964,6 +1057,7 @@ pub struct DeactivateKeys {
     ///stake's stake authority
     pub stake_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeactivateAccounts<'_, '_>> for DeactivateKeys {
     fn from(accounts: DeactivateAccounts) -> Self {
         Self {
@@ -1003,9 +1097,9 @@ impl From<[Pubkey; DEACTIVATE_IX_ACCOUNTS_LEN]> for DeactivateKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeactivateAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeactivateAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -1014,9 +1108,9 @@ impl<'info> From<DeactivateAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN]>
-    for DeactivateAccounts<'me, 'info>
-{
+for DeactivateAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -1025,6 +1119,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateAccounts<'_, '_>,
@@ -1033,9 +1128,11 @@ pub fn deactivate_invoke_with_program_id(
     let ix = deactivate_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_invoke(accounts: DeactivateAccounts<'_, '_>) -> ProgramResult {
     deactivate_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateAccounts<'_, '_>,
@@ -1045,12 +1142,14 @@ pub fn deactivate_invoke_signed_with_program_id(
     let ix = deactivate_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_invoke_signed(
     accounts: DeactivateAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     deactivate_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_verify_account_keys(
     accounts: DeactivateAccounts<'_, '_>,
     keys: DeactivateKeys,
@@ -1066,6 +1165,7 @@ pub fn deactivate_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_verify_writable_privileges<'me, 'info>(
     accounts: DeactivateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1076,6 +1176,7 @@ pub fn deactivate_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_verify_signer_privileges<'me, 'info>(
     accounts: DeactivateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1086,6 +1187,7 @@ pub fn deactivate_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_verify_account_privileges<'me, 'info>(
     accounts: DeactivateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1111,6 +1213,7 @@ pub fn set_lockup_ix(keys: SetLockupKeys, args: SetLockupIxArgs) -> Instruction
 }
 pub const SET_LOCKUP_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetLockupAccounts<'me, 'info> {
     ///The stake account to set the lockup of
     pub stake: &'me AccountInfo<'info>,
@@ -1124,6 +1227,7 @@ pub struct SetLockupKeys {
     ///stake's withdraw authority or lockup authority if lockup is active
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetLockupAccounts<'_, '_>> for SetLockupKeys {
     fn from(accounts: SetLockupAccounts) -> Self {
         Self {
@@ -1156,16 +1260,16 @@ impl From<[Pubkey; SET_LOCKUP_IX_ACCOUNTS_LEN]> for SetLockupKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetLockupAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetLockupAccounts<'_, 'info>) -> Self {
         [accounts.stake.clone(), accounts.authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN]>
-    for SetLockupAccounts<'me, 'info>
-{
+for SetLockupAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_LOCKUP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -1179,6 +1283,7 @@ pub struct SetLockupIxArgs {
     pub epoch: Option<u64>,
     pub custodian: Option<Pubkey>,
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetLockupAccounts<'_, '_>,
@@ -1188,12 +1293,14 @@ pub fn set_lockup_invoke_with_program_id(
     let ix = set_lockup_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_invoke(
     accounts: SetLockupAccounts<'_, '_>,
     args: SetLockupIxArgs,
 ) -> ProgramResult {
     set_lockup_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetLockupAccounts<'_, '_>,
@@ -1204,6 +1311,7 @@ pub fn set_lockup_invoke_signed_with_program_id(
     let ix = set_lockup_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_invoke_signed(
     accounts: SetLockupAccounts<'_, '_>,
     args: SetLockupIxArgs,
@@ -1211,6 +1319,7 @@ pub fn set_lockup_invoke_signed(
 ) -> ProgramResult {
     set_lockup_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_verify_account_keys(
     accounts: SetLockupAccounts<'_, '_>,
     keys: SetLockupKeys,
@@ -1225,6 +1334,7 @@ pub fn set_lockup_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_verify_writable_privileges<'me, 'info>(
     accounts: SetLockupAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1235,6 +1345,7 @@ pub fn set_lockup_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_verify_signer_privileges<'me, 'info>(
     accounts: SetLockupAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1245,6 +1356,7 @@ pub fn set_lockup_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_verify_account_privileges<'me, 'info>(
     accounts: SetLockupAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1252,16 +1364,29 @@ pub fn set_lockup_verify_account_privileges<'me, 'info>(
     set_lockup_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetLockupIxArgs {
+    const NAME: &'static str = "SetLockup";
+    const DISCM: &'static [u8] = &SET_LOCKUP_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_LOCKUP_IX_ACCOUNTS_LEN;
+    type Keys = SetLockupKeys;
+    type Args = Self;
+}
 pub const MERGE_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
 pub fn merge_ix_with_program_id(program_id: Pubkey, keys: MergeKeys) -> Instruction {
     let metas: [AccountMeta; MERGE_IX_ACCOUNTS_LEN] = keys.into();
-    Instruction::new_with_bincode(program_id, &StakeProgramProgramIx::Merge, Vec::from(metas))
+    Instruction::new_with_bincode(
+        program_id,
+        &StakeProgramProgramIx::Merge,
+        Vec::from(metas),
+    )
 }
 pub fn merge_ix(keys: MergeKeys) -> Instruction {
     merge_ix_with_program_id(crate::ID, keys)
 }
 pub const MERGE_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct MergeAccounts<'me, 'info> {
     ///The destination stake account to merge into
     pub to: &'me AccountInfo<'info>,
@@ -1287,6 +1412,7 @@ pub struct MergeKeys {
     ///Both from and to's stake authority
     pub stake_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<MergeAccounts<'_, '_>> for MergeKeys {
     fn from(accounts: MergeAccounts) -> Self {
         Self {
@@ -1340,7 +1466,9 @@ impl From<[Pubkey; MERGE_IX_ACCOUNTS_LEN]> for MergeKeys {
         }
     }
 }
-impl<'info> From<MergeAccounts<'_, 'info>> for [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<MergeAccounts<'_, 'info>>
+for [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN] {
     fn from(accounts: MergeAccounts<'_, 'info>) -> Self {
         [
             accounts.to.clone(),
@@ -1351,9 +1479,9 @@ impl<'info> From<MergeAccounts<'_, 'info>> for [AccountInfo<'info>; MERGE_IX_ACC
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN]>
-    for MergeAccounts<'me, 'info>
-{
+for MergeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             to: &arr[0],
@@ -1364,6 +1492,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn merge_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MergeAccounts<'_, '_>,
@@ -1372,9 +1501,11 @@ pub fn merge_invoke_with_program_id(
     let ix = merge_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_invoke(accounts: MergeAccounts<'_, '_>) -> ProgramResult {
     merge_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: MergeAccounts<'_, '_>,
@@ -1384,9 +1515,14 @@ pub fn merge_invoke_signed_with_program_id(
     let ix = merge_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn merge_invoke_signed(accounts: MergeAccounts<'_, '_>, seeds: &[&[&[u8]]]) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn merge_invoke_signed(
+    accounts: MergeAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
     merge_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn merge_verify_account_keys(
     accounts: MergeAccounts<'_, '_>,
     keys: MergeKeys,
@@ -1404,6 +1540,7 @@ pub fn merge_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn merge_verify_writable_privileges<'me, 'info>(
     accounts: MergeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1414,6 +1551,7 @@ pub fn merge_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn merge_verify_signer_privileges<'me, 'info>(
     accounts: MergeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1424,6 +1562,7 @@ pub fn merge_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn merge_verify_account_privileges<'me, 'info>(
     accounts: MergeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1452,6 +1591,7 @@ pub fn authorize_with_seed_ix(
 }
 pub const AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AuthorizeWithSeedAccounts<'me, 'info> {
     ///The stake account to be updated, with the authority to be updated being an account created with Pubkey::create_with_seed()
     pub stake: &'me AccountInfo<'info>,
@@ -1469,6 +1609,7 @@ pub struct AuthorizeWithSeedKeys {
     ///Clock sysvar. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub clock: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AuthorizeWithSeedAccounts<'_, '_>> for AuthorizeWithSeedKeys {
     fn from(accounts: AuthorizeWithSeedAccounts) -> Self {
         Self {
@@ -1508,21 +1649,19 @@ impl From<[Pubkey; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]> for AuthorizeWithSeedKe
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AuthorizeWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: AuthorizeWithSeedAccounts<'_, 'info>) -> Self {
-        [
-            accounts.stake.clone(),
-            accounts.authority_base.clone(),
-            accounts.clock.clone(),
-        ]
+        [accounts.stake.clone(), accounts.authority_base.clone(), accounts.clock.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for AuthorizeWithSeedAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+for AuthorizeWithSeedAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake: &arr[0],
             authority_base: &arr[1],
@@ -1537,6 +1676,7 @@ pub struct AuthorizeWithSeedIxArgs {
     pub authority_seed: String,
     pub authority_owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeWithSeedAccounts<'_, '_>,
@@ -1546,12 +1686,14 @@ pub fn authorize_with_seed_invoke_with_program_id(
     let ix = authorize_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_invoke(
     accounts: AuthorizeWithSeedAccounts<'_, '_>,
     args: AuthorizeWithSeedIxArgs,
 ) -> ProgramResult {
     authorize_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeWithSeedAccounts<'_, '_>,
@@ -1562,6 +1704,7 @@ pub fn authorize_with_seed_invoke_signed_with_program_id(
     let ix = authorize_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_invoke_signed(
     accounts: AuthorizeWithSeedAccounts<'_, '_>,
     args: AuthorizeWithSeedIxArgs,
@@ -1569,6 +1712,7 @@ pub fn authorize_with_seed_invoke_signed(
 ) -> ProgramResult {
     authorize_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_verify_account_keys(
     accounts: AuthorizeWithSeedAccounts<'_, '_>,
     keys: AuthorizeWithSeedKeys,
@@ -1584,6 +1728,7 @@ pub fn authorize_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: AuthorizeWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1594,6 +1739,7 @@ pub fn authorize_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: AuthorizeWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1604,6 +1750,7 @@ pub fn authorize_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_with_seed_verify_account_privileges<'me, 'info>(
     accounts: AuthorizeWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1611,6 +1758,14 @@ pub fn authorize_with_seed_verify_account_privileges<'me, 'info>(
     authorize_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeWithSeedIxArgs {
+    const NAME: &'static str = "AuthorizeWithSeed";
+    const DISCM: &'static [u8] = &AUTHORIZE_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeWithSeedKeys;
+    type Args = Self;
+}
 pub const INITIALIZE_CHECKED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
 pub fn initialize_checked_ix_with_program_id(
     program_id: Pubkey,
@@ -1628,6 +1783,7 @@ pub fn initialize_checked_ix(keys: InitializeCheckedKeys) -> Instruction {
 }
 pub const INITIALIZE_CHECKED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitializeCheckedAccounts<'me, 'info> {
     ///The stake account to initialize
     pub stake: &'me AccountInfo<'info>,
@@ -1649,6 +1805,7 @@ pub struct InitializeCheckedKeys {
     ///stake's new withdraw authority
     pub withdraw_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitializeCheckedAccounts<'_, '_>> for InitializeCheckedKeys {
     fn from(accounts: InitializeCheckedAccounts) -> Self {
         Self {
@@ -1695,9 +1852,9 @@ impl From<[Pubkey; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN]> for InitializeCheckedKey
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitializeCheckedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitializeCheckedAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -1707,9 +1864,9 @@ impl<'info> From<InitializeCheckedAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN]>
-    for InitializeCheckedAccounts<'me, 'info>
-{
+for InitializeCheckedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -1719,6 +1876,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_CHECKED_IX_ACCOUNTS_L
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeCheckedAccounts<'_, '_>,
@@ -1727,9 +1885,13 @@ pub fn initialize_checked_invoke_with_program_id(
     let ix = initialize_checked_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
-pub fn initialize_checked_invoke(accounts: InitializeCheckedAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn initialize_checked_invoke(
+    accounts: InitializeCheckedAccounts<'_, '_>,
+) -> ProgramResult {
     initialize_checked_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitializeCheckedAccounts<'_, '_>,
@@ -1739,12 +1901,14 @@ pub fn initialize_checked_invoke_signed_with_program_id(
     let ix = initialize_checked_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_invoke_signed(
     accounts: InitializeCheckedAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     initialize_checked_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_verify_account_keys(
     accounts: InitializeCheckedAccounts<'_, '_>,
     keys: InitializeCheckedKeys,
@@ -1761,6 +1925,7 @@ pub fn initialize_checked_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_verify_writable_privileges<'me, 'info>(
     accounts: InitializeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1771,6 +1936,7 @@ pub fn initialize_checked_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_verify_signer_privileges<'me, 'info>(
     accounts: InitializeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1781,6 +1947,7 @@ pub fn initialize_checked_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_checked_verify_account_privileges<'me, 'info>(
     accounts: InitializeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1809,6 +1976,7 @@ pub fn authorize_checked_ix(
 }
 pub const AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AuthorizeCheckedAccounts<'me, 'info> {
     ///The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
@@ -1830,6 +1998,7 @@ pub struct AuthorizeCheckedKeys {
     ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AuthorizeCheckedAccounts<'_, '_>> for AuthorizeCheckedKeys {
     fn from(accounts: AuthorizeCheckedAccounts) -> Self {
         Self {
@@ -1876,9 +2045,9 @@ impl From<[Pubkey; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]> for AuthorizeCheckedKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AuthorizeCheckedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN] {
     fn from(accounts: AuthorizeCheckedAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -1888,9 +2057,9 @@ impl<'info> From<AuthorizeCheckedAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]>
-    for AuthorizeCheckedAccounts<'me, 'info>
-{
+for AuthorizeCheckedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -1904,6 +2073,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_IX_ACCOUNTS_LE
 pub struct AuthorizeCheckedIxArgs {
     pub stake_authorize: StakeAuthorize,
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeCheckedAccounts<'_, '_>,
@@ -1913,12 +2083,14 @@ pub fn authorize_checked_invoke_with_program_id(
     let ix = authorize_checked_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_invoke(
     accounts: AuthorizeCheckedAccounts<'_, '_>,
     args: AuthorizeCheckedIxArgs,
 ) -> ProgramResult {
     authorize_checked_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeCheckedAccounts<'_, '_>,
@@ -1929,6 +2101,7 @@ pub fn authorize_checked_invoke_signed_with_program_id(
     let ix = authorize_checked_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_invoke_signed(
     accounts: AuthorizeCheckedAccounts<'_, '_>,
     args: AuthorizeCheckedIxArgs,
@@ -1936,6 +2109,7 @@ pub fn authorize_checked_invoke_signed(
 ) -> ProgramResult {
     authorize_checked_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_verify_account_keys(
     accounts: AuthorizeCheckedAccounts<'_, '_>,
     keys: AuthorizeCheckedKeys,
@@ -1952,6 +2126,7 @@ pub fn authorize_checked_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_verify_writable_privileges<'me, 'info>(
     accounts: AuthorizeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1962,6 +2137,7 @@ pub fn authorize_checked_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_verify_signer_privileges<'me, 'info>(
     accounts: AuthorizeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1972,6 +2148,7 @@ pub fn authorize_checked_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_verify_account_privileges<'me, 'info>(
     accounts: AuthorizeCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1979,6 +2156,14 @@ pub fn authorize_checked_verify_account_privileges<'me, 'info>(
     authorize_checked_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeCheckedIxArgs {
+    const NAME: &'static str = "AuthorizeChecked";
+    const DISCM: &'static [u8] = &AUTHORIZE_CHECKED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_CHECKED_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeCheckedKeys;
+    type Args = Self;
+}
 pub const AUTHORIZE_CHECKED_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
 pub fn authorize_checked_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -2000,6 +2185,7 @@ pub fn authorize_checked_with_seed_ix(
 }
 pub const AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AuthorizeCheckedWithSeedAccounts<'me, 'info> {
     ///The stake account to be updated
     pub stake: &'me AccountInfo<'info>,
@@ -2021,6 +2207,7 @@ pub struct AuthorizeCheckedWithSeedKeys {
     ///stake's new stake or withdraw authority to change to. If stake Lockup is active, the signing lockup authority must follow if updating withdrawer.
     pub new_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AuthorizeCheckedWithSeedAccounts<'_, '_>> for AuthorizeCheckedWithSeedKeys {
     fn from(accounts: AuthorizeCheckedWithSeedAccounts) -> Self {
         Self {
@@ -2032,8 +2219,7 @@ impl From<AuthorizeCheckedWithSeedAccounts<'_, '_>> for AuthorizeCheckedWithSeed
     }
 }
 impl From<AuthorizeCheckedWithSeedKeys>
-    for [AccountMeta; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(keys: AuthorizeCheckedWithSeedKeys) -> Self {
         [
             AccountMeta {
@@ -2059,7 +2245,8 @@ impl From<AuthorizeCheckedWithSeedKeys>
         ]
     }
 }
-impl From<[Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]> for AuthorizeCheckedWithSeedKeys {
+impl From<[Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]>
+for AuthorizeCheckedWithSeedKeys {
     fn from(pubkeys: [Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: pubkeys[0],
@@ -2069,9 +2256,9 @@ impl From<[Pubkey; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]> for AuthorizeCh
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AuthorizeCheckedWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: AuthorizeCheckedWithSeedAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -2081,10 +2268,15 @@ impl<'info> From<AuthorizeCheckedWithSeedAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for AuthorizeCheckedWithSeedAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN]>
+for AuthorizeCheckedWithSeedAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake: &arr[0],
             authority_base: &arr[1],
@@ -2099,6 +2291,7 @@ pub struct AuthorizeCheckedWithSeedIxArgs {
     pub authority_seed: String,
     pub authority_owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
@@ -2108,12 +2301,14 @@ pub fn authorize_checked_with_seed_invoke_with_program_id(
     let ix = authorize_checked_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_invoke(
     accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
     args: AuthorizeCheckedWithSeedIxArgs,
 ) -> ProgramResult {
     authorize_checked_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
@@ -2124,13 +2319,20 @@ pub fn authorize_checked_with_seed_invoke_signed_with_program_id(
     let ix = authorize_checked_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_invoke_signed(
     accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
     args: AuthorizeCheckedWithSeedIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    authorize_checked_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    authorize_checked_with_seed_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_verify_account_keys(
     accounts: AuthorizeCheckedWithSeedAccounts<'_, '_>,
     keys: AuthorizeCheckedWithSeedKeys,
@@ -2147,6 +2349,7 @@ pub fn authorize_checked_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2157,6 +2360,7 @@ pub fn authorize_checked_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2167,6 +2371,7 @@ pub fn authorize_checked_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_checked_with_seed_verify_account_privileges<'me, 'info>(
     accounts: AuthorizeCheckedWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2174,6 +2379,14 @@ pub fn authorize_checked_with_seed_verify_account_privileges<'me, 'info>(
     authorize_checked_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeCheckedWithSeedIxArgs {
+    const NAME: &'static str = "AuthorizeCheckedWithSeed";
+    const DISCM: &'static [u8] = &AUTHORIZE_CHECKED_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_CHECKED_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeCheckedWithSeedKeys;
+    type Args = Self;
+}
 pub const SET_LOCKUP_CHECKED_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
 pub fn set_lockup_checked_ix_with_program_id(
     program_id: Pubkey,
@@ -2195,6 +2408,7 @@ pub fn set_lockup_checked_ix(
 }
 pub const SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetLockupCheckedAccounts<'me, 'info> {
     ///The stake account to set the lockup of
     pub stake: &'me AccountInfo<'info>,
@@ -2208,6 +2422,7 @@ pub struct SetLockupCheckedKeys {
     ///stake's withdraw authority or lockup authority if lockup is active. If setting a new lockup authority, the signing new lockup authority must follow.
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetLockupCheckedAccounts<'_, '_>> for SetLockupCheckedKeys {
     fn from(accounts: SetLockupCheckedAccounts) -> Self {
         Self {
@@ -2240,16 +2455,16 @@ impl From<[Pubkey; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN]> for SetLockupCheckedKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetLockupCheckedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetLockupCheckedAccounts<'_, 'info>) -> Self {
         [accounts.stake.clone(), accounts.authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN]>
-    for SetLockupCheckedAccounts<'me, 'info>
-{
+for SetLockupCheckedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -2262,6 +2477,7 @@ pub struct SetLockupCheckedIxArgs {
     pub unix_timestamp: Option<i64>,
     pub epoch: Option<u64>,
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetLockupCheckedAccounts<'_, '_>,
@@ -2271,12 +2487,14 @@ pub fn set_lockup_checked_invoke_with_program_id(
     let ix = set_lockup_checked_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_invoke(
     accounts: SetLockupCheckedAccounts<'_, '_>,
     args: SetLockupCheckedIxArgs,
 ) -> ProgramResult {
     set_lockup_checked_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetLockupCheckedAccounts<'_, '_>,
@@ -2287,6 +2505,7 @@ pub fn set_lockup_checked_invoke_signed_with_program_id(
     let ix = set_lockup_checked_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_invoke_signed(
     accounts: SetLockupCheckedAccounts<'_, '_>,
     args: SetLockupCheckedIxArgs,
@@ -2294,6 +2513,7 @@ pub fn set_lockup_checked_invoke_signed(
 ) -> ProgramResult {
     set_lockup_checked_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_verify_account_keys(
     accounts: SetLockupCheckedAccounts<'_, '_>,
     keys: SetLockupCheckedKeys,
@@ -2308,6 +2528,7 @@ pub fn set_lockup_checked_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_verify_writable_privileges<'me, 'info>(
     accounts: SetLockupCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2318,6 +2539,7 @@ pub fn set_lockup_checked_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_verify_signer_privileges<'me, 'info>(
     accounts: SetLockupCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2328,6 +2550,7 @@ pub fn set_lockup_checked_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_lockup_checked_verify_account_privileges<'me, 'info>(
     accounts: SetLockupCheckedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2335,6 +2558,14 @@ pub fn set_lockup_checked_verify_account_privileges<'me, 'info>(
     set_lockup_checked_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetLockupCheckedIxArgs {
+    const NAME: &'static str = "SetLockupChecked";
+    const DISCM: &'static [u8] = &SET_LOCKUP_CHECKED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_LOCKUP_CHECKED_IX_ACCOUNTS_LEN;
+    type Keys = SetLockupCheckedKeys;
+    type Args = Self;
+}
 pub const GET_MINIMUM_DELEGATION_IX_DISCM: [u8; 4] = [13, 0, 0, 0];
 pub fn get_minimum_delegation_ix_with_program_id(program_id: Pubkey) -> Instruction {
     Instruction::new_with_bincode(
@@ -2346,13 +2577,18 @@ pub fn get_minimum_delegation_ix_with_program_id(program_id: Pubkey) -> Instruct
 pub fn get_minimum_delegation_ix() -> Instruction {
     get_minimum_delegation_ix_with_program_id(crate::ID)
 }
-pub fn get_minimum_delegation_invoke_with_program_id(program_id: Pubkey) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn get_minimum_delegation_invoke_with_program_id(
+    program_id: Pubkey,
+) -> ProgramResult {
     let ix = get_minimum_delegation_ix_with_program_id(program_id);
     invoke(&ix, &[])
 }
+#[cfg(feature = "cpi")]
 pub fn get_minimum_delegation_invoke() -> ProgramResult {
     get_minimum_delegation_invoke_with_program_id(crate::ID)
 }
+#[cfg(feature = "cpi")]
 pub fn get_minimum_delegation_invoke_signed_with_program_id(
     program_id: Pubkey,
     seeds: &[&[&[u8]]],
@@ -2360,6 +2596,7 @@ pub fn get_minimum_delegation_invoke_signed_with_program_id(
     let ix = get_minimum_delegation_ix_with_program_id(program_id);
     invoke_signed(&ix, &[], seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn get_minimum_delegation_invoke_signed(seeds: &[&[&[u8]]]) -> ProgramResult {
     get_minimum_delegation_invoke_signed_with_program_id(crate::ID, seeds)
 }
@@ -2380,6 +2617,7 @@ pub fn deactivate_delinquent_ix(keys: DeactivateDelinquentKeys) -> Instruction {
 }
 pub const DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeactivateDelinquentAccounts<'me, 'info> {
     ///The delinquent stake account to deactivate
     pub stake: &'me AccountInfo<'info>,
@@ -2397,6 +2635,7 @@ pub struct DeactivateDelinquentKeys {
     ///Reference vote account that has voted at least once in the last MINIMUM_DELINQUENT_EPOCHS_FOR_DEACTIVATION epochs
     pub reference_vote: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeactivateDelinquentAccounts<'_, '_>> for DeactivateDelinquentKeys {
     fn from(accounts: DeactivateDelinquentAccounts) -> Self {
         Self {
@@ -2406,7 +2645,8 @@ impl From<DeactivateDelinquentAccounts<'_, '_>> for DeactivateDelinquentKeys {
         }
     }
 }
-impl From<DeactivateDelinquentKeys> for [AccountMeta; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN] {
+impl From<DeactivateDelinquentKeys>
+for [AccountMeta; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN] {
     fn from(keys: DeactivateDelinquentKeys) -> Self {
         [
             AccountMeta {
@@ -2436,21 +2676,19 @@ impl From<[Pubkey; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN]> for DeactivateDelinqu
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeactivateDelinquentAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeactivateDelinquentAccounts<'_, 'info>) -> Self {
-        [
-            accounts.stake.clone(),
-            accounts.vote.clone(),
-            accounts.reference_vote.clone(),
-        ]
+        [accounts.stake.clone(), accounts.vote.clone(), accounts.reference_vote.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN]>
-    for DeactivateDelinquentAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN]) -> Self {
+for DeactivateDelinquentAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             stake: &arr[0],
             vote: &arr[1],
@@ -2458,6 +2696,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_DELINQUENT_IX_ACCOUNT
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateDelinquentAccounts<'_, '_>,
@@ -2466,11 +2705,13 @@ pub fn deactivate_delinquent_invoke_with_program_id(
     let ix = deactivate_delinquent_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_invoke(
     accounts: DeactivateDelinquentAccounts<'_, '_>,
 ) -> ProgramResult {
     deactivate_delinquent_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeactivateDelinquentAccounts<'_, '_>,
@@ -2480,12 +2721,14 @@ pub fn deactivate_delinquent_invoke_signed_with_program_id(
     let ix = deactivate_delinquent_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_invoke_signed(
     accounts: DeactivateDelinquentAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     deactivate_delinquent_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_verify_account_keys(
     accounts: DeactivateDelinquentAccounts<'_, '_>,
     keys: DeactivateDelinquentKeys,
@@ -2501,6 +2744,7 @@ pub fn deactivate_delinquent_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_verify_writable_privileges<'me, 'info>(
     accounts: DeactivateDelinquentAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2511,6 +2755,7 @@ pub fn deactivate_delinquent_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deactivate_delinquent_verify_account_privileges<'me, 'info>(
     accounts: DeactivateDelinquentAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2518,7 +2763,10 @@ pub fn deactivate_delinquent_verify_account_privileges<'me, 'info>(
     Ok(())
 }
 pub const REDELEGATE_IX_DISCM: [u8; 4] = [15, 0, 0, 0];
-pub fn redelegate_ix_with_program_id(program_id: Pubkey, keys: RedelegateKeys) -> Instruction {
+pub fn redelegate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RedelegateKeys,
+) -> Instruction {
     let metas: [AccountMeta; REDELEGATE_IX_ACCOUNTS_LEN] = keys.into();
     Instruction::new_with_bincode(
         program_id,
@@ -2531,6 +2779,7 @@ pub fn redelegate_ix(keys: RedelegateKeys) -> Instruction {
 }
 pub const REDELEGATE_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RedelegateAccounts<'me, 'info> {
     ///The delegated stake account to be redelegated. The account must be fully activated and carry a balance greater than or equal to the minimum delegation amount plus rent exempt minimum
     pub stake: &'me AccountInfo<'info>,
@@ -2556,6 +2805,7 @@ pub struct RedelegateKeys {
     ///stake's stake authority
     pub stake_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RedelegateAccounts<'_, '_>> for RedelegateKeys {
     fn from(accounts: RedelegateAccounts) -> Self {
         Self {
@@ -2609,9 +2859,9 @@ impl From<[Pubkey; REDELEGATE_IX_ACCOUNTS_LEN]> for RedelegateKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RedelegateAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] {
     fn from(accounts: RedelegateAccounts<'_, 'info>) -> Self {
         [
             accounts.stake.clone(),
@@ -2622,9 +2872,9 @@ impl<'info> From<RedelegateAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]>
-    for RedelegateAccounts<'me, 'info>
-{
+for RedelegateAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             stake: &arr[0],
@@ -2635,6 +2885,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RedelegateAccounts<'_, '_>,
@@ -2643,9 +2894,11 @@ pub fn redelegate_invoke_with_program_id(
     let ix = redelegate_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_invoke(accounts: RedelegateAccounts<'_, '_>) -> ProgramResult {
     redelegate_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RedelegateAccounts<'_, '_>,
@@ -2655,12 +2908,14 @@ pub fn redelegate_invoke_signed_with_program_id(
     let ix = redelegate_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_invoke_signed(
     accounts: RedelegateAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     redelegate_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_verify_account_keys(
     accounts: RedelegateAccounts<'_, '_>,
     keys: RedelegateKeys,
@@ -2678,6 +2933,7 @@ pub fn redelegate_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_verify_writable_privileges<'me, 'info>(
     accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2688,6 +2944,7 @@ pub fn redelegate_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_verify_signer_privileges<'me, 'info>(
     accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2698,6 +2955,7 @@ pub fn redelegate_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn redelegate_verify_account_privileges<'me, 'info>(
     accounts: RedelegateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
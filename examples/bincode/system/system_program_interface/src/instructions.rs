@@ -1,11 +1,13 @@
-use serde::{Deserialize, Serialize};
+use serde::{Serialize, Deserialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SystemProgramProgramIx {
@@ -23,6 +25,7 @@ pub enum SystemProgramProgramIx {
     TransferWithSeed(TransferWithSeedIxArgs),
     UpgradeNonceAccount,
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -30,6 +33,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -51,11 +55,15 @@ pub fn create_account_ix_with_program_id(
         Vec::from(metas),
     )
 }
-pub fn create_account_ix(keys: CreateAccountKeys, args: CreateAccountIxArgs) -> Instruction {
+pub fn create_account_ix(
+    keys: CreateAccountKeys,
+    args: CreateAccountIxArgs,
+) -> Instruction {
     create_account_ix_with_program_id(crate::ID, keys, args)
 }
 pub const CREATE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateAccountAccounts<'me, 'info> {
     ///Funding account
     pub from: &'me AccountInfo<'info>,
@@ -69,6 +77,7 @@ pub struct CreateAccountKeys {
     ///The new account to be created
     pub to: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateAccountAccounts<'_, '_>> for CreateAccountKeys {
     fn from(accounts: CreateAccountAccounts) -> Self {
         Self {
@@ -101,21 +110,18 @@ impl From<[Pubkey; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]> for CreateAccountKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateAccountAccounts<'_, 'info>) -> Self {
         [accounts.from.clone(), accounts.to.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for CreateAccountAccounts<'me, 'info>
-{
+for CreateAccountAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            from: &arr[0],
-            to: &arr[1],
-        }
+        Self { from: &arr[0], to: &arr[1] }
     }
 }
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -124,6 +130,7 @@ pub struct CreateAccountIxArgs {
     pub space: u64,
     pub owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateAccountAccounts<'_, '_>,
@@ -133,12 +140,14 @@ pub fn create_account_invoke_with_program_id(
     let ix = create_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_invoke(
     accounts: CreateAccountAccounts<'_, '_>,
     args: CreateAccountIxArgs,
 ) -> ProgramResult {
     create_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateAccountAccounts<'_, '_>,
@@ -149,6 +158,7 @@ pub fn create_account_invoke_signed_with_program_id(
     let ix = create_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_invoke_signed(
     accounts: CreateAccountAccounts<'_, '_>,
     args: CreateAccountIxArgs,
@@ -156,17 +166,22 @@ pub fn create_account_invoke_signed(
 ) -> ProgramResult {
     create_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_verify_account_keys(
     accounts: CreateAccountAccounts<'_, '_>,
     keys: CreateAccountKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.from.key, &keys.from), (accounts.to.key, &keys.to)] {
+    for (actual, expected) in [
+        (accounts.from.key, &keys.from),
+        (accounts.to.key, &keys.to),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_verify_writable_privileges<'me, 'info>(
     accounts: CreateAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -177,6 +192,7 @@ pub fn create_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_verify_signer_privileges<'me, 'info>(
     accounts: CreateAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -187,6 +203,7 @@ pub fn create_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_verify_account_privileges<'me, 'info>(
     accounts: CreateAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -194,6 +211,14 @@ pub fn create_account_verify_account_privileges<'me, 'info>(
     create_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateAccountIxArgs {
+    const NAME: &'static str = "CreateAccount";
+    const DISCM: &'static [u8] = &CREATE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = CreateAccountKeys;
+    type Args = Self;
+}
 pub const ASSIGN_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
 pub fn assign_ix_with_program_id(
     program_id: Pubkey,
@@ -212,6 +237,7 @@ pub fn assign_ix(keys: AssignKeys, args: AssignIxArgs) -> Instruction {
 }
 pub const ASSIGN_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AssignAccounts<'me, 'info> {
     ///The system account to assign a new program owner to
     pub assign: &'me AccountInfo<'info>,
@@ -221,6 +247,7 @@ pub struct AssignKeys {
     ///The system account to assign a new program owner to
     pub assign: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AssignAccounts<'_, '_>> for AssignKeys {
     fn from(accounts: AssignAccounts) -> Self {
         Self {
@@ -230,11 +257,13 @@ impl From<AssignAccounts<'_, '_>> for AssignKeys {
 }
 impl From<AssignKeys> for [AccountMeta; ASSIGN_IX_ACCOUNTS_LEN] {
     fn from(keys: AssignKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.assign,
-            is_signer: true,
-            is_writable: true,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.assign,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
     }
 }
 impl From<[Pubkey; ASSIGN_IX_ACCOUNTS_LEN]> for AssignKeys {
@@ -242,14 +271,16 @@ impl From<[Pubkey; ASSIGN_IX_ACCOUNTS_LEN]> for AssignKeys {
         Self { assign: pubkeys[0] }
     }
 }
-impl<'info> From<AssignAccounts<'_, 'info>> for [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<AssignAccounts<'_, 'info>>
+for [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN] {
     fn from(accounts: AssignAccounts<'_, 'info>) -> Self {
         [accounts.assign.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN]>
-    for AssignAccounts<'me, 'info>
-{
+for AssignAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN]) -> Self {
         Self { assign: &arr[0] }
     }
@@ -258,6 +289,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ASSIGN_IX_ACCOUNTS_LEN]>
 pub struct AssignIxArgs {
     pub owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn assign_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AssignAccounts<'_, '_>,
@@ -267,9 +299,14 @@ pub fn assign_invoke_with_program_id(
     let ix = assign_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
-pub fn assign_invoke(accounts: AssignAccounts<'_, '_>, args: AssignIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn assign_invoke(
+    accounts: AssignAccounts<'_, '_>,
+    args: AssignIxArgs,
+) -> ProgramResult {
     assign_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AssignAccounts<'_, '_>,
@@ -280,6 +317,7 @@ pub fn assign_invoke_signed_with_program_id(
     let ix = assign_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_invoke_signed(
     accounts: AssignAccounts<'_, '_>,
     args: AssignIxArgs,
@@ -287,6 +325,7 @@ pub fn assign_invoke_signed(
 ) -> ProgramResult {
     assign_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_verify_account_keys(
     accounts: AssignAccounts<'_, '_>,
     keys: AssignKeys,
@@ -298,6 +337,7 @@ pub fn assign_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_verify_writable_privileges<'me, 'info>(
     accounts: AssignAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -308,6 +348,7 @@ pub fn assign_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_verify_signer_privileges<'me, 'info>(
     accounts: AssignAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -318,6 +359,7 @@ pub fn assign_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_verify_account_privileges<'me, 'info>(
     accounts: AssignAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -325,6 +367,14 @@ pub fn assign_verify_account_privileges<'me, 'info>(
     assign_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AssignIxArgs {
+    const NAME: &'static str = "Assign";
+    const DISCM: &'static [u8] = &ASSIGN_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ASSIGN_IX_ACCOUNTS_LEN;
+    type Keys = AssignKeys;
+    type Args = Self;
+}
 pub const TRANSFER_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
 pub fn transfer_ix_with_program_id(
     program_id: Pubkey,
@@ -343,6 +393,7 @@ pub fn transfer_ix(keys: TransferKeys, args: TransferIxArgs) -> Instruction {
 }
 pub const TRANSFER_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct TransferAccounts<'me, 'info> {
     ///Funding account
     pub from: &'me AccountInfo<'info>,
@@ -356,6 +407,7 @@ pub struct TransferKeys {
     ///Recipient account
     pub to: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<TransferAccounts<'_, '_>> for TransferKeys {
     fn from(accounts: TransferAccounts) -> Self {
         Self {
@@ -388,25 +440,25 @@ impl From<[Pubkey; TRANSFER_IX_ACCOUNTS_LEN]> for TransferKeys {
         }
     }
 }
-impl<'info> From<TransferAccounts<'_, 'info>> for [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<TransferAccounts<'_, 'info>>
+for [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN] {
     fn from(accounts: TransferAccounts<'_, 'info>) -> Self {
         [accounts.from.clone(), accounts.to.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN]>
-    for TransferAccounts<'me, 'info>
-{
+for TransferAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; TRANSFER_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            from: &arr[0],
-            to: &arr[1],
-        }
+        Self { from: &arr[0], to: &arr[1] }
     }
 }
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct TransferIxArgs {
     pub lamports: u64,
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_invoke_with_program_id(
     program_id: Pubkey,
     accounts: TransferAccounts<'_, '_>,
@@ -416,9 +468,14 @@ pub fn transfer_invoke_with_program_id(
     let ix = transfer_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
-pub fn transfer_invoke(accounts: TransferAccounts<'_, '_>, args: TransferIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn transfer_invoke(
+    accounts: TransferAccounts<'_, '_>,
+    args: TransferIxArgs,
+) -> ProgramResult {
     transfer_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: TransferAccounts<'_, '_>,
@@ -429,6 +486,7 @@ pub fn transfer_invoke_signed_with_program_id(
     let ix = transfer_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_invoke_signed(
     accounts: TransferAccounts<'_, '_>,
     args: TransferIxArgs,
@@ -436,17 +494,22 @@ pub fn transfer_invoke_signed(
 ) -> ProgramResult {
     transfer_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_verify_account_keys(
     accounts: TransferAccounts<'_, '_>,
     keys: TransferKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [(accounts.from.key, &keys.from), (accounts.to.key, &keys.to)] {
+    for (actual, expected) in [
+        (accounts.from.key, &keys.from),
+        (accounts.to.key, &keys.to),
+    ] {
         if actual != expected {
             return Err((*actual, *expected));
         }
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_verify_writable_privileges<'me, 'info>(
     accounts: TransferAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -457,6 +520,7 @@ pub fn transfer_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_verify_signer_privileges<'me, 'info>(
     accounts: TransferAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -467,6 +531,7 @@ pub fn transfer_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_verify_account_privileges<'me, 'info>(
     accounts: TransferAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -474,6 +539,14 @@ pub fn transfer_verify_account_privileges<'me, 'info>(
     transfer_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for TransferIxArgs {
+    const NAME: &'static str = "Transfer";
+    const DISCM: &'static [u8] = &TRANSFER_IX_DISCM;
+    const ACCOUNTS_LEN: usize = TRANSFER_IX_ACCOUNTS_LEN;
+    type Keys = TransferKeys;
+    type Args = Self;
+}
 pub const CREATE_ACCOUNT_WITH_SEED_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
 pub fn create_account_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -495,6 +568,7 @@ pub fn create_account_with_seed_ix(
 }
 pub const CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateAccountWithSeedAccounts<'me, 'info> {
     ///Funding account
     pub from: &'me AccountInfo<'info>,
@@ -512,6 +586,7 @@ pub struct CreateAccountWithSeedKeys {
     ///Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
     pub base: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateAccountWithSeedAccounts<'_, '_>> for CreateAccountWithSeedKeys {
     fn from(accounts: CreateAccountWithSeedAccounts) -> Self {
         Self {
@@ -521,7 +596,8 @@ impl From<CreateAccountWithSeedAccounts<'_, '_>> for CreateAccountWithSeedKeys {
         }
     }
 }
-impl From<CreateAccountWithSeedKeys> for [AccountMeta; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
+impl From<CreateAccountWithSeedKeys>
+for [AccountMeta; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(keys: CreateAccountWithSeedKeys) -> Self {
         [
             AccountMeta {
@@ -542,7 +618,8 @@ impl From<CreateAccountWithSeedKeys> for [AccountMeta; CREATE_ACCOUNT_WITH_SEED_
         ]
     }
 }
-impl From<[Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]> for CreateAccountWithSeedKeys {
+impl From<[Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]>
+for CreateAccountWithSeedKeys {
     fn from(pubkeys: [Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             from: pubkeys[0],
@@ -551,21 +628,22 @@ impl From<[Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]> for CreateAccountW
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateAccountWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateAccountWithSeedAccounts<'_, 'info>) -> Self {
-        [
-            accounts.from.clone(),
-            accounts.to.clone(),
-            accounts.base.clone(),
-        ]
-    }
-}
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for CreateAccountWithSeedAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        [accounts.from.clone(), accounts.to.clone(), accounts.base.clone()]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]>
+for CreateAccountWithSeedAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             from: &arr[0],
             to: &arr[1],
@@ -581,6 +659,7 @@ pub struct CreateAccountWithSeedIxArgs {
     pub space: u64,
     pub owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateAccountWithSeedAccounts<'_, '_>,
@@ -590,12 +669,14 @@ pub fn create_account_with_seed_invoke_with_program_id(
     let ix = create_account_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_invoke(
     accounts: CreateAccountWithSeedAccounts<'_, '_>,
     args: CreateAccountWithSeedIxArgs,
 ) -> ProgramResult {
     create_account_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateAccountWithSeedAccounts<'_, '_>,
@@ -606,13 +687,20 @@ pub fn create_account_with_seed_invoke_signed_with_program_id(
     let ix = create_account_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_invoke_signed(
     accounts: CreateAccountWithSeedAccounts<'_, '_>,
     args: CreateAccountWithSeedIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    create_account_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    create_account_with_seed_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_verify_account_keys(
     accounts: CreateAccountWithSeedAccounts<'_, '_>,
     keys: CreateAccountWithSeedKeys,
@@ -628,6 +716,7 @@ pub fn create_account_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: CreateAccountWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -638,6 +727,7 @@ pub fn create_account_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: CreateAccountWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -648,6 +738,7 @@ pub fn create_account_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_account_with_seed_verify_account_privileges<'me, 'info>(
     accounts: CreateAccountWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -655,6 +746,14 @@ pub fn create_account_with_seed_verify_account_privileges<'me, 'info>(
     create_account_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateAccountWithSeedIxArgs {
+    const NAME: &'static str = "CreateAccountWithSeed";
+    const DISCM: &'static [u8] = &CREATE_ACCOUNT_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = CreateAccountWithSeedKeys;
+    type Args = Self;
+}
 pub const ADVANCE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
 pub fn advance_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -672,6 +771,7 @@ pub fn advance_nonce_account_ix(keys: AdvanceNonceAccountKeys) -> Instruction {
 }
 pub const ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AdvanceNonceAccountAccounts<'me, 'info> {
     ///Nonce account
     pub nonce: &'me AccountInfo<'info>,
@@ -689,6 +789,7 @@ pub struct AdvanceNonceAccountKeys {
     ///nonce's authority
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AdvanceNonceAccountAccounts<'_, '_>> for AdvanceNonceAccountKeys {
     fn from(accounts: AdvanceNonceAccountAccounts) -> Self {
         Self {
@@ -698,7 +799,8 @@ impl From<AdvanceNonceAccountAccounts<'_, '_>> for AdvanceNonceAccountKeys {
         }
     }
 }
-impl From<AdvanceNonceAccountKeys> for [AccountMeta; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<AdvanceNonceAccountKeys>
+for [AccountMeta; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: AdvanceNonceAccountKeys) -> Self {
         [
             AccountMeta {
@@ -728,9 +830,9 @@ impl From<[Pubkey; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for AdvanceNonceAccou
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AdvanceNonceAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: AdvanceNonceAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.nonce.clone(),
@@ -739,10 +841,12 @@ impl<'info> From<AdvanceNonceAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for AdvanceNonceAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for AdvanceNonceAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             nonce: &arr[0],
             recent_blockhashes: &arr[1],
@@ -750,6 +854,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNT
         }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
@@ -758,11 +863,13 @@ pub fn advance_nonce_account_invoke_with_program_id(
     let ix = advance_nonce_account_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_invoke(
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     advance_nonce_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
@@ -772,12 +879,14 @@ pub fn advance_nonce_account_invoke_signed_with_program_id(
     let ix = advance_nonce_account_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_invoke_signed(
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     advance_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_verify_account_keys(
     accounts: AdvanceNonceAccountAccounts<'_, '_>,
     keys: AdvanceNonceAccountKeys,
@@ -793,6 +902,7 @@ pub fn advance_nonce_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_verify_writable_privileges<'me, 'info>(
     accounts: AdvanceNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -803,6 +913,7 @@ pub fn advance_nonce_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_verify_signer_privileges<'me, 'info>(
     accounts: AdvanceNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -813,6 +924,7 @@ pub fn advance_nonce_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn advance_nonce_account_verify_account_privileges<'me, 'info>(
     accounts: AdvanceNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -841,6 +953,7 @@ pub fn withdraw_nonce_account_ix(
 }
 pub const WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct WithdrawNonceAccountAccounts<'me, 'info> {
     ///Nonce account
     pub nonce: &'me AccountInfo<'info>,
@@ -866,6 +979,7 @@ pub struct WithdrawNonceAccountKeys {
     ///nonce's authority
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<WithdrawNonceAccountAccounts<'_, '_>> for WithdrawNonceAccountKeys {
     fn from(accounts: WithdrawNonceAccountAccounts) -> Self {
         Self {
@@ -877,7 +991,8 @@ impl From<WithdrawNonceAccountAccounts<'_, '_>> for WithdrawNonceAccountKeys {
         }
     }
 }
-impl From<WithdrawNonceAccountKeys> for [AccountMeta; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<WithdrawNonceAccountKeys>
+for [AccountMeta; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: WithdrawNonceAccountKeys) -> Self {
         [
             AccountMeta {
@@ -908,7 +1023,8 @@ impl From<WithdrawNonceAccountKeys> for [AccountMeta; WITHDRAW_NONCE_ACCOUNT_IX_
         ]
     }
 }
-impl From<[Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for WithdrawNonceAccountKeys {
+impl From<[Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for WithdrawNonceAccountKeys {
     fn from(pubkeys: [Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             nonce: pubkeys[0],
@@ -919,9 +1035,9 @@ impl From<[Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for WithdrawNonceAcc
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<WithdrawNonceAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: WithdrawNonceAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.nonce.clone(),
@@ -932,10 +1048,12 @@ impl<'info> From<WithdrawNonceAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for WithdrawNonceAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for WithdrawNonceAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             nonce: &arr[0],
             to: &arr[1],
@@ -949,6 +1067,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUN
 pub struct WithdrawNonceAccountIxArgs {
     pub lamports: u64,
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawNonceAccountAccounts<'_, '_>,
@@ -958,12 +1077,14 @@ pub fn withdraw_nonce_account_invoke_with_program_id(
     let ix = withdraw_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_invoke(
     accounts: WithdrawNonceAccountAccounts<'_, '_>,
     args: WithdrawNonceAccountIxArgs,
 ) -> ProgramResult {
     withdraw_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawNonceAccountAccounts<'_, '_>,
@@ -974,13 +1095,20 @@ pub fn withdraw_nonce_account_invoke_signed_with_program_id(
     let ix = withdraw_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_invoke_signed(
     accounts: WithdrawNonceAccountAccounts<'_, '_>,
     args: WithdrawNonceAccountIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    withdraw_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    withdraw_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_verify_account_keys(
     accounts: WithdrawNonceAccountAccounts<'_, '_>,
     keys: WithdrawNonceAccountKeys,
@@ -998,6 +1126,7 @@ pub fn withdraw_nonce_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_verify_writable_privileges<'me, 'info>(
     accounts: WithdrawNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1008,6 +1137,7 @@ pub fn withdraw_nonce_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_verify_signer_privileges<'me, 'info>(
     accounts: WithdrawNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1018,6 +1148,7 @@ pub fn withdraw_nonce_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_nonce_account_verify_account_privileges<'me, 'info>(
     accounts: WithdrawNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1025,6 +1156,14 @@ pub fn withdraw_nonce_account_verify_account_privileges<'me, 'info>(
     withdraw_nonce_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for WithdrawNonceAccountIxArgs {
+    const NAME: &'static str = "WithdrawNonceAccount";
+    const DISCM: &'static [u8] = &WITHDRAW_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = WithdrawNonceAccountKeys;
+    type Args = Self;
+}
 pub const INITIALIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
 pub fn initialize_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -1046,6 +1185,7 @@ pub fn initialize_nonce_account_ix(
 }
 pub const INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitializeNonceAccountAccounts<'me, 'info> {
     ///Nonce account
     pub nonce: &'me AccountInfo<'info>,
@@ -1063,6 +1203,7 @@ pub struct InitializeNonceAccountKeys {
     ///Rent sysvar
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitializeNonceAccountAccounts<'_, '_>> for InitializeNonceAccountKeys {
     fn from(accounts: InitializeNonceAccountAccounts) -> Self {
         Self {
@@ -1072,7 +1213,8 @@ impl From<InitializeNonceAccountAccounts<'_, '_>> for InitializeNonceAccountKeys
         }
     }
 }
-impl From<InitializeNonceAccountKeys> for [AccountMeta; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<InitializeNonceAccountKeys>
+for [AccountMeta; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: InitializeNonceAccountKeys) -> Self {
         [
             AccountMeta {
@@ -1093,7 +1235,8 @@ impl From<InitializeNonceAccountKeys> for [AccountMeta; INITIALIZE_NONCE_ACCOUNT
         ]
     }
 }
-impl From<[Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for InitializeNonceAccountKeys {
+impl From<[Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for InitializeNonceAccountKeys {
     fn from(pubkeys: [Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             nonce: pubkeys[0],
@@ -1102,9 +1245,9 @@ impl From<[Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for InitializeNonc
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitializeNonceAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitializeNonceAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.nonce.clone(),
@@ -1113,10 +1256,15 @@ impl<'info> From<InitializeNonceAccountAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for InitializeNonceAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for InitializeNonceAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             nonce: &arr[0],
             recent_blockhashes: &arr[1],
@@ -1128,6 +1276,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_NONCE_ACCOUNT_IX_ACCO
 pub struct InitializeNonceAccountIxArgs {
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeNonceAccountAccounts<'_, '_>,
@@ -1137,12 +1286,14 @@ pub fn initialize_nonce_account_invoke_with_program_id(
     let ix = initialize_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_invoke(
     accounts: InitializeNonceAccountAccounts<'_, '_>,
     args: InitializeNonceAccountIxArgs,
 ) -> ProgramResult {
     initialize_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitializeNonceAccountAccounts<'_, '_>,
@@ -1153,13 +1304,20 @@ pub fn initialize_nonce_account_invoke_signed_with_program_id(
     let ix = initialize_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_invoke_signed(
     accounts: InitializeNonceAccountAccounts<'_, '_>,
     args: InitializeNonceAccountIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    initialize_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    initialize_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_verify_account_keys(
     accounts: InitializeNonceAccountAccounts<'_, '_>,
     keys: InitializeNonceAccountKeys,
@@ -1175,6 +1333,7 @@ pub fn initialize_nonce_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_verify_writable_privileges<'me, 'info>(
     accounts: InitializeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1185,12 +1344,21 @@ pub fn initialize_nonce_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_nonce_account_verify_account_privileges<'me, 'info>(
     accounts: InitializeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     initialize_nonce_account_verify_writable_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitializeNonceAccountIxArgs {
+    const NAME: &'static str = "InitializeNonceAccount";
+    const DISCM: &'static [u8] = &INITIALIZE_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = InitializeNonceAccountKeys;
+    type Args = Self;
+}
 pub const AUTHORIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
 pub fn authorize_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -1212,6 +1380,7 @@ pub fn authorize_nonce_account_ix(
 }
 pub const AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AuthorizeNonceAccountAccounts<'me, 'info> {
     ///Nonce account
     pub nonce: &'me AccountInfo<'info>,
@@ -1225,6 +1394,7 @@ pub struct AuthorizeNonceAccountKeys {
     ///nonce's authority
     pub authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AuthorizeNonceAccountAccounts<'_, '_>> for AuthorizeNonceAccountKeys {
     fn from(accounts: AuthorizeNonceAccountAccounts) -> Self {
         Self {
@@ -1233,7 +1403,8 @@ impl From<AuthorizeNonceAccountAccounts<'_, '_>> for AuthorizeNonceAccountKeys {
         }
     }
 }
-impl From<AuthorizeNonceAccountKeys> for [AccountMeta; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<AuthorizeNonceAccountKeys>
+for [AccountMeta; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: AuthorizeNonceAccountKeys) -> Self {
         [
             AccountMeta {
@@ -1249,7 +1420,8 @@ impl From<AuthorizeNonceAccountKeys> for [AccountMeta; AUTHORIZE_NONCE_ACCOUNT_I
         ]
     }
 }
-impl From<[Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for AuthorizeNonceAccountKeys {
+impl From<[Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for AuthorizeNonceAccountKeys {
     fn from(pubkeys: [Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             nonce: pubkeys[0],
@@ -1257,17 +1429,19 @@ impl From<[Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for AuthorizeNonceA
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AuthorizeNonceAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: AuthorizeNonceAccountAccounts<'_, 'info>) -> Self {
         [accounts.nonce.clone(), accounts.authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for AuthorizeNonceAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for AuthorizeNonceAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             nonce: &arr[0],
             authority: &arr[1],
@@ -1278,6 +1452,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOU
 pub struct AuthorizeNonceAccountIxArgs {
     pub new_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeNonceAccountAccounts<'_, '_>,
@@ -1287,12 +1462,14 @@ pub fn authorize_nonce_account_invoke_with_program_id(
     let ix = authorize_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_invoke(
     accounts: AuthorizeNonceAccountAccounts<'_, '_>,
     args: AuthorizeNonceAccountIxArgs,
 ) -> ProgramResult {
     authorize_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AuthorizeNonceAccountAccounts<'_, '_>,
@@ -1303,13 +1480,20 @@ pub fn authorize_nonce_account_invoke_signed_with_program_id(
     let ix = authorize_nonce_account_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_invoke_signed(
     accounts: AuthorizeNonceAccountAccounts<'_, '_>,
     args: AuthorizeNonceAccountIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    authorize_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    authorize_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_verify_account_keys(
     accounts: AuthorizeNonceAccountAccounts<'_, '_>,
     keys: AuthorizeNonceAccountKeys,
@@ -1324,6 +1508,7 @@ pub fn authorize_nonce_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_verify_writable_privileges<'me, 'info>(
     accounts: AuthorizeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1334,6 +1519,7 @@ pub fn authorize_nonce_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_verify_signer_privileges<'me, 'info>(
     accounts: AuthorizeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1344,6 +1530,7 @@ pub fn authorize_nonce_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn authorize_nonce_account_verify_account_privileges<'me, 'info>(
     accounts: AuthorizeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1351,6 +1538,14 @@ pub fn authorize_nonce_account_verify_account_privileges<'me, 'info>(
     authorize_nonce_account_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeNonceAccountIxArgs {
+    const NAME: &'static str = "AuthorizeNonceAccount";
+    const DISCM: &'static [u8] = &AUTHORIZE_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeNonceAccountKeys;
+    type Args = Self;
+}
 pub const ALLOCATE_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
 pub fn allocate_ix_with_program_id(
     program_id: Pubkey,
@@ -1369,6 +1564,7 @@ pub fn allocate_ix(keys: AllocateKeys, args: AllocateIxArgs) -> Instruction {
 }
 pub const ALLOCATE_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AllocateAccounts<'me, 'info> {
     ///The new account to allocate space for
     pub allocate: &'me AccountInfo<'info>,
@@ -1378,6 +1574,7 @@ pub struct AllocateKeys {
     ///The new account to allocate space for
     pub allocate: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AllocateAccounts<'_, '_>> for AllocateKeys {
     fn from(accounts: AllocateAccounts) -> Self {
         Self {
@@ -1387,28 +1584,30 @@ impl From<AllocateAccounts<'_, '_>> for AllocateKeys {
 }
 impl From<AllocateKeys> for [AccountMeta; ALLOCATE_IX_ACCOUNTS_LEN] {
     fn from(keys: AllocateKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.allocate,
-            is_signer: true,
-            is_writable: true,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.allocate,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
     }
 }
 impl From<[Pubkey; ALLOCATE_IX_ACCOUNTS_LEN]> for AllocateKeys {
     fn from(pubkeys: [Pubkey; ALLOCATE_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            allocate: pubkeys[0],
-        }
+        Self { allocate: pubkeys[0] }
     }
 }
-impl<'info> From<AllocateAccounts<'_, 'info>> for [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<AllocateAccounts<'_, 'info>>
+for [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN] {
     fn from(accounts: AllocateAccounts<'_, 'info>) -> Self {
         [accounts.allocate.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN]>
-    for AllocateAccounts<'me, 'info>
-{
+for AllocateAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN]) -> Self {
         Self { allocate: &arr[0] }
     }
@@ -1417,6 +1616,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ALLOCATE_IX_ACCOUNTS_LEN]>
 pub struct AllocateIxArgs {
     pub space: u64,
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AllocateAccounts<'_, '_>,
@@ -1426,9 +1626,14 @@ pub fn allocate_invoke_with_program_id(
     let ix = allocate_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
-pub fn allocate_invoke(accounts: AllocateAccounts<'_, '_>, args: AllocateIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn allocate_invoke(
+    accounts: AllocateAccounts<'_, '_>,
+    args: AllocateIxArgs,
+) -> ProgramResult {
     allocate_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AllocateAccounts<'_, '_>,
@@ -1439,6 +1644,7 @@ pub fn allocate_invoke_signed_with_program_id(
     let ix = allocate_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_invoke_signed(
     accounts: AllocateAccounts<'_, '_>,
     args: AllocateIxArgs,
@@ -1446,6 +1652,7 @@ pub fn allocate_invoke_signed(
 ) -> ProgramResult {
     allocate_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_verify_account_keys(
     accounts: AllocateAccounts<'_, '_>,
     keys: AllocateKeys,
@@ -1457,6 +1664,7 @@ pub fn allocate_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_verify_writable_privileges<'me, 'info>(
     accounts: AllocateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1467,6 +1675,7 @@ pub fn allocate_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_verify_signer_privileges<'me, 'info>(
     accounts: AllocateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1477,6 +1686,7 @@ pub fn allocate_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_verify_account_privileges<'me, 'info>(
     accounts: AllocateAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1484,6 +1694,14 @@ pub fn allocate_verify_account_privileges<'me, 'info>(
     allocate_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AllocateIxArgs {
+    const NAME: &'static str = "Allocate";
+    const DISCM: &'static [u8] = &ALLOCATE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ALLOCATE_IX_ACCOUNTS_LEN;
+    type Keys = AllocateKeys;
+    type Args = Self;
+}
 pub const ALLOCATE_WITH_SEED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
 pub fn allocate_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1505,6 +1723,7 @@ pub fn allocate_with_seed_ix(
 }
 pub const ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AllocateWithSeedAccounts<'me, 'info> {
     ///The new account to allocate space for
     pub allocate: &'me AccountInfo<'info>,
@@ -1518,6 +1737,7 @@ pub struct AllocateWithSeedKeys {
     ///Base account
     pub base: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AllocateWithSeedAccounts<'_, '_>> for AllocateWithSeedKeys {
     fn from(accounts: AllocateWithSeedAccounts) -> Self {
         Self {
@@ -1550,16 +1770,16 @@ impl From<[Pubkey; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]> for AllocateWithSeedKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AllocateWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: AllocateWithSeedAccounts<'_, 'info>) -> Self {
         [accounts.allocate.clone(), accounts.base.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for AllocateWithSeedAccounts<'me, 'info>
-{
+for AllocateWithSeedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             allocate: &arr[0],
@@ -1574,6 +1794,7 @@ pub struct AllocateWithSeedIxArgs {
     pub space: u64,
     pub owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AllocateWithSeedAccounts<'_, '_>,
@@ -1583,12 +1804,14 @@ pub fn allocate_with_seed_invoke_with_program_id(
     let ix = allocate_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_invoke(
     accounts: AllocateWithSeedAccounts<'_, '_>,
     args: AllocateWithSeedIxArgs,
 ) -> ProgramResult {
     allocate_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AllocateWithSeedAccounts<'_, '_>,
@@ -1599,6 +1822,7 @@ pub fn allocate_with_seed_invoke_signed_with_program_id(
     let ix = allocate_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_invoke_signed(
     accounts: AllocateWithSeedAccounts<'_, '_>,
     args: AllocateWithSeedIxArgs,
@@ -1606,6 +1830,7 @@ pub fn allocate_with_seed_invoke_signed(
 ) -> ProgramResult {
     allocate_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_verify_account_keys(
     accounts: AllocateWithSeedAccounts<'_, '_>,
     keys: AllocateWithSeedKeys,
@@ -1620,6 +1845,7 @@ pub fn allocate_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: AllocateWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1630,6 +1856,7 @@ pub fn allocate_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: AllocateWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1640,6 +1867,7 @@ pub fn allocate_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn allocate_with_seed_verify_account_privileges<'me, 'info>(
     accounts: AllocateWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1647,6 +1875,14 @@ pub fn allocate_with_seed_verify_account_privileges<'me, 'info>(
     allocate_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AllocateWithSeedIxArgs {
+    const NAME: &'static str = "AllocateWithSeed";
+    const DISCM: &'static [u8] = &ALLOCATE_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AllocateWithSeedKeys;
+    type Args = Self;
+}
 pub const ASSIGN_WITH_SEED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
 pub fn assign_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1660,11 +1896,15 @@ pub fn assign_with_seed_ix_with_program_id(
         Vec::from(metas),
     )
 }
-pub fn assign_with_seed_ix(keys: AssignWithSeedKeys, args: AssignWithSeedIxArgs) -> Instruction {
+pub fn assign_with_seed_ix(
+    keys: AssignWithSeedKeys,
+    args: AssignWithSeedIxArgs,
+) -> Instruction {
     assign_with_seed_ix_with_program_id(crate::ID, keys, args)
 }
 pub const ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct AssignWithSeedAccounts<'me, 'info> {
     ///The system account to assign a new program owner to
     pub assign: &'me AccountInfo<'info>,
@@ -1678,6 +1918,7 @@ pub struct AssignWithSeedKeys {
     ///Base account
     pub base: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<AssignWithSeedAccounts<'_, '_>> for AssignWithSeedKeys {
     fn from(accounts: AssignWithSeedAccounts) -> Self {
         Self {
@@ -1710,16 +1951,16 @@ impl From<[Pubkey; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]> for AssignWithSeedKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<AssignWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: AssignWithSeedAccounts<'_, 'info>) -> Self {
         [accounts.assign.clone(), accounts.base.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for AssignWithSeedAccounts<'me, 'info>
-{
+for AssignWithSeedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             assign: &arr[0],
@@ -1733,6 +1974,7 @@ pub struct AssignWithSeedIxArgs {
     pub seed: String,
     pub owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: AssignWithSeedAccounts<'_, '_>,
@@ -1742,12 +1984,14 @@ pub fn assign_with_seed_invoke_with_program_id(
     let ix = assign_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_invoke(
     accounts: AssignWithSeedAccounts<'_, '_>,
     args: AssignWithSeedIxArgs,
 ) -> ProgramResult {
     assign_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: AssignWithSeedAccounts<'_, '_>,
@@ -1758,6 +2002,7 @@ pub fn assign_with_seed_invoke_signed_with_program_id(
     let ix = assign_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_invoke_signed(
     accounts: AssignWithSeedAccounts<'_, '_>,
     args: AssignWithSeedIxArgs,
@@ -1765,6 +2010,7 @@ pub fn assign_with_seed_invoke_signed(
 ) -> ProgramResult {
     assign_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_verify_account_keys(
     accounts: AssignWithSeedAccounts<'_, '_>,
     keys: AssignWithSeedKeys,
@@ -1779,6 +2025,7 @@ pub fn assign_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: AssignWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1789,6 +2036,7 @@ pub fn assign_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: AssignWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1799,6 +2047,7 @@ pub fn assign_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn assign_with_seed_verify_account_privileges<'me, 'info>(
     accounts: AssignWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1806,6 +2055,14 @@ pub fn assign_with_seed_verify_account_privileges<'me, 'info>(
     assign_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AssignWithSeedIxArgs {
+    const NAME: &'static str = "AssignWithSeed";
+    const DISCM: &'static [u8] = &ASSIGN_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AssignWithSeedKeys;
+    type Args = Self;
+}
 pub const TRANSFER_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
 pub fn transfer_with_seed_ix_with_program_id(
     program_id: Pubkey,
@@ -1827,6 +2084,7 @@ pub fn transfer_with_seed_ix(
 }
 pub const TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct TransferWithSeedAccounts<'me, 'info> {
     ///Funding account
     pub from: &'me AccountInfo<'info>,
@@ -1844,6 +2102,7 @@ pub struct TransferWithSeedKeys {
     ///Recipient account
     pub to: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<TransferWithSeedAccounts<'_, '_>> for TransferWithSeedKeys {
     fn from(accounts: TransferWithSeedAccounts) -> Self {
         Self {
@@ -1883,20 +2142,16 @@ impl From<[Pubkey; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]> for TransferWithSeedKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<TransferWithSeedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] {
     fn from(accounts: TransferWithSeedAccounts<'_, 'info>) -> Self {
-        [
-            accounts.from.clone(),
-            accounts.base.clone(),
-            accounts.to.clone(),
-        ]
+        [accounts.from.clone(), accounts.base.clone(), accounts.to.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]>
-    for TransferWithSeedAccounts<'me, 'info>
-{
+for TransferWithSeedAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             from: &arr[0],
@@ -1911,6 +2166,7 @@ pub struct TransferWithSeedIxArgs {
     pub from_seed: String,
     pub from_owner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_invoke_with_program_id(
     program_id: Pubkey,
     accounts: TransferWithSeedAccounts<'_, '_>,
@@ -1920,12 +2176,14 @@ pub fn transfer_with_seed_invoke_with_program_id(
     let ix = transfer_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_invoke(
     accounts: TransferWithSeedAccounts<'_, '_>,
     args: TransferWithSeedIxArgs,
 ) -> ProgramResult {
     transfer_with_seed_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: TransferWithSeedAccounts<'_, '_>,
@@ -1936,6 +2194,7 @@ pub fn transfer_with_seed_invoke_signed_with_program_id(
     let ix = transfer_with_seed_ix_with_program_id(program_id, keys, args);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_invoke_signed(
     accounts: TransferWithSeedAccounts<'_, '_>,
     args: TransferWithSeedIxArgs,
@@ -1943,6 +2202,7 @@ pub fn transfer_with_seed_invoke_signed(
 ) -> ProgramResult {
     transfer_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_verify_account_keys(
     accounts: TransferWithSeedAccounts<'_, '_>,
     keys: TransferWithSeedKeys,
@@ -1958,6 +2218,7 @@ pub fn transfer_with_seed_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_verify_writable_privileges<'me, 'info>(
     accounts: TransferWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1968,6 +2229,7 @@ pub fn transfer_with_seed_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_verify_signer_privileges<'me, 'info>(
     accounts: TransferWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1978,6 +2240,7 @@ pub fn transfer_with_seed_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn transfer_with_seed_verify_account_privileges<'me, 'info>(
     accounts: TransferWithSeedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1985,6 +2248,14 @@ pub fn transfer_with_seed_verify_account_privileges<'me, 'info>(
     transfer_with_seed_verify_signer_privileges(accounts)?;
     Ok(())
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for TransferWithSeedIxArgs {
+    const NAME: &'static str = "TransferWithSeed";
+    const DISCM: &'static [u8] = &TRANSFER_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = TransferWithSeedKeys;
+    type Args = Self;
+}
 pub const UPGRADE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
 pub fn upgrade_nonce_account_ix_with_program_id(
     program_id: Pubkey,
@@ -2002,6 +2273,7 @@ pub fn upgrade_nonce_account_ix(keys: UpgradeNonceAccountKeys) -> Instruction {
 }
 pub const UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpgradeNonceAccountAccounts<'me, 'info> {
     ///Nonce account
     pub nonce: &'me AccountInfo<'info>,
@@ -2011,20 +2283,22 @@ pub struct UpgradeNonceAccountKeys {
     ///Nonce account
     pub nonce: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpgradeNonceAccountAccounts<'_, '_>> for UpgradeNonceAccountKeys {
     fn from(accounts: UpgradeNonceAccountAccounts) -> Self {
-        Self {
-            nonce: *accounts.nonce.key,
-        }
+        Self { nonce: *accounts.nonce.key }
     }
 }
-impl From<UpgradeNonceAccountKeys> for [AccountMeta; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<UpgradeNonceAccountKeys>
+for [AccountMeta; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: UpgradeNonceAccountKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.nonce,
-            is_signer: false,
-            is_writable: true,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
     }
 }
 impl From<[Pubkey; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for UpgradeNonceAccountKeys {
@@ -2032,20 +2306,23 @@ impl From<[Pubkey; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for UpgradeNonceAccou
         Self { nonce: pubkeys[0] }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpgradeNonceAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpgradeNonceAccountAccounts<'_, 'info>) -> Self {
         [accounts.nonce.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for UpgradeNonceAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for UpgradeNonceAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self { nonce: &arr[0] }
     }
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
@@ -2054,11 +2331,13 @@ pub fn upgrade_nonce_account_invoke_with_program_id(
     let ix = upgrade_nonce_account_ix_with_program_id(program_id, keys);
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_invoke(
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     upgrade_nonce_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
@@ -2068,12 +2347,14 @@ pub fn upgrade_nonce_account_invoke_signed_with_program_id(
     let ix = upgrade_nonce_account_ix_with_program_id(program_id, keys);
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_invoke_signed(
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     upgrade_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_verify_account_keys(
     accounts: UpgradeNonceAccountAccounts<'_, '_>,
     keys: UpgradeNonceAccountKeys,
@@ -2085,6 +2366,7 @@ pub fn upgrade_nonce_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_verify_writable_privileges<'me, 'info>(
     accounts: UpgradeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2095,6 +2377,7 @@ pub fn upgrade_nonce_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn upgrade_nonce_account_verify_account_privileges<'me, 'info>(
     accounts: UpgradeNonceAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -0,0 +1,404 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use crate::*;
+use solana_program::pubkey::Pubkey;
+pub const FEE_ACCOUNT_DISCM: [u8; 8] = [24, 55, 150, 250, 168, 27, 101, 178];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fee {
+    pub fee: FeeEnum,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeAccount(pub Fee);
+impl FeeAccount {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != FEE_ACCOUNT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        FEE_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Fee::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&FEE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, FEE_ACCOUNT_DISCM.to_vec()))
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for Fee {
+    const DISCRIMINATOR: &'static [u8] = &FEE_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for Fee {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for Fee {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&FEE_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for Fee {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < FEE_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&FEE_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(FEE_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Fee {
+    const NAME: &'static str = "Fee";
+    const DISCM: &'static [u8] = &FEE_ACCOUNT_DISCM;
+}
+pub const POOL_ACCOUNT_DISCM: [u8; 8] = [241, 154, 109, 4, 17, 177, 109, 188];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pool {
+    pub fee_authority: Pubkey,
+    pub lp_mint: Pubkey,
+    pub incoming_stake: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolAccount(pub Pool);
+impl PoolAccount {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != POOL_ACCOUNT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        POOL_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(Pool::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&POOL_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, POOL_ACCOUNT_DISCM.to_vec()))
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for Pool {
+    const DISCRIMINATOR: &'static [u8] = &POOL_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for Pool {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for Pool {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&POOL_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for Pool {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < POOL_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&POOL_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(POOL_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Pool {
+    const NAME: &'static str = "Pool";
+    const DISCM: &'static [u8] = &POOL_ACCOUNT_DISCM;
+}
+pub const PROTOCOL_FEE_ACCOUNT_DISCM: [u8; 8] = [121, 127, 98, 139, 72, 110, 44, 118];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProtocolFee {
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub fee_ratio: Rational,
+    pub referrer_fee_ratio: Rational,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProtocolFeeAccount(pub ProtocolFee);
+impl ProtocolFeeAccount {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != PROTOCOL_FEE_ACCOUNT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PROTOCOL_FEE_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ProtocolFee::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&PROTOCOL_FEE_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, PROTOCOL_FEE_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ProtocolFee {
+    const DISCRIMINATOR: &'static [u8] = &PROTOCOL_FEE_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for ProtocolFee {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for ProtocolFee {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&PROTOCOL_FEE_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for ProtocolFee {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < PROTOCOL_FEE_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&PROTOCOL_FEE_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(PROTOCOL_FEE_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ProtocolFee {
+    const NAME: &'static str = "ProtocolFee";
+    const DISCM: &'static [u8] = &PROTOCOL_FEE_ACCOUNT_DISCM;
+}
+pub const STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM: [u8; 8] = [
+    144, 205, 183, 241, 3, 250, 208, 215,
+];
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StakeAccountRecord {
+    pub lamports_at_creation: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeAccountRecordAccount(pub StakeAccountRecord);
+impl StakeAccountRecordAccount {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        use borsh::io::Read;
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(StakeAccountRecord::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+    /// `getProgramAccounts` filter that only matches accounts of this type
+    #[cfg(feature = "client")]
+    pub fn rpc_filter() -> RpcFilterType {
+        RpcFilterType::Memcmp(
+            Memcmp::new_raw_bytes(0, STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM.to_vec()),
+        )
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for StakeAccountRecord {
+    const DISCRIMINATOR: &'static [u8] = &STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Owner for StakeAccountRecord {
+    fn owner() -> anchor_lang::prelude::Pubkey {
+        crate::ID
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountSerialize for StakeAccountRecord {
+    fn try_serialize<W: borsh::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> anchor_lang::Result<()> {
+        writer
+            .write_all(&STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        BorshSerialize::serialize(self, writer)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AccountDeserialize for StakeAccountRecord {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM.len() {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into(),
+            );
+        }
+        if !buf.starts_with(&STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM) {
+            return Err(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into(),
+            );
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        *buf = buf
+            .get(STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM.len()..)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+        BorshDeserialize::deserialize(buf)
+            .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for StakeAccountRecord {
+    const NAME: &'static str = "StakeAccountRecord";
+    const DISCM: &'static [u8] = &STAKE_ACCOUNT_RECORD_ACCOUNT_DISCM;
+}
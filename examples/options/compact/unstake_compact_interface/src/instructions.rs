@@ -0,0 +1,659 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+};
+use borsh::io::Read;
+use crate::*;
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnstakeProgramIx {
+    InitProtocolFee,
+    SetProtocolFee(SetProtocolFeeIxArgs),
+    CreatePool(CreatePoolIxArgs),
+    AddLiquidity(AddLiquidityIxArgs),
+    RemoveLiquidity(RemoveLiquidityIxArgs),
+    SetFee(SetFeeIxArgs),
+    SetFeeAuthority,
+    DeactivateStakeAccount,
+    ReclaimStakeAccount,
+    Unstake,
+    UnstakeWsol,
+}
+impl UnstakeProgramIx {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INIT_PROTOCOL_FEE_IX_DISCM => Ok(Self::InitProtocolFee),
+            SET_PROTOCOL_FEE_IX_DISCM => {
+                Ok(Self::SetProtocolFee(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
+            }
+            CREATE_POOL_IX_DISCM => {
+                Ok(Self::CreatePool(CreatePoolIxArgs::deserialize(&mut reader)?))
+            }
+            ADD_LIQUIDITY_IX_DISCM => {
+                Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(&mut reader)?))
+            }
+            REMOVE_LIQUIDITY_IX_DISCM => {
+                Ok(
+                    Self::RemoveLiquidity(
+                        RemoveLiquidityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_FEE_IX_DISCM => Ok(Self::SetFee(SetFeeIxArgs::deserialize(&mut reader)?)),
+            SET_FEE_AUTHORITY_IX_DISCM => Ok(Self::SetFeeAuthority),
+            DEACTIVATE_STAKE_ACCOUNT_IX_DISCM => Ok(Self::DeactivateStakeAccount),
+            RECLAIM_STAKE_ACCOUNT_IX_DISCM => Ok(Self::ReclaimStakeAccount),
+            UNSTAKE_IX_DISCM => Ok(Self::Unstake),
+            UNSTAKE_WSOL_IX_DISCM => Ok(Self::UnstakeWsol),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        match self {
+            Self::InitProtocolFee => writer.write_all(&INIT_PROTOCOL_FEE_IX_DISCM),
+            Self::SetProtocolFee(args) => {
+                writer.write_all(&SET_PROTOCOL_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::CreatePool(args) => {
+                writer.write_all(&CREATE_POOL_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidity(args) => {
+                writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidity(args) => {
+                writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetFee(args) => {
+                writer.write_all(&SET_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetFeeAuthority => writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM),
+            Self::DeactivateStakeAccount => {
+                writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)
+            }
+            Self::ReclaimStakeAccount => {
+                writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)
+            }
+            Self::Unstake => writer.write_all(&UNSTAKE_IX_DISCM),
+            Self::UnstakeWsol => writer.write_all(&UNSTAKE_WSOL_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke(ix, &account_info)
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
+    ix: &Instruction,
+    accounts: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_info: [AccountInfo<'info>; N] = accounts.into();
+    invoke_signed(ix, &account_info, seeds)
+}
+/// Defines an instruction's `*_IX_ACCOUNTS_LEN`, `*Accounts` and `*Keys` structs, their conversions
+/// from and into pubkeys, `AccountMeta`s and `AccountInfo`s, and `*_verify_account_keys()`.
+/// Accounts are listed in order, followed by `(signer writable)` if they are signers and writable.
+macro_rules! ix_accounts {
+    (
+        $accounts:ident, $keys:ident, $len:ident = $n:literal, $verify_keys:ident,
+        [$($(#[$attr:meta])* $account:ident $(($($flag:ident)+))?),* $(,)?]
+    ) => {
+        pub const $len: usize = $n;
+        #[derive(Copy, Clone, Debug)]
+        #[cfg(feature = "cpi")]
+        pub struct $accounts<'me, 'info> {
+            $($(#[$attr])* pub $account: &'me AccountInfo<'info>,)*
+        }
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub struct $keys {
+            $($(#[$attr])* pub $account: Pubkey,)*
+        }
+        #[cfg(feature = "cpi")]
+        impl From<$accounts<'_, '_>> for $keys {
+            fn from(accounts: $accounts) -> Self {
+                Self {
+                    $($account: *accounts.$account.key,)*
+                }
+            }
+        }
+        impl From<$keys> for [AccountMeta; $len] {
+            fn from(keys: $keys) -> Self {
+                [$(AccountMeta {
+                    pubkey: keys.$account,
+                    is_signer: ix_accounts!(@is_signer $($($flag)+)?),
+                    is_writable: ix_accounts!(@is_writable $($($flag)+)?),
+                }),*]
+            }
+        }
+        impl From<[Pubkey; $len]> for $keys {
+            fn from(pubkeys: [Pubkey; $len]) -> Self {
+                let [$($account),*] = pubkeys;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'info> From<$accounts<'_, 'info>> for [AccountInfo<'info>; $len] {
+            fn from(accounts: $accounts<'_, 'info>) -> Self {
+                [$(accounts.$account.clone()),*]
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'me, 'info> From<&'me [AccountInfo<'info>; $len]> for $accounts<'me, 'info> {
+            fn from(arr: &'me [AccountInfo<'info>; $len]) -> Self {
+                let [$($account),*] = arr;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $verify_keys(
+            accounts: $accounts<'_, '_>,
+            keys: $keys,
+        ) -> Result<(), (Pubkey, Pubkey)> {
+            $(if *accounts.$account.key != keys.$account {
+                return Err((*accounts.$account.key, keys.$account));
+            })*
+            Ok(())
+        }
+    };
+    (@is_signer) => { false };
+    (@is_signer signer $($flag:ident)*) => { true };
+    (@is_signer $other:ident $($flag:ident)*) => { ix_accounts!(@is_signer $($flag)*) };
+    (@is_writable) => { false };
+    (@is_writable writable $($flag:ident)*) => { true };
+    (@is_writable $other:ident $($flag:ident)*) => { ix_accounts!(@is_writable $($flag)*) };
+}
+/// Defines an instruction's `*_verify_account_privileges()`, and its `*_verify_writable_privileges()`
+/// and `*_verify_signer_privileges()` if it has writable and signer accounts respectively.
+macro_rules! ix_privileges {
+    (
+        $accounts:ident, $verify:ident
+        $(, writable $verify_writable:ident [$($writable:ident),+])?
+        $(, signer $verify_signer:ident [$($signer:ident),+])?
+    ) => {
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_writable<'me, 'info>(
+                accounts: $accounts<'me, 'info>,
+            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                for should_be_writable in [$(accounts.$writable),+] {
+                    if !should_be_writable.is_writable {
+                        return Err((should_be_writable, ProgramError::InvalidAccountData));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_signer<'me, 'info>(
+                accounts: $accounts<'me, 'info>,
+            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                for should_be_signer in [$(accounts.$signer),+] {
+                    if !should_be_signer.is_signer {
+                        return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        #[cfg(feature = "cpi")]
+        pub fn $verify<'me, 'info>(
+            accounts: $accounts<'me, 'info>,
+        ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+            $($verify_writable(accounts)?;)?
+            $($verify_signer(accounts)?;)?
+            Ok(())
+        }
+    };
+}
+/// Defines an instruction's `*_IX_DISCM`, its `*IxData` struct wrapping its `*IxArgs` if it has args,
+/// and the data's serialization and trait impls.
+macro_rules! ix_data {
+    (
+        $name:literal, $discm:ident = $discm_value:expr, $ix_data:ident $(($ix_args:ident))?
+        $(, $keys:ident, $len:ident)?
+    ) => {
+        pub const $discm: [u8; 8] = $discm_value;
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $ix_data$((pub $ix_args))?;
+        $(
+            impl From<$ix_args> for $ix_data {
+                fn from(args: $ix_args) -> Self {
+                    Self(args)
+                }
+            }
+        )?
+        impl $ix_data {
+            pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+                let mut reader = buf;
+                let mut maybe_discm = [0u8; 8];
+                reader.read_exact(&mut maybe_discm)?;
+                if maybe_discm != $discm {
+                    return Err(borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!(
+                            "discm does not match. Expected: {:?}. Received: {:?}",
+                            $discm, maybe_discm,
+                        ),
+                    ));
+                }
+                Ok(Self$(($ix_args::deserialize(&mut reader)?))?)
+            }
+            pub fn serialize<W: borsh::io::Write>(&self, mut writer: W) -> borsh::io::Result<()> {
+                writer.write_all(&$discm)?;
+                $(<$ix_args as BorshSerialize>::serialize(&self.0, &mut writer)?;)?
+                Ok(())
+            }
+            pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+                let mut data = Vec::new();
+                self.serialize(&mut data)?;
+                Ok(data)
+            }
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::Discriminator for $ix_data {
+            const DISCRIMINATOR: &'static [u8] = &$discm;
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::AnchorSerialize for $ix_data {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                // like anchor's, without the discriminator
+                writer.write_all(&self.try_to_vec()?[$discm.len()..])
+            }
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::InstructionData for $ix_data {}
+        #[cfg(feature = "runtime")]
+        impl solores_runtime::Instruction for $ix_data {
+            const NAME: &'static str = $name;
+            const DISCM: &'static [u8] = &$discm;
+            const ACCOUNTS_LEN: usize = ix_data!(@or (0) $(($len))?);
+            type Keys = ix_data!(@or (()) $(($keys))?);
+            type Args = ix_data!(@or (()) $(($ix_args))?);
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+/// Defines an instruction's `*_ix()`, `*_invoke()` and `*_invoke_signed()`,
+/// and their `*_with_program_id()` variants.
+macro_rules! ix_fns {
+    (
+        $ix_data:ident $(($ix_args:ident))?,
+        [
+            $ix:ident,
+            $ix_with_program_id:ident,
+            $invoke:ident,
+            $invoke_with_program_id:ident,
+            $invoke_signed:ident,
+            $invoke_signed_with_program_id:ident $(,)?
+        ]
+        $(, $accounts:ident, $keys:ident, $len:ident)?
+    ) => {
+        ix_fns!(
+            @impl $ix_data,
+            [$ix, $ix_with_program_id, $invoke, $invoke_with_program_id, $invoke_signed, $invoke_signed_with_program_id],
+            [$(keys $keys accounts $accounts $len)?],
+            [$(args $ix_args)?]
+        );
+    };
+    (
+        @impl $ix_data:ident,
+        [$ix:ident, $ix_with_program_id:ident, $invoke:ident, $invoke_with_program_id:ident, $invoke_signed:ident, $invoke_signed_with_program_id:ident],
+        [$($keys_var:ident $keys:ident $accounts_var:ident $accounts:ident $len:ident)?],
+        [$($args_var:ident $ix_args:ident)?]
+    ) => {
+        pub fn $ix_with_program_id(
+            program_id: Pubkey,
+            $($keys_var: $keys,)?
+            $($args_var: $ix_args,)?
+        ) -> borsh::io::Result<Instruction> {
+            Ok(Instruction {
+                program_id,
+                accounts: ix_fns!(@or (Vec::new()) $((Vec::from(<[AccountMeta; $len]>::from($keys_var))))?),
+                data: ix_fns!(@or ($ix_data) $(($ix_data($args_var)))?).try_to_vec()?,
+            })
+        }
+        pub fn $ix($($keys_var: $keys,)? $($args_var: $ix_args,)?) -> borsh::io::Result<Instruction> {
+            $ix_with_program_id(crate::ID, $($keys_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?)?;
+            ix_fns!(@or (invoke(&ix, &[])) $((invoke_instruction(&ix, $accounts_var)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke($($accounts_var: $accounts<'_, '_>,)? $($args_var: $ix_args,)?) -> ProgramResult {
+            $invoke_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?)?;
+            ix_fns!(@or (invoke_signed(&ix, &[], seeds)) $((invoke_instruction_signed(&ix, $accounts_var, seeds)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed(
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)? seeds)
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+ix_accounts!(
+    InitProtocolFeeAccounts, InitProtocolFeeKeys, INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN = 3,
+    init_protocol_fee_verify_account_keys, [payer(signer writable),
+    protocol_fee_account(writable), system_program]
+);
+ix_privileges!(
+    InitProtocolFeeAccounts, init_protocol_fee_verify_account_privileges, writable
+    init_protocol_fee_verify_writable_privileges[payer, protocol_fee_account], signer
+    init_protocol_fee_verify_signer_privileges[payer]
+);
+ix_data!(
+    "initProtocolFee", INIT_PROTOCOL_FEE_IX_DISCM = [225, 155, 167, 170, 29, 145, 165,
+    90], InitProtocolFeeIxData, InitProtocolFeeKeys, INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    InitProtocolFeeIxData, [init_protocol_fee_ix, init_protocol_fee_ix_with_program_id,
+    init_protocol_fee_invoke, init_protocol_fee_invoke_with_program_id,
+    init_protocol_fee_invoke_signed, init_protocol_fee_invoke_signed_with_program_id],
+    InitProtocolFeeAccounts, InitProtocolFeeKeys, INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    SetProtocolFeeAccounts, SetProtocolFeeKeys, SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN = 2,
+    set_protocol_fee_verify_account_keys, [authority(signer),
+    protocol_fee_account(writable)]
+);
+ix_privileges!(
+    SetProtocolFeeAccounts, set_protocol_fee_verify_account_privileges, writable
+    set_protocol_fee_verify_writable_privileges[protocol_fee_account], signer
+    set_protocol_fee_verify_signer_privileges[authority]
+);
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetProtocolFeeIxArgs {
+    pub protocol_fee: ProtocolFee,
+}
+ix_data!(
+    "setProtocolFee", SET_PROTOCOL_FEE_IX_DISCM = [173, 239, 83, 242, 136, 43, 144, 217],
+    SetProtocolFeeIxData(SetProtocolFeeIxArgs), SetProtocolFeeKeys,
+    SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    SetProtocolFeeIxData(SetProtocolFeeIxArgs), [set_protocol_fee_ix,
+    set_protocol_fee_ix_with_program_id, set_protocol_fee_invoke,
+    set_protocol_fee_invoke_with_program_id, set_protocol_fee_invoke_signed,
+    set_protocol_fee_invoke_signed_with_program_id], SetProtocolFeeAccounts,
+    SetProtocolFeeKeys, SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    CreatePoolAccounts, CreatePoolKeys, CREATE_POOL_IX_ACCOUNTS_LEN = 9,
+    create_pool_verify_account_keys, [payer(signer writable), fee_authority(signer),
+    pool_account(signer writable), pool_sol_reserves, fee_account(writable),
+    lp_mint(signer writable), token_program, system_program, rent]
+);
+ix_privileges!(
+    CreatePoolAccounts, create_pool_verify_account_privileges, writable
+    create_pool_verify_writable_privileges[payer, pool_account, fee_account, lp_mint],
+    signer create_pool_verify_signer_privileges[payer, fee_authority, pool_account,
+    lp_mint]
+);
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePoolIxArgs {
+    pub fee: Fee,
+}
+ix_data!(
+    "createPool", CREATE_POOL_IX_DISCM = [233, 146, 209, 142, 207, 104, 64, 188],
+    CreatePoolIxData(CreatePoolIxArgs), CreatePoolKeys, CREATE_POOL_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    CreatePoolIxData(CreatePoolIxArgs), [create_pool_ix, create_pool_ix_with_program_id,
+    create_pool_invoke, create_pool_invoke_with_program_id, create_pool_invoke_signed,
+    create_pool_invoke_signed_with_program_id], CreatePoolAccounts, CreatePoolKeys,
+    CREATE_POOL_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    AddLiquidityAccounts, AddLiquidityKeys, ADD_LIQUIDITY_IX_ACCOUNTS_LEN = 7,
+    add_liquidity_verify_account_keys, [from(signer writable), pool_account(writable),
+    pool_sol_reserves(writable), lp_mint(writable), mint_lp_tokens_to(writable),
+    token_program, system_program]
+);
+ix_privileges!(
+    AddLiquidityAccounts, add_liquidity_verify_account_privileges, writable
+    add_liquidity_verify_writable_privileges[from, pool_account, pool_sol_reserves,
+    lp_mint, mint_lp_tokens_to], signer add_liquidity_verify_signer_privileges[from]
+);
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityIxArgs {
+    pub amount: u64,
+}
+ix_data!(
+    "addLiquidity", ADD_LIQUIDITY_IX_DISCM = [181, 157, 89, 67, 143, 182, 52, 72],
+    AddLiquidityIxData(AddLiquidityIxArgs), AddLiquidityKeys,
+    ADD_LIQUIDITY_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    AddLiquidityIxData(AddLiquidityIxArgs), [add_liquidity_ix,
+    add_liquidity_ix_with_program_id, add_liquidity_invoke,
+    add_liquidity_invoke_with_program_id, add_liquidity_invoke_signed,
+    add_liquidity_invoke_signed_with_program_id], AddLiquidityAccounts, AddLiquidityKeys,
+    ADD_LIQUIDITY_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    RemoveLiquidityAccounts, RemoveLiquidityKeys, REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN = 8,
+    remove_liquidity_verify_account_keys, [burn_lp_tokens_from_authority(signer),
+    to(writable), pool_account(writable), pool_sol_reserves(writable), lp_mint(writable),
+    burn_lp_tokens_from(writable), token_program, system_program]
+);
+ix_privileges!(
+    RemoveLiquidityAccounts, remove_liquidity_verify_account_privileges, writable
+    remove_liquidity_verify_writable_privileges[to, pool_account, pool_sol_reserves,
+    lp_mint, burn_lp_tokens_from], signer
+    remove_liquidity_verify_signer_privileges[burn_lp_tokens_from_authority]
+);
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityIxArgs {
+    pub amount_lp: u64,
+}
+ix_data!(
+    "removeLiquidity", REMOVE_LIQUIDITY_IX_DISCM = [80, 85, 209, 72, 24, 206, 177, 108],
+    RemoveLiquidityIxData(RemoveLiquidityIxArgs), RemoveLiquidityKeys,
+    REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    RemoveLiquidityIxData(RemoveLiquidityIxArgs), [remove_liquidity_ix,
+    remove_liquidity_ix_with_program_id, remove_liquidity_invoke,
+    remove_liquidity_invoke_with_program_id, remove_liquidity_invoke_signed,
+    remove_liquidity_invoke_signed_with_program_id], RemoveLiquidityAccounts,
+    RemoveLiquidityKeys, REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    SetFeeAccounts, SetFeeKeys, SET_FEE_IX_ACCOUNTS_LEN = 5, set_fee_verify_account_keys,
+    [fee_authority(signer), pool_account, fee_account(writable), system_program, rent]
+);
+ix_privileges!(
+    SetFeeAccounts, set_fee_verify_account_privileges, writable
+    set_fee_verify_writable_privileges[fee_account], signer
+    set_fee_verify_signer_privileges[fee_authority]
+);
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeIxArgs {
+    pub fee: Fee,
+}
+ix_data!(
+    "setFee", SET_FEE_IX_DISCM = [18, 154, 24, 18, 237, 214, 19, 80],
+    SetFeeIxData(SetFeeIxArgs), SetFeeKeys, SET_FEE_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    SetFeeIxData(SetFeeIxArgs), [set_fee_ix, set_fee_ix_with_program_id, set_fee_invoke,
+    set_fee_invoke_with_program_id, set_fee_invoke_signed,
+    set_fee_invoke_signed_with_program_id], SetFeeAccounts, SetFeeKeys,
+    SET_FEE_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    SetFeeAuthorityAccounts, SetFeeAuthorityKeys, SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN = 3,
+    set_fee_authority_verify_account_keys, [fee_authority(signer),
+    pool_account(writable), new_fee_authority]
+);
+ix_privileges!(
+    SetFeeAuthorityAccounts, set_fee_authority_verify_account_privileges, writable
+    set_fee_authority_verify_writable_privileges[pool_account], signer
+    set_fee_authority_verify_signer_privileges[fee_authority]
+);
+ix_data!(
+    "setFeeAuthority", SET_FEE_AUTHORITY_IX_DISCM = [31, 1, 50, 87, 237, 101, 97, 132],
+    SetFeeAuthorityIxData, SetFeeAuthorityKeys, SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    SetFeeAuthorityIxData, [set_fee_authority_ix, set_fee_authority_ix_with_program_id,
+    set_fee_authority_invoke, set_fee_authority_invoke_with_program_id,
+    set_fee_authority_invoke_signed, set_fee_authority_invoke_signed_with_program_id],
+    SetFeeAuthorityAccounts, SetFeeAuthorityKeys, SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    DeactivateStakeAccountAccounts, DeactivateStakeAccountKeys,
+    DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN = 5,
+    deactivate_stake_account_verify_account_keys, [stake_account(writable), pool_account,
+    pool_sol_reserves, clock, stake_program]
+);
+ix_privileges!(
+    DeactivateStakeAccountAccounts, deactivate_stake_account_verify_account_privileges,
+    writable deactivate_stake_account_verify_writable_privileges[stake_account]
+);
+ix_data!(
+    "deactivateStakeAccount", DEACTIVATE_STAKE_ACCOUNT_IX_DISCM = [217, 64, 76, 16, 216,
+    77, 123, 226], DeactivateStakeAccountIxData, DeactivateStakeAccountKeys,
+    DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    DeactivateStakeAccountIxData, [deactivate_stake_account_ix,
+    deactivate_stake_account_ix_with_program_id, deactivate_stake_account_invoke,
+    deactivate_stake_account_invoke_with_program_id,
+    deactivate_stake_account_invoke_signed,
+    deactivate_stake_account_invoke_signed_with_program_id],
+    DeactivateStakeAccountAccounts, DeactivateStakeAccountKeys,
+    DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    ReclaimStakeAccountAccounts, ReclaimStakeAccountKeys,
+    RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN = 7, reclaim_stake_account_verify_account_keys,
+    [stake_account(writable), pool_account(writable), pool_sol_reserves(writable),
+    stake_account_record_account(writable), clock, stake_history, stake_program]
+);
+ix_privileges!(
+    ReclaimStakeAccountAccounts, reclaim_stake_account_verify_account_privileges,
+    writable reclaim_stake_account_verify_writable_privileges[stake_account,
+    pool_account, pool_sol_reserves, stake_account_record_account]
+);
+ix_data!(
+    "reclaimStakeAccount", RECLAIM_STAKE_ACCOUNT_IX_DISCM = [47, 127, 90, 221, 10, 160,
+    183, 117], ReclaimStakeAccountIxData, ReclaimStakeAccountKeys,
+    RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    ReclaimStakeAccountIxData, [reclaim_stake_account_ix,
+    reclaim_stake_account_ix_with_program_id, reclaim_stake_account_invoke,
+    reclaim_stake_account_invoke_with_program_id, reclaim_stake_account_invoke_signed,
+    reclaim_stake_account_invoke_signed_with_program_id], ReclaimStakeAccountAccounts,
+    ReclaimStakeAccountKeys, RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    UnstakeAccounts, UnstakeKeys, UNSTAKE_IX_ACCOUNTS_LEN = 13,
+    unstake_verify_account_keys, [payer(signer writable), unstaker(signer),
+    stake_account(writable), destination(writable), pool_account(writable),
+    pool_sol_reserves(writable), fee_account, stake_account_record_account(writable),
+    protocol_fee_account, protocol_fee_destination(writable), clock, stake_program,
+    system_program]
+);
+ix_privileges!(
+    UnstakeAccounts, unstake_verify_account_privileges, writable
+    unstake_verify_writable_privileges[payer, stake_account, destination, pool_account,
+    pool_sol_reserves, stake_account_record_account, protocol_fee_destination], signer
+    unstake_verify_signer_privileges[payer, unstaker]
+);
+ix_data!(
+    "unstake", UNSTAKE_IX_DISCM = [90, 95, 107, 42, 205, 124, 50, 225], UnstakeIxData,
+    UnstakeKeys, UNSTAKE_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    UnstakeIxData, [unstake_ix, unstake_ix_with_program_id, unstake_invoke,
+    unstake_invoke_with_program_id, unstake_invoke_signed,
+    unstake_invoke_signed_with_program_id], UnstakeAccounts, UnstakeKeys,
+    UNSTAKE_IX_ACCOUNTS_LEN
+);
+ix_accounts!(
+    UnstakeWsolAccounts, UnstakeWsolKeys, UNSTAKE_WSOL_IX_ACCOUNTS_LEN = 14,
+    unstake_wsol_verify_account_keys, [payer(signer writable), unstaker(signer),
+    stake_account(writable), destination(writable), pool_account(writable),
+    pool_sol_reserves(writable), fee_account, stake_account_record_account(writable),
+    protocol_fee_account, protocol_fee_destination(writable), clock, stake_program,
+    system_program, token_program]
+);
+ix_privileges!(
+    UnstakeWsolAccounts, unstake_wsol_verify_account_privileges, writable
+    unstake_wsol_verify_writable_privileges[payer, stake_account, destination,
+    pool_account, pool_sol_reserves, stake_account_record_account,
+    protocol_fee_destination], signer unstake_wsol_verify_signer_privileges[payer,
+    unstaker]
+);
+ix_data!(
+    "unstakeWsol", UNSTAKE_WSOL_IX_DISCM = [125, 93, 190, 135, 89, 174, 142, 149],
+    UnstakeWsolIxData, UnstakeWsolKeys, UNSTAKE_WSOL_IX_ACCOUNTS_LEN
+);
+ix_fns!(
+    UnstakeWsolIxData, [unstake_wsol_ix, unstake_wsol_ix_with_program_id,
+    unstake_wsol_invoke, unstake_wsol_invoke_with_program_id, unstake_wsol_invoke_signed,
+    unstake_wsol_invoke_signed_with_program_id], UnstakeWsolAccounts, UnstakeWsolKeys,
+    UNSTAKE_WSOL_IX_ACCOUNTS_LEN
+);
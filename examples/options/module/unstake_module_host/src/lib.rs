@@ -0,0 +1,3 @@
+//! Crate that the unstake interface is generated into as a module with `--module crate::unstake`
+
+pub mod unstake;
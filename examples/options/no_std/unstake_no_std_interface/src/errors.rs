@@ -0,0 +1,88 @@
+use alloc::string::ToString;
+use solana_program::{
+    decode_error::DecodeError, msg, program_error::{PrintProgramError, ProgramError},
+};
+#[derive(Clone, Copy, Debug, Eq, num_derive::FromPrimitive, PartialEq)]
+pub enum UnstakeError {
+    InvalidLpTokenAccount = 6000,
+    PdaBumpNotCached = 6001,
+    InvalidFeeAuthority = 6002,
+    StakeAccountAuthorizedNotRetrievable = 6003,
+    StakeAccountLockupNotRetrievable = 6004,
+    StakeAccountLockupInForce = 6005,
+    InvalidFee = 6006,
+    InternalError = 6007,
+    NotEnoughLiquidity = 6008,
+    LiquidityToAddTooLittle = 6009,
+    DestinationNotWSol = 6010,
+    WrongProtocolFeeDestination = 6011,
+    InvalidProtocolFeeAuthority = 6012,
+}
+impl core::fmt::Display for UnstakeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            match self {
+                Self::InvalidLpTokenAccount => "The provided LP token account is invalid",
+                Self::PdaBumpNotCached => "Could not find PDA bump",
+                Self::InvalidFeeAuthority => {
+                    "The provided fee authority does not have the authority over the provided pool account"
+                }
+                Self::StakeAccountAuthorizedNotRetrievable => {
+                    "The Authorized of the given stake account is None (possibly an uninitialized stake account was given)"
+                }
+                Self::StakeAccountLockupNotRetrievable => {
+                    "The Lockup of the given stake account is None (possibly an uninitialized stake account was given)"
+                }
+                Self::StakeAccountLockupInForce => {
+                    "The provided stake account is locked up"
+                }
+                Self::InvalidFee => {
+                    "The provided description of fee violates the invariants"
+                }
+                Self::InternalError => "Internal Error",
+                Self::NotEnoughLiquidity => {
+                    "Not enough liquidity to service this unstake"
+                }
+                Self::LiquidityToAddTooLittle => "Liquidity to add too little",
+                Self::DestinationNotWSol => {
+                    "Destination token account is not a wrapped SOL account"
+                }
+                Self::WrongProtocolFeeDestination => {
+                    "Wrong protocol fee destination account"
+                }
+                Self::InvalidProtocolFeeAuthority => {
+                    "The provided protocol fee authority does not have the authority over the protocol fee account"
+                }
+            },
+        )
+    }
+}
+impl core::error::Error for UnstakeError {}
+impl From<UnstakeError> for ProgramError {
+    fn from(e: UnstakeError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramErrorCode for UnstakeError {
+    fn code(&self) -> u32 {
+        *self as u32
+    }
+    fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
+impl<T> DecodeError<T> for UnstakeError {
+    fn type_of() -> &'static str {
+        "UnstakeError"
+    }
+}
+impl PrintProgramError for UnstakeError {
+    fn print<E>(&self)
+    where
+        E: 'static + core::error::Error + DecodeError<E> + PrintProgramError
+            + num_traits::FromPrimitive,
+    {
+        msg!(& self.to_string());
+    }
+}
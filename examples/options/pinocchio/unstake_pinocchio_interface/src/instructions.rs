@@ -0,0 +1,3751 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use pinocchio::{
+    account_info::AccountInfo, cpi::invoke_signed,
+    instruction::{AccountMeta as CpiAccountMeta, Instruction as CpiInstruction, Signer},
+    program_error::ProgramError, ProgramResult,
+};
+use borsh::io::Read;
+use crate::*;
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnstakeProgramIx {
+    InitProtocolFee,
+    SetProtocolFee(SetProtocolFeeIxArgs),
+    CreatePool(CreatePoolIxArgs),
+    AddLiquidity(AddLiquidityIxArgs),
+    RemoveLiquidity(RemoveLiquidityIxArgs),
+    SetFee(SetFeeIxArgs),
+    SetFeeAuthority,
+    DeactivateStakeAccount,
+    ReclaimStakeAccount,
+    Unstake,
+    UnstakeWsol,
+}
+impl UnstakeProgramIx {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        match maybe_discm {
+            INIT_PROTOCOL_FEE_IX_DISCM => Ok(Self::InitProtocolFee),
+            SET_PROTOCOL_FEE_IX_DISCM => {
+                Ok(Self::SetProtocolFee(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
+            }
+            CREATE_POOL_IX_DISCM => {
+                Ok(Self::CreatePool(CreatePoolIxArgs::deserialize(&mut reader)?))
+            }
+            ADD_LIQUIDITY_IX_DISCM => {
+                Ok(Self::AddLiquidity(AddLiquidityIxArgs::deserialize(&mut reader)?))
+            }
+            REMOVE_LIQUIDITY_IX_DISCM => {
+                Ok(
+                    Self::RemoveLiquidity(
+                        RemoveLiquidityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            SET_FEE_IX_DISCM => Ok(Self::SetFee(SetFeeIxArgs::deserialize(&mut reader)?)),
+            SET_FEE_AUTHORITY_IX_DISCM => Ok(Self::SetFeeAuthority),
+            DEACTIVATE_STAKE_ACCOUNT_IX_DISCM => Ok(Self::DeactivateStakeAccount),
+            RECLAIM_STAKE_ACCOUNT_IX_DISCM => Ok(Self::ReclaimStakeAccount),
+            UNSTAKE_IX_DISCM => Ok(Self::Unstake),
+            UNSTAKE_WSOL_IX_DISCM => Ok(Self::UnstakeWsol),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        match self {
+            Self::InitProtocolFee => writer.write_all(&INIT_PROTOCOL_FEE_IX_DISCM),
+            Self::SetProtocolFee(args) => {
+                writer.write_all(&SET_PROTOCOL_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::CreatePool(args) => {
+                writer.write_all(&CREATE_POOL_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::AddLiquidity(args) => {
+                writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::RemoveLiquidity(args) => {
+                writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetFee(args) => {
+                writer.write_all(&SET_FEE_IX_DISCM)?;
+                args.serialize(&mut writer)
+            }
+            Self::SetFeeAuthority => writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM),
+            Self::DeactivateStakeAccount => {
+                writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)
+            }
+            Self::ReclaimStakeAccount => {
+                writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)
+            }
+            Self::Unstake => writer.write_all(&UNSTAKE_IX_DISCM),
+            Self::UnstakeWsol => writer.write_all(&UNSTAKE_WSOL_IX_DISCM),
+        }
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serializes instruction data into `buf` with `serialize`, returning the written bytes
+#[cfg(feature = "cpi")]
+fn write_ix_data(
+    buf: &mut [u8],
+    serialize: impl FnOnce(&mut &mut [u8]) -> borsh::io::Result<()>,
+) -> Result<&[u8], ProgramError> {
+    let buf_len = buf.len();
+    let mut writer = &mut *buf;
+    serialize(&mut writer).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let len = buf_len - writer.len();
+    Ok(&buf[..len])
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction_signed<'me, A, const N: usize>(
+    program_id: &Pubkey,
+    accounts: A,
+    data: &[u8],
+    signers: &[Signer],
+) -> ProgramResult
+where
+    A: Copy + Into<[CpiAccountMeta<'me>; N]> + Into<[&'me AccountInfo; N]>,
+{
+    let metas: [CpiAccountMeta<'me>; N] = accounts.into();
+    let account_infos: [&'me AccountInfo; N] = accounts.into();
+    invoke_signed(
+        &CpiInstruction {
+            program_id: &program_id.to_bytes(),
+            accounts: &metas,
+            data,
+        },
+        &account_infos,
+        signers,
+    )
+}
+pub const INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct InitProtocolFeeAccounts<'me> {
+    pub payer: &'me AccountInfo,
+    pub protocol_fee_account: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InitProtocolFeeKeys {
+    pub payer: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub system_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<InitProtocolFeeAccounts<'_>> for InitProtocolFeeKeys {
+    fn from(accounts: InitProtocolFeeAccounts) -> Self {
+        Self {
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            protocol_fee_account: Pubkey::new_from_array(
+                *accounts.protocol_fee_account.key(),
+            ),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+        }
+    }
+}
+impl From<InitProtocolFeeKeys> for [AccountMeta; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitProtocolFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for InitProtocolFeeKeys {
+    fn from(pubkeys: [Pubkey; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            protocol_fee_account: pubkeys[1],
+            system_program: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<InitProtocolFeeAccounts<'me>>
+for [&'me AccountInfo; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InitProtocolFeeAccounts<'me>) -> Self {
+        [accounts.payer, accounts.protocol_fee_account, accounts.system_program]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<InitProtocolFeeAccounts<'me>>
+for [CpiAccountMeta<'me>; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InitProtocolFeeAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
+for InitProtocolFeeAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            protocol_fee_account: &arr[1],
+            system_program: &arr[2],
+        }
+    }
+}
+pub const INIT_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [225, 155, 167, 170, 29, 145, 165, 90];
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitProtocolFeeIxData;
+impl InitProtocolFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != INIT_PROTOCOL_FEE_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INIT_PROTOCOL_FEE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&INIT_PROTOCOL_FEE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for InitProtocolFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &INIT_PROTOCOL_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for InitProtocolFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for InitProtocolFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitProtocolFeeIxData {
+    const NAME: &'static str = "initProtocolFee";
+    const DISCM: &'static [u8] = &INIT_PROTOCOL_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    type Keys = InitProtocolFeeKeys;
+    type Args = ();
+}
+pub fn init_protocol_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitProtocolFeeKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; INIT_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: InitProtocolFeeIxData.try_to_vec()?,
+    })
+}
+pub fn init_protocol_fee_ix(
+    keys: InitProtocolFeeKeys,
+) -> borsh::io::Result<Instruction> {
+    init_protocol_fee_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitProtocolFeeAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| InitProtocolFeeIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_invoke_signed(
+    accounts: InitProtocolFeeAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    init_protocol_fee_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitProtocolFeeAccounts<'_>,
+) -> ProgramResult {
+    init_protocol_fee_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_invoke(accounts: InitProtocolFeeAccounts<'_>) -> ProgramResult {
+    init_protocol_fee_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_verify_account_keys(
+    accounts: InitProtocolFeeAccounts<'_>,
+    keys: InitProtocolFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.payer.key()), keys.payer),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_account.key()),
+            keys.protocol_fee_account,
+        ),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_verify_writable_privileges<'me>(
+    accounts: InitProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.payer, accounts.protocol_fee_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_verify_signer_privileges<'me>(
+    accounts: InitProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn init_protocol_fee_verify_account_privileges<'me>(
+    accounts: InitProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    init_protocol_fee_verify_writable_privileges(accounts)?;
+    init_protocol_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct SetProtocolFeeAccounts<'me> {
+    pub authority: &'me AccountInfo,
+    pub protocol_fee_account: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetProtocolFeeKeys {
+    pub authority: Pubkey,
+    pub protocol_fee_account: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<SetProtocolFeeAccounts<'_>> for SetProtocolFeeKeys {
+    fn from(accounts: SetProtocolFeeAccounts) -> Self {
+        Self {
+            authority: Pubkey::new_from_array(*accounts.authority.key()),
+            protocol_fee_account: Pubkey::new_from_array(
+                *accounts.protocol_fee_account.key(),
+            ),
+        }
+    }
+}
+impl From<SetProtocolFeeKeys> for [AccountMeta; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetProtocolFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]> for SetProtocolFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: pubkeys[0],
+            protocol_fee_account: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetProtocolFeeAccounts<'me>>
+for [&'me AccountInfo; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetProtocolFeeAccounts<'me>) -> Self {
+        [accounts.authority, accounts.protocol_fee_account]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetProtocolFeeAccounts<'me>>
+for [CpiAccountMeta<'me>; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetProtocolFeeAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]>
+for SetProtocolFeeAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            authority: &arr[0],
+            protocol_fee_account: &arr[1],
+        }
+    }
+}
+pub const SET_PROTOCOL_FEE_IX_DISCM: [u8; 8] = [173, 239, 83, 242, 136, 43, 144, 217];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetProtocolFeeIxArgs {
+    pub protocol_fee: ProtocolFee,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetProtocolFeeIxData(pub SetProtocolFeeIxArgs);
+impl From<SetProtocolFeeIxArgs> for SetProtocolFeeIxData {
+    fn from(args: SetProtocolFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetProtocolFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_PROTOCOL_FEE_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_PROTOCOL_FEE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetProtocolFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&SET_PROTOCOL_FEE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetProtocolFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_PROTOCOL_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetProtocolFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetProtocolFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetProtocolFeeIxData {
+    const NAME: &'static str = "setProtocolFee";
+    const DISCM: &'static [u8] = &SET_PROTOCOL_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN;
+    type Keys = SetProtocolFeeKeys;
+    type Args = SetProtocolFeeIxArgs;
+}
+pub fn set_protocol_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetProtocolFeeKeys,
+    args: SetProtocolFeeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_PROTOCOL_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetProtocolFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_protocol_fee_ix(
+    keys: SetProtocolFeeKeys,
+    args: SetProtocolFeeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    set_protocol_fee_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeeAccounts<'_>,
+    args: SetProtocolFeeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 104];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| SetProtocolFeeIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_invoke_signed(
+    accounts: SetProtocolFeeAccounts<'_>,
+    args: SetProtocolFeeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    set_protocol_fee_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetProtocolFeeAccounts<'_>,
+    args: SetProtocolFeeIxArgs,
+) -> ProgramResult {
+    set_protocol_fee_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_invoke(
+    accounts: SetProtocolFeeAccounts<'_>,
+    args: SetProtocolFeeIxArgs,
+) -> ProgramResult {
+    set_protocol_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_verify_account_keys(
+    accounts: SetProtocolFeeAccounts<'_>,
+    keys: SetProtocolFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.authority.key()), keys.authority),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_account.key()),
+            keys.protocol_fee_account,
+        ),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_verify_writable_privileges<'me>(
+    accounts: SetProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.protocol_fee_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_verify_signer_privileges<'me>(
+    accounts: SetProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_protocol_fee_verify_account_privileges<'me>(
+    accounts: SetProtocolFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    set_protocol_fee_verify_writable_privileges(accounts)?;
+    set_protocol_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_POOL_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreatePoolAccounts<'me> {
+    pub payer: &'me AccountInfo,
+    pub fee_authority: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub fee_account: &'me AccountInfo,
+    pub lp_mint: &'me AccountInfo,
+    pub token_program: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CreatePoolKeys {
+    pub payer: Pubkey,
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreatePoolAccounts<'_>> for CreatePoolKeys {
+    fn from(accounts: CreatePoolAccounts) -> Self {
+        Self {
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            fee_authority: Pubkey::new_from_array(*accounts.fee_authority.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            fee_account: Pubkey::new_from_array(*accounts.fee_account.key()),
+            lp_mint: Pubkey::new_from_array(*accounts.lp_mint.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<CreatePoolKeys> for [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreatePoolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fee_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_mint,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN]> for CreatePoolKeys {
+    fn from(pubkeys: [Pubkey; CREATE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            fee_authority: pubkeys[1],
+            pool_account: pubkeys[2],
+            pool_sol_reserves: pubkeys[3],
+            fee_account: pubkeys[4],
+            lp_mint: pubkeys[5],
+            token_program: pubkeys[6],
+            system_program: pubkeys[7],
+            rent: pubkeys[8],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreatePoolAccounts<'me>>
+for [&'me AccountInfo; CREATE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreatePoolAccounts<'me>) -> Self {
+        [
+            accounts.payer,
+            accounts.fee_authority,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.fee_account,
+            accounts.lp_mint,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreatePoolAccounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_POOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreatePoolAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.fee_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.fee_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.lp_mint.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_POOL_IX_ACCOUNTS_LEN]>
+for CreatePoolAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_POOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            fee_authority: &arr[1],
+            pool_account: &arr[2],
+            pool_sol_reserves: &arr[3],
+            fee_account: &arr[4],
+            lp_mint: &arr[5],
+            token_program: &arr[6],
+            system_program: &arr[7],
+            rent: &arr[8],
+        }
+    }
+}
+pub const CREATE_POOL_IX_DISCM: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreatePoolIxArgs {
+    pub fee: Fee,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatePoolIxData(pub CreatePoolIxArgs);
+impl From<CreatePoolIxArgs> for CreatePoolIxData {
+    fn from(args: CreatePoolIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreatePoolIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != CREATE_POOL_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_POOL_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreatePoolIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&CREATE_POOL_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for CreatePoolIxData {
+    const DISCRIMINATOR: &'static [u8] = &CREATE_POOL_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for CreatePoolIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for CreatePoolIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreatePoolIxData {
+    const NAME: &'static str = "createPool";
+    const DISCM: &'static [u8] = &CREATE_POOL_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_POOL_IX_ACCOUNTS_LEN;
+    type Keys = CreatePoolKeys;
+    type Args = CreatePoolIxArgs;
+}
+pub fn create_pool_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreatePoolKeys,
+    args: CreatePoolIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_POOL_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreatePoolIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_pool_ix(
+    keys: CreatePoolKeys,
+    args: CreatePoolIxArgs,
+) -> borsh::io::Result<Instruction> {
+    create_pool_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreatePoolAccounts<'_>,
+    args: CreatePoolIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 41];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| CreatePoolIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_invoke_signed(
+    accounts: CreatePoolAccounts<'_>,
+    args: CreatePoolIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_pool_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreatePoolAccounts<'_>,
+    args: CreatePoolIxArgs,
+) -> ProgramResult {
+    create_pool_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_invoke(
+    accounts: CreatePoolAccounts<'_>,
+    args: CreatePoolIxArgs,
+) -> ProgramResult {
+    create_pool_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_verify_account_keys(
+    accounts: CreatePoolAccounts<'_>,
+    keys: CreatePoolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.payer.key()), keys.payer),
+        (Pubkey::new_from_array(*accounts.fee_authority.key()), keys.fee_authority),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.fee_account.key()), keys.fee_account),
+        (Pubkey::new_from_array(*accounts.lp_mint.key()), keys.lp_mint),
+        (Pubkey::new_from_array(*accounts.token_program.key()), keys.token_program),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+        (Pubkey::new_from_array(*accounts.rent.key()), keys.rent),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_verify_writable_privileges<'me>(
+    accounts: CreatePoolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.payer,
+        accounts.pool_account,
+        accounts.fee_account,
+        accounts.lp_mint,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_verify_signer_privileges<'me>(
+    accounts: CreatePoolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.payer,
+        accounts.fee_authority,
+        accounts.pool_account,
+        accounts.lp_mint,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_pool_verify_account_privileges<'me>(
+    accounts: CreatePoolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_pool_verify_writable_privileges(accounts)?;
+    create_pool_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const ADD_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AddLiquidityAccounts<'me> {
+    pub from: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub lp_mint: &'me AccountInfo,
+    pub mint_lp_tokens_to: &'me AccountInfo,
+    pub token_program: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AddLiquidityKeys {
+    pub from: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub mint_lp_tokens_to: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AddLiquidityAccounts<'_>> for AddLiquidityKeys {
+    fn from(accounts: AddLiquidityAccounts) -> Self {
+        Self {
+            from: Pubkey::new_from_array(*accounts.from.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            lp_mint: Pubkey::new_from_array(*accounts.lp_mint.key()),
+            mint_lp_tokens_to: Pubkey::new_from_array(*accounts.mint_lp_tokens_to.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+        }
+    }
+}
+impl From<AddLiquidityKeys> for [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: AddLiquidityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint_lp_tokens_to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]> for AddLiquidityKeys {
+    fn from(pubkeys: [Pubkey; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            pool_account: pubkeys[1],
+            pool_sol_reserves: pubkeys[2],
+            lp_mint: pubkeys[3],
+            mint_lp_tokens_to: pubkeys[4],
+            token_program: pubkeys[5],
+            system_program: pubkeys[6],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AddLiquidityAccounts<'me>>
+for [&'me AccountInfo; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AddLiquidityAccounts<'me>) -> Self {
+        [
+            accounts.from,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.lp_mint,
+            accounts.mint_lp_tokens_to,
+            accounts.token_program,
+            accounts.system_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AddLiquidityAccounts<'me>>
+for [CpiAccountMeta<'me>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AddLiquidityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.from.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.lp_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_lp_tokens_to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
+for AddLiquidityAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: &arr[0],
+            pool_account: &arr[1],
+            pool_sol_reserves: &arr[2],
+            lp_mint: &arr[3],
+            mint_lp_tokens_to: &arr[4],
+            token_program: &arr[5],
+            system_program: &arr[6],
+        }
+    }
+}
+pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddLiquidityIxArgs {
+    pub amount: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddLiquidityIxData(pub AddLiquidityIxArgs);
+impl From<AddLiquidityIxArgs> for AddLiquidityIxData {
+    fn from(args: AddLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl AddLiquidityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != ADD_LIQUIDITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        ADD_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(AddLiquidityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&ADD_LIQUIDITY_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for AddLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for AddLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for AddLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AddLiquidityIxData {
+    const NAME: &'static str = "addLiquidity";
+    const DISCM: &'static [u8] = &ADD_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ADD_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = AddLiquidityKeys;
+    type Args = AddLiquidityIxArgs;
+}
+pub fn add_liquidity_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AddLiquidityKeys,
+    args: AddLiquidityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: AddLiquidityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn add_liquidity_ix(
+    keys: AddLiquidityKeys,
+    args: AddLiquidityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    add_liquidity_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityAccounts<'_>,
+    args: AddLiquidityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 16];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| AddLiquidityIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_invoke_signed(
+    accounts: AddLiquidityAccounts<'_>,
+    args: AddLiquidityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    add_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AddLiquidityAccounts<'_>,
+    args: AddLiquidityIxArgs,
+) -> ProgramResult {
+    add_liquidity_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_invoke(
+    accounts: AddLiquidityAccounts<'_>,
+    args: AddLiquidityIxArgs,
+) -> ProgramResult {
+    add_liquidity_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_verify_account_keys(
+    accounts: AddLiquidityAccounts<'_>,
+    keys: AddLiquidityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.from.key()), keys.from),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.lp_mint.key()), keys.lp_mint),
+        (
+            Pubkey::new_from_array(*accounts.mint_lp_tokens_to.key()),
+            keys.mint_lp_tokens_to,
+        ),
+        (Pubkey::new_from_array(*accounts.token_program.key()), keys.token_program),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_verify_writable_privileges<'me>(
+    accounts: AddLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.from,
+        accounts.pool_account,
+        accounts.pool_sol_reserves,
+        accounts.lp_mint,
+        accounts.mint_lp_tokens_to,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_verify_signer_privileges<'me>(
+    accounts: AddLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.from] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn add_liquidity_verify_account_privileges<'me>(
+    accounts: AddLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    add_liquidity_verify_writable_privileges(accounts)?;
+    add_liquidity_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct RemoveLiquidityAccounts<'me> {
+    pub burn_lp_tokens_from_authority: &'me AccountInfo,
+    pub to: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub lp_mint: &'me AccountInfo,
+    pub burn_lp_tokens_from: &'me AccountInfo,
+    pub token_program: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityKeys {
+    pub burn_lp_tokens_from_authority: Pubkey,
+    pub to: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub lp_mint: Pubkey,
+    pub burn_lp_tokens_from: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<RemoveLiquidityAccounts<'_>> for RemoveLiquidityKeys {
+    fn from(accounts: RemoveLiquidityAccounts) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: Pubkey::new_from_array(
+                *accounts.burn_lp_tokens_from_authority.key(),
+            ),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            lp_mint: Pubkey::new_from_array(*accounts.lp_mint.key()),
+            burn_lp_tokens_from: Pubkey::new_from_array(
+                *accounts.burn_lp_tokens_from.key(),
+            ),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+        }
+    }
+}
+impl From<RemoveLiquidityKeys> for [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveLiquidityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.burn_lp_tokens_from_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.lp_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.burn_lp_tokens_from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]> for RemoveLiquidityKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: pubkeys[0],
+            to: pubkeys[1],
+            pool_account: pubkeys[2],
+            pool_sol_reserves: pubkeys[3],
+            lp_mint: pubkeys[4],
+            burn_lp_tokens_from: pubkeys[5],
+            token_program: pubkeys[6],
+            system_program: pubkeys[7],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RemoveLiquidityAccounts<'me>>
+for [&'me AccountInfo; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RemoveLiquidityAccounts<'me>) -> Self {
+        [
+            accounts.burn_lp_tokens_from_authority,
+            accounts.to,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.lp_mint,
+            accounts.burn_lp_tokens_from,
+            accounts.token_program,
+            accounts.system_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RemoveLiquidityAccounts<'me>>
+for [CpiAccountMeta<'me>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RemoveLiquidityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.burn_lp_tokens_from_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.lp_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.burn_lp_tokens_from.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]>
+for RemoveLiquidityAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            burn_lp_tokens_from_authority: &arr[0],
+            to: &arr[1],
+            pool_account: &arr[2],
+            pool_sol_reserves: &arr[3],
+            lp_mint: &arr[4],
+            burn_lp_tokens_from: &arr[5],
+            token_program: &arr[6],
+            system_program: &arr[7],
+        }
+    }
+}
+pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemoveLiquidityIxArgs {
+    pub amount_lp: u64,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveLiquidityIxData(pub RemoveLiquidityIxArgs);
+impl From<RemoveLiquidityIxArgs> for RemoveLiquidityIxData {
+    fn from(args: RemoveLiquidityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl RemoveLiquidityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != REMOVE_LIQUIDITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_LIQUIDITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(RemoveLiquidityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&REMOVE_LIQUIDITY_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for RemoveLiquidityIxData {
+    const DISCRIMINATOR: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for RemoveLiquidityIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for RemoveLiquidityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveLiquidityIxData {
+    const NAME: &'static str = "removeLiquidity";
+    const DISCM: &'static [u8] = &REMOVE_LIQUIDITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN;
+    type Keys = RemoveLiquidityKeys;
+    type Args = RemoveLiquidityIxArgs;
+}
+pub fn remove_liquidity_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveLiquidityKeys,
+    args: RemoveLiquidityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: RemoveLiquidityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn remove_liquidity_ix(
+    keys: RemoveLiquidityKeys,
+    args: RemoveLiquidityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    remove_liquidity_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityAccounts<'_>,
+    args: RemoveLiquidityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 16];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| RemoveLiquidityIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_invoke_signed(
+    accounts: RemoveLiquidityAccounts<'_>,
+    args: RemoveLiquidityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    remove_liquidity_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityAccounts<'_>,
+    args: RemoveLiquidityIxArgs,
+) -> ProgramResult {
+    remove_liquidity_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_invoke(
+    accounts: RemoveLiquidityAccounts<'_>,
+    args: RemoveLiquidityIxArgs,
+) -> ProgramResult {
+    remove_liquidity_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_verify_account_keys(
+    accounts: RemoveLiquidityAccounts<'_>,
+    keys: RemoveLiquidityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            Pubkey::new_from_array(*accounts.burn_lp_tokens_from_authority.key()),
+            keys.burn_lp_tokens_from_authority,
+        ),
+        (Pubkey::new_from_array(*accounts.to.key()), keys.to),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.lp_mint.key()), keys.lp_mint),
+        (
+            Pubkey::new_from_array(*accounts.burn_lp_tokens_from.key()),
+            keys.burn_lp_tokens_from,
+        ),
+        (Pubkey::new_from_array(*accounts.token_program.key()), keys.token_program),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_verify_writable_privileges<'me>(
+    accounts: RemoveLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.to,
+        accounts.pool_account,
+        accounts.pool_sol_reserves,
+        accounts.lp_mint,
+        accounts.burn_lp_tokens_from,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_verify_signer_privileges<'me>(
+    accounts: RemoveLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.burn_lp_tokens_from_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_liquidity_verify_account_privileges<'me>(
+    accounts: RemoveLiquidityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    remove_liquidity_verify_writable_privileges(accounts)?;
+    remove_liquidity_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_FEE_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct SetFeeAccounts<'me> {
+    pub fee_authority: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub fee_account: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetFeeKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub fee_account: Pubkey,
+    pub system_program: Pubkey,
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<SetFeeAccounts<'_>> for SetFeeKeys {
+    fn from(accounts: SetFeeAccounts) -> Self {
+        Self {
+            fee_authority: Pubkey::new_from_array(*accounts.fee_authority.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            fee_account: Pubkey::new_from_array(*accounts.fee_account.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<SetFeeKeys> for [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetFeeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.fee_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.fee_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeKeys {
+    fn from(pubkeys: [Pubkey; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            fee_authority: pubkeys[0],
+            pool_account: pubkeys[1],
+            fee_account: pubkeys[2],
+            system_program: pubkeys[3],
+            rent: pubkeys[4],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetFeeAccounts<'me>> for [&'me AccountInfo; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetFeeAccounts<'me>) -> Self {
+        [
+            accounts.fee_authority,
+            accounts.pool_account,
+            accounts.fee_account,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetFeeAccounts<'me>> for [CpiAccountMeta<'me>; SET_FEE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetFeeAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.fee_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.fee_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; SET_FEE_IX_ACCOUNTS_LEN]> for SetFeeAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; SET_FEE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            fee_authority: &arr[0],
+            pool_account: &arr[1],
+            fee_account: &arr[2],
+            system_program: &arr[3],
+            rent: &arr[4],
+        }
+    }
+}
+pub const SET_FEE_IX_DISCM: [u8; 8] = [18, 154, 24, 18, 237, 214, 19, 80];
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetFeeIxArgs {
+    pub fee: Fee,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetFeeIxData(pub SetFeeIxArgs);
+impl From<SetFeeIxArgs> for SetFeeIxData {
+    fn from(args: SetFeeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl SetFeeIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_FEE_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_FEE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(SetFeeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&SET_FEE_IX_DISCM)?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetFeeIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_FEE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetFeeIxData {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0, writer)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetFeeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetFeeIxData {
+    const NAME: &'static str = "setFee";
+    const DISCM: &'static [u8] = &SET_FEE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_FEE_IX_ACCOUNTS_LEN;
+    type Keys = SetFeeKeys;
+    type Args = SetFeeIxArgs;
+}
+pub fn set_fee_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetFeeKeys,
+    args: SetFeeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_FEE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: SetFeeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn set_fee_ix(
+    keys: SetFeeKeys,
+    args: SetFeeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    set_fee_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAccounts<'_>,
+    args: SetFeeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 41];
+    let data = write_ix_data(&mut buf, |writer| SetFeeIxData(args).serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_invoke_signed(
+    accounts: SetFeeAccounts<'_>,
+    args: SetFeeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    set_fee_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAccounts<'_>,
+    args: SetFeeIxArgs,
+) -> ProgramResult {
+    set_fee_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_invoke(
+    accounts: SetFeeAccounts<'_>,
+    args: SetFeeIxArgs,
+) -> ProgramResult {
+    set_fee_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_verify_account_keys(
+    accounts: SetFeeAccounts<'_>,
+    keys: SetFeeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.fee_authority.key()), keys.fee_authority),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (Pubkey::new_from_array(*accounts.fee_account.key()), keys.fee_account),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+        (Pubkey::new_from_array(*accounts.rent.key()), keys.rent),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_verify_writable_privileges<'me>(
+    accounts: SetFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.fee_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_verify_signer_privileges<'me>(
+    accounts: SetFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.fee_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_verify_account_privileges<'me>(
+    accounts: SetFeeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    set_fee_verify_writable_privileges(accounts)?;
+    set_fee_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct SetFeeAuthorityAccounts<'me> {
+    pub fee_authority: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub new_fee_authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SetFeeAuthorityKeys {
+    pub fee_authority: Pubkey,
+    pub pool_account: Pubkey,
+    pub new_fee_authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<SetFeeAuthorityAccounts<'_>> for SetFeeAuthorityKeys {
+    fn from(accounts: SetFeeAuthorityAccounts) -> Self {
+        Self {
+            fee_authority: Pubkey::new_from_array(*accounts.fee_authority.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            new_fee_authority: Pubkey::new_from_array(*accounts.new_fee_authority.key()),
+        }
+    }
+}
+impl From<SetFeeAuthorityKeys> for [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetFeeAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.fee_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_fee_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]> for SetFeeAuthorityKeys {
+    fn from(pubkeys: [Pubkey; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            fee_authority: pubkeys[0],
+            pool_account: pubkeys[1],
+            new_fee_authority: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetFeeAuthorityAccounts<'me>>
+for [&'me AccountInfo; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetFeeAuthorityAccounts<'me>) -> Self {
+        [accounts.fee_authority, accounts.pool_account, accounts.new_fee_authority]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetFeeAuthorityAccounts<'me>>
+for [CpiAccountMeta<'me>; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetFeeAuthorityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.fee_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_fee_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]>
+for SetFeeAuthorityAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            fee_authority: &arr[0],
+            pool_account: &arr[1],
+            new_fee_authority: &arr[2],
+        }
+    }
+}
+pub const SET_FEE_AUTHORITY_IX_DISCM: [u8; 8] = [31, 1, 50, 87, 237, 101, 97, 132];
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetFeeAuthorityIxData;
+impl SetFeeAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != SET_FEE_AUTHORITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_FEE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&SET_FEE_AUTHORITY_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for SetFeeAuthorityIxData {
+    const DISCRIMINATOR: &'static [u8] = &SET_FEE_AUTHORITY_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for SetFeeAuthorityIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for SetFeeAuthorityIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetFeeAuthorityIxData {
+    const NAME: &'static str = "setFeeAuthority";
+    const DISCM: &'static [u8] = &SET_FEE_AUTHORITY_IX_DISCM;
+    const ACCOUNTS_LEN: usize = SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = SetFeeAuthorityKeys;
+    type Args = ();
+}
+pub fn set_fee_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetFeeAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_FEE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetFeeAuthorityIxData.try_to_vec()?,
+    })
+}
+pub fn set_fee_authority_ix(
+    keys: SetFeeAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    set_fee_authority_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| SetFeeAuthorityIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_invoke_signed(
+    accounts: SetFeeAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    set_fee_authority_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetFeeAuthorityAccounts<'_>,
+) -> ProgramResult {
+    set_fee_authority_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_invoke(accounts: SetFeeAuthorityAccounts<'_>) -> ProgramResult {
+    set_fee_authority_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_verify_account_keys(
+    accounts: SetFeeAuthorityAccounts<'_>,
+    keys: SetFeeAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.fee_authority.key()), keys.fee_authority),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.new_fee_authority.key()),
+            keys.new_fee_authority,
+        ),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_verify_writable_privileges<'me>(
+    accounts: SetFeeAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.pool_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_verify_signer_privileges<'me>(
+    accounts: SetFeeAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.fee_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_fee_authority_verify_account_privileges<'me>(
+    accounts: SetFeeAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    set_fee_authority_verify_writable_privileges(accounts)?;
+    set_fee_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeactivateStakeAccountAccounts<'me> {
+    pub stake_account: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub clock: &'me AccountInfo,
+    pub stake_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeactivateStakeAccountKeys {
+    pub stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeactivateStakeAccountAccounts<'_>> for DeactivateStakeAccountKeys {
+    fn from(accounts: DeactivateStakeAccountAccounts) -> Self {
+        Self {
+            stake_account: Pubkey::new_from_array(*accounts.stake_account.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            clock: Pubkey::new_from_array(*accounts.clock.key()),
+            stake_program: Pubkey::new_from_array(*accounts.stake_program.key()),
+        }
+    }
+}
+impl From<DeactivateStakeAccountKeys>
+for [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeactivateStakeAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for DeactivateStakeAccountKeys {
+    fn from(pubkeys: [Pubkey; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_account: pubkeys[0],
+            pool_account: pubkeys[1],
+            pool_sol_reserves: pubkeys[2],
+            clock: pubkeys[3],
+            stake_program: pubkeys[4],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeactivateStakeAccountAccounts<'me>>
+for [&'me AccountInfo; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeactivateStakeAccountAccounts<'me>) -> Self {
+        [
+            accounts.stake_account,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.clock,
+            accounts.stake_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeactivateStakeAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeactivateStakeAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.stake_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.clock.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for DeactivateStakeAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_account: &arr[0],
+            pool_account: &arr[1],
+            pool_sol_reserves: &arr[2],
+            clock: &arr[3],
+            stake_program: &arr[4],
+        }
+    }
+}
+pub const DEACTIVATE_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [
+    217, 64, 76, 16, 216, 77, 123, 226,
+];
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeactivateStakeAccountIxData;
+impl DeactivateStakeAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != DEACTIVATE_STAKE_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEACTIVATE_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&DEACTIVATE_STAKE_ACCOUNT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for DeactivateStakeAccountIxData {
+    const DISCRIMINATOR: &'static [u8] = &DEACTIVATE_STAKE_ACCOUNT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for DeactivateStakeAccountIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for DeactivateStakeAccountIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeactivateStakeAccountIxData {
+    const NAME: &'static str = "deactivateStakeAccount";
+    const DISCM: &'static [u8] = &DEACTIVATE_STAKE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = DeactivateStakeAccountKeys;
+    type Args = ();
+}
+pub fn deactivate_stake_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeactivateStakeAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEACTIVATE_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DeactivateStakeAccountIxData.try_to_vec()?,
+    })
+}
+pub fn deactivate_stake_account_ix(
+    keys: DeactivateStakeAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    deactivate_stake_account_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeactivateStakeAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeactivateStakeAccountIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_invoke_signed(
+    accounts: DeactivateStakeAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    deactivate_stake_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeactivateStakeAccountAccounts<'_>,
+) -> ProgramResult {
+    deactivate_stake_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_invoke(
+    accounts: DeactivateStakeAccountAccounts<'_>,
+) -> ProgramResult {
+    deactivate_stake_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_verify_account_keys(
+    accounts: DeactivateStakeAccountAccounts<'_>,
+    keys: DeactivateStakeAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.stake_account.key()), keys.stake_account),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.clock.key()), keys.clock),
+        (Pubkey::new_from_array(*accounts.stake_program.key()), keys.stake_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_verify_writable_privileges<'me>(
+    accounts: DeactivateStakeAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.stake_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deactivate_stake_account_verify_account_privileges<'me>(
+    accounts: DeactivateStakeAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deactivate_stake_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct ReclaimStakeAccountAccounts<'me> {
+    pub stake_account: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub stake_account_record_account: &'me AccountInfo,
+    pub clock: &'me AccountInfo,
+    pub stake_history: &'me AccountInfo,
+    pub stake_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReclaimStakeAccountKeys {
+    pub stake_account: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub clock: Pubkey,
+    pub stake_history: Pubkey,
+    pub stake_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<ReclaimStakeAccountAccounts<'_>> for ReclaimStakeAccountKeys {
+    fn from(accounts: ReclaimStakeAccountAccounts) -> Self {
+        Self {
+            stake_account: Pubkey::new_from_array(*accounts.stake_account.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            stake_account_record_account: Pubkey::new_from_array(
+                *accounts.stake_account_record_account.key(),
+            ),
+            clock: Pubkey::new_from_array(*accounts.clock.key()),
+            stake_history: Pubkey::new_from_array(*accounts.stake_history.key()),
+            stake_program: Pubkey::new_from_array(*accounts.stake_program.key()),
+        }
+    }
+}
+impl From<ReclaimStakeAccountKeys>
+for [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: ReclaimStakeAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.stake_account_record_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_history,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]> for ReclaimStakeAccountKeys {
+    fn from(pubkeys: [Pubkey; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_account: pubkeys[0],
+            pool_account: pubkeys[1],
+            pool_sol_reserves: pubkeys[2],
+            stake_account_record_account: pubkeys[3],
+            clock: pubkeys[4],
+            stake_history: pubkeys[5],
+            stake_program: pubkeys[6],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ReclaimStakeAccountAccounts<'me>>
+for [&'me AccountInfo; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ReclaimStakeAccountAccounts<'me>) -> Self {
+        [
+            accounts.stake_account,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.stake_account_record_account,
+            accounts.clock,
+            accounts.stake_history,
+            accounts.stake_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ReclaimStakeAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ReclaimStakeAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.stake_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_account_record_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.clock.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_history.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for ReclaimStakeAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            stake_account: &arr[0],
+            pool_account: &arr[1],
+            pool_sol_reserves: &arr[2],
+            stake_account_record_account: &arr[3],
+            clock: &arr[4],
+            stake_history: &arr[5],
+            stake_program: &arr[6],
+        }
+    }
+}
+pub const RECLAIM_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [
+    47, 127, 90, 221, 10, 160, 183, 117,
+];
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReclaimStakeAccountIxData;
+impl ReclaimStakeAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != RECLAIM_STAKE_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        RECLAIM_STAKE_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&RECLAIM_STAKE_ACCOUNT_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for ReclaimStakeAccountIxData {
+    const DISCRIMINATOR: &'static [u8] = &RECLAIM_STAKE_ACCOUNT_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for ReclaimStakeAccountIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for ReclaimStakeAccountIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ReclaimStakeAccountIxData {
+    const NAME: &'static str = "reclaimStakeAccount";
+    const DISCM: &'static [u8] = &RECLAIM_STAKE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = ReclaimStakeAccountKeys;
+    type Args = ();
+}
+pub fn reclaim_stake_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ReclaimStakeAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; RECLAIM_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ReclaimStakeAccountIxData.try_to_vec()?,
+    })
+}
+pub fn reclaim_stake_account_ix(
+    keys: ReclaimStakeAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    reclaim_stake_account_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ReclaimStakeAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| ReclaimStakeAccountIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_invoke_signed(
+    accounts: ReclaimStakeAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    reclaim_stake_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ReclaimStakeAccountAccounts<'_>,
+) -> ProgramResult {
+    reclaim_stake_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_invoke(
+    accounts: ReclaimStakeAccountAccounts<'_>,
+) -> ProgramResult {
+    reclaim_stake_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_verify_account_keys(
+    accounts: ReclaimStakeAccountAccounts<'_>,
+    keys: ReclaimStakeAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.stake_account.key()), keys.stake_account),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (
+            Pubkey::new_from_array(*accounts.stake_account_record_account.key()),
+            keys.stake_account_record_account,
+        ),
+        (Pubkey::new_from_array(*accounts.clock.key()), keys.clock),
+        (Pubkey::new_from_array(*accounts.stake_history.key()), keys.stake_history),
+        (Pubkey::new_from_array(*accounts.stake_program.key()), keys.stake_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_verify_writable_privileges<'me>(
+    accounts: ReclaimStakeAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.stake_account,
+        accounts.pool_account,
+        accounts.pool_sol_reserves,
+        accounts.stake_account_record_account,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn reclaim_stake_account_verify_account_privileges<'me>(
+    accounts: ReclaimStakeAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    reclaim_stake_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const UNSTAKE_IX_ACCOUNTS_LEN: usize = 13;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UnstakeAccounts<'me> {
+    pub payer: &'me AccountInfo,
+    pub unstaker: &'me AccountInfo,
+    pub stake_account: &'me AccountInfo,
+    pub destination: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub fee_account: &'me AccountInfo,
+    pub stake_account_record_account: &'me AccountInfo,
+    pub protocol_fee_account: &'me AccountInfo,
+    pub protocol_fee_destination: &'me AccountInfo,
+    pub clock: &'me AccountInfo,
+    pub stake_program: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnstakeKeys {
+    pub payer: Pubkey,
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+    pub system_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UnstakeAccounts<'_>> for UnstakeKeys {
+    fn from(accounts: UnstakeAccounts) -> Self {
+        Self {
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            unstaker: Pubkey::new_from_array(*accounts.unstaker.key()),
+            stake_account: Pubkey::new_from_array(*accounts.stake_account.key()),
+            destination: Pubkey::new_from_array(*accounts.destination.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            fee_account: Pubkey::new_from_array(*accounts.fee_account.key()),
+            stake_account_record_account: Pubkey::new_from_array(
+                *accounts.stake_account_record_account.key(),
+            ),
+            protocol_fee_account: Pubkey::new_from_array(
+                *accounts.protocol_fee_account.key(),
+            ),
+            protocol_fee_destination: Pubkey::new_from_array(
+                *accounts.protocol_fee_destination.key(),
+            ),
+            clock: Pubkey::new_from_array(*accounts.clock.key()),
+            stake_program: Pubkey::new_from_array(*accounts.stake_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+        }
+    }
+}
+impl From<UnstakeKeys> for [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] {
+    fn from(keys: UnstakeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.unstaker,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fee_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_account_record_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UNSTAKE_IX_ACCOUNTS_LEN]> for UnstakeKeys {
+    fn from(pubkeys: [Pubkey; UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            unstaker: pubkeys[1],
+            stake_account: pubkeys[2],
+            destination: pubkeys[3],
+            pool_account: pubkeys[4],
+            pool_sol_reserves: pubkeys[5],
+            fee_account: pubkeys[6],
+            stake_account_record_account: pubkeys[7],
+            protocol_fee_account: pubkeys[8],
+            protocol_fee_destination: pubkeys[9],
+            clock: pubkeys[10],
+            stake_program: pubkeys[11],
+            system_program: pubkeys[12],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnstakeAccounts<'me>> for [&'me AccountInfo; UNSTAKE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnstakeAccounts<'me>) -> Self {
+        [
+            accounts.payer,
+            accounts.unstaker,
+            accounts.stake_account,
+            accounts.destination,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.fee_account,
+            accounts.stake_account_record_account,
+            accounts.protocol_fee_account,
+            accounts.protocol_fee_destination,
+            accounts.clock,
+            accounts.stake_program,
+            accounts.system_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnstakeAccounts<'me>> for [CpiAccountMeta<'me>; UNSTAKE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnstakeAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.unstaker.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.fee_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_account_record_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.clock.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UNSTAKE_IX_ACCOUNTS_LEN]> for UnstakeAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UNSTAKE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            unstaker: &arr[1],
+            stake_account: &arr[2],
+            destination: &arr[3],
+            pool_account: &arr[4],
+            pool_sol_reserves: &arr[5],
+            fee_account: &arr[6],
+            stake_account_record_account: &arr[7],
+            protocol_fee_account: &arr[8],
+            protocol_fee_destination: &arr[9],
+            clock: &arr[10],
+            stake_program: &arr[11],
+            system_program: &arr[12],
+        }
+    }
+}
+pub const UNSTAKE_IX_DISCM: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnstakeIxData;
+impl UnstakeIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != UNSTAKE_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNSTAKE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&UNSTAKE_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UnstakeIxData {
+    const DISCRIMINATOR: &'static [u8] = &UNSTAKE_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UnstakeIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UnstakeIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnstakeIxData {
+    const NAME: &'static str = "unstake";
+    const DISCM: &'static [u8] = &UNSTAKE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UNSTAKE_IX_ACCOUNTS_LEN;
+    type Keys = UnstakeKeys;
+    type Args = ();
+}
+pub fn unstake_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UnstakeKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: UnstakeIxData.try_to_vec()?,
+    })
+}
+pub fn unstake_ix(keys: UnstakeKeys) -> borsh::io::Result<Instruction> {
+    unstake_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UnstakeAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(&mut buf, |writer| UnstakeIxData.serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_invoke_signed(
+    accounts: UnstakeAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    unstake_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UnstakeAccounts<'_>,
+) -> ProgramResult {
+    unstake_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_invoke(accounts: UnstakeAccounts<'_>) -> ProgramResult {
+    unstake_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_verify_account_keys(
+    accounts: UnstakeAccounts<'_>,
+    keys: UnstakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.payer.key()), keys.payer),
+        (Pubkey::new_from_array(*accounts.unstaker.key()), keys.unstaker),
+        (Pubkey::new_from_array(*accounts.stake_account.key()), keys.stake_account),
+        (Pubkey::new_from_array(*accounts.destination.key()), keys.destination),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.fee_account.key()), keys.fee_account),
+        (
+            Pubkey::new_from_array(*accounts.stake_account_record_account.key()),
+            keys.stake_account_record_account,
+        ),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_account.key()),
+            keys.protocol_fee_account,
+        ),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_destination.key()),
+            keys.protocol_fee_destination,
+        ),
+        (Pubkey::new_from_array(*accounts.clock.key()), keys.clock),
+        (Pubkey::new_from_array(*accounts.stake_program.key()), keys.stake_program),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_verify_writable_privileges<'me>(
+    accounts: UnstakeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.payer,
+        accounts.stake_account,
+        accounts.destination,
+        accounts.pool_account,
+        accounts.pool_sol_reserves,
+        accounts.stake_account_record_account,
+        accounts.protocol_fee_destination,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_verify_signer_privileges<'me>(
+    accounts: UnstakeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.unstaker] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_verify_account_privileges<'me>(
+    accounts: UnstakeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    unstake_verify_writable_privileges(accounts)?;
+    unstake_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UNSTAKE_WSOL_IX_ACCOUNTS_LEN: usize = 14;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UnstakeWsolAccounts<'me> {
+    pub payer: &'me AccountInfo,
+    pub unstaker: &'me AccountInfo,
+    pub stake_account: &'me AccountInfo,
+    pub destination: &'me AccountInfo,
+    pub pool_account: &'me AccountInfo,
+    pub pool_sol_reserves: &'me AccountInfo,
+    pub fee_account: &'me AccountInfo,
+    pub stake_account_record_account: &'me AccountInfo,
+    pub protocol_fee_account: &'me AccountInfo,
+    pub protocol_fee_destination: &'me AccountInfo,
+    pub clock: &'me AccountInfo,
+    pub stake_program: &'me AccountInfo,
+    pub system_program: &'me AccountInfo,
+    pub token_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnstakeWsolKeys {
+    pub payer: Pubkey,
+    pub unstaker: Pubkey,
+    pub stake_account: Pubkey,
+    pub destination: Pubkey,
+    pub pool_account: Pubkey,
+    pub pool_sol_reserves: Pubkey,
+    pub fee_account: Pubkey,
+    pub stake_account_record_account: Pubkey,
+    pub protocol_fee_account: Pubkey,
+    pub protocol_fee_destination: Pubkey,
+    pub clock: Pubkey,
+    pub stake_program: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UnstakeWsolAccounts<'_>> for UnstakeWsolKeys {
+    fn from(accounts: UnstakeWsolAccounts) -> Self {
+        Self {
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            unstaker: Pubkey::new_from_array(*accounts.unstaker.key()),
+            stake_account: Pubkey::new_from_array(*accounts.stake_account.key()),
+            destination: Pubkey::new_from_array(*accounts.destination.key()),
+            pool_account: Pubkey::new_from_array(*accounts.pool_account.key()),
+            pool_sol_reserves: Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            fee_account: Pubkey::new_from_array(*accounts.fee_account.key()),
+            stake_account_record_account: Pubkey::new_from_array(
+                *accounts.stake_account_record_account.key(),
+            ),
+            protocol_fee_account: Pubkey::new_from_array(
+                *accounts.protocol_fee_account.key(),
+            ),
+            protocol_fee_destination: Pubkey::new_from_array(
+                *accounts.protocol_fee_destination.key(),
+            ),
+            clock: Pubkey::new_from_array(*accounts.clock.key()),
+            stake_program: Pubkey::new_from_array(*accounts.stake_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+        }
+    }
+}
+impl From<UnstakeWsolKeys> for [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(keys: UnstakeWsolKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.unstaker,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.pool_sol_reserves,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.fee_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_account_record_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.protocol_fee_destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.clock,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.stake_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]> for UnstakeWsolKeys {
+    fn from(pubkeys: [Pubkey; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: pubkeys[0],
+            unstaker: pubkeys[1],
+            stake_account: pubkeys[2],
+            destination: pubkeys[3],
+            pool_account: pubkeys[4],
+            pool_sol_reserves: pubkeys[5],
+            fee_account: pubkeys[6],
+            stake_account_record_account: pubkeys[7],
+            protocol_fee_account: pubkeys[8],
+            protocol_fee_destination: pubkeys[9],
+            clock: pubkeys[10],
+            stake_program: pubkeys[11],
+            system_program: pubkeys[12],
+            token_program: pubkeys[13],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnstakeWsolAccounts<'me>>
+for [&'me AccountInfo; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnstakeWsolAccounts<'me>) -> Self {
+        [
+            accounts.payer,
+            accounts.unstaker,
+            accounts.stake_account,
+            accounts.destination,
+            accounts.pool_account,
+            accounts.pool_sol_reserves,
+            accounts.fee_account,
+            accounts.stake_account_record_account,
+            accounts.protocol_fee_account,
+            accounts.protocol_fee_destination,
+            accounts.clock,
+            accounts.stake_program,
+            accounts.system_program,
+            accounts.token_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnstakeWsolAccounts<'me>>
+for [CpiAccountMeta<'me>; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnstakeWsolAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.unstaker.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.pool_sol_reserves.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.fee_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_account_record_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.protocol_fee_destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.clock.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.stake_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]>
+for UnstakeWsolAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UNSTAKE_WSOL_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            payer: &arr[0],
+            unstaker: &arr[1],
+            stake_account: &arr[2],
+            destination: &arr[3],
+            pool_account: &arr[4],
+            pool_sol_reserves: &arr[5],
+            fee_account: &arr[6],
+            stake_account_record_account: &arr[7],
+            protocol_fee_account: &arr[8],
+            protocol_fee_destination: &arr[9],
+            clock: &arr[10],
+            stake_program: &arr[11],
+            system_program: &arr[12],
+            token_program: &arr[13],
+        }
+    }
+}
+pub const UNSTAKE_WSOL_IX_DISCM: [u8; 8] = [125, 93, 190, 135, 89, 174, 142, 149];
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnstakeWsolIxData;
+impl UnstakeWsolIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm = [0u8; 8];
+        reader.read_exact(&mut maybe_discm)?;
+        if maybe_discm != UNSTAKE_WSOL_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNSTAKE_WSOL_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&UNSTAKE_WSOL_IX_DISCM)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::Discriminator for UnstakeWsolIxData {
+    const DISCRIMINATOR: &'static [u8] = &UNSTAKE_WSOL_IX_DISCM;
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::AnchorSerialize for UnstakeWsolIxData {
+    fn serialize<W: borsh::io::Write>(&self, _writer: &mut W) -> borsh::io::Result<()> {
+        Ok(())
+    }
+}
+#[cfg(feature = "anchor")]
+impl anchor_lang::InstructionData for UnstakeWsolIxData {}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnstakeWsolIxData {
+    const NAME: &'static str = "unstakeWsol";
+    const DISCM: &'static [u8] = &UNSTAKE_WSOL_IX_DISCM;
+    const ACCOUNTS_LEN: usize = UNSTAKE_WSOL_IX_ACCOUNTS_LEN;
+    type Keys = UnstakeWsolKeys;
+    type Args = ();
+}
+pub fn unstake_wsol_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UnstakeWsolKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UNSTAKE_WSOL_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: UnstakeWsolIxData.try_to_vec()?,
+    })
+}
+pub fn unstake_wsol_ix(keys: UnstakeWsolKeys) -> borsh::io::Result<Instruction> {
+    unstake_wsol_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UnstakeWsolAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 8];
+    let data = write_ix_data(&mut buf, |writer| UnstakeWsolIxData.serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_invoke_signed(
+    accounts: UnstakeWsolAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    unstake_wsol_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UnstakeWsolAccounts<'_>,
+) -> ProgramResult {
+    unstake_wsol_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_invoke(accounts: UnstakeWsolAccounts<'_>) -> ProgramResult {
+    unstake_wsol_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_verify_account_keys(
+    accounts: UnstakeWsolAccounts<'_>,
+    keys: UnstakeWsolKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (Pubkey::new_from_array(*accounts.payer.key()), keys.payer),
+        (Pubkey::new_from_array(*accounts.unstaker.key()), keys.unstaker),
+        (Pubkey::new_from_array(*accounts.stake_account.key()), keys.stake_account),
+        (Pubkey::new_from_array(*accounts.destination.key()), keys.destination),
+        (Pubkey::new_from_array(*accounts.pool_account.key()), keys.pool_account),
+        (
+            Pubkey::new_from_array(*accounts.pool_sol_reserves.key()),
+            keys.pool_sol_reserves,
+        ),
+        (Pubkey::new_from_array(*accounts.fee_account.key()), keys.fee_account),
+        (
+            Pubkey::new_from_array(*accounts.stake_account_record_account.key()),
+            keys.stake_account_record_account,
+        ),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_account.key()),
+            keys.protocol_fee_account,
+        ),
+        (
+            Pubkey::new_from_array(*accounts.protocol_fee_destination.key()),
+            keys.protocol_fee_destination,
+        ),
+        (Pubkey::new_from_array(*accounts.clock.key()), keys.clock),
+        (Pubkey::new_from_array(*accounts.stake_program.key()), keys.stake_program),
+        (Pubkey::new_from_array(*accounts.system_program.key()), keys.system_program),
+        (Pubkey::new_from_array(*accounts.token_program.key()), keys.token_program),
+    ] {
+        if actual != expected {
+            return Err((actual, expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_verify_writable_privileges<'me>(
+    accounts: UnstakeWsolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.payer,
+        accounts.stake_account,
+        accounts.destination,
+        accounts.pool_account,
+        accounts.pool_sol_reserves,
+        accounts.stake_account_record_account,
+        accounts.protocol_fee_destination,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_verify_signer_privileges<'me>(
+    accounts: UnstakeWsolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.unstaker] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unstake_wsol_verify_account_privileges<'me>(
+    accounts: UnstakeWsolAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    unstake_wsol_verify_writable_privileges(accounts)?;
+    unstake_wsol_verify_signer_privileges(accounts)?;
+    Ok(())
+}
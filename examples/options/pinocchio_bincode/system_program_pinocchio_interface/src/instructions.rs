@@ -0,0 +1,2621 @@
+use serde::{Serialize, Deserialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use pinocchio::{
+    account_info::AccountInfo, cpi::invoke_signed,
+    instruction::{AccountMeta as CpiAccountMeta, Instruction as CpiInstruction, Signer},
+    program_error::ProgramError, ProgramResult,
+};
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SystemProgramProgramIx {
+    CreateAccount(CreateAccountIxArgs),
+    Assign(AssignIxArgs),
+    Transfer(TransferIxArgs),
+    CreateAccountWithSeed(CreateAccountWithSeedIxArgs),
+    AdvanceNonceAccount,
+    WithdrawNonceAccount(WithdrawNonceAccountIxArgs),
+    InitializeNonceAccount(InitializeNonceAccountIxArgs),
+    AuthorizeNonceAccount(AuthorizeNonceAccountIxArgs),
+    Allocate(AllocateIxArgs),
+    AllocateWithSeed(AllocateWithSeedIxArgs),
+    AssignWithSeed(AssignWithSeedIxArgs),
+    TransferWithSeed(TransferWithSeedIxArgs),
+    UpgradeNonceAccount,
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction_signed<'me, A, const N: usize>(
+    program_id: &Pubkey,
+    accounts: A,
+    data: &[u8],
+    signers: &[Signer],
+) -> ProgramResult
+where
+    A: Copy + Into<[CpiAccountMeta<'me>; N]> + Into<[&'me AccountInfo; N]>,
+{
+    let metas: [CpiAccountMeta<'me>; N] = accounts.into();
+    let account_infos: [&'me AccountInfo; N] = accounts.into();
+    invoke_signed(
+        &CpiInstruction {
+            program_id: &program_id.to_bytes(),
+            accounts: &metas,
+            data,
+        },
+        &account_infos,
+        signers,
+    )
+}
+pub const CREATE_ACCOUNT_IX_DISCM: [u8; 4] = [0, 0, 0, 0];
+pub fn create_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateAccountKeys,
+    args: CreateAccountIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::CreateAccount(args),
+        Vec::from(metas),
+    )
+}
+pub fn create_account_ix(
+    keys: CreateAccountKeys,
+    args: CreateAccountIxArgs,
+) -> Instruction {
+    create_account_ix_with_program_id(crate::ID, keys, args)
+}
+pub const CREATE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateAccountAccounts<'me> {
+    ///Funding account
+    pub from: &'me AccountInfo,
+    ///The new account to be created
+    pub to: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateAccountKeys {
+    ///Funding account
+    pub from: Pubkey,
+    ///The new account to be created
+    pub to: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateAccountAccounts<'_>> for CreateAccountKeys {
+    fn from(accounts: CreateAccountAccounts) -> Self {
+        Self {
+            from: Pubkey::new_from_array(*accounts.from.key()),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+        }
+    }
+}
+impl From<CreateAccountKeys> for [AccountMeta; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]> for CreateAccountKeys {
+    fn from(pubkeys: [Pubkey; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            to: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateAccountAccounts<'me>>
+for [&'me AccountInfo; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateAccountAccounts<'me>) -> Self {
+        [accounts.from, accounts.to]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.from.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for CreateAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self { from: &arr[0], to: &arr[1] }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateAccountIxArgs {
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateAccountAccounts<'_>,
+    args: CreateAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: CreateAccountKeys = accounts.into();
+    let ix = create_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_invoke_signed(
+    accounts: CreateAccountAccounts<'_>,
+    args: CreateAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_account_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateAccountAccounts<'_>,
+    args: CreateAccountIxArgs,
+) -> ProgramResult {
+    create_account_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_invoke(
+    accounts: CreateAccountAccounts<'_>,
+    args: CreateAccountIxArgs,
+) -> ProgramResult {
+    create_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_verify_account_keys(
+    accounts: CreateAccountAccounts<'_>,
+    keys: CreateAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.from.key()), &keys.from),
+        (&Pubkey::new_from_array(*accounts.to.key()), &keys.to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_verify_writable_privileges<'me>(
+    accounts: CreateAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.from, accounts.to] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_verify_signer_privileges<'me>(
+    accounts: CreateAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.from, accounts.to] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_verify_account_privileges<'me>(
+    accounts: CreateAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_account_verify_writable_privileges(accounts)?;
+    create_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateAccountIxArgs {
+    const NAME: &'static str = "CreateAccount";
+    const DISCM: &'static [u8] = &CREATE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = CreateAccountKeys;
+    type Args = Self;
+}
+pub const ASSIGN_IX_DISCM: [u8; 4] = [1, 0, 0, 0];
+pub fn assign_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AssignKeys,
+    args: AssignIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; ASSIGN_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::Assign(args),
+        Vec::from(metas),
+    )
+}
+pub fn assign_ix(keys: AssignKeys, args: AssignIxArgs) -> Instruction {
+    assign_ix_with_program_id(crate::ID, keys, args)
+}
+pub const ASSIGN_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AssignAccounts<'me> {
+    ///The system account to assign a new program owner to
+    pub assign: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AssignKeys {
+    ///The system account to assign a new program owner to
+    pub assign: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AssignAccounts<'_>> for AssignKeys {
+    fn from(accounts: AssignAccounts) -> Self {
+        Self {
+            assign: Pubkey::new_from_array(*accounts.assign.key()),
+        }
+    }
+}
+impl From<AssignKeys> for [AccountMeta; ASSIGN_IX_ACCOUNTS_LEN] {
+    fn from(keys: AssignKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.assign,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ASSIGN_IX_ACCOUNTS_LEN]> for AssignKeys {
+    fn from(pubkeys: [Pubkey; ASSIGN_IX_ACCOUNTS_LEN]) -> Self {
+        Self { assign: pubkeys[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AssignAccounts<'me>> for [&'me AccountInfo; ASSIGN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AssignAccounts<'me>) -> Self {
+        [accounts.assign]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AssignAccounts<'me>> for [CpiAccountMeta<'me>; ASSIGN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AssignAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.assign.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ASSIGN_IX_ACCOUNTS_LEN]> for AssignAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ASSIGN_IX_ACCOUNTS_LEN]) -> Self {
+        Self { assign: &arr[0] }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AssignIxArgs {
+    pub owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn assign_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AssignAccounts<'_>,
+    args: AssignIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AssignKeys = accounts.into();
+    let ix = assign_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_invoke_signed(
+    accounts: AssignAccounts<'_>,
+    args: AssignIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    assign_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AssignAccounts<'_>,
+    args: AssignIxArgs,
+) -> ProgramResult {
+    assign_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn assign_invoke(accounts: AssignAccounts<'_>, args: AssignIxArgs) -> ProgramResult {
+    assign_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_verify_account_keys(
+    accounts: AssignAccounts<'_>,
+    keys: AssignKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.assign.key()), &keys.assign),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_verify_writable_privileges<'me>(
+    accounts: AssignAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.assign] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_verify_signer_privileges<'me>(
+    accounts: AssignAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.assign] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_verify_account_privileges<'me>(
+    accounts: AssignAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    assign_verify_writable_privileges(accounts)?;
+    assign_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AssignIxArgs {
+    const NAME: &'static str = "Assign";
+    const DISCM: &'static [u8] = &ASSIGN_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ASSIGN_IX_ACCOUNTS_LEN;
+    type Keys = AssignKeys;
+    type Args = Self;
+}
+pub const TRANSFER_IX_DISCM: [u8; 4] = [2, 0, 0, 0];
+pub fn transfer_ix_with_program_id(
+    program_id: Pubkey,
+    keys: TransferKeys,
+    args: TransferIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; TRANSFER_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::Transfer(args),
+        Vec::from(metas),
+    )
+}
+pub fn transfer_ix(keys: TransferKeys, args: TransferIxArgs) -> Instruction {
+    transfer_ix_with_program_id(crate::ID, keys, args)
+}
+pub const TRANSFER_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct TransferAccounts<'me> {
+    ///Funding account
+    pub from: &'me AccountInfo,
+    ///Recipient account
+    pub to: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct TransferKeys {
+    ///Funding account
+    pub from: Pubkey,
+    ///Recipient account
+    pub to: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<TransferAccounts<'_>> for TransferKeys {
+    fn from(accounts: TransferAccounts) -> Self {
+        Self {
+            from: Pubkey::new_from_array(*accounts.from.key()),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+        }
+    }
+}
+impl From<TransferKeys> for [AccountMeta; TRANSFER_IX_ACCOUNTS_LEN] {
+    fn from(keys: TransferKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; TRANSFER_IX_ACCOUNTS_LEN]> for TransferKeys {
+    fn from(pubkeys: [Pubkey; TRANSFER_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            to: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<TransferAccounts<'me>> for [&'me AccountInfo; TRANSFER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferAccounts<'me>) -> Self {
+        [accounts.from, accounts.to]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<TransferAccounts<'me>>
+for [CpiAccountMeta<'me>; TRANSFER_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.from.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; TRANSFER_IX_ACCOUNTS_LEN]> for TransferAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; TRANSFER_IX_ACCOUNTS_LEN]) -> Self {
+        Self { from: &arr[0], to: &arr[1] }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TransferIxArgs {
+    pub lamports: u64,
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferAccounts<'_>,
+    args: TransferIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: TransferKeys = accounts.into();
+    let ix = transfer_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_invoke_signed(
+    accounts: TransferAccounts<'_>,
+    args: TransferIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    transfer_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferAccounts<'_>,
+    args: TransferIxArgs,
+) -> ProgramResult {
+    transfer_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_invoke(
+    accounts: TransferAccounts<'_>,
+    args: TransferIxArgs,
+) -> ProgramResult {
+    transfer_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_verify_account_keys(
+    accounts: TransferAccounts<'_>,
+    keys: TransferKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.from.key()), &keys.from),
+        (&Pubkey::new_from_array(*accounts.to.key()), &keys.to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_verify_writable_privileges<'me>(
+    accounts: TransferAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.from, accounts.to] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_verify_signer_privileges<'me>(
+    accounts: TransferAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.from] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_verify_account_privileges<'me>(
+    accounts: TransferAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    transfer_verify_writable_privileges(accounts)?;
+    transfer_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for TransferIxArgs {
+    const NAME: &'static str = "Transfer";
+    const DISCM: &'static [u8] = &TRANSFER_IX_DISCM;
+    const ACCOUNTS_LEN: usize = TRANSFER_IX_ACCOUNTS_LEN;
+    type Keys = TransferKeys;
+    type Args = Self;
+}
+pub const CREATE_ACCOUNT_WITH_SEED_IX_DISCM: [u8; 4] = [3, 0, 0, 0];
+pub fn create_account_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateAccountWithSeedKeys,
+    args: CreateAccountWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::CreateAccountWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn create_account_with_seed_ix(
+    keys: CreateAccountWithSeedKeys,
+    args: CreateAccountWithSeedIxArgs,
+) -> Instruction {
+    create_account_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateAccountWithSeedAccounts<'me> {
+    ///Funding account
+    pub from: &'me AccountInfo,
+    ///The new account to be created
+    pub to: &'me AccountInfo,
+    ///Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
+    pub base: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateAccountWithSeedKeys {
+    ///Funding account
+    pub from: Pubkey,
+    ///The new account to be created
+    pub to: Pubkey,
+    ///Base account. Optional. The account matching the base Pubkey below must be provided as a signer, but may be the same as from
+    pub base: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateAccountWithSeedAccounts<'_>> for CreateAccountWithSeedKeys {
+    fn from(accounts: CreateAccountWithSeedAccounts) -> Self {
+        Self {
+            from: Pubkey::new_from_array(*accounts.from.key()),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+            base: Pubkey::new_from_array(*accounts.base.key()),
+        }
+    }
+}
+impl From<CreateAccountWithSeedKeys>
+for [AccountMeta; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateAccountWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]>
+for CreateAccountWithSeedKeys {
+    fn from(pubkeys: [Pubkey; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            to: pubkeys[1],
+            base: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateAccountWithSeedAccounts<'me>>
+for [&'me AccountInfo; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateAccountWithSeedAccounts<'me>) -> Self {
+        [accounts.from, accounts.to, accounts.base]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateAccountWithSeedAccounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateAccountWithSeedAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.from.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.base.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]>
+for CreateAccountWithSeedAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: &arr[0],
+            to: &arr[1],
+            base: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateAccountWithSeedIxArgs {
+    pub base: Pubkey,
+    pub seed: String,
+    pub lamports: u64,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateAccountWithSeedAccounts<'_>,
+    args: CreateAccountWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: CreateAccountWithSeedKeys = accounts.into();
+    let ix = create_account_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_invoke_signed(
+    accounts: CreateAccountWithSeedAccounts<'_>,
+    args: CreateAccountWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_account_with_seed_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateAccountWithSeedAccounts<'_>,
+    args: CreateAccountWithSeedIxArgs,
+) -> ProgramResult {
+    create_account_with_seed_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_invoke(
+    accounts: CreateAccountWithSeedAccounts<'_>,
+    args: CreateAccountWithSeedIxArgs,
+) -> ProgramResult {
+    create_account_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_verify_account_keys(
+    accounts: CreateAccountWithSeedAccounts<'_>,
+    keys: CreateAccountWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.from.key()), &keys.from),
+        (&Pubkey::new_from_array(*accounts.to.key()), &keys.to),
+        (&Pubkey::new_from_array(*accounts.base.key()), &keys.base),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_verify_writable_privileges<'me>(
+    accounts: CreateAccountWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.from, accounts.to] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_verify_signer_privileges<'me>(
+    accounts: CreateAccountWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.from, accounts.base] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_account_with_seed_verify_account_privileges<'me>(
+    accounts: CreateAccountWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_account_with_seed_verify_writable_privileges(accounts)?;
+    create_account_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateAccountWithSeedIxArgs {
+    const NAME: &'static str = "CreateAccountWithSeed";
+    const DISCM: &'static [u8] = &CREATE_ACCOUNT_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = CREATE_ACCOUNT_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = CreateAccountWithSeedKeys;
+    type Args = Self;
+}
+pub const ADVANCE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [4, 0, 0, 0];
+pub fn advance_nonce_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AdvanceNonceAccountKeys,
+) -> Instruction {
+    let metas: [AccountMeta; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::AdvanceNonceAccount,
+        Vec::from(metas),
+    )
+}
+pub fn advance_nonce_account_ix(keys: AdvanceNonceAccountKeys) -> Instruction {
+    advance_nonce_account_ix_with_program_id(crate::ID, keys)
+}
+pub const ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AdvanceNonceAccountAccounts<'me> {
+    ///Nonce account
+    pub nonce: &'me AccountInfo,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: &'me AccountInfo,
+    ///nonce's authority
+    pub authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AdvanceNonceAccountKeys {
+    ///Nonce account
+    pub nonce: Pubkey,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: Pubkey,
+    ///nonce's authority
+    pub authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AdvanceNonceAccountAccounts<'_>> for AdvanceNonceAccountKeys {
+    fn from(accounts: AdvanceNonceAccountAccounts) -> Self {
+        Self {
+            nonce: Pubkey::new_from_array(*accounts.nonce.key()),
+            recent_blockhashes: Pubkey::new_from_array(
+                *accounts.recent_blockhashes.key(),
+            ),
+            authority: Pubkey::new_from_array(*accounts.authority.key()),
+        }
+    }
+}
+impl From<AdvanceNonceAccountKeys>
+for [AccountMeta; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: AdvanceNonceAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.recent_blockhashes,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for AdvanceNonceAccountKeys {
+    fn from(pubkeys: [Pubkey; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: pubkeys[0],
+            recent_blockhashes: pubkeys[1],
+            authority: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AdvanceNonceAccountAccounts<'me>>
+for [&'me AccountInfo; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AdvanceNonceAccountAccounts<'me>) -> Self {
+        [accounts.nonce, accounts.recent_blockhashes, accounts.authority]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AdvanceNonceAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AdvanceNonceAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.nonce.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.recent_blockhashes.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for AdvanceNonceAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ADVANCE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: &arr[0],
+            recent_blockhashes: &arr[1],
+            authority: &arr[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AdvanceNonceAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AdvanceNonceAccountKeys = accounts.into();
+    let ix = advance_nonce_account_ix_with_program_id(program_id, keys);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_invoke_signed(
+    accounts: AdvanceNonceAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    advance_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AdvanceNonceAccountAccounts<'_>,
+) -> ProgramResult {
+    advance_nonce_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_invoke(
+    accounts: AdvanceNonceAccountAccounts<'_>,
+) -> ProgramResult {
+    advance_nonce_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_verify_account_keys(
+    accounts: AdvanceNonceAccountAccounts<'_>,
+    keys: AdvanceNonceAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.nonce.key()), &keys.nonce),
+        (
+            &Pubkey::new_from_array(*accounts.recent_blockhashes.key()),
+            &keys.recent_blockhashes,
+        ),
+        (&Pubkey::new_from_array(*accounts.authority.key()), &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_verify_writable_privileges<'me>(
+    accounts: AdvanceNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.nonce] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_verify_signer_privileges<'me>(
+    accounts: AdvanceNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn advance_nonce_account_verify_account_privileges<'me>(
+    accounts: AdvanceNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    advance_nonce_account_verify_writable_privileges(accounts)?;
+    advance_nonce_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const WITHDRAW_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [5, 0, 0, 0];
+pub fn withdraw_nonce_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: WithdrawNonceAccountKeys,
+    args: WithdrawNonceAccountIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::WithdrawNonceAccount(args),
+        Vec::from(metas),
+    )
+}
+pub fn withdraw_nonce_account_ix(
+    keys: WithdrawNonceAccountKeys,
+    args: WithdrawNonceAccountIxArgs,
+) -> Instruction {
+    withdraw_nonce_account_ix_with_program_id(crate::ID, keys, args)
+}
+pub const WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct WithdrawNonceAccountAccounts<'me> {
+    ///Nonce account
+    pub nonce: &'me AccountInfo,
+    ///Recipient account
+    pub to: &'me AccountInfo,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: &'me AccountInfo,
+    ///Rent sysvar
+    pub rent: &'me AccountInfo,
+    ///nonce's authority
+    pub authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct WithdrawNonceAccountKeys {
+    ///Nonce account
+    pub nonce: Pubkey,
+    ///Recipient account
+    pub to: Pubkey,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: Pubkey,
+    ///Rent sysvar
+    pub rent: Pubkey,
+    ///nonce's authority
+    pub authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<WithdrawNonceAccountAccounts<'_>> for WithdrawNonceAccountKeys {
+    fn from(accounts: WithdrawNonceAccountAccounts) -> Self {
+        Self {
+            nonce: Pubkey::new_from_array(*accounts.nonce.key()),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+            recent_blockhashes: Pubkey::new_from_array(
+                *accounts.recent_blockhashes.key(),
+            ),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+            authority: Pubkey::new_from_array(*accounts.authority.key()),
+        }
+    }
+}
+impl From<WithdrawNonceAccountKeys>
+for [AccountMeta; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: WithdrawNonceAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.recent_blockhashes,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for WithdrawNonceAccountKeys {
+    fn from(pubkeys: [Pubkey; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: pubkeys[0],
+            to: pubkeys[1],
+            recent_blockhashes: pubkeys[2],
+            rent: pubkeys[3],
+            authority: pubkeys[4],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<WithdrawNonceAccountAccounts<'me>>
+for [&'me AccountInfo; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: WithdrawNonceAccountAccounts<'me>) -> Self {
+        [
+            accounts.nonce,
+            accounts.to,
+            accounts.recent_blockhashes,
+            accounts.rent,
+            accounts.authority,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<WithdrawNonceAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: WithdrawNonceAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.nonce.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.recent_blockhashes.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for WithdrawNonceAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: &arr[0],
+            to: &arr[1],
+            recent_blockhashes: &arr[2],
+            rent: &arr[3],
+            authority: &arr[4],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WithdrawNonceAccountIxArgs {
+    pub lamports: u64,
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawNonceAccountAccounts<'_>,
+    args: WithdrawNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: WithdrawNonceAccountKeys = accounts.into();
+    let ix = withdraw_nonce_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_invoke_signed(
+    accounts: WithdrawNonceAccountAccounts<'_>,
+    args: WithdrawNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    withdraw_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: WithdrawNonceAccountAccounts<'_>,
+    args: WithdrawNonceAccountIxArgs,
+) -> ProgramResult {
+    withdraw_nonce_account_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_invoke(
+    accounts: WithdrawNonceAccountAccounts<'_>,
+    args: WithdrawNonceAccountIxArgs,
+) -> ProgramResult {
+    withdraw_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_verify_account_keys(
+    accounts: WithdrawNonceAccountAccounts<'_>,
+    keys: WithdrawNonceAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.nonce.key()), &keys.nonce),
+        (&Pubkey::new_from_array(*accounts.to.key()), &keys.to),
+        (
+            &Pubkey::new_from_array(*accounts.recent_blockhashes.key()),
+            &keys.recent_blockhashes,
+        ),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+        (&Pubkey::new_from_array(*accounts.authority.key()), &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_verify_writable_privileges<'me>(
+    accounts: WithdrawNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.nonce, accounts.to] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_verify_signer_privileges<'me>(
+    accounts: WithdrawNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn withdraw_nonce_account_verify_account_privileges<'me>(
+    accounts: WithdrawNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    withdraw_nonce_account_verify_writable_privileges(accounts)?;
+    withdraw_nonce_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for WithdrawNonceAccountIxArgs {
+    const NAME: &'static str = "WithdrawNonceAccount";
+    const DISCM: &'static [u8] = &WITHDRAW_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = WITHDRAW_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = WithdrawNonceAccountKeys;
+    type Args = Self;
+}
+pub const INITIALIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [6, 0, 0, 0];
+pub fn initialize_nonce_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: InitializeNonceAccountKeys,
+    args: InitializeNonceAccountIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::InitializeNonceAccount(args),
+        Vec::from(metas),
+    )
+}
+pub fn initialize_nonce_account_ix(
+    keys: InitializeNonceAccountKeys,
+    args: InitializeNonceAccountIxArgs,
+) -> Instruction {
+    initialize_nonce_account_ix_with_program_id(crate::ID, keys, args)
+}
+pub const INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct InitializeNonceAccountAccounts<'me> {
+    ///Nonce account
+    pub nonce: &'me AccountInfo,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: &'me AccountInfo,
+    ///Rent sysvar
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct InitializeNonceAccountKeys {
+    ///Nonce account
+    pub nonce: Pubkey,
+    ///RecentBlockhashes sysvar
+    pub recent_blockhashes: Pubkey,
+    ///Rent sysvar
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<InitializeNonceAccountAccounts<'_>> for InitializeNonceAccountKeys {
+    fn from(accounts: InitializeNonceAccountAccounts) -> Self {
+        Self {
+            nonce: Pubkey::new_from_array(*accounts.nonce.key()),
+            recent_blockhashes: Pubkey::new_from_array(
+                *accounts.recent_blockhashes.key(),
+            ),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<InitializeNonceAccountKeys>
+for [AccountMeta; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: InitializeNonceAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.recent_blockhashes,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for InitializeNonceAccountKeys {
+    fn from(pubkeys: [Pubkey; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: pubkeys[0],
+            recent_blockhashes: pubkeys[1],
+            rent: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<InitializeNonceAccountAccounts<'me>>
+for [&'me AccountInfo; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InitializeNonceAccountAccounts<'me>) -> Self {
+        [accounts.nonce, accounts.recent_blockhashes, accounts.rent]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<InitializeNonceAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: InitializeNonceAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.nonce.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.recent_blockhashes.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for InitializeNonceAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: &arr[0],
+            recent_blockhashes: &arr[1],
+            rent: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InitializeNonceAccountIxArgs {
+    pub authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeNonceAccountAccounts<'_>,
+    args: InitializeNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: InitializeNonceAccountKeys = accounts.into();
+    let ix = initialize_nonce_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_invoke_signed(
+    accounts: InitializeNonceAccountAccounts<'_>,
+    args: InitializeNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    initialize_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: InitializeNonceAccountAccounts<'_>,
+    args: InitializeNonceAccountIxArgs,
+) -> ProgramResult {
+    initialize_nonce_account_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_invoke(
+    accounts: InitializeNonceAccountAccounts<'_>,
+    args: InitializeNonceAccountIxArgs,
+) -> ProgramResult {
+    initialize_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_verify_account_keys(
+    accounts: InitializeNonceAccountAccounts<'_>,
+    keys: InitializeNonceAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.nonce.key()), &keys.nonce),
+        (
+            &Pubkey::new_from_array(*accounts.recent_blockhashes.key()),
+            &keys.recent_blockhashes,
+        ),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_verify_writable_privileges<'me>(
+    accounts: InitializeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.nonce] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn initialize_nonce_account_verify_account_privileges<'me>(
+    accounts: InitializeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    initialize_nonce_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitializeNonceAccountIxArgs {
+    const NAME: &'static str = "InitializeNonceAccount";
+    const DISCM: &'static [u8] = &INITIALIZE_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = INITIALIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = InitializeNonceAccountKeys;
+    type Args = Self;
+}
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [7, 0, 0, 0];
+pub fn authorize_nonce_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AuthorizeNonceAccountKeys,
+    args: AuthorizeNonceAccountIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::AuthorizeNonceAccount(args),
+        Vec::from(metas),
+    )
+}
+pub fn authorize_nonce_account_ix(
+    keys: AuthorizeNonceAccountKeys,
+    args: AuthorizeNonceAccountIxArgs,
+) -> Instruction {
+    authorize_nonce_account_ix_with_program_id(crate::ID, keys, args)
+}
+pub const AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AuthorizeNonceAccountAccounts<'me> {
+    ///Nonce account
+    pub nonce: &'me AccountInfo,
+    ///nonce's authority
+    pub authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AuthorizeNonceAccountKeys {
+    ///Nonce account
+    pub nonce: Pubkey,
+    ///nonce's authority
+    pub authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AuthorizeNonceAccountAccounts<'_>> for AuthorizeNonceAccountKeys {
+    fn from(accounts: AuthorizeNonceAccountAccounts) -> Self {
+        Self {
+            nonce: Pubkey::new_from_array(*accounts.nonce.key()),
+            authority: Pubkey::new_from_array(*accounts.authority.key()),
+        }
+    }
+}
+impl From<AuthorizeNonceAccountKeys>
+for [AccountMeta; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: AuthorizeNonceAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for AuthorizeNonceAccountKeys {
+    fn from(pubkeys: [Pubkey; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: pubkeys[0],
+            authority: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AuthorizeNonceAccountAccounts<'me>>
+for [&'me AccountInfo; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AuthorizeNonceAccountAccounts<'me>) -> Self {
+        [accounts.nonce, accounts.authority]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AuthorizeNonceAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AuthorizeNonceAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.nonce.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for AuthorizeNonceAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            nonce: &arr[0],
+            authority: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuthorizeNonceAccountIxArgs {
+    pub new_authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeNonceAccountAccounts<'_>,
+    args: AuthorizeNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AuthorizeNonceAccountKeys = accounts.into();
+    let ix = authorize_nonce_account_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_invoke_signed(
+    accounts: AuthorizeNonceAccountAccounts<'_>,
+    args: AuthorizeNonceAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    authorize_nonce_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AuthorizeNonceAccountAccounts<'_>,
+    args: AuthorizeNonceAccountIxArgs,
+) -> ProgramResult {
+    authorize_nonce_account_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_invoke(
+    accounts: AuthorizeNonceAccountAccounts<'_>,
+    args: AuthorizeNonceAccountIxArgs,
+) -> ProgramResult {
+    authorize_nonce_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_verify_account_keys(
+    accounts: AuthorizeNonceAccountAccounts<'_>,
+    keys: AuthorizeNonceAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.nonce.key()), &keys.nonce),
+        (&Pubkey::new_from_array(*accounts.authority.key()), &keys.authority),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_verify_writable_privileges<'me>(
+    accounts: AuthorizeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.nonce] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_verify_signer_privileges<'me>(
+    accounts: AuthorizeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn authorize_nonce_account_verify_account_privileges<'me>(
+    accounts: AuthorizeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    authorize_nonce_account_verify_writable_privileges(accounts)?;
+    authorize_nonce_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AuthorizeNonceAccountIxArgs {
+    const NAME: &'static str = "AuthorizeNonceAccount";
+    const DISCM: &'static [u8] = &AUTHORIZE_NONCE_ACCOUNT_IX_DISCM;
+    const ACCOUNTS_LEN: usize = AUTHORIZE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = AuthorizeNonceAccountKeys;
+    type Args = Self;
+}
+pub const ALLOCATE_IX_DISCM: [u8; 4] = [8, 0, 0, 0];
+pub fn allocate_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AllocateKeys,
+    args: AllocateIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; ALLOCATE_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::Allocate(args),
+        Vec::from(metas),
+    )
+}
+pub fn allocate_ix(keys: AllocateKeys, args: AllocateIxArgs) -> Instruction {
+    allocate_ix_with_program_id(crate::ID, keys, args)
+}
+pub const ALLOCATE_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AllocateAccounts<'me> {
+    ///The new account to allocate space for
+    pub allocate: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AllocateKeys {
+    ///The new account to allocate space for
+    pub allocate: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AllocateAccounts<'_>> for AllocateKeys {
+    fn from(accounts: AllocateAccounts) -> Self {
+        Self {
+            allocate: Pubkey::new_from_array(*accounts.allocate.key()),
+        }
+    }
+}
+impl From<AllocateKeys> for [AccountMeta; ALLOCATE_IX_ACCOUNTS_LEN] {
+    fn from(keys: AllocateKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.allocate,
+                is_signer: true,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ALLOCATE_IX_ACCOUNTS_LEN]> for AllocateKeys {
+    fn from(pubkeys: [Pubkey; ALLOCATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self { allocate: pubkeys[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AllocateAccounts<'me>> for [&'me AccountInfo; ALLOCATE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AllocateAccounts<'me>) -> Self {
+        [accounts.allocate]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AllocateAccounts<'me>>
+for [CpiAccountMeta<'me>; ALLOCATE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AllocateAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.allocate.key(),
+                is_writable: true,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ALLOCATE_IX_ACCOUNTS_LEN]> for AllocateAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ALLOCATE_IX_ACCOUNTS_LEN]) -> Self {
+        Self { allocate: &arr[0] }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AllocateIxArgs {
+    pub space: u64,
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AllocateAccounts<'_>,
+    args: AllocateIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AllocateKeys = accounts.into();
+    let ix = allocate_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_invoke_signed(
+    accounts: AllocateAccounts<'_>,
+    args: AllocateIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    allocate_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AllocateAccounts<'_>,
+    args: AllocateIxArgs,
+) -> ProgramResult {
+    allocate_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_invoke(
+    accounts: AllocateAccounts<'_>,
+    args: AllocateIxArgs,
+) -> ProgramResult {
+    allocate_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_verify_account_keys(
+    accounts: AllocateAccounts<'_>,
+    keys: AllocateKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.allocate.key()), &keys.allocate),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_verify_writable_privileges<'me>(
+    accounts: AllocateAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.allocate] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_verify_signer_privileges<'me>(
+    accounts: AllocateAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.allocate] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_verify_account_privileges<'me>(
+    accounts: AllocateAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    allocate_verify_writable_privileges(accounts)?;
+    allocate_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AllocateIxArgs {
+    const NAME: &'static str = "Allocate";
+    const DISCM: &'static [u8] = &ALLOCATE_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ALLOCATE_IX_ACCOUNTS_LEN;
+    type Keys = AllocateKeys;
+    type Args = Self;
+}
+pub const ALLOCATE_WITH_SEED_IX_DISCM: [u8; 4] = [9, 0, 0, 0];
+pub fn allocate_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AllocateWithSeedKeys,
+    args: AllocateWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::AllocateWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn allocate_with_seed_ix(
+    keys: AllocateWithSeedKeys,
+    args: AllocateWithSeedIxArgs,
+) -> Instruction {
+    allocate_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AllocateWithSeedAccounts<'me> {
+    ///The new account to allocate space for
+    pub allocate: &'me AccountInfo,
+    ///Base account
+    pub base: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AllocateWithSeedKeys {
+    ///The new account to allocate space for
+    pub allocate: Pubkey,
+    ///Base account
+    pub base: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AllocateWithSeedAccounts<'_>> for AllocateWithSeedKeys {
+    fn from(accounts: AllocateWithSeedAccounts) -> Self {
+        Self {
+            allocate: Pubkey::new_from_array(*accounts.allocate.key()),
+            base: Pubkey::new_from_array(*accounts.base.key()),
+        }
+    }
+}
+impl From<AllocateWithSeedKeys> for [AccountMeta; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(keys: AllocateWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.allocate,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]> for AllocateWithSeedKeys {
+    fn from(pubkeys: [Pubkey; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            allocate: pubkeys[0],
+            base: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AllocateWithSeedAccounts<'me>>
+for [&'me AccountInfo; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AllocateWithSeedAccounts<'me>) -> Self {
+        [accounts.allocate, accounts.base]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AllocateWithSeedAccounts<'me>>
+for [CpiAccountMeta<'me>; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AllocateWithSeedAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.allocate.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.base.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]>
+for AllocateWithSeedAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            allocate: &arr[0],
+            base: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AllocateWithSeedIxArgs {
+    pub base: Pubkey,
+    pub seed: String,
+    pub space: u64,
+    pub owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AllocateWithSeedAccounts<'_>,
+    args: AllocateWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AllocateWithSeedKeys = accounts.into();
+    let ix = allocate_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_invoke_signed(
+    accounts: AllocateWithSeedAccounts<'_>,
+    args: AllocateWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    allocate_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AllocateWithSeedAccounts<'_>,
+    args: AllocateWithSeedIxArgs,
+) -> ProgramResult {
+    allocate_with_seed_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_invoke(
+    accounts: AllocateWithSeedAccounts<'_>,
+    args: AllocateWithSeedIxArgs,
+) -> ProgramResult {
+    allocate_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_verify_account_keys(
+    accounts: AllocateWithSeedAccounts<'_>,
+    keys: AllocateWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.allocate.key()), &keys.allocate),
+        (&Pubkey::new_from_array(*accounts.base.key()), &keys.base),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_verify_writable_privileges<'me>(
+    accounts: AllocateWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.allocate] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_verify_signer_privileges<'me>(
+    accounts: AllocateWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.base] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn allocate_with_seed_verify_account_privileges<'me>(
+    accounts: AllocateWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    allocate_with_seed_verify_writable_privileges(accounts)?;
+    allocate_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AllocateWithSeedIxArgs {
+    const NAME: &'static str = "AllocateWithSeed";
+    const DISCM: &'static [u8] = &ALLOCATE_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ALLOCATE_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AllocateWithSeedKeys;
+    type Args = Self;
+}
+pub const ASSIGN_WITH_SEED_IX_DISCM: [u8; 4] = [10, 0, 0, 0];
+pub fn assign_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: AssignWithSeedKeys,
+    args: AssignWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::AssignWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn assign_with_seed_ix(
+    keys: AssignWithSeedKeys,
+    args: AssignWithSeedIxArgs,
+) -> Instruction {
+    assign_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct AssignWithSeedAccounts<'me> {
+    ///The system account to assign a new program owner to
+    pub assign: &'me AccountInfo,
+    ///Base account
+    pub base: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct AssignWithSeedKeys {
+    ///The system account to assign a new program owner to
+    pub assign: Pubkey,
+    ///Base account
+    pub base: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<AssignWithSeedAccounts<'_>> for AssignWithSeedKeys {
+    fn from(accounts: AssignWithSeedAccounts) -> Self {
+        Self {
+            assign: Pubkey::new_from_array(*accounts.assign.key()),
+            base: Pubkey::new_from_array(*accounts.base.key()),
+        }
+    }
+}
+impl From<AssignWithSeedKeys> for [AccountMeta; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(keys: AssignWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.assign,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]> for AssignWithSeedKeys {
+    fn from(pubkeys: [Pubkey; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            assign: pubkeys[0],
+            base: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AssignWithSeedAccounts<'me>>
+for [&'me AccountInfo; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AssignWithSeedAccounts<'me>) -> Self {
+        [accounts.assign, accounts.base]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<AssignWithSeedAccounts<'me>>
+for [CpiAccountMeta<'me>; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: AssignWithSeedAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.assign.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.base.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]>
+for AssignWithSeedAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            assign: &arr[0],
+            base: &arr[1],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AssignWithSeedIxArgs {
+    pub base: Pubkey,
+    pub seed: String,
+    pub owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: AssignWithSeedAccounts<'_>,
+    args: AssignWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: AssignWithSeedKeys = accounts.into();
+    let ix = assign_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_invoke_signed(
+    accounts: AssignWithSeedAccounts<'_>,
+    args: AssignWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    assign_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: AssignWithSeedAccounts<'_>,
+    args: AssignWithSeedIxArgs,
+) -> ProgramResult {
+    assign_with_seed_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_invoke(
+    accounts: AssignWithSeedAccounts<'_>,
+    args: AssignWithSeedIxArgs,
+) -> ProgramResult {
+    assign_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_verify_account_keys(
+    accounts: AssignWithSeedAccounts<'_>,
+    keys: AssignWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.assign.key()), &keys.assign),
+        (&Pubkey::new_from_array(*accounts.base.key()), &keys.base),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_verify_writable_privileges<'me>(
+    accounts: AssignWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.assign] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_verify_signer_privileges<'me>(
+    accounts: AssignWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.base] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn assign_with_seed_verify_account_privileges<'me>(
+    accounts: AssignWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    assign_with_seed_verify_writable_privileges(accounts)?;
+    assign_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for AssignWithSeedIxArgs {
+    const NAME: &'static str = "AssignWithSeed";
+    const DISCM: &'static [u8] = &ASSIGN_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = ASSIGN_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = AssignWithSeedKeys;
+    type Args = Self;
+}
+pub const TRANSFER_WITH_SEED_IX_DISCM: [u8; 4] = [11, 0, 0, 0];
+pub fn transfer_with_seed_ix_with_program_id(
+    program_id: Pubkey,
+    keys: TransferWithSeedKeys,
+    args: TransferWithSeedIxArgs,
+) -> Instruction {
+    let metas: [AccountMeta; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::TransferWithSeed(args),
+        Vec::from(metas),
+    )
+}
+pub fn transfer_with_seed_ix(
+    keys: TransferWithSeedKeys,
+    args: TransferWithSeedIxArgs,
+) -> Instruction {
+    transfer_with_seed_ix_with_program_id(crate::ID, keys, args)
+}
+pub const TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct TransferWithSeedAccounts<'me> {
+    ///Funding account
+    pub from: &'me AccountInfo,
+    ///from's base account
+    pub base: &'me AccountInfo,
+    ///Recipient account
+    pub to: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct TransferWithSeedKeys {
+    ///Funding account
+    pub from: Pubkey,
+    ///from's base account
+    pub base: Pubkey,
+    ///Recipient account
+    pub to: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<TransferWithSeedAccounts<'_>> for TransferWithSeedKeys {
+    fn from(accounts: TransferWithSeedAccounts) -> Self {
+        Self {
+            from: Pubkey::new_from_array(*accounts.from.key()),
+            base: Pubkey::new_from_array(*accounts.base.key()),
+            to: Pubkey::new_from_array(*accounts.to.key()),
+        }
+    }
+}
+impl From<TransferWithSeedKeys> for [AccountMeta; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(keys: TransferWithSeedKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.from,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.base,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.to,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]> for TransferWithSeedKeys {
+    fn from(pubkeys: [Pubkey; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: pubkeys[0],
+            base: pubkeys[1],
+            to: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<TransferWithSeedAccounts<'me>>
+for [&'me AccountInfo; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferWithSeedAccounts<'me>) -> Self {
+        [accounts.from, accounts.base, accounts.to]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<TransferWithSeedAccounts<'me>>
+for [CpiAccountMeta<'me>; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN] {
+    fn from(accounts: TransferWithSeedAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.from.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.base.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.to.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]>
+for TransferWithSeedAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            from: &arr[0],
+            base: &arr[1],
+            to: &arr[2],
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TransferWithSeedIxArgs {
+    pub lamports: u64,
+    pub from_seed: String,
+    pub from_owner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferWithSeedAccounts<'_>,
+    args: TransferWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: TransferWithSeedKeys = accounts.into();
+    let ix = transfer_with_seed_ix_with_program_id(program_id, keys, args);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_invoke_signed(
+    accounts: TransferWithSeedAccounts<'_>,
+    args: TransferWithSeedIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    transfer_with_seed_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: TransferWithSeedAccounts<'_>,
+    args: TransferWithSeedIxArgs,
+) -> ProgramResult {
+    transfer_with_seed_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_invoke(
+    accounts: TransferWithSeedAccounts<'_>,
+    args: TransferWithSeedIxArgs,
+) -> ProgramResult {
+    transfer_with_seed_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_verify_account_keys(
+    accounts: TransferWithSeedAccounts<'_>,
+    keys: TransferWithSeedKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.from.key()), &keys.from),
+        (&Pubkey::new_from_array(*accounts.base.key()), &keys.base),
+        (&Pubkey::new_from_array(*accounts.to.key()), &keys.to),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_verify_writable_privileges<'me>(
+    accounts: TransferWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.from, accounts.to] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_verify_signer_privileges<'me>(
+    accounts: TransferWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.base] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn transfer_with_seed_verify_account_privileges<'me>(
+    accounts: TransferWithSeedAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    transfer_with_seed_verify_writable_privileges(accounts)?;
+    transfer_with_seed_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for TransferWithSeedIxArgs {
+    const NAME: &'static str = "TransferWithSeed";
+    const DISCM: &'static [u8] = &TRANSFER_WITH_SEED_IX_DISCM;
+    const ACCOUNTS_LEN: usize = TRANSFER_WITH_SEED_IX_ACCOUNTS_LEN;
+    type Keys = TransferWithSeedKeys;
+    type Args = Self;
+}
+pub const UPGRADE_NONCE_ACCOUNT_IX_DISCM: [u8; 4] = [12, 0, 0, 0];
+pub fn upgrade_nonce_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpgradeNonceAccountKeys,
+) -> Instruction {
+    let metas: [AccountMeta; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Instruction::new_with_bincode(
+        program_id,
+        &SystemProgramProgramIx::UpgradeNonceAccount,
+        Vec::from(metas),
+    )
+}
+pub fn upgrade_nonce_account_ix(keys: UpgradeNonceAccountKeys) -> Instruction {
+    upgrade_nonce_account_ix_with_program_id(crate::ID, keys)
+}
+pub const UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UpgradeNonceAccountAccounts<'me> {
+    ///Nonce account
+    pub nonce: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpgradeNonceAccountKeys {
+    ///Nonce account
+    pub nonce: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UpgradeNonceAccountAccounts<'_>> for UpgradeNonceAccountKeys {
+    fn from(accounts: UpgradeNonceAccountAccounts) -> Self {
+        Self {
+            nonce: Pubkey::new_from_array(*accounts.nonce.key()),
+        }
+    }
+}
+impl From<UpgradeNonceAccountKeys>
+for [AccountMeta; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpgradeNonceAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.nonce,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]> for UpgradeNonceAccountKeys {
+    fn from(pubkeys: [Pubkey; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self { nonce: pubkeys[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpgradeNonceAccountAccounts<'me>>
+for [&'me AccountInfo; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpgradeNonceAccountAccounts<'me>) -> Self {
+        [accounts.nonce]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpgradeNonceAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpgradeNonceAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.nonce.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]>
+for UpgradeNonceAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UPGRADE_NONCE_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self { nonce: &arr[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpgradeNonceAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let keys: UpgradeNonceAccountKeys = accounts.into();
+    let ix = upgrade_nonce_account_ix_with_program_id(program_id, keys);
+    invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_invoke_signed(
+    accounts: UpgradeNonceAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    upgrade_nonce_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpgradeNonceAccountAccounts<'_>,
+) -> ProgramResult {
+    upgrade_nonce_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_invoke(
+    accounts: UpgradeNonceAccountAccounts<'_>,
+) -> ProgramResult {
+    upgrade_nonce_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_verify_account_keys(
+    accounts: UpgradeNonceAccountAccounts<'_>,
+    keys: UpgradeNonceAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.nonce.key()), &keys.nonce),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_verify_writable_privileges<'me>(
+    accounts: UpgradeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.nonce] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn upgrade_nonce_account_verify_account_privileges<'me>(
+    accounts: UpgradeNonceAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    upgrade_nonce_account_verify_writable_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use crate::*;
+use solana_program::pubkey::Pubkey;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UseAuthorityRecord {
+    pub key: Key,
+    pub allowed_uses: u64,
+    pub bump: u8,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for UseAuthorityRecord {
+    const NAME: &'static str = "UseAuthorityRecord";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CollectionAuthorityRecord {
+    pub key: Key,
+    pub bump: u8,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for CollectionAuthorityRecord {
+    const NAME: &'static str = "CollectionAuthorityRecord";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata {
+    pub key: Key,
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub data: Data,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Metadata {
+    const NAME: &'static str = "Metadata";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MasterEditionV2 {
+    pub key: Key,
+    pub supply: u64,
+    pub max_supply: Option<u64>,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for MasterEditionV2 {
+    const NAME: &'static str = "MasterEditionV2";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MasterEditionV1 {
+    pub key: Key,
+    pub supply: u64,
+    pub max_supply: Option<u64>,
+    pub printing_mint: Pubkey,
+    pub one_time_printing_authorization_mint: Pubkey,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for MasterEditionV1 {
+    const NAME: &'static str = "MasterEditionV1";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edition {
+    pub key: Key,
+    pub parent: Pubkey,
+    pub edition: u64,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Edition {
+    const NAME: &'static str = "Edition";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReservationListV2 {
+    pub key: Key,
+    pub master_edition: Pubkey,
+    pub supply_snapshot: Option<u64>,
+    pub reservations: Vec<Reservation>,
+    pub total_reservation_spots: u64,
+    pub current_reservation_spots: u64,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ReservationListV2 {
+    const NAME: &'static str = "ReservationListV2";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReservationListV1 {
+    pub key: Key,
+    pub master_edition: Pubkey,
+    pub supply_snapshot: Option<u64>,
+    pub reservations: Vec<ReservationV1>,
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ReservationListV1 {
+    const NAME: &'static str = "ReservationListV1";
+    const DISCM: &'static [u8] = &[];
+}
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EditionMarker {
+    pub key: Key,
+    pub ledger: [u8; 31],
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for EditionMarker {
+    const NAME: &'static str = "EditionMarker";
+    const DISCM: &'static [u8] = &[];
+}
@@ -0,0 +1,10511 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use pinocchio::{
+    account_info::AccountInfo, cpi::invoke_signed,
+    instruction::{AccountMeta as CpiAccountMeta, Instruction as CpiInstruction, Signer},
+    program_error::ProgramError, ProgramResult,
+};
+use borsh::io::Read;
+use crate::*;
+#[derive(Clone, Debug, PartialEq)]
+pub enum MplTokenMetadataProgramIx {
+    CreateMetadataAccount(CreateMetadataAccountIxArgs),
+    UpdateMetadataAccount(UpdateMetadataAccountIxArgs),
+    DeprecatedCreateMasterEdition(DeprecatedCreateMasterEditionIxArgs),
+    DeprecatedMintNewEditionFromMasterEditionViaPrintingToken,
+    UpdatePrimarySaleHappenedViaToken,
+    DeprecatedSetReservationList(DeprecatedSetReservationListIxArgs),
+    DeprecatedCreateReservationList,
+    SignMetadata,
+    DeprecatedMintPrintingTokensViaToken(DeprecatedMintPrintingTokensViaTokenIxArgs),
+    DeprecatedMintPrintingTokens(DeprecatedMintPrintingTokensIxArgs),
+    CreateMasterEdition(CreateMasterEditionIxArgs),
+    MintNewEditionFromMasterEditionViaToken(
+        MintNewEditionFromMasterEditionViaTokenIxArgs,
+    ),
+    ConvertMasterEditionV1ToV2,
+    MintNewEditionFromMasterEditionViaVaultProxy(
+        MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+    ),
+    PuffMetadata,
+    UpdateMetadataAccountV2(UpdateMetadataAccountV2IxArgs),
+    CreateMetadataAccountV2(CreateMetadataAccountV2IxArgs),
+    CreateMasterEditionV3(CreateMasterEditionV3IxArgs),
+    VerifyCollection,
+    Utilize(UtilizeIxArgs),
+    ApproveUseAuthority(ApproveUseAuthorityIxArgs),
+    RevokeUseAuthority,
+    UnverifyCollection,
+    ApproveCollectionAuthority,
+    RevokeCollectionAuthority,
+    SetAndVerifyCollection,
+    FreezeDelegatedAccount,
+    ThawDelegatedAccount,
+    RemoveCreatorVerification,
+}
+impl MplTokenMetadataProgramIx {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        match maybe_discm {
+            CREATE_METADATA_ACCOUNT_IX_DISCM => {
+                Ok(
+                    Self::CreateMetadataAccount(
+                        CreateMetadataAccountIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            UPDATE_METADATA_ACCOUNT_IX_DISCM => {
+                Ok(
+                    Self::UpdateMetadataAccount(
+                        UpdateMetadataAccountIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedCreateMasterEdition(
+                        DeprecatedCreateMasterEditionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM => {
+                Ok(Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken)
+            }
+            UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM => {
+                Ok(Self::UpdatePrimarySaleHappenedViaToken)
+            }
+            DEPRECATED_SET_RESERVATION_LIST_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedSetReservationList(
+                        DeprecatedSetReservationListIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM => {
+                Ok(Self::DeprecatedCreateReservationList)
+            }
+            SIGN_METADATA_IX_DISCM => Ok(Self::SignMetadata),
+            DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedMintPrintingTokensViaToken(
+                        DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedMintPrintingTokens(
+                        DeprecatedMintPrintingTokensIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_MASTER_EDITION_IX_DISCM => {
+                Ok(
+                    Self::CreateMasterEdition(
+                        CreateMasterEditionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM => {
+                Ok(
+                    Self::MintNewEditionFromMasterEditionViaToken(
+                        MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM => {
+                Ok(Self::ConvertMasterEditionV1ToV2)
+            }
+            MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM => {
+                Ok(
+                    Self::MintNewEditionFromMasterEditionViaVaultProxy(
+                        MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            PUFF_METADATA_IX_DISCM => Ok(Self::PuffMetadata),
+            UPDATE_METADATA_ACCOUNT_V2_IX_DISCM => {
+                Ok(
+                    Self::UpdateMetadataAccountV2(
+                        UpdateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_METADATA_ACCOUNT_V2_IX_DISCM => {
+                Ok(
+                    Self::CreateMetadataAccountV2(
+                        CreateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_MASTER_EDITION_V3_IX_DISCM => {
+                Ok(
+                    Self::CreateMasterEditionV3(
+                        CreateMasterEditionV3IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            VERIFY_COLLECTION_IX_DISCM => Ok(Self::VerifyCollection),
+            UTILIZE_IX_DISCM => {
+                Ok(Self::Utilize(UtilizeIxArgs::deserialize(&mut reader)?))
+            }
+            APPROVE_USE_AUTHORITY_IX_DISCM => {
+                Ok(
+                    Self::ApproveUseAuthority(
+                        ApproveUseAuthorityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            REVOKE_USE_AUTHORITY_IX_DISCM => Ok(Self::RevokeUseAuthority),
+            UNVERIFY_COLLECTION_IX_DISCM => Ok(Self::UnverifyCollection),
+            APPROVE_COLLECTION_AUTHORITY_IX_DISCM => Ok(Self::ApproveCollectionAuthority),
+            REVOKE_COLLECTION_AUTHORITY_IX_DISCM => Ok(Self::RevokeCollectionAuthority),
+            SET_AND_VERIFY_COLLECTION_IX_DISCM => Ok(Self::SetAndVerifyCollection),
+            FREEZE_DELEGATED_ACCOUNT_IX_DISCM => Ok(Self::FreezeDelegatedAccount),
+            THAW_DELEGATED_ACCOUNT_IX_DISCM => Ok(Self::ThawDelegatedAccount),
+            REMOVE_CREATOR_VERIFICATION_IX_DISCM => Ok(Self::RemoveCreatorVerification),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
+        }
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        match self {
+            Self::CreateMetadataAccount(args) => {
+                writer.write_all(&[CREATE_METADATA_ACCOUNT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::UpdateMetadataAccount(args) => {
+                writer.write_all(&[UPDATE_METADATA_ACCOUNT_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DeprecatedCreateMasterEdition(args) => {
+                writer.write_all(&[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken => {
+                writer
+                    .write_all(
+                        &[
+                            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                        ],
+                    )
+            }
+            Self::UpdatePrimarySaleHappenedViaToken => {
+                writer.write_all(&[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM])
+            }
+            Self::DeprecatedSetReservationList(args) => {
+                writer.write_all(&[DEPRECATED_SET_RESERVATION_LIST_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DeprecatedCreateReservationList => {
+                writer.write_all(&[DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM])
+            }
+            Self::SignMetadata => writer.write_all(&[SIGN_METADATA_IX_DISCM]),
+            Self::DeprecatedMintPrintingTokensViaToken(args) => {
+                writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::DeprecatedMintPrintingTokens(args) => {
+                writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::CreateMasterEdition(args) => {
+                writer.write_all(&[CREATE_MASTER_EDITION_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::MintNewEditionFromMasterEditionViaToken(args) => {
+                writer
+                    .write_all(
+                        &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM],
+                    )?;
+                args.serialize(&mut writer)
+            }
+            Self::ConvertMasterEditionV1ToV2 => {
+                writer.write_all(&[CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM])
+            }
+            Self::MintNewEditionFromMasterEditionViaVaultProxy(args) => {
+                writer
+                    .write_all(
+                        &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM],
+                    )?;
+                args.serialize(&mut writer)
+            }
+            Self::PuffMetadata => writer.write_all(&[PUFF_METADATA_IX_DISCM]),
+            Self::UpdateMetadataAccountV2(args) => {
+                writer.write_all(&[UPDATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::CreateMetadataAccountV2(args) => {
+                writer.write_all(&[CREATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::CreateMasterEditionV3(args) => {
+                writer.write_all(&[CREATE_MASTER_EDITION_V3_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::VerifyCollection => writer.write_all(&[VERIFY_COLLECTION_IX_DISCM]),
+            Self::Utilize(args) => {
+                writer.write_all(&[UTILIZE_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::ApproveUseAuthority(args) => {
+                writer.write_all(&[APPROVE_USE_AUTHORITY_IX_DISCM])?;
+                args.serialize(&mut writer)
+            }
+            Self::RevokeUseAuthority => {
+                writer.write_all(&[REVOKE_USE_AUTHORITY_IX_DISCM])
+            }
+            Self::UnverifyCollection => writer.write_all(&[UNVERIFY_COLLECTION_IX_DISCM]),
+            Self::ApproveCollectionAuthority => {
+                writer.write_all(&[APPROVE_COLLECTION_AUTHORITY_IX_DISCM])
+            }
+            Self::RevokeCollectionAuthority => {
+                writer.write_all(&[REVOKE_COLLECTION_AUTHORITY_IX_DISCM])
+            }
+            Self::SetAndVerifyCollection => {
+                writer.write_all(&[SET_AND_VERIFY_COLLECTION_IX_DISCM])
+            }
+            Self::FreezeDelegatedAccount => {
+                writer.write_all(&[FREEZE_DELEGATED_ACCOUNT_IX_DISCM])
+            }
+            Self::ThawDelegatedAccount => {
+                writer.write_all(&[THAW_DELEGATED_ACCOUNT_IX_DISCM])
+            }
+            Self::RemoveCreatorVerification => {
+                writer.write_all(&[REMOVE_CREATOR_VERIFICATION_IX_DISCM])
+            }
+        }
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+/// Serializes instruction data into `buf` with `serialize`, returning the written bytes
+#[cfg(feature = "cpi")]
+fn write_ix_data(
+    buf: &mut [u8],
+    serialize: impl FnOnce(&mut &mut [u8]) -> borsh::io::Result<()>,
+) -> Result<&[u8], ProgramError> {
+    let buf_len = buf.len();
+    let mut writer = &mut *buf;
+    serialize(&mut writer).map_err(|_| ProgramError::InvalidInstructionData)?;
+    let len = buf_len - writer.len();
+    Ok(&buf[..len])
+}
+#[cfg(feature = "cpi")]
+fn invoke_instruction_signed<'me, A, const N: usize>(
+    program_id: &Pubkey,
+    accounts: A,
+    data: &[u8],
+    signers: &[Signer],
+) -> ProgramResult
+where
+    A: Copy + Into<[CpiAccountMeta<'me>; N]> + Into<[&'me AccountInfo; N]>,
+{
+    let metas: [CpiAccountMeta<'me>; N] = accounts.into();
+    let account_infos: [&'me AccountInfo; N] = accounts.into();
+    invoke_signed(
+        &CpiInstruction {
+            program_id: &program_id.to_bytes(),
+            accounts: &metas,
+            data,
+        },
+        &account_infos,
+        signers,
+    )
+}
+/// Size of the stack buffer that `*_invoke*()` functions serialize the data of
+/// instructions with unbounded args into.
+/// Data that doesn't fit fails with `ProgramError::InvalidInstructionData`.
+pub const CPI_IX_DATA_BUF_LEN: usize = 1024;
+pub const CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateMetadataAccountAccounts<'me> {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Mint of token asset
+    pub mint: &'me AccountInfo,
+    ///Mint authority
+    pub mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///update authority info
+    pub update_authority: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateMetadataAccountKeys {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Mint of token asset
+    pub mint: Pubkey,
+    ///Mint authority
+    pub mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///update authority info
+    pub update_authority: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateMetadataAccountAccounts<'_>> for CreateMetadataAccountKeys {
+    fn from(accounts: CreateMetadataAccountAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<CreateMetadataAccountKeys>
+for [AccountMeta; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateMetadataAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountKeys {
+    fn from(pubkeys: [Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            mint: pubkeys[1],
+            mint_authority: pubkeys[2],
+            payer: pubkeys[3],
+            update_authority: pubkeys[4],
+            system_program: pubkeys[5],
+            rent: pubkeys[6],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMetadataAccountAccounts<'me>>
+for [&'me AccountInfo; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMetadataAccountAccounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.mint,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMetadataAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMetadataAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            mint: &arr[1],
+            mint_authority: &arr[2],
+            payer: &arr[3],
+            update_authority: &arr[4],
+            system_program: &arr[5],
+            rent: &arr[6],
+        }
+    }
+}
+pub const CREATE_METADATA_ACCOUNT_IX_DISCM: u8 = 0u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateMetadataAccountIxArgs {
+    pub create_metadata_account_args: CreateMetadataAccountArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateMetadataAccountIxData(pub CreateMetadataAccountIxArgs);
+impl From<CreateMetadataAccountIxArgs> for CreateMetadataAccountIxData {
+    fn from(args: CreateMetadataAccountIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateMetadataAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CREATE_METADATA_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateMetadataAccountIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[CREATE_METADATA_ACCOUNT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMetadataAccountIxData {
+    const NAME: &'static str = "CreateMetadataAccount";
+    const DISCM: &'static [u8] = &[CREATE_METADATA_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = CreateMetadataAccountKeys;
+    type Args = CreateMetadataAccountIxArgs;
+}
+pub fn create_metadata_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateMetadataAccountKeys,
+    args: CreateMetadataAccountIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateMetadataAccountIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_metadata_account_ix(
+    keys: CreateMetadataAccountKeys,
+    args: CreateMetadataAccountIxArgs,
+) -> borsh::io::Result<Instruction> {
+    create_metadata_account_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMetadataAccountAccounts<'_>,
+    args: CreateMetadataAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; CPI_IX_DATA_BUF_LEN];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| CreateMetadataAccountIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_invoke_signed(
+    accounts: CreateMetadataAccountAccounts<'_>,
+    args: CreateMetadataAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_metadata_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMetadataAccountAccounts<'_>,
+    args: CreateMetadataAccountIxArgs,
+) -> ProgramResult {
+    create_metadata_account_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_invoke(
+    accounts: CreateMetadataAccountAccounts<'_>,
+    args: CreateMetadataAccountIxArgs,
+) -> ProgramResult {
+    create_metadata_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_verify_account_keys(
+    accounts: CreateMetadataAccountAccounts<'_>,
+    keys: CreateMetadataAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_verify_writable_privileges<'me>(
+    accounts: CreateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_verify_signer_privileges<'me>(
+    accounts: CreateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.mint_authority, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_verify_account_privileges<'me>(
+    accounts: CreateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_metadata_account_verify_writable_privileges(accounts)?;
+    create_metadata_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UpdateMetadataAccountAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Update authority key
+    pub update_authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateMetadataAccountKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Update authority key
+    pub update_authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UpdateMetadataAccountAccounts<'_>> for UpdateMetadataAccountKeys {
+    fn from(accounts: UpdateMetadataAccountAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+        }
+    }
+}
+impl From<UpdateMetadataAccountKeys>
+for [AccountMeta; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateMetadataAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountKeys {
+    fn from(pubkeys: [Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            update_authority: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdateMetadataAccountAccounts<'me>>
+for [&'me AccountInfo; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdateMetadataAccountAccounts<'me>) -> Self {
+        [accounts.metadata, accounts.update_authority]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdateMetadataAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdateMetadataAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            update_authority: &arr[1],
+        }
+    }
+}
+pub const UPDATE_METADATA_ACCOUNT_IX_DISCM: u8 = 1u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateMetadataAccountIxArgs {
+    pub update_metadata_account_args: UpdateMetadataAccountArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateMetadataAccountIxData(pub UpdateMetadataAccountIxArgs);
+impl From<UpdateMetadataAccountIxArgs> for UpdateMetadataAccountIxData {
+    fn from(args: UpdateMetadataAccountIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateMetadataAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UPDATE_METADATA_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(UpdateMetadataAccountIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[UPDATE_METADATA_ACCOUNT_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateMetadataAccountIxData {
+    const NAME: &'static str = "UpdateMetadataAccount";
+    const DISCM: &'static [u8] = &[UPDATE_METADATA_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = UpdateMetadataAccountKeys;
+    type Args = UpdateMetadataAccountIxArgs;
+}
+pub fn update_metadata_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateMetadataAccountKeys,
+    args: UpdateMetadataAccountIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateMetadataAccountIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_metadata_account_ix(
+    keys: UpdateMetadataAccountKeys,
+    args: UpdateMetadataAccountIxArgs,
+) -> borsh::io::Result<Instruction> {
+    update_metadata_account_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateMetadataAccountAccounts<'_>,
+    args: UpdateMetadataAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; CPI_IX_DATA_BUF_LEN];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| UpdateMetadataAccountIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_invoke_signed(
+    accounts: UpdateMetadataAccountAccounts<'_>,
+    args: UpdateMetadataAccountIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    update_metadata_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateMetadataAccountAccounts<'_>,
+    args: UpdateMetadataAccountIxArgs,
+) -> ProgramResult {
+    update_metadata_account_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_invoke(
+    accounts: UpdateMetadataAccountAccounts<'_>,
+    args: UpdateMetadataAccountIxArgs,
+) -> ProgramResult {
+    update_metadata_account_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_verify_account_keys(
+    accounts: UpdateMetadataAccountAccounts<'_>,
+    keys: UpdateMetadataAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_verify_writable_privileges<'me>(
+    accounts: UpdateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_verify_signer_privileges<'me>(
+    accounts: UpdateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.update_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_verify_account_privileges<'me>(
+    accounts: UpdateMetadataAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    update_metadata_account_verify_writable_privileges(accounts)?;
+    update_metadata_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 13;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedCreateMasterEditionAccounts<'me> {
+    ///Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: &'me AccountInfo,
+    ///Metadata mint
+    pub mint: &'me AccountInfo,
+    ///Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
+    pub printing_mint: &'me AccountInfo,
+    ///One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
+    pub one_time_printing_authorization_mint: &'me AccountInfo,
+    ///Current Update authority key
+    pub update_authority: &'me AccountInfo,
+    ///Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    pub printing_mint_authority: &'me AccountInfo,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+    ///One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    pub one_time_printing_authorization_mint_authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedCreateMasterEditionKeys {
+    ///Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: Pubkey,
+    ///Metadata mint
+    pub mint: Pubkey,
+    ///Printing mint - A mint you control that can mint tokens that can be exchanged for limited editions of your master edition via the MintNewEditionFromMasterEditionViaToken endpoint
+    pub printing_mint: Pubkey,
+    ///One time authorization printing mint - A mint you control that prints tokens that gives the bearer permission to mint any number of tokens from the printing mint one time via an endpoint with the token-metadata program for your metadata. Also burns the token.
+    pub one_time_printing_authorization_mint: Pubkey,
+    ///Current Update authority key
+    pub update_authority: Pubkey,
+    ///Printing mint authority - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    pub printing_mint_authority: Pubkey,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+    ///One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
+    pub one_time_printing_authorization_mint_authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedCreateMasterEditionAccounts<'_>>
+for DeprecatedCreateMasterEditionKeys {
+    fn from(accounts: DeprecatedCreateMasterEditionAccounts) -> Self {
+        Self {
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            printing_mint: Pubkey::new_from_array(*accounts.printing_mint.key()),
+            one_time_printing_authorization_mint: Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint.key(),
+            ),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            printing_mint_authority: Pubkey::new_from_array(
+                *accounts.printing_mint_authority.key(),
+            ),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+            one_time_printing_authorization_mint_authority: Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint_authority.key(),
+            ),
+        }
+    }
+}
+impl From<DeprecatedCreateMasterEditionKeys>
+for [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeprecatedCreateMasterEditionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.one_time_printing_authorization_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.one_time_printing_authorization_mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateMasterEditionKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            edition: pubkeys[0],
+            mint: pubkeys[1],
+            printing_mint: pubkeys[2],
+            one_time_printing_authorization_mint: pubkeys[3],
+            update_authority: pubkeys[4],
+            printing_mint_authority: pubkeys[5],
+            mint_authority: pubkeys[6],
+            metadata: pubkeys[7],
+            payer: pubkeys[8],
+            token_program: pubkeys[9],
+            system_program: pubkeys[10],
+            rent: pubkeys[11],
+            one_time_printing_authorization_mint_authority: pubkeys[12],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedCreateMasterEditionAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedCreateMasterEditionAccounts<'me>) -> Self {
+        [
+            accounts.edition,
+            accounts.mint,
+            accounts.printing_mint,
+            accounts.one_time_printing_authorization_mint,
+            accounts.update_authority,
+            accounts.printing_mint_authority,
+            accounts.mint_authority,
+            accounts.metadata,
+            accounts.payer,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+            accounts.one_time_printing_authorization_mint_authority,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedCreateMasterEditionAccounts<'me>>
+for [CpiAccountMeta<'me>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedCreateMasterEditionAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.one_time_printing_authorization_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.one_time_printing_authorization_mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateMasterEditionAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            edition: &arr[0],
+            mint: &arr[1],
+            printing_mint: &arr[2],
+            one_time_printing_authorization_mint: &arr[3],
+            update_authority: &arr[4],
+            printing_mint_authority: &arr[5],
+            mint_authority: &arr[6],
+            metadata: &arr[7],
+            payer: &arr[8],
+            token_program: &arr[9],
+            system_program: &arr[10],
+            rent: &arr[11],
+            one_time_printing_authorization_mint_authority: &arr[12],
+        }
+    }
+}
+pub const DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM: u8 = 2u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeprecatedCreateMasterEditionIxArgs {
+    pub create_master_edition_args: CreateMasterEditionArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedCreateMasterEditionIxData(pub DeprecatedCreateMasterEditionIxArgs);
+impl From<DeprecatedCreateMasterEditionIxArgs> for DeprecatedCreateMasterEditionIxData {
+    fn from(args: DeprecatedCreateMasterEditionIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DeprecatedCreateMasterEditionIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(DeprecatedCreateMasterEditionIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedCreateMasterEditionIxData {
+    const NAME: &'static str = "DeprecatedCreateMasterEdition";
+    const DISCM: &'static [u8] = &[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedCreateMasterEditionKeys;
+    type Args = DeprecatedCreateMasterEditionIxArgs;
+}
+pub fn deprecated_create_master_edition_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedCreateMasterEditionKeys,
+    args: DeprecatedCreateMasterEditionIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: DeprecatedCreateMasterEditionIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deprecated_create_master_edition_ix(
+    keys: DeprecatedCreateMasterEditionKeys,
+    args: DeprecatedCreateMasterEditionIxArgs,
+) -> borsh::io::Result<Instruction> {
+    deprecated_create_master_edition_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedCreateMasterEditionAccounts<'_>,
+    args: DeprecatedCreateMasterEditionIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 10];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeprecatedCreateMasterEditionIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_invoke_signed(
+    accounts: DeprecatedCreateMasterEditionAccounts<'_>,
+    args: DeprecatedCreateMasterEditionIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_create_master_edition_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedCreateMasterEditionAccounts<'_>,
+    args: DeprecatedCreateMasterEditionIxArgs,
+) -> ProgramResult {
+    deprecated_create_master_edition_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_invoke(
+    accounts: DeprecatedCreateMasterEditionAccounts<'_>,
+    args: DeprecatedCreateMasterEditionIxArgs,
+) -> ProgramResult {
+    deprecated_create_master_edition_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_verify_account_keys(
+    accounts: DeprecatedCreateMasterEditionAccounts<'_>,
+    keys: DeprecatedCreateMasterEditionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.printing_mint.key()), &keys.printing_mint),
+        (
+            &Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint.key(),
+            ),
+            &keys.one_time_printing_authorization_mint,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.printing_mint_authority.key()),
+            &keys.printing_mint_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+        (
+            &Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint_authority.key(),
+            ),
+            &keys.one_time_printing_authorization_mint_authority,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_verify_writable_privileges<'me>(
+    accounts: DeprecatedCreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.edition,
+        accounts.mint,
+        accounts.printing_mint,
+        accounts.one_time_printing_authorization_mint,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_verify_signer_privileges<'me>(
+    accounts: DeprecatedCreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.update_authority,
+        accounts.printing_mint_authority,
+        accounts.mint_authority,
+        accounts.payer,
+        accounts.one_time_printing_authorization_mint_authority,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_master_edition_verify_account_privileges<'me>(
+    accounts: DeprecatedCreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_create_master_edition_verify_writable_privileges(accounts)?;
+    deprecated_create_master_edition_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN: usize = 16;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me> {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
+    pub edition: &'me AccountInfo,
+    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint: &'me AccountInfo,
+    ///Mint authority of new mint
+    pub mint_authority: &'me AccountInfo,
+    ///Printing Mint of master record edition
+    pub printing_mint: &'me AccountInfo,
+    ///Token account containing Printing mint token to be transferred
+    pub master_token_account: &'me AccountInfo,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
+    pub edition_marker: &'me AccountInfo,
+    ///Burn authority for this token
+    pub burn_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///update authority info for new metadata account
+    pub master_update_authority: &'me AccountInfo,
+    ///Master record metadata account
+    pub master_metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+    ///Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
+    pub reservation_list: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
+    pub edition: Pubkey,
+    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint: Pubkey,
+    ///Mint authority of new mint
+    pub mint_authority: Pubkey,
+    ///Printing Mint of master record edition
+    pub printing_mint: Pubkey,
+    ///Token account containing Printing mint token to be transferred
+    pub master_token_account: Pubkey,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master mint id, edition_number])
+    pub edition_marker: Pubkey,
+    ///Burn authority for this token
+    pub burn_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///update authority info for new metadata account
+    pub master_update_authority: Pubkey,
+    ///Master record metadata account
+    pub master_metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+    ///Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
+    pub reservation_list: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>>
+for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    fn from(
+        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts,
+    ) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            printing_mint: Pubkey::new_from_array(*accounts.printing_mint.key()),
+            master_token_account: Pubkey::new_from_array(
+                *accounts.master_token_account.key(),
+            ),
+            edition_marker: Pubkey::new_from_array(*accounts.edition_marker.key()),
+            burn_authority: Pubkey::new_from_array(*accounts.burn_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            master_update_authority: Pubkey::new_from_array(
+                *accounts.master_update_authority.key(),
+            ),
+            master_metadata: Pubkey::new_from_array(*accounts.master_metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+            reservation_list: Pubkey::new_from_array(*accounts.reservation_list.key()),
+        }
+    }
+}
+impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys>
+for [AccountMeta; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(
+        keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+    ) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.master_token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition_marker,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.burn_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.master_update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.master_metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.reservation_list,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<
+    [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            edition: pubkeys[1],
+            master_edition: pubkeys[2],
+            mint: pubkeys[3],
+            mint_authority: pubkeys[4],
+            printing_mint: pubkeys[5],
+            master_token_account: pubkeys[6],
+            edition_marker: pubkeys[7],
+            burn_authority: pubkeys[8],
+            payer: pubkeys[9],
+            master_update_authority: pubkeys[10],
+            master_metadata: pubkeys[11],
+            token_program: pubkeys[12],
+            system_program: pubkeys[13],
+            rent: pubkeys[14],
+            reservation_list: pubkeys[15],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>,
+    ) -> Self {
+        [
+            accounts.metadata,
+            accounts.edition,
+            accounts.master_edition,
+            accounts.mint,
+            accounts.mint_authority,
+            accounts.printing_mint,
+            accounts.master_token_account,
+            accounts.edition_marker,
+            accounts.burn_authority,
+            accounts.payer,
+            accounts.master_update_authority,
+            accounts.master_metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+            accounts.reservation_list,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>>
+for [CpiAccountMeta<
+    'me,
+>; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>,
+    ) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition_marker.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.burn_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.reservation_list.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+> From<
+    &'me [AccountInfo; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: &arr[0],
+            edition: &arr[1],
+            master_edition: &arr[2],
+            mint: &arr[3],
+            mint_authority: &arr[4],
+            printing_mint: &arr[5],
+            master_token_account: &arr[6],
+            edition_marker: &arr[7],
+            burn_authority: &arr[8],
+            payer: &arr[9],
+            master_update_authority: &arr[10],
+            master_metadata: &arr[11],
+            token_program: &arr[12],
+            system_program: &arr[13],
+            rent: &arr[14],
+            reservation_list: &arr[15],
+        }
+    }
+}
+pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM: u8 = 3u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData;
+impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm
+            != DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM
+        {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                        maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer
+            .write_all(
+                &[
+                    DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                ],
+            )
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction
+for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData {
+    const NAME: &'static str = "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken";
+    const DISCM: &'static [u8] = &[
+        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys;
+    type Args = ();
+}
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData
+            .try_to_vec()?,
+    })
+}
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix(
+    keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+) -> borsh::io::Result<Instruction> {
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
+        crate::ID,
+        keys,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| {
+            DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData
+                .serialize(writer)
+        },
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>,
+) -> ProgramResult {
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>,
+) -> ProgramResult {
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id(
+        crate::ID,
+        accounts,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_account_keys(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_>,
+    keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.printing_mint.key()), &keys.printing_mint),
+        (
+            &Pubkey::new_from_array(*accounts.master_token_account.key()),
+            &keys.master_token_account,
+        ),
+        (&Pubkey::new_from_array(*accounts.edition_marker.key()), &keys.edition_marker),
+        (&Pubkey::new_from_array(*accounts.burn_authority.key()), &keys.burn_authority),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.master_update_authority.key()),
+            &keys.master_update_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.master_metadata.key()),
+            &keys.master_metadata,
+        ),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+        (
+            &Pubkey::new_from_array(*accounts.reservation_list.key()),
+            &keys.reservation_list,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_writable_privileges<
+    'me,
+>(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.metadata,
+        accounts.edition,
+        accounts.master_edition,
+        accounts.mint,
+        accounts.printing_mint,
+        accounts.master_token_account,
+        accounts.edition_marker,
+        accounts.reservation_list,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_signer_privileges<
+    'me,
+>(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.mint_authority,
+        accounts.burn_authority,
+        accounts.payer,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_account_privileges<
+    'me,
+>(
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_writable_privileges(
+        accounts,
+    )?;
+    deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_signer_privileges(
+        accounts,
+    )?;
+    Ok(())
+}
+pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UpdatePrimarySaleHappenedViaTokenAccounts<'me> {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Owner on the token account
+    pub owner: &'me AccountInfo,
+    ///Account containing tokens from the metadata's mint
+    pub token: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdatePrimarySaleHappenedViaTokenKeys {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Owner on the token account
+    pub owner: Pubkey,
+    ///Account containing tokens from the metadata's mint
+    pub token: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UpdatePrimarySaleHappenedViaTokenAccounts<'_>>
+for UpdatePrimarySaleHappenedViaTokenKeys {
+    fn from(accounts: UpdatePrimarySaleHappenedViaTokenAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            owner: Pubkey::new_from_array(*accounts.owner.key()),
+            token: Pubkey::new_from_array(*accounts.token.key()),
+        }
+    }
+}
+impl From<UpdatePrimarySaleHappenedViaTokenKeys>
+for [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdatePrimarySaleHappenedViaTokenKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for UpdatePrimarySaleHappenedViaTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            owner: pubkeys[1],
+            token: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdatePrimarySaleHappenedViaTokenAccounts<'me>>
+for [&'me AccountInfo; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me>) -> Self {
+        [accounts.metadata, accounts.owner, accounts.token]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdatePrimarySaleHappenedViaTokenAccounts<'me>>
+for [CpiAccountMeta<'me>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+> From<&'me [AccountInfo; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for UpdatePrimarySaleHappenedViaTokenAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: &arr[0],
+            owner: &arr[1],
+            token: &arr[2],
+        }
+    }
+}
+pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM: u8 = 4u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdatePrimarySaleHappenedViaTokenIxData;
+impl UpdatePrimarySaleHappenedViaTokenIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdatePrimarySaleHappenedViaTokenIxData {
+    const NAME: &'static str = "UpdatePrimarySaleHappenedViaToken";
+    const DISCM: &'static [u8] = &[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = UpdatePrimarySaleHappenedViaTokenKeys;
+    type Args = ();
+}
+pub fn update_primary_sale_happened_via_token_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdatePrimarySaleHappenedViaTokenKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: UpdatePrimarySaleHappenedViaTokenIxData.try_to_vec()?,
+    })
+}
+pub fn update_primary_sale_happened_via_token_ix(
+    keys: UpdatePrimarySaleHappenedViaTokenKeys,
+) -> borsh::io::Result<Instruction> {
+    update_primary_sale_happened_via_token_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| UpdatePrimarySaleHappenedViaTokenIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_invoke_signed(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    update_primary_sale_happened_via_token_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_>,
+) -> ProgramResult {
+    update_primary_sale_happened_via_token_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_invoke(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_>,
+) -> ProgramResult {
+    update_primary_sale_happened_via_token_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_verify_account_keys(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_>,
+    keys: UpdatePrimarySaleHappenedViaTokenKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.owner.key()), &keys.owner),
+        (&Pubkey::new_from_array(*accounts.token.key()), &keys.token),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_verify_writable_privileges<'me>(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_verify_signer_privileges<'me>(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.owner] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_primary_sale_happened_via_token_verify_account_privileges<'me>(
+    accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    update_primary_sale_happened_via_token_verify_writable_privileges(accounts)?;
+    update_primary_sale_happened_via_token_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedSetReservationListAccounts<'me> {
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    pub reservation_list: &'me AccountInfo,
+    ///The resource you tied the reservation list too
+    pub resource: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedSetReservationListKeys {
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    pub reservation_list: Pubkey,
+    ///The resource you tied the reservation list too
+    pub resource: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedSetReservationListAccounts<'_>>
+for DeprecatedSetReservationListKeys {
+    fn from(accounts: DeprecatedSetReservationListAccounts) -> Self {
+        Self {
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            reservation_list: Pubkey::new_from_array(*accounts.reservation_list.key()),
+            resource: Pubkey::new_from_array(*accounts.resource.key()),
+        }
+    }
+}
+impl From<DeprecatedSetReservationListKeys>
+for [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeprecatedSetReservationListKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.reservation_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.resource,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedSetReservationListKeys {
+    fn from(pubkeys: [Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            master_edition: pubkeys[0],
+            reservation_list: pubkeys[1],
+            resource: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedSetReservationListAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedSetReservationListAccounts<'me>) -> Self {
+        [accounts.master_edition, accounts.reservation_list, accounts.resource]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedSetReservationListAccounts<'me>>
+for [CpiAccountMeta<'me>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedSetReservationListAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.reservation_list.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.resource.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedSetReservationListAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            master_edition: &arr[0],
+            reservation_list: &arr[1],
+            resource: &arr[2],
+        }
+    }
+}
+pub const DEPRECATED_SET_RESERVATION_LIST_IX_DISCM: u8 = 5u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeprecatedSetReservationListIxArgs {
+    pub set_reservation_list_args: SetReservationListArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedSetReservationListIxData(pub DeprecatedSetReservationListIxArgs);
+impl From<DeprecatedSetReservationListIxArgs> for DeprecatedSetReservationListIxData {
+    fn from(args: DeprecatedSetReservationListIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DeprecatedSetReservationListIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPRECATED_SET_RESERVATION_LIST_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_SET_RESERVATION_LIST_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(DeprecatedSetReservationListIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[DEPRECATED_SET_RESERVATION_LIST_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedSetReservationListIxData {
+    const NAME: &'static str = "DeprecatedSetReservationList";
+    const DISCM: &'static [u8] = &[DEPRECATED_SET_RESERVATION_LIST_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedSetReservationListKeys;
+    type Args = DeprecatedSetReservationListIxArgs;
+}
+pub fn deprecated_set_reservation_list_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedSetReservationListKeys,
+    args: DeprecatedSetReservationListIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: DeprecatedSetReservationListIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deprecated_set_reservation_list_ix(
+    keys: DeprecatedSetReservationListKeys,
+    args: DeprecatedSetReservationListIxArgs,
+) -> borsh::io::Result<Instruction> {
+    deprecated_set_reservation_list_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedSetReservationListAccounts<'_>,
+    args: DeprecatedSetReservationListIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; CPI_IX_DATA_BUF_LEN];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeprecatedSetReservationListIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_invoke_signed(
+    accounts: DeprecatedSetReservationListAccounts<'_>,
+    args: DeprecatedSetReservationListIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_set_reservation_list_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedSetReservationListAccounts<'_>,
+    args: DeprecatedSetReservationListIxArgs,
+) -> ProgramResult {
+    deprecated_set_reservation_list_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_invoke(
+    accounts: DeprecatedSetReservationListAccounts<'_>,
+    args: DeprecatedSetReservationListIxArgs,
+) -> ProgramResult {
+    deprecated_set_reservation_list_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_verify_account_keys(
+    accounts: DeprecatedSetReservationListAccounts<'_>,
+    keys: DeprecatedSetReservationListKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (
+            &Pubkey::new_from_array(*accounts.reservation_list.key()),
+            &keys.reservation_list,
+        ),
+        (&Pubkey::new_from_array(*accounts.resource.key()), &keys.resource),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_verify_writable_privileges<'me>(
+    accounts: DeprecatedSetReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.master_edition, accounts.reservation_list] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_verify_signer_privileges<'me>(
+    accounts: DeprecatedSetReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.resource] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_set_reservation_list_verify_account_privileges<'me>(
+    accounts: DeprecatedSetReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_set_reservation_list_verify_writable_privileges(accounts)?;
+    deprecated_set_reservation_list_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedCreateReservationListAccounts<'me> {
+    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    pub reservation_list: &'me AccountInfo,
+    ///Payer
+    pub payer: &'me AccountInfo,
+    ///Update authority
+    pub update_authority: &'me AccountInfo,
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
+    pub resource: &'me AccountInfo,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedCreateReservationListKeys {
+    ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
+    pub reservation_list: Pubkey,
+    ///Payer
+    pub payer: Pubkey,
+    ///Update authority
+    pub update_authority: Pubkey,
+    /// Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///A resource you wish to tie the reservation list to. This is so your later visitors who come to redeem can derive your reservation list PDA with something they can easily get at. You choose what this should be.
+    pub resource: Pubkey,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedCreateReservationListAccounts<'_>>
+for DeprecatedCreateReservationListKeys {
+    fn from(accounts: DeprecatedCreateReservationListAccounts) -> Self {
+        Self {
+            reservation_list: Pubkey::new_from_array(*accounts.reservation_list.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            resource: Pubkey::new_from_array(*accounts.resource.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<DeprecatedCreateReservationListKeys>
+for [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeprecatedCreateReservationListKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.reservation_list,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.resource,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateReservationListKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            reservation_list: pubkeys[0],
+            payer: pubkeys[1],
+            update_authority: pubkeys[2],
+            master_edition: pubkeys[3],
+            resource: pubkeys[4],
+            metadata: pubkeys[5],
+            system_program: pubkeys[6],
+            rent: pubkeys[7],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedCreateReservationListAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedCreateReservationListAccounts<'me>) -> Self {
+        [
+            accounts.reservation_list,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.master_edition,
+            accounts.resource,
+            accounts.metadata,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedCreateReservationListAccounts<'me>>
+for [CpiAccountMeta<'me>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedCreateReservationListAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.reservation_list.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.resource.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateReservationListAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            reservation_list: &arr[0],
+            payer: &arr[1],
+            update_authority: &arr[2],
+            master_edition: &arr[3],
+            resource: &arr[4],
+            metadata: &arr[5],
+            system_program: &arr[6],
+            rent: &arr[7],
+        }
+    }
+}
+pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM: u8 = 6u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedCreateReservationListIxData;
+impl DeprecatedCreateReservationListIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedCreateReservationListIxData {
+    const NAME: &'static str = "DeprecatedCreateReservationList";
+    const DISCM: &'static [u8] = &[DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedCreateReservationListKeys;
+    type Args = ();
+}
+pub fn deprecated_create_reservation_list_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedCreateReservationListKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys
+        .into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: DeprecatedCreateReservationListIxData.try_to_vec()?,
+    })
+}
+pub fn deprecated_create_reservation_list_ix(
+    keys: DeprecatedCreateReservationListKeys,
+) -> borsh::io::Result<Instruction> {
+    deprecated_create_reservation_list_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedCreateReservationListAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeprecatedCreateReservationListIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_invoke_signed(
+    accounts: DeprecatedCreateReservationListAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_create_reservation_list_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedCreateReservationListAccounts<'_>,
+) -> ProgramResult {
+    deprecated_create_reservation_list_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_invoke(
+    accounts: DeprecatedCreateReservationListAccounts<'_>,
+) -> ProgramResult {
+    deprecated_create_reservation_list_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_verify_account_keys(
+    accounts: DeprecatedCreateReservationListAccounts<'_>,
+    keys: DeprecatedCreateReservationListKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            &Pubkey::new_from_array(*accounts.reservation_list.key()),
+            &keys.reservation_list,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.resource.key()), &keys.resource),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_verify_writable_privileges<'me>(
+    accounts: DeprecatedCreateReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.reservation_list] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_verify_signer_privileges<'me>(
+    accounts: DeprecatedCreateReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.payer, accounts.update_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_create_reservation_list_verify_account_privileges<'me>(
+    accounts: DeprecatedCreateReservationListAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_create_reservation_list_verify_writable_privileges(accounts)?;
+    deprecated_create_reservation_list_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SIGN_METADATA_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct SignMetadataAccounts<'me> {
+    ///Metadata (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Creator
+    pub creator: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SignMetadataKeys {
+    ///Metadata (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Creator
+    pub creator: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<SignMetadataAccounts<'_>> for SignMetadataKeys {
+    fn from(accounts: SignMetadataAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            creator: Pubkey::new_from_array(*accounts.creator.key()),
+        }
+    }
+}
+impl From<SignMetadataKeys> for [AccountMeta; SIGN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: SignMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.creator,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SIGN_METADATA_IX_ACCOUNTS_LEN]> for SignMetadataKeys {
+    fn from(pubkeys: [Pubkey; SIGN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            creator: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SignMetadataAccounts<'me>>
+for [&'me AccountInfo; SIGN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SignMetadataAccounts<'me>) -> Self {
+        [accounts.metadata, accounts.creator]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SignMetadataAccounts<'me>>
+for [CpiAccountMeta<'me>; SIGN_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SignMetadataAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.creator.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; SIGN_METADATA_IX_ACCOUNTS_LEN]>
+for SignMetadataAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; SIGN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            creator: &arr[1],
+        }
+    }
+}
+pub const SIGN_METADATA_IX_DISCM: u8 = 7u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignMetadataIxData;
+impl SignMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SIGN_METADATA_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SIGN_METADATA_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[SIGN_METADATA_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SignMetadataIxData {
+    const NAME: &'static str = "SignMetadata";
+    const DISCM: &'static [u8] = &[SIGN_METADATA_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SIGN_METADATA_IX_ACCOUNTS_LEN;
+    type Keys = SignMetadataKeys;
+    type Args = ();
+}
+pub fn sign_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SignMetadataKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; SIGN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SignMetadataIxData.try_to_vec()?,
+    })
+}
+pub fn sign_metadata_ix(keys: SignMetadataKeys) -> borsh::io::Result<Instruction> {
+    sign_metadata_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SignMetadataAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(&mut buf, |writer| SignMetadataIxData.serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_invoke_signed(
+    accounts: SignMetadataAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    sign_metadata_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SignMetadataAccounts<'_>,
+) -> ProgramResult {
+    sign_metadata_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_invoke(accounts: SignMetadataAccounts<'_>) -> ProgramResult {
+    sign_metadata_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_verify_account_keys(
+    accounts: SignMetadataAccounts<'_>,
+    keys: SignMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.creator.key()), &keys.creator),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_verify_writable_privileges<'me>(
+    accounts: SignMetadataAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_verify_signer_privileges<'me>(
+    accounts: SignMetadataAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.creator] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn sign_metadata_verify_account_privileges<'me>(
+    accounts: SignMetadataAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    sign_metadata_verify_writable_privileges(accounts)?;
+    sign_metadata_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedMintPrintingTokensViaTokenAccounts<'me> {
+    ///Destination account
+    pub destination: &'me AccountInfo,
+    ///Token account containing one time authorization token
+    pub token: &'me AccountInfo,
+    ///One time authorization mint
+    pub one_time_printing_authorization_mint: &'me AccountInfo,
+    ///Printing mint
+    pub printing_mint: &'me AccountInfo,
+    ///Burn authority
+    pub burn_authority: &'me AccountInfo,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///Rent
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedMintPrintingTokensViaTokenKeys {
+    ///Destination account
+    pub destination: Pubkey,
+    ///Token account containing one time authorization token
+    pub token: Pubkey,
+    ///One time authorization mint
+    pub one_time_printing_authorization_mint: Pubkey,
+    ///Printing mint
+    pub printing_mint: Pubkey,
+    ///Burn authority
+    pub burn_authority: Pubkey,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///Rent
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedMintPrintingTokensViaTokenAccounts<'_>>
+for DeprecatedMintPrintingTokensViaTokenKeys {
+    fn from(accounts: DeprecatedMintPrintingTokensViaTokenAccounts) -> Self {
+        Self {
+            destination: Pubkey::new_from_array(*accounts.destination.key()),
+            token: Pubkey::new_from_array(*accounts.token.key()),
+            one_time_printing_authorization_mint: Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint.key(),
+            ),
+            printing_mint: Pubkey::new_from_array(*accounts.printing_mint.key()),
+            burn_authority: Pubkey::new_from_array(*accounts.burn_authority.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<DeprecatedMintPrintingTokensViaTokenKeys>
+for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeprecatedMintPrintingTokensViaTokenKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.one_time_printing_authorization_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.burn_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for DeprecatedMintPrintingTokensViaTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            destination: pubkeys[0],
+            token: pubkeys[1],
+            one_time_printing_authorization_mint: pubkeys[2],
+            printing_mint: pubkeys[3],
+            burn_authority: pubkeys[4],
+            metadata: pubkeys[5],
+            master_edition: pubkeys[6],
+            token_program: pubkeys[7],
+            rent: pubkeys[8],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintPrintingTokensViaTokenAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me>) -> Self {
+        [
+            accounts.destination,
+            accounts.token,
+            accounts.one_time_printing_authorization_mint,
+            accounts.printing_mint,
+            accounts.burn_authority,
+            accounts.metadata,
+            accounts.master_edition,
+            accounts.token_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintPrintingTokensViaTokenAccounts<'me>>
+for [CpiAccountMeta<'me>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.one_time_printing_authorization_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.burn_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+> From<&'me [AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for DeprecatedMintPrintingTokensViaTokenAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            destination: &arr[0],
+            token: &arr[1],
+            one_time_printing_authorization_mint: &arr[2],
+            printing_mint: &arr[3],
+            burn_authority: &arr[4],
+            metadata: &arr[5],
+            master_edition: &arr[6],
+            token_program: &arr[7],
+            rent: &arr[8],
+        }
+    }
+}
+pub const DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM: u8 = 8u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeprecatedMintPrintingTokensViaTokenIxArgs {
+    pub mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedMintPrintingTokensViaTokenIxData(
+    pub DeprecatedMintPrintingTokensViaTokenIxArgs,
+);
+impl From<DeprecatedMintPrintingTokensViaTokenIxArgs>
+for DeprecatedMintPrintingTokensViaTokenIxData {
+    fn from(args: DeprecatedMintPrintingTokensViaTokenIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DeprecatedMintPrintingTokensViaTokenIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedMintPrintingTokensViaTokenIxData {
+    const NAME: &'static str = "DeprecatedMintPrintingTokensViaToken";
+    const DISCM: &'static [u8] = &[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintPrintingTokensViaTokenKeys;
+    type Args = DeprecatedMintPrintingTokensViaTokenIxArgs;
+}
+pub fn deprecated_mint_printing_tokens_via_token_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedMintPrintingTokensViaTokenKeys,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: DeprecatedMintPrintingTokensViaTokenIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deprecated_mint_printing_tokens_via_token_ix(
+    keys: DeprecatedMintPrintingTokensViaTokenKeys,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+) -> borsh::io::Result<Instruction> {
+    deprecated_mint_printing_tokens_via_token_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_>,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeprecatedMintPrintingTokensViaTokenIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_invoke_signed(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_>,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_via_token_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_>,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_via_token_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_invoke(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_>,
+    args: DeprecatedMintPrintingTokensViaTokenIxArgs,
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_via_token_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_verify_account_keys(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_>,
+    keys: DeprecatedMintPrintingTokensViaTokenKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.destination.key()), &keys.destination),
+        (&Pubkey::new_from_array(*accounts.token.key()), &keys.token),
+        (
+            &Pubkey::new_from_array(
+                *accounts.one_time_printing_authorization_mint.key(),
+            ),
+            &keys.one_time_printing_authorization_mint,
+        ),
+        (&Pubkey::new_from_array(*accounts.printing_mint.key()), &keys.printing_mint),
+        (&Pubkey::new_from_array(*accounts.burn_authority.key()), &keys.burn_authority),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_verify_writable_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.destination,
+        accounts.token,
+        accounts.one_time_printing_authorization_mint,
+        accounts.printing_mint,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_verify_signer_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.burn_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_via_token_verify_account_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_mint_printing_tokens_via_token_verify_writable_privileges(accounts)?;
+    deprecated_mint_printing_tokens_via_token_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct DeprecatedMintPrintingTokensAccounts<'me> {
+    ///Destination account
+    pub destination: &'me AccountInfo,
+    ///Printing mint
+    pub printing_mint: &'me AccountInfo,
+    ///Update authority
+    pub update_authority: &'me AccountInfo,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///Rent
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct DeprecatedMintPrintingTokensKeys {
+    ///Destination account
+    pub destination: Pubkey,
+    ///Printing mint
+    pub printing_mint: Pubkey,
+    ///Update authority
+    pub update_authority: Pubkey,
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///Rent
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<DeprecatedMintPrintingTokensAccounts<'_>>
+for DeprecatedMintPrintingTokensKeys {
+    fn from(accounts: DeprecatedMintPrintingTokensAccounts) -> Self {
+        Self {
+            destination: Pubkey::new_from_array(*accounts.destination.key()),
+            printing_mint: Pubkey::new_from_array(*accounts.printing_mint.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<DeprecatedMintPrintingTokensKeys>
+for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] {
+    fn from(keys: DeprecatedMintPrintingTokensKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
+for DeprecatedMintPrintingTokensKeys {
+    fn from(pubkeys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            destination: pubkeys[0],
+            printing_mint: pubkeys[1],
+            update_authority: pubkeys[2],
+            metadata: pubkeys[3],
+            master_edition: pubkeys[4],
+            token_program: pubkeys[5],
+            rent: pubkeys[6],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintPrintingTokensAccounts<'me>>
+for [&'me AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedMintPrintingTokensAccounts<'me>) -> Self {
+        [
+            accounts.destination,
+            accounts.printing_mint,
+            accounts.update_authority,
+            accounts.metadata,
+            accounts.master_edition,
+            accounts.token_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<DeprecatedMintPrintingTokensAccounts<'me>>
+for [CpiAccountMeta<'me>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] {
+    fn from(accounts: DeprecatedMintPrintingTokensAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.destination.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
+for DeprecatedMintPrintingTokensAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            destination: &arr[0],
+            printing_mint: &arr[1],
+            update_authority: &arr[2],
+            metadata: &arr[3],
+            master_edition: &arr[4],
+            token_program: &arr[5],
+            rent: &arr[6],
+        }
+    }
+}
+pub const DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM: u8 = 9u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeprecatedMintPrintingTokensIxArgs {
+    pub mint_printing_tokens_via_token_args: MintPrintingTokensViaTokenArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeprecatedMintPrintingTokensIxData(pub DeprecatedMintPrintingTokensIxArgs);
+impl From<DeprecatedMintPrintingTokensIxArgs> for DeprecatedMintPrintingTokensIxData {
+    fn from(args: DeprecatedMintPrintingTokensIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl DeprecatedMintPrintingTokensIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(DeprecatedMintPrintingTokensIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedMintPrintingTokensIxData {
+    const NAME: &'static str = "DeprecatedMintPrintingTokens";
+    const DISCM: &'static [u8] = &[DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintPrintingTokensKeys;
+    type Args = DeprecatedMintPrintingTokensIxArgs;
+}
+pub fn deprecated_mint_printing_tokens_ix_with_program_id(
+    program_id: Pubkey,
+    keys: DeprecatedMintPrintingTokensKeys,
+    args: DeprecatedMintPrintingTokensIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: DeprecatedMintPrintingTokensIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn deprecated_mint_printing_tokens_ix(
+    keys: DeprecatedMintPrintingTokensKeys,
+    args: DeprecatedMintPrintingTokensIxArgs,
+) -> borsh::io::Result<Instruction> {
+    deprecated_mint_printing_tokens_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintPrintingTokensAccounts<'_>,
+    args: DeprecatedMintPrintingTokensIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| DeprecatedMintPrintingTokensIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_invoke_signed(
+    accounts: DeprecatedMintPrintingTokensAccounts<'_>,
+    args: DeprecatedMintPrintingTokensIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: DeprecatedMintPrintingTokensAccounts<'_>,
+    args: DeprecatedMintPrintingTokensIxArgs,
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_invoke(
+    accounts: DeprecatedMintPrintingTokensAccounts<'_>,
+    args: DeprecatedMintPrintingTokensIxArgs,
+) -> ProgramResult {
+    deprecated_mint_printing_tokens_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_verify_account_keys(
+    accounts: DeprecatedMintPrintingTokensAccounts<'_>,
+    keys: DeprecatedMintPrintingTokensKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.destination.key()), &keys.destination),
+        (&Pubkey::new_from_array(*accounts.printing_mint.key()), &keys.printing_mint),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_verify_writable_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.destination, accounts.printing_mint] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_verify_signer_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.update_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn deprecated_mint_printing_tokens_verify_account_privileges<'me>(
+    accounts: DeprecatedMintPrintingTokensAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    deprecated_mint_printing_tokens_verify_writable_privileges(accounts)?;
+    deprecated_mint_printing_tokens_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateMasterEditionAccounts<'me> {
+    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: &'me AccountInfo,
+    ///Metadata mint
+    pub mint: &'me AccountInfo,
+    ///Update authority
+    pub update_authority: &'me AccountInfo,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateMasterEditionKeys {
+    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: Pubkey,
+    ///Metadata mint
+    pub mint: Pubkey,
+    ///Update authority
+    pub update_authority: Pubkey,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateMasterEditionAccounts<'_>> for CreateMasterEditionKeys {
+    fn from(accounts: CreateMasterEditionAccounts) -> Self {
+        Self {
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<CreateMasterEditionKeys>
+for [AccountMeta; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateMasterEditionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]> for CreateMasterEditionKeys {
+    fn from(pubkeys: [Pubkey; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            edition: pubkeys[0],
+            mint: pubkeys[1],
+            update_authority: pubkeys[2],
+            mint_authority: pubkeys[3],
+            payer: pubkeys[4],
+            metadata: pubkeys[5],
+            token_program: pubkeys[6],
+            system_program: pubkeys[7],
+            rent: pubkeys[8],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMasterEditionAccounts<'me>>
+for [&'me AccountInfo; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMasterEditionAccounts<'me>) -> Self {
+        [
+            accounts.edition,
+            accounts.mint,
+            accounts.update_authority,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMasterEditionAccounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMasterEditionAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
+for CreateMasterEditionAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            edition: &arr[0],
+            mint: &arr[1],
+            update_authority: &arr[2],
+            mint_authority: &arr[3],
+            payer: &arr[4],
+            metadata: &arr[5],
+            token_program: &arr[6],
+            system_program: &arr[7],
+            rent: &arr[8],
+        }
+    }
+}
+pub const CREATE_MASTER_EDITION_IX_DISCM: u8 = 10u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateMasterEditionIxArgs {
+    pub create_master_edition_args: CreateMasterEditionArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateMasterEditionIxData(pub CreateMasterEditionIxArgs);
+impl From<CreateMasterEditionIxArgs> for CreateMasterEditionIxData {
+    fn from(args: CreateMasterEditionIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateMasterEditionIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CREATE_MASTER_EDITION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateMasterEditionIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[CREATE_MASTER_EDITION_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMasterEditionIxData {
+    const NAME: &'static str = "CreateMasterEdition";
+    const DISCM: &'static [u8] = &[CREATE_MASTER_EDITION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN;
+    type Keys = CreateMasterEditionKeys;
+    type Args = CreateMasterEditionIxArgs;
+}
+pub fn create_master_edition_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateMasterEditionKeys,
+    args: CreateMasterEditionIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateMasterEditionIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_master_edition_ix(
+    keys: CreateMasterEditionKeys,
+    args: CreateMasterEditionIxArgs,
+) -> borsh::io::Result<Instruction> {
+    create_master_edition_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMasterEditionAccounts<'_>,
+    args: CreateMasterEditionIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 10];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| CreateMasterEditionIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_invoke_signed(
+    accounts: CreateMasterEditionAccounts<'_>,
+    args: CreateMasterEditionIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_master_edition_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMasterEditionAccounts<'_>,
+    args: CreateMasterEditionIxArgs,
+) -> ProgramResult {
+    create_master_edition_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_invoke(
+    accounts: CreateMasterEditionAccounts<'_>,
+    args: CreateMasterEditionIxArgs,
+) -> ProgramResult {
+    create_master_edition_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_verify_account_keys(
+    accounts: CreateMasterEditionAccounts<'_>,
+    keys: CreateMasterEditionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_verify_writable_privileges<'me>(
+    accounts: CreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.edition, accounts.mint] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_verify_signer_privileges<'me>(
+    accounts: CreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.update_authority,
+        accounts.mint_authority,
+        accounts.payer,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_verify_account_privileges<'me>(
+    accounts: CreateMasterEditionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_master_edition_verify_writable_privileges(accounts)?;
+    create_master_edition_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 14;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct MintNewEditionFromMasterEditionViaTokenAccounts<'me> {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub new_metadata: &'me AccountInfo,
+    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    pub new_edition: &'me AccountInfo,
+    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub new_mint: &'me AccountInfo,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    pub edition_mark_pda: &'me AccountInfo,
+    ///Mint authority of new mint
+    pub new_mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///owner of token account containing master token (#8)
+    pub token_account_owner: &'me AccountInfo,
+    ///token account containing token from master metadata mint
+    pub token_account: &'me AccountInfo,
+    ///Update authority info for new metadata
+    pub new_metadata_update_authority: &'me AccountInfo,
+    ///Master record metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MintNewEditionFromMasterEditionViaTokenKeys {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub new_metadata: Pubkey,
+    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    pub new_edition: Pubkey,
+    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub new_mint: Pubkey,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    pub edition_mark_pda: Pubkey,
+    ///Mint authority of new mint
+    pub new_mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///owner of token account containing master token (#8)
+    pub token_account_owner: Pubkey,
+    ///token account containing token from master metadata mint
+    pub token_account: Pubkey,
+    ///Update authority info for new metadata
+    pub new_metadata_update_authority: Pubkey,
+    ///Master record metadata account
+    pub metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<MintNewEditionFromMasterEditionViaTokenAccounts<'_>>
+for MintNewEditionFromMasterEditionViaTokenKeys {
+    fn from(accounts: MintNewEditionFromMasterEditionViaTokenAccounts) -> Self {
+        Self {
+            new_metadata: Pubkey::new_from_array(*accounts.new_metadata.key()),
+            new_edition: Pubkey::new_from_array(*accounts.new_edition.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            new_mint: Pubkey::new_from_array(*accounts.new_mint.key()),
+            edition_mark_pda: Pubkey::new_from_array(*accounts.edition_mark_pda.key()),
+            new_mint_authority: Pubkey::new_from_array(
+                *accounts.new_mint_authority.key(),
+            ),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            token_account_owner: Pubkey::new_from_array(
+                *accounts.token_account_owner.key(),
+            ),
+            token_account: Pubkey::new_from_array(*accounts.token_account.key()),
+            new_metadata_update_authority: Pubkey::new_from_array(
+                *accounts.new_metadata_update_authority.key(),
+            ),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<MintNewEditionFromMasterEditionViaTokenKeys>
+for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(keys: MintNewEditionFromMasterEditionViaTokenKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.new_metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition_mark_pda,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_account_owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_metadata_update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for MintNewEditionFromMasterEditionViaTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            new_metadata: pubkeys[0],
+            new_edition: pubkeys[1],
+            master_edition: pubkeys[2],
+            new_mint: pubkeys[3],
+            edition_mark_pda: pubkeys[4],
+            new_mint_authority: pubkeys[5],
+            payer: pubkeys[6],
+            token_account_owner: pubkeys[7],
+            token_account: pubkeys[8],
+            new_metadata_update_authority: pubkeys[9],
+            metadata: pubkeys[10],
+            token_program: pubkeys[11],
+            system_program: pubkeys[12],
+            rent: pubkeys[13],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<MintNewEditionFromMasterEditionViaTokenAccounts<'me>>
+for [&'me AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me>) -> Self {
+        [
+            accounts.new_metadata,
+            accounts.new_edition,
+            accounts.master_edition,
+            accounts.new_mint,
+            accounts.edition_mark_pda,
+            accounts.new_mint_authority,
+            accounts.payer,
+            accounts.token_account_owner,
+            accounts.token_account,
+            accounts.new_metadata_update_authority,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<MintNewEditionFromMasterEditionViaTokenAccounts<'me>>
+for [CpiAccountMeta<
+    'me,
+>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.new_metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition_mark_pda.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_account_owner.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_metadata_update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+> From<
+    &'me [AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
+> for MintNewEditionFromMasterEditionViaTokenAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            new_metadata: &arr[0],
+            new_edition: &arr[1],
+            master_edition: &arr[2],
+            new_mint: &arr[3],
+            edition_mark_pda: &arr[4],
+            new_mint_authority: &arr[5],
+            payer: &arr[6],
+            token_account_owner: &arr[7],
+            token_account: &arr[8],
+            new_metadata_update_authority: &arr[9],
+            metadata: &arr[10],
+            token_program: &arr[11],
+            system_program: &arr[12],
+            rent: &arr[13],
+        }
+    }
+}
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM: u8 = 11u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintNewEditionFromMasterEditionViaTokenIxArgs {
+    pub mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintNewEditionFromMasterEditionViaTokenIxData(
+    pub MintNewEditionFromMasterEditionViaTokenIxArgs,
+);
+impl From<MintNewEditionFromMasterEditionViaTokenIxArgs>
+for MintNewEditionFromMasterEditionViaTokenIxData {
+    fn from(args: MintNewEditionFromMasterEditionViaTokenIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl MintNewEditionFromMasterEditionViaTokenIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM,
+                        maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(
+            Self(
+                MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(&mut reader)?,
+            ),
+        )
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for MintNewEditionFromMasterEditionViaTokenIxData {
+    const NAME: &'static str = "MintNewEditionFromMasterEditionViaToken";
+    const DISCM: &'static [u8] = &[
+        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = MintNewEditionFromMasterEditionViaTokenKeys;
+    type Args = MintNewEditionFromMasterEditionViaTokenIxArgs;
+}
+pub fn mint_new_edition_from_master_edition_via_token_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MintNewEditionFromMasterEditionViaTokenKeys,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: MintNewEditionFromMasterEditionViaTokenIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn mint_new_edition_from_master_edition_via_token_ix(
+    keys: MintNewEditionFromMasterEditionViaTokenKeys,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+) -> borsh::io::Result<Instruction> {
+    mint_new_edition_from_master_edition_via_token_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| MintNewEditionFromMasterEditionViaTokenIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_invoke_signed(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_token_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_token_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_invoke(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaTokenIxArgs,
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_token_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_account_keys(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_>,
+    keys: MintNewEditionFromMasterEditionViaTokenKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.new_metadata.key()), &keys.new_metadata),
+        (&Pubkey::new_from_array(*accounts.new_edition.key()), &keys.new_edition),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.new_mint.key()), &keys.new_mint),
+        (
+            &Pubkey::new_from_array(*accounts.edition_mark_pda.key()),
+            &keys.edition_mark_pda,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.new_mint_authority.key()),
+            &keys.new_mint_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.token_account_owner.key()),
+            &keys.token_account_owner,
+        ),
+        (&Pubkey::new_from_array(*accounts.token_account.key()), &keys.token_account),
+        (
+            &Pubkey::new_from_array(*accounts.new_metadata_update_authority.key()),
+            &keys.new_metadata_update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_writable_privileges<'me>(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.new_metadata,
+        accounts.new_edition,
+        accounts.master_edition,
+        accounts.new_mint,
+        accounts.edition_mark_pda,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_signer_privileges<'me>(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.new_mint_authority,
+        accounts.payer,
+        accounts.token_account_owner,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_account_privileges<'me>(
+    accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    mint_new_edition_from_master_edition_via_token_verify_writable_privileges(accounts)?;
+    mint_new_edition_from_master_edition_via_token_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN: usize = 3;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct ConvertMasterEditionV1ToV2Accounts<'me> {
+    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: &'me AccountInfo,
+    ///One time authorization mint
+    pub one_time_auth: &'me AccountInfo,
+    ///Printing mint
+    pub printing_mint: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ConvertMasterEditionV1ToV2Keys {
+    ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
+    pub master_edition: Pubkey,
+    ///One time authorization mint
+    pub one_time_auth: Pubkey,
+    ///Printing mint
+    pub printing_mint: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<ConvertMasterEditionV1ToV2Accounts<'_>> for ConvertMasterEditionV1ToV2Keys {
+    fn from(accounts: ConvertMasterEditionV1ToV2Accounts) -> Self {
+        Self {
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            one_time_auth: Pubkey::new_from_array(*accounts.one_time_auth.key()),
+            printing_mint: Pubkey::new_from_array(*accounts.printing_mint.key()),
+        }
+    }
+}
+impl From<ConvertMasterEditionV1ToV2Keys>
+for [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: ConvertMasterEditionV1ToV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.one_time_auth,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.printing_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
+for ConvertMasterEditionV1ToV2Keys {
+    fn from(pubkeys: [Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            master_edition: pubkeys[0],
+            one_time_auth: pubkeys[1],
+            printing_mint: pubkeys[2],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ConvertMasterEditionV1ToV2Accounts<'me>>
+for [&'me AccountInfo; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ConvertMasterEditionV1ToV2Accounts<'me>) -> Self {
+        [accounts.master_edition, accounts.one_time_auth, accounts.printing_mint]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ConvertMasterEditionV1ToV2Accounts<'me>>
+for [CpiAccountMeta<'me>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ConvertMasterEditionV1ToV2Accounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.one_time_auth.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.printing_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
+for ConvertMasterEditionV1ToV2Accounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            master_edition: &arr[0],
+            one_time_auth: &arr[1],
+            printing_mint: &arr[2],
+        }
+    }
+}
+pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM: u8 = 12u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvertMasterEditionV1ToV2IxData;
+impl ConvertMasterEditionV1ToV2IxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ConvertMasterEditionV1ToV2IxData {
+    const NAME: &'static str = "ConvertMasterEditionV1ToV2";
+    const DISCM: &'static [u8] = &[CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN;
+    type Keys = ConvertMasterEditionV1ToV2Keys;
+    type Args = ();
+}
+pub fn convert_master_edition_v1_to_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ConvertMasterEditionV1ToV2Keys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] = keys
+        .into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ConvertMasterEditionV1ToV2IxData.try_to_vec()?,
+    })
+}
+pub fn convert_master_edition_v1_to_v2_ix(
+    keys: ConvertMasterEditionV1ToV2Keys,
+) -> borsh::io::Result<Instruction> {
+    convert_master_edition_v1_to_v2_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ConvertMasterEditionV1ToV2Accounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| ConvertMasterEditionV1ToV2IxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_invoke_signed(
+    accounts: ConvertMasterEditionV1ToV2Accounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ConvertMasterEditionV1ToV2Accounts<'_>,
+) -> ProgramResult {
+    convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_invoke(
+    accounts: ConvertMasterEditionV1ToV2Accounts<'_>,
+) -> ProgramResult {
+    convert_master_edition_v1_to_v2_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_verify_account_keys(
+    accounts: ConvertMasterEditionV1ToV2Accounts<'_>,
+    keys: ConvertMasterEditionV1ToV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.one_time_auth.key()), &keys.one_time_auth),
+        (&Pubkey::new_from_array(*accounts.printing_mint.key()), &keys.printing_mint),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_verify_writable_privileges<'me>(
+    accounts: ConvertMasterEditionV1ToV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.master_edition,
+        accounts.one_time_auth,
+        accounts.printing_mint,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn convert_master_edition_v1_to_v2_verify_account_privileges<'me>(
+    accounts: ConvertMasterEditionV1ToV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    convert_master_edition_v1_to_v2_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN: usize = 17;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me> {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub new_metadata: &'me AccountInfo,
+    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    pub new_edition: &'me AccountInfo,
+    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
+    pub master_edition: &'me AccountInfo,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub new_mint: &'me AccountInfo,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    pub edition_mark_pda: &'me AccountInfo,
+    ///Mint authority of new mint
+    pub new_mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///Vault authority
+    pub vault_authority: &'me AccountInfo,
+    ///Safety deposit token store account
+    pub safety_deposit_store: &'me AccountInfo,
+    ///Safety deposit box
+    pub safety_deposit_box: &'me AccountInfo,
+    ///Vault
+    pub vault: &'me AccountInfo,
+    ///Update authority info for new metadata
+    pub new_metadata_update_authority: &'me AccountInfo,
+    ///Master record metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///Token vault program
+    pub token_vault_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct MintNewEditionFromMasterEditionViaVaultProxyKeys {
+    ///New Metadata key (pda of ['metadata', program id, mint id])
+    pub new_metadata: Pubkey,
+    ///New Edition (pda of ['metadata', program id, mint id, 'edition'])
+    pub new_edition: Pubkey,
+    ///Master Record Edition V2 (pda of ['metadata', program id, master metadata mint id, 'edition']
+    pub master_edition: Pubkey,
+    ///Mint of new token - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub new_mint: Pubkey,
+    ///Edition pda to mark creation - will be checked for pre-existence. (pda of ['metadata', program id, master metadata mint id, 'edition', edition_number]) where edition_number is NOT the edition number you pass in args but actually edition_number = floor(edition/EDITION_MARKER_BIT_SIZE).
+    pub edition_mark_pda: Pubkey,
+    ///Mint authority of new mint
+    pub new_mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///Vault authority
+    pub vault_authority: Pubkey,
+    ///Safety deposit token store account
+    pub safety_deposit_store: Pubkey,
+    ///Safety deposit box
+    pub safety_deposit_box: Pubkey,
+    ///Vault
+    pub vault: Pubkey,
+    ///Update authority info for new metadata
+    pub new_metadata_update_authority: Pubkey,
+    ///Master record metadata account
+    pub metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///Token vault program
+    pub token_vault_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>>
+for MintNewEditionFromMasterEditionViaVaultProxyKeys {
+    fn from(accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts) -> Self {
+        Self {
+            new_metadata: Pubkey::new_from_array(*accounts.new_metadata.key()),
+            new_edition: Pubkey::new_from_array(*accounts.new_edition.key()),
+            master_edition: Pubkey::new_from_array(*accounts.master_edition.key()),
+            new_mint: Pubkey::new_from_array(*accounts.new_mint.key()),
+            edition_mark_pda: Pubkey::new_from_array(*accounts.edition_mark_pda.key()),
+            new_mint_authority: Pubkey::new_from_array(
+                *accounts.new_mint_authority.key(),
+            ),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            vault_authority: Pubkey::new_from_array(*accounts.vault_authority.key()),
+            safety_deposit_store: Pubkey::new_from_array(
+                *accounts.safety_deposit_store.key(),
+            ),
+            safety_deposit_box: Pubkey::new_from_array(
+                *accounts.safety_deposit_box.key(),
+            ),
+            vault: Pubkey::new_from_array(*accounts.vault.key()),
+            new_metadata_update_authority: Pubkey::new_from_array(
+                *accounts.new_metadata_update_authority.key(),
+            ),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            token_vault_program: Pubkey::new_from_array(
+                *accounts.token_vault_program.key(),
+            ),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<MintNewEditionFromMasterEditionViaVaultProxyKeys>
+for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] {
+    fn from(keys: MintNewEditionFromMasterEditionViaVaultProxyKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.new_metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.master_edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition_mark_pda,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vault_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.safety_deposit_store,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.safety_deposit_box,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.vault,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.new_metadata_update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_vault_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN]>
+for MintNewEditionFromMasterEditionViaVaultProxyKeys {
+    fn from(
+        pubkeys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            new_metadata: pubkeys[0],
+            new_edition: pubkeys[1],
+            master_edition: pubkeys[2],
+            new_mint: pubkeys[3],
+            edition_mark_pda: pubkeys[4],
+            new_mint_authority: pubkeys[5],
+            payer: pubkeys[6],
+            vault_authority: pubkeys[7],
+            safety_deposit_store: pubkeys[8],
+            safety_deposit_box: pubkeys[9],
+            vault: pubkeys[10],
+            new_metadata_update_authority: pubkeys[11],
+            metadata: pubkeys[12],
+            token_program: pubkeys[13],
+            token_vault_program: pubkeys[14],
+            system_program: pubkeys[15],
+            rent: pubkeys[16],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>>
+for [&'me AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>,
+    ) -> Self {
+        [
+            accounts.new_metadata,
+            accounts.new_edition,
+            accounts.master_edition,
+            accounts.new_mint,
+            accounts.edition_mark_pda,
+            accounts.new_mint_authority,
+            accounts.payer,
+            accounts.vault_authority,
+            accounts.safety_deposit_store,
+            accounts.safety_deposit_box,
+            accounts.vault,
+            accounts.new_metadata_update_authority,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.token_vault_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>>
+for [CpiAccountMeta<
+    'me,
+>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>,
+    ) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.new_metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.master_edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition_mark_pda.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.vault_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.safety_deposit_store.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.safety_deposit_box.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.vault.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_metadata_update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_vault_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+> From<
+    &'me [AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
+> for MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            new_metadata: &arr[0],
+            new_edition: &arr[1],
+            master_edition: &arr[2],
+            new_mint: &arr[3],
+            edition_mark_pda: &arr[4],
+            new_mint_authority: &arr[5],
+            payer: &arr[6],
+            vault_authority: &arr[7],
+            safety_deposit_store: &arr[8],
+            safety_deposit_box: &arr[9],
+            vault: &arr[10],
+            new_metadata_update_authority: &arr[11],
+            metadata: &arr[12],
+            token_program: &arr[13],
+            token_vault_program: &arr[14],
+            system_program: &arr[15],
+            rent: &arr[16],
+        }
+    }
+}
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM: u8 = 13u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintNewEditionFromMasterEditionViaVaultProxyIxArgs {
+    pub mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct MintNewEditionFromMasterEditionViaVaultProxyIxData(
+    pub MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+);
+impl From<MintNewEditionFromMasterEditionViaVaultProxyIxArgs>
+for MintNewEditionFromMasterEditionViaVaultProxyIxData {
+    fn from(args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl MintNewEditionFromMasterEditionViaVaultProxyIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM,
+                        maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(
+            Self(
+                MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(
+                    &mut reader,
+                )?,
+            ),
+        )
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer
+            .write_all(
+                &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM],
+            )?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction
+for MintNewEditionFromMasterEditionViaVaultProxyIxData {
+    const NAME: &'static str = "MintNewEditionFromMasterEditionViaVaultProxy";
+    const DISCM: &'static [u8] = &[
+        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN;
+    type Keys = MintNewEditionFromMasterEditionViaVaultProxyKeys;
+    type Args = MintNewEditionFromMasterEditionViaVaultProxyIxArgs;
+}
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
+    program_id: Pubkey,
+    keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] = keys
+        .into();
+    let data: MintNewEditionFromMasterEditionViaVaultProxyIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix(
+    keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+) -> borsh::io::Result<Instruction> {
+    mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| {
+            MintNewEditionFromMasterEditionViaVaultProxyIxData(args).serialize(writer)
+        },
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>,
+    args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
+) -> ProgramResult {
+    mint_new_edition_from_master_edition_via_vault_proxy_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_keys(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_>,
+    keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.new_metadata.key()), &keys.new_metadata),
+        (&Pubkey::new_from_array(*accounts.new_edition.key()), &keys.new_edition),
+        (&Pubkey::new_from_array(*accounts.master_edition.key()), &keys.master_edition),
+        (&Pubkey::new_from_array(*accounts.new_mint.key()), &keys.new_mint),
+        (
+            &Pubkey::new_from_array(*accounts.edition_mark_pda.key()),
+            &keys.edition_mark_pda,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.new_mint_authority.key()),
+            &keys.new_mint_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.vault_authority.key()),
+            &keys.vault_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.safety_deposit_store.key()),
+            &keys.safety_deposit_store,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.safety_deposit_box.key()),
+            &keys.safety_deposit_box,
+        ),
+        (&Pubkey::new_from_array(*accounts.vault.key()), &keys.vault),
+        (
+            &Pubkey::new_from_array(*accounts.new_metadata_update_authority.key()),
+            &keys.new_metadata_update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (
+            &Pubkey::new_from_array(*accounts.token_vault_program.key()),
+            &keys.token_vault_program,
+        ),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_privileges<
+    'me,
+>(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.new_metadata,
+        accounts.new_edition,
+        accounts.master_edition,
+        accounts.new_mint,
+        accounts.edition_mark_pda,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges<
+    'me,
+>(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.new_mint_authority,
+        accounts.payer,
+        accounts.vault_authority,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_privileges<
+    'me,
+>(
+    accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_privileges(
+        accounts,
+    )?;
+    mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges(
+        accounts,
+    )?;
+    Ok(())
+}
+pub const PUFF_METADATA_IX_ACCOUNTS_LEN: usize = 1;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct PuffMetadataAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct PuffMetadataKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<PuffMetadataAccounts<'_>> for PuffMetadataKeys {
+    fn from(accounts: PuffMetadataAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+        }
+    }
+}
+impl From<PuffMetadataKeys> for [AccountMeta; PUFF_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(keys: PuffMetadataKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; PUFF_METADATA_IX_ACCOUNTS_LEN]> for PuffMetadataKeys {
+    fn from(pubkeys: [Pubkey; PUFF_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self { metadata: pubkeys[0] }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<PuffMetadataAccounts<'me>>
+for [&'me AccountInfo; PUFF_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(accounts: PuffMetadataAccounts<'me>) -> Self {
+        [accounts.metadata]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<PuffMetadataAccounts<'me>>
+for [CpiAccountMeta<'me>; PUFF_METADATA_IX_ACCOUNTS_LEN] {
+    fn from(accounts: PuffMetadataAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; PUFF_METADATA_IX_ACCOUNTS_LEN]>
+for PuffMetadataAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; PUFF_METADATA_IX_ACCOUNTS_LEN]) -> Self {
+        Self { metadata: &arr[0] }
+    }
+}
+pub const PUFF_METADATA_IX_DISCM: u8 = 14u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct PuffMetadataIxData;
+impl PuffMetadataIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != PUFF_METADATA_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PUFF_METADATA_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[PUFF_METADATA_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PuffMetadataIxData {
+    const NAME: &'static str = "PuffMetadata";
+    const DISCM: &'static [u8] = &[PUFF_METADATA_IX_DISCM];
+    const ACCOUNTS_LEN: usize = PUFF_METADATA_IX_ACCOUNTS_LEN;
+    type Keys = PuffMetadataKeys;
+    type Args = ();
+}
+pub fn puff_metadata_ix_with_program_id(
+    program_id: Pubkey,
+    keys: PuffMetadataKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; PUFF_METADATA_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: PuffMetadataIxData.try_to_vec()?,
+    })
+}
+pub fn puff_metadata_ix(keys: PuffMetadataKeys) -> borsh::io::Result<Instruction> {
+    puff_metadata_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: PuffMetadataAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(&mut buf, |writer| PuffMetadataIxData.serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_invoke_signed(
+    accounts: PuffMetadataAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    puff_metadata_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: PuffMetadataAccounts<'_>,
+) -> ProgramResult {
+    puff_metadata_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_invoke(accounts: PuffMetadataAccounts<'_>) -> ProgramResult {
+    puff_metadata_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_verify_account_keys(
+    accounts: PuffMetadataAccounts<'_>,
+    keys: PuffMetadataKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_verify_writable_privileges<'me>(
+    accounts: PuffMetadataAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn puff_metadata_verify_account_privileges<'me>(
+    accounts: PuffMetadataAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    puff_metadata_verify_writable_privileges(accounts)?;
+    Ok(())
+}
+pub const UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UpdateMetadataAccountV2Accounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Update authority key
+    pub update_authority: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UpdateMetadataAccountV2Keys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Update authority key
+    pub update_authority: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UpdateMetadataAccountV2Accounts<'_>> for UpdateMetadataAccountV2Keys {
+    fn from(accounts: UpdateMetadataAccountV2Accounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+        }
+    }
+}
+impl From<UpdateMetadataAccountV2Keys>
+for [AccountMeta; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: UpdateMetadataAccountV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountV2Keys {
+    fn from(pubkeys: [Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            update_authority: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdateMetadataAccountV2Accounts<'me>>
+for [&'me AccountInfo; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdateMetadataAccountV2Accounts<'me>) -> Self {
+        [accounts.metadata, accounts.update_authority]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UpdateMetadataAccountV2Accounts<'me>>
+for [CpiAccountMeta<'me>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UpdateMetadataAccountV2Accounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountV2Accounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: &arr[0],
+            update_authority: &arr[1],
+        }
+    }
+}
+pub const UPDATE_METADATA_ACCOUNT_V2_IX_DISCM: u8 = 15u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateMetadataAccountV2IxArgs {
+    pub update_metadata_account_args_v2: UpdateMetadataAccountArgsV2,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateMetadataAccountV2IxData(pub UpdateMetadataAccountV2IxArgs);
+impl From<UpdateMetadataAccountV2IxArgs> for UpdateMetadataAccountV2IxData {
+    fn from(args: UpdateMetadataAccountV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UpdateMetadataAccountV2IxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UPDATE_METADATA_ACCOUNT_V2_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(UpdateMetadataAccountV2IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[UPDATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateMetadataAccountV2IxData {
+    const NAME: &'static str = "UpdateMetadataAccountV2";
+    const DISCM: &'static [u8] = &[UPDATE_METADATA_ACCOUNT_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN;
+    type Keys = UpdateMetadataAccountV2Keys;
+    type Args = UpdateMetadataAccountV2IxArgs;
+}
+pub fn update_metadata_account_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UpdateMetadataAccountV2Keys,
+    args: UpdateMetadataAccountV2IxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UpdateMetadataAccountV2IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn update_metadata_account_v2_ix(
+    keys: UpdateMetadataAccountV2Keys,
+    args: UpdateMetadataAccountV2IxArgs,
+) -> borsh::io::Result<Instruction> {
+    update_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateMetadataAccountV2Accounts<'_>,
+    args: UpdateMetadataAccountV2IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; CPI_IX_DATA_BUF_LEN];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| UpdateMetadataAccountV2IxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_invoke_signed(
+    accounts: UpdateMetadataAccountV2Accounts<'_>,
+    args: UpdateMetadataAccountV2IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    update_metadata_account_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UpdateMetadataAccountV2Accounts<'_>,
+    args: UpdateMetadataAccountV2IxArgs,
+) -> ProgramResult {
+    update_metadata_account_v2_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_invoke(
+    accounts: UpdateMetadataAccountV2Accounts<'_>,
+    args: UpdateMetadataAccountV2IxArgs,
+) -> ProgramResult {
+    update_metadata_account_v2_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_verify_account_keys(
+    accounts: UpdateMetadataAccountV2Accounts<'_>,
+    keys: UpdateMetadataAccountV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_verify_writable_privileges<'me>(
+    accounts: UpdateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_verify_signer_privileges<'me>(
+    accounts: UpdateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.update_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn update_metadata_account_v2_verify_account_privileges<'me>(
+    accounts: UpdateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    update_metadata_account_v2_verify_writable_privileges(accounts)?;
+    update_metadata_account_v2_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 7;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateMetadataAccountV2Accounts<'me> {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Mint of token asset
+    pub mint: &'me AccountInfo,
+    ///Mint authority
+    pub mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///update authority info
+    pub update_authority: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateMetadataAccountV2Keys {
+    ///Metadata key (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Mint of token asset
+    pub mint: Pubkey,
+    ///Mint authority
+    pub mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///update authority info
+    pub update_authority: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateMetadataAccountV2Accounts<'_>> for CreateMetadataAccountV2Keys {
+    fn from(accounts: CreateMetadataAccountV2Accounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<CreateMetadataAccountV2Keys>
+for [AccountMeta; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateMetadataAccountV2Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountV2Keys {
+    fn from(pubkeys: [Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            mint: pubkeys[1],
+            mint_authority: pubkeys[2],
+            payer: pubkeys[3],
+            update_authority: pubkeys[4],
+            system_program: pubkeys[5],
+            rent: pubkeys[6],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMetadataAccountV2Accounts<'me>>
+for [&'me AccountInfo; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMetadataAccountV2Accounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.mint,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMetadataAccountV2Accounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMetadataAccountV2Accounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountV2Accounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: &arr[0],
+            mint: &arr[1],
+            mint_authority: &arr[2],
+            payer: &arr[3],
+            update_authority: &arr[4],
+            system_program: &arr[5],
+            rent: &arr[6],
+        }
+    }
+}
+pub const CREATE_METADATA_ACCOUNT_V2_IX_DISCM: u8 = 16u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateMetadataAccountV2IxArgs {
+    pub create_metadata_account_args_v2: CreateMetadataAccountArgsV2,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateMetadataAccountV2IxData(pub CreateMetadataAccountV2IxArgs);
+impl From<CreateMetadataAccountV2IxArgs> for CreateMetadataAccountV2IxData {
+    fn from(args: CreateMetadataAccountV2IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateMetadataAccountV2IxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CREATE_METADATA_ACCOUNT_V2_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateMetadataAccountV2IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[CREATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMetadataAccountV2IxData {
+    const NAME: &'static str = "CreateMetadataAccountV2";
+    const DISCM: &'static [u8] = &[CREATE_METADATA_ACCOUNT_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN;
+    type Keys = CreateMetadataAccountV2Keys;
+    type Args = CreateMetadataAccountV2IxArgs;
+}
+pub fn create_metadata_account_v2_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateMetadataAccountV2Keys,
+    args: CreateMetadataAccountV2IxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateMetadataAccountV2IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_metadata_account_v2_ix(
+    keys: CreateMetadataAccountV2Keys,
+    args: CreateMetadataAccountV2IxArgs,
+) -> borsh::io::Result<Instruction> {
+    create_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMetadataAccountV2Accounts<'_>,
+    args: CreateMetadataAccountV2IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; CPI_IX_DATA_BUF_LEN];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| CreateMetadataAccountV2IxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_invoke_signed(
+    accounts: CreateMetadataAccountV2Accounts<'_>,
+    args: CreateMetadataAccountV2IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_metadata_account_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMetadataAccountV2Accounts<'_>,
+    args: CreateMetadataAccountV2IxArgs,
+) -> ProgramResult {
+    create_metadata_account_v2_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_invoke(
+    accounts: CreateMetadataAccountV2Accounts<'_>,
+    args: CreateMetadataAccountV2IxArgs,
+) -> ProgramResult {
+    create_metadata_account_v2_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_verify_account_keys(
+    accounts: CreateMetadataAccountV2Accounts<'_>,
+    keys: CreateMetadataAccountV2Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_verify_writable_privileges<'me>(
+    accounts: CreateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_verify_signer_privileges<'me>(
+    accounts: CreateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.mint_authority, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_metadata_account_v2_verify_account_privileges<'me>(
+    accounts: CreateMetadataAccountV2Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_metadata_account_v2_verify_writable_privileges(accounts)?;
+    create_metadata_account_v2_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct CreateMasterEditionV3Accounts<'me> {
+    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: &'me AccountInfo,
+    ///Metadata mint
+    pub mint: &'me AccountInfo,
+    ///Update authority
+    pub update_authority: &'me AccountInfo,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct CreateMasterEditionV3Keys {
+    ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
+    pub edition: Pubkey,
+    ///Metadata mint
+    pub mint: Pubkey,
+    ///Update authority
+    pub update_authority: Pubkey,
+    ///Mint authority on the metadata's mint - THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY
+    pub mint_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<CreateMasterEditionV3Accounts<'_>> for CreateMasterEditionV3Keys {
+    fn from(accounts: CreateMasterEditionV3Accounts) -> Self {
+        Self {
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            mint_authority: Pubkey::new_from_array(*accounts.mint_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<CreateMasterEditionV3Keys>
+for [AccountMeta; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
+    fn from(keys: CreateMasterEditionV3Keys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]>
+for CreateMasterEditionV3Keys {
+    fn from(pubkeys: [Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            edition: pubkeys[0],
+            mint: pubkeys[1],
+            update_authority: pubkeys[2],
+            mint_authority: pubkeys[3],
+            payer: pubkeys[4],
+            metadata: pubkeys[5],
+            token_program: pubkeys[6],
+            system_program: pubkeys[7],
+            rent: pubkeys[8],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMasterEditionV3Accounts<'me>>
+for [&'me AccountInfo; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMasterEditionV3Accounts<'me>) -> Self {
+        [
+            accounts.edition,
+            accounts.mint,
+            accounts.update_authority,
+            accounts.mint_authority,
+            accounts.payer,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<CreateMasterEditionV3Accounts<'me>>
+for [CpiAccountMeta<'me>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
+    fn from(accounts: CreateMasterEditionV3Accounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]>
+for CreateMasterEditionV3Accounts<'me> {
+    fn from(arr: &'me [AccountInfo; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            edition: &arr[0],
+            mint: &arr[1],
+            update_authority: &arr[2],
+            mint_authority: &arr[3],
+            payer: &arr[4],
+            metadata: &arr[5],
+            token_program: &arr[6],
+            system_program: &arr[7],
+            rent: &arr[8],
+        }
+    }
+}
+pub const CREATE_MASTER_EDITION_V3_IX_DISCM: u8 = 17u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateMasterEditionV3IxArgs {
+    pub create_master_edition_args: CreateMasterEditionArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateMasterEditionV3IxData(pub CreateMasterEditionV3IxArgs);
+impl From<CreateMasterEditionV3IxArgs> for CreateMasterEditionV3IxData {
+    fn from(args: CreateMasterEditionV3IxArgs) -> Self {
+        Self(args)
+    }
+}
+impl CreateMasterEditionV3IxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != CREATE_MASTER_EDITION_V3_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_MASTER_EDITION_V3_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(CreateMasterEditionV3IxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[CREATE_MASTER_EDITION_V3_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMasterEditionV3IxData {
+    const NAME: &'static str = "CreateMasterEditionV3";
+    const DISCM: &'static [u8] = &[CREATE_MASTER_EDITION_V3_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN;
+    type Keys = CreateMasterEditionV3Keys;
+    type Args = CreateMasterEditionV3IxArgs;
+}
+pub fn create_master_edition_v3_ix_with_program_id(
+    program_id: Pubkey,
+    keys: CreateMasterEditionV3Keys,
+    args: CreateMasterEditionV3IxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] = keys.into();
+    let data: CreateMasterEditionV3IxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn create_master_edition_v3_ix(
+    keys: CreateMasterEditionV3Keys,
+    args: CreateMasterEditionV3IxArgs,
+) -> borsh::io::Result<Instruction> {
+    create_master_edition_v3_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMasterEditionV3Accounts<'_>,
+    args: CreateMasterEditionV3IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 10];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| CreateMasterEditionV3IxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_invoke_signed(
+    accounts: CreateMasterEditionV3Accounts<'_>,
+    args: CreateMasterEditionV3IxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    create_master_edition_v3_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: CreateMasterEditionV3Accounts<'_>,
+    args: CreateMasterEditionV3IxArgs,
+) -> ProgramResult {
+    create_master_edition_v3_invoke_signed_with_program_id(
+        program_id,
+        accounts,
+        args,
+        &[],
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_invoke(
+    accounts: CreateMasterEditionV3Accounts<'_>,
+    args: CreateMasterEditionV3IxArgs,
+) -> ProgramResult {
+    create_master_edition_v3_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_verify_account_keys(
+    accounts: CreateMasterEditionV3Accounts<'_>,
+    keys: CreateMasterEditionV3Keys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.mint_authority.key()), &keys.mint_authority),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_verify_writable_privileges<'me>(
+    accounts: CreateMasterEditionV3Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.edition, accounts.mint, accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_verify_signer_privileges<'me>(
+    accounts: CreateMasterEditionV3Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [
+        accounts.update_authority,
+        accounts.mint_authority,
+        accounts.payer,
+    ] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn create_master_edition_v3_verify_account_privileges<'me>(
+    accounts: CreateMasterEditionV3Accounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    create_master_edition_v3_verify_writable_privileges(accounts)?;
+    create_master_edition_v3_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct VerifyCollectionAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Collection Update authority
+    pub collection_authority: &'me AccountInfo,
+    ///payer
+    pub payer: &'me AccountInfo,
+    ///Mint of the Collection
+    pub collection_mint: &'me AccountInfo,
+    ///Metadata Account of the Collection
+    pub collection: &'me AccountInfo,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct VerifyCollectionKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Collection Update authority
+    pub collection_authority: Pubkey,
+    ///payer
+    pub payer: Pubkey,
+    ///Mint of the Collection
+    pub collection_mint: Pubkey,
+    ///Metadata Account of the Collection
+    pub collection: Pubkey,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<VerifyCollectionAccounts<'_>> for VerifyCollectionKeys {
+    fn from(accounts: VerifyCollectionAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            collection_authority: Pubkey::new_from_array(
+                *accounts.collection_authority.key(),
+            ),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            collection_mint: Pubkey::new_from_array(*accounts.collection_mint.key()),
+            collection: Pubkey::new_from_array(*accounts.collection.key()),
+            collection_master_edition_account: Pubkey::new_from_array(
+                *accounts.collection_master_edition_account.key(),
+            ),
+        }
+    }
+}
+impl From<VerifyCollectionKeys> for [AccountMeta; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(keys: VerifyCollectionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.collection_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_master_edition_account,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for VerifyCollectionKeys {
+    fn from(pubkeys: [Pubkey; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            collection_authority: pubkeys[1],
+            payer: pubkeys[2],
+            collection_mint: pubkeys[3],
+            collection: pubkeys[4],
+            collection_master_edition_account: pubkeys[5],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<VerifyCollectionAccounts<'me>>
+for [&'me AccountInfo; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: VerifyCollectionAccounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.collection_authority,
+            accounts.payer,
+            accounts.collection_mint,
+            accounts.collection,
+            accounts.collection_master_edition_account,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<VerifyCollectionAccounts<'me>>
+for [CpiAccountMeta<'me>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: VerifyCollectionAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_master_edition_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for VerifyCollectionAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            collection_authority: &arr[1],
+            payer: &arr[2],
+            collection_mint: &arr[3],
+            collection: &arr[4],
+            collection_master_edition_account: &arr[5],
+        }
+    }
+}
+pub const VERIFY_COLLECTION_IX_DISCM: u8 = 18u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerifyCollectionIxData;
+impl VerifyCollectionIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != VERIFY_COLLECTION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        VERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[VERIFY_COLLECTION_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for VerifyCollectionIxData {
+    const NAME: &'static str = "VerifyCollection";
+    const DISCM: &'static [u8] = &[VERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = VERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = VerifyCollectionKeys;
+    type Args = ();
+}
+pub fn verify_collection_ix_with_program_id(
+    program_id: Pubkey,
+    keys: VerifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: VerifyCollectionIxData.try_to_vec()?,
+    })
+}
+pub fn verify_collection_ix(
+    keys: VerifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    verify_collection_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: VerifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| VerifyCollectionIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_invoke_signed(
+    accounts: VerifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    verify_collection_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: VerifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    verify_collection_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_invoke(
+    accounts: VerifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    verify_collection_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_verify_account_keys(
+    accounts: VerifyCollectionAccounts<'_>,
+    keys: VerifyCollectionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority.key()),
+            &keys.collection_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.collection_mint.key()),
+            &keys.collection_mint,
+        ),
+        (&Pubkey::new_from_array(*accounts.collection.key()), &keys.collection),
+        (
+            &Pubkey::new_from_array(*accounts.collection_master_edition_account.key()),
+            &keys.collection_master_edition_account,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_verify_writable_privileges<'me>(
+    accounts: VerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_verify_signer_privileges<'me>(
+    accounts: VerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.collection_authority, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn verify_collection_verify_account_privileges<'me>(
+    accounts: VerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    verify_collection_verify_writable_privileges(accounts)?;
+    verify_collection_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UTILIZE_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UtilizeAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token Account Of NFT
+    pub token_account: &'me AccountInfo,
+    ///Mint of the Metadata
+    pub mint: &'me AccountInfo,
+    ///A Use Authority / Can be the current Owner of the NFT
+    pub use_authority: &'me AccountInfo,
+    ///Owner
+    pub owner: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///Associated Token program
+    pub ata_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+    ///Use Authority Record PDA If present the program Assumes a delegated use authority
+    pub use_authority_record: &'me AccountInfo,
+    ///Program As Signer (Burner)
+    pub burner: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UtilizeKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Token Account Of NFT
+    pub token_account: Pubkey,
+    ///Mint of the Metadata
+    pub mint: Pubkey,
+    ///A Use Authority / Can be the current Owner of the NFT
+    pub use_authority: Pubkey,
+    ///Owner
+    pub owner: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///Associated Token program
+    pub ata_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+    ///Use Authority Record PDA If present the program Assumes a delegated use authority
+    pub use_authority_record: Pubkey,
+    ///Program As Signer (Burner)
+    pub burner: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UtilizeAccounts<'_>> for UtilizeKeys {
+    fn from(accounts: UtilizeAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_account: Pubkey::new_from_array(*accounts.token_account.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            use_authority: Pubkey::new_from_array(*accounts.use_authority.key()),
+            owner: Pubkey::new_from_array(*accounts.owner.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            ata_program: Pubkey::new_from_array(*accounts.ata_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+            use_authority_record: Pubkey::new_from_array(
+                *accounts.use_authority_record.key(),
+            ),
+            burner: Pubkey::new_from_array(*accounts.burner.key()),
+        }
+    }
+}
+impl From<UtilizeKeys> for [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] {
+    fn from(keys: UtilizeKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.use_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.ata_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.use_authority_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.burner,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeKeys {
+    fn from(pubkeys: [Pubkey; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            token_account: pubkeys[1],
+            mint: pubkeys[2],
+            use_authority: pubkeys[3],
+            owner: pubkeys[4],
+            token_program: pubkeys[5],
+            ata_program: pubkeys[6],
+            system_program: pubkeys[7],
+            rent: pubkeys[8],
+            use_authority_record: pubkeys[9],
+            burner: pubkeys[10],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UtilizeAccounts<'me>> for [&'me AccountInfo; UTILIZE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UtilizeAccounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.token_account,
+            accounts.mint,
+            accounts.use_authority,
+            accounts.owner,
+            accounts.token_program,
+            accounts.ata_program,
+            accounts.system_program,
+            accounts.rent,
+            accounts.use_authority_record,
+            accounts.burner,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UtilizeAccounts<'me>> for [CpiAccountMeta<'me>; UTILIZE_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UtilizeAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.use_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.ata_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.use_authority_record.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.burner.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            token_account: &arr[1],
+            mint: &arr[2],
+            use_authority: &arr[3],
+            owner: &arr[4],
+            token_program: &arr[5],
+            ata_program: &arr[6],
+            system_program: &arr[7],
+            rent: &arr[8],
+            use_authority_record: &arr[9],
+            burner: &arr[10],
+        }
+    }
+}
+pub const UTILIZE_IX_DISCM: u8 = 19u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UtilizeIxArgs {
+    pub utilize_args: UtilizeArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct UtilizeIxData(pub UtilizeIxArgs);
+impl From<UtilizeIxArgs> for UtilizeIxData {
+    fn from(args: UtilizeIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl UtilizeIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UTILIZE_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UTILIZE_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(UtilizeIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[UTILIZE_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UtilizeIxData {
+    const NAME: &'static str = "Utilize";
+    const DISCM: &'static [u8] = &[UTILIZE_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UTILIZE_IX_ACCOUNTS_LEN;
+    type Keys = UtilizeKeys;
+    type Args = UtilizeIxArgs;
+}
+pub fn utilize_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UtilizeKeys,
+    args: UtilizeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] = keys.into();
+    let data: UtilizeIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn utilize_ix(
+    keys: UtilizeKeys,
+    args: UtilizeIxArgs,
+) -> borsh::io::Result<Instruction> {
+    utilize_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UtilizeAccounts<'_>,
+    args: UtilizeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(&mut buf, |writer| UtilizeIxData(args).serialize(writer))?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_invoke_signed(
+    accounts: UtilizeAccounts<'_>,
+    args: UtilizeIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    utilize_invoke_signed_with_program_id(crate::ID, accounts, args, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UtilizeAccounts<'_>,
+    args: UtilizeIxArgs,
+) -> ProgramResult {
+    utilize_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_invoke(
+    accounts: UtilizeAccounts<'_>,
+    args: UtilizeIxArgs,
+) -> ProgramResult {
+    utilize_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_verify_account_keys(
+    accounts: UtilizeAccounts<'_>,
+    keys: UtilizeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_account.key()), &keys.token_account),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.use_authority.key()), &keys.use_authority),
+        (&Pubkey::new_from_array(*accounts.owner.key()), &keys.owner),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.ata_program.key()), &keys.ata_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+        (
+            &Pubkey::new_from_array(*accounts.use_authority_record.key()),
+            &keys.use_authority_record,
+        ),
+        (&Pubkey::new_from_array(*accounts.burner.key()), &keys.burner),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_verify_writable_privileges<'me>(
+    accounts: UtilizeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.metadata,
+        accounts.token_account,
+        accounts.mint,
+        accounts.use_authority_record,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_verify_signer_privileges<'me>(
+    accounts: UtilizeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.use_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn utilize_verify_account_privileges<'me>(
+    accounts: UtilizeAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    utilize_verify_writable_privileges(accounts)?;
+    utilize_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 11;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct ApproveUseAuthorityAccounts<'me> {
+    ///Use Authority Record PDA
+    pub use_authority_record: &'me AccountInfo,
+    ///Owner
+    pub owner: &'me AccountInfo,
+    ///Payer
+    pub payer: &'me AccountInfo,
+    ///A Use Authority
+    pub user: &'me AccountInfo,
+    ///Owned Token Account Of Mint
+    pub owner_token_account: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Mint of Metadata
+    pub mint: &'me AccountInfo,
+    ///Program As Signer (Burner)
+    pub burner: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ApproveUseAuthorityKeys {
+    ///Use Authority Record PDA
+    pub use_authority_record: Pubkey,
+    ///Owner
+    pub owner: Pubkey,
+    ///Payer
+    pub payer: Pubkey,
+    ///A Use Authority
+    pub user: Pubkey,
+    ///Owned Token Account Of Mint
+    pub owner_token_account: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Mint of Metadata
+    pub mint: Pubkey,
+    ///Program As Signer (Burner)
+    pub burner: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<ApproveUseAuthorityAccounts<'_>> for ApproveUseAuthorityKeys {
+    fn from(accounts: ApproveUseAuthorityAccounts) -> Self {
+        Self {
+            use_authority_record: Pubkey::new_from_array(
+                *accounts.use_authority_record.key(),
+            ),
+            owner: Pubkey::new_from_array(*accounts.owner.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            user: Pubkey::new_from_array(*accounts.user.key()),
+            owner_token_account: Pubkey::new_from_array(
+                *accounts.owner_token_account.key(),
+            ),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            burner: Pubkey::new_from_array(*accounts.burner.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<ApproveUseAuthorityKeys>
+for [AccountMeta; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: ApproveUseAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.use_authority_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.user,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.owner_token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.burner,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]> for ApproveUseAuthorityKeys {
+    fn from(pubkeys: [Pubkey; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            use_authority_record: pubkeys[0],
+            owner: pubkeys[1],
+            payer: pubkeys[2],
+            user: pubkeys[3],
+            owner_token_account: pubkeys[4],
+            metadata: pubkeys[5],
+            mint: pubkeys[6],
+            burner: pubkeys[7],
+            token_program: pubkeys[8],
+            system_program: pubkeys[9],
+            rent: pubkeys[10],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ApproveUseAuthorityAccounts<'me>>
+for [&'me AccountInfo; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ApproveUseAuthorityAccounts<'me>) -> Self {
+        [
+            accounts.use_authority_record,
+            accounts.owner,
+            accounts.payer,
+            accounts.user,
+            accounts.owner_token_account,
+            accounts.metadata,
+            accounts.mint,
+            accounts.burner,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ApproveUseAuthorityAccounts<'me>>
+for [CpiAccountMeta<'me>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ApproveUseAuthorityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.use_authority_record.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.user.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner_token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.burner.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]>
+for ApproveUseAuthorityAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            use_authority_record: &arr[0],
+            owner: &arr[1],
+            payer: &arr[2],
+            user: &arr[3],
+            owner_token_account: &arr[4],
+            metadata: &arr[5],
+            mint: &arr[6],
+            burner: &arr[7],
+            token_program: &arr[8],
+            system_program: &arr[9],
+            rent: &arr[10],
+        }
+    }
+}
+pub const APPROVE_USE_AUTHORITY_IX_DISCM: u8 = 20u8;
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApproveUseAuthorityIxArgs {
+    pub approve_use_authority_args: ApproveUseAuthorityArgs,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApproveUseAuthorityIxData(pub ApproveUseAuthorityIxArgs);
+impl From<ApproveUseAuthorityIxArgs> for ApproveUseAuthorityIxData {
+    fn from(args: ApproveUseAuthorityIxArgs) -> Self {
+        Self(args)
+    }
+}
+impl ApproveUseAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != APPROVE_USE_AUTHORITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        APPROVE_USE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self(ApproveUseAuthorityIxArgs::deserialize(&mut reader)?))
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[APPROVE_USE_AUTHORITY_IX_DISCM])?;
+        self.0.serialize(&mut writer)
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ApproveUseAuthorityIxData {
+    const NAME: &'static str = "ApproveUseAuthority";
+    const DISCM: &'static [u8] = &[APPROVE_USE_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ApproveUseAuthorityKeys;
+    type Args = ApproveUseAuthorityIxArgs;
+}
+pub fn approve_use_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ApproveUseAuthorityKeys,
+    args: ApproveUseAuthorityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    let data: ApproveUseAuthorityIxData = args.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: data.try_to_vec()?,
+    })
+}
+pub fn approve_use_authority_ix(
+    keys: ApproveUseAuthorityKeys,
+    args: ApproveUseAuthorityIxArgs,
+) -> borsh::io::Result<Instruction> {
+    approve_use_authority_ix_with_program_id(crate::ID, keys, args)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ApproveUseAuthorityAccounts<'_>,
+    args: ApproveUseAuthorityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 9];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| ApproveUseAuthorityIxData(args).serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_invoke_signed(
+    accounts: ApproveUseAuthorityAccounts<'_>,
+    args: ApproveUseAuthorityIxArgs,
+    signers: &[Signer],
+) -> ProgramResult {
+    approve_use_authority_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ApproveUseAuthorityAccounts<'_>,
+    args: ApproveUseAuthorityIxArgs,
+) -> ProgramResult {
+    approve_use_authority_invoke_signed_with_program_id(program_id, accounts, args, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_invoke(
+    accounts: ApproveUseAuthorityAccounts<'_>,
+    args: ApproveUseAuthorityIxArgs,
+) -> ProgramResult {
+    approve_use_authority_invoke_with_program_id(crate::ID, accounts, args)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_verify_account_keys(
+    accounts: ApproveUseAuthorityAccounts<'_>,
+    keys: ApproveUseAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            &Pubkey::new_from_array(*accounts.use_authority_record.key()),
+            &keys.use_authority_record,
+        ),
+        (&Pubkey::new_from_array(*accounts.owner.key()), &keys.owner),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (&Pubkey::new_from_array(*accounts.user.key()), &keys.user),
+        (
+            &Pubkey::new_from_array(*accounts.owner_token_account.key()),
+            &keys.owner_token_account,
+        ),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.burner.key()), &keys.burner),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_verify_writable_privileges<'me>(
+    accounts: ApproveUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.use_authority_record,
+        accounts.owner_token_account,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_verify_signer_privileges<'me>(
+    accounts: ApproveUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.owner, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_use_authority_verify_account_privileges<'me>(
+    accounts: ApproveUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    approve_use_authority_verify_writable_privileges(accounts)?;
+    approve_use_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 9;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct RevokeUseAuthorityAccounts<'me> {
+    ///Use Authority Record PDA
+    pub use_authority_record: &'me AccountInfo,
+    ///Owner
+    pub owner: &'me AccountInfo,
+    ///A Use Authority
+    pub user: &'me AccountInfo,
+    ///Owned Token Account Of Mint
+    pub owner_token_account: &'me AccountInfo,
+    ///Mint of Metadata
+    pub mint: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Token program
+    pub token_program: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RevokeUseAuthorityKeys {
+    ///Use Authority Record PDA
+    pub use_authority_record: Pubkey,
+    ///Owner
+    pub owner: Pubkey,
+    ///A Use Authority
+    pub user: Pubkey,
+    ///Owned Token Account Of Mint
+    pub owner_token_account: Pubkey,
+    ///Mint of Metadata
+    pub mint: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Token program
+    pub token_program: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<RevokeUseAuthorityAccounts<'_>> for RevokeUseAuthorityKeys {
+    fn from(accounts: RevokeUseAuthorityAccounts) -> Self {
+        Self {
+            use_authority_record: Pubkey::new_from_array(
+                *accounts.use_authority_record.key(),
+            ),
+            owner: Pubkey::new_from_array(*accounts.owner.key()),
+            user: Pubkey::new_from_array(*accounts.user.key()),
+            owner_token_account: Pubkey::new_from_array(
+                *accounts.owner_token_account.key(),
+            ),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<RevokeUseAuthorityKeys>
+for [AccountMeta; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: RevokeUseAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.use_authority_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.user,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.owner_token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]> for RevokeUseAuthorityKeys {
+    fn from(pubkeys: [Pubkey; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            use_authority_record: pubkeys[0],
+            owner: pubkeys[1],
+            user: pubkeys[2],
+            owner_token_account: pubkeys[3],
+            mint: pubkeys[4],
+            metadata: pubkeys[5],
+            token_program: pubkeys[6],
+            system_program: pubkeys[7],
+            rent: pubkeys[8],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RevokeUseAuthorityAccounts<'me>>
+for [&'me AccountInfo; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeUseAuthorityAccounts<'me>) -> Self {
+        [
+            accounts.use_authority_record,
+            accounts.owner,
+            accounts.user,
+            accounts.owner_token_account,
+            accounts.mint,
+            accounts.metadata,
+            accounts.token_program,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RevokeUseAuthorityAccounts<'me>>
+for [CpiAccountMeta<'me>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeUseAuthorityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.use_authority_record.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.user.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.owner_token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]>
+for RevokeUseAuthorityAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            use_authority_record: &arr[0],
+            owner: &arr[1],
+            user: &arr[2],
+            owner_token_account: &arr[3],
+            mint: &arr[4],
+            metadata: &arr[5],
+            token_program: &arr[6],
+            system_program: &arr[7],
+            rent: &arr[8],
+        }
+    }
+}
+pub const REVOKE_USE_AUTHORITY_IX_DISCM: u8 = 21u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeUseAuthorityIxData;
+impl RevokeUseAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REVOKE_USE_AUTHORITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_USE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[REVOKE_USE_AUTHORITY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RevokeUseAuthorityIxData {
+    const NAME: &'static str = "RevokeUseAuthority";
+    const DISCM: &'static [u8] = &[REVOKE_USE_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = RevokeUseAuthorityKeys;
+    type Args = ();
+}
+pub fn revoke_use_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RevokeUseAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RevokeUseAuthorityIxData.try_to_vec()?,
+    })
+}
+pub fn revoke_use_authority_ix(
+    keys: RevokeUseAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    revoke_use_authority_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeUseAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| RevokeUseAuthorityIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_invoke_signed(
+    accounts: RevokeUseAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    revoke_use_authority_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeUseAuthorityAccounts<'_>,
+) -> ProgramResult {
+    revoke_use_authority_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_invoke(
+    accounts: RevokeUseAuthorityAccounts<'_>,
+) -> ProgramResult {
+    revoke_use_authority_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_verify_account_keys(
+    accounts: RevokeUseAuthorityAccounts<'_>,
+    keys: RevokeUseAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            &Pubkey::new_from_array(*accounts.use_authority_record.key()),
+            &keys.use_authority_record,
+        ),
+        (&Pubkey::new_from_array(*accounts.owner.key()), &keys.owner),
+        (&Pubkey::new_from_array(*accounts.user.key()), &keys.user),
+        (
+            &Pubkey::new_from_array(*accounts.owner_token_account.key()),
+            &keys.owner_token_account,
+        ),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_verify_writable_privileges<'me>(
+    accounts: RevokeUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [
+        accounts.use_authority_record,
+        accounts.owner_token_account,
+    ] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_verify_signer_privileges<'me>(
+    accounts: RevokeUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.owner] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_verify_account_privileges<'me>(
+    accounts: RevokeUseAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    revoke_use_authority_verify_writable_privileges(accounts)?;
+    revoke_use_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct UnverifyCollectionAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Collection Authority
+    pub collection_authority: &'me AccountInfo,
+    ///Mint of the Collection
+    pub collection_mint: &'me AccountInfo,
+    ///Metadata Account of the Collection
+    pub collection: &'me AccountInfo,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: &'me AccountInfo,
+    ///Collection Authority Record PDA
+    pub collection_authority_record: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct UnverifyCollectionKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Collection Authority
+    pub collection_authority: Pubkey,
+    ///Mint of the Collection
+    pub collection_mint: Pubkey,
+    ///Metadata Account of the Collection
+    pub collection: Pubkey,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: Pubkey,
+    ///Collection Authority Record PDA
+    pub collection_authority_record: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<UnverifyCollectionAccounts<'_>> for UnverifyCollectionKeys {
+    fn from(accounts: UnverifyCollectionAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            collection_authority: Pubkey::new_from_array(
+                *accounts.collection_authority.key(),
+            ),
+            collection_mint: Pubkey::new_from_array(*accounts.collection_mint.key()),
+            collection: Pubkey::new_from_array(*accounts.collection.key()),
+            collection_master_edition_account: Pubkey::new_from_array(
+                *accounts.collection_master_edition_account.key(),
+            ),
+            collection_authority_record: Pubkey::new_from_array(
+                *accounts.collection_authority_record.key(),
+            ),
+        }
+    }
+}
+impl From<UnverifyCollectionKeys>
+for [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(keys: UnverifyCollectionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.collection_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_master_edition_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_authority_record,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for UnverifyCollectionKeys {
+    fn from(pubkeys: [Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            collection_authority: pubkeys[1],
+            collection_mint: pubkeys[2],
+            collection: pubkeys[3],
+            collection_master_edition_account: pubkeys[4],
+            collection_authority_record: pubkeys[5],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnverifyCollectionAccounts<'me>>
+for [&'me AccountInfo; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnverifyCollectionAccounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.collection_authority,
+            accounts.collection_mint,
+            accounts.collection,
+            accounts.collection_master_edition_account,
+            accounts.collection_authority_record,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<UnverifyCollectionAccounts<'me>>
+for [CpiAccountMeta<'me>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: UnverifyCollectionAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_master_edition_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority_record.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for UnverifyCollectionAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            collection_authority: &arr[1],
+            collection_mint: &arr[2],
+            collection: &arr[3],
+            collection_master_edition_account: &arr[4],
+            collection_authority_record: &arr[5],
+        }
+    }
+}
+pub const UNVERIFY_COLLECTION_IX_DISCM: u8 = 22u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnverifyCollectionIxData;
+impl UnverifyCollectionIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != UNVERIFY_COLLECTION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNVERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[UNVERIFY_COLLECTION_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnverifyCollectionIxData {
+    const NAME: &'static str = "UnverifyCollection";
+    const DISCM: &'static [u8] = &[UNVERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = UnverifyCollectionKeys;
+    type Args = ();
+}
+pub fn unverify_collection_ix_with_program_id(
+    program_id: Pubkey,
+    keys: UnverifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: UnverifyCollectionIxData.try_to_vec()?,
+    })
+}
+pub fn unverify_collection_ix(
+    keys: UnverifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    unverify_collection_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: UnverifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| UnverifyCollectionIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_invoke_signed(
+    accounts: UnverifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    unverify_collection_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: UnverifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    unverify_collection_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_invoke(
+    accounts: UnverifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    unverify_collection_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_verify_account_keys(
+    accounts: UnverifyCollectionAccounts<'_>,
+    keys: UnverifyCollectionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority.key()),
+            &keys.collection_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.collection_mint.key()),
+            &keys.collection_mint,
+        ),
+        (&Pubkey::new_from_array(*accounts.collection.key()), &keys.collection),
+        (
+            &Pubkey::new_from_array(*accounts.collection_master_edition_account.key()),
+            &keys.collection_master_edition_account,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority_record.key()),
+            &keys.collection_authority_record,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_verify_writable_privileges<'me>(
+    accounts: UnverifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_verify_signer_privileges<'me>(
+    accounts: UnverifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.collection_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_verify_account_privileges<'me>(
+    accounts: UnverifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    unverify_collection_verify_writable_privileges(accounts)?;
+    unverify_collection_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct ApproveCollectionAuthorityAccounts<'me> {
+    ///Collection Authority Record PDA
+    pub collection_authority_record: &'me AccountInfo,
+    ///A Collection Authority
+    pub new_collection_authority: &'me AccountInfo,
+    ///Update Authority of Collection NFT
+    pub update_authority: &'me AccountInfo,
+    ///Payer
+    pub payer: &'me AccountInfo,
+    ///Collection Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Mint of Collection Metadata
+    pub mint: &'me AccountInfo,
+    ///System program
+    pub system_program: &'me AccountInfo,
+    ///Rent info
+    pub rent: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ApproveCollectionAuthorityKeys {
+    ///Collection Authority Record PDA
+    pub collection_authority_record: Pubkey,
+    ///A Collection Authority
+    pub new_collection_authority: Pubkey,
+    ///Update Authority of Collection NFT
+    pub update_authority: Pubkey,
+    ///Payer
+    pub payer: Pubkey,
+    ///Collection Metadata account
+    pub metadata: Pubkey,
+    ///Mint of Collection Metadata
+    pub mint: Pubkey,
+    ///System program
+    pub system_program: Pubkey,
+    ///Rent info
+    pub rent: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<ApproveCollectionAuthorityAccounts<'_>> for ApproveCollectionAuthorityKeys {
+    fn from(accounts: ApproveCollectionAuthorityAccounts) -> Self {
+        Self {
+            collection_authority_record: Pubkey::new_from_array(
+                *accounts.collection_authority_record.key(),
+            ),
+            new_collection_authority: Pubkey::new_from_array(
+                *accounts.new_collection_authority.key(),
+            ),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            system_program: Pubkey::new_from_array(*accounts.system_program.key()),
+            rent: Pubkey::new_from_array(*accounts.rent.key()),
+        }
+    }
+}
+impl From<ApproveCollectionAuthorityKeys>
+for [AccountMeta; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: ApproveCollectionAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.collection_authority_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.new_collection_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.system_program,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.rent,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for ApproveCollectionAuthorityKeys {
+    fn from(pubkeys: [Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            collection_authority_record: pubkeys[0],
+            new_collection_authority: pubkeys[1],
+            update_authority: pubkeys[2],
+            payer: pubkeys[3],
+            metadata: pubkeys[4],
+            mint: pubkeys[5],
+            system_program: pubkeys[6],
+            rent: pubkeys[7],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ApproveCollectionAuthorityAccounts<'me>>
+for [&'me AccountInfo; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ApproveCollectionAuthorityAccounts<'me>) -> Self {
+        [
+            accounts.collection_authority_record,
+            accounts.new_collection_authority,
+            accounts.update_authority,
+            accounts.payer,
+            accounts.metadata,
+            accounts.mint,
+            accounts.system_program,
+            accounts.rent,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ApproveCollectionAuthorityAccounts<'me>>
+for [CpiAccountMeta<'me>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ApproveCollectionAuthorityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority_record.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.new_collection_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.system_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.rent.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for ApproveCollectionAuthorityAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            collection_authority_record: &arr[0],
+            new_collection_authority: &arr[1],
+            update_authority: &arr[2],
+            payer: &arr[3],
+            metadata: &arr[4],
+            mint: &arr[5],
+            system_program: &arr[6],
+            rent: &arr[7],
+        }
+    }
+}
+pub const APPROVE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 23u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApproveCollectionAuthorityIxData;
+impl ApproveCollectionAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != APPROVE_COLLECTION_AUTHORITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        APPROVE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[APPROVE_COLLECTION_AUTHORITY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ApproveCollectionAuthorityIxData {
+    const NAME: &'static str = "ApproveCollectionAuthority";
+    const DISCM: &'static [u8] = &[APPROVE_COLLECTION_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ApproveCollectionAuthorityKeys;
+    type Args = ();
+}
+pub fn approve_collection_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ApproveCollectionAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ApproveCollectionAuthorityIxData.try_to_vec()?,
+    })
+}
+pub fn approve_collection_authority_ix(
+    keys: ApproveCollectionAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    approve_collection_authority_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ApproveCollectionAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| ApproveCollectionAuthorityIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_invoke_signed(
+    accounts: ApproveCollectionAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    approve_collection_authority_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ApproveCollectionAuthorityAccounts<'_>,
+) -> ProgramResult {
+    approve_collection_authority_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_invoke(
+    accounts: ApproveCollectionAuthorityAccounts<'_>,
+) -> ProgramResult {
+    approve_collection_authority_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_verify_account_keys(
+    accounts: ApproveCollectionAuthorityAccounts<'_>,
+    keys: ApproveCollectionAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority_record.key()),
+            &keys.collection_authority_record,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.new_collection_authority.key()),
+            &keys.new_collection_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.system_program.key()), &keys.system_program),
+        (&Pubkey::new_from_array(*accounts.rent.key()), &keys.rent),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_verify_writable_privileges<'me>(
+    accounts: ApproveCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.collection_authority_record] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_verify_signer_privileges<'me>(
+    accounts: ApproveCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.update_authority, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn approve_collection_authority_verify_account_privileges<'me>(
+    accounts: ApproveCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    approve_collection_authority_verify_writable_privileges(accounts)?;
+    approve_collection_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct RevokeCollectionAuthorityAccounts<'me> {
+    ///Collection Authority Record PDA
+    pub collection_authority_record: &'me AccountInfo,
+    ///Update Authority of Collection NFT
+    pub update_authority: &'me AccountInfo,
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Mint of Metadata
+    pub mint: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RevokeCollectionAuthorityKeys {
+    ///Collection Authority Record PDA
+    pub collection_authority_record: Pubkey,
+    ///Update Authority of Collection NFT
+    pub update_authority: Pubkey,
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Mint of Metadata
+    pub mint: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<RevokeCollectionAuthorityAccounts<'_>> for RevokeCollectionAuthorityKeys {
+    fn from(accounts: RevokeCollectionAuthorityAccounts) -> Self {
+        Self {
+            collection_authority_record: Pubkey::new_from_array(
+                *accounts.collection_authority_record.key(),
+            ),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+        }
+    }
+}
+impl From<RevokeCollectionAuthorityKeys>
+for [AccountMeta; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(keys: RevokeCollectionAuthorityKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.collection_authority_record,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for RevokeCollectionAuthorityKeys {
+    fn from(pubkeys: [Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            collection_authority_record: pubkeys[0],
+            update_authority: pubkeys[1],
+            metadata: pubkeys[2],
+            mint: pubkeys[3],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RevokeCollectionAuthorityAccounts<'me>>
+for [&'me AccountInfo; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeCollectionAuthorityAccounts<'me>) -> Self {
+        [
+            accounts.collection_authority_record,
+            accounts.update_authority,
+            accounts.metadata,
+            accounts.mint,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RevokeCollectionAuthorityAccounts<'me>>
+for [CpiAccountMeta<'me>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RevokeCollectionAuthorityAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority_record.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for RevokeCollectionAuthorityAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            collection_authority_record: &arr[0],
+            update_authority: &arr[1],
+            metadata: &arr[2],
+            mint: &arr[3],
+        }
+    }
+}
+pub const REVOKE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 24u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevokeCollectionAuthorityIxData;
+impl RevokeCollectionAuthorityIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REVOKE_COLLECTION_AUTHORITY_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[REVOKE_COLLECTION_AUTHORITY_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RevokeCollectionAuthorityIxData {
+    const NAME: &'static str = "RevokeCollectionAuthority";
+    const DISCM: &'static [u8] = &[REVOKE_COLLECTION_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = RevokeCollectionAuthorityKeys;
+    type Args = ();
+}
+pub fn revoke_collection_authority_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RevokeCollectionAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RevokeCollectionAuthorityIxData.try_to_vec()?,
+    })
+}
+pub fn revoke_collection_authority_ix(
+    keys: RevokeCollectionAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
+    revoke_collection_authority_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeCollectionAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| RevokeCollectionAuthorityIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_invoke_signed(
+    accounts: RevokeCollectionAuthorityAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    revoke_collection_authority_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RevokeCollectionAuthorityAccounts<'_>,
+) -> ProgramResult {
+    revoke_collection_authority_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_invoke(
+    accounts: RevokeCollectionAuthorityAccounts<'_>,
+) -> ProgramResult {
+    revoke_collection_authority_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_verify_account_keys(
+    accounts: RevokeCollectionAuthorityAccounts<'_>,
+    keys: RevokeCollectionAuthorityKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority_record.key()),
+            &keys.collection_authority_record,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_verify_writable_privileges<'me>(
+    accounts: RevokeCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.collection_authority_record] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_verify_signer_privileges<'me>(
+    accounts: RevokeCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.update_authority] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn revoke_collection_authority_verify_account_privileges<'me>(
+    accounts: RevokeCollectionAuthorityAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    revoke_collection_authority_verify_writable_privileges(accounts)?;
+    revoke_collection_authority_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 8;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct SetAndVerifyCollectionAccounts<'me> {
+    ///Metadata account
+    pub metadata: &'me AccountInfo,
+    ///Collection Update authority
+    pub collection_authority: &'me AccountInfo,
+    ///Payer
+    pub payer: &'me AccountInfo,
+    ///Update Authority of Collection NFT and NFT
+    pub update_authority: &'me AccountInfo,
+    ///Mint of the Collection
+    pub collection_mint: &'me AccountInfo,
+    ///Metadata Account of the Collection
+    pub collection: &'me AccountInfo,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: &'me AccountInfo,
+    ///Collection Authority Record PDA
+    pub collection_authority_record: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct SetAndVerifyCollectionKeys {
+    ///Metadata account
+    pub metadata: Pubkey,
+    ///Collection Update authority
+    pub collection_authority: Pubkey,
+    ///Payer
+    pub payer: Pubkey,
+    ///Update Authority of Collection NFT and NFT
+    pub update_authority: Pubkey,
+    ///Mint of the Collection
+    pub collection_mint: Pubkey,
+    ///Metadata Account of the Collection
+    pub collection: Pubkey,
+    ///MasterEdition2 Account of the Collection Token
+    pub collection_master_edition_account: Pubkey,
+    ///Collection Authority Record PDA
+    pub collection_authority_record: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<SetAndVerifyCollectionAccounts<'_>> for SetAndVerifyCollectionKeys {
+    fn from(accounts: SetAndVerifyCollectionAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            collection_authority: Pubkey::new_from_array(
+                *accounts.collection_authority.key(),
+            ),
+            payer: Pubkey::new_from_array(*accounts.payer.key()),
+            update_authority: Pubkey::new_from_array(*accounts.update_authority.key()),
+            collection_mint: Pubkey::new_from_array(*accounts.collection_mint.key()),
+            collection: Pubkey::new_from_array(*accounts.collection.key()),
+            collection_master_edition_account: Pubkey::new_from_array(
+                *accounts.collection_master_edition_account.key(),
+            ),
+            collection_authority_record: Pubkey::new_from_array(
+                *accounts.collection_authority_record.key(),
+            ),
+        }
+    }
+}
+impl From<SetAndVerifyCollectionKeys>
+for [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(keys: SetAndVerifyCollectionKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.collection_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.payer,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.update_authority,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_master_edition_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.collection_authority_record,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for SetAndVerifyCollectionKeys {
+    fn from(pubkeys: [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            collection_authority: pubkeys[1],
+            payer: pubkeys[2],
+            update_authority: pubkeys[3],
+            collection_mint: pubkeys[4],
+            collection: pubkeys[5],
+            collection_master_edition_account: pubkeys[6],
+            collection_authority_record: pubkeys[7],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetAndVerifyCollectionAccounts<'me>>
+for [&'me AccountInfo; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetAndVerifyCollectionAccounts<'me>) -> Self {
+        [
+            accounts.metadata,
+            accounts.collection_authority,
+            accounts.payer,
+            accounts.update_authority,
+            accounts.collection_mint,
+            accounts.collection,
+            accounts.collection_master_edition_account,
+            accounts.collection_authority_record,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<SetAndVerifyCollectionAccounts<'me>>
+for [CpiAccountMeta<'me>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: SetAndVerifyCollectionAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.payer.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.update_authority.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_master_edition_account.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.collection_authority_record.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for SetAndVerifyCollectionAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: &arr[0],
+            collection_authority: &arr[1],
+            payer: &arr[2],
+            update_authority: &arr[3],
+            collection_mint: &arr[4],
+            collection: &arr[5],
+            collection_master_edition_account: &arr[6],
+            collection_authority_record: &arr[7],
+        }
+    }
+}
+pub const SET_AND_VERIFY_COLLECTION_IX_DISCM: u8 = 25u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetAndVerifyCollectionIxData;
+impl SetAndVerifyCollectionIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != SET_AND_VERIFY_COLLECTION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_AND_VERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[SET_AND_VERIFY_COLLECTION_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetAndVerifyCollectionIxData {
+    const NAME: &'static str = "SetAndVerifyCollection";
+    const DISCM: &'static [u8] = &[SET_AND_VERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = SetAndVerifyCollectionKeys;
+    type Args = ();
+}
+pub fn set_and_verify_collection_ix_with_program_id(
+    program_id: Pubkey,
+    keys: SetAndVerifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: SetAndVerifyCollectionIxData.try_to_vec()?,
+    })
+}
+pub fn set_and_verify_collection_ix(
+    keys: SetAndVerifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
+    set_and_verify_collection_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAndVerifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| SetAndVerifyCollectionIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_invoke_signed(
+    accounts: SetAndVerifyCollectionAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    set_and_verify_collection_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: SetAndVerifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    set_and_verify_collection_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_invoke(
+    accounts: SetAndVerifyCollectionAccounts<'_>,
+) -> ProgramResult {
+    set_and_verify_collection_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_verify_account_keys(
+    accounts: SetAndVerifyCollectionAccounts<'_>,
+    keys: SetAndVerifyCollectionKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority.key()),
+            &keys.collection_authority,
+        ),
+        (&Pubkey::new_from_array(*accounts.payer.key()), &keys.payer),
+        (
+            &Pubkey::new_from_array(*accounts.update_authority.key()),
+            &keys.update_authority,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.collection_mint.key()),
+            &keys.collection_mint,
+        ),
+        (&Pubkey::new_from_array(*accounts.collection.key()), &keys.collection),
+        (
+            &Pubkey::new_from_array(*accounts.collection_master_edition_account.key()),
+            &keys.collection_master_edition_account,
+        ),
+        (
+            &Pubkey::new_from_array(*accounts.collection_authority_record.key()),
+            &keys.collection_authority_record,
+        ),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_verify_writable_privileges<'me>(
+    accounts: SetAndVerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_verify_signer_privileges<'me>(
+    accounts: SetAndVerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.collection_authority, accounts.payer] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn set_and_verify_collection_verify_account_privileges<'me>(
+    accounts: SetAndVerifyCollectionAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    set_and_verify_collection_verify_writable_privileges(accounts)?;
+    set_and_verify_collection_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct FreezeDelegatedAccountAccounts<'me> {
+    ///Delegate
+    pub delegate: &'me AccountInfo,
+    ///Token account to freeze
+    pub token_account: &'me AccountInfo,
+    ///Edition
+    pub edition: &'me AccountInfo,
+    ///Token mint
+    pub mint: &'me AccountInfo,
+    ///Token Program
+    pub token_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct FreezeDelegatedAccountKeys {
+    ///Delegate
+    pub delegate: Pubkey,
+    ///Token account to freeze
+    pub token_account: Pubkey,
+    ///Edition
+    pub edition: Pubkey,
+    ///Token mint
+    pub mint: Pubkey,
+    ///Token Program
+    pub token_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<FreezeDelegatedAccountAccounts<'_>> for FreezeDelegatedAccountKeys {
+    fn from(accounts: FreezeDelegatedAccountAccounts) -> Self {
+        Self {
+            delegate: Pubkey::new_from_array(*accounts.delegate.key()),
+            token_account: Pubkey::new_from_array(*accounts.token_account.key()),
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+        }
+    }
+}
+impl From<FreezeDelegatedAccountKeys>
+for [AccountMeta; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: FreezeDelegatedAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.delegate,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for FreezeDelegatedAccountKeys {
+    fn from(pubkeys: [Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            delegate: pubkeys[0],
+            token_account: pubkeys[1],
+            edition: pubkeys[2],
+            mint: pubkeys[3],
+            token_program: pubkeys[4],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<FreezeDelegatedAccountAccounts<'me>>
+for [&'me AccountInfo; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: FreezeDelegatedAccountAccounts<'me>) -> Self {
+        [
+            accounts.delegate,
+            accounts.token_account,
+            accounts.edition,
+            accounts.mint,
+            accounts.token_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<FreezeDelegatedAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: FreezeDelegatedAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.delegate.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for FreezeDelegatedAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            delegate: &arr[0],
+            token_account: &arr[1],
+            edition: &arr[2],
+            mint: &arr[3],
+            token_program: &arr[4],
+        }
+    }
+}
+pub const FREEZE_DELEGATED_ACCOUNT_IX_DISCM: u8 = 26u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct FreezeDelegatedAccountIxData;
+impl FreezeDelegatedAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != FREEZE_DELEGATED_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        FREEZE_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[FREEZE_DELEGATED_ACCOUNT_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for FreezeDelegatedAccountIxData {
+    const NAME: &'static str = "FreezeDelegatedAccount";
+    const DISCM: &'static [u8] = &[FREEZE_DELEGATED_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = FreezeDelegatedAccountKeys;
+    type Args = ();
+}
+pub fn freeze_delegated_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: FreezeDelegatedAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: FreezeDelegatedAccountIxData.try_to_vec()?,
+    })
+}
+pub fn freeze_delegated_account_ix(
+    keys: FreezeDelegatedAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    freeze_delegated_account_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: FreezeDelegatedAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| FreezeDelegatedAccountIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_invoke_signed(
+    accounts: FreezeDelegatedAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    freeze_delegated_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: FreezeDelegatedAccountAccounts<'_>,
+) -> ProgramResult {
+    freeze_delegated_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_invoke(
+    accounts: FreezeDelegatedAccountAccounts<'_>,
+) -> ProgramResult {
+    freeze_delegated_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_verify_account_keys(
+    accounts: FreezeDelegatedAccountAccounts<'_>,
+    keys: FreezeDelegatedAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.delegate.key()), &keys.delegate),
+        (&Pubkey::new_from_array(*accounts.token_account.key()), &keys.token_account),
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_verify_writable_privileges<'me>(
+    accounts: FreezeDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_verify_signer_privileges<'me>(
+    accounts: FreezeDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.delegate] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn freeze_delegated_account_verify_account_privileges<'me>(
+    accounts: FreezeDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    freeze_delegated_account_verify_writable_privileges(accounts)?;
+    freeze_delegated_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct ThawDelegatedAccountAccounts<'me> {
+    ///Delegate
+    pub delegate: &'me AccountInfo,
+    ///Token account to thaw
+    pub token_account: &'me AccountInfo,
+    ///Edition
+    pub edition: &'me AccountInfo,
+    ///Token mint
+    pub mint: &'me AccountInfo,
+    ///Token Program
+    pub token_program: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct ThawDelegatedAccountKeys {
+    ///Delegate
+    pub delegate: Pubkey,
+    ///Token account to thaw
+    pub token_account: Pubkey,
+    ///Edition
+    pub edition: Pubkey,
+    ///Token mint
+    pub mint: Pubkey,
+    ///Token Program
+    pub token_program: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<ThawDelegatedAccountAccounts<'_>> for ThawDelegatedAccountKeys {
+    fn from(accounts: ThawDelegatedAccountAccounts) -> Self {
+        Self {
+            delegate: Pubkey::new_from_array(*accounts.delegate.key()),
+            token_account: Pubkey::new_from_array(*accounts.token_account.key()),
+            edition: Pubkey::new_from_array(*accounts.edition.key()),
+            mint: Pubkey::new_from_array(*accounts.mint.key()),
+            token_program: Pubkey::new_from_array(*accounts.token_program.key()),
+        }
+    }
+}
+impl From<ThawDelegatedAccountKeys>
+for [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(keys: ThawDelegatedAccountKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.delegate,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_account,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.edition,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.mint,
+                is_signer: false,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: keys.token_program,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for ThawDelegatedAccountKeys {
+    fn from(pubkeys: [Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            delegate: pubkeys[0],
+            token_account: pubkeys[1],
+            edition: pubkeys[2],
+            mint: pubkeys[3],
+            token_program: pubkeys[4],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ThawDelegatedAccountAccounts<'me>>
+for [&'me AccountInfo; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ThawDelegatedAccountAccounts<'me>) -> Self {
+        [
+            accounts.delegate,
+            accounts.token_account,
+            accounts.edition,
+            accounts.mint,
+            accounts.token_program,
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<ThawDelegatedAccountAccounts<'me>>
+for [CpiAccountMeta<'me>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+    fn from(accounts: ThawDelegatedAccountAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.delegate.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_account.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.edition.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.mint.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.token_program.key(),
+                is_writable: false,
+                is_signer: false,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for ThawDelegatedAccountAccounts<'me> {
+    fn from(arr: &'me [AccountInfo; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            delegate: &arr[0],
+            token_account: &arr[1],
+            edition: &arr[2],
+            mint: &arr[3],
+            token_program: &arr[4],
+        }
+    }
+}
+pub const THAW_DELEGATED_ACCOUNT_IX_DISCM: u8 = 27u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThawDelegatedAccountIxData;
+impl ThawDelegatedAccountIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != THAW_DELEGATED_ACCOUNT_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        THAW_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[THAW_DELEGATED_ACCOUNT_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ThawDelegatedAccountIxData {
+    const NAME: &'static str = "ThawDelegatedAccount";
+    const DISCM: &'static [u8] = &[THAW_DELEGATED_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = ThawDelegatedAccountKeys;
+    type Args = ();
+}
+pub fn thaw_delegated_account_ix_with_program_id(
+    program_id: Pubkey,
+    keys: ThawDelegatedAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: ThawDelegatedAccountIxData.try_to_vec()?,
+    })
+}
+pub fn thaw_delegated_account_ix(
+    keys: ThawDelegatedAccountKeys,
+) -> borsh::io::Result<Instruction> {
+    thaw_delegated_account_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: ThawDelegatedAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| ThawDelegatedAccountIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_invoke_signed(
+    accounts: ThawDelegatedAccountAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    thaw_delegated_account_invoke_signed_with_program_id(crate::ID, accounts, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: ThawDelegatedAccountAccounts<'_>,
+) -> ProgramResult {
+    thaw_delegated_account_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_invoke(
+    accounts: ThawDelegatedAccountAccounts<'_>,
+) -> ProgramResult {
+    thaw_delegated_account_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_verify_account_keys(
+    accounts: ThawDelegatedAccountAccounts<'_>,
+    keys: ThawDelegatedAccountKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.delegate.key()), &keys.delegate),
+        (&Pubkey::new_from_array(*accounts.token_account.key()), &keys.token_account),
+        (&Pubkey::new_from_array(*accounts.edition.key()), &keys.edition),
+        (&Pubkey::new_from_array(*accounts.mint.key()), &keys.mint),
+        (&Pubkey::new_from_array(*accounts.token_program.key()), &keys.token_program),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_verify_writable_privileges<'me>(
+    accounts: ThawDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.token_account] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_verify_signer_privileges<'me>(
+    accounts: ThawDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.delegate] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn thaw_delegated_account_verify_account_privileges<'me>(
+    accounts: ThawDelegatedAccountAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    thaw_delegated_account_verify_writable_privileges(accounts)?;
+    thaw_delegated_account_verify_signer_privileges(accounts)?;
+    Ok(())
+}
+pub const REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN: usize = 2;
+#[derive(Copy, Clone)]
+#[cfg(feature = "cpi")]
+pub struct RemoveCreatorVerificationAccounts<'me> {
+    ///Metadata (pda of ['metadata', program id, mint id])
+    pub metadata: &'me AccountInfo,
+    ///Creator
+    pub creator: &'me AccountInfo,
+}
+#[derive(Copy, Clone, Debug)]
+pub struct RemoveCreatorVerificationKeys {
+    ///Metadata (pda of ['metadata', program id, mint id])
+    pub metadata: Pubkey,
+    ///Creator
+    pub creator: Pubkey,
+}
+#[cfg(feature = "cpi")]
+impl From<RemoveCreatorVerificationAccounts<'_>> for RemoveCreatorVerificationKeys {
+    fn from(accounts: RemoveCreatorVerificationAccounts) -> Self {
+        Self {
+            metadata: Pubkey::new_from_array(*accounts.metadata.key()),
+            creator: Pubkey::new_from_array(*accounts.creator.key()),
+        }
+    }
+}
+impl From<RemoveCreatorVerificationKeys>
+for [AccountMeta; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] {
+    fn from(keys: RemoveCreatorVerificationKeys) -> Self {
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: keys.creator,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
+    }
+}
+impl From<[Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]>
+for RemoveCreatorVerificationKeys {
+    fn from(pubkeys: [Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]) -> Self {
+        Self {
+            metadata: pubkeys[0],
+            creator: pubkeys[1],
+        }
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RemoveCreatorVerificationAccounts<'me>>
+for [&'me AccountInfo; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RemoveCreatorVerificationAccounts<'me>) -> Self {
+        [accounts.metadata, accounts.creator]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<RemoveCreatorVerificationAccounts<'me>>
+for [CpiAccountMeta<'me>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] {
+    fn from(accounts: RemoveCreatorVerificationAccounts<'me>) -> Self {
+        [
+            CpiAccountMeta {
+                pubkey: accounts.metadata.key(),
+                is_writable: true,
+                is_signer: false,
+            },
+            CpiAccountMeta {
+                pubkey: accounts.creator.key(),
+                is_writable: false,
+                is_signer: true,
+            },
+        ]
+    }
+}
+#[cfg(feature = "cpi")]
+impl<'me> From<&'me [AccountInfo; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]>
+for RemoveCreatorVerificationAccounts<'me> {
+    fn from(
+        arr: &'me [AccountInfo; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN],
+    ) -> Self {
+        Self {
+            metadata: &arr[0],
+            creator: &arr[1],
+        }
+    }
+}
+pub const REMOVE_CREATOR_VERIFICATION_IX_DISCM: u8 = 28u8;
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoveCreatorVerificationIxData;
+impl RemoveCreatorVerificationIxData {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+        let mut reader = buf;
+        let mut maybe_discm_buf = [0u8; 1];
+        reader.read_exact(&mut maybe_discm_buf)?;
+        let maybe_discm = maybe_discm_buf[0];
+        if maybe_discm != REMOVE_CREATOR_VERIFICATION_IX_DISCM {
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_CREATOR_VERIFICATION_IX_DISCM, maybe_discm
+                    ),
+                ),
+            );
+        }
+        Ok(Self)
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer.write_all(&[REMOVE_CREATOR_VERIFICATION_IX_DISCM])
+    }
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.serialize(&mut data)?;
+        Ok(data)
+    }
+}
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveCreatorVerificationIxData {
+    const NAME: &'static str = "RemoveCreatorVerification";
+    const DISCM: &'static [u8] = &[REMOVE_CREATOR_VERIFICATION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN;
+    type Keys = RemoveCreatorVerificationKeys;
+    type Args = ();
+}
+pub fn remove_creator_verification_ix_with_program_id(
+    program_id: Pubkey,
+    keys: RemoveCreatorVerificationKeys,
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] = keys.into();
+    Ok(Instruction {
+        program_id,
+        accounts: Vec::from(metas),
+        data: RemoveCreatorVerificationIxData.try_to_vec()?,
+    })
+}
+pub fn remove_creator_verification_ix(
+    keys: RemoveCreatorVerificationKeys,
+) -> borsh::io::Result<Instruction> {
+    remove_creator_verification_ix_with_program_id(crate::ID, keys)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_invoke_signed_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveCreatorVerificationAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    let mut buf = [0u8; 1];
+    let data = write_ix_data(
+        &mut buf,
+        |writer| RemoveCreatorVerificationIxData.serialize(writer),
+    )?;
+    invoke_instruction_signed(&program_id, accounts, data, signers)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_invoke_signed(
+    accounts: RemoveCreatorVerificationAccounts<'_>,
+    signers: &[Signer],
+) -> ProgramResult {
+    remove_creator_verification_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        signers,
+    )
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_invoke_with_program_id(
+    program_id: Pubkey,
+    accounts: RemoveCreatorVerificationAccounts<'_>,
+) -> ProgramResult {
+    remove_creator_verification_invoke_signed_with_program_id(program_id, accounts, &[])
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_invoke(
+    accounts: RemoveCreatorVerificationAccounts<'_>,
+) -> ProgramResult {
+    remove_creator_verification_invoke_with_program_id(crate::ID, accounts)
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_verify_account_keys(
+    accounts: RemoveCreatorVerificationAccounts<'_>,
+    keys: RemoveCreatorVerificationKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (&Pubkey::new_from_array(*accounts.metadata.key()), &keys.metadata),
+        (&Pubkey::new_from_array(*accounts.creator.key()), &keys.creator),
+    ] {
+        if actual != expected {
+            return Err((*actual, *expected));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_verify_writable_privileges<'me>(
+    accounts: RemoveCreatorVerificationAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_writable in [accounts.metadata] {
+        if !should_be_writable.is_writable() {
+            return Err((should_be_writable, ProgramError::InvalidAccountData));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_verify_signer_privileges<'me>(
+    accounts: RemoveCreatorVerificationAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    for should_be_signer in [accounts.creator] {
+        if !should_be_signer.is_signer() {
+            return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+        }
+    }
+    Ok(())
+}
+#[cfg(feature = "cpi")]
+pub fn remove_creator_verification_verify_account_privileges<'me>(
+    accounts: RemoveCreatorVerificationAccounts<'me>,
+) -> Result<(), (&'me AccountInfo, ProgramError)> {
+    remove_creator_verification_verify_writable_privileges(accounts)?;
+    remove_creator_verification_verify_signer_privileges(accounts)?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+#[cfg(feature = "devnet")]
+solana_program::declare_id!("DEVNET1111111111111111111111111111111111111");
+#[cfg(all(feature = "localnet", not(any(feature = "devnet"))))]
+solana_program::declare_id!("LocaLNET11111111111111111111111111111111111");
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+solana_program::declare_id!("unpXTU2Ndrc7WWNyEhQWe4udTzSibLPi25SXv2xbCHQ");
+/// Program IDs of every cluster feature, regardless of which are enabled
+pub mod ids {
+    ///Program ID with feature `devnet`
+    pub mod devnet {
+        solana_program::declare_id!("DEVNET1111111111111111111111111111111111111");
+    }
+    ///Program ID with feature `localnet`
+    pub mod localnet {
+        solana_program::declare_id!("LocaLNET11111111111111111111111111111111111");
+    }
+}
+/// Version of solores that generated this crate
+pub const SOLORES_VERSION: &str = "0.8.0";
+/// Hex-encoded SHA-256 of the IDL this crate was generated from
+pub const IDL_SHA256: &str = "53d44a4c0b984103e81f34a4dc2742a70d7a6e5ae0b2c4d1e3dc3bdf1db7abdc";
+/// The solores options this crate was generated with, in `solores.toml` format
+pub const SOLORES_OPTIONS: &str = "borsh-vers = \"workspace=true\"\nbytemuck-vers = \"workspace=true\"\nnum-derive-vers = \"workspace=true\"\nnum-traits-vers = \"workspace=true\"\noutput-crate-name = \"unstake_program_id_for_interface\"\nserde-vers = \"workspace=true\"\nserde-with-vers = \"workspace=true\"\nsolana-program-vers = \"workspace=true\"\nsolores-runtime-vers = \"workspace=true\"\nthiserror-vers = \"workspace=true\"\n\n[[program-id-for]]\nfeature = \"devnet\"\nprogram-id = \"DEVNET1111111111111111111111111111111111111\"\n\n[[program-id-for]]\nfeature = \"localnet\"\nprogram-id = \"LocaLNET11111111111111111111111111111111111\"\n";
+/// The IDL this crate was generated from
+#[cfg(feature = "idl")]
+pub const IDL_JSON: &str = include_str!("../idl.json");
+pub mod accounts;
+pub use accounts::*;
+pub mod typedefs;
+pub use typedefs::*;
+pub mod instructions;
+pub use instructions::*;
+pub mod errors;
+pub use errors::*;
@@ -0,0 +1,13 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+use crate::*;
+use solana_program::pubkey::Pubkey;
+mod fee;
+pub use fee::*;
+mod pool;
+pub use pool::*;
+mod protocol_fee;
+pub use protocol_fee::*;
+mod stake_account_record;
+pub use stake_account_record::*;
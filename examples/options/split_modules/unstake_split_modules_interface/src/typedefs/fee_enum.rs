@@ -0,0 +1,7 @@
+use super::*;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeeEnum {
+    Flat { ratio: Rational },
+    LiquidityLinear { params: LiquidityLinearParams },
+}
@@ -0,0 +1,7 @@
+use super::*;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LiquidityLinearParams {
+    pub max_liq_remaining: Rational,
+    pub zero_liq_remaining: Rational,
+}
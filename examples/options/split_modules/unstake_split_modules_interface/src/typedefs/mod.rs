@@ -0,0 +1,7 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+mod rational;
+pub use rational::*;
+mod liquidity_linear_params;
+pub use liquidity_linear_params::*;
+mod fee_enum;
+pub use fee_enum::*;
@@ -0,0 +1,7 @@
+use super::*;
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rational {
+    pub num: u64,
+    pub denom: u64,
+}
@@ -1,32 +1,36 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{
-    entrypoint::ProgramResult,
-    instruction::Instruction,
-    program::{invoke, invoke_signed},
-    pubkey::Pubkey,
-};
-use std::io::Read;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+#[cfg(feature = "cpi")]
+use solana_program::{entrypoint::ProgramResult, program::{invoke, invoke_signed}};
+use borsh::io::Read;
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShankIxNoAccountsProgramIx {
     NoAccountsIx(NoAccountsIxIxArgs),
 }
 impl ShankIxNoAccountsProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         match maybe_discm {
-            NO_ACCOUNTS_IX_IX_DISCM => Ok(Self::NoAccountsIx(NoAccountsIxIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            NO_ACCOUNTS_IX_IX_DISCM => {
+                Ok(Self::NoAccountsIx(NoAccountsIxIxArgs::deserialize(&mut reader)?))
+            }
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::NoAccountsIx(args) => {
                 writer.write_all(&[NO_ACCOUNTS_IX_IX_DISCM])?;
@@ -34,7 +38,7 @@ impl ShankIxNoAccountsProgramIx {
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
@@ -54,36 +58,49 @@ impl From<NoAccountsIxIxArgs> for NoAccountsIxIxData {
     }
 }
 impl NoAccountsIxIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != NO_ACCOUNTS_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ACCOUNTS_IX_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NO_ACCOUNTS_IX_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(NoAccountsIxIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[NO_ACCOUNTS_IX_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NoAccountsIxIxData {
+    const NAME: &'static str = "noAccountsIx";
+    const DISCM: &'static [u8] = &[NO_ACCOUNTS_IX_IX_DISCM];
+    const ACCOUNTS_LEN: usize = 0;
+    type Keys = ();
+    type Args = NoAccountsIxIxArgs;
+}
 pub fn no_accounts_ix_ix_with_program_id(
     program_id: Pubkey,
     args: NoAccountsIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let data: NoAccountsIxIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -91,9 +108,10 @@ pub fn no_accounts_ix_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn no_accounts_ix_ix(args: NoAccountsIxIxArgs) -> std::io::Result<Instruction> {
+pub fn no_accounts_ix_ix(args: NoAccountsIxIxArgs) -> borsh::io::Result<Instruction> {
     no_accounts_ix_ix_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_ix_invoke_with_program_id(
     program_id: Pubkey,
     args: NoAccountsIxIxArgs,
@@ -101,9 +119,11 @@ pub fn no_accounts_ix_invoke_with_program_id(
     let ix = no_accounts_ix_ix_with_program_id(program_id, args)?;
     invoke(&ix, &[])
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_ix_invoke(args: NoAccountsIxIxArgs) -> ProgramResult {
     no_accounts_ix_invoke_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_ix_invoke_signed_with_program_id(
     program_id: Pubkey,
     args: NoAccountsIxIxArgs,
@@ -112,6 +132,10 @@ pub fn no_accounts_ix_invoke_signed_with_program_id(
     let ix = no_accounts_ix_ix_with_program_id(program_id, args)?;
     invoke_signed(&ix, &[], seeds)
 }
-pub fn no_accounts_ix_invoke_signed(args: NoAccountsIxIxArgs, seeds: &[&[&[u8]]]) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn no_accounts_ix_invoke_signed(
+    args: NoAccountsIxIxArgs,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
     no_accounts_ix_invoke_signed_with_program_id(crate::ID, args, seeds)
 }
@@ -1,32 +1,40 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{
-    entrypoint::ProgramResult,
-    instruction::Instruction,
-    program::{invoke, invoke_signed},
-    pubkey::Pubkey,
-};
-use std::io::Read;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+#[cfg(feature = "cpi")]
+use solana_program::{entrypoint::ProgramResult, program::{invoke, invoke_signed}};
+use borsh::io::Read;
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShankIxNoAccountsPubkeyArgProgramIx {
     NoAccountsPubkeyArgIx(NoAccountsPubkeyArgIxIxArgs),
 }
 impl ShankIxNoAccountsPubkeyArgProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         match maybe_discm {
-            NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM => Ok(Self::NoAccountsPubkeyArgIx(
-                NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?,
-            )),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM => {
+                Ok(
+                    Self::NoAccountsPubkeyArgIx(
+                        NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::NoAccountsPubkeyArgIx(args) => {
                 writer.write_all(&[NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM])?;
@@ -34,7 +42,7 @@ impl ShankIxNoAccountsPubkeyArgProgramIx {
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
@@ -54,36 +62,49 @@ impl From<NoAccountsPubkeyArgIxIxArgs> for NoAccountsPubkeyArgIxIxData {
     }
 }
 impl NoAccountsPubkeyArgIxIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(NoAccountsPubkeyArgIxIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NoAccountsPubkeyArgIxIxData {
+    const NAME: &'static str = "noAccountsPubkeyArgIx";
+    const DISCM: &'static [u8] = &[NO_ACCOUNTS_PUBKEY_ARG_IX_IX_DISCM];
+    const ACCOUNTS_LEN: usize = 0;
+    type Keys = ();
+    type Args = NoAccountsPubkeyArgIxIxArgs;
+}
 pub fn no_accounts_pubkey_arg_ix_ix_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let data: NoAccountsPubkeyArgIxIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -93,9 +114,10 @@ pub fn no_accounts_pubkey_arg_ix_ix_with_program_id(
 }
 pub fn no_accounts_pubkey_arg_ix_ix(
     args: NoAccountsPubkeyArgIxIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     no_accounts_pubkey_arg_ix_ix_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
@@ -103,9 +125,13 @@ pub fn no_accounts_pubkey_arg_ix_invoke_with_program_id(
     let ix = no_accounts_pubkey_arg_ix_ix_with_program_id(program_id, args)?;
     invoke(&ix, &[])
 }
-pub fn no_accounts_pubkey_arg_ix_invoke(args: NoAccountsPubkeyArgIxIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn no_accounts_pubkey_arg_ix_invoke(
+    args: NoAccountsPubkeyArgIxIxArgs,
+) -> ProgramResult {
     no_accounts_pubkey_arg_ix_invoke_with_program_id(crate::ID, args)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_signed_with_program_id(
     program_id: Pubkey,
     args: NoAccountsPubkeyArgIxIxArgs,
@@ -114,6 +140,7 @@ pub fn no_accounts_pubkey_arg_ix_invoke_signed_with_program_id(
     let ix = no_accounts_pubkey_arg_ix_ix_with_program_id(program_id, args)?;
     invoke_signed(&ix, &[], seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn no_accounts_pubkey_arg_ix_invoke_signed(
     args: NoAccountsPubkeyArgIxIxArgs,
     seeds: &[&[&[u8]]],
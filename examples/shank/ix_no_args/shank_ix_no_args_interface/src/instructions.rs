@@ -1,41 +1,51 @@
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
-use std::io::Read;
+use borsh::io::Read;
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShankIxNoArgsProgramIx {
     NoArgsIx,
 }
 impl ShankIxNoArgsProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         match maybe_discm {
             NO_ARGS_IX_IX_DISCM => Ok(Self::NoArgsIx),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::NoArgsIx => writer.write_all(&[NO_ARGS_IX_IX_DISCM]),
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -43,6 +53,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -53,6 +64,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const NO_ARGS_IX_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct NoArgsIxAccounts<'me, 'info> {
     pub b: &'me AccountInfo<'info>,
 }
@@ -60,6 +72,7 @@ pub struct NoArgsIxAccounts<'me, 'info> {
 pub struct NoArgsIxKeys {
     pub b: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<NoArgsIxAccounts<'_, '_>> for NoArgsIxKeys {
     fn from(accounts: NoArgsIxAccounts) -> Self {
         Self { b: *accounts.b.key }
@@ -67,11 +80,13 @@ impl From<NoArgsIxAccounts<'_, '_>> for NoArgsIxKeys {
 }
 impl From<NoArgsIxKeys> for [AccountMeta; NO_ARGS_IX_IX_ACCOUNTS_LEN] {
     fn from(keys: NoArgsIxKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.b,
-            is_signer: false,
-            is_writable: true,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.b,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
     }
 }
 impl From<[Pubkey; NO_ARGS_IX_IX_ACCOUNTS_LEN]> for NoArgsIxKeys {
@@ -79,14 +94,16 @@ impl From<[Pubkey; NO_ARGS_IX_IX_ACCOUNTS_LEN]> for NoArgsIxKeys {
         Self { b: pubkeys[0] }
     }
 }
-impl<'info> From<NoArgsIxAccounts<'_, 'info>> for [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<NoArgsIxAccounts<'_, 'info>>
+for [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN] {
     fn from(accounts: NoArgsIxAccounts<'_, 'info>) -> Self {
         [accounts.b.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN]>
-    for NoArgsIxAccounts<'me, 'info>
-{
+for NoArgsIxAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; NO_ARGS_IX_IX_ACCOUNTS_LEN]) -> Self {
         Self { b: &arr[0] }
     }
@@ -95,35 +112,48 @@ pub const NO_ARGS_IX_IX_DISCM: u8 = 69u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct NoArgsIxIxData;
 impl NoArgsIxIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != NO_ARGS_IX_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NO_ARGS_IX_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NO_ARGS_IX_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[NO_ARGS_IX_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NoArgsIxIxData {
+    const NAME: &'static str = "noArgsIx";
+    const DISCM: &'static [u8] = &[NO_ARGS_IX_IX_DISCM];
+    const ACCOUNTS_LEN: usize = NO_ARGS_IX_IX_ACCOUNTS_LEN;
+    type Keys = NoArgsIxKeys;
+    type Args = ();
+}
 pub fn no_args_ix_ix_with_program_id(
     program_id: Pubkey,
     keys: NoArgsIxKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; NO_ARGS_IX_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -131,9 +161,10 @@ pub fn no_args_ix_ix_with_program_id(
         data: NoArgsIxIxData.try_to_vec()?,
     })
 }
-pub fn no_args_ix_ix(keys: NoArgsIxKeys) -> std::io::Result<Instruction> {
+pub fn no_args_ix_ix(keys: NoArgsIxKeys) -> borsh::io::Result<Instruction> {
     no_args_ix_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NoArgsIxAccounts<'_, '_>,
@@ -142,9 +173,11 @@ pub fn no_args_ix_invoke_with_program_id(
     let ix = no_args_ix_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_invoke(accounts: NoArgsIxAccounts<'_, '_>) -> ProgramResult {
     no_args_ix_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: NoArgsIxAccounts<'_, '_>,
@@ -154,12 +187,14 @@ pub fn no_args_ix_invoke_signed_with_program_id(
     let ix = no_args_ix_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_invoke_signed(
     accounts: NoArgsIxAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     no_args_ix_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_verify_account_keys(
     accounts: NoArgsIxAccounts<'_, '_>,
     keys: NoArgsIxKeys,
@@ -171,6 +206,7 @@ pub fn no_args_ix_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_verify_writable_privileges<'me, 'info>(
     accounts: NoArgsIxAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -181,6 +217,7 @@ pub fn no_args_ix_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn no_args_ix_verify_account_privileges<'me, 'info>(
     accounts: NoArgsIxAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
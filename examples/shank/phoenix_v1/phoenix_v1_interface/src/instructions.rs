@@ -1,14 +1,16 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
-use std::io::Read;
+use borsh::io::Read;
+use crate::*;
 #[derive(Clone, Debug, PartialEq)]
 pub enum PhoenixV1ProgramIx {
     Swap(SwapIxArgs),
@@ -28,7 +30,9 @@ pub enum PhoenixV1ProgramIx {
     RequestSeat,
     Log,
     PlaceMultiplePostOnlyOrders(PlaceMultiplePostOnlyOrdersIxArgs),
-    PlaceMultiplePostOnlyOrdersWithFreeFunds(PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs),
+    PlaceMultiplePostOnlyOrdersWithFreeFunds(
+        PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
+    ),
     InitializeMarket(InitializeMarketIxArgs),
     ClaimAuthority,
     NameSuccessor(NameSuccessorIxArgs),
@@ -41,87 +45,148 @@ pub enum PhoenixV1ProgramIx {
     ChangeFeeRecipient,
 }
 impl PhoenixV1ProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         match maybe_discm {
             SWAP_IX_DISCM => Ok(Self::Swap(SwapIxArgs::deserialize(&mut reader)?)),
-            SWAP_WITH_FREE_FUNDS_IX_DISCM => Ok(Self::SwapWithFreeFunds(
-                SwapWithFreeFundsIxArgs::deserialize(&mut reader)?,
-            )),
-            PLACE_LIMIT_ORDER_IX_DISCM => Ok(Self::PlaceLimitOrder(
-                PlaceLimitOrderIxArgs::deserialize(&mut reader)?,
-            )),
-            PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM => Ok(Self::PlaceLimitOrderWithFreeFunds(
-                PlaceLimitOrderWithFreeFundsIxArgs::deserialize(&mut reader)?,
-            )),
-            REDUCE_ORDER_IX_DISCM => Ok(Self::ReduceOrder(ReduceOrderIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM => Ok(Self::ReduceOrderWithFreeFunds(
-                ReduceOrderWithFreeFundsIxArgs::deserialize(&mut reader)?,
-            )),
+            SWAP_WITH_FREE_FUNDS_IX_DISCM => {
+                Ok(
+                    Self::SwapWithFreeFunds(
+                        SwapWithFreeFundsIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            PLACE_LIMIT_ORDER_IX_DISCM => {
+                Ok(
+                    Self::PlaceLimitOrder(
+                        PlaceLimitOrderIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM => {
+                Ok(
+                    Self::PlaceLimitOrderWithFreeFunds(
+                        PlaceLimitOrderWithFreeFundsIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            REDUCE_ORDER_IX_DISCM => {
+                Ok(Self::ReduceOrder(ReduceOrderIxArgs::deserialize(&mut reader)?))
+            }
+            REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM => {
+                Ok(
+                    Self::ReduceOrderWithFreeFunds(
+                        ReduceOrderWithFreeFundsIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             CANCEL_ALL_ORDERS_IX_DISCM => Ok(Self::CancelAllOrders),
-            CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM => Ok(Self::CancelAllOrdersWithFreeFunds),
-            CANCEL_UP_TO_IX_DISCM => Ok(Self::CancelUpTo(CancelUpToIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM => Ok(Self::CancelUpToWithFreeFunds(
-                CancelUpToWithFreeFundsIxArgs::deserialize(&mut reader)?,
-            )),
-            CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM => Ok(Self::CancelMultipleOrdersById(
-                CancelMultipleOrdersByIdIxArgs::deserialize(&mut reader)?,
-            )),
+            CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM => {
+                Ok(Self::CancelAllOrdersWithFreeFunds)
+            }
+            CANCEL_UP_TO_IX_DISCM => {
+                Ok(Self::CancelUpTo(CancelUpToIxArgs::deserialize(&mut reader)?))
+            }
+            CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM => {
+                Ok(
+                    Self::CancelUpToWithFreeFunds(
+                        CancelUpToWithFreeFundsIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM => {
+                Ok(
+                    Self::CancelMultipleOrdersById(
+                        CancelMultipleOrdersByIdIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM => {
-                Ok(Self::CancelMultipleOrdersByIdWithFreeFunds(
-                    CancelMultipleOrdersByIdWithFreeFundsIxArgs::deserialize(&mut reader)?,
-                ))
+                Ok(
+                    Self::CancelMultipleOrdersByIdWithFreeFunds(
+                        CancelMultipleOrdersByIdWithFreeFundsIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            WITHDRAW_FUNDS_IX_DISCM => {
+                Ok(Self::WithdrawFunds(WithdrawFundsIxArgs::deserialize(&mut reader)?))
+            }
+            DEPOSIT_FUNDS_IX_DISCM => {
+                Ok(Self::DepositFunds(DepositFundsIxArgs::deserialize(&mut reader)?))
             }
-            WITHDRAW_FUNDS_IX_DISCM => Ok(Self::WithdrawFunds(WithdrawFundsIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            DEPOSIT_FUNDS_IX_DISCM => Ok(Self::DepositFunds(DepositFundsIxArgs::deserialize(
-                &mut reader,
-            )?)),
             REQUEST_SEAT_IX_DISCM => Ok(Self::RequestSeat),
             LOG_IX_DISCM => Ok(Self::Log),
-            PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM => Ok(Self::PlaceMultiplePostOnlyOrders(
-                PlaceMultiplePostOnlyOrdersIxArgs::deserialize(&mut reader)?,
-            )),
+            PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM => {
+                Ok(
+                    Self::PlaceMultiplePostOnlyOrders(
+                        PlaceMultiplePostOnlyOrdersIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM => {
-                Ok(Self::PlaceMultiplePostOnlyOrdersWithFreeFunds(
-                    PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs::deserialize(&mut reader)?,
-                ))
+                Ok(
+                    Self::PlaceMultiplePostOnlyOrdersWithFreeFunds(
+                        PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            INITIALIZE_MARKET_IX_DISCM => {
+                Ok(
+                    Self::InitializeMarket(
+                        InitializeMarketIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
             }
-            INITIALIZE_MARKET_IX_DISCM => Ok(Self::InitializeMarket(
-                InitializeMarketIxArgs::deserialize(&mut reader)?,
-            )),
             CLAIM_AUTHORITY_IX_DISCM => Ok(Self::ClaimAuthority),
-            NAME_SUCCESSOR_IX_DISCM => Ok(Self::NameSuccessor(NameSuccessorIxArgs::deserialize(
-                &mut reader,
-            )?)),
-            CHANGE_MARKET_STATUS_IX_DISCM => Ok(Self::ChangeMarketStatus(
-                ChangeMarketStatusIxArgs::deserialize(&mut reader)?,
-            )),
-            CHANGE_SEAT_STATUS_IX_DISCM => Ok(Self::ChangeSeatStatus(
-                ChangeSeatStatusIxArgs::deserialize(&mut reader)?,
-            )),
+            NAME_SUCCESSOR_IX_DISCM => {
+                Ok(Self::NameSuccessor(NameSuccessorIxArgs::deserialize(&mut reader)?))
+            }
+            CHANGE_MARKET_STATUS_IX_DISCM => {
+                Ok(
+                    Self::ChangeMarketStatus(
+                        ChangeMarketStatusIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CHANGE_SEAT_STATUS_IX_DISCM => {
+                Ok(
+                    Self::ChangeSeatStatus(
+                        ChangeSeatStatusIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             REQUEST_SEAT_AUTHORIZED_IX_DISCM => Ok(Self::RequestSeatAuthorized),
             EVICT_SEAT_IX_DISCM => Ok(Self::EvictSeat),
-            FORCE_CANCEL_ORDERS_IX_DISCM => Ok(Self::ForceCancelOrders(
-                ForceCancelOrdersIxArgs::deserialize(&mut reader)?,
-            )),
+            FORCE_CANCEL_ORDERS_IX_DISCM => {
+                Ok(
+                    Self::ForceCancelOrders(
+                        ForceCancelOrdersIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             COLLECT_FEES_IX_DISCM => Ok(Self::CollectFees),
             CHANGE_FEE_RECIPIENT_IX_DISCM => Ok(Self::ChangeFeeRecipient),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::Swap(args) => {
                 writer.write_all(&[SWAP_IX_DISCM])?;
@@ -164,7 +229,10 @@ impl PhoenixV1ProgramIx {
                 args.serialize(&mut writer)
             }
             Self::CancelMultipleOrdersByIdWithFreeFunds(args) => {
-                writer.write_all(&[CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM])?;
+                writer
+                    .write_all(
+                        &[CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM],
+                    )?;
                 args.serialize(&mut writer)
             }
             Self::WithdrawFunds(args) => {
@@ -182,7 +250,10 @@ impl PhoenixV1ProgramIx {
                 args.serialize(&mut writer)
             }
             Self::PlaceMultiplePostOnlyOrdersWithFreeFunds(args) => {
-                writer.write_all(&[PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM])?;
+                writer
+                    .write_all(
+                        &[PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM],
+                    )?;
                 args.serialize(&mut writer)
             }
             Self::InitializeMarket(args) => {
@@ -202,22 +273,27 @@ impl PhoenixV1ProgramIx {
                 writer.write_all(&[CHANGE_SEAT_STATUS_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
-            Self::RequestSeatAuthorized => writer.write_all(&[REQUEST_SEAT_AUTHORIZED_IX_DISCM]),
+            Self::RequestSeatAuthorized => {
+                writer.write_all(&[REQUEST_SEAT_AUTHORIZED_IX_DISCM])
+            }
             Self::EvictSeat => writer.write_all(&[EVICT_SEAT_IX_DISCM]),
             Self::ForceCancelOrders(args) => {
                 writer.write_all(&[FORCE_CANCEL_ORDERS_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
             Self::CollectFees => writer.write_all(&[COLLECT_FEES_IX_DISCM]),
-            Self::ChangeFeeRecipient => writer.write_all(&[CHANGE_FEE_RECIPIENT_IX_DISCM]),
+            Self::ChangeFeeRecipient => {
+                writer.write_all(&[CHANGE_FEE_RECIPIENT_IX_DISCM])
+            }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -225,6 +301,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -235,6 +312,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const SWAP_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SwapAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -274,6 +352,7 @@ pub struct SwapKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SwapAccounts<'_, '_>> for SwapKeys {
     fn from(accounts: SwapAccounts) -> Self {
         Self {
@@ -355,7 +434,9 @@ impl From<[Pubkey; SWAP_IX_ACCOUNTS_LEN]> for SwapKeys {
         }
     }
 }
-impl<'info> From<SwapAccounts<'_, 'info>> for [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<SwapAccounts<'_, 'info>>
+for [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN] {
     fn from(accounts: SwapAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -370,9 +451,9 @@ impl<'info> From<SwapAccounts<'_, 'info>> for [AccountInfo<'info>; SWAP_IX_ACCOU
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN]>
-    for SwapAccounts<'me, 'info>
-{
+for SwapAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SWAP_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -401,37 +482,50 @@ impl From<SwapIxArgs> for SwapIxData {
     }
 }
 impl SwapIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != SWAP_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SWAP_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SWAP_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SwapIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[SWAP_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SwapIxData {
+    const NAME: &'static str = "Swap";
+    const DISCM: &'static [u8] = &[SWAP_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SWAP_IX_ACCOUNTS_LEN;
+    type Keys = SwapKeys;
+    type Args = SwapIxArgs;
+}
 pub fn swap_ix_with_program_id(
     program_id: Pubkey,
     keys: SwapKeys,
     args: SwapIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SWAP_IX_ACCOUNTS_LEN] = keys.into();
     let data: SwapIxData = args.into();
     Ok(Instruction {
@@ -440,9 +534,10 @@ pub fn swap_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn swap_ix(keys: SwapKeys, args: SwapIxArgs) -> std::io::Result<Instruction> {
+pub fn swap_ix(keys: SwapKeys, args: SwapIxArgs) -> borsh::io::Result<Instruction> {
     swap_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SwapAccounts<'_, '_>,
@@ -452,9 +547,11 @@ pub fn swap_invoke_with_program_id(
     let ix = swap_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_invoke(accounts: SwapAccounts<'_, '_>, args: SwapIxArgs) -> ProgramResult {
     swap_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SwapAccounts<'_, '_>,
@@ -465,6 +562,7 @@ pub fn swap_invoke_signed_with_program_id(
     let ix = swap_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_invoke_signed(
     accounts: SwapAccounts<'_, '_>,
     args: SwapIxArgs,
@@ -472,6 +570,7 @@ pub fn swap_invoke_signed(
 ) -> ProgramResult {
     swap_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_verify_account_keys(
     accounts: SwapAccounts<'_, '_>,
     keys: SwapKeys,
@@ -493,6 +592,7 @@ pub fn swap_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_verify_writable_privileges<'me, 'info>(
     accounts: SwapAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -509,6 +609,7 @@ pub fn swap_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_verify_signer_privileges<'me, 'info>(
     accounts: SwapAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -519,6 +620,7 @@ pub fn swap_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_verify_account_privileges<'me, 'info>(
     accounts: SwapAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -528,6 +630,7 @@ pub fn swap_verify_account_privileges<'me, 'info>(
 }
 pub const SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SwapWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -549,6 +652,7 @@ pub struct SwapWithFreeFundsKeys {
     pub trader: Pubkey,
     pub seat: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SwapWithFreeFundsAccounts<'_, '_>> for SwapWithFreeFundsKeys {
     fn from(accounts: SwapWithFreeFundsAccounts) -> Self {
         Self {
@@ -560,7 +664,8 @@ impl From<SwapWithFreeFundsAccounts<'_, '_>> for SwapWithFreeFundsKeys {
         }
     }
 }
-impl From<SwapWithFreeFundsKeys> for [AccountMeta; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
+impl From<SwapWithFreeFundsKeys>
+for [AccountMeta; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: SwapWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -602,9 +707,9 @@ impl From<[Pubkey; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]> for SwapWithFreeFundsK
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SwapWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: SwapWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -615,10 +720,12 @@ impl<'info> From<SwapWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for SwapWithFreeFundsAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
+for SwapWithFreeFundsAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -642,37 +749,50 @@ impl From<SwapWithFreeFundsIxArgs> for SwapWithFreeFundsIxData {
     }
 }
 impl SwapWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != SWAP_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SWAP_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SWAP_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(SwapWithFreeFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[SWAP_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SwapWithFreeFundsIxData {
+    const NAME: &'static str = "SwapWithFreeFunds";
+    const DISCM: &'static [u8] = &[SWAP_WITH_FREE_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = SwapWithFreeFundsKeys;
+    type Args = SwapWithFreeFundsIxArgs;
+}
 pub fn swap_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: SwapWithFreeFundsKeys,
     args: SwapWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SWAP_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
     let data: SwapWithFreeFundsIxData = args.into();
     Ok(Instruction {
@@ -684,9 +804,10 @@ pub fn swap_with_free_funds_ix_with_program_id(
 pub fn swap_with_free_funds_ix(
     keys: SwapWithFreeFundsKeys,
     args: SwapWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     swap_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
@@ -696,12 +817,14 @@ pub fn swap_with_free_funds_invoke_with_program_id(
     let ix = swap_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_invoke(
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
     args: SwapWithFreeFundsIxArgs,
 ) -> ProgramResult {
     swap_with_free_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
@@ -712,6 +835,7 @@ pub fn swap_with_free_funds_invoke_signed_with_program_id(
     let ix = swap_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_invoke_signed(
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
     args: SwapWithFreeFundsIxArgs,
@@ -719,6 +843,7 @@ pub fn swap_with_free_funds_invoke_signed(
 ) -> ProgramResult {
     swap_with_free_funds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_verify_account_keys(
     accounts: SwapWithFreeFundsAccounts<'_, '_>,
     keys: SwapWithFreeFundsKeys,
@@ -736,6 +861,7 @@ pub fn swap_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_verify_writable_privileges<'me, 'info>(
     accounts: SwapWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -746,6 +872,7 @@ pub fn swap_with_free_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: SwapWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -756,6 +883,7 @@ pub fn swap_with_free_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn swap_with_free_funds_verify_account_privileges<'me, 'info>(
     accounts: SwapWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -765,6 +893,7 @@ pub fn swap_with_free_funds_verify_account_privileges<'me, 'info>(
 }
 pub const PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct PlaceLimitOrderAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -806,6 +935,7 @@ pub struct PlaceLimitOrderKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<PlaceLimitOrderAccounts<'_, '_>> for PlaceLimitOrderKeys {
     fn from(accounts: PlaceLimitOrderAccounts) -> Self {
         Self {
@@ -894,9 +1024,9 @@ impl From<[Pubkey; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN]> for PlaceLimitOrderKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<PlaceLimitOrderAccounts<'_, 'info>>
-    for [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN] {
     fn from(accounts: PlaceLimitOrderAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -912,9 +1042,9 @@ impl<'info> From<PlaceLimitOrderAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN]>
-    for PlaceLimitOrderAccounts<'me, 'info>
-{
+for PlaceLimitOrderAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -944,37 +1074,50 @@ impl From<PlaceLimitOrderIxArgs> for PlaceLimitOrderIxData {
     }
 }
 impl PlaceLimitOrderIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != PLACE_LIMIT_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_LIMIT_ORDER_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PLACE_LIMIT_ORDER_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(PlaceLimitOrderIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[PLACE_LIMIT_ORDER_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PlaceLimitOrderIxData {
+    const NAME: &'static str = "PlaceLimitOrder";
+    const DISCM: &'static [u8] = &[PLACE_LIMIT_ORDER_IX_DISCM];
+    const ACCOUNTS_LEN: usize = PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN;
+    type Keys = PlaceLimitOrderKeys;
+    type Args = PlaceLimitOrderIxArgs;
+}
 pub fn place_limit_order_ix_with_program_id(
     program_id: Pubkey,
     keys: PlaceLimitOrderKeys,
     args: PlaceLimitOrderIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; PLACE_LIMIT_ORDER_IX_ACCOUNTS_LEN] = keys.into();
     let data: PlaceLimitOrderIxData = args.into();
     Ok(Instruction {
@@ -986,9 +1129,10 @@ pub fn place_limit_order_ix_with_program_id(
 pub fn place_limit_order_ix(
     keys: PlaceLimitOrderKeys,
     args: PlaceLimitOrderIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     place_limit_order_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderAccounts<'_, '_>,
@@ -998,12 +1142,14 @@ pub fn place_limit_order_invoke_with_program_id(
     let ix = place_limit_order_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_invoke(
     accounts: PlaceLimitOrderAccounts<'_, '_>,
     args: PlaceLimitOrderIxArgs,
 ) -> ProgramResult {
     place_limit_order_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderAccounts<'_, '_>,
@@ -1014,6 +1160,7 @@ pub fn place_limit_order_invoke_signed_with_program_id(
     let ix = place_limit_order_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_invoke_signed(
     accounts: PlaceLimitOrderAccounts<'_, '_>,
     args: PlaceLimitOrderIxArgs,
@@ -1021,6 +1168,7 @@ pub fn place_limit_order_invoke_signed(
 ) -> ProgramResult {
     place_limit_order_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_verify_account_keys(
     accounts: PlaceLimitOrderAccounts<'_, '_>,
     keys: PlaceLimitOrderKeys,
@@ -1043,6 +1191,7 @@ pub fn place_limit_order_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_verify_writable_privileges<'me, 'info>(
     accounts: PlaceLimitOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1059,6 +1208,7 @@ pub fn place_limit_order_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_verify_signer_privileges<'me, 'info>(
     accounts: PlaceLimitOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1069,6 +1219,7 @@ pub fn place_limit_order_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_verify_account_privileges<'me, 'info>(
     accounts: PlaceLimitOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1078,6 +1229,7 @@ pub fn place_limit_order_verify_account_privileges<'me, 'info>(
 }
 pub const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct PlaceLimitOrderWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -1099,7 +1251,9 @@ pub struct PlaceLimitOrderWithFreeFundsKeys {
     pub trader: Pubkey,
     pub seat: Pubkey,
 }
-impl From<PlaceLimitOrderWithFreeFundsAccounts<'_, '_>> for PlaceLimitOrderWithFreeFundsKeys {
+#[cfg(feature = "cpi")]
+impl From<PlaceLimitOrderWithFreeFundsAccounts<'_, '_>>
+for PlaceLimitOrderWithFreeFundsKeys {
     fn from(accounts: PlaceLimitOrderWithFreeFundsAccounts) -> Self {
         Self {
             phoenix_program: *accounts.phoenix_program.key,
@@ -1111,8 +1265,7 @@ impl From<PlaceLimitOrderWithFreeFundsAccounts<'_, '_>> for PlaceLimitOrderWithF
     }
 }
 impl From<PlaceLimitOrderWithFreeFundsKeys>
-    for [AccountMeta; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: PlaceLimitOrderWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -1144,9 +1297,10 @@ impl From<PlaceLimitOrderWithFreeFundsKeys>
     }
 }
 impl From<[Pubkey; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for PlaceLimitOrderWithFreeFundsKeys
-{
-    fn from(pubkeys: [Pubkey; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
+for PlaceLimitOrderWithFreeFundsKeys {
+    fn from(
+        pubkeys: [Pubkey; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: pubkeys[0],
             log_authority: pubkeys[1],
@@ -1156,9 +1310,9 @@ impl From<[Pubkey; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<PlaceLimitOrderWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -1169,9 +1323,12 @@ impl<'info> From<PlaceLimitOrderWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for PlaceLimitOrderWithFreeFundsAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for PlaceLimitOrderWithFreeFundsAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -1198,40 +1355,52 @@ impl From<PlaceLimitOrderWithFreeFundsIxArgs> for PlaceLimitOrderWithFreeFundsIx
     }
 }
 impl PlaceLimitOrderWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(PlaceLimitOrderWithFreeFundsIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(PlaceLimitOrderWithFreeFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PlaceLimitOrderWithFreeFundsIxData {
+    const NAME: &'static str = "PlaceLimitOrderWithFreeFunds";
+    const DISCM: &'static [u8] = &[PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = PlaceLimitOrderWithFreeFundsKeys;
+    type Args = PlaceLimitOrderWithFreeFundsIxArgs;
+}
 pub fn place_limit_order_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: PlaceLimitOrderWithFreeFundsKeys,
     args: PlaceLimitOrderWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; PLACE_LIMIT_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: PlaceLimitOrderWithFreeFundsIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -1242,24 +1411,31 @@ pub fn place_limit_order_with_free_funds_ix_with_program_id(
 pub fn place_limit_order_with_free_funds_ix(
     keys: PlaceLimitOrderWithFreeFundsKeys,
     args: PlaceLimitOrderWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     place_limit_order_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
     args: PlaceLimitOrderWithFreeFundsIxArgs,
 ) -> ProgramResult {
     let keys: PlaceLimitOrderWithFreeFundsKeys = accounts.into();
-    let ix = place_limit_order_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = place_limit_order_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_invoke(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
     args: PlaceLimitOrderWithFreeFundsIxArgs,
 ) -> ProgramResult {
     place_limit_order_with_free_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
@@ -1267,9 +1443,14 @@ pub fn place_limit_order_with_free_funds_invoke_signed_with_program_id(
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: PlaceLimitOrderWithFreeFundsKeys = accounts.into();
-    let ix = place_limit_order_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = place_limit_order_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_invoke_signed(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
     args: PlaceLimitOrderWithFreeFundsIxArgs,
@@ -1282,6 +1463,7 @@ pub fn place_limit_order_with_free_funds_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_verify_account_keys(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'_, '_>,
     keys: PlaceLimitOrderWithFreeFundsKeys,
@@ -1299,6 +1481,7 @@ pub fn place_limit_order_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_verify_writable_privileges<'me, 'info>(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1309,6 +1492,7 @@ pub fn place_limit_order_with_free_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1319,6 +1503,7 @@ pub fn place_limit_order_with_free_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_limit_order_with_free_funds_verify_account_privileges<'me, 'info>(
     accounts: PlaceLimitOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1328,6 +1513,7 @@ pub fn place_limit_order_with_free_funds_verify_account_privileges<'me, 'info>(
 }
 pub const REDUCE_ORDER_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ReduceOrderAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -1367,6 +1553,7 @@ pub struct ReduceOrderKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ReduceOrderAccounts<'_, '_>> for ReduceOrderKeys {
     fn from(accounts: ReduceOrderAccounts) -> Self {
         Self {
@@ -1448,9 +1635,9 @@ impl From<[Pubkey; REDUCE_ORDER_IX_ACCOUNTS_LEN]> for ReduceOrderKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ReduceOrderAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN] {
     fn from(accounts: ReduceOrderAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -1465,9 +1652,9 @@ impl<'info> From<ReduceOrderAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN]>
-    for ReduceOrderAccounts<'me, 'info>
-{
+for ReduceOrderAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REDUCE_ORDER_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -1496,37 +1683,50 @@ impl From<ReduceOrderIxArgs> for ReduceOrderIxData {
     }
 }
 impl ReduceOrderIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REDUCE_ORDER_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REDUCE_ORDER_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REDUCE_ORDER_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ReduceOrderIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REDUCE_ORDER_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ReduceOrderIxData {
+    const NAME: &'static str = "ReduceOrder";
+    const DISCM: &'static [u8] = &[REDUCE_ORDER_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REDUCE_ORDER_IX_ACCOUNTS_LEN;
+    type Keys = ReduceOrderKeys;
+    type Args = ReduceOrderIxArgs;
+}
 pub fn reduce_order_ix_with_program_id(
     program_id: Pubkey,
     keys: ReduceOrderKeys,
     args: ReduceOrderIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REDUCE_ORDER_IX_ACCOUNTS_LEN] = keys.into();
     let data: ReduceOrderIxData = args.into();
     Ok(Instruction {
@@ -1538,9 +1738,10 @@ pub fn reduce_order_ix_with_program_id(
 pub fn reduce_order_ix(
     keys: ReduceOrderKeys,
     args: ReduceOrderIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     reduce_order_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderAccounts<'_, '_>,
@@ -1550,12 +1751,14 @@ pub fn reduce_order_invoke_with_program_id(
     let ix = reduce_order_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_invoke(
     accounts: ReduceOrderAccounts<'_, '_>,
     args: ReduceOrderIxArgs,
 ) -> ProgramResult {
     reduce_order_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderAccounts<'_, '_>,
@@ -1566,6 +1769,7 @@ pub fn reduce_order_invoke_signed_with_program_id(
     let ix = reduce_order_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_invoke_signed(
     accounts: ReduceOrderAccounts<'_, '_>,
     args: ReduceOrderIxArgs,
@@ -1573,6 +1777,7 @@ pub fn reduce_order_invoke_signed(
 ) -> ProgramResult {
     reduce_order_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_verify_account_keys(
     accounts: ReduceOrderAccounts<'_, '_>,
     keys: ReduceOrderKeys,
@@ -1594,6 +1799,7 @@ pub fn reduce_order_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_verify_writable_privileges<'me, 'info>(
     accounts: ReduceOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1610,6 +1816,7 @@ pub fn reduce_order_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_verify_signer_privileges<'me, 'info>(
     accounts: ReduceOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1620,6 +1827,7 @@ pub fn reduce_order_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_verify_account_privileges<'me, 'info>(
     accounts: ReduceOrderAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1629,6 +1837,7 @@ pub fn reduce_order_verify_account_privileges<'me, 'info>(
 }
 pub const REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ReduceOrderWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -1648,6 +1857,7 @@ pub struct ReduceOrderWithFreeFundsKeys {
     pub market: Pubkey,
     pub trader: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ReduceOrderWithFreeFundsAccounts<'_, '_>> for ReduceOrderWithFreeFundsKeys {
     fn from(accounts: ReduceOrderWithFreeFundsAccounts) -> Self {
         Self {
@@ -1659,8 +1869,7 @@ impl From<ReduceOrderWithFreeFundsAccounts<'_, '_>> for ReduceOrderWithFreeFunds
     }
 }
 impl From<ReduceOrderWithFreeFundsKeys>
-    for [AccountMeta; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: ReduceOrderWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -1686,7 +1895,8 @@ impl From<ReduceOrderWithFreeFundsKeys>
         ]
     }
 }
-impl From<[Pubkey; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]> for ReduceOrderWithFreeFundsKeys {
+impl From<[Pubkey; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for ReduceOrderWithFreeFundsKeys {
     fn from(pubkeys: [Pubkey; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: pubkeys[0],
@@ -1696,9 +1906,9 @@ impl From<[Pubkey; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]> for ReduceOrde
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ReduceOrderWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: ReduceOrderWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -1708,10 +1918,15 @@ impl<'info> From<ReduceOrderWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for ReduceOrderWithFreeFundsAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for ReduceOrderWithFreeFundsAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -1734,39 +1949,50 @@ impl From<ReduceOrderWithFreeFundsIxArgs> for ReduceOrderWithFreeFundsIxData {
     }
 }
 impl ReduceOrderWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(ReduceOrderWithFreeFundsIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(ReduceOrderWithFreeFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ReduceOrderWithFreeFundsIxData {
+    const NAME: &'static str = "ReduceOrderWithFreeFunds";
+    const DISCM: &'static [u8] = &[REDUCE_ORDER_WITH_FREE_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = ReduceOrderWithFreeFundsKeys;
+    type Args = ReduceOrderWithFreeFundsIxArgs;
+}
 pub fn reduce_order_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: ReduceOrderWithFreeFundsKeys,
     args: ReduceOrderWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REDUCE_ORDER_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
     let data: ReduceOrderWithFreeFundsIxData = args.into();
     Ok(Instruction {
@@ -1778,9 +2004,10 @@ pub fn reduce_order_with_free_funds_ix_with_program_id(
 pub fn reduce_order_with_free_funds_ix(
     keys: ReduceOrderWithFreeFundsKeys,
     args: ReduceOrderWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     reduce_order_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
@@ -1790,12 +2017,14 @@ pub fn reduce_order_with_free_funds_invoke_with_program_id(
     let ix = reduce_order_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_invoke(
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
     args: ReduceOrderWithFreeFundsIxArgs,
 ) -> ProgramResult {
     reduce_order_with_free_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
@@ -1806,13 +2035,20 @@ pub fn reduce_order_with_free_funds_invoke_signed_with_program_id(
     let ix = reduce_order_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_invoke_signed(
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
     args: ReduceOrderWithFreeFundsIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    reduce_order_with_free_funds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    reduce_order_with_free_funds_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_verify_account_keys(
     accounts: ReduceOrderWithFreeFundsAccounts<'_, '_>,
     keys: ReduceOrderWithFreeFundsKeys,
@@ -1829,6 +2065,7 @@ pub fn reduce_order_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_verify_writable_privileges<'me, 'info>(
     accounts: ReduceOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1839,6 +2076,7 @@ pub fn reduce_order_with_free_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: ReduceOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1849,6 +2087,7 @@ pub fn reduce_order_with_free_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn reduce_order_with_free_funds_verify_account_privileges<'me, 'info>(
     accounts: ReduceOrderWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1858,6 +2097,7 @@ pub fn reduce_order_with_free_funds_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelAllOrdersAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -1897,6 +2137,7 @@ pub struct CancelAllOrdersKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CancelAllOrdersAccounts<'_, '_>> for CancelAllOrdersKeys {
     fn from(accounts: CancelAllOrdersAccounts) -> Self {
         Self {
@@ -1978,9 +2219,9 @@ impl From<[Pubkey; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN]> for CancelAllOrdersKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelAllOrdersAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelAllOrdersAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -1995,9 +2236,9 @@ impl<'info> From<CancelAllOrdersAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN]>
-    for CancelAllOrdersAccounts<'me, 'info>
-{
+for CancelAllOrdersAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -2016,35 +2257,48 @@ pub const CANCEL_ALL_ORDERS_IX_DISCM: u8 = 6u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersIxData;
 impl CancelAllOrdersIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_ALL_ORDERS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_ALL_ORDERS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_ALL_ORDERS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_ALL_ORDERS_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelAllOrdersIxData {
+    const NAME: &'static str = "CancelAllOrders";
+    const DISCM: &'static [u8] = &[CANCEL_ALL_ORDERS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN;
+    type Keys = CancelAllOrdersKeys;
+    type Args = ();
+}
 pub fn cancel_all_orders_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelAllOrdersKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CANCEL_ALL_ORDERS_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -2052,9 +2306,12 @@ pub fn cancel_all_orders_ix_with_program_id(
         data: CancelAllOrdersIxData.try_to_vec()?,
     })
 }
-pub fn cancel_all_orders_ix(keys: CancelAllOrdersKeys) -> std::io::Result<Instruction> {
+pub fn cancel_all_orders_ix(
+    keys: CancelAllOrdersKeys,
+) -> borsh::io::Result<Instruction> {
     cancel_all_orders_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersAccounts<'_, '_>,
@@ -2063,9 +2320,13 @@ pub fn cancel_all_orders_invoke_with_program_id(
     let ix = cancel_all_orders_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn cancel_all_orders_invoke(accounts: CancelAllOrdersAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn cancel_all_orders_invoke(
+    accounts: CancelAllOrdersAccounts<'_, '_>,
+) -> ProgramResult {
     cancel_all_orders_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersAccounts<'_, '_>,
@@ -2075,12 +2336,14 @@ pub fn cancel_all_orders_invoke_signed_with_program_id(
     let ix = cancel_all_orders_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_invoke_signed(
     accounts: CancelAllOrdersAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     cancel_all_orders_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_verify_account_keys(
     accounts: CancelAllOrdersAccounts<'_, '_>,
     keys: CancelAllOrdersKeys,
@@ -2102,6 +2365,7 @@ pub fn cancel_all_orders_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_verify_writable_privileges<'me, 'info>(
     accounts: CancelAllOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2118,6 +2382,7 @@ pub fn cancel_all_orders_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_verify_signer_privileges<'me, 'info>(
     accounts: CancelAllOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2128,6 +2393,7 @@ pub fn cancel_all_orders_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_verify_account_privileges<'me, 'info>(
     accounts: CancelAllOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2137,6 +2403,7 @@ pub fn cancel_all_orders_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelAllOrdersWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -2156,7 +2423,9 @@ pub struct CancelAllOrdersWithFreeFundsKeys {
     pub market: Pubkey,
     pub trader: Pubkey,
 }
-impl From<CancelAllOrdersWithFreeFundsAccounts<'_, '_>> for CancelAllOrdersWithFreeFundsKeys {
+#[cfg(feature = "cpi")]
+impl From<CancelAllOrdersWithFreeFundsAccounts<'_, '_>>
+for CancelAllOrdersWithFreeFundsKeys {
     fn from(accounts: CancelAllOrdersWithFreeFundsAccounts) -> Self {
         Self {
             phoenix_program: *accounts.phoenix_program.key,
@@ -2167,8 +2436,7 @@ impl From<CancelAllOrdersWithFreeFundsAccounts<'_, '_>> for CancelAllOrdersWithF
     }
 }
 impl From<CancelAllOrdersWithFreeFundsKeys>
-    for [AccountMeta; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: CancelAllOrdersWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -2195,9 +2463,10 @@ impl From<CancelAllOrdersWithFreeFundsKeys>
     }
 }
 impl From<[Pubkey; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for CancelAllOrdersWithFreeFundsKeys
-{
-    fn from(pubkeys: [Pubkey; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
+for CancelAllOrdersWithFreeFundsKeys {
+    fn from(
+        pubkeys: [Pubkey; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: pubkeys[0],
             log_authority: pubkeys[1],
@@ -2206,9 +2475,9 @@ impl From<[Pubkey; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelAllOrdersWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelAllOrdersWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -2218,9 +2487,12 @@ impl<'info> From<CancelAllOrdersWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for CancelAllOrdersWithFreeFundsAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for CancelAllOrdersWithFreeFundsAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -2236,36 +2508,50 @@ pub const CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM: u8 = 7u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CancelAllOrdersWithFreeFundsIxData;
 impl CancelAllOrdersWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelAllOrdersWithFreeFundsIxData {
+    const NAME: &'static str = "CancelAllOrdersWithFreeFunds";
+    const DISCM: &'static [u8] = &[CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = CancelAllOrdersWithFreeFundsKeys;
+    type Args = ();
+}
 pub fn cancel_all_orders_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelAllOrdersWithFreeFundsKeys,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CANCEL_ALL_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys
+        .into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -2274,9 +2560,10 @@ pub fn cancel_all_orders_with_free_funds_ix_with_program_id(
 }
 pub fn cancel_all_orders_with_free_funds_ix(
     keys: CancelAllOrdersWithFreeFundsKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     cancel_all_orders_with_free_funds_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
@@ -2285,11 +2572,13 @@ pub fn cancel_all_orders_with_free_funds_invoke_with_program_id(
     let ix = cancel_all_orders_with_free_funds_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_invoke(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
 ) -> ProgramResult {
     cancel_all_orders_with_free_funds_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
@@ -2299,12 +2588,18 @@ pub fn cancel_all_orders_with_free_funds_invoke_signed_with_program_id(
     let ix = cancel_all_orders_with_free_funds_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_invoke_signed(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    cancel_all_orders_with_free_funds_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    cancel_all_orders_with_free_funds_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_verify_account_keys(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'_, '_>,
     keys: CancelAllOrdersWithFreeFundsKeys,
@@ -2321,6 +2616,7 @@ pub fn cancel_all_orders_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_verify_writable_privileges<'me, 'info>(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2331,6 +2627,7 @@ pub fn cancel_all_orders_with_free_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2341,6 +2638,7 @@ pub fn cancel_all_orders_with_free_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_all_orders_with_free_funds_verify_account_privileges<'me, 'info>(
     accounts: CancelAllOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2350,6 +2648,7 @@ pub fn cancel_all_orders_with_free_funds_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_UP_TO_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelUpToAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -2389,6 +2688,7 @@ pub struct CancelUpToKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CancelUpToAccounts<'_, '_>> for CancelUpToKeys {
     fn from(accounts: CancelUpToAccounts) -> Self {
         Self {
@@ -2470,9 +2770,9 @@ impl From<[Pubkey; CANCEL_UP_TO_IX_ACCOUNTS_LEN]> for CancelUpToKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelUpToAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelUpToAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -2487,9 +2787,9 @@ impl<'info> From<CancelUpToAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN]>
-    for CancelUpToAccounts<'me, 'info>
-{
+for CancelUpToAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CANCEL_UP_TO_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -2518,37 +2818,50 @@ impl From<CancelUpToIxArgs> for CancelUpToIxData {
     }
 }
 impl CancelUpToIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_UP_TO_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_UP_TO_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_UP_TO_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(CancelUpToIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_UP_TO_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelUpToIxData {
+    const NAME: &'static str = "CancelUpTo";
+    const DISCM: &'static [u8] = &[CANCEL_UP_TO_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CANCEL_UP_TO_IX_ACCOUNTS_LEN;
+    type Keys = CancelUpToKeys;
+    type Args = CancelUpToIxArgs;
+}
 pub fn cancel_up_to_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelUpToKeys,
     args: CancelUpToIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CANCEL_UP_TO_IX_ACCOUNTS_LEN] = keys.into();
     let data: CancelUpToIxData = args.into();
     Ok(Instruction {
@@ -2560,9 +2873,10 @@ pub fn cancel_up_to_ix_with_program_id(
 pub fn cancel_up_to_ix(
     keys: CancelUpToKeys,
     args: CancelUpToIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     cancel_up_to_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToAccounts<'_, '_>,
@@ -2572,12 +2886,14 @@ pub fn cancel_up_to_invoke_with_program_id(
     let ix = cancel_up_to_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_invoke(
     accounts: CancelUpToAccounts<'_, '_>,
     args: CancelUpToIxArgs,
 ) -> ProgramResult {
     cancel_up_to_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToAccounts<'_, '_>,
@@ -2588,6 +2904,7 @@ pub fn cancel_up_to_invoke_signed_with_program_id(
     let ix = cancel_up_to_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_invoke_signed(
     accounts: CancelUpToAccounts<'_, '_>,
     args: CancelUpToIxArgs,
@@ -2595,6 +2912,7 @@ pub fn cancel_up_to_invoke_signed(
 ) -> ProgramResult {
     cancel_up_to_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_verify_account_keys(
     accounts: CancelUpToAccounts<'_, '_>,
     keys: CancelUpToKeys,
@@ -2616,6 +2934,7 @@ pub fn cancel_up_to_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_verify_writable_privileges<'me, 'info>(
     accounts: CancelUpToAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2632,6 +2951,7 @@ pub fn cancel_up_to_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_verify_signer_privileges<'me, 'info>(
     accounts: CancelUpToAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2642,6 +2962,7 @@ pub fn cancel_up_to_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_verify_account_privileges<'me, 'info>(
     accounts: CancelUpToAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2651,6 +2972,7 @@ pub fn cancel_up_to_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelUpToWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -2670,6 +2992,7 @@ pub struct CancelUpToWithFreeFundsKeys {
     pub market: Pubkey,
     pub trader: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CancelUpToWithFreeFundsAccounts<'_, '_>> for CancelUpToWithFreeFundsKeys {
     fn from(accounts: CancelUpToWithFreeFundsAccounts) -> Self {
         Self {
@@ -2681,8 +3004,7 @@ impl From<CancelUpToWithFreeFundsAccounts<'_, '_>> for CancelUpToWithFreeFundsKe
     }
 }
 impl From<CancelUpToWithFreeFundsKeys>
-    for [AccountMeta; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: CancelUpToWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -2708,7 +3030,8 @@ impl From<CancelUpToWithFreeFundsKeys>
         ]
     }
 }
-impl From<[Pubkey; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]> for CancelUpToWithFreeFundsKeys {
+impl From<[Pubkey; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for CancelUpToWithFreeFundsKeys {
     fn from(pubkeys: [Pubkey; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: pubkeys[0],
@@ -2718,9 +3041,9 @@ impl From<[Pubkey; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]> for CancelUpTo
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelUpToWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelUpToWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -2730,10 +3053,15 @@ impl<'info> From<CancelUpToWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for CancelUpToWithFreeFundsAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
+for CancelUpToWithFreeFundsAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -2756,39 +3084,50 @@ impl From<CancelUpToWithFreeFundsIxArgs> for CancelUpToWithFreeFundsIxData {
     }
 }
 impl CancelUpToWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(CancelUpToWithFreeFundsIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(CancelUpToWithFreeFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelUpToWithFreeFundsIxData {
+    const NAME: &'static str = "CancelUpToWithFreeFunds";
+    const DISCM: &'static [u8] = &[CANCEL_UP_TO_WITH_FREE_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = CancelUpToWithFreeFundsKeys;
+    type Args = CancelUpToWithFreeFundsIxArgs;
+}
 pub fn cancel_up_to_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelUpToWithFreeFundsKeys,
     args: CancelUpToWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CANCEL_UP_TO_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
     let data: CancelUpToWithFreeFundsIxData = args.into();
     Ok(Instruction {
@@ -2800,9 +3139,10 @@ pub fn cancel_up_to_with_free_funds_ix_with_program_id(
 pub fn cancel_up_to_with_free_funds_ix(
     keys: CancelUpToWithFreeFundsKeys,
     args: CancelUpToWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     cancel_up_to_with_free_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
@@ -2812,12 +3152,14 @@ pub fn cancel_up_to_with_free_funds_invoke_with_program_id(
     let ix = cancel_up_to_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_invoke(
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
     args: CancelUpToWithFreeFundsIxArgs,
 ) -> ProgramResult {
     cancel_up_to_with_free_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
@@ -2828,13 +3170,20 @@ pub fn cancel_up_to_with_free_funds_invoke_signed_with_program_id(
     let ix = cancel_up_to_with_free_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_invoke_signed(
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
     args: CancelUpToWithFreeFundsIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    cancel_up_to_with_free_funds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    cancel_up_to_with_free_funds_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_verify_account_keys(
     accounts: CancelUpToWithFreeFundsAccounts<'_, '_>,
     keys: CancelUpToWithFreeFundsKeys,
@@ -2851,6 +3200,7 @@ pub fn cancel_up_to_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_verify_writable_privileges<'me, 'info>(
     accounts: CancelUpToWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2861,6 +3211,7 @@ pub fn cancel_up_to_with_free_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: CancelUpToWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2871,6 +3222,7 @@ pub fn cancel_up_to_with_free_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_up_to_with_free_funds_verify_account_privileges<'me, 'info>(
     accounts: CancelUpToWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2880,6 +3232,7 @@ pub fn cancel_up_to_with_free_funds_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelMultipleOrdersByIdAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -2919,6 +3272,7 @@ pub struct CancelMultipleOrdersByIdKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CancelMultipleOrdersByIdAccounts<'_, '_>> for CancelMultipleOrdersByIdKeys {
     fn from(accounts: CancelMultipleOrdersByIdAccounts) -> Self {
         Self {
@@ -2935,8 +3289,7 @@ impl From<CancelMultipleOrdersByIdAccounts<'_, '_>> for CancelMultipleOrdersById
     }
 }
 impl From<CancelMultipleOrdersByIdKeys>
-    for [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN] {
     fn from(keys: CancelMultipleOrdersByIdKeys) -> Self {
         [
             AccountMeta {
@@ -2987,7 +3340,8 @@ impl From<CancelMultipleOrdersByIdKeys>
         ]
     }
 }
-impl From<[Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]> for CancelMultipleOrdersByIdKeys {
+impl From<[Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]>
+for CancelMultipleOrdersByIdKeys {
     fn from(pubkeys: [Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: pubkeys[0],
@@ -3002,9 +3356,9 @@ impl From<[Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]> for CancelMult
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelMultipleOrdersByIdAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelMultipleOrdersByIdAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -3019,10 +3373,15 @@ impl<'info> From<CancelMultipleOrdersByIdAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]>
-    for CancelMultipleOrdersByIdAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN]>
+for CancelMultipleOrdersByIdAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -3050,39 +3409,50 @@ impl From<CancelMultipleOrdersByIdIxArgs> for CancelMultipleOrdersByIdIxData {
     }
 }
 impl CancelMultipleOrdersByIdIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(CancelMultipleOrdersByIdIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(CancelMultipleOrdersByIdIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelMultipleOrdersByIdIxData {
+    const NAME: &'static str = "CancelMultipleOrdersById";
+    const DISCM: &'static [u8] = &[CANCEL_MULTIPLE_ORDERS_BY_ID_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN;
+    type Keys = CancelMultipleOrdersByIdKeys;
+    type Args = CancelMultipleOrdersByIdIxArgs;
+}
 pub fn cancel_multiple_orders_by_id_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelMultipleOrdersByIdKeys,
     args: CancelMultipleOrdersByIdIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_IX_ACCOUNTS_LEN] = keys.into();
     let data: CancelMultipleOrdersByIdIxData = args.into();
     Ok(Instruction {
@@ -3094,9 +3464,10 @@ pub fn cancel_multiple_orders_by_id_ix_with_program_id(
 pub fn cancel_multiple_orders_by_id_ix(
     keys: CancelMultipleOrdersByIdKeys,
     args: CancelMultipleOrdersByIdIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     cancel_multiple_orders_by_id_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
@@ -3106,12 +3477,14 @@ pub fn cancel_multiple_orders_by_id_invoke_with_program_id(
     let ix = cancel_multiple_orders_by_id_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_invoke(
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
     args: CancelMultipleOrdersByIdIxArgs,
 ) -> ProgramResult {
     cancel_multiple_orders_by_id_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
@@ -3122,13 +3495,20 @@ pub fn cancel_multiple_orders_by_id_invoke_signed_with_program_id(
     let ix = cancel_multiple_orders_by_id_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_invoke_signed(
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
     args: CancelMultipleOrdersByIdIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    cancel_multiple_orders_by_id_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    cancel_multiple_orders_by_id_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_verify_account_keys(
     accounts: CancelMultipleOrdersByIdAccounts<'_, '_>,
     keys: CancelMultipleOrdersByIdKeys,
@@ -3150,6 +3530,7 @@ pub fn cancel_multiple_orders_by_id_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_verify_writable_privileges<'me, 'info>(
     accounts: CancelMultipleOrdersByIdAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3166,6 +3547,7 @@ pub fn cancel_multiple_orders_by_id_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_verify_signer_privileges<'me, 'info>(
     accounts: CancelMultipleOrdersByIdAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3176,6 +3558,7 @@ pub fn cancel_multiple_orders_by_id_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_verify_account_privileges<'me, 'info>(
     accounts: CancelMultipleOrdersByIdAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3185,6 +3568,7 @@ pub fn cancel_multiple_orders_by_id_verify_account_privileges<'me, 'info>(
 }
 pub const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -3204,9 +3588,9 @@ pub struct CancelMultipleOrdersByIdWithFreeFundsKeys {
     pub market: Pubkey,
     pub trader: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>>
-    for CancelMultipleOrdersByIdWithFreeFundsKeys
-{
+for CancelMultipleOrdersByIdWithFreeFundsKeys {
     fn from(accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts) -> Self {
         Self {
             phoenix_program: *accounts.phoenix_program.key,
@@ -3217,8 +3601,7 @@ impl From<CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>>
     }
 }
 impl From<CancelMultipleOrdersByIdWithFreeFundsKeys>
-    for [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: CancelMultipleOrdersByIdWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -3245,8 +3628,7 @@ impl From<CancelMultipleOrdersByIdWithFreeFundsKeys>
     }
 }
 impl From<[Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for CancelMultipleOrdersByIdWithFreeFundsKeys
-{
+for CancelMultipleOrdersByIdWithFreeFundsKeys {
     fn from(
         pubkeys: [Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -3258,9 +3640,9 @@ impl From<[Pubkey; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -3270,13 +3652,19 @@ impl<'info> From<CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<
+        'info,
+    >; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+> for CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>;
-                 CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -3297,48 +3685,61 @@ pub struct CancelMultipleOrdersByIdWithFreeFundsIxData(
     pub CancelMultipleOrdersByIdWithFreeFundsIxArgs,
 );
 impl From<CancelMultipleOrdersByIdWithFreeFundsIxArgs>
-    for CancelMultipleOrdersByIdWithFreeFundsIxData
-{
+for CancelMultipleOrdersByIdWithFreeFundsIxData {
     fn from(args: CancelMultipleOrdersByIdWithFreeFundsIxArgs) -> Self {
         Self(args)
     }
 }
 impl CancelMultipleOrdersByIdWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM,
+                        maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(
-            CancelMultipleOrdersByIdWithFreeFundsIxArgs::deserialize(&mut reader)?,
-        ))
+        Ok(Self(CancelMultipleOrdersByIdWithFreeFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CancelMultipleOrdersByIdWithFreeFundsIxData {
+    const NAME: &'static str = "CancelMultipleOrdersByIdWithFreeFunds";
+    const DISCM: &'static [u8] = &[
+        CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = CancelMultipleOrdersByIdWithFreeFundsKeys;
+    type Args = CancelMultipleOrdersByIdWithFreeFundsIxArgs;
+}
 pub fn cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: CancelMultipleOrdersByIdWithFreeFundsKeys,
     args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: CancelMultipleOrdersByIdWithFreeFundsIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -3349,25 +3750,39 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(
 pub fn cancel_multiple_orders_by_id_with_free_funds_ix(
     keys: CancelMultipleOrdersByIdWithFreeFundsKeys,
     args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
-    cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(crate::ID, keys, args)
+) -> borsh::io::Result<Instruction> {
+    cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
     args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
 ) -> ProgramResult {
     let keys: CancelMultipleOrdersByIdWithFreeFundsKeys = accounts.into();
-    let ix =
-        cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_invoke(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
     args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
 ) -> ProgramResult {
-    cancel_multiple_orders_by_id_with_free_funds_invoke_with_program_id(crate::ID, accounts, args)
+    cancel_multiple_orders_by_id_with_free_funds_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
@@ -3375,10 +3790,14 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_signed_with_program_i
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: CancelMultipleOrdersByIdWithFreeFundsKeys = accounts.into();
-    let ix =
-        cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = cancel_multiple_orders_by_id_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_signed(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
     args: CancelMultipleOrdersByIdWithFreeFundsIxArgs,
@@ -3391,6 +3810,7 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_keys(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'_, '_>,
     keys: CancelMultipleOrdersByIdWithFreeFundsKeys,
@@ -3407,7 +3827,11 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
-pub fn cancel_multiple_orders_by_id_with_free_funds_verify_writable_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn cancel_multiple_orders_by_id_with_free_funds_verify_writable_privileges<
+    'me,
+    'info,
+>(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [accounts.market] {
@@ -3417,6 +3841,7 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_verify_writable_privileges<'
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn cancel_multiple_orders_by_id_with_free_funds_verify_signer_privileges<'me, 'info>(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3427,7 +3852,11 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_verify_signer_privileges<'me
     }
     Ok(())
 }
-pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_privileges<
+    'me,
+    'info,
+>(
     accounts: CancelMultipleOrdersByIdWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     cancel_multiple_orders_by_id_with_free_funds_verify_writable_privileges(accounts)?;
@@ -3436,6 +3865,7 @@ pub fn cancel_multiple_orders_by_id_with_free_funds_verify_account_privileges<'m
 }
 pub const WITHDRAW_FUNDS_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct WithdrawFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -3475,6 +3905,7 @@ pub struct WithdrawFundsKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<WithdrawFundsAccounts<'_, '_>> for WithdrawFundsKeys {
     fn from(accounts: WithdrawFundsAccounts) -> Self {
         Self {
@@ -3556,9 +3987,9 @@ impl From<[Pubkey; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN]> for WithdrawFundsKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<WithdrawFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: WithdrawFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -3573,9 +4004,9 @@ impl<'info> From<WithdrawFundsAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN]>
-    for WithdrawFundsAccounts<'me, 'info>
-{
+for WithdrawFundsAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -3604,37 +4035,50 @@ impl From<WithdrawFundsIxArgs> for WithdrawFundsIxData {
     }
 }
 impl WithdrawFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != WITHDRAW_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    WITHDRAW_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        WITHDRAW_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(WithdrawFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[WITHDRAW_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for WithdrawFundsIxData {
+    const NAME: &'static str = "WithdrawFunds";
+    const DISCM: &'static [u8] = &[WITHDRAW_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = WITHDRAW_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = WithdrawFundsKeys;
+    type Args = WithdrawFundsIxArgs;
+}
 pub fn withdraw_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: WithdrawFundsKeys,
     args: WithdrawFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; WITHDRAW_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
     let data: WithdrawFundsIxData = args.into();
     Ok(Instruction {
@@ -3646,9 +4090,10 @@ pub fn withdraw_funds_ix_with_program_id(
 pub fn withdraw_funds_ix(
     keys: WithdrawFundsKeys,
     args: WithdrawFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     withdraw_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawFundsAccounts<'_, '_>,
@@ -3658,12 +4103,14 @@ pub fn withdraw_funds_invoke_with_program_id(
     let ix = withdraw_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_invoke(
     accounts: WithdrawFundsAccounts<'_, '_>,
     args: WithdrawFundsIxArgs,
 ) -> ProgramResult {
     withdraw_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: WithdrawFundsAccounts<'_, '_>,
@@ -3674,6 +4121,7 @@ pub fn withdraw_funds_invoke_signed_with_program_id(
     let ix = withdraw_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_invoke_signed(
     accounts: WithdrawFundsAccounts<'_, '_>,
     args: WithdrawFundsIxArgs,
@@ -3681,6 +4129,7 @@ pub fn withdraw_funds_invoke_signed(
 ) -> ProgramResult {
     withdraw_funds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_verify_account_keys(
     accounts: WithdrawFundsAccounts<'_, '_>,
     keys: WithdrawFundsKeys,
@@ -3702,6 +4151,7 @@ pub fn withdraw_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_verify_writable_privileges<'me, 'info>(
     accounts: WithdrawFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3718,6 +4168,7 @@ pub fn withdraw_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_verify_signer_privileges<'me, 'info>(
     accounts: WithdrawFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3728,6 +4179,7 @@ pub fn withdraw_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn withdraw_funds_verify_account_privileges<'me, 'info>(
     accounts: WithdrawFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3737,6 +4189,7 @@ pub fn withdraw_funds_verify_account_privileges<'me, 'info>(
 }
 pub const DEPOSIT_FUNDS_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DepositFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -3778,6 +4231,7 @@ pub struct DepositFundsKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DepositFundsAccounts<'_, '_>> for DepositFundsKeys {
     fn from(accounts: DepositFundsAccounts) -> Self {
         Self {
@@ -3866,9 +4320,9 @@ impl From<[Pubkey; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN]> for DepositFundsKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DepositFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(accounts: DepositFundsAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -3884,9 +4338,9 @@ impl<'info> From<DepositFundsAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN]>
-    for DepositFundsAccounts<'me, 'info>
-{
+for DepositFundsAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -3916,37 +4370,50 @@ impl From<DepositFundsIxArgs> for DepositFundsIxData {
     }
 }
 impl DepositFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPOSIT_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPOSIT_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPOSIT_FUNDS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(DepositFundsIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPOSIT_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DepositFundsIxData {
+    const NAME: &'static str = "DepositFunds";
+    const DISCM: &'static [u8] = &[DEPOSIT_FUNDS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPOSIT_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = DepositFundsKeys;
+    type Args = DepositFundsIxArgs;
+}
 pub fn deposit_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: DepositFundsKeys,
     args: DepositFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; DEPOSIT_FUNDS_IX_ACCOUNTS_LEN] = keys.into();
     let data: DepositFundsIxData = args.into();
     Ok(Instruction {
@@ -3958,9 +4425,10 @@ pub fn deposit_funds_ix_with_program_id(
 pub fn deposit_funds_ix(
     keys: DepositFundsKeys,
     args: DepositFundsIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deposit_funds_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DepositFundsAccounts<'_, '_>,
@@ -3970,12 +4438,14 @@ pub fn deposit_funds_invoke_with_program_id(
     let ix = deposit_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_invoke(
     accounts: DepositFundsAccounts<'_, '_>,
     args: DepositFundsIxArgs,
 ) -> ProgramResult {
     deposit_funds_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DepositFundsAccounts<'_, '_>,
@@ -3986,6 +4456,7 @@ pub fn deposit_funds_invoke_signed_with_program_id(
     let ix = deposit_funds_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_invoke_signed(
     accounts: DepositFundsAccounts<'_, '_>,
     args: DepositFundsIxArgs,
@@ -3993,6 +4464,7 @@ pub fn deposit_funds_invoke_signed(
 ) -> ProgramResult {
     deposit_funds_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_verify_account_keys(
     accounts: DepositFundsAccounts<'_, '_>,
     keys: DepositFundsKeys,
@@ -4015,6 +4487,7 @@ pub fn deposit_funds_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_verify_writable_privileges<'me, 'info>(
     accounts: DepositFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4031,6 +4504,7 @@ pub fn deposit_funds_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_verify_signer_privileges<'me, 'info>(
     accounts: DepositFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4041,6 +4515,7 @@ pub fn deposit_funds_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deposit_funds_verify_account_privileges<'me, 'info>(
     accounts: DepositFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4050,6 +4525,7 @@ pub fn deposit_funds_verify_account_privileges<'me, 'info>(
 }
 pub const REQUEST_SEAT_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RequestSeatAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -4075,6 +4551,7 @@ pub struct RequestSeatKeys {
     ///System program
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RequestSeatAccounts<'_, '_>> for RequestSeatKeys {
     fn from(accounts: RequestSeatAccounts) -> Self {
         Self {
@@ -4135,9 +4612,9 @@ impl From<[Pubkey; REQUEST_SEAT_IX_ACCOUNTS_LEN]> for RequestSeatKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RequestSeatAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN] {
     fn from(accounts: RequestSeatAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -4149,9 +4626,9 @@ impl<'info> From<RequestSeatAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN]>
-    for RequestSeatAccounts<'me, 'info>
-{
+for RequestSeatAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; REQUEST_SEAT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -4167,35 +4644,48 @@ pub const REQUEST_SEAT_IX_DISCM: u8 = 14u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RequestSeatIxData;
 impl RequestSeatIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REQUEST_SEAT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REQUEST_SEAT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REQUEST_SEAT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REQUEST_SEAT_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RequestSeatIxData {
+    const NAME: &'static str = "RequestSeat";
+    const DISCM: &'static [u8] = &[REQUEST_SEAT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REQUEST_SEAT_IX_ACCOUNTS_LEN;
+    type Keys = RequestSeatKeys;
+    type Args = ();
+}
 pub fn request_seat_ix_with_program_id(
     program_id: Pubkey,
     keys: RequestSeatKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REQUEST_SEAT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -4203,9 +4693,10 @@ pub fn request_seat_ix_with_program_id(
         data: RequestSeatIxData.try_to_vec()?,
     })
 }
-pub fn request_seat_ix(keys: RequestSeatKeys) -> std::io::Result<Instruction> {
+pub fn request_seat_ix(keys: RequestSeatKeys) -> borsh::io::Result<Instruction> {
     request_seat_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAccounts<'_, '_>,
@@ -4214,9 +4705,11 @@ pub fn request_seat_invoke_with_program_id(
     let ix = request_seat_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_invoke(accounts: RequestSeatAccounts<'_, '_>) -> ProgramResult {
     request_seat_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAccounts<'_, '_>,
@@ -4226,12 +4719,14 @@ pub fn request_seat_invoke_signed_with_program_id(
     let ix = request_seat_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_invoke_signed(
     accounts: RequestSeatAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     request_seat_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_verify_account_keys(
     accounts: RequestSeatAccounts<'_, '_>,
     keys: RequestSeatKeys,
@@ -4250,6 +4745,7 @@ pub fn request_seat_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_verify_writable_privileges<'me, 'info>(
     accounts: RequestSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4260,6 +4756,7 @@ pub fn request_seat_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_verify_signer_privileges<'me, 'info>(
     accounts: RequestSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4270,6 +4767,7 @@ pub fn request_seat_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_verify_account_privileges<'me, 'info>(
     accounts: RequestSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4279,6 +4777,7 @@ pub fn request_seat_verify_account_privileges<'me, 'info>(
 }
 pub const LOG_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct LogAccounts<'me, 'info> {
     ///Log authority
     pub log_authority: &'me AccountInfo<'info>,
@@ -4288,6 +4787,7 @@ pub struct LogKeys {
     ///Log authority
     pub log_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<LogAccounts<'_, '_>> for LogKeys {
     fn from(accounts: LogAccounts) -> Self {
         Self {
@@ -4297,62 +4797,79 @@ impl From<LogAccounts<'_, '_>> for LogKeys {
 }
 impl From<LogKeys> for [AccountMeta; LOG_IX_ACCOUNTS_LEN] {
     fn from(keys: LogKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.log_authority,
-            is_signer: true,
-            is_writable: false,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.log_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+        ]
     }
 }
 impl From<[Pubkey; LOG_IX_ACCOUNTS_LEN]> for LogKeys {
     fn from(pubkeys: [Pubkey; LOG_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            log_authority: pubkeys[0],
-        }
+        Self { log_authority: pubkeys[0] }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<LogAccounts<'_, 'info>> for [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN] {
     fn from(accounts: LogAccounts<'_, 'info>) -> Self {
         [accounts.log_authority.clone()]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN]> for LogAccounts<'me, 'info> {
+#[cfg(feature = "cpi")]
+impl<'me, 'info> From<&'me [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN]>
+for LogAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; LOG_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            log_authority: &arr[0],
-        }
+        Self { log_authority: &arr[0] }
     }
 }
 pub const LOG_IX_DISCM: u8 = 15u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct LogIxData;
 impl LogIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != LOG_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    LOG_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        LOG_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[LOG_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
-pub fn log_ix_with_program_id(program_id: Pubkey, keys: LogKeys) -> std::io::Result<Instruction> {
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for LogIxData {
+    const NAME: &'static str = "Log";
+    const DISCM: &'static [u8] = &[LOG_IX_DISCM];
+    const ACCOUNTS_LEN: usize = LOG_IX_ACCOUNTS_LEN;
+    type Keys = LogKeys;
+    type Args = ();
+}
+pub fn log_ix_with_program_id(
+    program_id: Pubkey,
+    keys: LogKeys,
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; LOG_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -4360,9 +4877,10 @@ pub fn log_ix_with_program_id(program_id: Pubkey, keys: LogKeys) -> std::io::Res
         data: LogIxData.try_to_vec()?,
     })
 }
-pub fn log_ix(keys: LogKeys) -> std::io::Result<Instruction> {
+pub fn log_ix(keys: LogKeys) -> borsh::io::Result<Instruction> {
     log_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn log_invoke_with_program_id(
     program_id: Pubkey,
     accounts: LogAccounts<'_, '_>,
@@ -4371,9 +4889,11 @@ pub fn log_invoke_with_program_id(
     let ix = log_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn log_invoke(accounts: LogAccounts<'_, '_>) -> ProgramResult {
     log_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn log_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: LogAccounts<'_, '_>,
@@ -4383,9 +4903,14 @@ pub fn log_invoke_signed_with_program_id(
     let ix = log_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
-pub fn log_invoke_signed(accounts: LogAccounts<'_, '_>, seeds: &[&[&[u8]]]) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn log_invoke_signed(
+    accounts: LogAccounts<'_, '_>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
     log_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn log_verify_account_keys(
     accounts: LogAccounts<'_, '_>,
     keys: LogKeys,
@@ -4397,6 +4922,7 @@ pub fn log_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn log_verify_signer_privileges<'me, 'info>(
     accounts: LogAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4407,6 +4933,7 @@ pub fn log_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn log_verify_account_privileges<'me, 'info>(
     accounts: LogAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4415,6 +4942,7 @@ pub fn log_verify_account_privileges<'me, 'info>(
 }
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct PlaceMultiplePostOnlyOrdersAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -4456,7 +4984,9 @@ pub struct PlaceMultiplePostOnlyOrdersKeys {
     ///Token program
     pub token_program: Pubkey,
 }
-impl From<PlaceMultiplePostOnlyOrdersAccounts<'_, '_>> for PlaceMultiplePostOnlyOrdersKeys {
+#[cfg(feature = "cpi")]
+impl From<PlaceMultiplePostOnlyOrdersAccounts<'_, '_>>
+for PlaceMultiplePostOnlyOrdersKeys {
     fn from(accounts: PlaceMultiplePostOnlyOrdersAccounts) -> Self {
         Self {
             phoenix_program: *accounts.phoenix_program.key,
@@ -4473,8 +5003,7 @@ impl From<PlaceMultiplePostOnlyOrdersAccounts<'_, '_>> for PlaceMultiplePostOnly
     }
 }
 impl From<PlaceMultiplePostOnlyOrdersKeys>
-    for [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] {
     fn from(keys: PlaceMultiplePostOnlyOrdersKeys) -> Self {
         [
             AccountMeta {
@@ -4531,8 +5060,7 @@ impl From<PlaceMultiplePostOnlyOrdersKeys>
     }
 }
 impl From<[Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]>
-    for PlaceMultiplePostOnlyOrdersKeys
-{
+for PlaceMultiplePostOnlyOrdersKeys {
     fn from(pubkeys: [Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: pubkeys[0],
@@ -4548,9 +5076,9 @@ impl From<[Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<PlaceMultiplePostOnlyOrdersAccounts<'_, 'info>>
-    for [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] {
     fn from(accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -4566,9 +5094,12 @@ impl<'info> From<PlaceMultiplePostOnlyOrdersAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]>
-    for PlaceMultiplePostOnlyOrdersAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN]>
+for PlaceMultiplePostOnlyOrdersAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -4600,40 +5131,52 @@ impl From<PlaceMultiplePostOnlyOrdersIxArgs> for PlaceMultiplePostOnlyOrdersIxDa
     }
 }
 impl PlaceMultiplePostOnlyOrdersIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(PlaceMultiplePostOnlyOrdersIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(PlaceMultiplePostOnlyOrdersIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PlaceMultiplePostOnlyOrdersIxData {
+    const NAME: &'static str = "PlaceMultiplePostOnlyOrders";
+    const DISCM: &'static [u8] = &[PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN;
+    type Keys = PlaceMultiplePostOnlyOrdersKeys;
+    type Args = PlaceMultiplePostOnlyOrdersIxArgs;
+}
 pub fn place_multiple_post_only_orders_ix_with_program_id(
     program_id: Pubkey,
     keys: PlaceMultiplePostOnlyOrdersKeys,
     args: PlaceMultiplePostOnlyOrdersIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: PlaceMultiplePostOnlyOrdersIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -4644,9 +5187,10 @@ pub fn place_multiple_post_only_orders_ix_with_program_id(
 pub fn place_multiple_post_only_orders_ix(
     keys: PlaceMultiplePostOnlyOrdersKeys,
     args: PlaceMultiplePostOnlyOrdersIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     place_multiple_post_only_orders_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
@@ -4656,12 +5200,14 @@ pub fn place_multiple_post_only_orders_invoke_with_program_id(
     let ix = place_multiple_post_only_orders_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_invoke(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
     args: PlaceMultiplePostOnlyOrdersIxArgs,
 ) -> ProgramResult {
     place_multiple_post_only_orders_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
@@ -4672,13 +5218,20 @@ pub fn place_multiple_post_only_orders_invoke_signed_with_program_id(
     let ix = place_multiple_post_only_orders_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_invoke_signed(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
     args: PlaceMultiplePostOnlyOrdersIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    place_multiple_post_only_orders_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    place_multiple_post_only_orders_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_verify_account_keys(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'_, '_>,
     keys: PlaceMultiplePostOnlyOrdersKeys,
@@ -4701,6 +5254,7 @@ pub fn place_multiple_post_only_orders_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_verify_writable_privileges<'me, 'info>(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4717,6 +5271,7 @@ pub fn place_multiple_post_only_orders_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_verify_signer_privileges<'me, 'info>(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4727,6 +5282,7 @@ pub fn place_multiple_post_only_orders_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_verify_account_privileges<'me, 'info>(
     accounts: PlaceMultiplePostOnlyOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4736,6 +5292,7 @@ pub fn place_multiple_post_only_orders_verify_account_privileges<'me, 'info>(
 }
 pub const PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -4757,9 +5314,9 @@ pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
     pub trader: Pubkey,
     pub seat: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>>
-    for PlaceMultiplePostOnlyOrdersWithFreeFundsKeys
-{
+for PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
     fn from(accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts) -> Self {
         Self {
             phoenix_program: *accounts.phoenix_program.key,
@@ -4771,8 +5328,7 @@ impl From<PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>>
     }
 }
 impl From<PlaceMultiplePostOnlyOrdersWithFreeFundsKeys>
-    for [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
     fn from(keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys) -> Self {
         [
             AccountMeta {
@@ -4804,8 +5360,7 @@ impl From<PlaceMultiplePostOnlyOrdersWithFreeFundsKeys>
     }
 }
 impl From<[Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for PlaceMultiplePostOnlyOrdersWithFreeFundsKeys
-{
+for PlaceMultiplePostOnlyOrdersWithFreeFundsKeys {
     fn from(
         pubkeys: [Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -4818,10 +5373,14 @@ impl From<[Pubkey; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_L
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, 'info>>
-    for [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]
-{
-    fn from(accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, 'info>) -> Self {
+for [AccountInfo<
+    'info,
+>; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, 'info>,
+    ) -> Self {
         [
             accounts.phoenix_program.clone(),
             accounts.log_authority.clone(),
@@ -4831,13 +5390,19 @@ impl<'info> From<PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN]>
-    for PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<
+        'info,
+    >; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+> for PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>;
-                 PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -4859,48 +5424,65 @@ pub struct PlaceMultiplePostOnlyOrdersWithFreeFundsIxData(
     pub PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
 );
 impl From<PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs>
-    for PlaceMultiplePostOnlyOrdersWithFreeFundsIxData
-{
+for PlaceMultiplePostOnlyOrdersWithFreeFundsIxData {
     fn from(args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs) -> Self {
         Self(args)
     }
 }
 impl PlaceMultiplePostOnlyOrdersWithFreeFundsIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM,
+                        maybe_discm
+                    ),
                 ),
-            ));
-        }
-        Ok(Self(
-            PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs::deserialize(&mut reader)?,
-        ))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+            );
+        }
+        Ok(
+            Self(
+                PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs::deserialize(&mut reader)?,
+            ),
+        )
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PlaceMultiplePostOnlyOrdersWithFreeFundsIxData {
+    const NAME: &'static str = "PlaceMultiplePostOnlyOrdersWithFreeFunds";
+    const DISCM: &'static [u8] = &[
+        PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN;
+    type Keys = PlaceMultiplePostOnlyOrdersWithFreeFundsKeys;
+    type Args = PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs;
+}
 pub fn place_multiple_post_only_orders_with_free_funds_ix_with_program_id(
     program_id: Pubkey,
     keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys,
     args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: PlaceMultiplePostOnlyOrdersWithFreeFundsIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -4911,19 +5493,28 @@ pub fn place_multiple_post_only_orders_with_free_funds_ix_with_program_id(
 pub fn place_multiple_post_only_orders_with_free_funds_ix(
     keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys,
     args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
-) -> std::io::Result<Instruction> {
-    place_multiple_post_only_orders_with_free_funds_ix_with_program_id(crate::ID, keys, args)
+) -> borsh::io::Result<Instruction> {
+    place_multiple_post_only_orders_with_free_funds_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_with_free_funds_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
     args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
 ) -> ProgramResult {
     let keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys = accounts.into();
-    let ix =
-        place_multiple_post_only_orders_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = place_multiple_post_only_orders_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_with_free_funds_invoke(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
     args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
@@ -4934,6 +5525,7 @@ pub fn place_multiple_post_only_orders_with_free_funds_invoke(
         args,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_with_free_funds_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
@@ -4941,10 +5533,14 @@ pub fn place_multiple_post_only_orders_with_free_funds_invoke_signed_with_progra
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys = accounts.into();
-    let ix =
-        place_multiple_post_only_orders_with_free_funds_ix_with_program_id(program_id, keys, args)?;
+    let ix = place_multiple_post_only_orders_with_free_funds_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_with_free_funds_invoke_signed(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
     args: PlaceMultiplePostOnlyOrdersWithFreeFundsIxArgs,
@@ -4957,6 +5553,7 @@ pub fn place_multiple_post_only_orders_with_free_funds_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn place_multiple_post_only_orders_with_free_funds_verify_account_keys(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'_, '_>,
     keys: PlaceMultiplePostOnlyOrdersWithFreeFundsKeys,
@@ -4974,7 +5571,11 @@ pub fn place_multiple_post_only_orders_with_free_funds_verify_account_keys(
     }
     Ok(())
 }
-pub fn place_multiple_post_only_orders_with_free_funds_verify_writable_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn place_multiple_post_only_orders_with_free_funds_verify_writable_privileges<
+    'me,
+    'info,
+>(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [accounts.market] {
@@ -4984,7 +5585,11 @@ pub fn place_multiple_post_only_orders_with_free_funds_verify_writable_privilege
     }
     Ok(())
 }
-pub fn place_multiple_post_only_orders_with_free_funds_verify_signer_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn place_multiple_post_only_orders_with_free_funds_verify_signer_privileges<
+    'me,
+    'info,
+>(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [accounts.trader] {
@@ -4994,15 +5599,22 @@ pub fn place_multiple_post_only_orders_with_free_funds_verify_signer_privileges<
     }
     Ok(())
 }
-pub fn place_multiple_post_only_orders_with_free_funds_verify_account_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn place_multiple_post_only_orders_with_free_funds_verify_account_privileges<
+    'me,
+    'info,
+>(
     accounts: PlaceMultiplePostOnlyOrdersWithFreeFundsAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    place_multiple_post_only_orders_with_free_funds_verify_writable_privileges(accounts)?;
+    place_multiple_post_only_orders_with_free_funds_verify_writable_privileges(
+        accounts,
+    )?;
     place_multiple_post_only_orders_with_free_funds_verify_signer_privileges(accounts)?;
     Ok(())
 }
 pub const INITIALIZE_MARKET_IX_ACCOUNTS_LEN: usize = 10;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct InitializeMarketAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -5048,6 +5660,7 @@ pub struct InitializeMarketKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<InitializeMarketAccounts<'_, '_>> for InitializeMarketKeys {
     fn from(accounts: InitializeMarketAccounts) -> Self {
         Self {
@@ -5136,9 +5749,9 @@ impl From<[Pubkey; INITIALIZE_MARKET_IX_ACCOUNTS_LEN]> for InitializeMarketKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<InitializeMarketAccounts<'_, 'info>>
-    for [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LEN] {
     fn from(accounts: InitializeMarketAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -5154,9 +5767,9 @@ impl<'info> From<InitializeMarketAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LEN]>
-    for InitializeMarketAccounts<'me, 'info>
-{
+for InitializeMarketAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; INITIALIZE_MARKET_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -5186,37 +5799,50 @@ impl From<InitializeMarketIxArgs> for InitializeMarketIxData {
     }
 }
 impl InitializeMarketIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != INITIALIZE_MARKET_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    INITIALIZE_MARKET_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        INITIALIZE_MARKET_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(InitializeMarketIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[INITIALIZE_MARKET_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for InitializeMarketIxData {
+    const NAME: &'static str = "InitializeMarket";
+    const DISCM: &'static [u8] = &[INITIALIZE_MARKET_IX_DISCM];
+    const ACCOUNTS_LEN: usize = INITIALIZE_MARKET_IX_ACCOUNTS_LEN;
+    type Keys = InitializeMarketKeys;
+    type Args = InitializeMarketIxArgs;
+}
 pub fn initialize_market_ix_with_program_id(
     program_id: Pubkey,
     keys: InitializeMarketKeys,
     args: InitializeMarketIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; INITIALIZE_MARKET_IX_ACCOUNTS_LEN] = keys.into();
     let data: InitializeMarketIxData = args.into();
     Ok(Instruction {
@@ -5228,9 +5854,10 @@ pub fn initialize_market_ix_with_program_id(
 pub fn initialize_market_ix(
     keys: InitializeMarketKeys,
     args: InitializeMarketIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     initialize_market_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_invoke_with_program_id(
     program_id: Pubkey,
     accounts: InitializeMarketAccounts<'_, '_>,
@@ -5240,12 +5867,14 @@ pub fn initialize_market_invoke_with_program_id(
     let ix = initialize_market_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_invoke(
     accounts: InitializeMarketAccounts<'_, '_>,
     args: InitializeMarketIxArgs,
 ) -> ProgramResult {
     initialize_market_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: InitializeMarketAccounts<'_, '_>,
@@ -5256,6 +5885,7 @@ pub fn initialize_market_invoke_signed_with_program_id(
     let ix = initialize_market_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_invoke_signed(
     accounts: InitializeMarketAccounts<'_, '_>,
     args: InitializeMarketIxArgs,
@@ -5263,6 +5893,7 @@ pub fn initialize_market_invoke_signed(
 ) -> ProgramResult {
     initialize_market_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_verify_account_keys(
     accounts: InitializeMarketAccounts<'_, '_>,
     keys: InitializeMarketKeys,
@@ -5285,6 +5916,7 @@ pub fn initialize_market_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_verify_writable_privileges<'me, 'info>(
     accounts: InitializeMarketAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5300,6 +5932,7 @@ pub fn initialize_market_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_verify_signer_privileges<'me, 'info>(
     accounts: InitializeMarketAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5310,6 +5943,7 @@ pub fn initialize_market_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn initialize_market_verify_account_privileges<'me, 'info>(
     accounts: InitializeMarketAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5319,6 +5953,7 @@ pub fn initialize_market_verify_account_privileges<'me, 'info>(
 }
 pub const CLAIM_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ClaimAuthorityAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -5340,6 +5975,7 @@ pub struct ClaimAuthorityKeys {
     ///The successor account must sign to claim authority
     pub successor: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ClaimAuthorityAccounts<'_, '_>> for ClaimAuthorityKeys {
     fn from(accounts: ClaimAuthorityAccounts) -> Self {
         Self {
@@ -5386,9 +6022,9 @@ impl From<[Pubkey; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN]> for ClaimAuthorityKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ClaimAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: ClaimAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -5398,9 +6034,9 @@ impl<'info> From<ClaimAuthorityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for ClaimAuthorityAccounts<'me, 'info>
-{
+for ClaimAuthorityAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -5414,35 +6050,48 @@ pub const CLAIM_AUTHORITY_IX_DISCM: u8 = 101u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClaimAuthorityIxData;
 impl ClaimAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CLAIM_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CLAIM_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CLAIM_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CLAIM_AUTHORITY_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ClaimAuthorityIxData {
+    const NAME: &'static str = "ClaimAuthority";
+    const DISCM: &'static [u8] = &[CLAIM_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CLAIM_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ClaimAuthorityKeys;
+    type Args = ();
+}
 pub fn claim_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: ClaimAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CLAIM_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -5450,9 +6099,10 @@ pub fn claim_authority_ix_with_program_id(
         data: ClaimAuthorityIxData.try_to_vec()?,
     })
 }
-pub fn claim_authority_ix(keys: ClaimAuthorityKeys) -> std::io::Result<Instruction> {
+pub fn claim_authority_ix(keys: ClaimAuthorityKeys) -> borsh::io::Result<Instruction> {
     claim_authority_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAuthorityAccounts<'_, '_>,
@@ -5461,9 +6111,13 @@ pub fn claim_authority_invoke_with_program_id(
     let ix = claim_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn claim_authority_invoke(accounts: ClaimAuthorityAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn claim_authority_invoke(
+    accounts: ClaimAuthorityAccounts<'_, '_>,
+) -> ProgramResult {
     claim_authority_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ClaimAuthorityAccounts<'_, '_>,
@@ -5473,12 +6127,14 @@ pub fn claim_authority_invoke_signed_with_program_id(
     let ix = claim_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_invoke_signed(
     accounts: ClaimAuthorityAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     claim_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_verify_account_keys(
     accounts: ClaimAuthorityAccounts<'_, '_>,
     keys: ClaimAuthorityKeys,
@@ -5495,6 +6151,7 @@ pub fn claim_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_verify_writable_privileges<'me, 'info>(
     accounts: ClaimAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5505,6 +6162,7 @@ pub fn claim_authority_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_verify_signer_privileges<'me, 'info>(
     accounts: ClaimAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5515,6 +6173,7 @@ pub fn claim_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn claim_authority_verify_account_privileges<'me, 'info>(
     accounts: ClaimAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5524,6 +6183,7 @@ pub fn claim_authority_verify_account_privileges<'me, 'info>(
 }
 pub const NAME_SUCCESSOR_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct NameSuccessorAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -5545,6 +6205,7 @@ pub struct NameSuccessorKeys {
     ///The market_authority account must sign to name successor
     pub market_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<NameSuccessorAccounts<'_, '_>> for NameSuccessorKeys {
     fn from(accounts: NameSuccessorAccounts) -> Self {
         Self {
@@ -5591,9 +6252,9 @@ impl From<[Pubkey; NAME_SUCCESSOR_IX_ACCOUNTS_LEN]> for NameSuccessorKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<NameSuccessorAccounts<'_, 'info>>
-    for [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN] {
     fn from(accounts: NameSuccessorAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -5603,9 +6264,9 @@ impl<'info> From<NameSuccessorAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN]>
-    for NameSuccessorAccounts<'me, 'info>
-{
+for NameSuccessorAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; NAME_SUCCESSOR_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -5629,37 +6290,50 @@ impl From<NameSuccessorIxArgs> for NameSuccessorIxData {
     }
 }
 impl NameSuccessorIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != NAME_SUCCESSOR_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    NAME_SUCCESSOR_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        NAME_SUCCESSOR_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(NameSuccessorIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[NAME_SUCCESSOR_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for NameSuccessorIxData {
+    const NAME: &'static str = "NameSuccessor";
+    const DISCM: &'static [u8] = &[NAME_SUCCESSOR_IX_DISCM];
+    const ACCOUNTS_LEN: usize = NAME_SUCCESSOR_IX_ACCOUNTS_LEN;
+    type Keys = NameSuccessorKeys;
+    type Args = NameSuccessorIxArgs;
+}
 pub fn name_successor_ix_with_program_id(
     program_id: Pubkey,
     keys: NameSuccessorKeys,
     args: NameSuccessorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; NAME_SUCCESSOR_IX_ACCOUNTS_LEN] = keys.into();
     let data: NameSuccessorIxData = args.into();
     Ok(Instruction {
@@ -5671,9 +6345,10 @@ pub fn name_successor_ix_with_program_id(
 pub fn name_successor_ix(
     keys: NameSuccessorKeys,
     args: NameSuccessorIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     name_successor_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_invoke_with_program_id(
     program_id: Pubkey,
     accounts: NameSuccessorAccounts<'_, '_>,
@@ -5683,12 +6358,14 @@ pub fn name_successor_invoke_with_program_id(
     let ix = name_successor_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_invoke(
     accounts: NameSuccessorAccounts<'_, '_>,
     args: NameSuccessorIxArgs,
 ) -> ProgramResult {
     name_successor_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: NameSuccessorAccounts<'_, '_>,
@@ -5699,6 +6376,7 @@ pub fn name_successor_invoke_signed_with_program_id(
     let ix = name_successor_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_invoke_signed(
     accounts: NameSuccessorAccounts<'_, '_>,
     args: NameSuccessorIxArgs,
@@ -5706,6 +6384,7 @@ pub fn name_successor_invoke_signed(
 ) -> ProgramResult {
     name_successor_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_verify_account_keys(
     accounts: NameSuccessorAccounts<'_, '_>,
     keys: NameSuccessorKeys,
@@ -5722,6 +6401,7 @@ pub fn name_successor_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_verify_writable_privileges<'me, 'info>(
     accounts: NameSuccessorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5732,6 +6412,7 @@ pub fn name_successor_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_verify_signer_privileges<'me, 'info>(
     accounts: NameSuccessorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5742,6 +6423,7 @@ pub fn name_successor_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn name_successor_verify_account_privileges<'me, 'info>(
     accounts: NameSuccessorAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5751,6 +6433,7 @@ pub fn name_successor_verify_account_privileges<'me, 'info>(
 }
 pub const CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ChangeMarketStatusAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -5772,6 +6455,7 @@ pub struct ChangeMarketStatusKeys {
     ///The market_authority account must sign to change market status
     pub market_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ChangeMarketStatusAccounts<'_, '_>> for ChangeMarketStatusKeys {
     fn from(accounts: ChangeMarketStatusAccounts) -> Self {
         Self {
@@ -5782,7 +6466,8 @@ impl From<ChangeMarketStatusAccounts<'_, '_>> for ChangeMarketStatusKeys {
         }
     }
 }
-impl From<ChangeMarketStatusKeys> for [AccountMeta; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] {
+impl From<ChangeMarketStatusKeys>
+for [AccountMeta; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] {
     fn from(keys: ChangeMarketStatusKeys) -> Self {
         [
             AccountMeta {
@@ -5818,9 +6503,9 @@ impl From<[Pubkey; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN]> for ChangeMarketStatus
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ChangeMarketStatusAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] {
     fn from(accounts: ChangeMarketStatusAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -5830,10 +6515,12 @@ impl<'info> From<ChangeMarketStatusAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN]>
-    for ChangeMarketStatusAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN]) -> Self {
+for ChangeMarketStatusAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -5856,37 +6543,50 @@ impl From<ChangeMarketStatusIxArgs> for ChangeMarketStatusIxData {
     }
 }
 impl ChangeMarketStatusIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CHANGE_MARKET_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CHANGE_MARKET_STATUS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHANGE_MARKET_STATUS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ChangeMarketStatusIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CHANGE_MARKET_STATUS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ChangeMarketStatusIxData {
+    const NAME: &'static str = "ChangeMarketStatus";
+    const DISCM: &'static [u8] = &[CHANGE_MARKET_STATUS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN;
+    type Keys = ChangeMarketStatusKeys;
+    type Args = ChangeMarketStatusIxArgs;
+}
 pub fn change_market_status_ix_with_program_id(
     program_id: Pubkey,
     keys: ChangeMarketStatusKeys,
     args: ChangeMarketStatusIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CHANGE_MARKET_STATUS_IX_ACCOUNTS_LEN] = keys.into();
     let data: ChangeMarketStatusIxData = args.into();
     Ok(Instruction {
@@ -5898,9 +6598,10 @@ pub fn change_market_status_ix_with_program_id(
 pub fn change_market_status_ix(
     keys: ChangeMarketStatusKeys,
     args: ChangeMarketStatusIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     change_market_status_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeMarketStatusAccounts<'_, '_>,
@@ -5910,12 +6611,14 @@ pub fn change_market_status_invoke_with_program_id(
     let ix = change_market_status_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_invoke(
     accounts: ChangeMarketStatusAccounts<'_, '_>,
     args: ChangeMarketStatusIxArgs,
 ) -> ProgramResult {
     change_market_status_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ChangeMarketStatusAccounts<'_, '_>,
@@ -5926,6 +6629,7 @@ pub fn change_market_status_invoke_signed_with_program_id(
     let ix = change_market_status_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_invoke_signed(
     accounts: ChangeMarketStatusAccounts<'_, '_>,
     args: ChangeMarketStatusIxArgs,
@@ -5933,6 +6637,7 @@ pub fn change_market_status_invoke_signed(
 ) -> ProgramResult {
     change_market_status_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_verify_account_keys(
     accounts: ChangeMarketStatusAccounts<'_, '_>,
     keys: ChangeMarketStatusKeys,
@@ -5949,6 +6654,7 @@ pub fn change_market_status_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_verify_writable_privileges<'me, 'info>(
     accounts: ChangeMarketStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5959,6 +6665,7 @@ pub fn change_market_status_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_verify_signer_privileges<'me, 'info>(
     accounts: ChangeMarketStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5969,6 +6676,7 @@ pub fn change_market_status_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_market_status_verify_account_privileges<'me, 'info>(
     accounts: ChangeMarketStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5978,6 +6686,7 @@ pub fn change_market_status_verify_account_privileges<'me, 'info>(
 }
 pub const CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ChangeSeatStatusAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -6001,6 +6710,7 @@ pub struct ChangeSeatStatusKeys {
     pub market_authority: Pubkey,
     pub seat: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ChangeSeatStatusAccounts<'_, '_>> for ChangeSeatStatusKeys {
     fn from(accounts: ChangeSeatStatusAccounts) -> Self {
         Self {
@@ -6054,9 +6764,9 @@ impl From<[Pubkey; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN]> for ChangeSeatStatusKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ChangeSeatStatusAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN] {
     fn from(accounts: ChangeSeatStatusAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -6067,9 +6777,9 @@ impl<'info> From<ChangeSeatStatusAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN]>
-    for ChangeSeatStatusAccounts<'me, 'info>
-{
+for ChangeSeatStatusAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -6094,37 +6804,50 @@ impl From<ChangeSeatStatusIxArgs> for ChangeSeatStatusIxData {
     }
 }
 impl ChangeSeatStatusIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CHANGE_SEAT_STATUS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CHANGE_SEAT_STATUS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHANGE_SEAT_STATUS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ChangeSeatStatusIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CHANGE_SEAT_STATUS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ChangeSeatStatusIxData {
+    const NAME: &'static str = "ChangeSeatStatus";
+    const DISCM: &'static [u8] = &[CHANGE_SEAT_STATUS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN;
+    type Keys = ChangeSeatStatusKeys;
+    type Args = ChangeSeatStatusIxArgs;
+}
 pub fn change_seat_status_ix_with_program_id(
     program_id: Pubkey,
     keys: ChangeSeatStatusKeys,
     args: ChangeSeatStatusIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CHANGE_SEAT_STATUS_IX_ACCOUNTS_LEN] = keys.into();
     let data: ChangeSeatStatusIxData = args.into();
     Ok(Instruction {
@@ -6136,9 +6859,10 @@ pub fn change_seat_status_ix_with_program_id(
 pub fn change_seat_status_ix(
     keys: ChangeSeatStatusKeys,
     args: ChangeSeatStatusIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     change_seat_status_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeSeatStatusAccounts<'_, '_>,
@@ -6148,12 +6872,14 @@ pub fn change_seat_status_invoke_with_program_id(
     let ix = change_seat_status_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_invoke(
     accounts: ChangeSeatStatusAccounts<'_, '_>,
     args: ChangeSeatStatusIxArgs,
 ) -> ProgramResult {
     change_seat_status_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ChangeSeatStatusAccounts<'_, '_>,
@@ -6164,6 +6890,7 @@ pub fn change_seat_status_invoke_signed_with_program_id(
     let ix = change_seat_status_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_invoke_signed(
     accounts: ChangeSeatStatusAccounts<'_, '_>,
     args: ChangeSeatStatusIxArgs,
@@ -6171,6 +6898,7 @@ pub fn change_seat_status_invoke_signed(
 ) -> ProgramResult {
     change_seat_status_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_verify_account_keys(
     accounts: ChangeSeatStatusAccounts<'_, '_>,
     keys: ChangeSeatStatusKeys,
@@ -6188,6 +6916,7 @@ pub fn change_seat_status_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_verify_writable_privileges<'me, 'info>(
     accounts: ChangeSeatStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6198,6 +6927,7 @@ pub fn change_seat_status_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_verify_signer_privileges<'me, 'info>(
     accounts: ChangeSeatStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6208,6 +6938,7 @@ pub fn change_seat_status_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_seat_status_verify_account_privileges<'me, 'info>(
     accounts: ChangeSeatStatusAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6217,6 +6948,7 @@ pub fn change_seat_status_verify_account_privileges<'me, 'info>(
 }
 pub const REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RequestSeatAuthorizedAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -6248,6 +6980,7 @@ pub struct RequestSeatAuthorizedKeys {
     ///System program
     pub system_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RequestSeatAuthorizedAccounts<'_, '_>> for RequestSeatAuthorizedKeys {
     fn from(accounts: RequestSeatAuthorizedAccounts) -> Self {
         Self {
@@ -6262,7 +6995,8 @@ impl From<RequestSeatAuthorizedAccounts<'_, '_>> for RequestSeatAuthorizedKeys {
         }
     }
 }
-impl From<RequestSeatAuthorizedKeys> for [AccountMeta; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] {
+impl From<RequestSeatAuthorizedKeys>
+for [AccountMeta; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] {
     fn from(keys: RequestSeatAuthorizedKeys) -> Self {
         [
             AccountMeta {
@@ -6308,7 +7042,8 @@ impl From<RequestSeatAuthorizedKeys> for [AccountMeta; REQUEST_SEAT_AUTHORIZED_I
         ]
     }
 }
-impl From<[Pubkey; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]> for RequestSeatAuthorizedKeys {
+impl From<[Pubkey; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]>
+for RequestSeatAuthorizedKeys {
     fn from(pubkeys: [Pubkey; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: pubkeys[0],
@@ -6322,9 +7057,9 @@ impl From<[Pubkey; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]> for RequestSeatAuth
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RequestSeatAuthorizedAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] {
     fn from(accounts: RequestSeatAuthorizedAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -6338,10 +7073,12 @@ impl<'info> From<RequestSeatAuthorizedAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]>
-    for RequestSeatAuthorizedAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN]) -> Self {
+for RequestSeatAuthorizedAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -6358,35 +7095,48 @@ pub const REQUEST_SEAT_AUTHORIZED_IX_DISCM: u8 = 105u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RequestSeatAuthorizedIxData;
 impl RequestSeatAuthorizedIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REQUEST_SEAT_AUTHORIZED_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REQUEST_SEAT_AUTHORIZED_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REQUEST_SEAT_AUTHORIZED_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REQUEST_SEAT_AUTHORIZED_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RequestSeatAuthorizedIxData {
+    const NAME: &'static str = "RequestSeatAuthorized";
+    const DISCM: &'static [u8] = &[REQUEST_SEAT_AUTHORIZED_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN;
+    type Keys = RequestSeatAuthorizedKeys;
+    type Args = ();
+}
 pub fn request_seat_authorized_ix_with_program_id(
     program_id: Pubkey,
     keys: RequestSeatAuthorizedKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REQUEST_SEAT_AUTHORIZED_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -6394,9 +7144,12 @@ pub fn request_seat_authorized_ix_with_program_id(
         data: RequestSeatAuthorizedIxData.try_to_vec()?,
     })
 }
-pub fn request_seat_authorized_ix(keys: RequestSeatAuthorizedKeys) -> std::io::Result<Instruction> {
+pub fn request_seat_authorized_ix(
+    keys: RequestSeatAuthorizedKeys,
+) -> borsh::io::Result<Instruction> {
     request_seat_authorized_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
@@ -6405,11 +7158,13 @@ pub fn request_seat_authorized_invoke_with_program_id(
     let ix = request_seat_authorized_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_invoke(
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
 ) -> ProgramResult {
     request_seat_authorized_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
@@ -6419,12 +7174,14 @@ pub fn request_seat_authorized_invoke_signed_with_program_id(
     let ix = request_seat_authorized_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_invoke_signed(
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     request_seat_authorized_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_verify_account_keys(
     accounts: RequestSeatAuthorizedAccounts<'_, '_>,
     keys: RequestSeatAuthorizedKeys,
@@ -6445,6 +7202,7 @@ pub fn request_seat_authorized_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_verify_writable_privileges<'me, 'info>(
     accounts: RequestSeatAuthorizedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6455,6 +7213,7 @@ pub fn request_seat_authorized_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_verify_signer_privileges<'me, 'info>(
     accounts: RequestSeatAuthorizedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6465,6 +7224,7 @@ pub fn request_seat_authorized_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn request_seat_authorized_verify_account_privileges<'me, 'info>(
     accounts: RequestSeatAuthorizedAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6474,6 +7234,7 @@ pub fn request_seat_authorized_verify_account_privileges<'me, 'info>(
 }
 pub const EVICT_SEAT_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct EvictSeatAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -6513,6 +7274,7 @@ pub struct EvictSeatKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<EvictSeatAccounts<'_, '_>> for EvictSeatKeys {
     fn from(accounts: EvictSeatAccounts) -> Self {
         Self {
@@ -6608,9 +7370,9 @@ impl From<[Pubkey; EVICT_SEAT_IX_ACCOUNTS_LEN]> for EvictSeatKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<EvictSeatAccounts<'_, 'info>>
-    for [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN] {
     fn from(accounts: EvictSeatAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -6627,9 +7389,9 @@ impl<'info> From<EvictSeatAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN]>
-    for EvictSeatAccounts<'me, 'info>
-{
+for EvictSeatAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; EVICT_SEAT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -6650,35 +7412,48 @@ pub const EVICT_SEAT_IX_DISCM: u8 = 106u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct EvictSeatIxData;
 impl EvictSeatIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != EVICT_SEAT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    EVICT_SEAT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        EVICT_SEAT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[EVICT_SEAT_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for EvictSeatIxData {
+    const NAME: &'static str = "EvictSeat";
+    const DISCM: &'static [u8] = &[EVICT_SEAT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = EVICT_SEAT_IX_ACCOUNTS_LEN;
+    type Keys = EvictSeatKeys;
+    type Args = ();
+}
 pub fn evict_seat_ix_with_program_id(
     program_id: Pubkey,
     keys: EvictSeatKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; EVICT_SEAT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -6686,9 +7461,10 @@ pub fn evict_seat_ix_with_program_id(
         data: EvictSeatIxData.try_to_vec()?,
     })
 }
-pub fn evict_seat_ix(keys: EvictSeatKeys) -> std::io::Result<Instruction> {
+pub fn evict_seat_ix(keys: EvictSeatKeys) -> borsh::io::Result<Instruction> {
     evict_seat_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_invoke_with_program_id(
     program_id: Pubkey,
     accounts: EvictSeatAccounts<'_, '_>,
@@ -6697,9 +7473,11 @@ pub fn evict_seat_invoke_with_program_id(
     let ix = evict_seat_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_invoke(accounts: EvictSeatAccounts<'_, '_>) -> ProgramResult {
     evict_seat_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: EvictSeatAccounts<'_, '_>,
@@ -6709,12 +7487,14 @@ pub fn evict_seat_invoke_signed_with_program_id(
     let ix = evict_seat_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_invoke_signed(
     accounts: EvictSeatAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     evict_seat_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_verify_account_keys(
     accounts: EvictSeatAccounts<'_, '_>,
     keys: EvictSeatKeys,
@@ -6738,6 +7518,7 @@ pub fn evict_seat_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_verify_writable_privileges<'me, 'info>(
     accounts: EvictSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6754,6 +7535,7 @@ pub fn evict_seat_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_verify_signer_privileges<'me, 'info>(
     accounts: EvictSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6764,6 +7546,7 @@ pub fn evict_seat_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn evict_seat_verify_account_privileges<'me, 'info>(
     accounts: EvictSeatAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6773,6 +7556,7 @@ pub fn evict_seat_verify_account_privileges<'me, 'info>(
 }
 pub const FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ForceCancelOrdersAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -6820,6 +7604,7 @@ pub struct ForceCancelOrdersKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ForceCancelOrdersAccounts<'_, '_>> for ForceCancelOrdersKeys {
     fn from(accounts: ForceCancelOrdersAccounts) -> Self {
         Self {
@@ -6915,9 +7700,9 @@ impl From<[Pubkey; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]> for ForceCancelOrdersKe
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ForceCancelOrdersAccounts<'_, 'info>>
-    for [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] {
     fn from(accounts: ForceCancelOrdersAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -6934,10 +7719,12 @@ impl<'info> From<ForceCancelOrdersAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]>
-    for ForceCancelOrdersAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN]) -> Self {
+for ForceCancelOrdersAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -6967,37 +7754,50 @@ impl From<ForceCancelOrdersIxArgs> for ForceCancelOrdersIxData {
     }
 }
 impl ForceCancelOrdersIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != FORCE_CANCEL_ORDERS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FORCE_CANCEL_ORDERS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        FORCE_CANCEL_ORDERS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ForceCancelOrdersIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[FORCE_CANCEL_ORDERS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ForceCancelOrdersIxData {
+    const NAME: &'static str = "ForceCancelOrders";
+    const DISCM: &'static [u8] = &[FORCE_CANCEL_ORDERS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN;
+    type Keys = ForceCancelOrdersKeys;
+    type Args = ForceCancelOrdersIxArgs;
+}
 pub fn force_cancel_orders_ix_with_program_id(
     program_id: Pubkey,
     keys: ForceCancelOrdersKeys,
     args: ForceCancelOrdersIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; FORCE_CANCEL_ORDERS_IX_ACCOUNTS_LEN] = keys.into();
     let data: ForceCancelOrdersIxData = args.into();
     Ok(Instruction {
@@ -7009,9 +7809,10 @@ pub fn force_cancel_orders_ix_with_program_id(
 pub fn force_cancel_orders_ix(
     keys: ForceCancelOrdersKeys,
     args: ForceCancelOrdersIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     force_cancel_orders_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ForceCancelOrdersAccounts<'_, '_>,
@@ -7021,12 +7822,14 @@ pub fn force_cancel_orders_invoke_with_program_id(
     let ix = force_cancel_orders_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_invoke(
     accounts: ForceCancelOrdersAccounts<'_, '_>,
     args: ForceCancelOrdersIxArgs,
 ) -> ProgramResult {
     force_cancel_orders_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ForceCancelOrdersAccounts<'_, '_>,
@@ -7037,6 +7840,7 @@ pub fn force_cancel_orders_invoke_signed_with_program_id(
     let ix = force_cancel_orders_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_invoke_signed(
     accounts: ForceCancelOrdersAccounts<'_, '_>,
     args: ForceCancelOrdersIxArgs,
@@ -7044,6 +7848,7 @@ pub fn force_cancel_orders_invoke_signed(
 ) -> ProgramResult {
     force_cancel_orders_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_verify_account_keys(
     accounts: ForceCancelOrdersAccounts<'_, '_>,
     keys: ForceCancelOrdersKeys,
@@ -7067,6 +7872,7 @@ pub fn force_cancel_orders_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_verify_writable_privileges<'me, 'info>(
     accounts: ForceCancelOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7083,6 +7889,7 @@ pub fn force_cancel_orders_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_verify_signer_privileges<'me, 'info>(
     accounts: ForceCancelOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7093,6 +7900,7 @@ pub fn force_cancel_orders_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn force_cancel_orders_verify_account_privileges<'me, 'info>(
     accounts: ForceCancelOrdersAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7102,6 +7910,7 @@ pub fn force_cancel_orders_verify_account_privileges<'me, 'info>(
 }
 pub const COLLECT_FEES_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CollectFeesAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -7135,6 +7944,7 @@ pub struct CollectFeesKeys {
     ///Token program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CollectFeesAccounts<'_, '_>> for CollectFeesKeys {
     fn from(accounts: CollectFeesAccounts) -> Self {
         Self {
@@ -7202,9 +8012,9 @@ impl From<[Pubkey; COLLECT_FEES_IX_ACCOUNTS_LEN]> for CollectFeesKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CollectFeesAccounts<'_, 'info>>
-    for [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN] {
     fn from(accounts: CollectFeesAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -7217,9 +8027,9 @@ impl<'info> From<CollectFeesAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN]>
-    for CollectFeesAccounts<'me, 'info>
-{
+for CollectFeesAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; COLLECT_FEES_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             phoenix_program: &arr[0],
@@ -7236,35 +8046,48 @@ pub const COLLECT_FEES_IX_DISCM: u8 = 108u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct CollectFeesIxData;
 impl CollectFeesIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != COLLECT_FEES_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    COLLECT_FEES_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        COLLECT_FEES_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[COLLECT_FEES_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CollectFeesIxData {
+    const NAME: &'static str = "CollectFees";
+    const DISCM: &'static [u8] = &[COLLECT_FEES_IX_DISCM];
+    const ACCOUNTS_LEN: usize = COLLECT_FEES_IX_ACCOUNTS_LEN;
+    type Keys = CollectFeesKeys;
+    type Args = ();
+}
 pub fn collect_fees_ix_with_program_id(
     program_id: Pubkey,
     keys: CollectFeesKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; COLLECT_FEES_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7272,9 +8095,10 @@ pub fn collect_fees_ix_with_program_id(
         data: CollectFeesIxData.try_to_vec()?,
     })
 }
-pub fn collect_fees_ix(keys: CollectFeesKeys) -> std::io::Result<Instruction> {
+pub fn collect_fees_ix(keys: CollectFeesKeys) -> borsh::io::Result<Instruction> {
     collect_fees_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CollectFeesAccounts<'_, '_>,
@@ -7283,9 +8107,11 @@ pub fn collect_fees_invoke_with_program_id(
     let ix = collect_fees_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_invoke(accounts: CollectFeesAccounts<'_, '_>) -> ProgramResult {
     collect_fees_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CollectFeesAccounts<'_, '_>,
@@ -7295,12 +8121,14 @@ pub fn collect_fees_invoke_signed_with_program_id(
     let ix = collect_fees_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_invoke_signed(
     accounts: CollectFeesAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     collect_fees_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_verify_account_keys(
     accounts: CollectFeesAccounts<'_, '_>,
     keys: CollectFeesKeys,
@@ -7320,6 +8148,7 @@ pub fn collect_fees_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_verify_writable_privileges<'me, 'info>(
     accounts: CollectFeesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7334,6 +8163,7 @@ pub fn collect_fees_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_verify_signer_privileges<'me, 'info>(
     accounts: CollectFeesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7344,6 +8174,7 @@ pub fn collect_fees_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn collect_fees_verify_account_privileges<'me, 'info>(
     accounts: CollectFeesAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7353,6 +8184,7 @@ pub fn collect_fees_verify_account_privileges<'me, 'info>(
 }
 pub const CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ChangeFeeRecipientAccounts<'me, 'info> {
     ///Phoenix program
     pub phoenix_program: &'me AccountInfo<'info>,
@@ -7378,6 +8210,7 @@ pub struct ChangeFeeRecipientKeys {
     ///New fee recipient
     pub new_fee_recipient: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ChangeFeeRecipientAccounts<'_, '_>> for ChangeFeeRecipientKeys {
     fn from(accounts: ChangeFeeRecipientAccounts) -> Self {
         Self {
@@ -7389,7 +8222,8 @@ impl From<ChangeFeeRecipientAccounts<'_, '_>> for ChangeFeeRecipientKeys {
         }
     }
 }
-impl From<ChangeFeeRecipientKeys> for [AccountMeta; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] {
+impl From<ChangeFeeRecipientKeys>
+for [AccountMeta; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] {
     fn from(keys: ChangeFeeRecipientKeys) -> Self {
         [
             AccountMeta {
@@ -7431,9 +8265,9 @@ impl From<[Pubkey; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN]> for ChangeFeeRecipient
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ChangeFeeRecipientAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] {
     fn from(accounts: ChangeFeeRecipientAccounts<'_, 'info>) -> Self {
         [
             accounts.phoenix_program.clone(),
@@ -7444,10 +8278,12 @@ impl<'info> From<ChangeFeeRecipientAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN]>
-    for ChangeFeeRecipientAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN]) -> Self {
+for ChangeFeeRecipientAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             phoenix_program: &arr[0],
             log_authority: &arr[1],
@@ -7461,35 +8297,48 @@ pub const CHANGE_FEE_RECIPIENT_IX_DISCM: u8 = 109u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ChangeFeeRecipientIxData;
 impl ChangeFeeRecipientIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CHANGE_FEE_RECIPIENT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CHANGE_FEE_RECIPIENT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CHANGE_FEE_RECIPIENT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CHANGE_FEE_RECIPIENT_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ChangeFeeRecipientIxData {
+    const NAME: &'static str = "ChangeFeeRecipient";
+    const DISCM: &'static [u8] = &[CHANGE_FEE_RECIPIENT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN;
+    type Keys = ChangeFeeRecipientKeys;
+    type Args = ();
+}
 pub fn change_fee_recipient_ix_with_program_id(
     program_id: Pubkey,
     keys: ChangeFeeRecipientKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CHANGE_FEE_RECIPIENT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7497,9 +8346,12 @@ pub fn change_fee_recipient_ix_with_program_id(
         data: ChangeFeeRecipientIxData.try_to_vec()?,
     })
 }
-pub fn change_fee_recipient_ix(keys: ChangeFeeRecipientKeys) -> std::io::Result<Instruction> {
+pub fn change_fee_recipient_ix(
+    keys: ChangeFeeRecipientKeys,
+) -> borsh::io::Result<Instruction> {
     change_fee_recipient_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ChangeFeeRecipientAccounts<'_, '_>,
@@ -7508,9 +8360,13 @@ pub fn change_fee_recipient_invoke_with_program_id(
     let ix = change_fee_recipient_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn change_fee_recipient_invoke(accounts: ChangeFeeRecipientAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn change_fee_recipient_invoke(
+    accounts: ChangeFeeRecipientAccounts<'_, '_>,
+) -> ProgramResult {
     change_fee_recipient_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ChangeFeeRecipientAccounts<'_, '_>,
@@ -7520,12 +8376,14 @@ pub fn change_fee_recipient_invoke_signed_with_program_id(
     let ix = change_fee_recipient_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_invoke_signed(
     accounts: ChangeFeeRecipientAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     change_fee_recipient_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_verify_account_keys(
     accounts: ChangeFeeRecipientAccounts<'_, '_>,
     keys: ChangeFeeRecipientKeys,
@@ -7543,6 +8401,7 @@ pub fn change_fee_recipient_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_verify_writable_privileges<'me, 'info>(
     accounts: ChangeFeeRecipientAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7553,6 +8412,7 @@ pub fn change_fee_recipient_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_verify_signer_privileges<'me, 'info>(
     accounts: ChangeFeeRecipientAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7563,6 +8423,7 @@ pub fn change_fee_recipient_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn change_fee_recipient_verify_account_privileges<'me, 'info>(
     accounts: ChangeFeeRecipientAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
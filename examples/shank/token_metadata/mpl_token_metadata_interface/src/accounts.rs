@@ -1,5 +1,5 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+use crate::*;
 use solana_program::pubkey::Pubkey;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -8,12 +8,22 @@ pub struct UseAuthorityRecord {
     pub allowed_uses: u64,
     pub bump: u8,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for UseAuthorityRecord {
+    const NAME: &'static str = "UseAuthorityRecord";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectionAuthorityRecord {
     pub key: Key,
     pub bump: u8,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for CollectionAuthorityRecord {
+    const NAME: &'static str = "CollectionAuthorityRecord";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Metadata {
@@ -28,6 +38,11 @@ pub struct Metadata {
     pub collection: Option<Collection>,
     pub uses: Option<Uses>,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Metadata {
+    const NAME: &'static str = "Metadata";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MasterEditionV2 {
@@ -35,6 +50,11 @@ pub struct MasterEditionV2 {
     pub supply: u64,
     pub max_supply: Option<u64>,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for MasterEditionV2 {
+    const NAME: &'static str = "MasterEditionV2";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MasterEditionV1 {
@@ -44,6 +64,11 @@ pub struct MasterEditionV1 {
     pub printing_mint: Pubkey,
     pub one_time_printing_authorization_mint: Pubkey,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for MasterEditionV1 {
+    const NAME: &'static str = "MasterEditionV1";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edition {
@@ -51,6 +76,11 @@ pub struct Edition {
     pub parent: Pubkey,
     pub edition: u64,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for Edition {
+    const NAME: &'static str = "Edition";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReservationListV2 {
@@ -61,6 +91,11 @@ pub struct ReservationListV2 {
     pub total_reservation_spots: u64,
     pub current_reservation_spots: u64,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ReservationListV2 {
+    const NAME: &'static str = "ReservationListV2";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReservationListV1 {
@@ -69,9 +104,19 @@ pub struct ReservationListV1 {
     pub supply_snapshot: Option<u64>,
     pub reservations: Vec<ReservationV1>,
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for ReservationListV1 {
+    const NAME: &'static str = "ReservationListV1";
+    const DISCM: &'static [u8] = &[];
+}
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EditionMarker {
     pub key: Key,
     pub ledger: [u8; 31],
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramAccount for EditionMarker {
+    const NAME: &'static str = "EditionMarker";
+    const DISCM: &'static [u8] = &[];
+}
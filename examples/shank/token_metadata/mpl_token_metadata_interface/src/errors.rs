@@ -1,7 +1,5 @@
 use solana_program::{
-    decode_error::DecodeError,
-    msg,
-    program_error::{PrintProgramError, ProgramError},
+    decode_error::DecodeError, msg, program_error::{PrintProgramError, ProgramError},
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
@@ -16,9 +14,13 @@ pub enum MplTokenMetadataError {
     AlreadyInitialized = 3,
     #[error("Uninitialized")]
     Uninitialized = 4,
-    #[error(" Metadata's key must match seed of ['metadata', program id, mint] provided")]
+    #[error(
+        " Metadata's key must match seed of ['metadata', program id, mint] provided"
+    )]
     InvalidMetadataKey = 5,
-    #[error("Edition's key must match seed of ['metadata', program id, name, 'edition'] provided")]
+    #[error(
+        "Edition's key must match seed of ['metadata', program id, name, 'edition'] provided"
+    )]
     InvalidEditionKey = 6,
     #[error("Update Authority given does not match")]
     UpdateAuthorityIncorrect = 7,
@@ -46,7 +48,9 @@ pub enum MplTokenMetadataError {
     MaxEditionsMintedAlready = 17,
     #[error("Token mint to failed")]
     TokenMintToFailed = 18,
-    #[error("The master edition record passed must match the master record on the edition given")]
+    #[error(
+        "The master edition record passed must match the master record on the edition given"
+    )]
     MasterRecordMismatch = 19,
     #[error("The destination account does not have the right mint")]
     DestinationMintMismatch = 20,
@@ -66,7 +70,9 @@ pub enum MplTokenMetadataError {
     DerivedKeyInvalid = 27,
     #[error("The Printing mint does not match that on the master edition!")]
     PrintingMintMismatch = 28,
-    #[error("The One Time Printing Auth mint does not match that on the master edition!")]
+    #[error(
+        "The One Time Printing Auth mint does not match that on the master edition!"
+    )]
     OneTimePrintingAuthMintMismatch = 29,
     #[error("The mint of the token account does not match the Printing mint!")]
     TokenAccountMintMismatch = 30,
@@ -116,7 +122,9 @@ pub enum MplTokenMetadataError {
     BeyondMaxAddressSize = 50,
     #[error("NumericalOverflowError")]
     NumericalOverflowError = 51,
-    #[error("This reservation would go beyond the maximum supply of the master edition!")]
+    #[error(
+        "This reservation would go beyond the maximum supply of the master edition!"
+    )]
     ReservationBreachesMaximumSupply = 52,
     #[error("Address not in reservation!")]
     AddressNotInReservation = 53,
@@ -128,13 +136,17 @@ pub enum MplTokenMetadataError {
     SpotMismatch = 56,
     #[error("Incorrect account owner")]
     IncorrectOwner = 57,
-    #[error("printing these tokens would breach the maximum supply limit of the master edition")]
+    #[error(
+        "printing these tokens would breach the maximum supply limit of the master edition"
+    )]
     PrintingWouldBreachMaximumSupply = 58,
     #[error("Data is immutable")]
     DataIsImmutable = 59,
     #[error("No duplicate creator addresses")]
     DuplicateCreatorAddress = 60,
-    #[error("Reservation spots remaining should match total spots when first being created")]
+    #[error(
+        "Reservation spots remaining should match total spots when first being created"
+    )]
     ReservationSpotsRemainingShouldMatchTotalSpotsAtStart = 61,
     #[error("Invalid token program")]
     InvalidTokenProgram = 62,
@@ -156,7 +168,9 @@ pub enum MplTokenMetadataError {
     OneTimeAuthMintSupplyMustBeZeroForConversion = 70,
     #[error("You tried to insert one edition too many into an edition mark pda")]
     InvalidEditionIndex = 71,
-    #[error("In the legacy system the reservation needs to be of size one for cpu limit reasons")]
+    #[error(
+        "In the legacy system the reservation needs to be of size one for cpu limit reasons"
+    )]
     ReservationArrayShouldBeSizeOne = 72,
     #[error("Is Mutable can only be flipped to false")]
     IsMutableCanOnlyBeFlippedToFalse = 73,
@@ -164,7 +178,9 @@ pub enum MplTokenMetadataError {
     CollectionCannotBeVerifiedInThisInstruction = 74,
     #[error("This instruction was deprecated in a previous release and is now removed")]
     Removed = 75,
-    #[error("This token use method is burn and there are no remaining uses, it must be burned")]
+    #[error(
+        "This token use method is burn and there are no remaining uses, it must be burned"
+    )]
     MustBeBurned = 76,
     #[error("This use method is invalid")]
     InvalidUseMethod = 77,
@@ -178,7 +194,9 @@ pub enum MplTokenMetadataError {
     InvalidCollectionUpdateAuthority = 81,
     #[error("Collection Must Be a Unique Master Edition v2")]
     CollectionMustBeAUniqueMasterEdition = 82,
-    #[error("The Use Authority Record Already Exists, to modify it Revoke, then Approve")]
+    #[error(
+        "The Use Authority Record Already Exists, to modify it Revoke, then Approve"
+    )]
     UseAuthorityRecordAlreadyExists = 83,
     #[error("The Use Authority Record is empty or already revoked")]
     UseAuthorityRecordAlreadyRevoked = 84,
@@ -212,6 +230,15 @@ impl From<MplTokenMetadataError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::ProgramErrorCode for MplTokenMetadataError {
+    fn code(&self) -> u32 {
+        *self as u32
+    }
+    fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
 impl<T> DecodeError<T> for MplTokenMetadataError {
     fn type_of() -> &'static str {
         "MplTokenMetadataError"
@@ -220,12 +247,9 @@ impl<T> DecodeError<T> for MplTokenMetadataError {
 impl PrintProgramError for MplTokenMetadataError {
     fn print<E>(&self)
     where
-        E: 'static
-            + std::error::Error
-            + DecodeError<E>
-            + PrintProgramError
+        E: 'static + std::error::Error + DecodeError<E> + PrintProgramError
             + num_traits::FromPrimitive,
     {
-        msg!(&self.to_string());
+        msg!(& self.to_string());
     }
 }
@@ -1,14 +1,16 @@
-use crate::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+#[cfg(feature = "cpi")]
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    pubkey::Pubkey,
 };
-use std::io::Read;
+use borsh::io::Read;
+use crate::*;
 #[derive(Clone, Debug, PartialEq)]
 pub enum MplTokenMetadataProgramIx {
     CreateMetadataAccount(CreateMetadataAccountIxArgs),
@@ -22,7 +24,9 @@ pub enum MplTokenMetadataProgramIx {
     DeprecatedMintPrintingTokensViaToken(DeprecatedMintPrintingTokensViaTokenIxArgs),
     DeprecatedMintPrintingTokens(DeprecatedMintPrintingTokensIxArgs),
     CreateMasterEdition(CreateMasterEditionIxArgs),
-    MintNewEditionFromMasterEditionViaToken(MintNewEditionFromMasterEditionViaTokenIxArgs),
+    MintNewEditionFromMasterEditionViaToken(
+        MintNewEditionFromMasterEditionViaTokenIxArgs,
+    ),
     ConvertMasterEditionV1ToV2,
     MintNewEditionFromMasterEditionViaVaultProxy(
         MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
@@ -44,71 +48,127 @@ pub enum MplTokenMetadataProgramIx {
     RemoveCreatorVerification,
 }
 impl MplTokenMetadataProgramIx {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         match maybe_discm {
-            CREATE_METADATA_ACCOUNT_IX_DISCM => Ok(Self::CreateMetadataAccount(
-                CreateMetadataAccountIxArgs::deserialize(&mut reader)?,
-            )),
-            UPDATE_METADATA_ACCOUNT_IX_DISCM => Ok(Self::UpdateMetadataAccount(
-                UpdateMetadataAccountIxArgs::deserialize(&mut reader)?,
-            )),
-            DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM => Ok(Self::DeprecatedCreateMasterEdition(
-                DeprecatedCreateMasterEditionIxArgs::deserialize(&mut reader)?,
-            )),
+            CREATE_METADATA_ACCOUNT_IX_DISCM => {
+                Ok(
+                    Self::CreateMetadataAccount(
+                        CreateMetadataAccountIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            UPDATE_METADATA_ACCOUNT_IX_DISCM => {
+                Ok(
+                    Self::UpdateMetadataAccount(
+                        UpdateMetadataAccountIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedCreateMasterEdition(
+                        DeprecatedCreateMasterEditionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM => {
                 Ok(Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken)
             }
             UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM => {
                 Ok(Self::UpdatePrimarySaleHappenedViaToken)
             }
-            DEPRECATED_SET_RESERVATION_LIST_IX_DISCM => Ok(Self::DeprecatedSetReservationList(
-                DeprecatedSetReservationListIxArgs::deserialize(&mut reader)?,
-            )),
+            DEPRECATED_SET_RESERVATION_LIST_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedSetReservationList(
+                        DeprecatedSetReservationListIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM => {
                 Ok(Self::DeprecatedCreateReservationList)
             }
             SIGN_METADATA_IX_DISCM => Ok(Self::SignMetadata),
             DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM => {
-                Ok(Self::DeprecatedMintPrintingTokensViaToken(
-                    DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(&mut reader)?,
-                ))
+                Ok(
+                    Self::DeprecatedMintPrintingTokensViaToken(
+                        DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM => {
+                Ok(
+                    Self::DeprecatedMintPrintingTokens(
+                        DeprecatedMintPrintingTokensIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_MASTER_EDITION_IX_DISCM => {
+                Ok(
+                    Self::CreateMasterEdition(
+                        CreateMasterEditionIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
             }
-            DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM => Ok(Self::DeprecatedMintPrintingTokens(
-                DeprecatedMintPrintingTokensIxArgs::deserialize(&mut reader)?,
-            )),
-            CREATE_MASTER_EDITION_IX_DISCM => Ok(Self::CreateMasterEdition(
-                CreateMasterEditionIxArgs::deserialize(&mut reader)?,
-            )),
             MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM => {
-                Ok(Self::MintNewEditionFromMasterEditionViaToken(
-                    MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(&mut reader)?,
-                ))
+                Ok(
+                    Self::MintNewEditionFromMasterEditionViaToken(
+                        MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
+            }
+            CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM => {
+                Ok(Self::ConvertMasterEditionV1ToV2)
             }
-            CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM => Ok(Self::ConvertMasterEditionV1ToV2),
             MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM => {
-                Ok(Self::MintNewEditionFromMasterEditionViaVaultProxy(
-                    MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(&mut reader)?,
-                ))
+                Ok(
+                    Self::MintNewEditionFromMasterEditionViaVaultProxy(
+                        MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(
+                            &mut reader,
+                        )?,
+                    ),
+                )
             }
             PUFF_METADATA_IX_DISCM => Ok(Self::PuffMetadata),
-            UPDATE_METADATA_ACCOUNT_V2_IX_DISCM => Ok(Self::UpdateMetadataAccountV2(
-                UpdateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
-            )),
-            CREATE_METADATA_ACCOUNT_V2_IX_DISCM => Ok(Self::CreateMetadataAccountV2(
-                CreateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
-            )),
-            CREATE_MASTER_EDITION_V3_IX_DISCM => Ok(Self::CreateMasterEditionV3(
-                CreateMasterEditionV3IxArgs::deserialize(&mut reader)?,
-            )),
+            UPDATE_METADATA_ACCOUNT_V2_IX_DISCM => {
+                Ok(
+                    Self::UpdateMetadataAccountV2(
+                        UpdateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_METADATA_ACCOUNT_V2_IX_DISCM => {
+                Ok(
+                    Self::CreateMetadataAccountV2(
+                        CreateMetadataAccountV2IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
+            CREATE_MASTER_EDITION_V3_IX_DISCM => {
+                Ok(
+                    Self::CreateMasterEditionV3(
+                        CreateMasterEditionV3IxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             VERIFY_COLLECTION_IX_DISCM => Ok(Self::VerifyCollection),
-            UTILIZE_IX_DISCM => Ok(Self::Utilize(UtilizeIxArgs::deserialize(&mut reader)?)),
-            APPROVE_USE_AUTHORITY_IX_DISCM => Ok(Self::ApproveUseAuthority(
-                ApproveUseAuthorityIxArgs::deserialize(&mut reader)?,
-            )),
+            UTILIZE_IX_DISCM => {
+                Ok(Self::Utilize(UtilizeIxArgs::deserialize(&mut reader)?))
+            }
+            APPROVE_USE_AUTHORITY_IX_DISCM => {
+                Ok(
+                    Self::ApproveUseAuthority(
+                        ApproveUseAuthorityIxArgs::deserialize(&mut reader)?,
+                    ),
+                )
+            }
             REVOKE_USE_AUTHORITY_IX_DISCM => Ok(Self::RevokeUseAuthority),
             UNVERIFY_COLLECTION_IX_DISCM => Ok(Self::UnverifyCollection),
             APPROVE_COLLECTION_AUTHORITY_IX_DISCM => Ok(Self::ApproveCollectionAuthority),
@@ -117,13 +177,20 @@ impl MplTokenMetadataProgramIx {
             FREEZE_DELEGATED_ACCOUNT_IX_DISCM => Ok(Self::FreezeDelegatedAccount),
             THAW_DELEGATED_ACCOUNT_IX_DISCM => Ok(Self::ThawDelegatedAccount),
             REMOVE_CREATOR_VERIFICATION_IX_DISCM => Ok(Self::RemoveCreatorVerification),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("discm {:?} not found", maybe_discm),
-            )),
+            _ => {
+                Err(
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!("discm {:?} not found", maybe_discm),
+                    ),
+                )
+            }
         }
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         match self {
             Self::CreateMetadataAccount(args) => {
                 writer.write_all(&[CREATE_METADATA_ACCOUNT_IX_DISCM])?;
@@ -137,9 +204,14 @@ impl MplTokenMetadataProgramIx {
                 writer.write_all(&[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
-            Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken => writer.write_all(&[
-                DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
-            ]),
+            Self::DeprecatedMintNewEditionFromMasterEditionViaPrintingToken => {
+                writer
+                    .write_all(
+                        &[
+                            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                        ],
+                    )
+            }
             Self::UpdatePrimarySaleHappenedViaToken => {
                 writer.write_all(&[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM])
             }
@@ -164,7 +236,10 @@ impl MplTokenMetadataProgramIx {
                 args.serialize(&mut writer)
             }
             Self::MintNewEditionFromMasterEditionViaToken(args) => {
-                writer.write_all(&[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM])?;
+                writer
+                    .write_all(
+                        &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM],
+                    )?;
                 args.serialize(&mut writer)
             }
             Self::ConvertMasterEditionV1ToV2 => {
@@ -172,7 +247,9 @@ impl MplTokenMetadataProgramIx {
             }
             Self::MintNewEditionFromMasterEditionViaVaultProxy(args) => {
                 writer
-                    .write_all(&[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM])?;
+                    .write_all(
+                        &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM],
+                    )?;
                 args.serialize(&mut writer)
             }
             Self::PuffMetadata => writer.write_all(&[PUFF_METADATA_IX_DISCM]),
@@ -197,7 +274,9 @@ impl MplTokenMetadataProgramIx {
                 writer.write_all(&[APPROVE_USE_AUTHORITY_IX_DISCM])?;
                 args.serialize(&mut writer)
             }
-            Self::RevokeUseAuthority => writer.write_all(&[REVOKE_USE_AUTHORITY_IX_DISCM]),
+            Self::RevokeUseAuthority => {
+                writer.write_all(&[REVOKE_USE_AUTHORITY_IX_DISCM])
+            }
             Self::UnverifyCollection => writer.write_all(&[UNVERIFY_COLLECTION_IX_DISCM]),
             Self::ApproveCollectionAuthority => {
                 writer.write_all(&[APPROVE_COLLECTION_AUTHORITY_IX_DISCM])
@@ -205,20 +284,27 @@ impl MplTokenMetadataProgramIx {
             Self::RevokeCollectionAuthority => {
                 writer.write_all(&[REVOKE_COLLECTION_AUTHORITY_IX_DISCM])
             }
-            Self::SetAndVerifyCollection => writer.write_all(&[SET_AND_VERIFY_COLLECTION_IX_DISCM]),
-            Self::FreezeDelegatedAccount => writer.write_all(&[FREEZE_DELEGATED_ACCOUNT_IX_DISCM]),
-            Self::ThawDelegatedAccount => writer.write_all(&[THAW_DELEGATED_ACCOUNT_IX_DISCM]),
+            Self::SetAndVerifyCollection => {
+                writer.write_all(&[SET_AND_VERIFY_COLLECTION_IX_DISCM])
+            }
+            Self::FreezeDelegatedAccount => {
+                writer.write_all(&[FREEZE_DELEGATED_ACCOUNT_IX_DISCM])
+            }
+            Self::ThawDelegatedAccount => {
+                writer.write_all(&[THAW_DELEGATED_ACCOUNT_IX_DISCM])
+            }
             Self::RemoveCreatorVerification => {
                 writer.write_all(&[REMOVE_CREATOR_VERIFICATION_IX_DISCM])
             }
         }
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -226,6 +312,7 @@ fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     let account_info: [AccountInfo<'info>; N] = accounts.into();
     invoke(ix, &account_info)
 }
+#[cfg(feature = "cpi")]
 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
     ix: &Instruction,
     accounts: A,
@@ -236,6 +323,7 @@ fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: u
 }
 pub const CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateMetadataAccountAccounts<'me, 'info> {
     ///Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
@@ -269,6 +357,7 @@ pub struct CreateMetadataAccountKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateMetadataAccountAccounts<'_, '_>> for CreateMetadataAccountKeys {
     fn from(accounts: CreateMetadataAccountAccounts) -> Self {
         Self {
@@ -282,7 +371,8 @@ impl From<CreateMetadataAccountAccounts<'_, '_>> for CreateMetadataAccountKeys {
         }
     }
 }
-impl From<CreateMetadataAccountKeys> for [AccountMeta; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<CreateMetadataAccountKeys>
+for [AccountMeta; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: CreateMetadataAccountKeys) -> Self {
         [
             AccountMeta {
@@ -323,7 +413,8 @@ impl From<CreateMetadataAccountKeys> for [AccountMeta; CREATE_METADATA_ACCOUNT_I
         ]
     }
 }
-impl From<[Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]> for CreateMetadataAccountKeys {
+impl From<[Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountKeys {
     fn from(pubkeys: [Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -336,9 +427,9 @@ impl From<[Pubkey; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]> for CreateMetadataA
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateMetadataAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateMetadataAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -351,10 +442,12 @@ impl<'info> From<CreateMetadataAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for CreateMetadataAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for CreateMetadataAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             mint: &arr[1],
@@ -380,37 +473,50 @@ impl From<CreateMetadataAccountIxArgs> for CreateMetadataAccountIxData {
     }
 }
 impl CreateMetadataAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CREATE_METADATA_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(CreateMetadataAccountIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CREATE_METADATA_ACCOUNT_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMetadataAccountIxData {
+    const NAME: &'static str = "CreateMetadataAccount";
+    const DISCM: &'static [u8] = &[CREATE_METADATA_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = CreateMetadataAccountKeys;
+    type Args = CreateMetadataAccountIxArgs;
+}
 pub fn create_metadata_account_ix_with_program_id(
     program_id: Pubkey,
     keys: CreateMetadataAccountKeys,
     args: CreateMetadataAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CREATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     let data: CreateMetadataAccountIxData = args.into();
     Ok(Instruction {
@@ -422,9 +528,10 @@ pub fn create_metadata_account_ix_with_program_id(
 pub fn create_metadata_account_ix(
     keys: CreateMetadataAccountKeys,
     args: CreateMetadataAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     create_metadata_account_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountAccounts<'_, '_>,
@@ -434,12 +541,14 @@ pub fn create_metadata_account_invoke_with_program_id(
     let ix = create_metadata_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_invoke(
     accounts: CreateMetadataAccountAccounts<'_, '_>,
     args: CreateMetadataAccountIxArgs,
 ) -> ProgramResult {
     create_metadata_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountAccounts<'_, '_>,
@@ -450,13 +559,20 @@ pub fn create_metadata_account_invoke_signed_with_program_id(
     let ix = create_metadata_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_invoke_signed(
     accounts: CreateMetadataAccountAccounts<'_, '_>,
     args: CreateMetadataAccountIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    create_metadata_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    create_metadata_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_verify_account_keys(
     accounts: CreateMetadataAccountAccounts<'_, '_>,
     keys: CreateMetadataAccountKeys,
@@ -476,6 +592,7 @@ pub fn create_metadata_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_verify_writable_privileges<'me, 'info>(
     accounts: CreateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -486,6 +603,7 @@ pub fn create_metadata_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_verify_signer_privileges<'me, 'info>(
     accounts: CreateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -496,6 +614,7 @@ pub fn create_metadata_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_verify_account_privileges<'me, 'info>(
     accounts: CreateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -505,6 +624,7 @@ pub fn create_metadata_account_verify_account_privileges<'me, 'info>(
 }
 pub const UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpdateMetadataAccountAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -518,6 +638,7 @@ pub struct UpdateMetadataAccountKeys {
     ///Update authority key
     pub update_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpdateMetadataAccountAccounts<'_, '_>> for UpdateMetadataAccountKeys {
     fn from(accounts: UpdateMetadataAccountAccounts) -> Self {
         Self {
@@ -526,7 +647,8 @@ impl From<UpdateMetadataAccountAccounts<'_, '_>> for UpdateMetadataAccountKeys {
         }
     }
 }
-impl From<UpdateMetadataAccountKeys> for [AccountMeta; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<UpdateMetadataAccountKeys>
+for [AccountMeta; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: UpdateMetadataAccountKeys) -> Self {
         [
             AccountMeta {
@@ -542,7 +664,8 @@ impl From<UpdateMetadataAccountKeys> for [AccountMeta; UPDATE_METADATA_ACCOUNT_I
         ]
     }
 }
-impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]> for UpdateMetadataAccountKeys {
+impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountKeys {
     fn from(pubkeys: [Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -550,17 +673,19 @@ impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]> for UpdateMetadataA
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpdateMetadataAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpdateMetadataAccountAccounts<'_, 'info>) -> Self {
         [accounts.metadata.clone(), accounts.update_authority.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for UpdateMetadataAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for UpdateMetadataAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             update_authority: &arr[1],
@@ -581,37 +706,50 @@ impl From<UpdateMetadataAccountIxArgs> for UpdateMetadataAccountIxData {
     }
 }
 impl UpdateMetadataAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != UPDATE_METADATA_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_METADATA_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(UpdateMetadataAccountIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[UPDATE_METADATA_ACCOUNT_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateMetadataAccountIxData {
+    const NAME: &'static str = "UpdateMetadataAccount";
+    const DISCM: &'static [u8] = &[UPDATE_METADATA_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = UpdateMetadataAccountKeys;
+    type Args = UpdateMetadataAccountIxArgs;
+}
 pub fn update_metadata_account_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdateMetadataAccountKeys,
     args: UpdateMetadataAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UPDATE_METADATA_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     let data: UpdateMetadataAccountIxData = args.into();
     Ok(Instruction {
@@ -623,9 +761,10 @@ pub fn update_metadata_account_ix_with_program_id(
 pub fn update_metadata_account_ix(
     keys: UpdateMetadataAccountKeys,
     args: UpdateMetadataAccountIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     update_metadata_account_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
@@ -635,12 +774,14 @@ pub fn update_metadata_account_invoke_with_program_id(
     let ix = update_metadata_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_invoke(
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
     args: UpdateMetadataAccountIxArgs,
 ) -> ProgramResult {
     update_metadata_account_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
@@ -651,13 +792,20 @@ pub fn update_metadata_account_invoke_signed_with_program_id(
     let ix = update_metadata_account_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_invoke_signed(
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
     args: UpdateMetadataAccountIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    update_metadata_account_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    update_metadata_account_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_verify_account_keys(
     accounts: UpdateMetadataAccountAccounts<'_, '_>,
     keys: UpdateMetadataAccountKeys,
@@ -672,6 +820,7 @@ pub fn update_metadata_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_verify_writable_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -682,6 +831,7 @@ pub fn update_metadata_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_verify_signer_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -692,6 +842,7 @@ pub fn update_metadata_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_verify_account_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -701,6 +852,7 @@ pub fn update_metadata_account_verify_account_privileges<'me, 'info>(
 }
 pub const DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 13;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeprecatedCreateMasterEditionAccounts<'me, 'info> {
     ///Unallocated edition V1 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
@@ -758,7 +910,9 @@ pub struct DeprecatedCreateMasterEditionKeys {
     ///One time authorization printing mint authority - must be provided if using max supply. THIS WILL TRANSFER AUTHORITY AWAY FROM THIS KEY.
     pub one_time_printing_authorization_mint_authority: Pubkey,
 }
-impl From<DeprecatedCreateMasterEditionAccounts<'_, '_>> for DeprecatedCreateMasterEditionKeys {
+#[cfg(feature = "cpi")]
+impl From<DeprecatedCreateMasterEditionAccounts<'_, '_>>
+for DeprecatedCreateMasterEditionKeys {
     fn from(accounts: DeprecatedCreateMasterEditionAccounts) -> Self {
         Self {
             edition: *accounts.edition.key,
@@ -782,8 +936,7 @@ impl From<DeprecatedCreateMasterEditionAccounts<'_, '_>> for DeprecatedCreateMas
     }
 }
 impl From<DeprecatedCreateMasterEditionKeys>
-    for [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
     fn from(keys: DeprecatedCreateMasterEditionKeys) -> Self {
         [
             AccountMeta {
@@ -855,9 +1008,10 @@ impl From<DeprecatedCreateMasterEditionKeys>
     }
 }
 impl From<[Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
-    for DeprecatedCreateMasterEditionKeys
-{
-    fn from(pubkeys: [Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]) -> Self {
+for DeprecatedCreateMasterEditionKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             edition: pubkeys[0],
             mint: pubkeys[1],
@@ -875,9 +1029,9 @@ impl From<[Pubkey; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeprecatedCreateMasterEditionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeprecatedCreateMasterEditionAccounts<'_, 'info>) -> Self {
         [
             accounts.edition.clone(),
@@ -892,15 +1046,16 @@ impl<'info> From<DeprecatedCreateMasterEditionAccounts<'_, 'info>>
             accounts.token_program.clone(),
             accounts.system_program.clone(),
             accounts.rent.clone(),
-            accounts
-                .one_time_printing_authorization_mint_authority
-                .clone(),
+            accounts.one_time_printing_authorization_mint_authority.clone(),
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
-    for DeprecatedCreateMasterEditionAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateMasterEditionAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -935,40 +1090,52 @@ impl From<DeprecatedCreateMasterEditionIxArgs> for DeprecatedCreateMasterEdition
     }
 }
 impl DeprecatedCreateMasterEditionIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(DeprecatedCreateMasterEditionIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(DeprecatedCreateMasterEditionIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedCreateMasterEditionIxData {
+    const NAME: &'static str = "DeprecatedCreateMasterEdition";
+    const DISCM: &'static [u8] = &[DEPRECATED_CREATE_MASTER_EDITION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedCreateMasterEditionKeys;
+    type Args = DeprecatedCreateMasterEditionIxArgs;
+}
 pub fn deprecated_create_master_edition_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedCreateMasterEditionKeys,
     args: DeprecatedCreateMasterEditionIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: DeprecatedCreateMasterEditionIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -979,24 +1146,31 @@ pub fn deprecated_create_master_edition_ix_with_program_id(
 pub fn deprecated_create_master_edition_ix(
     keys: DeprecatedCreateMasterEditionKeys,
     args: DeprecatedCreateMasterEditionIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_create_master_edition_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
     args: DeprecatedCreateMasterEditionIxArgs,
 ) -> ProgramResult {
     let keys: DeprecatedCreateMasterEditionKeys = accounts.into();
-    let ix = deprecated_create_master_edition_ix_with_program_id(program_id, keys, args)?;
+    let ix = deprecated_create_master_edition_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_invoke(
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
     args: DeprecatedCreateMasterEditionIxArgs,
 ) -> ProgramResult {
     deprecated_create_master_edition_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
@@ -1004,16 +1178,27 @@ pub fn deprecated_create_master_edition_invoke_signed_with_program_id(
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: DeprecatedCreateMasterEditionKeys = accounts.into();
-    let ix = deprecated_create_master_edition_ix_with_program_id(program_id, keys, args)?;
+    let ix = deprecated_create_master_edition_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_invoke_signed(
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
     args: DeprecatedCreateMasterEditionIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    deprecated_create_master_edition_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    deprecated_create_master_edition_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_verify_account_keys(
     accounts: DeprecatedCreateMasterEditionAccounts<'_, '_>,
     keys: DeprecatedCreateMasterEditionKeys,
@@ -1027,10 +1212,7 @@ pub fn deprecated_create_master_edition_verify_account_keys(
             &keys.one_time_printing_authorization_mint,
         ),
         (accounts.update_authority.key, &keys.update_authority),
-        (
-            accounts.printing_mint_authority.key,
-            &keys.printing_mint_authority,
-        ),
+        (accounts.printing_mint_authority.key, &keys.printing_mint_authority),
         (accounts.mint_authority.key, &keys.mint_authority),
         (accounts.metadata.key, &keys.metadata),
         (accounts.payer.key, &keys.payer),
@@ -1048,6 +1230,7 @@ pub fn deprecated_create_master_edition_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_verify_writable_privileges<'me, 'info>(
     accounts: DeprecatedCreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1063,6 +1246,7 @@ pub fn deprecated_create_master_edition_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_verify_signer_privileges<'me, 'info>(
     accounts: DeprecatedCreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1079,6 +1263,7 @@ pub fn deprecated_create_master_edition_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_master_edition_verify_account_privileges<'me, 'info>(
     accounts: DeprecatedCreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1086,10 +1271,13 @@ pub fn deprecated_create_master_edition_verify_account_privileges<'me, 'info>(
     deprecated_create_master_edition_verify_signer_privileges(accounts)?;
     Ok(())
 }
-pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN:
-    usize = 16;
+pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN: usize = 16;
 #[derive(Copy, Clone, Debug)]
-pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info> {
+#[cfg(feature = "cpi")]
+pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<
+    'me,
+    'info,
+> {
     ///New Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
     ///New Edition V1 (pda of ['metadata', program id, mint id, 'edition'])
@@ -1158,10 +1346,12 @@ pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
     ///Reservation List - If present, and you are on this list, you can get an edition number given by your position on the list.
     pub reservation_list: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>>
-    for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
-{
-    fn from(accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts) -> Self {
+for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
+    fn from(
+        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts,
+    ) -> Self {
         Self {
             metadata: *accounts.metadata.key,
             edition: *accounts.edition.key,
@@ -1183,10 +1373,10 @@ impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_,
     }
 }
 impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys>
-    for [AccountMeta;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN]
-{
-    fn from(keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys) -> Self {
+for [AccountMeta; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(
+        keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
+    ) -> Self {
         [
             AccountMeta {
                 pubkey: keys.metadata,
@@ -1271,12 +1461,9 @@ impl From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys>
         ]
     }
 }
-impl
-    From<
-        [Pubkey;
-            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
-    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys
-{
+impl From<
+    [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys {
     fn from(
         pubkeys: [Pubkey; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -1300,12 +1487,18 @@ impl
         }
     }
 }
-impl<'info> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>>
-    for [AccountInfo<'info>;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN]
-{
+#[cfg(feature = "cpi")]
+impl<
+    'info,
+> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>>
+for [AccountInfo<
+    'info,
+>; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(
-        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, 'info>,
+        accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<
+            '_,
+            'info,
+        >,
     ) -> Self {
         [
             accounts.metadata.clone(),
@@ -1327,12 +1520,15 @@ impl<'info> From<DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccoun
         ]
     }
 }
-impl<'me, 'info>
-    From<
-        &'me [AccountInfo<'info>;
-                 DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
-    > for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<
+        'info,
+    >; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN],
+> for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<
             'info,
@@ -1362,7 +1558,7 @@ pub const DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData;
 impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
@@ -1370,59 +1566,82 @@ impl DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData {
         if maybe_discm
             != DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM
         {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
-                    maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                        maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[
-            DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
-        ])
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer
+            .write_all(
+                &[
+                    DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+                ],
+            )
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction
+for DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData {
+    const NAME: &'static str = "DeprecatedMintNewEditionFromMasterEditionViaPrintingToken";
+    const DISCM: &'static [u8] = &[
+        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys;
+    type Args = ();
+}
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta;
-        DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_PRINTING_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
-        data: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData.try_to_vec()?,
+        data: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenIxData
+            .try_to_vec()?,
     })
 }
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_ix(
     keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
         crate::ID,
         keys,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
 ) -> ProgramResult {
-    let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts.into();
+    let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts
+        .into();
     let ix = deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
-        program_id, keys,
+        program_id,
+        keys,
     )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke(
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
 ) -> ProgramResult {
@@ -1431,17 +1650,21 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke
         accounts,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts.into();
+    let keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys = accounts
+        .into();
     let ix = deprecated_mint_new_edition_from_master_edition_via_printing_token_ix_with_program_id(
-        program_id, keys,
+        program_id,
+        keys,
     )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke_signed(
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
@@ -1452,6 +1675,7 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_invoke
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_account_keys(
     accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'_, '_>,
     keys: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenKeys,
@@ -1463,17 +1687,11 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
         (accounts.mint.key, &keys.mint),
         (accounts.mint_authority.key, &keys.mint_authority),
         (accounts.printing_mint.key, &keys.printing_mint),
-        (
-            accounts.master_token_account.key,
-            &keys.master_token_account,
-        ),
+        (accounts.master_token_account.key, &keys.master_token_account),
         (accounts.edition_marker.key, &keys.edition_marker),
         (accounts.burn_authority.key, &keys.burn_authority),
         (accounts.payer.key, &keys.payer),
-        (
-            accounts.master_update_authority.key,
-            &keys.master_update_authority,
-        ),
+        (accounts.master_update_authority.key, &keys.master_update_authority),
         (accounts.master_metadata.key, &keys.master_metadata),
         (accounts.token_program.key, &keys.token_program),
         (accounts.system_program.key, &keys.system_program),
@@ -1486,11 +1704,15 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_writable_privileges<
     'me,
     'info,
 >(
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<
+        'me,
+        'info,
+    >,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
         accounts.metadata,
@@ -1508,11 +1730,15 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_signer_privileges<
     'me,
     'info,
 >(
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<
+        'me,
+        'info,
+    >,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [
         accounts.mint_authority,
@@ -1525,11 +1751,15 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_account_privileges<
     'me,
     'info,
 >(
-    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<'me, 'info>,
+    accounts: DeprecatedMintNewEditionFromMasterEditionViaPrintingTokenAccounts<
+        'me,
+        'info,
+    >,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     deprecated_mint_new_edition_from_master_edition_via_printing_token_verify_writable_privileges(
         accounts,
@@ -1541,6 +1771,7 @@ pub fn deprecated_mint_new_edition_from_master_edition_via_printing_token_verify
 }
 pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info> {
     ///Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
@@ -1558,9 +1789,9 @@ pub struct UpdatePrimarySaleHappenedViaTokenKeys {
     ///Account containing tokens from the metadata's mint
     pub token: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>>
-    for UpdatePrimarySaleHappenedViaTokenKeys
-{
+for UpdatePrimarySaleHappenedViaTokenKeys {
     fn from(accounts: UpdatePrimarySaleHappenedViaTokenAccounts) -> Self {
         Self {
             metadata: *accounts.metadata.key,
@@ -1570,8 +1801,7 @@ impl From<UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>>
     }
 }
 impl From<UpdatePrimarySaleHappenedViaTokenKeys>
-    for [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(keys: UpdatePrimarySaleHappenedViaTokenKeys) -> Self {
         [
             AccountMeta {
@@ -1593,9 +1823,10 @@ impl From<UpdatePrimarySaleHappenedViaTokenKeys>
     }
 }
 impl From<[Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for UpdatePrimarySaleHappenedViaTokenKeys
-{
-    fn from(pubkeys: [Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]) -> Self {
+for UpdatePrimarySaleHappenedViaTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: pubkeys[0],
             owner: pubkeys[1],
@@ -1603,23 +1834,23 @@ impl From<[Pubkey; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpdatePrimarySaleHappenedViaTokenAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, 'info>) -> Self {
-        [
-            accounts.metadata.clone(),
-            accounts.owner.clone(),
-            accounts.token.clone(),
-        ]
+        [accounts.metadata.clone(), accounts.owner.clone(), accounts.token.clone()]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN]>
+for UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             metadata: &arr[0],
@@ -1632,36 +1863,50 @@ pub const UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM: u8 = 4u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpdatePrimarySaleHappenedViaTokenIxData;
 impl UpdatePrimarySaleHappenedViaTokenIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdatePrimarySaleHappenedViaTokenIxData {
+    const NAME: &'static str = "UpdatePrimarySaleHappenedViaToken";
+    const DISCM: &'static [u8] = &[UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = UpdatePrimarySaleHappenedViaTokenKeys;
+    type Args = ();
+}
 pub fn update_primary_sale_happened_via_token_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdatePrimarySaleHappenedViaTokenKeys,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; UPDATE_PRIMARY_SALE_HAPPENED_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -1670,37 +1915,52 @@ pub fn update_primary_sale_happened_via_token_ix_with_program_id(
 }
 pub fn update_primary_sale_happened_via_token_ix(
     keys: UpdatePrimarySaleHappenedViaTokenKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     update_primary_sale_happened_via_token_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
 ) -> ProgramResult {
     let keys: UpdatePrimarySaleHappenedViaTokenKeys = accounts.into();
-    let ix = update_primary_sale_happened_via_token_ix_with_program_id(program_id, keys)?;
+    let ix = update_primary_sale_happened_via_token_ix_with_program_id(
+        program_id,
+        keys,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_invoke(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
 ) -> ProgramResult {
     update_primary_sale_happened_via_token_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: UpdatePrimarySaleHappenedViaTokenKeys = accounts.into();
-    let ix = update_primary_sale_happened_via_token_ix_with_program_id(program_id, keys)?;
+    let ix = update_primary_sale_happened_via_token_ix_with_program_id(
+        program_id,
+        keys,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_invoke_signed(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    update_primary_sale_happened_via_token_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    update_primary_sale_happened_via_token_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_verify_account_keys(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'_, '_>,
     keys: UpdatePrimarySaleHappenedViaTokenKeys,
@@ -1716,6 +1976,7 @@ pub fn update_primary_sale_happened_via_token_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_verify_writable_privileges<'me, 'info>(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1726,6 +1987,7 @@ pub fn update_primary_sale_happened_via_token_verify_writable_privileges<'me, 'i
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_verify_signer_privileges<'me, 'info>(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1736,6 +1998,7 @@ pub fn update_primary_sale_happened_via_token_verify_signer_privileges<'me, 'inf
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_primary_sale_happened_via_token_verify_account_privileges<'me, 'info>(
     accounts: UpdatePrimarySaleHappenedViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1745,6 +2008,7 @@ pub fn update_primary_sale_happened_via_token_verify_account_privileges<'me, 'in
 }
 pub const DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeprecatedSetReservationListAccounts<'me, 'info> {
     ///Master Edition V1 key (pda of ['metadata', program id, mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
@@ -1762,7 +2026,9 @@ pub struct DeprecatedSetReservationListKeys {
     ///The resource you tied the reservation list too
     pub resource: Pubkey,
 }
-impl From<DeprecatedSetReservationListAccounts<'_, '_>> for DeprecatedSetReservationListKeys {
+#[cfg(feature = "cpi")]
+impl From<DeprecatedSetReservationListAccounts<'_, '_>>
+for DeprecatedSetReservationListKeys {
     fn from(accounts: DeprecatedSetReservationListAccounts) -> Self {
         Self {
             master_edition: *accounts.master_edition.key,
@@ -1772,8 +2038,7 @@ impl From<DeprecatedSetReservationListAccounts<'_, '_>> for DeprecatedSetReserva
     }
 }
 impl From<DeprecatedSetReservationListKeys>
-    for [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
     fn from(keys: DeprecatedSetReservationListKeys) -> Self {
         [
             AccountMeta {
@@ -1795,8 +2060,7 @@ impl From<DeprecatedSetReservationListKeys>
     }
 }
 impl From<[Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
-    for DeprecatedSetReservationListKeys
-{
+for DeprecatedSetReservationListKeys {
     fn from(pubkeys: [Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             master_edition: pubkeys[0],
@@ -1805,9 +2069,9 @@ impl From<[Pubkey; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeprecatedSetReservationListAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeprecatedSetReservationListAccounts<'_, 'info>) -> Self {
         [
             accounts.master_edition.clone(),
@@ -1816,9 +2080,12 @@ impl<'info> From<DeprecatedSetReservationListAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
-    for DeprecatedSetReservationListAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedSetReservationListAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -1843,40 +2110,52 @@ impl From<DeprecatedSetReservationListIxArgs> for DeprecatedSetReservationListIx
     }
 }
 impl DeprecatedSetReservationListIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPRECATED_SET_RESERVATION_LIST_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_SET_RESERVATION_LIST_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_SET_RESERVATION_LIST_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(DeprecatedSetReservationListIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(DeprecatedSetReservationListIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPRECATED_SET_RESERVATION_LIST_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedSetReservationListIxData {
+    const NAME: &'static str = "DeprecatedSetReservationList";
+    const DISCM: &'static [u8] = &[DEPRECATED_SET_RESERVATION_LIST_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedSetReservationListKeys;
+    type Args = DeprecatedSetReservationListIxArgs;
+}
 pub fn deprecated_set_reservation_list_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedSetReservationListKeys,
     args: DeprecatedSetReservationListIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_SET_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: DeprecatedSetReservationListIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -1887,9 +2166,10 @@ pub fn deprecated_set_reservation_list_ix_with_program_id(
 pub fn deprecated_set_reservation_list_ix(
     keys: DeprecatedSetReservationListKeys,
     args: DeprecatedSetReservationListIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_set_reservation_list_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
@@ -1899,12 +2179,14 @@ pub fn deprecated_set_reservation_list_invoke_with_program_id(
     let ix = deprecated_set_reservation_list_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_invoke(
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
     args: DeprecatedSetReservationListIxArgs,
 ) -> ProgramResult {
     deprecated_set_reservation_list_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
@@ -1915,13 +2197,20 @@ pub fn deprecated_set_reservation_list_invoke_signed_with_program_id(
     let ix = deprecated_set_reservation_list_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_invoke_signed(
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
     args: DeprecatedSetReservationListIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    deprecated_set_reservation_list_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    deprecated_set_reservation_list_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_verify_account_keys(
     accounts: DeprecatedSetReservationListAccounts<'_, '_>,
     keys: DeprecatedSetReservationListKeys,
@@ -1937,6 +2226,7 @@ pub fn deprecated_set_reservation_list_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_verify_writable_privileges<'me, 'info>(
     accounts: DeprecatedSetReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1947,6 +2237,7 @@ pub fn deprecated_set_reservation_list_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_verify_signer_privileges<'me, 'info>(
     accounts: DeprecatedSetReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1957,6 +2248,7 @@ pub fn deprecated_set_reservation_list_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_set_reservation_list_verify_account_privileges<'me, 'info>(
     accounts: DeprecatedSetReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -1966,6 +2258,7 @@ pub fn deprecated_set_reservation_list_verify_account_privileges<'me, 'info>(
 }
 pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeprecatedCreateReservationListAccounts<'me, 'info> {
     ///PDA for ReservationList of ['metadata', program id, master edition key, 'reservation', resource-key]
     pub reservation_list: &'me AccountInfo<'info>,
@@ -2003,7 +2296,9 @@ pub struct DeprecatedCreateReservationListKeys {
     ///Rent info
     pub rent: Pubkey,
 }
-impl From<DeprecatedCreateReservationListAccounts<'_, '_>> for DeprecatedCreateReservationListKeys {
+#[cfg(feature = "cpi")]
+impl From<DeprecatedCreateReservationListAccounts<'_, '_>>
+for DeprecatedCreateReservationListKeys {
     fn from(accounts: DeprecatedCreateReservationListAccounts) -> Self {
         Self {
             reservation_list: *accounts.reservation_list.key,
@@ -2018,8 +2313,7 @@ impl From<DeprecatedCreateReservationListAccounts<'_, '_>> for DeprecatedCreateR
     }
 }
 impl From<DeprecatedCreateReservationListKeys>
-    for [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
     fn from(keys: DeprecatedCreateReservationListKeys) -> Self {
         [
             AccountMeta {
@@ -2066,9 +2360,10 @@ impl From<DeprecatedCreateReservationListKeys>
     }
 }
 impl From<[Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
-    for DeprecatedCreateReservationListKeys
-{
-    fn from(pubkeys: [Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]) -> Self {
+for DeprecatedCreateReservationListKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             reservation_list: pubkeys[0],
             payer: pubkeys[1],
@@ -2081,9 +2376,9 @@ impl From<[Pubkey; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeprecatedCreateReservationListAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeprecatedCreateReservationListAccounts<'_, 'info>) -> Self {
         [
             accounts.reservation_list.clone(),
@@ -2097,11 +2392,16 @@ impl<'info> From<DeprecatedCreateReservationListAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
-    for DeprecatedCreateReservationListAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN]>
+for DeprecatedCreateReservationListAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             reservation_list: &arr[0],
@@ -2119,36 +2419,50 @@ pub const DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM: u8 = 6u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct DeprecatedCreateReservationListIxData;
 impl DeprecatedCreateReservationListIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedCreateReservationListIxData {
+    const NAME: &'static str = "DeprecatedCreateReservationList";
+    const DISCM: &'static [u8] = &[DEPRECATED_CREATE_RESERVATION_LIST_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedCreateReservationListKeys;
+    type Args = ();
+}
 pub fn deprecated_create_reservation_list_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedCreateReservationListKeys,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_CREATE_RESERVATION_LIST_IX_ACCOUNTS_LEN] = keys
+        .into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -2157,9 +2471,10 @@ pub fn deprecated_create_reservation_list_ix_with_program_id(
 }
 pub fn deprecated_create_reservation_list_ix(
     keys: DeprecatedCreateReservationListKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_create_reservation_list_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
@@ -2168,11 +2483,13 @@ pub fn deprecated_create_reservation_list_invoke_with_program_id(
     let ix = deprecated_create_reservation_list_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_invoke(
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
 ) -> ProgramResult {
     deprecated_create_reservation_list_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
@@ -2182,12 +2499,18 @@ pub fn deprecated_create_reservation_list_invoke_signed_with_program_id(
     let ix = deprecated_create_reservation_list_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_invoke_signed(
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    deprecated_create_reservation_list_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    deprecated_create_reservation_list_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_verify_account_keys(
     accounts: DeprecatedCreateReservationListAccounts<'_, '_>,
     keys: DeprecatedCreateReservationListKeys,
@@ -2208,6 +2531,7 @@ pub fn deprecated_create_reservation_list_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_verify_writable_privileges<'me, 'info>(
     accounts: DeprecatedCreateReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2218,6 +2542,7 @@ pub fn deprecated_create_reservation_list_verify_writable_privileges<'me, 'info>
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_verify_signer_privileges<'me, 'info>(
     accounts: DeprecatedCreateReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2228,6 +2553,7 @@ pub fn deprecated_create_reservation_list_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_create_reservation_list_verify_account_privileges<'me, 'info>(
     accounts: DeprecatedCreateReservationListAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2237,6 +2563,7 @@ pub fn deprecated_create_reservation_list_verify_account_privileges<'me, 'info>(
 }
 pub const SIGN_METADATA_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SignMetadataAccounts<'me, 'info> {
     ///Metadata (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
@@ -2250,6 +2577,7 @@ pub struct SignMetadataKeys {
     ///Creator
     pub creator: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SignMetadataAccounts<'_, '_>> for SignMetadataKeys {
     fn from(accounts: SignMetadataAccounts) -> Self {
         Self {
@@ -2282,16 +2610,16 @@ impl From<[Pubkey; SIGN_METADATA_IX_ACCOUNTS_LEN]> for SignMetadataKeys {
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SignMetadataAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN] {
     fn from(accounts: SignMetadataAccounts<'_, 'info>) -> Self {
         [accounts.metadata.clone(), accounts.creator.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN]>
-    for SignMetadataAccounts<'me, 'info>
-{
+for SignMetadataAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; SIGN_METADATA_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: &arr[0],
@@ -2303,35 +2631,48 @@ pub const SIGN_METADATA_IX_DISCM: u8 = 7u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignMetadataIxData;
 impl SignMetadataIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != SIGN_METADATA_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SIGN_METADATA_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SIGN_METADATA_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[SIGN_METADATA_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SignMetadataIxData {
+    const NAME: &'static str = "SignMetadata";
+    const DISCM: &'static [u8] = &[SIGN_METADATA_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SIGN_METADATA_IX_ACCOUNTS_LEN;
+    type Keys = SignMetadataKeys;
+    type Args = ();
+}
 pub fn sign_metadata_ix_with_program_id(
     program_id: Pubkey,
     keys: SignMetadataKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SIGN_METADATA_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -2339,9 +2680,10 @@ pub fn sign_metadata_ix_with_program_id(
         data: SignMetadataIxData.try_to_vec()?,
     })
 }
-pub fn sign_metadata_ix(keys: SignMetadataKeys) -> std::io::Result<Instruction> {
+pub fn sign_metadata_ix(keys: SignMetadataKeys) -> borsh::io::Result<Instruction> {
     sign_metadata_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SignMetadataAccounts<'_, '_>,
@@ -2350,9 +2692,11 @@ pub fn sign_metadata_invoke_with_program_id(
     let ix = sign_metadata_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_invoke(accounts: SignMetadataAccounts<'_, '_>) -> ProgramResult {
     sign_metadata_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SignMetadataAccounts<'_, '_>,
@@ -2362,12 +2706,14 @@ pub fn sign_metadata_invoke_signed_with_program_id(
     let ix = sign_metadata_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_invoke_signed(
     accounts: SignMetadataAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     sign_metadata_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_verify_account_keys(
     accounts: SignMetadataAccounts<'_, '_>,
     keys: SignMetadataKeys,
@@ -2382,6 +2728,7 @@ pub fn sign_metadata_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_verify_writable_privileges<'me, 'info>(
     accounts: SignMetadataAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2392,6 +2739,7 @@ pub fn sign_metadata_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_verify_signer_privileges<'me, 'info>(
     accounts: SignMetadataAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2402,6 +2750,7 @@ pub fn sign_metadata_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn sign_metadata_verify_account_privileges<'me, 'info>(
     accounts: SignMetadataAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2411,6 +2760,7 @@ pub fn sign_metadata_verify_account_privileges<'me, 'info>(
 }
 pub const DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info> {
     ///Destination account
     pub destination: &'me AccountInfo<'info>,
@@ -2452,9 +2802,9 @@ pub struct DeprecatedMintPrintingTokensViaTokenKeys {
     ///Rent
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>>
-    for DeprecatedMintPrintingTokensViaTokenKeys
-{
+for DeprecatedMintPrintingTokensViaTokenKeys {
     fn from(accounts: DeprecatedMintPrintingTokensViaTokenAccounts) -> Self {
         Self {
             destination: *accounts.destination.key,
@@ -2472,8 +2822,7 @@ impl From<DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>>
     }
 }
 impl From<DeprecatedMintPrintingTokensViaTokenKeys>
-    for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(keys: DeprecatedMintPrintingTokensViaTokenKeys) -> Self {
         [
             AccountMeta {
@@ -2525,9 +2874,10 @@ impl From<DeprecatedMintPrintingTokensViaTokenKeys>
     }
 }
 impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for DeprecatedMintPrintingTokensViaTokenKeys
-{
-    fn from(pubkeys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]) -> Self {
+for DeprecatedMintPrintingTokensViaTokenKeys {
+    fn from(
+        pubkeys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             destination: pubkeys[0],
             token: pubkeys[1],
@@ -2541,9 +2891,9 @@ impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeprecatedMintPrintingTokensViaTokenAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, 'info>) -> Self {
         [
             accounts.destination.clone(),
@@ -2558,12 +2908,17 @@ impl<'info> From<DeprecatedMintPrintingTokensViaTokenAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
+> for DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             destination: &arr[0],
@@ -2589,48 +2944,58 @@ pub struct DeprecatedMintPrintingTokensViaTokenIxData(
     pub DeprecatedMintPrintingTokensViaTokenIxArgs,
 );
 impl From<DeprecatedMintPrintingTokensViaTokenIxArgs>
-    for DeprecatedMintPrintingTokensViaTokenIxData
-{
+for DeprecatedMintPrintingTokensViaTokenIxData {
     fn from(args: DeprecatedMintPrintingTokensViaTokenIxArgs) -> Self {
         Self(args)
     }
 }
 impl DeprecatedMintPrintingTokensViaTokenIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(
-            DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(&mut reader)?,
-        ))
+        Ok(Self(DeprecatedMintPrintingTokensViaTokenIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedMintPrintingTokensViaTokenIxData {
+    const NAME: &'static str = "DeprecatedMintPrintingTokensViaToken";
+    const DISCM: &'static [u8] = &[DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintPrintingTokensViaTokenKeys;
+    type Args = DeprecatedMintPrintingTokensViaTokenIxArgs;
+}
 pub fn deprecated_mint_printing_tokens_via_token_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedMintPrintingTokensViaTokenKeys,
     args: DeprecatedMintPrintingTokensViaTokenIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: DeprecatedMintPrintingTokensViaTokenIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -2641,24 +3006,35 @@ pub fn deprecated_mint_printing_tokens_via_token_ix_with_program_id(
 pub fn deprecated_mint_printing_tokens_via_token_ix(
     keys: DeprecatedMintPrintingTokensViaTokenKeys,
     args: DeprecatedMintPrintingTokensViaTokenIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_mint_printing_tokens_via_token_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
     args: DeprecatedMintPrintingTokensViaTokenIxArgs,
 ) -> ProgramResult {
     let keys: DeprecatedMintPrintingTokensViaTokenKeys = accounts.into();
-    let ix = deprecated_mint_printing_tokens_via_token_ix_with_program_id(program_id, keys, args)?;
+    let ix = deprecated_mint_printing_tokens_via_token_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_invoke(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
     args: DeprecatedMintPrintingTokensViaTokenIxArgs,
 ) -> ProgramResult {
-    deprecated_mint_printing_tokens_via_token_invoke_with_program_id(crate::ID, accounts, args)
+    deprecated_mint_printing_tokens_via_token_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
@@ -2666,9 +3042,14 @@ pub fn deprecated_mint_printing_tokens_via_token_invoke_signed_with_program_id(
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: DeprecatedMintPrintingTokensViaTokenKeys = accounts.into();
-    let ix = deprecated_mint_printing_tokens_via_token_ix_with_program_id(program_id, keys, args)?;
+    let ix = deprecated_mint_printing_tokens_via_token_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_invoke_signed(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
     args: DeprecatedMintPrintingTokensViaTokenIxArgs,
@@ -2681,6 +3062,7 @@ pub fn deprecated_mint_printing_tokens_via_token_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_verify_account_keys(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'_, '_>,
     keys: DeprecatedMintPrintingTokensViaTokenKeys,
@@ -2705,6 +3087,7 @@ pub fn deprecated_mint_printing_tokens_via_token_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_verify_writable_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2720,6 +3103,7 @@ pub fn deprecated_mint_printing_tokens_via_token_verify_writable_privileges<'me,
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_verify_signer_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2730,6 +3114,7 @@ pub fn deprecated_mint_printing_tokens_via_token_verify_signer_privileges<'me, '
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_via_token_verify_account_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2739,6 +3124,7 @@ pub fn deprecated_mint_printing_tokens_via_token_verify_account_privileges<'me,
 }
 pub const DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct DeprecatedMintPrintingTokensAccounts<'me, 'info> {
     ///Destination account
     pub destination: &'me AccountInfo<'info>,
@@ -2772,7 +3158,9 @@ pub struct DeprecatedMintPrintingTokensKeys {
     ///Rent
     pub rent: Pubkey,
 }
-impl From<DeprecatedMintPrintingTokensAccounts<'_, '_>> for DeprecatedMintPrintingTokensKeys {
+#[cfg(feature = "cpi")]
+impl From<DeprecatedMintPrintingTokensAccounts<'_, '_>>
+for DeprecatedMintPrintingTokensKeys {
     fn from(accounts: DeprecatedMintPrintingTokensAccounts) -> Self {
         Self {
             destination: *accounts.destination.key,
@@ -2786,8 +3174,7 @@ impl From<DeprecatedMintPrintingTokensAccounts<'_, '_>> for DeprecatedMintPrinti
     }
 }
 impl From<DeprecatedMintPrintingTokensKeys>
-    for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] {
     fn from(keys: DeprecatedMintPrintingTokensKeys) -> Self {
         [
             AccountMeta {
@@ -2829,8 +3216,7 @@ impl From<DeprecatedMintPrintingTokensKeys>
     }
 }
 impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
-    for DeprecatedMintPrintingTokensKeys
-{
+for DeprecatedMintPrintingTokensKeys {
     fn from(pubkeys: [Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             destination: pubkeys[0],
@@ -2843,9 +3229,9 @@ impl From<[Pubkey; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<DeprecatedMintPrintingTokensAccounts<'_, 'info>>
-    for [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] {
     fn from(accounts: DeprecatedMintPrintingTokensAccounts<'_, 'info>) -> Self {
         [
             accounts.destination.clone(),
@@ -2858,9 +3244,12 @@ impl<'info> From<DeprecatedMintPrintingTokensAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
-    for DeprecatedMintPrintingTokensAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN]>
+for DeprecatedMintPrintingTokensAccounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -2889,40 +3278,52 @@ impl From<DeprecatedMintPrintingTokensIxArgs> for DeprecatedMintPrintingTokensIx
     }
 }
 impl DeprecatedMintPrintingTokensIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(DeprecatedMintPrintingTokensIxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(DeprecatedMintPrintingTokensIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for DeprecatedMintPrintingTokensIxData {
+    const NAME: &'static str = "DeprecatedMintPrintingTokens";
+    const DISCM: &'static [u8] = &[DEPRECATED_MINT_PRINTING_TOKENS_IX_DISCM];
+    const ACCOUNTS_LEN: usize = DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN;
+    type Keys = DeprecatedMintPrintingTokensKeys;
+    type Args = DeprecatedMintPrintingTokensIxArgs;
+}
 pub fn deprecated_mint_printing_tokens_ix_with_program_id(
     program_id: Pubkey,
     keys: DeprecatedMintPrintingTokensKeys,
     args: DeprecatedMintPrintingTokensIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; DEPRECATED_MINT_PRINTING_TOKENS_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: DeprecatedMintPrintingTokensIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -2933,9 +3334,10 @@ pub fn deprecated_mint_printing_tokens_ix_with_program_id(
 pub fn deprecated_mint_printing_tokens_ix(
     keys: DeprecatedMintPrintingTokensKeys,
     args: DeprecatedMintPrintingTokensIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     deprecated_mint_printing_tokens_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_invoke_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
@@ -2945,12 +3347,14 @@ pub fn deprecated_mint_printing_tokens_invoke_with_program_id(
     let ix = deprecated_mint_printing_tokens_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_invoke(
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
     args: DeprecatedMintPrintingTokensIxArgs,
 ) -> ProgramResult {
     deprecated_mint_printing_tokens_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
@@ -2961,13 +3365,20 @@ pub fn deprecated_mint_printing_tokens_invoke_signed_with_program_id(
     let ix = deprecated_mint_printing_tokens_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_invoke_signed(
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
     args: DeprecatedMintPrintingTokensIxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    deprecated_mint_printing_tokens_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    deprecated_mint_printing_tokens_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_verify_account_keys(
     accounts: DeprecatedMintPrintingTokensAccounts<'_, '_>,
     keys: DeprecatedMintPrintingTokensKeys,
@@ -2987,6 +3398,7 @@ pub fn deprecated_mint_printing_tokens_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_verify_writable_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -2997,6 +3409,7 @@ pub fn deprecated_mint_printing_tokens_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_verify_signer_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3007,6 +3420,7 @@ pub fn deprecated_mint_printing_tokens_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn deprecated_mint_printing_tokens_verify_account_privileges<'me, 'info>(
     accounts: DeprecatedMintPrintingTokensAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3016,6 +3430,7 @@ pub fn deprecated_mint_printing_tokens_verify_account_privileges<'me, 'info>(
 }
 pub const CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateMasterEditionAccounts<'me, 'info> {
     ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
@@ -3057,6 +3472,7 @@ pub struct CreateMasterEditionKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateMasterEditionAccounts<'_, '_>> for CreateMasterEditionKeys {
     fn from(accounts: CreateMasterEditionAccounts) -> Self {
         Self {
@@ -3072,7 +3488,8 @@ impl From<CreateMasterEditionAccounts<'_, '_>> for CreateMasterEditionKeys {
         }
     }
 }
-impl From<CreateMasterEditionKeys> for [AccountMeta; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
+impl From<CreateMasterEditionKeys>
+for [AccountMeta; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
     fn from(keys: CreateMasterEditionKeys) -> Self {
         [
             AccountMeta {
@@ -3138,9 +3555,9 @@ impl From<[Pubkey; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]> for CreateMasterEditi
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateMasterEditionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateMasterEditionAccounts<'_, 'info>) -> Self {
         [
             accounts.edition.clone(),
@@ -3155,10 +3572,12 @@ impl<'info> From<CreateMasterEditionAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]>
-    for CreateMasterEditionAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN]) -> Self {
+for CreateMasterEditionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             edition: &arr[0],
             mint: &arr[1],
@@ -3186,37 +3605,50 @@ impl From<CreateMasterEditionIxArgs> for CreateMasterEditionIxData {
     }
 }
 impl CreateMasterEditionIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CREATE_MASTER_EDITION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_MASTER_EDITION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(CreateMasterEditionIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CREATE_MASTER_EDITION_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMasterEditionIxData {
+    const NAME: &'static str = "CreateMasterEdition";
+    const DISCM: &'static [u8] = &[CREATE_MASTER_EDITION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN;
+    type Keys = CreateMasterEditionKeys;
+    type Args = CreateMasterEditionIxArgs;
+}
 pub fn create_master_edition_ix_with_program_id(
     program_id: Pubkey,
     keys: CreateMasterEditionKeys,
     args: CreateMasterEditionIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CREATE_MASTER_EDITION_IX_ACCOUNTS_LEN] = keys.into();
     let data: CreateMasterEditionIxData = args.into();
     Ok(Instruction {
@@ -3228,9 +3660,10 @@ pub fn create_master_edition_ix_with_program_id(
 pub fn create_master_edition_ix(
     keys: CreateMasterEditionKeys,
     args: CreateMasterEditionIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     create_master_edition_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionAccounts<'_, '_>,
@@ -3240,12 +3673,14 @@ pub fn create_master_edition_invoke_with_program_id(
     let ix = create_master_edition_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_invoke(
     accounts: CreateMasterEditionAccounts<'_, '_>,
     args: CreateMasterEditionIxArgs,
 ) -> ProgramResult {
     create_master_edition_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionAccounts<'_, '_>,
@@ -3256,6 +3691,7 @@ pub fn create_master_edition_invoke_signed_with_program_id(
     let ix = create_master_edition_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_invoke_signed(
     accounts: CreateMasterEditionAccounts<'_, '_>,
     args: CreateMasterEditionIxArgs,
@@ -3263,6 +3699,7 @@ pub fn create_master_edition_invoke_signed(
 ) -> ProgramResult {
     create_master_edition_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_verify_account_keys(
     accounts: CreateMasterEditionAccounts<'_, '_>,
     keys: CreateMasterEditionKeys,
@@ -3284,6 +3721,7 @@ pub fn create_master_edition_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_verify_writable_privileges<'me, 'info>(
     accounts: CreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3294,6 +3732,7 @@ pub fn create_master_edition_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_verify_signer_privileges<'me, 'info>(
     accounts: CreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3308,6 +3747,7 @@ pub fn create_master_edition_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_verify_account_privileges<'me, 'info>(
     accounts: CreateMasterEditionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3317,6 +3757,7 @@ pub fn create_master_edition_verify_account_privileges<'me, 'info>(
 }
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN: usize = 14;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info> {
     ///New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: &'me AccountInfo<'info>,
@@ -3378,9 +3819,9 @@ pub struct MintNewEditionFromMasterEditionViaTokenKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>>
-    for MintNewEditionFromMasterEditionViaTokenKeys
-{
+for MintNewEditionFromMasterEditionViaTokenKeys {
     fn from(accounts: MintNewEditionFromMasterEditionViaTokenAccounts) -> Self {
         Self {
             new_metadata: *accounts.new_metadata.key,
@@ -3401,8 +3842,7 @@ impl From<MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>>
     }
 }
 impl From<MintNewEditionFromMasterEditionViaTokenKeys>
-    for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] {
     fn from(keys: MintNewEditionFromMasterEditionViaTokenKeys) -> Self {
         [
             AccountMeta {
@@ -3479,8 +3919,7 @@ impl From<MintNewEditionFromMasterEditionViaTokenKeys>
     }
 }
 impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for MintNewEditionFromMasterEditionViaTokenKeys
-{
+for MintNewEditionFromMasterEditionViaTokenKeys {
     fn from(
         pubkeys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -3502,10 +3941,14 @@ impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LE
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<MintNewEditionFromMasterEditionViaTokenAccounts<'_, 'info>>
-    for [AccountInfo<'info>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN]
-{
-    fn from(accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, 'info>) -> Self {
+for [AccountInfo<
+    'info,
+>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, 'info>,
+    ) -> Self {
         [
             accounts.new_metadata.clone(),
             accounts.new_edition.clone(),
@@ -3524,13 +3967,19 @@ impl<'info> From<MintNewEditionFromMasterEditionViaTokenAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info>
-    From<&'me [AccountInfo<'info>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN]>
-    for MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<
+        'info,
+    >; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
+> for MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>;
-                 MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             new_metadata: &arr[0],
@@ -3554,56 +4003,72 @@ pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM: u8 = 11u8;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintNewEditionFromMasterEditionViaTokenIxArgs {
-    pub mint_new_edition_from_master_edition_via_token_args:
-        MintNewEditionFromMasterEditionViaTokenArgs,
+    pub mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct MintNewEditionFromMasterEditionViaTokenIxData(
     pub MintNewEditionFromMasterEditionViaTokenIxArgs,
 );
 impl From<MintNewEditionFromMasterEditionViaTokenIxArgs>
-    for MintNewEditionFromMasterEditionViaTokenIxData
-{
+for MintNewEditionFromMasterEditionViaTokenIxData {
     fn from(args: MintNewEditionFromMasterEditionViaTokenIxArgs) -> Self {
         Self(args)
     }
 }
 impl MintNewEditionFromMasterEditionViaTokenIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM,
+                        maybe_discm
+                    ),
                 ),
-            ));
-        }
-        Ok(Self(
-            MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(&mut reader)?,
-        ))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+            );
+        }
+        Ok(
+            Self(
+                MintNewEditionFromMasterEditionViaTokenIxArgs::deserialize(&mut reader)?,
+            ),
+        )
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for MintNewEditionFromMasterEditionViaTokenIxData {
+    const NAME: &'static str = "MintNewEditionFromMasterEditionViaToken";
+    const DISCM: &'static [u8] = &[
+        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN;
+    type Keys = MintNewEditionFromMasterEditionViaTokenKeys;
+    type Args = MintNewEditionFromMasterEditionViaTokenIxArgs;
+}
 pub fn mint_new_edition_from_master_edition_via_token_ix_with_program_id(
     program_id: Pubkey,
     keys: MintNewEditionFromMasterEditionViaTokenKeys,
     args: MintNewEditionFromMasterEditionViaTokenIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: MintNewEditionFromMasterEditionViaTokenIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -3614,25 +4079,39 @@ pub fn mint_new_edition_from_master_edition_via_token_ix_with_program_id(
 pub fn mint_new_edition_from_master_edition_via_token_ix(
     keys: MintNewEditionFromMasterEditionViaTokenKeys,
     args: MintNewEditionFromMasterEditionViaTokenIxArgs,
-) -> std::io::Result<Instruction> {
-    mint_new_edition_from_master_edition_via_token_ix_with_program_id(crate::ID, keys, args)
+) -> borsh::io::Result<Instruction> {
+    mint_new_edition_from_master_edition_via_token_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_token_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
     args: MintNewEditionFromMasterEditionViaTokenIxArgs,
 ) -> ProgramResult {
     let keys: MintNewEditionFromMasterEditionViaTokenKeys = accounts.into();
-    let ix =
-        mint_new_edition_from_master_edition_via_token_ix_with_program_id(program_id, keys, args)?;
+    let ix = mint_new_edition_from_master_edition_via_token_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_token_invoke(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
     args: MintNewEditionFromMasterEditionViaTokenIxArgs,
 ) -> ProgramResult {
-    mint_new_edition_from_master_edition_via_token_invoke_with_program_id(crate::ID, accounts, args)
+    mint_new_edition_from_master_edition_via_token_invoke_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_token_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
@@ -3640,10 +4119,14 @@ pub fn mint_new_edition_from_master_edition_via_token_invoke_signed_with_program
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     let keys: MintNewEditionFromMasterEditionViaTokenKeys = accounts.into();
-    let ix =
-        mint_new_edition_from_master_edition_via_token_ix_with_program_id(program_id, keys, args)?;
+    let ix = mint_new_edition_from_master_edition_via_token_ix_with_program_id(
+        program_id,
+        keys,
+        args,
+    )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_token_invoke_signed(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
     args: MintNewEditionFromMasterEditionViaTokenIxArgs,
@@ -3656,6 +4139,7 @@ pub fn mint_new_edition_from_master_edition_via_token_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_token_verify_account_keys(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'_, '_>,
     keys: MintNewEditionFromMasterEditionViaTokenKeys,
@@ -3685,7 +4169,11 @@ pub fn mint_new_edition_from_master_edition_via_token_verify_account_keys(
     }
     Ok(())
 }
-pub fn mint_new_edition_from_master_edition_via_token_verify_writable_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_writable_privileges<
+    'me,
+    'info,
+>(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_writable in [
@@ -3701,7 +4189,11 @@ pub fn mint_new_edition_from_master_edition_via_token_verify_writable_privileges
     }
     Ok(())
 }
-pub fn mint_new_edition_from_master_edition_via_token_verify_signer_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_signer_privileges<
+    'me,
+    'info,
+>(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [
@@ -3715,7 +4207,11 @@ pub fn mint_new_edition_from_master_edition_via_token_verify_signer_privileges<'
     }
     Ok(())
 }
-pub fn mint_new_edition_from_master_edition_via_token_verify_account_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_token_verify_account_privileges<
+    'me,
+    'info,
+>(
     accounts: MintNewEditionFromMasterEditionViaTokenAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     mint_new_edition_from_master_edition_via_token_verify_writable_privileges(accounts)?;
@@ -3724,6 +4220,7 @@ pub fn mint_new_edition_from_master_edition_via_token_verify_account_privileges<
 }
 pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN: usize = 3;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ConvertMasterEditionV1ToV2Accounts<'me, 'info> {
     ///Master Record Edition V1 (pda of ['metadata', program id, master metadata mint id, 'edition'])
     pub master_edition: &'me AccountInfo<'info>,
@@ -3741,7 +4238,9 @@ pub struct ConvertMasterEditionV1ToV2Keys {
     ///Printing mint
     pub printing_mint: Pubkey,
 }
-impl From<ConvertMasterEditionV1ToV2Accounts<'_, '_>> for ConvertMasterEditionV1ToV2Keys {
+#[cfg(feature = "cpi")]
+impl From<ConvertMasterEditionV1ToV2Accounts<'_, '_>>
+for ConvertMasterEditionV1ToV2Keys {
     fn from(accounts: ConvertMasterEditionV1ToV2Accounts) -> Self {
         Self {
             master_edition: *accounts.master_edition.key,
@@ -3751,8 +4250,7 @@ impl From<ConvertMasterEditionV1ToV2Accounts<'_, '_>> for ConvertMasterEditionV1
     }
 }
 impl From<ConvertMasterEditionV1ToV2Keys>
-    for [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] {
     fn from(keys: ConvertMasterEditionV1ToV2Keys) -> Self {
         [
             AccountMeta {
@@ -3774,8 +4272,7 @@ impl From<ConvertMasterEditionV1ToV2Keys>
     }
 }
 impl From<[Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
-    for ConvertMasterEditionV1ToV2Keys
-{
+for ConvertMasterEditionV1ToV2Keys {
     fn from(pubkeys: [Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             master_edition: pubkeys[0],
@@ -3784,9 +4281,9 @@ impl From<[Pubkey; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ConvertMasterEditionV1ToV2Accounts<'_, 'info>>
-    for [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] {
     fn from(accounts: ConvertMasterEditionV1ToV2Accounts<'_, 'info>) -> Self {
         [
             accounts.master_edition.clone(),
@@ -3795,9 +4292,12 @@ impl<'info> From<ConvertMasterEditionV1ToV2Accounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
-    for ConvertMasterEditionV1ToV2Accounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN]>
+for ConvertMasterEditionV1ToV2Accounts<'me, 'info> {
     fn from(
         arr: &'me [AccountInfo<'info>; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -3812,36 +4312,50 @@ pub const CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM: u8 = 12u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConvertMasterEditionV1ToV2IxData;
 impl ConvertMasterEditionV1ToV2IxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ConvertMasterEditionV1ToV2IxData {
+    const NAME: &'static str = "ConvertMasterEditionV1ToV2";
+    const DISCM: &'static [u8] = &[CONVERT_MASTER_EDITION_V1_TO_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN;
+    type Keys = ConvertMasterEditionV1ToV2Keys;
+    type Args = ();
+}
 pub fn convert_master_edition_v1_to_v2_ix_with_program_id(
     program_id: Pubkey,
     keys: ConvertMasterEditionV1ToV2Keys,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] = keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; CONVERT_MASTER_EDITION_V1_TO_V2_IX_ACCOUNTS_LEN] = keys
+        .into();
     Ok(Instruction {
         program_id,
         accounts: Vec::from(metas),
@@ -3850,9 +4364,10 @@ pub fn convert_master_edition_v1_to_v2_ix_with_program_id(
 }
 pub fn convert_master_edition_v1_to_v2_ix(
     keys: ConvertMasterEditionV1ToV2Keys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     convert_master_edition_v1_to_v2_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
@@ -3861,11 +4376,13 @@ pub fn convert_master_edition_v1_to_v2_invoke_with_program_id(
     let ix = convert_master_edition_v1_to_v2_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_invoke(
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
 ) -> ProgramResult {
     convert_master_edition_v1_to_v2_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
@@ -3875,12 +4392,18 @@ pub fn convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
     let ix = convert_master_edition_v1_to_v2_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_invoke_signed(
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    convert_master_edition_v1_to_v2_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    convert_master_edition_v1_to_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_verify_account_keys(
     accounts: ConvertMasterEditionV1ToV2Accounts<'_, '_>,
     keys: ConvertMasterEditionV1ToV2Keys,
@@ -3896,6 +4419,7 @@ pub fn convert_master_edition_v1_to_v2_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_verify_writable_privileges<'me, 'info>(
     accounts: ConvertMasterEditionV1ToV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3910,6 +4434,7 @@ pub fn convert_master_edition_v1_to_v2_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn convert_master_edition_v1_to_v2_verify_account_privileges<'me, 'info>(
     accounts: ConvertMasterEditionV1ToV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -3918,6 +4443,7 @@ pub fn convert_master_edition_v1_to_v2_verify_account_privileges<'me, 'info>(
 }
 pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN: usize = 17;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info> {
     ///New Metadata key (pda of ['metadata', program id, mint id])
     pub new_metadata: &'me AccountInfo<'info>,
@@ -3991,9 +4517,9 @@ pub struct MintNewEditionFromMasterEditionViaVaultProxyKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>>
-    for MintNewEditionFromMasterEditionViaVaultProxyKeys
-{
+for MintNewEditionFromMasterEditionViaVaultProxyKeys {
     fn from(accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts) -> Self {
         Self {
             new_metadata: *accounts.new_metadata.key,
@@ -4017,8 +4543,7 @@ impl From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>>
     }
 }
 impl From<MintNewEditionFromMasterEditionViaVaultProxyKeys>
-    for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] {
     fn from(keys: MintNewEditionFromMasterEditionViaVaultProxyKeys) -> Self {
         [
             AccountMeta {
@@ -4110,8 +4635,7 @@ impl From<MintNewEditionFromMasterEditionViaVaultProxyKeys>
     }
 }
 impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN]>
-    for MintNewEditionFromMasterEditionViaVaultProxyKeys
-{
+for MintNewEditionFromMasterEditionViaVaultProxyKeys {
     fn from(
         pubkeys: [Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
     ) -> Self {
@@ -4136,10 +4660,14 @@ impl From<[Pubkey; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOU
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, 'info>>
-    for [AccountInfo<'info>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN]
-{
-    fn from(accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, 'info>) -> Self {
+for [AccountInfo<
+    'info,
+>; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] {
+    fn from(
+        accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, 'info>,
+    ) -> Self {
         [
             accounts.new_metadata.clone(),
             accounts.new_edition.clone(),
@@ -4161,15 +4689,19 @@ impl<'info> From<MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, 'info>
         ]
     }
 }
-impl<'me, 'info>
-    From<
-        &'me [AccountInfo<'info>;
-                 MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
-    > for MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info>
-{
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<
+    &'me [AccountInfo<
+        'info,
+    >; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
+> for MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info> {
     fn from(
-        arr: &'me [AccountInfo<'info>;
-                 MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
+        arr: &'me [AccountInfo<
+            'info,
+        >; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN],
     ) -> Self {
         Self {
             new_metadata: &arr[0],
@@ -4196,56 +4728,78 @@ pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM: u8 = 13
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyIxArgs {
-    pub mint_new_edition_from_master_edition_via_token_args:
-        MintNewEditionFromMasterEditionViaTokenArgs,
+    pub mint_new_edition_from_master_edition_via_token_args: MintNewEditionFromMasterEditionViaTokenArgs,
 }
 #[derive(Clone, Debug, PartialEq)]
 pub struct MintNewEditionFromMasterEditionViaVaultProxyIxData(
     pub MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
 );
 impl From<MintNewEditionFromMasterEditionViaVaultProxyIxArgs>
-    for MintNewEditionFromMasterEditionViaVaultProxyIxData
-{
+for MintNewEditionFromMasterEditionViaVaultProxyIxData {
     fn from(args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs) -> Self {
         Self(args)
     }
 }
 impl MintNewEditionFromMasterEditionViaVaultProxyIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM,
+                        maybe_discm
+                    ),
                 ),
-            ));
-        }
-        Ok(Self(
-            MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(&mut reader)?,
-        ))
-    }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
-        writer.write_all(&[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM])?;
+            );
+        }
+        Ok(
+            Self(
+                MintNewEditionFromMasterEditionViaVaultProxyIxArgs::deserialize(
+                    &mut reader,
+                )?,
+            ),
+        )
+    }
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
+        writer
+            .write_all(
+                &[MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM],
+            )?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction
+for MintNewEditionFromMasterEditionViaVaultProxyIxData {
+    const NAME: &'static str = "MintNewEditionFromMasterEditionViaVaultProxy";
+    const DISCM: &'static [u8] = &[
+        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_DISCM,
+    ];
+    const ACCOUNTS_LEN: usize = MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN;
+    type Keys = MintNewEditionFromMasterEditionViaVaultProxyKeys;
+    type Args = MintNewEditionFromMasterEditionViaVaultProxyIxArgs;
+}
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
     program_id: Pubkey,
     keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
     args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
-) -> std::io::Result<Instruction> {
-    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] =
-        keys.into();
+) -> borsh::io::Result<Instruction> {
+    let metas: [AccountMeta; MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY_IX_ACCOUNTS_LEN] = keys
+        .into();
     let data: MintNewEditionFromMasterEditionViaVaultProxyIxData = args.into();
     Ok(Instruction {
         program_id,
@@ -4256,9 +4810,14 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_ix(
     keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
     args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
-) -> std::io::Result<Instruction> {
-    mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(crate::ID, keys, args)
+) -> borsh::io::Result<Instruction> {
+    mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
+        crate::ID,
+        keys,
+        args,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
@@ -4266,10 +4825,13 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_with_program_
 ) -> ProgramResult {
     let keys: MintNewEditionFromMasterEditionViaVaultProxyKeys = accounts.into();
     let ix = mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
-        program_id, keys, args,
+        program_id,
+        keys,
+        args,
     )?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke(
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
     args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
@@ -4280,6 +4842,7 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke(
         args,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
@@ -4288,10 +4851,13 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed_with_p
 ) -> ProgramResult {
     let keys: MintNewEditionFromMasterEditionViaVaultProxyKeys = accounts.into();
     let ix = mint_new_edition_from_master_edition_via_vault_proxy_ix_with_program_id(
-        program_id, keys, args,
+        program_id,
+        keys,
+        args,
     )?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed(
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
     args: MintNewEditionFromMasterEditionViaVaultProxyIxArgs,
@@ -4304,6 +4870,7 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_invoke_signed(
         seeds,
     )
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_keys(
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'_, '_>,
     keys: MintNewEditionFromMasterEditionViaVaultProxyKeys,
@@ -4317,10 +4884,7 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_keys(
         (accounts.new_mint_authority.key, &keys.new_mint_authority),
         (accounts.payer.key, &keys.payer),
         (accounts.vault_authority.key, &keys.vault_authority),
-        (
-            accounts.safety_deposit_store.key,
-            &keys.safety_deposit_store,
-        ),
+        (accounts.safety_deposit_store.key, &keys.safety_deposit_store),
         (accounts.safety_deposit_box.key, &keys.safety_deposit_box),
         (accounts.vault.key, &keys.vault),
         (
@@ -4339,6 +4903,7 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_privileges<
     'me,
     'info,
@@ -4358,7 +4923,11 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_priv
     }
     Ok(())
 }
-pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges<'me, 'info>(
+#[cfg(feature = "cpi")]
+pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges<
+    'me,
+    'info,
+>(
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
     for should_be_signer in [
@@ -4372,18 +4941,24 @@ pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privil
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn mint_new_edition_from_master_edition_via_vault_proxy_verify_account_privileges<
     'me,
     'info,
 >(
     accounts: MintNewEditionFromMasterEditionViaVaultProxyAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_privileges(accounts)?;
-    mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges(accounts)?;
+    mint_new_edition_from_master_edition_via_vault_proxy_verify_writable_privileges(
+        accounts,
+    )?;
+    mint_new_edition_from_master_edition_via_vault_proxy_verify_signer_privileges(
+        accounts,
+    )?;
     Ok(())
 }
 pub const PUFF_METADATA_IX_ACCOUNTS_LEN: usize = 1;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct PuffMetadataAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -4393,6 +4968,7 @@ pub struct PuffMetadataKeys {
     ///Metadata account
     pub metadata: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<PuffMetadataAccounts<'_, '_>> for PuffMetadataKeys {
     fn from(accounts: PuffMetadataAccounts) -> Self {
         Self {
@@ -4402,30 +4978,30 @@ impl From<PuffMetadataAccounts<'_, '_>> for PuffMetadataKeys {
 }
 impl From<PuffMetadataKeys> for [AccountMeta; PUFF_METADATA_IX_ACCOUNTS_LEN] {
     fn from(keys: PuffMetadataKeys) -> Self {
-        [AccountMeta {
-            pubkey: keys.metadata,
-            is_signer: false,
-            is_writable: true,
-        }]
+        [
+            AccountMeta {
+                pubkey: keys.metadata,
+                is_signer: false,
+                is_writable: true,
+            },
+        ]
     }
 }
 impl From<[Pubkey; PUFF_METADATA_IX_ACCOUNTS_LEN]> for PuffMetadataKeys {
     fn from(pubkeys: [Pubkey; PUFF_METADATA_IX_ACCOUNTS_LEN]) -> Self {
-        Self {
-            metadata: pubkeys[0],
-        }
+        Self { metadata: pubkeys[0] }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<PuffMetadataAccounts<'_, 'info>>
-    for [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN] {
     fn from(accounts: PuffMetadataAccounts<'_, 'info>) -> Self {
         [accounts.metadata.clone()]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN]>
-    for PuffMetadataAccounts<'me, 'info>
-{
+for PuffMetadataAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; PUFF_METADATA_IX_ACCOUNTS_LEN]) -> Self {
         Self { metadata: &arr[0] }
     }
@@ -4434,35 +5010,48 @@ pub const PUFF_METADATA_IX_DISCM: u8 = 14u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct PuffMetadataIxData;
 impl PuffMetadataIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != PUFF_METADATA_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    PUFF_METADATA_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        PUFF_METADATA_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[PUFF_METADATA_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for PuffMetadataIxData {
+    const NAME: &'static str = "PuffMetadata";
+    const DISCM: &'static [u8] = &[PUFF_METADATA_IX_DISCM];
+    const ACCOUNTS_LEN: usize = PUFF_METADATA_IX_ACCOUNTS_LEN;
+    type Keys = PuffMetadataKeys;
+    type Args = ();
+}
 pub fn puff_metadata_ix_with_program_id(
     program_id: Pubkey,
     keys: PuffMetadataKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; PUFF_METADATA_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -4470,9 +5059,10 @@ pub fn puff_metadata_ix_with_program_id(
         data: PuffMetadataIxData.try_to_vec()?,
     })
 }
-pub fn puff_metadata_ix(keys: PuffMetadataKeys) -> std::io::Result<Instruction> {
+pub fn puff_metadata_ix(keys: PuffMetadataKeys) -> borsh::io::Result<Instruction> {
     puff_metadata_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_invoke_with_program_id(
     program_id: Pubkey,
     accounts: PuffMetadataAccounts<'_, '_>,
@@ -4481,9 +5071,11 @@ pub fn puff_metadata_invoke_with_program_id(
     let ix = puff_metadata_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_invoke(accounts: PuffMetadataAccounts<'_, '_>) -> ProgramResult {
     puff_metadata_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: PuffMetadataAccounts<'_, '_>,
@@ -4493,12 +5085,14 @@ pub fn puff_metadata_invoke_signed_with_program_id(
     let ix = puff_metadata_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_invoke_signed(
     accounts: PuffMetadataAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     puff_metadata_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_verify_account_keys(
     accounts: PuffMetadataAccounts<'_, '_>,
     keys: PuffMetadataKeys,
@@ -4510,6 +5104,7 @@ pub fn puff_metadata_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_verify_writable_privileges<'me, 'info>(
     accounts: PuffMetadataAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4520,6 +5115,7 @@ pub fn puff_metadata_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn puff_metadata_verify_account_privileges<'me, 'info>(
     accounts: PuffMetadataAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4528,6 +5124,7 @@ pub fn puff_metadata_verify_account_privileges<'me, 'info>(
 }
 pub const UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UpdateMetadataAccountV2Accounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -4541,6 +5138,7 @@ pub struct UpdateMetadataAccountV2Keys {
     ///Update authority key
     pub update_authority: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UpdateMetadataAccountV2Accounts<'_, '_>> for UpdateMetadataAccountV2Keys {
     fn from(accounts: UpdateMetadataAccountV2Accounts) -> Self {
         Self {
@@ -4550,8 +5148,7 @@ impl From<UpdateMetadataAccountV2Accounts<'_, '_>> for UpdateMetadataAccountV2Ke
     }
 }
 impl From<UpdateMetadataAccountV2Keys>
-    for [AccountMeta; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
     fn from(keys: UpdateMetadataAccountV2Keys) -> Self {
         [
             AccountMeta {
@@ -4567,7 +5164,8 @@ impl From<UpdateMetadataAccountV2Keys>
         ]
     }
 }
-impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]> for UpdateMetadataAccountV2Keys {
+impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountV2Keys {
     fn from(pubkeys: [Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -4575,17 +5173,22 @@ impl From<[Pubkey; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]> for UpdateMetada
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UpdateMetadataAccountV2Accounts<'_, 'info>>
-    for [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
     fn from(accounts: UpdateMetadataAccountV2Accounts<'_, 'info>) -> Self {
         [accounts.metadata.clone(), accounts.update_authority.clone()]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
-    for UpdateMetadataAccountV2Accounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for UpdateMetadataAccountV2Accounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             update_authority: &arr[1],
@@ -4606,39 +5209,50 @@ impl From<UpdateMetadataAccountV2IxArgs> for UpdateMetadataAccountV2IxData {
     }
 }
 impl UpdateMetadataAccountV2IxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != UPDATE_METADATA_ACCOUNT_V2_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UPDATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UPDATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(UpdateMetadataAccountV2IxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(UpdateMetadataAccountV2IxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[UPDATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UpdateMetadataAccountV2IxData {
+    const NAME: &'static str = "UpdateMetadataAccountV2";
+    const DISCM: &'static [u8] = &[UPDATE_METADATA_ACCOUNT_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN;
+    type Keys = UpdateMetadataAccountV2Keys;
+    type Args = UpdateMetadataAccountV2IxArgs;
+}
 pub fn update_metadata_account_v2_ix_with_program_id(
     program_id: Pubkey,
     keys: UpdateMetadataAccountV2Keys,
     args: UpdateMetadataAccountV2IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UPDATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = keys.into();
     let data: UpdateMetadataAccountV2IxData = args.into();
     Ok(Instruction {
@@ -4650,9 +5264,10 @@ pub fn update_metadata_account_v2_ix_with_program_id(
 pub fn update_metadata_account_v2_ix(
     keys: UpdateMetadataAccountV2Keys,
     args: UpdateMetadataAccountV2IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     update_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
@@ -4662,12 +5277,14 @@ pub fn update_metadata_account_v2_invoke_with_program_id(
     let ix = update_metadata_account_v2_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_invoke(
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
     args: UpdateMetadataAccountV2IxArgs,
 ) -> ProgramResult {
     update_metadata_account_v2_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
@@ -4678,13 +5295,20 @@ pub fn update_metadata_account_v2_invoke_signed_with_program_id(
     let ix = update_metadata_account_v2_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_invoke_signed(
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
     args: UpdateMetadataAccountV2IxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    update_metadata_account_v2_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    update_metadata_account_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_verify_account_keys(
     accounts: UpdateMetadataAccountV2Accounts<'_, '_>,
     keys: UpdateMetadataAccountV2Keys,
@@ -4699,6 +5323,7 @@ pub fn update_metadata_account_v2_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_verify_writable_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4709,6 +5334,7 @@ pub fn update_metadata_account_v2_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_verify_signer_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4719,6 +5345,7 @@ pub fn update_metadata_account_v2_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn update_metadata_account_v2_verify_account_privileges<'me, 'info>(
     accounts: UpdateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4728,6 +5355,7 @@ pub fn update_metadata_account_v2_verify_account_privileges<'me, 'info>(
 }
 pub const CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN: usize = 7;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateMetadataAccountV2Accounts<'me, 'info> {
     ///Metadata key (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
@@ -4761,6 +5389,7 @@ pub struct CreateMetadataAccountV2Keys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateMetadataAccountV2Accounts<'_, '_>> for CreateMetadataAccountV2Keys {
     fn from(accounts: CreateMetadataAccountV2Accounts) -> Self {
         Self {
@@ -4775,8 +5404,7 @@ impl From<CreateMetadataAccountV2Accounts<'_, '_>> for CreateMetadataAccountV2Ke
     }
 }
 impl From<CreateMetadataAccountV2Keys>
-    for [AccountMeta; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
     fn from(keys: CreateMetadataAccountV2Keys) -> Self {
         [
             AccountMeta {
@@ -4817,7 +5445,8 @@ impl From<CreateMetadataAccountV2Keys>
         ]
     }
 }
-impl From<[Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]> for CreateMetadataAccountV2Keys {
+impl From<[Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountV2Keys {
     fn from(pubkeys: [Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -4830,9 +5459,9 @@ impl From<[Pubkey; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]> for CreateMetada
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateMetadataAccountV2Accounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateMetadataAccountV2Accounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -4845,10 +5474,15 @@ impl<'info> From<CreateMetadataAccountV2Accounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
-    for CreateMetadataAccountV2Accounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN]>
+for CreateMetadataAccountV2Accounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             mint: &arr[1],
@@ -4874,39 +5508,50 @@ impl From<CreateMetadataAccountV2IxArgs> for CreateMetadataAccountV2IxData {
     }
 }
 impl CreateMetadataAccountV2IxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CREATE_METADATA_ACCOUNT_V2_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_METADATA_ACCOUNT_V2_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
-        Ok(Self(CreateMetadataAccountV2IxArgs::deserialize(
-            &mut reader,
-        )?))
+        Ok(Self(CreateMetadataAccountV2IxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CREATE_METADATA_ACCOUNT_V2_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMetadataAccountV2IxData {
+    const NAME: &'static str = "CreateMetadataAccountV2";
+    const DISCM: &'static [u8] = &[CREATE_METADATA_ACCOUNT_V2_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN;
+    type Keys = CreateMetadataAccountV2Keys;
+    type Args = CreateMetadataAccountV2IxArgs;
+}
 pub fn create_metadata_account_v2_ix_with_program_id(
     program_id: Pubkey,
     keys: CreateMetadataAccountV2Keys,
     args: CreateMetadataAccountV2IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CREATE_METADATA_ACCOUNT_V2_IX_ACCOUNTS_LEN] = keys.into();
     let data: CreateMetadataAccountV2IxData = args.into();
     Ok(Instruction {
@@ -4918,9 +5563,10 @@ pub fn create_metadata_account_v2_ix_with_program_id(
 pub fn create_metadata_account_v2_ix(
     keys: CreateMetadataAccountV2Keys,
     args: CreateMetadataAccountV2IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     create_metadata_account_v2_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
@@ -4930,12 +5576,14 @@ pub fn create_metadata_account_v2_invoke_with_program_id(
     let ix = create_metadata_account_v2_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_invoke(
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
     args: CreateMetadataAccountV2IxArgs,
 ) -> ProgramResult {
     create_metadata_account_v2_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
@@ -4946,13 +5594,20 @@ pub fn create_metadata_account_v2_invoke_signed_with_program_id(
     let ix = create_metadata_account_v2_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_invoke_signed(
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
     args: CreateMetadataAccountV2IxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    create_metadata_account_v2_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    create_metadata_account_v2_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_verify_account_keys(
     accounts: CreateMetadataAccountV2Accounts<'_, '_>,
     keys: CreateMetadataAccountV2Keys,
@@ -4972,6 +5627,7 @@ pub fn create_metadata_account_v2_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_verify_writable_privileges<'me, 'info>(
     accounts: CreateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4982,6 +5638,7 @@ pub fn create_metadata_account_v2_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_verify_signer_privileges<'me, 'info>(
     accounts: CreateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -4992,6 +5649,7 @@ pub fn create_metadata_account_v2_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_metadata_account_v2_verify_account_privileges<'me, 'info>(
     accounts: CreateMetadataAccountV2Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5001,6 +5659,7 @@ pub fn create_metadata_account_v2_verify_account_privileges<'me, 'info>(
 }
 pub const CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct CreateMasterEditionV3Accounts<'me, 'info> {
     ///Unallocated edition V2 account with address as pda of ['metadata', program id, mint, 'edition']
     pub edition: &'me AccountInfo<'info>,
@@ -5042,6 +5701,7 @@ pub struct CreateMasterEditionV3Keys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<CreateMasterEditionV3Accounts<'_, '_>> for CreateMasterEditionV3Keys {
     fn from(accounts: CreateMasterEditionV3Accounts) -> Self {
         Self {
@@ -5057,7 +5717,8 @@ impl From<CreateMasterEditionV3Accounts<'_, '_>> for CreateMasterEditionV3Keys {
         }
     }
 }
-impl From<CreateMasterEditionV3Keys> for [AccountMeta; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
+impl From<CreateMasterEditionV3Keys>
+for [AccountMeta; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
     fn from(keys: CreateMasterEditionV3Keys) -> Self {
         [
             AccountMeta {
@@ -5108,7 +5769,8 @@ impl From<CreateMasterEditionV3Keys> for [AccountMeta; CREATE_MASTER_EDITION_V3_
         ]
     }
 }
-impl From<[Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]> for CreateMasterEditionV3Keys {
+impl From<[Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]>
+for CreateMasterEditionV3Keys {
     fn from(pubkeys: [Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             edition: pubkeys[0],
@@ -5123,9 +5785,9 @@ impl From<[Pubkey; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]> for CreateMasterEd
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<CreateMasterEditionV3Accounts<'_, 'info>>
-    for [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] {
     fn from(accounts: CreateMasterEditionV3Accounts<'_, 'info>) -> Self {
         [
             accounts.edition.clone(),
@@ -5140,10 +5802,15 @@ impl<'info> From<CreateMasterEditionV3Accounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]>
-    for CreateMasterEditionV3Accounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN]>
+for CreateMasterEditionV3Accounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             edition: &arr[0],
             mint: &arr[1],
@@ -5171,37 +5838,50 @@ impl From<CreateMasterEditionV3IxArgs> for CreateMasterEditionV3IxData {
     }
 }
 impl CreateMasterEditionV3IxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != CREATE_MASTER_EDITION_V3_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    CREATE_MASTER_EDITION_V3_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        CREATE_MASTER_EDITION_V3_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(CreateMasterEditionV3IxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[CREATE_MASTER_EDITION_V3_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for CreateMasterEditionV3IxData {
+    const NAME: &'static str = "CreateMasterEditionV3";
+    const DISCM: &'static [u8] = &[CREATE_MASTER_EDITION_V3_IX_DISCM];
+    const ACCOUNTS_LEN: usize = CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN;
+    type Keys = CreateMasterEditionV3Keys;
+    type Args = CreateMasterEditionV3IxArgs;
+}
 pub fn create_master_edition_v3_ix_with_program_id(
     program_id: Pubkey,
     keys: CreateMasterEditionV3Keys,
     args: CreateMasterEditionV3IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; CREATE_MASTER_EDITION_V3_IX_ACCOUNTS_LEN] = keys.into();
     let data: CreateMasterEditionV3IxData = args.into();
     Ok(Instruction {
@@ -5213,9 +5893,10 @@ pub fn create_master_edition_v3_ix_with_program_id(
 pub fn create_master_edition_v3_ix(
     keys: CreateMasterEditionV3Keys,
     args: CreateMasterEditionV3IxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     create_master_edition_v3_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_invoke_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
@@ -5225,12 +5906,14 @@ pub fn create_master_edition_v3_invoke_with_program_id(
     let ix = create_master_edition_v3_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_invoke(
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
     args: CreateMasterEditionV3IxArgs,
 ) -> ProgramResult {
     create_master_edition_v3_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
@@ -5241,13 +5924,20 @@ pub fn create_master_edition_v3_invoke_signed_with_program_id(
     let ix = create_master_edition_v3_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_invoke_signed(
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
     args: CreateMasterEditionV3IxArgs,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    create_master_edition_v3_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
+    create_master_edition_v3_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        args,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_verify_account_keys(
     accounts: CreateMasterEditionV3Accounts<'_, '_>,
     keys: CreateMasterEditionV3Keys,
@@ -5269,6 +5959,7 @@ pub fn create_master_edition_v3_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_verify_writable_privileges<'me, 'info>(
     accounts: CreateMasterEditionV3Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5279,6 +5970,7 @@ pub fn create_master_edition_v3_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_verify_signer_privileges<'me, 'info>(
     accounts: CreateMasterEditionV3Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5293,6 +5985,7 @@ pub fn create_master_edition_v3_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn create_master_edition_v3_verify_account_privileges<'me, 'info>(
     accounts: CreateMasterEditionV3Accounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5302,6 +5995,7 @@ pub fn create_master_edition_v3_verify_account_privileges<'me, 'info>(
 }
 pub const VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct VerifyCollectionAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -5331,6 +6025,7 @@ pub struct VerifyCollectionKeys {
     ///MasterEdition2 Account of the Collection Token
     pub collection_master_edition_account: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<VerifyCollectionAccounts<'_, '_>> for VerifyCollectionKeys {
     fn from(accounts: VerifyCollectionAccounts) -> Self {
         Self {
@@ -5339,7 +6034,9 @@ impl From<VerifyCollectionAccounts<'_, '_>> for VerifyCollectionKeys {
             payer: *accounts.payer.key,
             collection_mint: *accounts.collection_mint.key,
             collection: *accounts.collection.key,
-            collection_master_edition_account: *accounts.collection_master_edition_account.key,
+            collection_master_edition_account: *accounts
+                .collection_master_edition_account
+                .key,
         }
     }
 }
@@ -5391,9 +6088,9 @@ impl From<[Pubkey; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for VerifyCollectionKeys
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<VerifyCollectionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
     fn from(accounts: VerifyCollectionAccounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -5405,9 +6102,9 @@ impl<'info> From<VerifyCollectionAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
-    for VerifyCollectionAccounts<'me, 'info>
-{
+for VerifyCollectionAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: &arr[0],
@@ -5423,35 +6120,48 @@ pub const VERIFY_COLLECTION_IX_DISCM: u8 = 18u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct VerifyCollectionIxData;
 impl VerifyCollectionIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != VERIFY_COLLECTION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    VERIFY_COLLECTION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        VERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[VERIFY_COLLECTION_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for VerifyCollectionIxData {
+    const NAME: &'static str = "VerifyCollection";
+    const DISCM: &'static [u8] = &[VERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = VERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = VerifyCollectionKeys;
+    type Args = ();
+}
 pub fn verify_collection_ix_with_program_id(
     program_id: Pubkey,
     keys: VerifyCollectionKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -5459,9 +6169,12 @@ pub fn verify_collection_ix_with_program_id(
         data: VerifyCollectionIxData.try_to_vec()?,
     })
 }
-pub fn verify_collection_ix(keys: VerifyCollectionKeys) -> std::io::Result<Instruction> {
+pub fn verify_collection_ix(
+    keys: VerifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
     verify_collection_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_invoke_with_program_id(
     program_id: Pubkey,
     accounts: VerifyCollectionAccounts<'_, '_>,
@@ -5470,9 +6183,13 @@ pub fn verify_collection_invoke_with_program_id(
     let ix = verify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn verify_collection_invoke(accounts: VerifyCollectionAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn verify_collection_invoke(
+    accounts: VerifyCollectionAccounts<'_, '_>,
+) -> ProgramResult {
     verify_collection_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: VerifyCollectionAccounts<'_, '_>,
@@ -5482,22 +6199,21 @@ pub fn verify_collection_invoke_signed_with_program_id(
     let ix = verify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_invoke_signed(
     accounts: VerifyCollectionAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     verify_collection_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_verify_account_keys(
     accounts: VerifyCollectionAccounts<'_, '_>,
     keys: VerifyCollectionKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.metadata.key, &keys.metadata),
-        (
-            accounts.collection_authority.key,
-            &keys.collection_authority,
-        ),
+        (accounts.collection_authority.key, &keys.collection_authority),
         (accounts.payer.key, &keys.payer),
         (accounts.collection_mint.key, &keys.collection_mint),
         (accounts.collection.key, &keys.collection),
@@ -5512,6 +6228,7 @@ pub fn verify_collection_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_verify_writable_privileges<'me, 'info>(
     accounts: VerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5522,6 +6239,7 @@ pub fn verify_collection_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_verify_signer_privileges<'me, 'info>(
     accounts: VerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5532,6 +6250,7 @@ pub fn verify_collection_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn verify_collection_verify_account_privileges<'me, 'info>(
     accounts: VerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5541,6 +6260,7 @@ pub fn verify_collection_verify_account_privileges<'me, 'info>(
 }
 pub const UTILIZE_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UtilizeAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -5590,6 +6310,7 @@ pub struct UtilizeKeys {
     ///Program As Signer (Burner)
     pub burner: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UtilizeAccounts<'_, '_>> for UtilizeKeys {
     fn from(accounts: UtilizeAccounts) -> Self {
         Self {
@@ -5685,7 +6406,9 @@ impl From<[Pubkey; UTILIZE_IX_ACCOUNTS_LEN]> for UtilizeKeys {
         }
     }
 }
-impl<'info> From<UtilizeAccounts<'_, 'info>> for [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN] {
+#[cfg(feature = "cpi")]
+impl<'info> From<UtilizeAccounts<'_, 'info>>
+for [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN] {
     fn from(accounts: UtilizeAccounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -5702,9 +6425,9 @@ impl<'info> From<UtilizeAccounts<'_, 'info>> for [AccountInfo<'info>; UTILIZE_IX
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]>
-    for UtilizeAccounts<'me, 'info>
-{
+for UtilizeAccounts<'me, 'info> {
     fn from(arr: &'me [AccountInfo<'info>; UTILIZE_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: &arr[0],
@@ -5735,37 +6458,50 @@ impl From<UtilizeIxArgs> for UtilizeIxData {
     }
 }
 impl UtilizeIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != UTILIZE_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UTILIZE_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UTILIZE_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(UtilizeIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[UTILIZE_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UtilizeIxData {
+    const NAME: &'static str = "Utilize";
+    const DISCM: &'static [u8] = &[UTILIZE_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UTILIZE_IX_ACCOUNTS_LEN;
+    type Keys = UtilizeKeys;
+    type Args = UtilizeIxArgs;
+}
 pub fn utilize_ix_with_program_id(
     program_id: Pubkey,
     keys: UtilizeKeys,
     args: UtilizeIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UTILIZE_IX_ACCOUNTS_LEN] = keys.into();
     let data: UtilizeIxData = args.into();
     Ok(Instruction {
@@ -5774,9 +6510,13 @@ pub fn utilize_ix_with_program_id(
         data: data.try_to_vec()?,
     })
 }
-pub fn utilize_ix(keys: UtilizeKeys, args: UtilizeIxArgs) -> std::io::Result<Instruction> {
+pub fn utilize_ix(
+    keys: UtilizeKeys,
+    args: UtilizeIxArgs,
+) -> borsh::io::Result<Instruction> {
     utilize_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UtilizeAccounts<'_, '_>,
@@ -5786,9 +6526,14 @@ pub fn utilize_invoke_with_program_id(
     let ix = utilize_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn utilize_invoke(accounts: UtilizeAccounts<'_, '_>, args: UtilizeIxArgs) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn utilize_invoke(
+    accounts: UtilizeAccounts<'_, '_>,
+    args: UtilizeIxArgs,
+) -> ProgramResult {
     utilize_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UtilizeAccounts<'_, '_>,
@@ -5799,6 +6544,7 @@ pub fn utilize_invoke_signed_with_program_id(
     let ix = utilize_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_invoke_signed(
     accounts: UtilizeAccounts<'_, '_>,
     args: UtilizeIxArgs,
@@ -5806,6 +6552,7 @@ pub fn utilize_invoke_signed(
 ) -> ProgramResult {
     utilize_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_verify_account_keys(
     accounts: UtilizeAccounts<'_, '_>,
     keys: UtilizeKeys,
@@ -5820,10 +6567,7 @@ pub fn utilize_verify_account_keys(
         (accounts.ata_program.key, &keys.ata_program),
         (accounts.system_program.key, &keys.system_program),
         (accounts.rent.key, &keys.rent),
-        (
-            accounts.use_authority_record.key,
-            &keys.use_authority_record,
-        ),
+        (accounts.use_authority_record.key, &keys.use_authority_record),
         (accounts.burner.key, &keys.burner),
     ] {
         if actual != expected {
@@ -5832,6 +6576,7 @@ pub fn utilize_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_verify_writable_privileges<'me, 'info>(
     accounts: UtilizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5847,6 +6592,7 @@ pub fn utilize_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_verify_signer_privileges<'me, 'info>(
     accounts: UtilizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5857,6 +6603,7 @@ pub fn utilize_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn utilize_verify_account_privileges<'me, 'info>(
     accounts: UtilizeAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -5866,6 +6613,7 @@ pub fn utilize_verify_account_privileges<'me, 'info>(
 }
 pub const APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 11;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ApproveUseAuthorityAccounts<'me, 'info> {
     ///Use Authority Record PDA
     pub use_authority_record: &'me AccountInfo<'info>,
@@ -5915,6 +6663,7 @@ pub struct ApproveUseAuthorityKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ApproveUseAuthorityAccounts<'_, '_>> for ApproveUseAuthorityKeys {
     fn from(accounts: ApproveUseAuthorityAccounts) -> Self {
         Self {
@@ -5932,7 +6681,8 @@ impl From<ApproveUseAuthorityAccounts<'_, '_>> for ApproveUseAuthorityKeys {
         }
     }
 }
-impl From<ApproveUseAuthorityKeys> for [AccountMeta; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+impl From<ApproveUseAuthorityKeys>
+for [AccountMeta; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(keys: ApproveUseAuthorityKeys) -> Self {
         [
             AccountMeta {
@@ -6010,9 +6760,9 @@ impl From<[Pubkey; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]> for ApproveUseAuthori
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ApproveUseAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: ApproveUseAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.use_authority_record.clone(),
@@ -6029,10 +6779,12 @@ impl<'info> From<ApproveUseAuthorityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for ApproveUseAuthorityAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+for ApproveUseAuthorityAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             use_authority_record: &arr[0],
             owner: &arr[1],
@@ -6062,37 +6814,50 @@ impl From<ApproveUseAuthorityIxArgs> for ApproveUseAuthorityIxData {
     }
 }
 impl ApproveUseAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != APPROVE_USE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    APPROVE_USE_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        APPROVE_USE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self(ApproveUseAuthorityIxArgs::deserialize(&mut reader)?))
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[APPROVE_USE_AUTHORITY_IX_DISCM])?;
         self.0.serialize(&mut writer)
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ApproveUseAuthorityIxData {
+    const NAME: &'static str = "ApproveUseAuthority";
+    const DISCM: &'static [u8] = &[APPROVE_USE_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ApproveUseAuthorityKeys;
+    type Args = ApproveUseAuthorityIxArgs;
+}
 pub fn approve_use_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: ApproveUseAuthorityKeys,
     args: ApproveUseAuthorityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; APPROVE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     let data: ApproveUseAuthorityIxData = args.into();
     Ok(Instruction {
@@ -6104,9 +6869,10 @@ pub fn approve_use_authority_ix_with_program_id(
 pub fn approve_use_authority_ix(
     keys: ApproveUseAuthorityKeys,
     args: ApproveUseAuthorityIxArgs,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     approve_use_authority_ix_with_program_id(crate::ID, keys, args)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
@@ -6116,12 +6882,14 @@ pub fn approve_use_authority_invoke_with_program_id(
     let ix = approve_use_authority_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_invoke(
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
     args: ApproveUseAuthorityIxArgs,
 ) -> ProgramResult {
     approve_use_authority_invoke_with_program_id(crate::ID, accounts, args)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
@@ -6132,6 +6900,7 @@ pub fn approve_use_authority_invoke_signed_with_program_id(
     let ix = approve_use_authority_ix_with_program_id(program_id, keys, args)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_invoke_signed(
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
     args: ApproveUseAuthorityIxArgs,
@@ -6139,15 +6908,13 @@ pub fn approve_use_authority_invoke_signed(
 ) -> ProgramResult {
     approve_use_authority_invoke_signed_with_program_id(crate::ID, accounts, args, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_verify_account_keys(
     accounts: ApproveUseAuthorityAccounts<'_, '_>,
     keys: ApproveUseAuthorityKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (
-            accounts.use_authority_record.key,
-            &keys.use_authority_record,
-        ),
+        (accounts.use_authority_record.key, &keys.use_authority_record),
         (accounts.owner.key, &keys.owner),
         (accounts.payer.key, &keys.payer),
         (accounts.user.key, &keys.user),
@@ -6165,16 +6932,21 @@ pub fn approve_use_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_verify_writable_privileges<'me, 'info>(
     accounts: ApproveUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.use_authority_record, accounts.owner_token_account] {
+    for should_be_writable in [
+        accounts.use_authority_record,
+        accounts.owner_token_account,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_verify_signer_privileges<'me, 'info>(
     accounts: ApproveUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6185,6 +6957,7 @@ pub fn approve_use_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_use_authority_verify_account_privileges<'me, 'info>(
     accounts: ApproveUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6194,6 +6967,7 @@ pub fn approve_use_authority_verify_account_privileges<'me, 'info>(
 }
 pub const REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN: usize = 9;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RevokeUseAuthorityAccounts<'me, 'info> {
     ///Use Authority Record PDA
     pub use_authority_record: &'me AccountInfo<'info>,
@@ -6235,6 +7009,7 @@ pub struct RevokeUseAuthorityKeys {
     ///Rent info
     pub rent: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RevokeUseAuthorityAccounts<'_, '_>> for RevokeUseAuthorityKeys {
     fn from(accounts: RevokeUseAuthorityAccounts) -> Self {
         Self {
@@ -6250,7 +7025,8 @@ impl From<RevokeUseAuthorityAccounts<'_, '_>> for RevokeUseAuthorityKeys {
         }
     }
 }
-impl From<RevokeUseAuthorityKeys> for [AccountMeta; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
+impl From<RevokeUseAuthorityKeys>
+for [AccountMeta; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(keys: RevokeUseAuthorityKeys) -> Self {
         [
             AccountMeta {
@@ -6316,9 +7092,9 @@ impl From<[Pubkey; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]> for RevokeUseAuthority
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RevokeUseAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: RevokeUseAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.use_authority_record.clone(),
@@ -6333,10 +7109,12 @@ impl<'info> From<RevokeUseAuthorityAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for RevokeUseAuthorityAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+for RevokeUseAuthorityAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             use_authority_record: &arr[0],
             owner: &arr[1],
@@ -6354,35 +7132,48 @@ pub const REVOKE_USE_AUTHORITY_IX_DISCM: u8 = 21u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RevokeUseAuthorityIxData;
 impl RevokeUseAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REVOKE_USE_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REVOKE_USE_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_USE_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REVOKE_USE_AUTHORITY_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RevokeUseAuthorityIxData {
+    const NAME: &'static str = "RevokeUseAuthority";
+    const DISCM: &'static [u8] = &[REVOKE_USE_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = RevokeUseAuthorityKeys;
+    type Args = ();
+}
 pub fn revoke_use_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: RevokeUseAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REVOKE_USE_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -6390,9 +7181,12 @@ pub fn revoke_use_authority_ix_with_program_id(
         data: RevokeUseAuthorityIxData.try_to_vec()?,
     })
 }
-pub fn revoke_use_authority_ix(keys: RevokeUseAuthorityKeys) -> std::io::Result<Instruction> {
+pub fn revoke_use_authority_ix(
+    keys: RevokeUseAuthorityKeys,
+) -> borsh::io::Result<Instruction> {
     revoke_use_authority_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RevokeUseAuthorityAccounts<'_, '_>,
@@ -6401,9 +7195,13 @@ pub fn revoke_use_authority_invoke_with_program_id(
     let ix = revoke_use_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn revoke_use_authority_invoke(accounts: RevokeUseAuthorityAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn revoke_use_authority_invoke(
+    accounts: RevokeUseAuthorityAccounts<'_, '_>,
+) -> ProgramResult {
     revoke_use_authority_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RevokeUseAuthorityAccounts<'_, '_>,
@@ -6413,21 +7211,20 @@ pub fn revoke_use_authority_invoke_signed_with_program_id(
     let ix = revoke_use_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_invoke_signed(
     accounts: RevokeUseAuthorityAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     revoke_use_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_verify_account_keys(
     accounts: RevokeUseAuthorityAccounts<'_, '_>,
     keys: RevokeUseAuthorityKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (
-            accounts.use_authority_record.key,
-            &keys.use_authority_record,
-        ),
+        (accounts.use_authority_record.key, &keys.use_authority_record),
         (accounts.owner.key, &keys.owner),
         (accounts.user.key, &keys.user),
         (accounts.owner_token_account.key, &keys.owner_token_account),
@@ -6443,16 +7240,21 @@ pub fn revoke_use_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_verify_writable_privileges<'me, 'info>(
     accounts: RevokeUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
-    for should_be_writable in [accounts.use_authority_record, accounts.owner_token_account] {
+    for should_be_writable in [
+        accounts.use_authority_record,
+        accounts.owner_token_account,
+    ] {
         if !should_be_writable.is_writable {
             return Err((should_be_writable, ProgramError::InvalidAccountData));
         }
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_verify_signer_privileges<'me, 'info>(
     accounts: RevokeUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6463,6 +7265,7 @@ pub fn revoke_use_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_use_authority_verify_account_privileges<'me, 'info>(
     accounts: RevokeUseAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6472,6 +7275,7 @@ pub fn revoke_use_authority_verify_account_privileges<'me, 'info>(
 }
 pub const UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 6;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct UnverifyCollectionAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -6501,6 +7305,7 @@ pub struct UnverifyCollectionKeys {
     ///Collection Authority Record PDA
     pub collection_authority_record: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<UnverifyCollectionAccounts<'_, '_>> for UnverifyCollectionKeys {
     fn from(accounts: UnverifyCollectionAccounts) -> Self {
         Self {
@@ -6508,12 +7313,15 @@ impl From<UnverifyCollectionAccounts<'_, '_>> for UnverifyCollectionKeys {
             collection_authority: *accounts.collection_authority.key,
             collection_mint: *accounts.collection_mint.key,
             collection: *accounts.collection.key,
-            collection_master_edition_account: *accounts.collection_master_edition_account.key,
+            collection_master_edition_account: *accounts
+                .collection_master_edition_account
+                .key,
             collection_authority_record: *accounts.collection_authority_record.key,
         }
     }
 }
-impl From<UnverifyCollectionKeys> for [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+impl From<UnverifyCollectionKeys>
+for [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
     fn from(keys: UnverifyCollectionKeys) -> Self {
         [
             AccountMeta {
@@ -6561,9 +7369,9 @@ impl From<[Pubkey; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for UnverifyCollectionK
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<UnverifyCollectionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
     fn from(accounts: UnverifyCollectionAccounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -6575,10 +7383,12 @@ impl<'info> From<UnverifyCollectionAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
-    for UnverifyCollectionAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+for UnverifyCollectionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             collection_authority: &arr[1],
@@ -6593,35 +7403,48 @@ pub const UNVERIFY_COLLECTION_IX_DISCM: u8 = 22u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnverifyCollectionIxData;
 impl UnverifyCollectionIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != UNVERIFY_COLLECTION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    UNVERIFY_COLLECTION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        UNVERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[UNVERIFY_COLLECTION_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for UnverifyCollectionIxData {
+    const NAME: &'static str = "UnverifyCollection";
+    const DISCM: &'static [u8] = &[UNVERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = UnverifyCollectionKeys;
+    type Args = ();
+}
 pub fn unverify_collection_ix_with_program_id(
     program_id: Pubkey,
     keys: UnverifyCollectionKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; UNVERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -6629,9 +7452,12 @@ pub fn unverify_collection_ix_with_program_id(
         data: UnverifyCollectionIxData.try_to_vec()?,
     })
 }
-pub fn unverify_collection_ix(keys: UnverifyCollectionKeys) -> std::io::Result<Instruction> {
+pub fn unverify_collection_ix(
+    keys: UnverifyCollectionKeys,
+) -> borsh::io::Result<Instruction> {
     unverify_collection_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_invoke_with_program_id(
     program_id: Pubkey,
     accounts: UnverifyCollectionAccounts<'_, '_>,
@@ -6640,9 +7466,13 @@ pub fn unverify_collection_invoke_with_program_id(
     let ix = unverify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
-pub fn unverify_collection_invoke(accounts: UnverifyCollectionAccounts<'_, '_>) -> ProgramResult {
+#[cfg(feature = "cpi")]
+pub fn unverify_collection_invoke(
+    accounts: UnverifyCollectionAccounts<'_, '_>,
+) -> ProgramResult {
     unverify_collection_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: UnverifyCollectionAccounts<'_, '_>,
@@ -6652,32 +7482,28 @@ pub fn unverify_collection_invoke_signed_with_program_id(
     let ix = unverify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_invoke_signed(
     accounts: UnverifyCollectionAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     unverify_collection_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_verify_account_keys(
     accounts: UnverifyCollectionAccounts<'_, '_>,
     keys: UnverifyCollectionKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.metadata.key, &keys.metadata),
-        (
-            accounts.collection_authority.key,
-            &keys.collection_authority,
-        ),
+        (accounts.collection_authority.key, &keys.collection_authority),
         (accounts.collection_mint.key, &keys.collection_mint),
         (accounts.collection.key, &keys.collection),
         (
             accounts.collection_master_edition_account.key,
             &keys.collection_master_edition_account,
         ),
-        (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
-        ),
+        (accounts.collection_authority_record.key, &keys.collection_authority_record),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -6685,6 +7511,7 @@ pub fn unverify_collection_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_verify_writable_privileges<'me, 'info>(
     accounts: UnverifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6695,6 +7522,7 @@ pub fn unverify_collection_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_verify_signer_privileges<'me, 'info>(
     accounts: UnverifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6705,6 +7533,7 @@ pub fn unverify_collection_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn unverify_collection_verify_account_privileges<'me, 'info>(
     accounts: UnverifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6714,6 +7543,7 @@ pub fn unverify_collection_verify_account_privileges<'me, 'info>(
 }
 pub const APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ApproveCollectionAuthorityAccounts<'me, 'info> {
     ///Collection Authority Record PDA
     pub collection_authority_record: &'me AccountInfo<'info>,
@@ -6751,7 +7581,9 @@ pub struct ApproveCollectionAuthorityKeys {
     ///Rent info
     pub rent: Pubkey,
 }
-impl From<ApproveCollectionAuthorityAccounts<'_, '_>> for ApproveCollectionAuthorityKeys {
+#[cfg(feature = "cpi")]
+impl From<ApproveCollectionAuthorityAccounts<'_, '_>>
+for ApproveCollectionAuthorityKeys {
     fn from(accounts: ApproveCollectionAuthorityAccounts) -> Self {
         Self {
             collection_authority_record: *accounts.collection_authority_record.key,
@@ -6766,8 +7598,7 @@ impl From<ApproveCollectionAuthorityAccounts<'_, '_>> for ApproveCollectionAutho
     }
 }
 impl From<ApproveCollectionAuthorityKeys>
-    for [AccountMeta; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(keys: ApproveCollectionAuthorityKeys) -> Self {
         [
             AccountMeta {
@@ -6814,8 +7645,7 @@ impl From<ApproveCollectionAuthorityKeys>
     }
 }
 impl From<[Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for ApproveCollectionAuthorityKeys
-{
+for ApproveCollectionAuthorityKeys {
     fn from(pubkeys: [Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             collection_authority_record: pubkeys[0],
@@ -6829,9 +7659,9 @@ impl From<[Pubkey; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ApproveCollectionAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: ApproveCollectionAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.collection_authority_record.clone(),
@@ -6845,10 +7675,15 @@ impl<'info> From<ApproveCollectionAuthorityAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for ApproveCollectionAuthorityAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for ApproveCollectionAuthorityAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             collection_authority_record: &arr[0],
             new_collection_authority: &arr[1],
@@ -6865,35 +7700,48 @@ pub const APPROVE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 23u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ApproveCollectionAuthorityIxData;
 impl ApproveCollectionAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != APPROVE_COLLECTION_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    APPROVE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        APPROVE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[APPROVE_COLLECTION_AUTHORITY_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ApproveCollectionAuthorityIxData {
+    const NAME: &'static str = "ApproveCollectionAuthority";
+    const DISCM: &'static [u8] = &[APPROVE_COLLECTION_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = ApproveCollectionAuthorityKeys;
+    type Args = ();
+}
 pub fn approve_collection_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: ApproveCollectionAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; APPROVE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -6903,9 +7751,10 @@ pub fn approve_collection_authority_ix_with_program_id(
 }
 pub fn approve_collection_authority_ix(
     keys: ApproveCollectionAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     approve_collection_authority_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
@@ -6914,11 +7763,13 @@ pub fn approve_collection_authority_invoke_with_program_id(
     let ix = approve_collection_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_invoke(
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
 ) -> ProgramResult {
     approve_collection_authority_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
@@ -6928,25 +7779,25 @@ pub fn approve_collection_authority_invoke_signed_with_program_id(
     let ix = approve_collection_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_invoke_signed(
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    approve_collection_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
+    approve_collection_authority_invoke_signed_with_program_id(
+        crate::ID,
+        accounts,
+        seeds,
+    )
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_verify_account_keys(
     accounts: ApproveCollectionAuthorityAccounts<'_, '_>,
     keys: ApproveCollectionAuthorityKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
-        ),
-        (
-            accounts.new_collection_authority.key,
-            &keys.new_collection_authority,
-        ),
+        (accounts.collection_authority_record.key, &keys.collection_authority_record),
+        (accounts.new_collection_authority.key, &keys.new_collection_authority),
         (accounts.update_authority.key, &keys.update_authority),
         (accounts.payer.key, &keys.payer),
         (accounts.metadata.key, &keys.metadata),
@@ -6960,6 +7811,7 @@ pub fn approve_collection_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_verify_writable_privileges<'me, 'info>(
     accounts: ApproveCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6970,6 +7822,7 @@ pub fn approve_collection_authority_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_verify_signer_privileges<'me, 'info>(
     accounts: ApproveCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6980,6 +7833,7 @@ pub fn approve_collection_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn approve_collection_authority_verify_account_privileges<'me, 'info>(
     accounts: ApproveCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -6989,6 +7843,7 @@ pub fn approve_collection_authority_verify_account_privileges<'me, 'info>(
 }
 pub const REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN: usize = 4;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RevokeCollectionAuthorityAccounts<'me, 'info> {
     ///Collection Authority Record PDA
     pub collection_authority_record: &'me AccountInfo<'info>,
@@ -7010,6 +7865,7 @@ pub struct RevokeCollectionAuthorityKeys {
     ///Mint of Metadata
     pub mint: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RevokeCollectionAuthorityAccounts<'_, '_>> for RevokeCollectionAuthorityKeys {
     fn from(accounts: RevokeCollectionAuthorityAccounts) -> Self {
         Self {
@@ -7021,8 +7877,7 @@ impl From<RevokeCollectionAuthorityAccounts<'_, '_>> for RevokeCollectionAuthori
     }
 }
 impl From<RevokeCollectionAuthorityKeys>
-    for [AccountMeta; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(keys: RevokeCollectionAuthorityKeys) -> Self {
         [
             AccountMeta {
@@ -7048,7 +7903,8 @@ impl From<RevokeCollectionAuthorityKeys>
         ]
     }
 }
-impl From<[Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]> for RevokeCollectionAuthorityKeys {
+impl From<[Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for RevokeCollectionAuthorityKeys {
     fn from(pubkeys: [Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             collection_authority_record: pubkeys[0],
@@ -7058,9 +7914,9 @@ impl From<[Pubkey; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]> for RevokeColle
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RevokeCollectionAuthorityAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] {
     fn from(accounts: RevokeCollectionAuthorityAccounts<'_, 'info>) -> Self {
         [
             accounts.collection_authority_record.clone(),
@@ -7070,10 +7926,15 @@ impl<'info> From<RevokeCollectionAuthorityAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
-    for RevokeCollectionAuthorityAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN]>
+for RevokeCollectionAuthorityAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             collection_authority_record: &arr[0],
             update_authority: &arr[1],
@@ -7086,35 +7947,48 @@ pub const REVOKE_COLLECTION_AUTHORITY_IX_DISCM: u8 = 24u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RevokeCollectionAuthorityIxData;
 impl RevokeCollectionAuthorityIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REVOKE_COLLECTION_AUTHORITY_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REVOKE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REVOKE_COLLECTION_AUTHORITY_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REVOKE_COLLECTION_AUTHORITY_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RevokeCollectionAuthorityIxData {
+    const NAME: &'static str = "RevokeCollectionAuthority";
+    const DISCM: &'static [u8] = &[REVOKE_COLLECTION_AUTHORITY_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN;
+    type Keys = RevokeCollectionAuthorityKeys;
+    type Args = ();
+}
 pub fn revoke_collection_authority_ix_with_program_id(
     program_id: Pubkey,
     keys: RevokeCollectionAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REVOKE_COLLECTION_AUTHORITY_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7124,9 +7998,10 @@ pub fn revoke_collection_authority_ix_with_program_id(
 }
 pub fn revoke_collection_authority_ix(
     keys: RevokeCollectionAuthorityKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     revoke_collection_authority_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
@@ -7135,11 +8010,13 @@ pub fn revoke_collection_authority_invoke_with_program_id(
     let ix = revoke_collection_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_invoke(
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
 ) -> ProgramResult {
     revoke_collection_authority_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
@@ -7149,21 +8026,20 @@ pub fn revoke_collection_authority_invoke_signed_with_program_id(
     let ix = revoke_collection_authority_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_invoke_signed(
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     revoke_collection_authority_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_verify_account_keys(
     accounts: RevokeCollectionAuthorityAccounts<'_, '_>,
     keys: RevokeCollectionAuthorityKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
-        (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
-        ),
+        (accounts.collection_authority_record.key, &keys.collection_authority_record),
         (accounts.update_authority.key, &keys.update_authority),
         (accounts.metadata.key, &keys.metadata),
         (accounts.mint.key, &keys.mint),
@@ -7174,6 +8050,7 @@ pub fn revoke_collection_authority_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_verify_writable_privileges<'me, 'info>(
     accounts: RevokeCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7184,6 +8061,7 @@ pub fn revoke_collection_authority_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_verify_signer_privileges<'me, 'info>(
     accounts: RevokeCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7194,6 +8072,7 @@ pub fn revoke_collection_authority_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn revoke_collection_authority_verify_account_privileges<'me, 'info>(
     accounts: RevokeCollectionAuthorityAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7203,6 +8082,7 @@ pub fn revoke_collection_authority_verify_account_privileges<'me, 'info>(
 }
 pub const SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN: usize = 8;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct SetAndVerifyCollectionAccounts<'me, 'info> {
     ///Metadata account
     pub metadata: &'me AccountInfo<'info>,
@@ -7240,6 +8120,7 @@ pub struct SetAndVerifyCollectionKeys {
     ///Collection Authority Record PDA
     pub collection_authority_record: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<SetAndVerifyCollectionAccounts<'_, '_>> for SetAndVerifyCollectionKeys {
     fn from(accounts: SetAndVerifyCollectionAccounts) -> Self {
         Self {
@@ -7249,12 +8130,15 @@ impl From<SetAndVerifyCollectionAccounts<'_, '_>> for SetAndVerifyCollectionKeys
             update_authority: *accounts.update_authority.key,
             collection_mint: *accounts.collection_mint.key,
             collection: *accounts.collection.key,
-            collection_master_edition_account: *accounts.collection_master_edition_account.key,
+            collection_master_edition_account: *accounts
+                .collection_master_edition_account
+                .key,
             collection_authority_record: *accounts.collection_authority_record.key,
         }
     }
 }
-impl From<SetAndVerifyCollectionKeys> for [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
+impl From<SetAndVerifyCollectionKeys>
+for [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
     fn from(keys: SetAndVerifyCollectionKeys) -> Self {
         [
             AccountMeta {
@@ -7300,7 +8184,8 @@ impl From<SetAndVerifyCollectionKeys> for [AccountMeta; SET_AND_VERIFY_COLLECTIO
         ]
     }
 }
-impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for SetAndVerifyCollectionKeys {
+impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for SetAndVerifyCollectionKeys {
     fn from(pubkeys: [Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -7314,9 +8199,9 @@ impl From<[Pubkey; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]> for SetAndVerifyC
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<SetAndVerifyCollectionAccounts<'_, 'info>>
-    for [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] {
     fn from(accounts: SetAndVerifyCollectionAccounts<'_, 'info>) -> Self {
         [
             accounts.metadata.clone(),
@@ -7330,10 +8215,15 @@ impl<'info> From<SetAndVerifyCollectionAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
-    for SetAndVerifyCollectionAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN]>
+for SetAndVerifyCollectionAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             collection_authority: &arr[1],
@@ -7350,35 +8240,48 @@ pub const SET_AND_VERIFY_COLLECTION_IX_DISCM: u8 = 25u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct SetAndVerifyCollectionIxData;
 impl SetAndVerifyCollectionIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != SET_AND_VERIFY_COLLECTION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    SET_AND_VERIFY_COLLECTION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        SET_AND_VERIFY_COLLECTION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[SET_AND_VERIFY_COLLECTION_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for SetAndVerifyCollectionIxData {
+    const NAME: &'static str = "SetAndVerifyCollection";
+    const DISCM: &'static [u8] = &[SET_AND_VERIFY_COLLECTION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN;
+    type Keys = SetAndVerifyCollectionKeys;
+    type Args = ();
+}
 pub fn set_and_verify_collection_ix_with_program_id(
     program_id: Pubkey,
     keys: SetAndVerifyCollectionKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; SET_AND_VERIFY_COLLECTION_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7388,9 +8291,10 @@ pub fn set_and_verify_collection_ix_with_program_id(
 }
 pub fn set_and_verify_collection_ix(
     keys: SetAndVerifyCollectionKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     set_and_verify_collection_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_invoke_with_program_id(
     program_id: Pubkey,
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
@@ -7399,11 +8303,13 @@ pub fn set_and_verify_collection_invoke_with_program_id(
     let ix = set_and_verify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_invoke(
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
 ) -> ProgramResult {
     set_and_verify_collection_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
@@ -7413,22 +8319,21 @@ pub fn set_and_verify_collection_invoke_signed_with_program_id(
     let ix = set_and_verify_collection_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_invoke_signed(
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     set_and_verify_collection_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_verify_account_keys(
     accounts: SetAndVerifyCollectionAccounts<'_, '_>,
     keys: SetAndVerifyCollectionKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
     for (actual, expected) in [
         (accounts.metadata.key, &keys.metadata),
-        (
-            accounts.collection_authority.key,
-            &keys.collection_authority,
-        ),
+        (accounts.collection_authority.key, &keys.collection_authority),
         (accounts.payer.key, &keys.payer),
         (accounts.update_authority.key, &keys.update_authority),
         (accounts.collection_mint.key, &keys.collection_mint),
@@ -7437,10 +8342,7 @@ pub fn set_and_verify_collection_verify_account_keys(
             accounts.collection_master_edition_account.key,
             &keys.collection_master_edition_account,
         ),
-        (
-            accounts.collection_authority_record.key,
-            &keys.collection_authority_record,
-        ),
+        (accounts.collection_authority_record.key, &keys.collection_authority_record),
     ] {
         if actual != expected {
             return Err((*actual, *expected));
@@ -7448,6 +8350,7 @@ pub fn set_and_verify_collection_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_verify_writable_privileges<'me, 'info>(
     accounts: SetAndVerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7458,6 +8361,7 @@ pub fn set_and_verify_collection_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_verify_signer_privileges<'me, 'info>(
     accounts: SetAndVerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7468,6 +8372,7 @@ pub fn set_and_verify_collection_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn set_and_verify_collection_verify_account_privileges<'me, 'info>(
     accounts: SetAndVerifyCollectionAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7477,6 +8382,7 @@ pub fn set_and_verify_collection_verify_account_privileges<'me, 'info>(
 }
 pub const FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct FreezeDelegatedAccountAccounts<'me, 'info> {
     ///Delegate
     pub delegate: &'me AccountInfo<'info>,
@@ -7502,6 +8408,7 @@ pub struct FreezeDelegatedAccountKeys {
     ///Token Program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<FreezeDelegatedAccountAccounts<'_, '_>> for FreezeDelegatedAccountKeys {
     fn from(accounts: FreezeDelegatedAccountAccounts) -> Self {
         Self {
@@ -7513,7 +8420,8 @@ impl From<FreezeDelegatedAccountAccounts<'_, '_>> for FreezeDelegatedAccountKeys
         }
     }
 }
-impl From<FreezeDelegatedAccountKeys> for [AccountMeta; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<FreezeDelegatedAccountKeys>
+for [AccountMeta; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: FreezeDelegatedAccountKeys) -> Self {
         [
             AccountMeta {
@@ -7544,7 +8452,8 @@ impl From<FreezeDelegatedAccountKeys> for [AccountMeta; FREEZE_DELEGATED_ACCOUNT
         ]
     }
 }
-impl From<[Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]> for FreezeDelegatedAccountKeys {
+impl From<[Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for FreezeDelegatedAccountKeys {
     fn from(pubkeys: [Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             delegate: pubkeys[0],
@@ -7555,9 +8464,9 @@ impl From<[Pubkey; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]> for FreezeDelegate
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<FreezeDelegatedAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: FreezeDelegatedAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.delegate.clone(),
@@ -7568,10 +8477,15 @@ impl<'info> From<FreezeDelegatedAccountAccounts<'_, 'info>>
         ]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for FreezeDelegatedAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for FreezeDelegatedAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             delegate: &arr[0],
             token_account: &arr[1],
@@ -7585,35 +8499,48 @@ pub const FREEZE_DELEGATED_ACCOUNT_IX_DISCM: u8 = 26u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct FreezeDelegatedAccountIxData;
 impl FreezeDelegatedAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != FREEZE_DELEGATED_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    FREEZE_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        FREEZE_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[FREEZE_DELEGATED_ACCOUNT_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for FreezeDelegatedAccountIxData {
+    const NAME: &'static str = "FreezeDelegatedAccount";
+    const DISCM: &'static [u8] = &[FREEZE_DELEGATED_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = FreezeDelegatedAccountKeys;
+    type Args = ();
+}
 pub fn freeze_delegated_account_ix_with_program_id(
     program_id: Pubkey,
     keys: FreezeDelegatedAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; FREEZE_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7623,9 +8550,10 @@ pub fn freeze_delegated_account_ix_with_program_id(
 }
 pub fn freeze_delegated_account_ix(
     keys: FreezeDelegatedAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     freeze_delegated_account_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
@@ -7634,11 +8562,13 @@ pub fn freeze_delegated_account_invoke_with_program_id(
     let ix = freeze_delegated_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_invoke(
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     freeze_delegated_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
@@ -7648,12 +8578,14 @@ pub fn freeze_delegated_account_invoke_signed_with_program_id(
     let ix = freeze_delegated_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_invoke_signed(
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     freeze_delegated_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_verify_account_keys(
     accounts: FreezeDelegatedAccountAccounts<'_, '_>,
     keys: FreezeDelegatedAccountKeys,
@@ -7671,6 +8603,7 @@ pub fn freeze_delegated_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_verify_writable_privileges<'me, 'info>(
     accounts: FreezeDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7681,6 +8614,7 @@ pub fn freeze_delegated_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_verify_signer_privileges<'me, 'info>(
     accounts: FreezeDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7691,6 +8625,7 @@ pub fn freeze_delegated_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn freeze_delegated_account_verify_account_privileges<'me, 'info>(
     accounts: FreezeDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7700,6 +8635,7 @@ pub fn freeze_delegated_account_verify_account_privileges<'me, 'info>(
 }
 pub const THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN: usize = 5;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct ThawDelegatedAccountAccounts<'me, 'info> {
     ///Delegate
     pub delegate: &'me AccountInfo<'info>,
@@ -7725,6 +8661,7 @@ pub struct ThawDelegatedAccountKeys {
     ///Token Program
     pub token_program: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<ThawDelegatedAccountAccounts<'_, '_>> for ThawDelegatedAccountKeys {
     fn from(accounts: ThawDelegatedAccountAccounts) -> Self {
         Self {
@@ -7736,7 +8673,8 @@ impl From<ThawDelegatedAccountAccounts<'_, '_>> for ThawDelegatedAccountKeys {
         }
     }
 }
-impl From<ThawDelegatedAccountKeys> for [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
+impl From<ThawDelegatedAccountKeys>
+for [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(keys: ThawDelegatedAccountKeys) -> Self {
         [
             AccountMeta {
@@ -7767,7 +8705,8 @@ impl From<ThawDelegatedAccountKeys> for [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_
         ]
     }
 }
-impl From<[Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]> for ThawDelegatedAccountKeys {
+impl From<[Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
+for ThawDelegatedAccountKeys {
     fn from(pubkeys: [Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             delegate: pubkeys[0],
@@ -7778,9 +8717,9 @@ impl From<[Pubkey; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]> for ThawDelegatedAcc
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<ThawDelegatedAccountAccounts<'_, 'info>>
-    for [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] {
     fn from(accounts: ThawDelegatedAccountAccounts<'_, 'info>) -> Self {
         [
             accounts.delegate.clone(),
@@ -7791,10 +8730,12 @@ impl<'info> From<ThawDelegatedAccountAccounts<'_, 'info>>
         ]
     }
 }
+#[cfg(feature = "cpi")]
 impl<'me, 'info> From<&'me [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]>
-    for ThawDelegatedAccountAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN]) -> Self {
+for ThawDelegatedAccountAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             delegate: &arr[0],
             token_account: &arr[1],
@@ -7808,35 +8749,48 @@ pub const THAW_DELEGATED_ACCOUNT_IX_DISCM: u8 = 27u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ThawDelegatedAccountIxData;
 impl ThawDelegatedAccountIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != THAW_DELEGATED_ACCOUNT_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    THAW_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        THAW_DELEGATED_ACCOUNT_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[THAW_DELEGATED_ACCOUNT_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for ThawDelegatedAccountIxData {
+    const NAME: &'static str = "ThawDelegatedAccount";
+    const DISCM: &'static [u8] = &[THAW_DELEGATED_ACCOUNT_IX_DISCM];
+    const ACCOUNTS_LEN: usize = THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN;
+    type Keys = ThawDelegatedAccountKeys;
+    type Args = ();
+}
 pub fn thaw_delegated_account_ix_with_program_id(
     program_id: Pubkey,
     keys: ThawDelegatedAccountKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; THAW_DELEGATED_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -7844,9 +8798,12 @@ pub fn thaw_delegated_account_ix_with_program_id(
         data: ThawDelegatedAccountIxData.try_to_vec()?,
     })
 }
-pub fn thaw_delegated_account_ix(keys: ThawDelegatedAccountKeys) -> std::io::Result<Instruction> {
+pub fn thaw_delegated_account_ix(
+    keys: ThawDelegatedAccountKeys,
+) -> borsh::io::Result<Instruction> {
     thaw_delegated_account_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_invoke_with_program_id(
     program_id: Pubkey,
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
@@ -7855,11 +8812,13 @@ pub fn thaw_delegated_account_invoke_with_program_id(
     let ix = thaw_delegated_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_invoke(
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
 ) -> ProgramResult {
     thaw_delegated_account_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
@@ -7869,12 +8828,14 @@ pub fn thaw_delegated_account_invoke_signed_with_program_id(
     let ix = thaw_delegated_account_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_invoke_signed(
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     thaw_delegated_account_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_verify_account_keys(
     accounts: ThawDelegatedAccountAccounts<'_, '_>,
     keys: ThawDelegatedAccountKeys,
@@ -7892,6 +8853,7 @@ pub fn thaw_delegated_account_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_verify_writable_privileges<'me, 'info>(
     accounts: ThawDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7902,6 +8864,7 @@ pub fn thaw_delegated_account_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_verify_signer_privileges<'me, 'info>(
     accounts: ThawDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7912,6 +8875,7 @@ pub fn thaw_delegated_account_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn thaw_delegated_account_verify_account_privileges<'me, 'info>(
     accounts: ThawDelegatedAccountAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -7921,6 +8885,7 @@ pub fn thaw_delegated_account_verify_account_privileges<'me, 'info>(
 }
 pub const REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN: usize = 2;
 #[derive(Copy, Clone, Debug)]
+#[cfg(feature = "cpi")]
 pub struct RemoveCreatorVerificationAccounts<'me, 'info> {
     ///Metadata (pda of ['metadata', program id, mint id])
     pub metadata: &'me AccountInfo<'info>,
@@ -7934,6 +8899,7 @@ pub struct RemoveCreatorVerificationKeys {
     ///Creator
     pub creator: Pubkey,
 }
+#[cfg(feature = "cpi")]
 impl From<RemoveCreatorVerificationAccounts<'_, '_>> for RemoveCreatorVerificationKeys {
     fn from(accounts: RemoveCreatorVerificationAccounts) -> Self {
         Self {
@@ -7943,8 +8909,7 @@ impl From<RemoveCreatorVerificationAccounts<'_, '_>> for RemoveCreatorVerificati
     }
 }
 impl From<RemoveCreatorVerificationKeys>
-    for [AccountMeta; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]
-{
+for [AccountMeta; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] {
     fn from(keys: RemoveCreatorVerificationKeys) -> Self {
         [
             AccountMeta {
@@ -7960,7 +8925,8 @@ impl From<RemoveCreatorVerificationKeys>
         ]
     }
 }
-impl From<[Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]> for RemoveCreatorVerificationKeys {
+impl From<[Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]>
+for RemoveCreatorVerificationKeys {
     fn from(pubkeys: [Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]) -> Self {
         Self {
             metadata: pubkeys[0],
@@ -7968,17 +8934,22 @@ impl From<[Pubkey; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]> for RemoveCreat
         }
     }
 }
+#[cfg(feature = "cpi")]
 impl<'info> From<RemoveCreatorVerificationAccounts<'_, 'info>>
-    for [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]
-{
+for [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] {
     fn from(accounts: RemoveCreatorVerificationAccounts<'_, 'info>) -> Self {
         [accounts.metadata.clone(), accounts.creator.clone()]
     }
 }
-impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]>
-    for RemoveCreatorVerificationAccounts<'me, 'info>
-{
-    fn from(arr: &'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]) -> Self {
+#[cfg(feature = "cpi")]
+impl<
+    'me,
+    'info,
+> From<&'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN]>
+for RemoveCreatorVerificationAccounts<'me, 'info> {
+    fn from(
+        arr: &'me [AccountInfo<'info>; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN],
+    ) -> Self {
         Self {
             metadata: &arr[0],
             creator: &arr[1],
@@ -7989,35 +8960,48 @@ pub const REMOVE_CREATOR_VERIFICATION_IX_DISCM: u8 = 28u8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct RemoveCreatorVerificationIxData;
 impl RemoveCreatorVerificationIxData {
-    pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+    pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
         let mut reader = buf;
         let mut maybe_discm_buf = [0u8; 1];
         reader.read_exact(&mut maybe_discm_buf)?;
         let maybe_discm = maybe_discm_buf[0];
         if maybe_discm != REMOVE_CREATOR_VERIFICATION_IX_DISCM {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!(
-                    "discm does not match. Expected: {:?}. Received: {:?}",
-                    REMOVE_CREATOR_VERIFICATION_IX_DISCM, maybe_discm
+            return Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other,
+                    format!(
+                        "discm does not match. Expected: {:?}. Received: {:?}",
+                        REMOVE_CREATOR_VERIFICATION_IX_DISCM, maybe_discm
+                    ),
                 ),
-            ));
+            );
         }
         Ok(Self)
     }
-    pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+    pub fn serialize<W: borsh::io::Write>(
+        &self,
+        mut writer: W,
+    ) -> borsh::io::Result<()> {
         writer.write_all(&[REMOVE_CREATOR_VERIFICATION_IX_DISCM])
     }
-    pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+    pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
         let mut data = Vec::new();
         self.serialize(&mut data)?;
         Ok(data)
     }
 }
+#[cfg(feature = "runtime")]
+impl solores_runtime::Instruction for RemoveCreatorVerificationIxData {
+    const NAME: &'static str = "RemoveCreatorVerification";
+    const DISCM: &'static [u8] = &[REMOVE_CREATOR_VERIFICATION_IX_DISCM];
+    const ACCOUNTS_LEN: usize = REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN;
+    type Keys = RemoveCreatorVerificationKeys;
+    type Args = ();
+}
 pub fn remove_creator_verification_ix_with_program_id(
     program_id: Pubkey,
     keys: RemoveCreatorVerificationKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     let metas: [AccountMeta; REMOVE_CREATOR_VERIFICATION_IX_ACCOUNTS_LEN] = keys.into();
     Ok(Instruction {
         program_id,
@@ -8027,9 +9011,10 @@ pub fn remove_creator_verification_ix_with_program_id(
 }
 pub fn remove_creator_verification_ix(
     keys: RemoveCreatorVerificationKeys,
-) -> std::io::Result<Instruction> {
+) -> borsh::io::Result<Instruction> {
     remove_creator_verification_ix_with_program_id(crate::ID, keys)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_invoke_with_program_id(
     program_id: Pubkey,
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
@@ -8038,11 +9023,13 @@ pub fn remove_creator_verification_invoke_with_program_id(
     let ix = remove_creator_verification_ix_with_program_id(program_id, keys)?;
     invoke_instruction(&ix, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_invoke(
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
 ) -> ProgramResult {
     remove_creator_verification_invoke_with_program_id(crate::ID, accounts)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_invoke_signed_with_program_id(
     program_id: Pubkey,
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
@@ -8052,12 +9039,14 @@ pub fn remove_creator_verification_invoke_signed_with_program_id(
     let ix = remove_creator_verification_ix_with_program_id(program_id, keys)?;
     invoke_instruction_signed(&ix, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_invoke_signed(
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
     remove_creator_verification_invoke_signed_with_program_id(crate::ID, accounts, seeds)
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_verify_account_keys(
     accounts: RemoveCreatorVerificationAccounts<'_, '_>,
     keys: RemoveCreatorVerificationKeys,
@@ -8072,6 +9061,7 @@ pub fn remove_creator_verification_verify_account_keys(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_verify_writable_privileges<'me, 'info>(
     accounts: RemoveCreatorVerificationAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -8082,6 +9072,7 @@ pub fn remove_creator_verification_verify_writable_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_verify_signer_privileges<'me, 'info>(
     accounts: RemoveCreatorVerificationAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -8092,6 +9083,7 @@ pub fn remove_creator_verification_verify_signer_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[cfg(feature = "cpi")]
 pub fn remove_creator_verification_verify_account_privileges<'me, 'info>(
     accounts: RemoveCreatorVerificationAccounts<'me, 'info>,
 ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
@@ -0,0 +1,52 @@
+//! Traits implemented by the items of crates generated by [solores](https://crates.io/crates/solores)
+//! behind their `runtime` feature, so that code can be generic over the instructions, accounts,
+//! events and errors of any generated program interface.
+
+#![no_std]
+
+/// An instruction of a program.
+///
+/// Implemented by the instruction's `*IxData` struct, or its `*IxArgs` struct for bincode IDLs.
+pub trait Instruction {
+    /// Name of the instruction in the IDL
+    const NAME: &'static str;
+
+    /// Bytes that the instruction's data starts with
+    const DISCM: &'static [u8];
+
+    /// Number of accounts the instruction takes
+    const ACCOUNTS_LEN: usize;
+
+    /// The `*Keys` struct of the instruction's accounts, `()` if it takes none
+    type Keys;
+
+    /// The `*IxArgs` struct of the instruction's args, `()` if it takes none
+    type Args;
+}
+
+/// An account owned by a program. Implemented by the account's data struct.
+pub trait ProgramAccount {
+    /// Name of the account in the IDL
+    const NAME: &'static str;
+
+    /// Bytes that the account's data starts with, empty if the IDL format has no account discriminators
+    const DISCM: &'static [u8];
+}
+
+/// An event emitted by a program. Implemented by the event's data struct.
+pub trait ProgramEvent {
+    /// Name of the event in the IDL
+    const NAME: &'static str;
+
+    /// Bytes that the event's data starts with
+    const DISCM: &'static [u8];
+}
+
+/// A program's errors enum, whose variants are the program's custom error codes
+pub trait ProgramErrorCode: Sized {
+    /// The `ProgramError::Custom` code of this error
+    fn code(&self) -> u32;
+
+    /// The error with `ProgramError::Custom` code `code`, `None` if the program has no such error
+    fn from_code(code: u32) -> Option<Self>;
+}
@@ -0,0 +1,581 @@
+//! `--compact` output: the instructions module defines `macro_rules!` once and invokes them
+//! once per instruction with its accounts and args, instead of expanding every instruction's items.
+//!
+//! prettyplease doesn't format macro bodies, so the definitions are written verbatim from
+//! the templates here and the format-specific ones in each format's instructions module.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::LitInt;
+
+use crate::{
+    naming::{pascal_case, shouty_snake_case, snake_case, snake_ident},
+    target::Target,
+};
+
+const IX_ACCOUNTS_SOLANA_PROGRAM: &str = r#"/// Defines an instruction's `*_IX_ACCOUNTS_LEN`, `*Accounts` and `*Keys` structs, their conversions
+/// from and into pubkeys, `AccountMeta`s and `AccountInfo`s, and `*_verify_account_keys()`.
+/// Accounts are listed in order, followed by `(signer writable)` if they are signers and writable.
+macro_rules! ix_accounts {
+    (
+        $accounts:ident, $keys:ident, $len:ident = $n:literal, $verify_keys:ident,
+        [$($(#[$attr:meta])* $account:ident $(($($flag:ident)+))?),* $(,)?]
+    ) => {
+        pub const $len: usize = $n;
+        #[derive(Copy, Clone, Debug)]
+        #[cfg(feature = "cpi")]
+        pub struct $accounts<'me, 'info> {
+            $($(#[$attr])* pub $account: &'me AccountInfo<'info>,)*
+        }
+        KEYS_DERIVES
+        pub struct $keys {
+            $($(#[$attr])* pub $account: Pubkey,)*
+        }
+        #[cfg(feature = "cpi")]
+        impl From<$accounts<'_, '_>> for $keys {
+            fn from(accounts: $accounts) -> Self {
+                Self {
+                    $($account: *accounts.$account.key,)*
+                }
+            }
+        }
+        impl From<$keys> for [AccountMeta; $len] {
+            fn from(keys: $keys) -> Self {
+                [$(AccountMeta {
+                    pubkey: keys.$account,
+                    is_signer: ix_accounts!(@is_signer $($($flag)+)?),
+                    is_writable: ix_accounts!(@is_writable $($($flag)+)?),
+                }),*]
+            }
+        }
+        impl From<[Pubkey; $len]> for $keys {
+            fn from(pubkeys: [Pubkey; $len]) -> Self {
+                let [$($account),*] = pubkeys;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'info> From<$accounts<'_, 'info>> for [AccountInfo<'info>; $len] {
+            fn from(accounts: $accounts<'_, 'info>) -> Self {
+                [$(accounts.$account.clone()),*]
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'me, 'info> From<&'me [AccountInfo<'info>; $len]> for $accounts<'me, 'info> {
+            fn from(arr: &'me [AccountInfo<'info>; $len]) -> Self {
+                let [$($account),*] = arr;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $verify_keys(
+            accounts: $accounts<'_, '_>,
+            keys: $keys,
+        ) -> Result<(), (Pubkey, Pubkey)> {
+            $(if *accounts.$account.key != keys.$account {
+                return Err((*accounts.$account.key, keys.$account));
+            })*
+            Ok(())
+        }
+    };
+    (@is_signer) => { false };
+    (@is_signer signer $($flag:ident)*) => { true };
+    (@is_signer $other:ident $($flag:ident)*) => { ix_accounts!(@is_signer $($flag)*) };
+    (@is_writable) => { false };
+    (@is_writable writable $($flag:ident)*) => { true };
+    (@is_writable $other:ident $($flag:ident)*) => { ix_accounts!(@is_writable $($flag)*) };
+}
+"#;
+
+const IX_ACCOUNTS_PINOCCHIO: &str = r#"/// Defines an instruction's `*_IX_ACCOUNTS_LEN`, `*Accounts` and `*Keys` structs, their conversions
+/// from and into pubkeys, `AccountMeta`s and `AccountInfo`s, and `*_verify_account_keys()`.
+/// Accounts are listed in order, followed by `(signer writable)` if they are signers and writable.
+macro_rules! ix_accounts {
+    (
+        $accounts:ident, $keys:ident, $len:ident = $n:literal, $verify_keys:ident,
+        [$($(#[$attr:meta])* $account:ident $(($($flag:ident)+))?),* $(,)?]
+    ) => {
+        pub const $len: usize = $n;
+        #[derive(Copy, Clone)]
+        #[cfg(feature = "cpi")]
+        pub struct $accounts<'me> {
+            $($(#[$attr])* pub $account: &'me AccountInfo,)*
+        }
+        KEYS_DERIVES
+        pub struct $keys {
+            $($(#[$attr])* pub $account: Pubkey,)*
+        }
+        #[cfg(feature = "cpi")]
+        impl From<$accounts<'_>> for $keys {
+            fn from(accounts: $accounts) -> Self {
+                Self {
+                    $($account: Pubkey::new_from_array(*accounts.$account.key()),)*
+                }
+            }
+        }
+        impl From<$keys> for [AccountMeta; $len] {
+            fn from(keys: $keys) -> Self {
+                [$(AccountMeta {
+                    pubkey: keys.$account,
+                    is_signer: ix_accounts!(@is_signer $($($flag)+)?),
+                    is_writable: ix_accounts!(@is_writable $($($flag)+)?),
+                }),*]
+            }
+        }
+        impl From<[Pubkey; $len]> for $keys {
+            fn from(pubkeys: [Pubkey; $len]) -> Self {
+                let [$($account),*] = pubkeys;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'me> From<$accounts<'me>> for [&'me AccountInfo; $len] {
+            fn from(accounts: $accounts<'me>) -> Self {
+                [$(accounts.$account),*]
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'me> From<$accounts<'me>> for [CpiAccountMeta<'me>; $len] {
+            fn from(accounts: $accounts<'me>) -> Self {
+                [$(CpiAccountMeta {
+                    pubkey: accounts.$account.key(),
+                    is_writable: ix_accounts!(@is_writable $($($flag)+)?),
+                    is_signer: ix_accounts!(@is_signer $($($flag)+)?),
+                }),*]
+            }
+        }
+        #[cfg(feature = "cpi")]
+        impl<'me> From<&'me [AccountInfo; $len]> for $accounts<'me> {
+            fn from(arr: &'me [AccountInfo; $len]) -> Self {
+                let [$($account),*] = arr;
+                Self { $($account),* }
+            }
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $verify_keys(
+            accounts: $accounts<'_>,
+            keys: $keys,
+        ) -> Result<(), (Pubkey, Pubkey)> {
+            $(if Pubkey::new_from_array(*accounts.$account.key()) != keys.$account {
+                return Err((Pubkey::new_from_array(*accounts.$account.key()), keys.$account));
+            })*
+            Ok(())
+        }
+    };
+    (@is_signer) => { false };
+    (@is_signer signer $($flag:ident)*) => { true };
+    (@is_signer $other:ident $($flag:ident)*) => { ix_accounts!(@is_signer $($flag)*) };
+    (@is_writable) => { false };
+    (@is_writable writable $($flag:ident)*) => { true };
+    (@is_writable $other:ident $($flag:ident)*) => { ix_accounts!(@is_writable $($flag)*) };
+}
+"#;
+
+const IX_PRIVILEGES_SOLANA_PROGRAM: &str = r#"/// Defines an instruction's `*_verify_account_privileges()`, and its `*_verify_writable_privileges()`
+/// and `*_verify_signer_privileges()` if it has writable and signer accounts respectively.
+macro_rules! ix_privileges {
+    (
+        $accounts:ident, $verify:ident
+        $(, writable $verify_writable:ident [$($writable:ident),+])?
+        $(, signer $verify_signer:ident [$($signer:ident),+])?
+    ) => {
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_writable<'me, 'info>(
+                accounts: $accounts<'me, 'info>,
+            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                for should_be_writable in [$(accounts.$writable),+] {
+                    if !should_be_writable.is_writable {
+                        return Err((should_be_writable, ProgramError::InvalidAccountData));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_signer<'me, 'info>(
+                accounts: $accounts<'me, 'info>,
+            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                for should_be_signer in [$(accounts.$signer),+] {
+                    if !should_be_signer.is_signer {
+                        return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        #[cfg(feature = "cpi")]
+        pub fn $verify<'me, 'info>(
+            accounts: $accounts<'me, 'info>,
+        ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+            $($verify_writable(accounts)?;)?
+            $($verify_signer(accounts)?;)?
+            Ok(())
+        }
+    };
+}
+"#;
+
+const IX_PRIVILEGES_PINOCCHIO: &str = r#"/// Defines an instruction's `*_verify_account_privileges()`, and its `*_verify_writable_privileges()`
+/// and `*_verify_signer_privileges()` if it has writable and signer accounts respectively.
+macro_rules! ix_privileges {
+    (
+        $accounts:ident, $verify:ident
+        $(, writable $verify_writable:ident [$($writable:ident),+])?
+        $(, signer $verify_signer:ident [$($signer:ident),+])?
+    ) => {
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_writable<'me>(
+                accounts: $accounts<'me>,
+            ) -> Result<(), (&'me AccountInfo, ProgramError)> {
+                for should_be_writable in [$(accounts.$writable),+] {
+                    if !should_be_writable.is_writable() {
+                        return Err((should_be_writable, ProgramError::InvalidAccountData));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        $(
+            #[cfg(feature = "cpi")]
+            pub fn $verify_signer<'me>(
+                accounts: $accounts<'me>,
+            ) -> Result<(), (&'me AccountInfo, ProgramError)> {
+                for should_be_signer in [$(accounts.$signer),+] {
+                    if !should_be_signer.is_signer() {
+                        return Err((should_be_signer, ProgramError::MissingRequiredSignature));
+                    }
+                }
+                Ok(())
+            }
+        )?
+        #[cfg(feature = "cpi")]
+        pub fn $verify<'me>(
+            accounts: $accounts<'me>,
+        ) -> Result<(), (&'me AccountInfo, ProgramError)> {
+            $($verify_writable(accounts)?;)?
+            $($verify_signer(accounts)?;)?
+            Ok(())
+        }
+    };
+}
+"#;
+
+const BORSH_IX_FNS_SOLANA_PROGRAM: &str = r#"/// Defines an instruction's `*_ix()`, `*_invoke()` and `*_invoke_signed()`,
+/// and their `*_with_program_id()` variants.
+macro_rules! ix_fns {
+    (
+        $ix_data:ident $(($ix_args:ident))?,
+        [
+            $ix:ident,
+            $ix_with_program_id:ident,
+            $invoke:ident,
+            $invoke_with_program_id:ident,
+            $invoke_signed:ident,
+            $invoke_signed_with_program_id:ident $(,)?
+        ]
+        $(, $accounts:ident, $keys:ident, $len:ident)?
+    ) => {
+        ix_fns!(
+            @impl $ix_data,
+            [$ix, $ix_with_program_id, $invoke, $invoke_with_program_id, $invoke_signed, $invoke_signed_with_program_id],
+            [$(keys $keys accounts $accounts $len)?],
+            [$(args $ix_args)?]
+        );
+    };
+    (
+        @impl $ix_data:ident,
+        [$ix:ident, $ix_with_program_id:ident, $invoke:ident, $invoke_with_program_id:ident, $invoke_signed:ident, $invoke_signed_with_program_id:ident],
+        [$($keys_var:ident $keys:ident $accounts_var:ident $accounts:ident $len:ident)?],
+        [$($args_var:ident $ix_args:ident)?]
+    ) => {
+        pub fn $ix_with_program_id(
+            program_id: Pubkey,
+            $($keys_var: $keys,)?
+            $($args_var: $ix_args,)?
+        ) -> borsh::io::Result<Instruction> {
+            Ok(Instruction {
+                program_id,
+                accounts: ix_fns!(@or (Vec::new()) $((Vec::from(<[AccountMeta; $len]>::from($keys_var))))?),
+                data: ix_fns!(@or ($ix_data) $(($ix_data($args_var)))?).try_to_vec()?,
+            })
+        }
+        pub fn $ix($($keys_var: $keys,)? $($args_var: $ix_args,)?) -> borsh::io::Result<Instruction> {
+            $ix_with_program_id(crate::ID, $($keys_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?)?;
+            ix_fns!(@or (invoke(&ix, &[])) $((invoke_instruction(&ix, $accounts_var)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke($($accounts_var: $accounts<'_, '_>,)? $($args_var: $ix_args,)?) -> ProgramResult {
+            $invoke_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?)?;
+            ix_fns!(@or (invoke_signed(&ix, &[], seeds)) $((invoke_instruction_signed(&ix, $accounts_var, seeds)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed(
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)? seeds)
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+"#;
+
+const BORSH_IX_FNS_PINOCCHIO: &str = r#"/// Defines an instruction's `*_ix()`, `*_invoke()` and `*_invoke_signed()`,
+/// and their `*_with_program_id()` variants. The data is serialized into a `[u8; N]` stack buffer.
+macro_rules! ix_fns {
+    (
+        $ix_data:ident $(($ix_args:ident))?, [u8; $buf_len:expr],
+        [
+            $ix:ident,
+            $ix_with_program_id:ident,
+            $invoke:ident,
+            $invoke_with_program_id:ident,
+            $invoke_signed:ident,
+            $invoke_signed_with_program_id:ident $(,)?
+        ]
+        $(, $accounts:ident, $keys:ident, $len:ident)?
+    ) => {
+        ix_fns!(
+            @impl $ix_data, $buf_len,
+            [$ix, $ix_with_program_id, $invoke, $invoke_with_program_id, $invoke_signed, $invoke_signed_with_program_id],
+            [$(keys $keys accounts $accounts $len)?],
+            [$(args $ix_args)?]
+        );
+    };
+    (
+        @impl $ix_data:ident, $buf_len:expr,
+        [$ix:ident, $ix_with_program_id:ident, $invoke:ident, $invoke_with_program_id:ident, $invoke_signed:ident, $invoke_signed_with_program_id:ident],
+        [$($keys_var:ident $keys:ident $accounts_var:ident $accounts:ident $len:ident)?],
+        [$($args_var:ident $ix_args:ident)?]
+    ) => {
+        pub fn $ix_with_program_id(
+            program_id: Pubkey,
+            $($keys_var: $keys,)?
+            $($args_var: $ix_args,)?
+        ) -> borsh::io::Result<Instruction> {
+            Ok(Instruction {
+                program_id,
+                accounts: ix_fns!(@or (Vec::new()) $((Vec::from(<[AccountMeta; $len]>::from($keys_var))))?),
+                data: ix_fns!(@or ($ix_data) $(($ix_data($args_var)))?).try_to_vec()?,
+            })
+        }
+        pub fn $ix($($keys_var: $keys,)? $($args_var: $ix_args,)?) -> borsh::io::Result<Instruction> {
+            $ix_with_program_id(crate::ID, $($keys_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+            signers: &[Signer],
+        ) -> ProgramResult {
+            let mut buf = [0u8; $buf_len];
+            let data = write_ix_data(&mut buf, |writer| {
+                ix_fns!(@or ($ix_data) $(($ix_data($args_var)))?).serialize(writer)
+            })?;
+            ix_fns!(
+                @or (invoke_signed(
+                    &CpiInstruction {
+                        program_id: &program_id.to_bytes(),
+                        accounts: &[],
+                        data,
+                    },
+                    &[],
+                    signers,
+                ))
+                $((invoke_instruction_signed(&program_id, $accounts_var, data, signers)))?
+            )
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed(
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+            signers: &[Signer],
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)? signers)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(program_id, $($accounts_var,)? $($args_var,)? &[])
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke($($accounts_var: $accounts<'_>,)? $($args_var: $ix_args,)?) -> ProgramResult {
+            $invoke_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)?)
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+"#;
+
+/// `ix_accounts!`, whose `*Keys` structs have `keys_derives`
+pub fn ix_accounts_macro(target: Target, keys_derives: &str) -> String {
+    let template = match target {
+        Target::SolanaProgram => IX_ACCOUNTS_SOLANA_PROGRAM,
+        Target::Pinocchio => IX_ACCOUNTS_PINOCCHIO,
+    };
+    template.replace("KEYS_DERIVES", keys_derives)
+}
+
+pub fn ix_privileges_macro(target: Target) -> &'static str {
+    match target {
+        Target::SolanaProgram => IX_PRIVILEGES_SOLANA_PROGRAM,
+        Target::Pinocchio => IX_PRIVILEGES_PINOCCHIO,
+    }
+}
+
+/// `ix_fns!` of the formats whose instruction data is a borsh-serialized `*IxData`
+pub fn borsh_ix_fns_macro(target: Target) -> &'static str {
+    match target {
+        Target::SolanaProgram => BORSH_IX_FNS_SOLANA_PROGRAM,
+        Target::Pinocchio => BORSH_IX_FNS_PINOCCHIO,
+    }
+}
+
+/// An instruction account as listed in `ix_accounts!` and `ix_privileges!` invocations
+pub struct MacroAccount<'a> {
+    pub name: &'a str,
+    pub desc: Option<&'a str>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl MacroAccount<'_> {
+    fn field_ident(&self) -> Ident {
+        snake_ident(self.name)
+    }
+}
+
+impl ToTokens for MacroAccount<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(desc) = self.desc {
+            tokens.extend(quote! { #[doc = #desc] });
+        }
+        self.field_ident().to_tokens(tokens);
+        let mut flags = Vec::new();
+        if self.is_signer {
+            flags.push(format_ident!("signer"));
+        }
+        if self.is_writable {
+            flags.push(format_ident!("writable"));
+        }
+        if !flags.is_empty() {
+            tokens.extend(quote! { (#(#flags)*) });
+        }
+    }
+}
+
+/// `ix_accounts!(..)` and, if any of `accounts` are privileged, `ix_privileges!(..)`
+/// for the instruction named `rust_name`
+pub fn ix_accounts_invocations(rust_name: &str, accounts: &[MacroAccount]) -> TokenStream {
+    let pascal = pascal_case(rust_name);
+    let shouty = shouty_snake_case(rust_name);
+    let snake = snake_case(rust_name);
+    let accounts_ident = format_ident!("{pascal}Accounts");
+    let keys_ident = format_ident!("{pascal}Keys");
+    let accounts_len_ident = format_ident!("{shouty}_IX_ACCOUNTS_LEN");
+    let verify_keys_ident = format_ident!("{snake}_verify_account_keys");
+    let n_accounts = LitInt::new(&accounts.len().to_string(), Span::call_site());
+    let mut res = quote! {
+        ix_accounts!(
+            #accounts_ident, #keys_ident, #accounts_len_ident = #n_accounts, #verify_keys_ident,
+            [#(#accounts),*]
+        );
+    };
+    let writables: Vec<_> = accounts
+        .iter()
+        .filter(|a| a.is_writable)
+        .map(MacroAccount::field_ident)
+        .collect();
+    let signers: Vec<_> = accounts
+        .iter()
+        .filter(|a| a.is_signer)
+        .map(MacroAccount::field_ident)
+        .collect();
+    if writables.is_empty() && signers.is_empty() {
+        return res;
+    }
+    let verify_ident = format_ident!("{snake}_verify_account_privileges");
+    let mut privileges = quote! { #accounts_ident, #verify_ident };
+    if !writables.is_empty() {
+        let verify_writable_ident = format_ident!("{snake}_verify_writable_privileges");
+        privileges.extend(quote! { , writable #verify_writable_ident [#(#writables),*] });
+    }
+    if !signers.is_empty() {
+        let verify_signer_ident = format_ident!("{snake}_verify_signer_privileges");
+        privileges.extend(quote! { , signer #verify_signer_ident [#(#signers),*] });
+    }
+    res.extend(quote! {
+        ix_privileges!(#privileges);
+    });
+    res
+}
+
+/// The `[..]` of the `*_ix*()` and `*_invoke*()` function names in `ix_fns!` invocations
+pub fn ix_fn_idents(rust_name: &str) -> TokenStream {
+    let snake = snake_case(rust_name);
+    let idents = [
+        "_ix",
+        "_ix_with_program_id",
+        "_invoke",
+        "_invoke_with_program_id",
+        "_invoke_signed",
+        "_invoke_signed_with_program_id",
+    ]
+    .iter()
+    .map(|suffix| format_ident!("{snake}{suffix}"));
+    quote! { [#(#idents),*] }
+}
+
+/// `ix_fns!(..)` of the formats whose instruction data is a borsh-serialized `*IxData`.
+/// `data_buf_len` is the length of pinocchio `*_invoke*()` functions' data buffer.
+pub fn borsh_ix_fns_invocation(
+    rust_name: &str,
+    has_accounts: bool,
+    has_ix_args: bool,
+    data_buf_len: Option<TokenStream>,
+) -> TokenStream {
+    let pascal = pascal_case(rust_name);
+    let ix_data_ident = format_ident!("{pascal}IxData");
+    let ix_args = has_ix_args.then(|| {
+        let ix_args_ident = format_ident!("{pascal}IxArgs");
+        quote! { (#ix_args_ident) }
+    });
+    let data_buf = data_buf_len.map(|len| quote! { , [u8; #len] });
+    let fn_idents = ix_fn_idents(rust_name);
+    let accounts = has_accounts.then(|| {
+        let accounts_ident = format_ident!("{pascal}Accounts");
+        let keys_ident = format_ident!("{pascal}Keys");
+        let accounts_len_ident = format_ident!("{}_IX_ACCOUNTS_LEN", shouty_snake_case(rust_name));
+        quote! { , #accounts_ident, #keys_ident, #accounts_len_ident }
+    });
+    quote! {
+        ix_fns!(#ix_data_ident #ix_args #data_buf, #fn_idents #accounts);
+    }
+}
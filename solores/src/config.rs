@@ -0,0 +1,478 @@
+//! `solores.toml` config file support.
+//!
+//! The config file can contain all of [`Args`]'s fields in kebab-case,
+//! along with per-item customisations for the IDL's typedefs, accounts and instructions:
+//!
+//! ```toml
+//! idl-paths = ["idl.json"]
+//! output-dir = "interfaces"
+//! zero-copy = ["Ticks", "Seat"]
+//! borsh-vers = "workspace = true"
+//! exclude-ix = ["*Twap*"]
+//!
+//! [typedefs.MarketHeader]
+//! rename = "PhoenixMarketHeader"
+//! derives = ["Eq", "Hash"]
+//! zero-copy = true
+//!
+//! [typedefs.MarketHeader.types]
+//! raw_base_units_per_base_unit = "u32"
+//!
+//! [instructions.swap]
+//! rename = "phoenix_swap"
+//!
+//! [instructions.swap.accounts]
+//! seat = "trader_seat"
+//!
+//! # in order of precedence
+//! [[program-id-for]]
+//! feature = "devnet"
+//! program-id = "DEVNET1111111111111111111111111111111111111"
+//!
+//! [map-type]
+//! I80F48 = "fixed::types::I80F48"
+//!
+//! [map-type-vers]
+//! fixed = "^1.27"
+//! ```
+//!
+//! Values passed on the command line override those in the config file.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::{parser::ValueSource, ArgMatches, Parser};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    idl_format::IdlFormatKind, target::Target, write_cargotoml::relative_path, Args, Error, Result,
+    DEFAULT_PROGRAM_ID_MSG,
+};
+
+pub const DEFAULT_CONFIG_FILE_NAME: &str = "solores.toml";
+
+/// Invokes `$m!` with the fields that [`Config`] and [`Args`] both have, with the same type,
+/// other than `idl_paths` and `output_dir`
+macro_rules! with_plain_fields {
+    ($m:ident) => {
+        $m!(
+            strict,
+            output_crate_name,
+            zero_copy,
+            solana_program_vers,
+            borsh_vers,
+            thiserror_vers,
+            num_derive_vers,
+            num_traits_vers,
+            serde_vers,
+            bytemuck_vers,
+            serde_with_vers,
+            solana_rpc_client_api_vers,
+            pinocchio_vers,
+            split_solana_crates_vers,
+            anchor_lang_vers,
+            solores_runtime_vers,
+            extern_crate,
+            only_ix,
+            exclude_ix,
+            only_account,
+            exclude_account,
+            only_event,
+            exclude_event,
+            only_type,
+            exclude_type,
+            no_std,
+            target,
+            split_solana_crates,
+            solana_program_path,
+            split_modules,
+            compact
+        )
+    };
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub idl_paths: Option<Vec<PathBuf>>,
+    pub format: Option<IdlFormatKind>,
+    pub strict: Option<bool>,
+    pub output_dir: Option<PathBuf>,
+    pub output_crate_name: Option<String>,
+    pub program_id: Option<String>,
+    pub program_id_for: Option<Vec<ProgramIdFor>>,
+    pub zero_copy: Option<Vec<String>>,
+    pub solana_program_vers: Option<String>,
+    pub borsh_vers: Option<String>,
+    pub thiserror_vers: Option<String>,
+    pub num_derive_vers: Option<String>,
+    pub num_traits_vers: Option<String>,
+    pub serde_vers: Option<String>,
+    pub bytemuck_vers: Option<String>,
+    pub serde_with_vers: Option<String>,
+    pub solana_rpc_client_api_vers: Option<String>,
+    pub pinocchio_vers: Option<String>,
+    pub split_solana_crates_vers: Option<String>,
+    pub anchor_lang_vers: Option<String>,
+    pub solores_runtime_vers: Option<String>,
+    pub map_type: Option<BTreeMap<String, String>>,
+    pub map_type_vers: Option<BTreeMap<String, String>>,
+    pub extern_crate: Option<Vec<PathBuf>>,
+    pub only_ix: Option<Vec<String>>,
+    pub exclude_ix: Option<Vec<String>>,
+    pub only_account: Option<Vec<String>>,
+    pub exclude_account: Option<Vec<String>>,
+    pub only_event: Option<Vec<String>>,
+    pub exclude_event: Option<Vec<String>>,
+    pub only_type: Option<Vec<String>>,
+    pub exclude_type: Option<Vec<String>>,
+    pub no_std: Option<bool>,
+    pub target: Option<Target>,
+    pub split_solana_crates: Option<bool>,
+    pub solana_program_path: Option<String>,
+    pub split_modules: Option<bool>,
+    pub compact: Option<bool>,
+    pub module: Option<String>,
+
+    /// keyed by IDL typedef name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub typedefs: HashMap<String, ItemConfig>,
+
+    /// keyed by IDL account name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub accounts: HashMap<String, ItemConfig>,
+
+    /// keyed by IDL instruction name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub instructions: HashMap<String, ItemConfig>,
+}
+
+/// A `--program-id-for FEATURE=PUBKEY`. An array of tables rather than a table
+/// since the first enabled feature's program ID applies.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProgramIdFor {
+    pub feature: String,
+    pub program_id: String,
+}
+
+/// Customisations for a single typedef, account or instruction
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ItemConfig {
+    /// Name to use for the generated rust item instead of the IDL name.
+    /// Does not affect discriminants.
+    pub rename: Option<String>,
+
+    /// Additional derives for the generated struct/enum.
+    /// For instructions, these are applied to `*IxArgs`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derives: Vec<String>,
+
+    /// Same as passing the item's name to `-z`. Not applicable to instructions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub zero_copy: bool,
+
+    /// Rust types to use for the given struct fields/instruction args
+    /// instead of the types in the IDL, keyed by IDL field/arg name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub types: HashMap<String, String>,
+
+    /// Names to use for the given instruction accounts' fields instead of their IDL names,
+    /// keyed by IDL account name. Anchor nested account structs' accounts are named `{struct}_{account}`.
+    /// Only applicable to instructions.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub accounts: HashMap<String, String>,
+}
+
+impl Config {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let s = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        let mut config: Self = toml::from_str(&s).map_err(|source| Error::InvalidToml {
+            path: path.to_owned(),
+            source,
+        })?;
+        // relative paths in the config file are relative to the config file
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        config.idl_paths = config
+            .idl_paths
+            .map(|paths| paths.into_iter().map(|p| base_dir.join(p)).collect());
+        config.output_dir = config.output_dir.map(|p| base_dir.join(p));
+        config.extern_crate = config
+            .extern_crate
+            .map(|paths| paths.into_iter().map(|p| base_dir.join(p)).collect());
+        Ok(config)
+    }
+
+    /// Sets all fields of `args` that were not explicitly passed on the command line
+    /// to the values in this config, if present.
+    ///
+    /// `matches` must be the `ArgMatches` `args` was created from.
+    pub fn merge_into(self, args: &mut Args, matches: &ArgMatches) {
+        let is_from_cli = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(
+                    if let Some(v) = self.$field {
+                        if !is_from_cli(stringify!($field)) {
+                            args.$field = v;
+                        }
+                    }
+                )*
+            };
+        }
+
+        merge!(idl_paths, output_dir);
+        with_plain_fields!(merge);
+        if self.program_id.is_some() && !is_from_cli("program_id") {
+            args.program_id = self.program_id;
+        }
+        if self.format.is_some() && !is_from_cli("format") {
+            args.format = self.format;
+        }
+        if self.module.is_some() && !is_from_cli("module") {
+            args.module = self.module;
+        }
+        if let Some(v) = self.program_id_for {
+            if !is_from_cli("program_id_for") {
+                args.program_id_for = v.into_iter().map(|p| (p.feature, p.program_id)).collect();
+            }
+        }
+        if let Some(v) = self.map_type {
+            if !is_from_cli("map_type") {
+                args.map_type = v.into_iter().collect();
+            }
+        }
+        if let Some(v) = self.map_type_vers {
+            if !is_from_cli("map_type_vers") {
+                args.map_type_vers = v.into_iter().collect();
+            }
+        }
+
+        for (name, _) in self
+            .typedefs
+            .iter()
+            .chain(self.accounts.iter())
+            .filter(|(_, c)| c.zero_copy)
+        {
+            if !args.zero_copy.contains(name) {
+                args.zero_copy.push(name.clone());
+            }
+        }
+        args.typedef_configs = self.typedefs;
+        args.account_configs = self.accounts;
+        args.instruction_configs = self.instructions;
+    }
+
+    /// The options of `args` that differ from the defaults, as the config that generates the same crate.
+    /// The IDL paths and output directory are left out since they don't affect the crate's contents.
+    /// The extern crate paths are made relative to the output directory, as if the config file was in it.
+    pub fn from_args(args: &Args) -> Self {
+        let defaults = Args::parse_from(["solores"]);
+        // entries derived from --extern-crate are left out, the extern crate paths are enough
+        let non_empty_map = |entries: &[(String, String)], derived: &HashSet<String>| {
+            let res: BTreeMap<String, String> = entries
+                .iter()
+                .filter(|(name, _)| !derived.contains(name))
+                .cloned()
+                .collect();
+            (!res.is_empty()).then_some(res)
+        };
+
+        macro_rules! changed {
+            ($($field:ident),*) => {
+                Self {
+                    $($field: (args.$field != defaults.$field).then(|| args.$field.clone()),)*
+                    idl_paths: None,
+                    output_dir: None,
+                    format: args.format,
+                    program_id: args
+                        .program_id
+                        .clone()
+                        .filter(|id| id != DEFAULT_PROGRAM_ID_MSG),
+                    program_id_for: (!args.program_id_for.is_empty()).then(|| {
+                        args.program_id_for
+                            .iter()
+                            .map(|(feature, program_id)| ProgramIdFor {
+                                feature: feature.clone(),
+                                program_id: program_id.clone(),
+                            })
+                            .collect()
+                    }),
+                    map_type: non_empty_map(&args.map_type, &args.extern_crate_types),
+                    map_type_vers: non_empty_map(&args.map_type_vers, &args.extern_crate_packages),
+                    module: args.module.clone(),
+                    typedefs: args.typedef_configs.clone(),
+                    accounts: args.account_configs.clone(),
+                    instructions: args.instruction_configs.clone(),
+                }
+            };
+        }
+
+        let mut res = with_plain_fields!(changed);
+        res.extern_crate = res.extern_crate.map(|paths| {
+            paths
+                .iter()
+                .map(|p| relative_path(p, &args.output_dir).unwrap_or_else(|| p.clone()))
+                .collect()
+        });
+        res
+    }
+
+    /// `solores.toml` contents with the keys in sorted order
+    pub fn to_toml(&self) -> toml::Table {
+        toml::Table::try_from(self).unwrap()
+    }
+}
+
+/// Warns about entries in `configs` that don't match any of the IDL's items
+pub fn warn_unmatched<'a>(
+    kind: &str,
+    configs: &HashMap<String, ItemConfig>,
+    idl_names: impl IntoIterator<Item = &'a str>,
+) {
+    let idl_names: HashSet<&str> = idl_names.into_iter().collect();
+    for name in configs.keys() {
+        if !idl_names.contains(name.as_str()) {
+            log::warn!("config for {kind} {name} does not match any {kind} in IDL");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, FromArgMatches};
+
+    const CONFIG: &str = r#"
+        idl-paths = ["idl.json"]
+        output-crate-name = "from_config"
+        borsh-vers = "workspace = true"
+        zero-copy = ["Ticks"]
+
+        [typedefs.MarketHeader]
+        rename = "PhoenixMarketHeader"
+        zero-copy = true
+
+        [map-type]
+        I80F48 = "fixed::types::I80F48"
+    "#;
+
+    fn parse_args(cli: &[&str]) -> (Args, ArgMatches) {
+        let matches = Args::command().get_matches_from(cli);
+        let args = Args::from_arg_matches(&matches).unwrap();
+        (args, matches)
+    }
+
+    #[test]
+    fn test_config_fills_defaults() {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+        let (mut args, matches) = parse_args(&["solores"]);
+        config.merge_into(&mut args, &matches);
+        assert_eq!(args.idl_paths, [PathBuf::from("idl.json")]);
+        assert_eq!(args.output_crate_name, "from_config");
+        assert_eq!(args.borsh_vers, "workspace = true");
+        assert_eq!(args.zero_copy, ["Ticks", "MarketHeader"]);
+        assert_eq!(
+            args.typedef_configs["MarketHeader"].rename.as_deref(),
+            Some("PhoenixMarketHeader")
+        );
+        assert_eq!(
+            args.map_type,
+            [("I80F48".to_owned(), "fixed::types::I80F48".to_owned())]
+        );
+    }
+
+    #[test]
+    fn test_cli_overrides_config() {
+        let config: Config = toml::from_str(CONFIG).unwrap();
+        let (mut args, matches) = parse_args(&[
+            "solores",
+            "other_idl.json",
+            "--output-crate-name",
+            "from_cli",
+            "-z",
+            "Seat",
+        ]);
+        config.merge_into(&mut args, &matches);
+        assert_eq!(args.idl_paths, [PathBuf::from("other_idl.json")]);
+        assert_eq!(args.output_crate_name, "from_cli");
+        assert_eq!(args.borsh_vers, "workspace = true");
+        assert_eq!(args.zero_copy, ["Seat", "MarketHeader"]);
+    }
+
+    #[test]
+    fn test_from_args_round_trips() {
+        let (args, _) = parse_args(&[
+            "solores",
+            "idl.json",
+            "--borsh-vers",
+            "workspace = true",
+            "--map-type",
+            "I80F48=fixed::types::I80F48",
+        ]);
+        let toml = Config::from_args(&args).to_toml().to_string();
+        assert_eq!(
+            toml,
+            "borsh-vers = \"workspace = true\"\n\n[map-type]\nI80F48 = \"fixed::types::I80F48\"\n"
+        );
+        let config: Config = toml::from_str(&toml).unwrap();
+        let (mut merged, matches) = parse_args(&["solores", "idl.json"]);
+        config.merge_into(&mut merged, &matches);
+        assert_eq!(merged.borsh_vers, args.borsh_vers);
+        assert_eq!(merged.map_type, args.map_type);
+    }
+
+    #[test]
+    fn test_from_args_extern_crate_relative_to_output_dir() {
+        let extern_crate = std::path::absolute("deps/shared_interface").unwrap();
+        let (args, _) = parse_args(&[
+            "solores",
+            "idl.json",
+            "-o",
+            "out",
+            "--extern-crate",
+            extern_crate.to_str().unwrap(),
+        ]);
+        assert_eq!(
+            Config::from_args(&args).extern_crate,
+            Some(vec![PathBuf::from("../deps/shared_interface")])
+        );
+    }
+
+    #[test]
+    fn test_program_id_for_keeps_precedence_order() {
+        let config: Config = toml::from_str(
+            r#"
+            [[program-id-for]]
+            feature = "localnet"
+            program-id = "Loca1111111111111111111111111111111111111111"
+
+            [[program-id-for]]
+            feature = "devnet"
+            program-id = "Dev1111111111111111111111111111111111111111"
+            "#,
+        )
+        .unwrap();
+        let (mut args, matches) = parse_args(&["solores"]);
+        config.merge_into(&mut args, &matches);
+        let features: Vec<&str> = args
+            .program_id_for
+            .iter()
+            .map(|(f, _)| f.as_str())
+            .collect();
+        assert_eq!(features, ["localnet", "devnet"]);
+        let options = Config::from_args(&args).to_toml().to_string();
+        assert!(options.find("localnet").unwrap() < options.find("devnet").unwrap());
+    }
+
+    #[test]
+    fn test_unknown_field_rejected() {
+        assert!(toml::from_str::<Config>("borsh-version = \"1\"").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use crate::idl_format::IdlFormatKind;
+
+/// Errors returned by solores
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("invalid IDL JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    /// `path` is the path to the offending JSON value e.g. `instructions[0].args[1].type`.
+    /// `source` contains its line and column if the IDL was parsed from a string.
+    #[error("invalid {format} IDL at {path}: {source}")]
+    InvalidIdl {
+        format: IdlFormatKind,
+        path: String,
+        source: serde_json::Error,
+    },
+
+    /// Fields that would be ignored by solores in strict mode
+    #[error("unsupported fields in {format} IDL: {}", fields.join(", "))]
+    UnknownIdlFields {
+        format: IdlFormatKind,
+        fields: Vec<String>,
+    },
+
+    /// Wraps an error that occured while generating the crate for the IDL at `path`
+    #[error("{}: {source}", path.display())]
+    Idl { path: PathBuf, source: Box<Error> },
+
+    #[error("invalid TOML in {}: {source}", path.display())]
+    InvalidToml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+
+    #[error("{}: {message}", path.display())]
+    InvalidCargoToml { path: PathBuf, message: String },
+
+    /// An IDL item's name, type or derive cannot be converted to rust tokens
+    #[error("{item}: invalid {kind} `{value}`: {source}")]
+    InvalidItem {
+        item: String,
+        kind: &'static str,
+        value: String,
+        source: syn::Error,
+    },
+
+    #[error("generated {} is not valid rust: {source}", path.display())]
+    InvalidGeneratedCode { path: PathBuf, source: syn::Error },
+
+    #[error("outdated generated files: {}", paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", "))]
+    OutdatedFiles { paths: Vec<PathBuf> },
+
+    #[error("IDL path must be provided either as an arg or in the config file")]
+    MissingIdlPath,
+
+    /// Args that cannot be used together, such as `--program-id` with multiple IDLs
+    #[error("{0}")]
+    ArgumentConflict(&'static str),
+
+    #[error("multiple IDLs generate crate {name}, including {}", idl_path.display())]
+    DuplicateCrate { name: String, idl_path: PathBuf },
+
+    #[error("invalid glob pattern `{pattern}`: {source}")]
+    InvalidPattern {
+        pattern: String,
+        source: glob::PatternError,
+    },
+
+    #[error("found {count} problem(s) in IDLs")]
+    LintFailed { count: usize },
+
+    /// A `--program-id-for` pubkey that is invalid or feature that can't be added to the generated crate
+    #[error("--program-id-for {feature}={program_id}: {reason}")]
+    InvalidProgramIdFor {
+        feature: String,
+        program_id: String,
+        reason: &'static str,
+    },
+
+    /// An `--extern-crate` without any types to import
+    #[error("extern crate {} has no typedefs or accounts", path.display())]
+    EmptyExternCrate { path: PathBuf },
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn in_idl(self, path: impl Into<PathBuf>) -> Self {
+        Self::Idl {
+            path: path.into(),
+            source: Box::new(self),
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
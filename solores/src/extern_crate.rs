@@ -0,0 +1,405 @@
+//! Reuse of the types of other generated interface crates, passed with `--extern-crate`.
+//!
+//! The extern crates' types are added to [`Args::map_type`] with their paths in the extern crate,
+//! so that they are imported instead of generated, and the extern crates are added as dependencies.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{self, Path, PathBuf},
+};
+
+use clap::{CommandFactory, Parser};
+use syn::{ext::IdentExt, Item, Visibility};
+use toml::Value;
+
+use crate::{
+    config::Config, load_idl_path, metadata::IDL_FILE_NAME, naming::ident, Args, Error, Result,
+};
+
+/// A generated interface crate whose types are reused
+#[derive(Debug)]
+pub struct ExternCrate {
+    /// Package name in the crate's `Cargo.toml`
+    pub package: String,
+
+    /// Dependency version arg e.g. "^0.1.0", `path = "/path/to/crate"`
+    pub dependency: String,
+
+    /// (name the type is referred to by, rust path of the type relative to the crate root)
+    pub types: Vec<(String, String)>,
+}
+
+impl ExternCrate {
+    /// Loads the extern crate at `path`, which is either the directory of a generated crate or an IDL
+    pub fn load(path: &Path, args: &Args) -> Result<Self> {
+        let res = if path.is_dir() {
+            Self::from_crate_dir(path, args)?
+        } else {
+            Self::from_idl(path, args)?
+        };
+        if res.types.is_empty() {
+            return Err(Error::EmptyExternCrate {
+                path: path.to_owned(),
+            });
+        }
+        Ok(res)
+    }
+
+    /// The crate generated for the IDL with default options, depended on by the IDL's version
+    fn from_idl(idl_path: &Path, args: &Args) -> Result<Self> {
+        let idl = load_idl_path(idl_path, args)?;
+        Ok(Self {
+            package: format!("{}_interface", idl.program_name()),
+            dependency: format!("^{}", idl.program_version()),
+            types: idl
+                .defined_types()
+                .into_iter()
+                .map(|(name, rust_name)| (name, ident(&rust_name).to_string()))
+                .collect(),
+        })
+    }
+
+    /// Depended on by path, with the public structs and enums of its `typedefs` and `accounts` modules.
+    ///
+    /// The types are referred to by their names in the crate's `idl.json` with the options
+    /// in its `[package.metadata.solores]` applied, or by their rust names if it has no `idl.json`.
+    fn from_crate_dir(dir: &Path, args: &Args) -> Result<Self> {
+        let manifest_path = dir.join("Cargo.toml");
+        let manifest =
+            fs::read_to_string(&manifest_path).map_err(|e| Error::io(&manifest_path, e))?;
+        let manifest: toml::Table =
+            toml::from_str(&manifest).map_err(|source| Error::InvalidToml {
+                path: manifest_path.clone(),
+                source,
+            })?;
+        let package = manifest
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::InvalidCargoToml {
+                path: manifest_path.clone(),
+                message: "package.name is missing".to_owned(),
+            })?
+            .to_owned();
+
+        let mut rust_names = HashSet::new();
+        for path in module_files(&dir.join("src"))? {
+            let src = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+            let file = syn::parse_file(&src).map_err(|source| Error::InvalidGeneratedCode {
+                path: path.clone(),
+                source,
+            })?;
+            rust_names.extend(file.items.iter().filter_map(|item| {
+                let (vis, ident) = match item {
+                    Item::Struct(s) => (&s.vis, &s.ident),
+                    Item::Enum(e) => (&e.vis, &e.ident),
+                    _ => return None,
+                };
+                matches!(vis, Visibility::Public(_)).then(|| ident.unraw().to_string())
+            }));
+        }
+
+        let idl_path = dir.join(IDL_FILE_NAME);
+        let types = if idl_path.exists() {
+            let crate_args = generated_with_args(&manifest, &manifest_path, args)?;
+            let mut idl = load_idl_path(&idl_path, &crate_args)?;
+            idl.apply_item_configs(&crate_args);
+            idl.defined_types()
+                .into_iter()
+                // leaves out types that were filtered out or mapped to other crates' types
+                .filter(|(_, rust_name)| rust_names.contains(rust_name))
+                .map(|(name, rust_name)| (name, ident(&rust_name).to_string()))
+                .collect()
+        } else {
+            log::warn!(
+                "{} not found, referring to the types of {package} by their rust names",
+                idl_path.to_string_lossy()
+            );
+            rust_names
+                .into_iter()
+                .map(|name| (name.clone(), ident(&name).to_string()))
+                .collect()
+        };
+
+        let dir = path::absolute(dir).map_err(|e| Error::io(dir, e))?;
+        Ok(Self {
+            package,
+            dependency: format!("path = {}", Value::from(dir.to_string_lossy().as_ref())),
+            types,
+        })
+    }
+}
+
+/// `src/{typedefs,accounts}.rs`, or with `--split-modules`, the item files in `src/{typedefs,accounts}/`
+fn module_files(src_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut res = Vec::new();
+    for module in ["typedefs", "accounts"] {
+        let file = src_dir.join(format!("{module}.rs"));
+        if file.exists() {
+            res.push(file);
+        }
+        let dir = src_dir.join(module);
+        if !dir.is_dir() {
+            continue;
+        }
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&dir).map_err(|e| Error::io(&dir, e))? {
+            let path = entry.map_err(|e| Error::io(&dir, e))?.path();
+            if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+        files.sort();
+        res.extend(files);
+    }
+    Ok(res)
+}
+
+/// The args the crate with `manifest` was generated with, from its `[package.metadata.solores]`.
+/// Only the format options of `args` apply if it has none.
+fn generated_with_args(manifest: &toml::Table, manifest_path: &Path, args: &Args) -> Result<Args> {
+    let matches = Args::command().get_matches_from(["solores"]);
+    let mut res = Args::parse_from(["solores"]);
+    res.format = args.format;
+    res.strict = args.strict;
+    let options = manifest
+        .get("package")
+        .and_then(|p| p.get("metadata"))
+        .and_then(|m| m.get("solores"))
+        .and_then(|s| s.get("options"));
+    if let Some(options) = options {
+        let config: Config = options
+            .clone()
+            .try_into()
+            .map_err(|source| Error::InvalidToml {
+                path: manifest_path.to_owned(),
+                source,
+            })?;
+        config.merge_into(&mut res, &matches);
+    }
+    Ok(res)
+}
+
+/// Loads all of `args.extern_crate` and adds their types to `args.map_type`
+/// and their dependencies to `args.map_type_vers`.
+///
+/// Mappings and versions that are already set take precedence.
+/// The added entries are recorded in `args.extern_crate_types` and `args.extern_crate_packages`
+/// so that they are left out of the generation metadata, which has the `--extern-crate` paths instead.
+pub fn map_extern_types(args: &mut Args) -> Result<()> {
+    for path in args.extern_crate.clone() {
+        let extern_crate = ExternCrate::load(&path, args)?;
+        let crate_ident = extern_crate.package.replace('-', "_");
+        for (name, type_path) in extern_crate.types {
+            if !args.map_type.iter().any(|(n, _)| n == &name) {
+                args.extern_crate_types.insert(name.clone());
+                args.map_type
+                    .push((name, format!("{crate_ident}::{type_path}")));
+            }
+        }
+        if !args
+            .map_type_vers
+            .iter()
+            .any(|(n, _)| n.replace('-', "_") == crate_ident)
+        {
+            args.extern_crate_packages
+                .insert(extern_crate.package.clone());
+            args.map_type_vers
+                .push((extern_crate.package, extern_crate.dependency));
+        }
+        log::info!(
+            "Using types of {crate_ident} from {}",
+            path.to_string_lossy()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Generator;
+
+    use super::*;
+
+    const SHARED_IDL: &str = r#"{
+        "version": "0.2.0",
+        "name": "shared",
+        "instructions": [{ "name": "noop", "accounts": [], "args": [] }],
+        "types": [{
+            "name": "Fraction",
+            "type": { "kind": "struct", "fields": [{ "name": "num", "type": "u64" }] }
+        }]
+    }"#;
+
+    const IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "my_program",
+        "instructions": [{
+            "name": "ix",
+            "accounts": [],
+            "args": [{ "name": "fee", "type": { "defined": "Fraction" } }]
+        }],
+        "types": [{
+            "name": "Fraction",
+            "type": { "kind": "struct", "fields": [{ "name": "num", "type": "u64" }] }
+        }]
+    }"#;
+
+    fn dependency(output: &crate::output::Output) -> Value {
+        let cargo_toml: toml::Table =
+            toml::from_str(&output.files[Path::new("my_program_interface/Cargo.toml")]).unwrap();
+        cargo_toml["dependencies"]["shared_interface"].clone()
+    }
+
+    #[test]
+    fn test_extern_crate_types_imported() {
+        let dir = std::env::temp_dir().join(format!("solores-extern-{}", std::process::id()));
+        let idl_path = dir.join("shared.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&idl_path, SHARED_IDL).unwrap();
+        Generator::new()
+            .output_dir(&dir)
+            .generate_str(SHARED_IDL)
+            .unwrap()
+            .write()
+            .unwrap();
+
+        for extern_path in [idl_path, dir.join("shared_interface")] {
+            let output = Generator::new()
+                .extern_crate(&extern_path)
+                .output_dir("")
+                .generate_str(IDL)
+                .unwrap();
+            let instructions = &output.files[Path::new("my_program_interface/src/instructions.rs")];
+            assert!(instructions.contains("pub fee: shared_interface::Fraction"));
+            assert!(!output
+                .files
+                .contains_key(Path::new("my_program_interface/src/typedefs.rs")));
+            let dependency = dependency(&output);
+            if extern_path.is_dir() {
+                assert!(Path::new(dependency["path"].as_str().unwrap()).is_relative());
+            } else {
+                assert_eq!(dependency["version"].as_str(), Some("^0.2.0"));
+            }
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_extern_crate_metadata_has_no_absolute_paths() {
+        let dir =
+            std::env::temp_dir().join(format!("solores-extern-metadata-{}", std::process::id()));
+        Generator::new()
+            .output_dir(&dir)
+            .generate_str(SHARED_IDL)
+            .unwrap()
+            .write()
+            .unwrap();
+
+        let output = Generator::new()
+            .extern_crate(dir.join("shared_interface"))
+            .output_dir(&dir)
+            .generate_str(IDL)
+            .unwrap();
+        let crate_dir = dir.join("my_program_interface");
+        let lib_rs = &output.files[&crate_dir.join("src/lib.rs")];
+        let cargo_toml: toml::Table =
+            toml::from_str(&output.files[&crate_dir.join("Cargo.toml")]).unwrap();
+        let metadata = cargo_toml["package"]["metadata"]["solores"].to_string();
+        let dir = dir.to_string_lossy();
+        for generated in [lib_rs.as_str(), metadata.as_str()] {
+            assert!(!generated.contains(dir.as_ref()));
+            assert!(!generated.contains("map-type"));
+        }
+        assert_eq!(
+            cargo_toml["package"]["metadata"]["solores"]["options"]["extern-crate"]
+                .as_array()
+                .unwrap()
+                .as_slice(),
+            [Value::from("shared_interface")]
+        );
+
+        fs::remove_dir_all(dir.as_ref()).unwrap();
+    }
+
+    #[test]
+    fn test_extern_crate_dir_types_by_idl_name() {
+        const SHARED_IDL: &str = r#"{
+            "version": "0.2.0",
+            "name": "shared",
+            "instructions": [{ "name": "noop", "accounts": [], "args": [] }],
+            "types": [
+                {
+                    "name": "tickSize",
+                    "type": { "kind": "struct", "fields": [{ "name": "num", "type": "u64" }] }
+                },
+                {
+                    "name": "fee_tier",
+                    "type": { "kind": "struct", "fields": [{ "name": "bps", "type": "u16" }] }
+                }
+            ]
+        }"#;
+        const IDL: &str = r#"{
+            "version": "0.1.0",
+            "name": "my_program",
+            "instructions": [{
+                "name": "ix",
+                "accounts": [],
+                "args": [
+                    { "name": "tick", "type": { "defined": "tickSize" } },
+                    { "name": "fee", "type": { "defined": "fee_tier" } }
+                ]
+            }]
+        }"#;
+        let dir = std::env::temp_dir().join(format!("solores-extern-split-{}", std::process::id()));
+        Generator::new()
+            .output_dir(&dir)
+            .split_modules(true)
+            .typedef_config(
+                "fee_tier",
+                crate::config::ItemConfig {
+                    rename: Some("FeeTierV2".to_owned()),
+                    ..Default::default()
+                },
+            )
+            .generate_str(SHARED_IDL)
+            .unwrap()
+            .write()
+            .unwrap();
+
+        let output = Generator::new()
+            .extern_crate(dir.join("shared_interface"))
+            .output_dir("")
+            .generate_str(IDL)
+            .unwrap();
+        let instructions = &output.files[Path::new("my_program_interface/src/instructions.rs")];
+        assert!(instructions.contains("pub tick: shared_interface::TickSize"));
+        assert!(instructions.contains("pub fee: shared_interface::FeeTierV2"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_extern_crate_without_types_rejected() {
+        let dir = std::env::temp_dir().join(format!("solores-extern-empty-{}", std::process::id()));
+        Generator::new()
+            .output_dir(&dir)
+            .generate_str(
+                r#"{ "version": "0.1.0", "name": "empty", "instructions": [{ "name": "noop" }] }"#,
+            )
+            .unwrap()
+            .write()
+            .unwrap();
+
+        let crate_dir = dir.join("empty_interface");
+        let err = Generator::new()
+            .extern_crate(&crate_dir)
+            .generate_str(IDL)
+            .unwrap_err();
+        assert!(matches!(err, Error::EmptyExternCrate { path } if path == crate_dir));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
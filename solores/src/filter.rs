@@ -0,0 +1,204 @@
+//! Selection of the IDL items to generate with the `--only-*` and `--exclude-*` args.
+//!
+//! Patterns are globs matched against IDL names. When any filter is set, typedefs are pruned
+//! to the ones that the generated instructions, accounts and events reference, directly or
+//! transitively, plus the ones selected by the typedef filters if those are set.
+//! Accounts that are referenced are kept even if they are filtered out.
+//! Errors are always generated in full: IDLs don't record which instructions return which errors,
+//! and any retained instruction can fail with any of the program's error codes,
+//! which the errors enum must still be able to convert.
+
+use std::collections::HashSet;
+
+use glob::Pattern;
+
+use crate::{Args, Error, Result};
+
+/// Glob patterns for the IDL names of one kind of item
+#[derive(Debug, Default)]
+pub struct ItemFilter {
+    only: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl ItemFilter {
+    pub fn new(only: &[String], exclude: &[String]) -> Result<Self> {
+        let patterns = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| {
+                    Pattern::new(p).map_err(|source| Error::InvalidPattern {
+                        pattern: p.clone(),
+                        source,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Self {
+            only: patterns(only)?,
+            exclude: patterns(exclude)?,
+        })
+    }
+
+    pub fn is_set(&self) -> bool {
+        !self.only.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Whether the item named `name` matches any `only` pattern, if there are any,
+    /// and no `exclude` pattern
+    pub fn matches(&self, name: &str) -> bool {
+        (self.only.is_empty() || self.only.iter().any(|p| p.matches(name)))
+            && !self.exclude.iter().any(|p| p.matches(name))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Filters {
+    pub instructions: ItemFilter,
+    pub accounts: ItemFilter,
+    pub events: ItemFilter,
+    pub typedefs: ItemFilter,
+}
+
+impl Filters {
+    pub fn from_args(args: &Args) -> Result<Self> {
+        Ok(Self {
+            instructions: ItemFilter::new(&args.only_ix, &args.exclude_ix)?,
+            accounts: ItemFilter::new(&args.only_account, &args.exclude_account)?,
+            events: ItemFilter::new(&args.only_event, &args.exclude_event)?,
+            typedefs: ItemFilter::new(&args.only_type, &args.exclude_type)?,
+        })
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.instructions.is_set()
+            || self.accounts.is_set()
+            || self.events.is_set()
+            || self.typedefs.is_set()
+    }
+
+    /// Whether the typedef named `name` is generated even if it's not referenced
+    pub fn is_typedef_root(&self, name: &str) -> bool {
+        self.typedefs.is_set() && self.typedefs.matches(name)
+    }
+}
+
+/// Retains only the items that `keep` returns true for, setting `items` to `None` if none are left.
+/// Returns the number of items removed.
+pub fn retain_items<T>(items: &mut Option<Vec<T>>, keep: impl FnMut(&T) -> bool) -> usize {
+    let Some(v) = items.as_mut() else {
+        return 0;
+    };
+    let n = v.len();
+    v.retain(keep);
+    let removed = n - v.len();
+    if removed > 0 && v.is_empty() {
+        *items = None;
+    }
+    removed
+}
+
+/// `roots` and all the types they reference, directly or transitively.
+/// `references(name)` returns the names of the types that type `name` directly references.
+pub fn referenced_types(
+    roots: impl IntoIterator<Item = String>,
+    references: impl Fn(&str) -> Vec<String>,
+) -> HashSet<String> {
+    let mut res = HashSet::new();
+    let mut stack: Vec<String> = roots.into_iter().collect();
+    while let Some(name) = stack.pop() {
+        if res.contains(&name) {
+            continue;
+        }
+        stack.extend(references(&name));
+        res.insert(name);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::load_idl_str;
+
+    use super::*;
+
+    #[test]
+    fn test_item_filter() {
+        let filter =
+            ItemFilter::new(&["perp*".into(), "deposit".into()], &["*Twap".into()]).unwrap();
+        assert!(filter.matches("perpMarket"));
+        assert!(filter.matches("deposit"));
+        assert!(!filter.matches("perpTwap"));
+        assert!(!filter.matches("withdraw"));
+        assert!(ItemFilter::default().matches("withdraw"));
+        assert!(ItemFilter::new(&["[".into()], &[]).is_err());
+    }
+
+    #[test]
+    fn test_referenced_types() {
+        let referenced = referenced_types(["A".to_owned()], |name| match name {
+            "A" => vec!["B".to_owned()],
+            "B" => vec!["A".to_owned(), "C".to_owned()],
+            _ => vec![],
+        });
+        assert_eq!(
+            referenced,
+            HashSet::from(["A", "B", "C"].map(ToOwned::to_owned))
+        );
+    }
+    #[test]
+    fn test_apply_filters() {
+        let idl = r#"{
+            "version": "0.1.0",
+            "name": "my_program",
+            "instructions": [
+                {
+                    "name": "deposit",
+                    "accounts": [],
+                    "args": [{ "name": "params", "type": { "defined": "DepositParams" } }]
+                },
+                {
+                    "name": "withdraw",
+                    "accounts": [],
+                    "args": [{ "name": "params", "type": { "defined": "WithdrawParams" } }]
+                }
+            ],
+            "accounts": [{
+                "name": "Vault",
+                "type": { "kind": "struct", "fields": [{ "name": "amount", "type": "u64" }] }
+            }],
+            "types": [
+                {
+                    "name": "DepositParams",
+                    "type": { "kind": "struct", "fields": [{ "name": "fee", "type": { "defined": "Fee" } }] }
+                },
+                {
+                    "name": "WithdrawParams",
+                    "type": { "kind": "struct", "fields": [{ "name": "amount", "type": "u64" }] }
+                },
+                {
+                    "name": "Fee",
+                    "type": { "kind": "struct", "fields": [{ "name": "bps", "type": "u16" }] }
+                }
+            ]
+        }"#;
+        let args = Args::parse_from(["solores", "--only-ix", "dep*", "--exclude-account", "*"]);
+        let mut idl = load_idl_str(idl, &args).unwrap();
+        idl.apply_filters(&Filters::from_args(&args).unwrap());
+        let mut types: Vec<_> = idl
+            .defined_types()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        types.sort();
+        assert_eq!(types, ["DepositParams", "Fee"]);
+        let modules = idl.modules(&args).unwrap();
+        let names: Vec<_> = modules.iter().map(|m| m.name()).collect();
+        assert_eq!(names, ["typedefs", "instructions"]);
+        let instructions_head = modules[1].gen_head().unwrap().to_string();
+        assert!(instructions_head.contains("Unknown"));
+        assert!(!instructions_head.contains("Withdraw"));
+    }
+}
@@ -0,0 +1,361 @@
+//! Builder-style API for generating interface crates in memory.
+//!
+//! ```no_run
+//! use solores::Generator;
+//!
+//! let idl = std::fs::read_to_string("idl.json").unwrap();
+//! let output = Generator::new()
+//!     .zero_copy(["Ticks", "Seat"])
+//!     .borsh_vers("workspace = true")
+//!     .generate_str(&idl)
+//!     .unwrap();
+//! for (path, contents) in output.files {
+//!     println!("{}:\n{contents}", path.display());
+//! }
+//! ```
+
+use std::{io::Read, path::PathBuf};
+
+use clap::Parser;
+
+use crate::{
+    config::ItemConfig,
+    extern_crate::map_extern_types,
+    gen_crate,
+    idl_format::{IdlFormat, IdlFormatKind},
+    load_idl_str, load_idl_value,
+    output::Output,
+    target::Target,
+    Args, Result,
+};
+
+/// Generates a crate from an IDL without touching the filesystem.
+///
+/// Generated file paths are relative to [`Generator::output_dir`],
+/// which defaults to an empty path, so the crate is at `<output_crate_name>/`.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    args: Args,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        let mut args = Args::parse_from(["solores"]);
+        args.output_dir = PathBuf::new();
+        Self { args }
+    }
+}
+
+impl From<Args> for Generator {
+    fn from(args: Args) -> Self {
+        Self { args }
+    }
+}
+
+macro_rules! string_setters {
+    ($($field:ident),*) => {
+        $(
+            pub fn $field(mut self, $field: impl Into<String>) -> Self {
+                self.args.$field = $field.into();
+                self
+            }
+        )*
+    };
+}
+
+/// Setters for the `--only-*`/`--exclude-*` glob patterns, see [`crate::filter`]
+macro_rules! filter_setters {
+    ($($field:ident),*) => {
+        $(
+            pub fn $field<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+                self.args.$field = patterns.into_iter().map(Into::into).collect();
+                self
+            }
+        )*
+    };
+}
+
+impl Generator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.args.output_dir = output_dir.into();
+        self
+    }
+
+    pub fn program_id(mut self, program_id: impl Into<String>) -> Self {
+        self.args.program_id = Some(program_id.into());
+        self
+    }
+
+    /// Uses `program_id` as the program ID when the generated crate's `feature` is enabled
+    pub fn program_id_for(
+        mut self,
+        feature: impl Into<String>,
+        program_id: impl Into<String>,
+    ) -> Self {
+        self.args
+            .program_id_for
+            .push((feature.into(), program_id.into()));
+        self
+    }
+
+    /// Sets the IDL format instead of detecting it
+    pub fn format(mut self, format: IdlFormatKind) -> Self {
+        self.args.format = Some(format);
+        self
+    }
+
+    /// Rejects IDLs with fields that are not supported by solores
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.args.strict = strict;
+        self
+    }
+
+    /// Generates a `#![no_std]` crate that uses `alloc`
+    pub fn no_std(mut self, no_std: bool) -> Self {
+        self.args.no_std = no_std;
+        self
+    }
+
+    /// Sets the SDK for the on-chain items behind the generated crate's `cpi` feature
+    pub fn target(mut self, target: Target) -> Self {
+        self.args.target = target;
+        self
+    }
+
+    /// Depends on the split solana crates instead of `solana-program`
+    pub fn split_solana_crates(mut self, split_solana_crates: bool) -> Self {
+        self.args.split_solana_crates = split_solana_crates;
+        self
+    }
+
+    /// Writes instructions, accounts and typedefs as one file per item
+    pub fn split_modules(mut self, split_modules: bool) -> Self {
+        self.args.split_modules = split_modules;
+        self
+    }
+
+    /// Defines instructions' items with `macro_rules!` invoked once per instruction
+    pub fn compact(mut self, compact: bool) -> Self {
+        self.args.compact = compact;
+        self
+    }
+
+    /// Writes the modules to [`Self::output_dir`] as the module at `path` of an existing crate
+    pub fn module(mut self, path: impl Into<String>) -> Self {
+        self.args.module = Some(path.into());
+        self
+    }
+
+    pub fn zero_copy<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.args.zero_copy = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    string_setters!(
+        output_crate_name,
+        solana_program_vers,
+        borsh_vers,
+        thiserror_vers,
+        num_derive_vers,
+        num_traits_vers,
+        serde_vers,
+        bytemuck_vers,
+        serde_with_vers,
+        solana_rpc_client_api_vers,
+        pinocchio_vers,
+        split_solana_crates_vers,
+        anchor_lang_vers,
+        solores_runtime_vers,
+        solana_program_path
+    );
+
+    /// Uses the rust type at `path` for IDL `defined` or primitive type `name`
+    /// instead of generating it. The crate in `path` is added as a dependency.
+    pub fn map_type(mut self, name: impl Into<String>, path: impl Into<String>) -> Self {
+        self.args.map_type.push((name.into(), path.into()));
+        self
+    }
+
+    /// Sets the dependency version of crate `name` in [`Self::map_type`] paths
+    pub fn map_type_vers(mut self, name: impl Into<String>, vers: impl Into<String>) -> Self {
+        self.args.map_type_vers.push((name.into(), vers.into()));
+        self
+    }
+
+    /// Imports the types of the generated crate in directory `path`, or of the crate generated for the IDL at `path`,
+    /// instead of generating them
+    pub fn extern_crate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.args.extern_crate.push(path.into());
+        self
+    }
+
+    filter_setters!(
+        only_ix,
+        exclude_ix,
+        only_account,
+        exclude_account,
+        only_event,
+        exclude_event,
+        only_type,
+        exclude_type
+    );
+
+    pub fn typedef_config(mut self, name: impl Into<String>, config: ItemConfig) -> Self {
+        self.args.typedef_configs.insert(name.into(), config);
+        self
+    }
+
+    pub fn account_config(mut self, name: impl Into<String>, config: ItemConfig) -> Self {
+        self.args.account_configs.insert(name.into(), config);
+        self
+    }
+
+    pub fn instruction_config(mut self, name: impl Into<String>, config: ItemConfig) -> Self {
+        self.args.instruction_configs.insert(name.into(), config);
+        self
+    }
+
+    pub fn generate_str(&self, idl: &str) -> Result<Output> {
+        self.generate(load_idl_str(idl, &self.args)?)
+    }
+
+    pub fn generate_reader<R: Read>(&self, mut idl: R) -> Result<Output> {
+        let mut s = String::new();
+        idl.read_to_string(&mut s).map_err(serde_json::Error::io)?;
+        self.generate_str(&s)
+    }
+
+    pub fn generate_value(&self, idl: &serde_json::Value) -> Result<Output> {
+        self.generate(load_idl_value(idl, &self.args)?)
+    }
+
+    fn generate(&self, idl: Box<dyn IdlFormat>) -> Result<Output> {
+        let mut args = self.args.clone();
+        map_extern_types(&mut args)?;
+        let mut out = Output::default();
+        gen_crate(args, idl, &mut out)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::Error;
+
+    use super::*;
+
+    const IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "my_program",
+        "instructions": [{ "name": "blankIx" }]
+    }"#;
+
+    #[test]
+    fn test_generate_str() {
+        let output = Generator::new()
+            .output_crate_name("my_interface")
+            .program_id("11111111111111111111111111111111")
+            .generate_str(IDL)
+            .unwrap();
+        let paths: Vec<&Path> = output.files.keys().map(PathBuf::as_path).collect();
+        assert_eq!(
+            paths,
+            [
+                "my_interface/.gitignore",
+                "my_interface/Cargo.toml",
+                "my_interface/idl.json",
+                "my_interface/src/instructions.rs",
+                "my_interface/src/lib.rs",
+            ]
+            .map(Path::new)
+        );
+        assert!(output.files[Path::new("my_interface/src/lib.rs")]
+            .contains("declare_id!(\"11111111111111111111111111111111\")"));
+        assert!(output.files[Path::new("my_interface/Cargo.toml")].contains("version = \"0.1.0\""));
+    }
+
+    #[test]
+    fn test_invalid_type_override() {
+        let config = ItemConfig {
+            types: [("arg".to_owned(), "Vec<".to_owned())].into(),
+            ..Default::default()
+        };
+        let idl = r#"{
+            "version": "0.1.0",
+            "name": "my_program",
+            "instructions": [{ "name": "ix", "args": [{ "name": "arg", "type": "u8" }] }]
+        }"#;
+        let err = Generator::new()
+            .instruction_config("ix", config)
+            .generate_str(idl)
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::InvalidItem { item, kind: "type", .. } if item == "instruction ix field arg")
+        );
+    }
+
+    #[test]
+    fn test_invalid_field_reports_path_and_line() {
+        let idl = r#"{
+            "version": "0.1.0",
+            "name": "my_program",
+            "instructions": [{ "name": "ix", "args": [{ "name": "arg", "type": 1 }] }]
+        }"#;
+        let err = Generator::new().generate_str(idl).unwrap_err();
+        match &err {
+            Error::InvalidIdl {
+                format,
+                path,
+                source,
+            } => {
+                assert_eq!(*format, IdlFormatKind::Anchor);
+                assert_eq!(path, "instructions[0].args[0].type");
+                assert_eq!(source.line(), 4);
+            }
+            _ => panic!("unexpected error {err}"),
+        }
+    }
+
+    #[test]
+    fn test_strict_rejects_unknown_fields() {
+        let idl = r#"{
+            "version": "0.1.0",
+            "name": "my_program",
+            "instructions": [{ "name": "blankIx", "discriminator": [0] }]
+        }"#;
+        assert!(Generator::new().generate_str(idl).is_ok());
+        let err = Generator::new().strict(true).generate_str(idl).unwrap_err();
+        assert!(
+            matches!(&err, Error::UnknownIdlFields { fields, .. } if fields == &["instructions[0].discriminator"])
+        );
+    }
+
+    #[test]
+    fn test_format_override() {
+        let err = Generator::new()
+            .format(IdlFormatKind::Shank)
+            .generate_str(IDL)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidIdl {
+                format: IdlFormatKind::Shank,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        assert!(matches!(
+            Generator::new().generate_str("{"),
+            Err(Error::InvalidJson(_))
+        ));
+    }
+}
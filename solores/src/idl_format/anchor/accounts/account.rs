@@ -1,32 +1,50 @@
-use heck::{ToPascalCase, ToShoutySnakeCase};
-use proc_macro2::TokenStream;
+use heck::ToPascalCase;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use serde::Deserialize;
 use sha2::{Digest, Sha256};
 
-use crate::idl_format::anchor::typedefs::NamedType;
-use crate::utils::conditional_pascal_case;
+use crate::{
+    idl_format::anchor::typedefs::NamedType,
+    naming::{ident, shouty_snake_case},
+};
 
 #[derive(Deserialize)]
 pub struct NamedAccount(pub NamedType);
 
 impl NamedAccount {
+    /// pre-image: "account:{AccountStructName}"
+    pub fn discm(&self) -> [u8; 8] {
+        <[u8; 8]>::try_from(
+            &Sha256::digest(format!("account:{}", self.0.name.to_pascal_case()).as_bytes())
+                .as_slice()[..8],
+        )
+        .unwrap()
+    }
+
+    /// The crate-level names of the items generated for the account if named `rust_name`
+    pub fn item_names(rust_name: &str) -> Vec<String> {
+        vec![
+            format!("{}_ACCOUNT_DISCM", shouty_snake_case(rust_name)),
+            format!("{rust_name}Account"),
+            rust_name.to_owned(),
+        ]
+    }
+
     pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
-        let name = &self.0.name;
+        let rust_name = self.0.rust_name();
         // discriminant
-        let account_discm_ident = format_ident!("{}_ACCOUNT_DISCM", name.to_shouty_snake_case());
-        // pre-image: "account:{AccountStructName}"
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("account:{}", name.to_pascal_case()).as_bytes()).as_slice()
-                [..8],
-        )
-        .unwrap();
+        let account_discm_ident = format_ident!("{}_ACCOUNT_DISCM", shouty_snake_case(&rust_name));
+        let discm = self.discm();
         let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
 
         let struct_def = self.0.to_token_stream(cli_args);
 
-        let struct_ident = format_ident!("{}", conditional_pascal_case(name));
-        let account_ident = format_ident!("{}Account", conditional_pascal_case(name));
+        let struct_ident = ident(&rust_name);
+        let account_ident = format_ident!("{}Account", rust_name);
+        let is_zero_copy = cli_args.zero_copy.iter().any(|e| e == &self.0.name);
+        let anchor_impls = anchor_account_impls(&struct_ident, &account_discm_ident, is_zero_copy);
+        let name = &self.0.name;
         quote! {
             pub const #account_discm_ident: [u8; 8] = #discm_tokens;
 
@@ -36,32 +54,111 @@ impl NamedAccount {
             pub struct #account_ident(pub #struct_ident);
 
             impl #account_ident {
-                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
-                    use std::io::Read;
+                pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+                    use borsh::io::Read;
                     let mut reader = buf;
                     let mut maybe_discm = [0u8; 8];
                     reader.read_exact(&mut maybe_discm)?;
                     if maybe_discm != #account_discm_ident {
                         return Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
+                            borsh::io::Error::new(
+                                borsh::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #account_discm_ident, maybe_discm)
                             )
                         );
                     }
                     Ok(Self(#struct_ident::deserialize(&mut reader)?))
                 }
 
-                pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+                pub fn serialize<W: borsh::io::Write>(&self, mut writer: W) -> borsh::io::Result<()> {
                     writer.write_all(&#account_discm_ident)?;
                     self.0.serialize(&mut writer)
                 }
 
-                pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+                pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
                     let mut data = Vec::new();
                     self.serialize(&mut data)?;
                     Ok(data)
                 }
+
+                /// `getProgramAccounts` filter that only matches accounts of this type
+                #[cfg(feature = "client")]
+                pub fn rpc_filter() -> RpcFilterType {
+                    RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, #account_discm_ident.to_vec()))
+                }
+            }
+
+            #anchor_impls
+
+            #[cfg(feature = "runtime")]
+            impl solores_runtime::ProgramAccount for #struct_ident {
+                const NAME: &'static str = #name;
+                const DISCM: &'static [u8] = &#account_discm_ident;
+            }
+        }
+    }
+}
+
+/// anchor-lang trait impls behind the `anchor` feature, so that the account can be used in
+/// `Account<'info, T>`, or `AccountLoader<'info, T>` if zero-copy.
+///
+/// anchor's `AnchorSerialize` and `AnchorDeserialize` are borsh 0.10's traits, so these are
+/// written in terms of the borsh 1 traits that the account derives instead of deriving them.
+fn anchor_account_impls(
+    struct_ident: &Ident,
+    account_discm_ident: &Ident,
+    is_zero_copy: bool,
+) -> TokenStream {
+    let mut res = quote! {
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::Discriminator for #struct_ident {
+            const DISCRIMINATOR: &'static [u8] = &#account_discm_ident;
+        }
+
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::Owner for #struct_ident {
+            fn owner() -> anchor_lang::prelude::Pubkey {
+                crate::ID
+            }
+        }
+
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::AccountSerialize for #struct_ident {
+            fn try_serialize<W: borsh::io::Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
+                writer
+                    .write_all(&#account_discm_ident)
+                    .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+                BorshSerialize::serialize(self, writer)
+                    .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotSerialize)?;
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::AccountDeserialize for #struct_ident {
+            fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                if buf.len() < #account_discm_ident.len() {
+                    return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
+                }
+                if !buf.starts_with(&#account_discm_ident) {
+                    return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+                }
+                Self::try_deserialize_unchecked(buf)
+            }
+
+            fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+                *buf = buf
+                    .get(#account_discm_ident.len()..)
+                    .ok_or(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound)?;
+                BorshDeserialize::deserialize(buf)
+                    .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into())
             }
         }
+    };
+    if is_zero_copy {
+        res.extend(quote! {
+            #[cfg(feature = "anchor")]
+            impl anchor_lang::ZeroCopy for #struct_ident {}
+        });
     }
+    res
 }
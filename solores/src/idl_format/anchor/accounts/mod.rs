@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, Result};
 
 mod account;
 pub use account::*;
@@ -16,9 +16,11 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
         "accounts"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
+            #[cfg(feature = "client")]
+            use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
         };
         for a in self.named_accounts {
             if self.cli_args.zero_copy.iter().any(|e| e == &a.0.name) {
@@ -47,13 +49,24 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
                 break;
             }
         }
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.items().into_iter().map(|(_, tokens)| tokens).collect())
+    }
+
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(Some(self.items()))
+    }
+}
+
+impl AccountsCodegenModule<'_> {
+    /// (rust name, items) of each account
+    fn items(&self) -> Vec<(String, TokenStream)> {
         self.named_accounts
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .map(|e| (e.0.rust_name(), e.to_token_stream(self.cli_args)))
             .collect()
     }
 }
@@ -1,19 +1,58 @@
-use heck::ToPascalCase;
 use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use quote::{quote, ToTokens};
 use serde::Deserialize;
 use syn::LitInt;
 
+use crate::{
+    lint::{check_keyword, Lint},
+    naming::{pascal_case, pascal_ident},
+    utils::check_ident,
+};
+
 #[derive(Deserialize)]
 pub struct ErrorEnumVariant {
-    code: u32,
-    name: String,
-    msg: String,
+    pub code: u32,
+    pub name: String,
+    pub msg: String,
+}
+
+impl ErrorEnumVariant {
+    pub fn validate(&self) -> crate::Result<()> {
+        check_ident(&format!("error {}", self.name), &pascal_case(&self.name))
+    }
+
+    pub fn lint(&self, lints: &mut Vec<Lint>) {
+        check_keyword(
+            lints,
+            &format!("error {}", self.name),
+            &pascal_case(&self.name),
+        );
+    }
+}
+
+impl ErrorEnumVariant {
+    /// The variant without thiserror's `#[error]` attribute
+    pub fn no_std_variant(&self) -> TokenStream {
+        let variant_ident = pascal_ident(&self.name);
+        let code_literal = LitInt::new(&self.code.to_string(), Span::call_site());
+        quote! {
+            #variant_ident = #code_literal,
+        }
+    }
+
+    /// The variant's arm in the hand-written `Display` impl's match
+    pub fn display_arm(&self) -> TokenStream {
+        let variant_ident = pascal_ident(&self.name);
+        let msg = &self.msg;
+        quote! {
+            Self::#variant_ident => #msg,
+        }
+    }
 }
 
 impl ToTokens for ErrorEnumVariant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let variant_ident = format_ident!("{}", self.name.to_pascal_case());
+        let variant_ident = pascal_ident(&self.name);
         let msg = &self.msg;
         let code_literal = LitInt::new(&self.code.to_string(), Span::call_site());
         tokens.extend(quote! {
@@ -1,10 +1,16 @@
-use heck::{ToPascalCase, ToShoutySnakeCase};
+use std::collections::HashSet;
+
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
 use sha2::{Digest, Sha256};
 
-use crate::idl_format::anchor::typedefs::TypedefField;
+use crate::{
+    idl_format::anchor::typedefs::TypedefField,
+    lint::{check_keyword, Lint},
+    naming::{pascal_case, pascal_ident, shouty_snake_case},
+    utils::check_ident,
+};
 
 #[derive(Deserialize)]
 pub struct Event(pub EventType);
@@ -15,29 +21,67 @@ pub struct EventType {
     // NB: theres also an `index` field that's ignored for now since we dk what it does:
     // https://github.com/coral-xyz/anchor/blob/8f30f00ec363b7e82aa0b3c7041e912919b33cf5/lang/attribute/event/src/lib.rs#L62C1-L64
     pub fields: Vec<TypedefField>,
+    /// Name used for generated items instead of the IDL name.
+    /// Does not affect the discriminant.
+    #[serde(skip)]
+    pub rename: Option<String>,
 }
 
 impl EventType {
+    pub fn validate(&self) -> crate::Result<()> {
+        let item = format!("event {}", self.name);
+        check_ident(&item, &pascal_case(self.rust_name()))?;
+        self.fields.iter().try_for_each(|f| f.validate(&item))
+    }
+
+    pub fn lint(&self, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("event {}", self.name);
+        check_keyword(lints, &item, &pascal_case(&self.name));
+        self.fields
+            .iter()
+            .for_each(|f| f.lint(&item, type_names, lints));
+    }
+
+    pub fn rust_name(&self) -> &str {
+        self.rename.as_deref().unwrap_or(&self.name)
+    }
+
+    /// The crate-level names of the items generated for the event if named `rust_name`
+    pub fn item_names(rust_name: &str) -> Vec<String> {
+        let pascal = pascal_case(rust_name);
+        vec![
+            format!("{}_EVENT_DISCM", shouty_snake_case(rust_name)),
+            format!("{pascal}Event"),
+            pascal,
+        ]
+    }
+
+    /// pre-image: "event:{EventName}"
+    pub fn discm(&self) -> [u8; 8] {
+        <[u8; 8]>::try_from(
+            &Sha256::digest(format!("event:{}", self.name).as_bytes()).as_slice()[..8],
+        )
+        .unwrap()
+    }
+
     pub fn struct_ident(&self) -> Ident {
-        format_ident!("{}", self.name.to_pascal_case())
+        pascal_ident(self.rust_name())
     }
 }
 
 impl ToTokens for Event {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         // discriminant
-        let event_discm_ident = format_ident!("{}_EVENT_DISCM", self.0.name.to_shouty_snake_case());
-        // pre-image: "event:{EventName}"
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("event:{}", self.0.name).as_bytes()).as_slice()[..8],
-        )
-        .unwrap();
+        let event_discm_ident =
+            format_ident!("{}_EVENT_DISCM", shouty_snake_case(self.0.rust_name()));
+        let discm = self.0.discm();
         let discm_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
 
         let struct_def = &self.0;
 
         let struct_ident = self.0.struct_ident();
         let event_ident = format_ident!("{}Event", struct_ident);
+        let name = &self.0.name;
         tokens.extend(quote! {
             pub const #event_discm_ident: [u8; 8] = #discm_tokens;
 
@@ -47,25 +91,31 @@ impl ToTokens for Event {
             pub struct #event_ident(pub #struct_ident);
 
             impl BorshSerialize for #event_ident {
-                fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
                     #event_discm_ident.serialize(writer)?;
                     self.0.serialize(writer)
                 }
             }
 
             impl #event_ident {
-                pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+                pub fn deserialize(buf: &mut &[u8]) -> borsh::io::Result<Self> {
                     let maybe_discm = <[u8; 8]>::deserialize(buf)?;
                     if maybe_discm != #event_discm_ident {
                         return Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #event_discm_ident, maybe_discm)
+                            borsh::io::Error::new(
+                                borsh::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #event_discm_ident, maybe_discm)
                             )
                         );
                     }
                     Ok(Self(#struct_ident::deserialize(buf)?))
                 }
             }
+
+            #[cfg(feature = "runtime")]
+            impl solores_runtime::ProgramEvent for #struct_ident {
+                const NAME: &'static str = #name;
+                const DISCM: &'static [u8] = &#event_discm_ident;
+            }
         });
     }
 }
@@ -105,6 +155,7 @@ mod tests {
         let event_type = EventType {
             name: "TestEvent".to_string(),
             fields: vec![field1, field2],
+            rename: None,
         };
 
         // Generate the tokens.
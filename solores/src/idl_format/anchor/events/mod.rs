@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, Result};
 
 mod event;
 pub use event::*;
@@ -13,7 +13,7 @@ impl IdlCodegenModule for EventsCodegenModule<'_> {
         "events"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
         };
@@ -38,10 +38,10 @@ impl IdlCodegenModule for EventsCodegenModule<'_> {
                 break;
             }
         }
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
-        self.0.iter().map(|e| e.into_token_stream()).collect()
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.0.iter().map(|e| e.into_token_stream()).collect())
     }
 }
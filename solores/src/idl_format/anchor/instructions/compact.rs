@@ -0,0 +1,161 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::LitInt;
+
+use crate::{
+    compact::{
+        borsh_ix_fns_invocation, borsh_ix_fns_macro, ix_accounts_invocations, ix_accounts_macro,
+        ix_privileges_macro, MacroAccount,
+    },
+    target::Target,
+};
+
+use super::NamedInstruction;
+
+const IX_DATA: &str = r#"/// Defines an instruction's `*_IX_DISCM`, its `*IxData` struct wrapping its `*IxArgs` if it has args,
+/// and the data's serialization and trait impls.
+macro_rules! ix_data {
+    (
+        $name:literal, $discm:ident = $discm_value:expr, $ix_data:ident $(($ix_args:ident))?
+        $(, $keys:ident, $len:ident)?
+    ) => {
+        pub const $discm: [u8; 8] = $discm_value;
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $ix_data$((pub $ix_args))?;
+        $(
+            impl From<$ix_args> for $ix_data {
+                fn from(args: $ix_args) -> Self {
+                    Self(args)
+                }
+            }
+        )?
+        impl $ix_data {
+            pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
+                let mut reader = buf;
+                let mut maybe_discm = [0u8; 8];
+                reader.read_exact(&mut maybe_discm)?;
+                if maybe_discm != $discm {
+                    return Err(borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other,
+                        format!(
+                            "discm does not match. Expected: {:?}. Received: {:?}",
+                            $discm, maybe_discm,
+                        ),
+                    ));
+                }
+                Ok(Self$(($ix_args::deserialize(&mut reader)?))?)
+            }
+            pub fn serialize<W: borsh::io::Write>(&self, mut writer: W) -> borsh::io::Result<()> {
+                writer.write_all(&$discm)?;
+                $(<$ix_args as BorshSerialize>::serialize(&self.0, &mut writer)?;)?
+                Ok(())
+            }
+            pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
+                let mut data = Vec::new();
+                self.serialize(&mut data)?;
+                Ok(data)
+            }
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::Discriminator for $ix_data {
+            const DISCRIMINATOR: &'static [u8] = &$discm;
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::AnchorSerialize for $ix_data {
+            fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+                // like anchor's, without the discriminator
+                writer.write_all(&self.try_to_vec()?[$discm.len()..])
+            }
+        }
+        #[cfg(feature = "anchor")]
+        impl anchor_lang::InstructionData for $ix_data {}
+        #[cfg(feature = "runtime")]
+        impl solores_runtime::Instruction for $ix_data {
+            const NAME: &'static str = $name;
+            const DISCM: &'static [u8] = &$discm;
+            const ACCOUNTS_LEN: usize = ix_data!(@or (0) $(($len))?);
+            type Keys = ix_data!(@or (()) $(($keys))?);
+            type Args = ix_data!(@or (()) $(($ix_args))?);
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+"#;
+
+/// The `macro_rules!` that [`NamedInstruction::write_compact_items`] invokes
+pub fn compact_macros(instructions: &[NamedInstruction], target: Target) -> String {
+    let mut res = String::new();
+    if instructions.iter().any(NamedInstruction::has_accounts) {
+        res.push_str(&ix_accounts_macro(
+            target,
+            "#[derive(Copy, Clone, Debug, PartialEq)]",
+        ));
+    }
+    if instructions
+        .iter()
+        .any(NamedInstruction::has_privileged_accounts)
+    {
+        res.push_str(ix_privileges_macro(target));
+    }
+    res.push_str(IX_DATA);
+    res.push_str(borsh_ix_fns_macro(target));
+    res
+}
+
+impl NamedInstruction {
+    /// [`Self::write_items`] for `--compact`: the `*IxArgs` struct, and invocations of the
+    /// [`compact_macros`] for the rest of the items.
+    pub fn write_compact_items(
+        &self,
+        tokens: &mut TokenStream,
+        target: Target,
+        data_max_len: Option<usize>,
+    ) {
+        if self.has_accounts() {
+            let accounts = self.ix_accounts();
+            let accounts: Vec<_> = accounts
+                .iter()
+                .map(|acc| MacroAccount {
+                    name: &acc.name,
+                    desc: None,
+                    is_signer: acc.is_signer,
+                    is_writable: acc.is_mut,
+                })
+                .collect();
+            tokens.extend(ix_accounts_invocations(self.rust_name(), &accounts));
+        }
+        self.write_ix_args_struct(tokens);
+
+        let name = &self.name;
+        let discm_ident = self.discm_ident();
+        let discm: TokenStream = format!("{:?}", self.discm()).parse().unwrap();
+        let ix_data_ident = self.ix_data_ident();
+        let ix_args = self.has_ix_args().then(|| {
+            let ix_args_ident = self.ix_args_ident();
+            quote! { (#ix_args_ident) }
+        });
+        let keys = self.has_accounts().then(|| {
+            let keys_ident = self.keys_ident();
+            let accounts_len_ident = self.accounts_len_ident();
+            quote! { , #keys_ident, #accounts_len_ident }
+        });
+        tokens.extend(quote! {
+            ix_data!(#name, #discm_ident = #discm, #ix_data_ident #ix_args #keys);
+        });
+
+        let data_buf_len = match target {
+            Target::SolanaProgram => None,
+            Target::Pinocchio => Some(match data_max_len {
+                Some(len) => LitInt::new(&len.to_string(), Span::call_site()).into_token_stream(),
+                None => quote! { CPI_IX_DATA_BUF_LEN },
+            }),
+        };
+        tokens.extend(borsh_ix_fns_invocation(
+            self.rust_name(),
+            self.has_accounts(),
+            self.has_ix_args(),
+            data_buf_len,
+        ));
+    }
+}
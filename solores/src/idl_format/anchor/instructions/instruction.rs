@@ -1,8 +1,9 @@
 // TODO: determine borsh version for more efficient implementations of deserialize_reader
 // that makes use of ix_args' deserialize_reader method if available
 
-use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
-use itertools::Itertools;
+use std::collections::HashSet;
+
+use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
@@ -10,8 +11,12 @@ use sha2::{Digest, Sha256};
 use syn::{LitBool, LitInt};
 
 use crate::{
+    config::ItemConfig,
     idl_format::anchor::typedefs::TypedefField,
-    utils::{unique_by_report_dups, UniqueByReportDupsResult},
+    lint::{check_keyword, Lint},
+    naming::{dedup_names, pascal_case, shouty_snake_case, snake_case, snake_ident},
+    target::Target,
+    utils::{check_derive, check_ident},
 };
 
 #[derive(Deserialize)]
@@ -19,39 +24,160 @@ pub struct NamedInstruction {
     pub name: String,
     pub accounts: Option<Vec<IxAccountEntry>>,
     pub args: Option<Vec<TypedefField>>,
+    #[serde(skip)]
+    pub config: ItemConfig,
 }
 
 impl NamedInstruction {
+    /// The name used for generated items, which may differ from the IDL name.
+    /// Discriminants are always derived from the IDL name.
+    pub fn rust_name(&self) -> &str {
+        self.config.rename.as_deref().unwrap_or(&self.name)
+    }
+
+    /// pre-image: "global:{instruction_fn_name}"
+    pub fn discm(&self) -> [u8; 8] {
+        // TODO: handle non-global instructions (state methods - idk if thats deprecated)
+        <[u8; 8]>::try_from(
+            &Sha256::digest(format!("global:{}", self.name.to_snake_case()).as_bytes()).as_slice()
+                [..8],
+        )
+        .unwrap()
+    }
+
+    pub fn apply_config(&mut self, config: &ItemConfig) {
+        self.config = config.clone();
+        for arg in self.args.iter_mut().flatten() {
+            arg.override_type(&config.types);
+        }
+    }
+
+    /// Checks that the instruction's items can be generated
+    pub fn validate(&self) -> crate::Result<()> {
+        let item = format!("instruction {}", self.name);
+        check_ident(&item, &pascal_case(self.rust_name()))?;
+        check_ident(&item, &snake_case(self.rust_name()))?;
+        for derive in self.config.derives.iter() {
+            check_derive(&item, derive)?;
+        }
+        for arg in self.args.iter().flatten() {
+            arg.validate(&item)?;
+        }
+
+        for acc in self.ix_accounts().iter() {
+            check_ident(&format!("{item} account"), &snake_case(&acc.name))?;
+        }
+        Ok(())
+    }
+
+    /// The instruction's flattened accounts, renamed by the config's `accounts`
+    /// and with any remaining duplicate names disambiguated.
+    pub fn ix_accounts(&self) -> Vec<IxAccount> {
+        let mut accounts = to_ix_accounts(self.accounts.as_deref().unwrap_or_default());
+        self.rename_accounts(&mut accounts);
+        accounts
+    }
+
+    /// Returns the `(old, new)` names of the accounts renamed to disambiguate them
+    fn rename_accounts(&self, accounts: &mut [IxAccount]) -> Vec<(String, String)> {
+        for acc in accounts.iter_mut() {
+            if let Some(rename) = self.config.accounts.get(&acc.name) {
+                acc.name = rename.clone();
+            }
+        }
+        dedup_names(accounts.iter_mut().map(|acc| &mut acc.name), snake_case)
+    }
+
+    /// Warns about accounts with duplicate names that are renamed in the generated crate
+    pub fn warn_duplicate_accounts(&self) {
+        for (old, new) in self.rename_accounts(&mut to_ix_accounts(
+            self.accounts.as_deref().unwrap_or_default(),
+        )) {
+            log::warn!(
+                "instruction {}: duplicate account {old} renamed to {new}",
+                self.name
+            );
+        }
+    }
+
+    /// The crate-level names of the items generated for the instruction if named `rust_name`
+    pub fn item_names(rust_name: &str) -> Vec<String> {
+        let pascal = pascal_case(rust_name);
+        let shouty = shouty_snake_case(rust_name);
+        let snake = snake_case(rust_name);
+        ["Accounts", "Keys", "IxArgs", "IxData"]
+            .iter()
+            .map(|suffix| format!("{pascal}{suffix}"))
+            .chain(
+                ["_IX_DISCM", "_IX_ACCOUNTS_LEN"]
+                    .iter()
+                    .map(|suffix| format!("{shouty}{suffix}")),
+            )
+            .chain(
+                [
+                    "_ix",
+                    "_ix_with_program_id",
+                    "_invoke",
+                    "_invoke_with_program_id",
+                    "_invoke_signed",
+                    "_invoke_signed_with_program_id",
+                    "_verify_account_keys",
+                    "_verify_account_privileges",
+                    "_verify_writable_privileges",
+                    "_verify_signer_privileges",
+                ]
+                .iter()
+                .map(|suffix| format!("{snake}{suffix}")),
+            )
+            .collect()
+    }
+
+    /// Adds the instruction's lints
+    pub fn lint(&self, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("instruction {}", self.name);
+        check_keyword(lints, &item, &pascal_case(self.rust_name()));
+        for arg in self.args.iter().flatten() {
+            arg.lint(&item, type_names, lints);
+        }
+        for acc in to_ix_accounts(self.accounts.as_deref().unwrap_or_default()).iter() {
+            check_keyword(
+                lints,
+                &format!("{item} account {}", acc.name),
+                &snake_case(&acc.name),
+            );
+        }
+    }
+
     pub fn ix_args_ident(&self) -> Ident {
-        format_ident!("{}IxArgs", self.name.to_pascal_case())
+        format_ident!("{}IxArgs", pascal_case(self.rust_name()))
     }
 
     pub fn ix_data_ident(&self) -> Ident {
-        format_ident!("{}IxData", self.name.to_pascal_case())
+        format_ident!("{}IxData", pascal_case(self.rust_name()))
     }
 
     pub fn ix_fn_ident(&self) -> Ident {
-        format_ident!("{}_ix", self.name.to_snake_case())
+        format_ident!("{}_ix", snake_case(self.rust_name()))
     }
 
     pub fn ix_fn_with_program_id_ident(&self) -> Ident {
-        format_ident!("{}_ix_with_program_id", self.name.to_snake_case())
+        format_ident!("{}_ix_with_program_id", snake_case(self.rust_name()))
     }
 
     pub fn discm_ident(&self) -> Ident {
-        format_ident!("{}_IX_DISCM", &self.name.to_shouty_snake_case())
+        format_ident!("{}_IX_DISCM", shouty_snake_case(self.rust_name()))
     }
 
     pub fn accounts_ident(&self) -> Ident {
-        format_ident!("{}Accounts", self.name.to_pascal_case())
+        format_ident!("{}Accounts", pascal_case(self.rust_name()))
     }
 
     pub fn keys_ident(&self) -> Ident {
-        format_ident!("{}Keys", self.name.to_pascal_case())
+        format_ident!("{}Keys", pascal_case(self.rust_name()))
     }
 
     pub fn accounts_len_ident(&self) -> Ident {
-        format_ident!("{}_IX_ACCOUNTS_LEN", self.name.to_shouty_snake_case())
+        format_ident!("{}_IX_ACCOUNTS_LEN", shouty_snake_case(self.rust_name()))
     }
 
     pub fn has_ix_args(&self) -> bool {
@@ -76,7 +202,16 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_defined()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_defined())
+    }
+
+    pub fn args_has_large_array(&self) -> bool {
+        let args = if !self.has_ix_args() {
+            return false;
+        } else {
+            self.args.as_ref().unwrap()
+        };
+        args.iter().any(|a| a.r#type.is_or_has_large_array())
     }
 
     pub fn args_has_pubkeys(&self) -> bool {
@@ -85,7 +220,7 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_pubkey()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_pubkey())
     }
 
     pub fn has_privileged_accounts(&self) -> bool {
@@ -94,10 +229,19 @@ impl NamedInstruction {
         } else {
             self.accounts.as_ref().unwrap()
         };
-        accounts
+        accounts.iter().any(|a| a.has_privileged_accounts())
+    }
+
+    /// Max length of the instruction's data, `None` if unbounded.
+    /// `defined_len` returns the max borsh-serialized length of `defined` types.
+    pub fn data_max_len(&self, defined_len: &dyn Fn(&str) -> Option<usize>) -> Option<usize> {
+        let args_len: Option<usize> = self
+            .args
             .iter()
-            .map(|a| a.has_privileged_accounts())
-            .any(|b| b)
+            .flatten()
+            .map(|a| a.r#type.borsh_max_len(defined_len))
+            .sum();
+        Some(8 + args_len?)
     }
 
     /// export accounts_len as const
@@ -112,20 +256,29 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_accounts_struct(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
+    pub fn write_accounts_struct(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        target: Target,
+    ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
+        let account_info = target.account_info();
         let accounts_fields = accounts.iter().map(|acc| {
-            let account_name = format_ident!("{}", &acc.name.to_snake_case());
+            let account_name = snake_ident(&acc.name);
             quote! {
-                pub #account_name: &'me AccountInfo<'info>
+                pub #account_name: &'me #account_info
             }
         });
+        let derives = target.accounts_derives();
+        let lifetimes = target.accounts_lifetimes();
         tokens.extend(quote! {
-            #[derive(Copy, Clone, Debug)]
-            pub struct #accounts_ident<'me, 'info> {
+            #derives
+            #[cfg(feature = "cpi")]
+            pub struct #accounts_ident #lifetimes {
                 #(#accounts_fields),*
             }
         });
@@ -137,7 +290,7 @@ impl NamedInstruction {
         }
         let keys_ident = self.keys_ident();
         let keys_fields = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             quote! {
                 pub #account_ident: Pubkey
             }
@@ -151,20 +304,28 @@ impl NamedInstruction {
     }
 
     /// From<XAccounts> for XKeys
-    pub fn write_from_accounts_for_keys(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
+    pub fn write_from_accounts_for_keys(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        target: Target,
+    ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
         let keys_ident = self.keys_ident();
         let from_keys_fields = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
+            let key = target.account_key(quote! { accounts.#account_ident });
             quote! {
-                #account_ident: *accounts.#account_ident.key
+                #account_ident: #key
             }
         });
+        let elided_lifetimes = target.accounts_elided_lifetimes();
         tokens.extend(quote! {
-            impl From<#accounts_ident<'_, '_>> for #keys_ident {
+            #[cfg(feature = "cpi")]
+            impl From<#accounts_ident #elided_lifetimes> for #keys_ident {
                 fn from(accounts: #accounts_ident) -> Self {
                     Self {
                         #(#from_keys_fields),*
@@ -201,7 +362,7 @@ impl NamedInstruction {
         let accounts_len_ident = self.accounts_len_ident();
         let keys_ident = self.keys_ident();
         let from_pubkey_arr_fields = accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
             quote! {
                 #account_ident: pubkeys[#index_lit]
@@ -223,23 +384,71 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
-        let account_info_clone = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-               accounts.#account_ident.clone()
+        match target {
+            Target::SolanaProgram => {
+                let account_info_clone = accounts.iter().map(|acc| {
+                    let account_ident = snake_ident(&acc.name);
+                    quote! {
+                       accounts.#account_ident.clone()
+                    }
+                });
+                tokens.extend(quote! {
+                    #[cfg(feature = "cpi")]
+                    impl<'info> From<#accounts_ident<'_, 'info>> for [AccountInfo<'info>; #accounts_len_ident] {
+                        fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
+                            [
+                                #(#account_info_clone),*
+                            ]
+                        }
+                    }
+                });
             }
-        });
+            Target::Pinocchio => {
+                let account_infos = accounts.iter().map(|acc| {
+                    let account_ident = snake_ident(&acc.name);
+                    quote! {
+                       accounts.#account_ident
+                    }
+                });
+                tokens.extend(quote! {
+                    #[cfg(feature = "cpi")]
+                    impl<'me> From<#accounts_ident<'me>> for [&'me AccountInfo; #accounts_len_ident] {
+                        fn from(accounts: #accounts_ident<'me>) -> Self {
+                            [
+                                #(#account_infos),*
+                            ]
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    /// From <XAccounts> for [pinocchio::instruction::AccountMeta]
+    pub fn write_from_accounts_for_cpi_meta_arr(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+    ) {
+        if !self.has_accounts() {
+            return;
+        }
+        let accounts_ident = self.accounts_ident();
+        let accounts_len_ident = self.accounts_len_ident();
+        let metas = accounts.iter().map(IxAccount::to_cpi_account_meta_tokens);
         tokens.extend(quote! {
-            impl<'info> From<#accounts_ident<'_, 'info>> for [AccountInfo<'info>; #accounts_len_ident] {
-                fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
+            #[cfg(feature = "cpi")]
+            impl<'me> From<#accounts_ident<'me>> for [CpiAccountMeta<'me>; #accounts_len_ident] {
+                fn from(accounts: #accounts_ident<'me>) -> Self {
                     [
-                        #(#account_info_clone),*
+                        #(#metas),*
                     ]
                 }
             }
@@ -251,6 +460,7 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
@@ -258,15 +468,18 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
         let from_account_info_fields = accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
             quote! {
                #account_ident: &arr[#index_lit]
             }
         });
+        let lifetimes = target.accounts_lifetimes();
+        let account_info = target.account_info();
         tokens.extend(quote! {
-            impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
-                fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
+            #[cfg(feature = "cpi")]
+            impl #lifetimes From<&'me [#account_info; #accounts_len_ident]> for #accounts_ident #lifetimes {
+                fn from(arr: &'me [#account_info; #accounts_len_ident]) -> Self {
                     Self {
                         #(#from_account_info_fields),*
                     }
@@ -282,10 +495,26 @@ impl NamedInstruction {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let extra_derives = if self.config.derives.is_empty() {
+            quote! {}
+        } else {
+            let derives = self
+                .config
+                .derives
+                .iter()
+                .map(|d| d.parse::<TokenStream>().unwrap());
+            quote! {
+                #[derive(#(#derives),*)]
+            }
+        };
+        let args_fields = args.iter().map(|a| {
+            let serde_with_attr = a.r#type.serde_with_attr();
+            quote! { #serde_with_attr pub #a }
+        });
         tokens.extend(quote! {
             #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
             #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            #extra_derives
             pub struct #ix_args_ident {
                 #(#args_fields),*
             }
@@ -294,13 +523,7 @@ impl NamedInstruction {
 
     pub fn write_discm(&self, tokens: &mut TokenStream) {
         let discm_ident = self.discm_ident();
-        // pre-image: "global:{instruction_fn_name}"
-        // TODO: handle non-global instructions (state methods - idk if thats deprecated)
-        let discm = <[u8; 8]>::try_from(
-            &Sha256::digest(format!("global:{}", self.name.to_snake_case()).as_bytes()).as_slice()
-                [..8],
-        )
-        .unwrap();
+        let discm = self.discm();
         let discm_value_tokens: TokenStream = format!("{:?}", discm).parse().unwrap();
         tokens.extend(quote! {
             pub const #discm_ident: [u8; 8] = #discm_value_tokens;
@@ -347,8 +570,8 @@ impl NamedInstruction {
             reader.read_exact(&mut maybe_discm)?;
             if maybe_discm != #discm_ident {
                 return Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #discm_ident, maybe_discm)
+                    borsh::io::Error::new(
+                        borsh::io::ErrorKind::Other, format!("discm does not match. Expected: {:?}. Received: {:?}", #discm_ident, maybe_discm)
                     )
                 );
             }
@@ -375,15 +598,15 @@ impl NamedInstruction {
         };
         tokens.extend(quote! {
             impl #ix_data_ident {
-                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
                     #deserialize_body
                 }
 
-                pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+                pub fn serialize<W: borsh::io::Write>(&self, mut writer: W) -> borsh::io::Result<()> {
                     #serialize_body
                 }
 
-                pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+                pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
                     let mut data = Vec::new();
                     self.serialize(&mut data)?;
                     Ok(data)
@@ -392,6 +615,70 @@ impl NamedInstruction {
         });
     }
 
+    /// anchor-lang trait impls behind the `anchor` feature, so that the data can be used
+    /// as anchor `InstructionData`.
+    ///
+    /// anchor's `AnchorSerialize` is borsh 0.10's trait, so it is implemented in terms of
+    /// the borsh 1 traits that the args derive. Like anchor's, it doesn't write the discriminator.
+    pub fn write_anchor_ix_data_impls(&self, tokens: &mut TokenStream) {
+        let discm_ident = self.discm_ident();
+        let ix_data_ident = self.ix_data_ident();
+        let (writer, serialize_body) = if self.has_ix_args() {
+            (
+                quote! { writer },
+                quote! { BorshSerialize::serialize(&self.0, writer) },
+            )
+        } else {
+            (quote! { _writer }, quote! { Ok(()) })
+        };
+        tokens.extend(quote! {
+            #[cfg(feature = "anchor")]
+            impl anchor_lang::Discriminator for #ix_data_ident {
+                const DISCRIMINATOR: &'static [u8] = &#discm_ident;
+            }
+
+            #[cfg(feature = "anchor")]
+            impl anchor_lang::AnchorSerialize for #ix_data_ident {
+                fn serialize<W: borsh::io::Write>(&self, #writer: &mut W) -> borsh::io::Result<()> {
+                    #serialize_body
+                }
+            }
+
+            #[cfg(feature = "anchor")]
+            impl anchor_lang::InstructionData for #ix_data_ident {}
+        });
+    }
+
+    /// solores-runtime `Instruction` impl behind the `runtime` feature
+    pub fn write_runtime_ix_impl(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let discm_ident = self.discm_ident();
+        let ix_data_ident = self.ix_data_ident();
+        let (accounts_len, keys) = if self.has_accounts() {
+            let accounts_len_ident = self.accounts_len_ident();
+            let keys_ident = self.keys_ident();
+            (quote! { #accounts_len_ident }, quote! { #keys_ident })
+        } else {
+            (quote! { 0 }, quote! { () })
+        };
+        let args = if self.has_ix_args() {
+            let ix_args_ident = self.ix_args_ident();
+            quote! { #ix_args_ident }
+        } else {
+            quote! { () }
+        };
+        tokens.extend(quote! {
+            #[cfg(feature = "runtime")]
+            impl solores_runtime::Instruction for #ix_data_ident {
+                const NAME: &'static str = #name;
+                const DISCM: &'static [u8] = &#discm_ident;
+                const ACCOUNTS_LEN: usize = #accounts_len;
+                type Keys = #keys;
+                type Args = #args;
+            }
+        });
+    }
+
     /// _ix()
     /// _ix_with_program_id()
     pub fn write_ix_fn(&self, tokens: &mut TokenStream) {
@@ -442,7 +729,7 @@ impl NamedInstruction {
         };
 
         tokens.extend(quote! {
-            pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> std::io::Result<Instruction> {
+            pub fn #ix_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> borsh::io::Result<Instruction> {
                 #fn_body
                 Ok(Instruction {
                     program_id,
@@ -451,18 +738,19 @@ impl NamedInstruction {
                 })
             }
 
-            pub fn #ix_fn_ident(#fn_params) -> std::io::Result<Instruction> {
+            pub fn #ix_fn_ident(#fn_params) -> borsh::io::Result<Instruction> {
                 #ix_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
     }
 
-    fn invoke_fn_params_prefix(&self) -> TokenStream {
+    fn invoke_fn_params_prefix(&self, target: Target) -> TokenStream {
         let accounts_ident = self.accounts_ident();
         let ix_args_ident = self.ix_args_ident();
+        let elided_lifetimes = target.accounts_elided_lifetimes();
         let mut fn_params = quote! {};
         if self.has_accounts() {
-            fn_params.extend(quote! { accounts: #accounts_ident<'_, '_>, });
+            fn_params.extend(quote! { accounts: #accounts_ident #elided_lifetimes, });
         }
         if self.has_ix_args() {
             fn_params.extend(quote! { args: #ix_args_ident, })
@@ -504,10 +792,10 @@ impl NamedInstruction {
     /// _invoke()
     /// _invoke_with_program_id()
     pub fn write_invoke_fn(&self, tokens: &mut TokenStream) {
-        let invoke_fn_ident = format_ident!("{}_invoke", self.name.to_snake_case());
+        let invoke_fn_ident = format_ident!("{}_invoke", snake_case(self.rust_name()));
         let invoke_with_program_id_fn_ident =
-            format_ident!("{}_invoke_with_program_id", self.name.to_snake_case());
-        let fn_params = self.invoke_fn_params_prefix();
+            format_ident!("{}_invoke_with_program_id", snake_case(self.rust_name()));
+        let fn_params = self.invoke_fn_params_prefix(Target::SolanaProgram);
         let fn_args = self.invoke_fn_args_prefix();
         let call_assign = self.ix_call_assign();
         let invoke = if self.has_accounts() {
@@ -520,11 +808,13 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
+            #[cfg(feature = "cpi")]
             pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
                 #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
             }
@@ -534,12 +824,13 @@ impl NamedInstruction {
     /// _invoke_signed()
     /// _invoke_signed_with_program_id()
     pub fn write_invoke_signed_fn(&self, tokens: &mut TokenStream) {
-        let invoke_signed_fn_ident = format_ident!("{}_invoke_signed", self.name.to_snake_case());
+        let invoke_signed_fn_ident =
+            format_ident!("{}_invoke_signed", snake_case(self.rust_name()));
         let invoke_signed_with_program_id_fn_ident = format_ident!(
             "{}_invoke_signed_with_program_id",
-            self.name.to_snake_case()
+            snake_case(self.rust_name())
         );
-        let mut fn_params = self.invoke_fn_params_prefix();
+        let mut fn_params = self.invoke_fn_params_prefix(Target::SolanaProgram);
         fn_params.extend(quote! { seeds: &[&[&[u8]]], });
         let mut fn_args = self.invoke_fn_args_prefix();
         fn_args.extend(quote! { seeds, });
@@ -554,27 +845,113 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #invoke_signed_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
+            #[cfg(feature = "cpi")]
             pub fn #invoke_signed_fn_ident(#fn_params) -> ProgramResult {
                 #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
     }
 
+    /// pinocchio _invoke()
+    /// _invoke_with_program_id()
+    /// _invoke_signed()
+    /// _invoke_signed_with_program_id()
+    ///
+    /// `data_max_len` is the max length of the instruction's data, `None` if unbounded
+    pub fn write_pinocchio_invoke_fns(
+        &self,
+        tokens: &mut TokenStream,
+        data_max_len: Option<usize>,
+    ) {
+        let snake = snake_case(self.rust_name());
+        let invoke_fn_ident = format_ident!("{snake}_invoke");
+        let invoke_with_program_id_fn_ident = format_ident!("{snake}_invoke_with_program_id");
+        let invoke_signed_fn_ident = format_ident!("{snake}_invoke_signed");
+        let invoke_signed_with_program_id_fn_ident =
+            format_ident!("{snake}_invoke_signed_with_program_id");
+        let ix_data_ident = self.ix_data_ident();
+        let fn_params = self.invoke_fn_params_prefix(Target::Pinocchio);
+        let fn_args = self.invoke_fn_args_prefix();
+
+        let data_buf_len = match data_max_len {
+            Some(len) => LitInt::new(&len.to_string(), Span::call_site()).into_token_stream(),
+            None => quote! { CPI_IX_DATA_BUF_LEN },
+        };
+        let ix_data = if self.has_ix_args() {
+            quote! { #ix_data_ident(args) }
+        } else {
+            quote! { #ix_data_ident }
+        };
+        let invoke = if self.has_accounts() {
+            quote! {
+                invoke_instruction_signed(&program_id, accounts, data, signers)
+            }
+        } else {
+            quote! {
+                invoke_signed(
+                    &CpiInstruction {
+                        program_id: &program_id.to_bytes(),
+                        accounts: &[],
+                        data,
+                    },
+                    &[],
+                    signers,
+                )
+            }
+        };
+        tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_signed_with_program_id_fn_ident(
+                program_id: Pubkey,
+                #fn_params
+                signers: &[Signer],
+            ) -> ProgramResult {
+                let mut buf = [0u8; #data_buf_len];
+                let data = write_ix_data(&mut buf, |writer| #ix_data.serialize(writer))?;
+                #invoke
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_signed_fn_ident(#fn_params signers: &[Signer]) -> ProgramResult {
+                #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args signers)
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+                #invoke_signed_with_program_id_fn_ident(program_id, #fn_args &[])
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
+                #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
+            }
+        });
+    }
+
     /// _verify_account_keys()
-    pub fn write_verify_account_keys_fn(&self, tokens: &mut TokenStream, accounts: &[IxAccount]) {
+    pub fn write_verify_account_keys_fn(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+        target: Target,
+    ) {
         if !self.has_accounts() {
             return;
         }
         let verify_account_keys_fn_ident =
-            format_ident!("{}_verify_account_keys", self.name.to_snake_case());
+            format_ident!("{}_verify_account_keys", snake_case(self.rust_name()));
         let accounts_ident = self.accounts_ident();
         let keys_ident = self.keys_ident();
-        let key_tups = accounts.iter().map(IxAccount::to_verify_account_keys_tuple);
+        let elided_lifetimes = target.accounts_elided_lifetimes();
+        let key_tups = accounts
+            .iter()
+            .map(|acc| acc.to_verify_account_keys_tuple(target));
         // edge-case of accounts and keys being empty
         let pubkeys_loop_check = if accounts.is_empty() {
             quote! {}
@@ -590,8 +967,9 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #verify_account_keys_fn_ident(
-                accounts: #accounts_ident<'_, '_>,
+                accounts: #accounts_ident #elided_lifetimes,
                 keys: #keys_ident
             ) -> Result<(), (Pubkey, Pubkey)> {
                 #pubkeys_loop_check
@@ -607,17 +985,22 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
+        target: Target,
     ) {
         if !self.has_privileged_accounts() {
             return;
         }
         let verify_account_privileges_fn_ident =
-            format_ident!("{}_verify_account_privileges", self.name.to_snake_case());
-        let verify_writable_privileges_fn_ident =
-            format_ident!("{}_verify_writable_privileges", self.name.to_snake_case());
+            format_ident!("{}_verify_account_privileges", snake_case(self.rust_name()));
+        let verify_writable_privileges_fn_ident = format_ident!(
+            "{}_verify_writable_privileges",
+            snake_case(self.rust_name())
+        );
         let verify_signer_privileges_fn_ident =
-            format_ident!("{}_verify_signer_privileges", self.name.to_snake_case());
+            format_ident!("{}_verify_signer_privileges", snake_case(self.rust_name()));
         let accounts_ident = self.accounts_ident();
+        let lifetimes = target.accounts_lifetimes();
+        let account_info = target.account_info();
 
         let mut verify_fn_body = quote! {};
 
@@ -636,14 +1019,16 @@ impl NamedInstruction {
             .peekable();
         let has_writables = writables.peek().is_some();
         if has_writables {
+            let should_be_writable_is_writable = target.is_writable(quote! { should_be_writable });
             tokens.extend(quote! {
-                pub fn #verify_writable_privileges_fn_ident<'me, 'info>(
-                    accounts: #accounts_ident<'me, 'info>,
-                ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                #[cfg(feature = "cpi")]
+                pub fn #verify_writable_privileges_fn_ident #lifetimes(
+                    accounts: #accounts_ident #lifetimes,
+                ) -> Result<(), (&'me #account_info, ProgramError)> {
                     for should_be_writable in [
                         #(#writables),*
                     ] {
-                        if !should_be_writable.is_writable {
+                        if !#should_be_writable_is_writable {
                             return Err((should_be_writable, ProgramError::InvalidAccountData));
                         }
                     }
@@ -670,14 +1055,16 @@ impl NamedInstruction {
             .peekable();
         let has_signers = signers.peek().is_some();
         if has_signers {
+            let should_be_signer_is_signer = target.is_signer(quote! { should_be_signer });
             tokens.extend(quote! {
-                pub fn #verify_signer_privileges_fn_ident<'me, 'info>(
-                    accounts: #accounts_ident<'me, 'info>,
-                ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                #[cfg(feature = "cpi")]
+                pub fn #verify_signer_privileges_fn_ident #lifetimes(
+                    accounts: #accounts_ident #lifetimes,
+                ) -> Result<(), (&'me #account_info, ProgramError)> {
                     for should_be_signer in [
                         #(#signers),*
                     ] {
-                        if !should_be_signer.is_signer {
+                        if !#should_be_signer_is_signer {
                             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
                         }
                     }
@@ -690,9 +1077,10 @@ impl NamedInstruction {
         }
 
         tokens.extend(quote! {
-            pub fn #verify_account_privileges_fn_ident<'me, 'info>(
-                accounts: #accounts_ident<'me, 'info>,
-            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+            #[cfg(feature = "cpi")]
+            pub fn #verify_account_privileges_fn_ident #lifetimes(
+                accounts: #accounts_ident #lifetimes,
+            ) -> Result<(), (&'me #account_info, ProgramError)> {
                 #verify_fn_body
                 Ok(())
             }
@@ -700,47 +1088,55 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let accounts = self
-            .accounts
-            .as_ref()
-            .map_or(Vec::new(), |v| to_ix_accounts(v));
+impl NamedInstruction {
+    /// Writes all of the instruction's items, with the on-chain ones written against `target`.
+    /// `data_max_len` is the max length of the instruction's data, `None` if unbounded.
+    pub fn write_items(
+        &self,
+        tokens: &mut TokenStream,
+        target: Target,
+        data_max_len: Option<usize>,
+    ) {
+        let accounts = self.ix_accounts();
         let n_accounts = accounts.len();
 
-        let UniqueByReportDupsResult { duplicates, .. } =
-            unique_by_report_dups(accounts.iter(), |acc| acc.name.clone());
-
-        if !duplicates.is_empty() {
-            log::error!(
-                "Found duplicate accounts for instruction {}: {}",
-                &self.name,
-                duplicates.iter().map(|acc| &acc.name).format(", ")
-            );
-            panic!();
-        }
-
         self.write_accounts_len(tokens, n_accounts);
-        self.write_accounts_struct(tokens, &accounts);
+        self.write_accounts_struct(tokens, &accounts, target);
         self.write_keys_struct(tokens, &accounts);
-        self.write_from_accounts_for_keys(tokens, &accounts);
+        self.write_from_accounts_for_keys(tokens, &accounts, target);
         self.write_from_keys_for_meta_arr(tokens, &accounts);
         self.write_from_pubkey_arr_for_keys(tokens, &accounts);
-        self.write_from_accounts_for_account_info_arr(tokens, &accounts);
-        self.write_from_account_info_arr_for_accounts(tokens, &accounts);
+        self.write_from_accounts_for_account_info_arr(tokens, &accounts, target);
+        if target == Target::Pinocchio {
+            self.write_from_accounts_for_cpi_meta_arr(tokens, &accounts);
+        }
+        self.write_from_account_info_arr_for_accounts(tokens, &accounts, target);
 
         self.write_discm(tokens);
         self.write_ix_args_struct(tokens);
         self.write_ix_data_struct(tokens);
         self.write_from_ix_args_for_ix_data(tokens);
         self.write_ix_data_impl(tokens);
+        self.write_anchor_ix_data_impls(tokens);
+        self.write_runtime_ix_impl(tokens);
 
         self.write_ix_fn(tokens);
-        self.write_invoke_fn(tokens);
-        self.write_invoke_signed_fn(tokens);
+        match target {
+            Target::SolanaProgram => {
+                self.write_invoke_fn(tokens);
+                self.write_invoke_signed_fn(tokens);
+            }
+            Target::Pinocchio => self.write_pinocchio_invoke_fns(tokens, data_max_len),
+        }
+
+        self.write_verify_account_keys_fn(tokens, &accounts, target);
+        self.write_verify_account_privileges_fns(tokens, &accounts, target);
+    }
+}
 
-        self.write_verify_account_keys_fn(tokens, &accounts);
-        self.write_verify_account_privileges_fns(tokens, &accounts);
+impl ToTokens for NamedInstruction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.write_items(tokens, Target::SolanaProgram, None);
     }
 }
 
@@ -752,10 +1148,7 @@ pub struct InnerAccountStruct {
 
 impl InnerAccountStruct {
     pub fn has_privileged_accounts(&self) -> bool {
-        self.accounts
-            .iter()
-            .map(|a| a.has_privileged_accounts())
-            .any(|b| b)
+        self.accounts.iter().any(|a| a.has_privileged_accounts())
     }
 }
 
@@ -785,7 +1178,7 @@ pub struct IxAccount {
 
 impl IxAccount {
     pub fn field_ident(&self) -> Ident {
-        format_ident!("{}", self.name.to_snake_case())
+        snake_ident(&self.name)
     }
 
     pub fn is_privileged(&self) -> bool {
@@ -805,10 +1198,24 @@ impl IxAccount {
         }
     }
 
-    pub fn to_verify_account_keys_tuple(&self) -> TokenStream {
+    pub fn to_cpi_account_meta_tokens(&self) -> TokenStream {
+        let is_writable_arg = LitBool::new(self.is_mut, Span::call_site());
+        let is_signer_arg = LitBool::new(self.is_signer, Span::call_site());
+        let name = self.field_ident();
+        quote! {
+            CpiAccountMeta {
+                pubkey: accounts.#name.key(),
+                is_writable: #is_writable_arg,
+                is_signer: #is_signer_arg,
+            }
+        }
+    }
+
+    pub fn to_verify_account_keys_tuple(&self, target: Target) -> TokenStream {
         let name = self.field_ident();
+        let key = target.account_key(quote! { accounts.#name });
         quote! {
-            (*accounts.#name.key, keys.#name)
+            (#key, keys.#name)
         }
     }
 }
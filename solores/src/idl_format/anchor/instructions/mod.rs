@@ -1,15 +1,28 @@
-use heck::ToPascalCase;
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::LitInt;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    naming::{pascal_case, pascal_ident},
+    target::{Target, PINOCCHIO_DEFAULT_DATA_BUF_LEN},
+    {idl_format::IdlCodegenModule, Result},
+};
 
+mod compact;
 mod instruction;
 pub use instruction::*;
 
 pub struct IxCodegenModule<'a> {
     pub program_name: &'a str,
     pub instructions: &'a [NamedInstruction],
+    /// Whether `ProgramIx` has an `Unknown` variant for the discriminators of filtered out instructions
+    pub has_unknown_variant: bool,
+    pub target: Target,
+    /// Max length of each instruction's data, `None` if unbounded.
+    /// Only used by [`Target::Pinocchio`], empty otherwise.
+    pub data_max_lens: Vec<Option<usize>>,
+    /// Whether instructions' items are written as invocations of [`compact::compact_macros`]
+    pub compact: bool,
 }
 
 impl IdlCodegenModule for IxCodegenModule<'_> {
@@ -17,60 +30,83 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         "instructions"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {};
-        let has_args = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_ix_args())
-            .any(|b| b);
+        let has_args = self.instructions.iter().any(|ix| ix.has_ix_args());
         if has_args {
             res.extend(quote! {
                 use borsh::{BorshDeserialize, BorshSerialize};
             });
         }
-        let has_accounts = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_accounts())
-            .any(|b| b);
+        let has_accounts = self.instructions.iter().any(|ix| ix.has_accounts());
 
-        let mut solana_program_imports = if has_accounts {
+        let solana_program_imports = if has_accounts {
             quote! {
-                account_info::AccountInfo,
-                entrypoint::ProgramResult,
                 instruction::{AccountMeta, Instruction},
-                program::{invoke, invoke_signed},
                 pubkey::Pubkey,
             }
         } else {
             quote! {
-                entrypoint::ProgramResult,
                 instruction::Instruction,
-                program::{invoke, invoke_signed},
                 pubkey::Pubkey,
             }
         };
         let has_privileged_accounts = self
             .instructions
             .iter()
-            .map(|ix| ix.has_privileged_accounts())
-            .any(|b| b);
-        if has_privileged_accounts {
-            solana_program_imports.extend(quote! {
-                program_error::ProgramError,
-            });
-        }
+            .any(|ix| ix.has_privileged_accounts());
+        let cpi_imports = match self.target {
+            Target::SolanaProgram => {
+                let mut cpi_imports = if has_accounts {
+                    quote! {
+                        account_info::AccountInfo,
+                        entrypoint::ProgramResult,
+                        program::{invoke, invoke_signed},
+                    }
+                } else {
+                    quote! {
+                        entrypoint::ProgramResult,
+                        program::{invoke, invoke_signed},
+                    }
+                };
+                if has_privileged_accounts {
+                    cpi_imports.extend(quote! {
+                        program_error::ProgramError,
+                    });
+                }
+                quote! {
+                    use solana_program::{#cpi_imports};
+                }
+            }
+            Target::Pinocchio => {
+                let cpi_imports = if has_accounts {
+                    quote! {
+                        account_info::AccountInfo,
+                        cpi::invoke_signed,
+                        instruction::{AccountMeta as CpiAccountMeta, Instruction as CpiInstruction, Signer},
+                    }
+                } else {
+                    quote! {
+                        cpi::invoke_signed,
+                        instruction::{Instruction as CpiInstruction, Signer},
+                    }
+                };
+                quote! {
+                    use pinocchio::{#cpi_imports program_error::ProgramError, ProgramResult};
+                }
+            }
+        };
 
         res.extend(quote! {
             use solana_program::{#solana_program_imports};
-            use std::io::Read;
+            #[cfg(feature = "cpi")]
+            #cpi_imports
+            use borsh::io::Read;
         });
         let has_defined_type = self
             .instructions
             .iter()
-            .map(|ix| ix.args_has_defined_type())
-            .any(|b| b);
+            .any(|ix| ix.args_has_defined_type());
         if has_defined_type {
             res.extend(quote! {
                 use crate::*;
@@ -78,11 +114,31 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         }
 
         // program ix enum
-        let program_ix_enum_ident =
-            format_ident!("{}ProgramIx", self.program_name.to_pascal_case());
-        let program_ix_enum_variants = self.instructions.iter().map(enum_variant);
-        let serialize_variant_match_arms =
-            self.instructions.iter().map(serialize_variant_match_arm);
+        let program_ix_enum_ident = format_ident!("{}ProgramIx", pascal_case(self.program_name));
+        let mut program_ix_enum_variants: Vec<_> =
+            self.instructions.iter().map(enum_variant).collect();
+        let mut serialize_variant_match_arms: Vec<_> = self
+            .instructions
+            .iter()
+            .map(serialize_variant_match_arm)
+            .collect();
+        let mut unknown_discm_expr = quote! {
+            Err(
+                borsh::io::Error::new(
+                    borsh::io::ErrorKind::Other, format!("discm {:?} not found", maybe_discm)
+                )
+            )
+        };
+        if self.has_unknown_variant {
+            program_ix_enum_variants.push(quote! { Unknown([u8; 8], Vec<u8>) });
+            serialize_variant_match_arms.push(quote! {
+                Self::Unknown(discm, data) => {
+                    writer.write_all(discm)?;
+                    writer.write_all(data)
+                }
+            });
+            unknown_discm_expr = quote! { Ok(Self::Unknown(maybe_discm, reader.to_vec())) };
+        }
         let deserialize_variant_match_arms =
             self.instructions.iter().map(deserialize_variant_match_arm);
 
@@ -93,27 +149,23 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             }
 
             impl #program_ix_enum_ident {
-                pub fn deserialize(buf: &[u8]) -> std::io::Result<Self> {
+                pub fn deserialize(buf: &[u8]) -> borsh::io::Result<Self> {
                     let mut reader = buf;
                     let mut maybe_discm = [0u8; 8];
                     reader.read_exact(&mut maybe_discm)?;
                     match maybe_discm {
                         #(#deserialize_variant_match_arms),*,
-                        _ => Err(
-                            std::io::Error::new(
-                                std::io::ErrorKind::Other, format!("discm {:?} not found", maybe_discm)
-                            )
-                        ),
+                        _ => #unknown_discm_expr,
                     }
                 }
 
-                pub fn serialize<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+                pub fn serialize<W: borsh::io::Write>(&self, mut writer: W) -> borsh::io::Result<()> {
                     match self {
                         #(#serialize_variant_match_arms),*,
                     }
                 }
 
-                pub fn try_to_vec(&self) -> std::io::Result<Vec<u8>> {
+                pub fn try_to_vec(&self) -> borsh::io::Result<Vec<u8>> {
                     let mut data = Vec::new();
                     self.serialize(&mut data)?;
                     Ok(data)
@@ -121,8 +173,11 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             }
         });
 
-        if has_accounts {
+        if self.target == Target::Pinocchio {
+            res.extend(self.pinocchio_cpi_helpers());
+        } else if has_accounts {
             res.extend(quote! {
+                #[cfg(feature = "cpi")]
                 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
                     ix: &Instruction,
                     accounts: A,
@@ -130,6 +185,7 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
                     let account_info: [AccountInfo<'info>; N] = accounts.into();
                     invoke(ix, &account_info)
                 }
+                #[cfg(feature = "cpi")]
                 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
                     ix: &Instruction,
                     accounts: A,
@@ -141,19 +197,104 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             });
         }
 
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.items().into_iter().map(|(_, tokens)| tokens).collect())
+    }
+
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(Some(self.items()))
+    }
+
+    fn gen_macros(&self) -> String {
+        if !self.compact || self.instructions.is_empty() {
+            return String::new();
+        }
+        compact::compact_macros(self.instructions, self.target)
+    }
+}
+
+impl IxCodegenModule<'_> {
+    /// (rust name, items) of each instruction
+    fn items(&self) -> Vec<(String, TokenStream)> {
         self.instructions
             .iter()
-            .map(|e| e.into_token_stream())
+            .enumerate()
+            .map(|(i, ix)| {
+                let mut tokens = quote! {};
+                let data_max_len = self.data_max_lens.get(i).copied().flatten();
+                if self.compact {
+                    ix.write_compact_items(&mut tokens, self.target, data_max_len);
+                } else {
+                    ix.write_items(&mut tokens, self.target, data_max_len);
+                }
+                (ix.rust_name().to_owned(), tokens)
+            })
             .collect()
     }
+
+    /// Helpers shared by pinocchio `*_invoke*()` functions
+    fn pinocchio_cpi_helpers(&self) -> TokenStream {
+        let mut res = quote! {
+            /// Serializes instruction data into `buf` with `serialize`, returning the written bytes
+            #[cfg(feature = "cpi")]
+            fn write_ix_data(
+                buf: &mut [u8],
+                serialize: impl FnOnce(&mut &mut [u8]) -> borsh::io::Result<()>,
+            ) -> Result<&[u8], ProgramError> {
+                let buf_len = buf.len();
+                let mut writer = &mut *buf;
+                serialize(&mut writer).map_err(|_| ProgramError::InvalidInstructionData)?;
+                let len = buf_len - writer.len();
+                Ok(&buf[..len])
+            }
+        };
+        if self.instructions.iter().any(|ix| ix.has_accounts()) {
+            res.extend(quote! {
+                #[cfg(feature = "cpi")]
+                fn invoke_instruction_signed<'me, A, const N: usize>(
+                    program_id: &Pubkey,
+                    accounts: A,
+                    data: &[u8],
+                    signers: &[Signer],
+                ) -> ProgramResult
+                where
+                    A: Copy + Into<[CpiAccountMeta<'me>; N]> + Into<[&'me AccountInfo; N]>,
+                {
+                    let metas: [CpiAccountMeta<'me>; N] = accounts.into();
+                    let account_infos: [&'me AccountInfo; N] = accounts.into();
+                    invoke_signed(
+                        &CpiInstruction {
+                            program_id: &program_id.to_bytes(),
+                            accounts: &metas,
+                            data,
+                        },
+                        &account_infos,
+                        signers,
+                    )
+                }
+            });
+        }
+        if self.data_max_lens.iter().any(Option::is_none) {
+            let default_len = LitInt::new(
+                &PINOCCHIO_DEFAULT_DATA_BUF_LEN.to_string(),
+                Span::call_site(),
+            );
+            res.extend(quote! {
+                /// Size of the stack buffer that `*_invoke*()` functions serialize the data of
+                /// instructions with unbounded args into.
+                /// Data that doesn't fit fails with `ProgramError::InvalidInstructionData`.
+                pub const CPI_IX_DATA_BUF_LEN: usize = #default_len;
+            });
+        }
+        res
+    }
 }
 
 pub fn enum_variant(ix: &NamedInstruction) -> TokenStream {
-    let variant_ident = format_ident!("{}", ix.name.to_pascal_case());
+    let variant_ident = pascal_ident(ix.rust_name());
     let mut res = quote!(
         #variant_ident
     );
@@ -167,7 +308,7 @@ pub fn enum_variant(ix: &NamedInstruction) -> TokenStream {
 }
 
 pub fn serialize_variant_match_arm(ix: &NamedInstruction) -> TokenStream {
-    let variant_ident = format_ident!("{}", ix.name.to_pascal_case());
+    let variant_ident = pascal_ident(ix.rust_name());
     let discm_ident = ix.discm_ident();
     let serialize_expr = if ix.has_ix_args() {
         quote! {{
@@ -187,7 +328,7 @@ pub fn serialize_variant_match_arm(ix: &NamedInstruction) -> TokenStream {
 }
 
 pub fn deserialize_variant_match_arm(ix: &NamedInstruction) -> TokenStream {
-    let variant_ident = format_ident!("{}", ix.name.to_pascal_case());
+    let variant_ident = pascal_ident(ix.rust_name());
     let discm_ident = ix.discm_ident();
     let mut variant_expr = quote! {
         Self::#variant_ident
@@ -1,9 +1,24 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 use serde::Deserialize;
 use toml::{map::Map, Value};
 
-use crate::write_cargotoml::{
-    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
-    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+use crate::{
+    config::warn_unmatched,
+    filter::{referenced_types, retain_items, Filters},
+    lint::{check_duplicate_names, check_keyword, collisions, Lint, ANCHOR_ERROR_CODE_OFFSET},
+    naming::{pascal_case, ItemNames},
+    solana_crates::SolanaCratesUsage,
+    target::Target,
+    utils::{check_ident, MAX_TYPE_DEPTH},
+    write_cargotoml::{
+        borsh_dependency, mapped_type_dependency, num_traits_dependency, serde_dependency,
+        solana_program_dependency, DependencyValue, FeaturesDependencyValue,
+        OptionalDependencyValue, ANCHOR_FEATURE, ANCHOR_LANG_CRATE, BORSH_CRATE, BYTEMUCK_CRATE,
+        CLIENT_FEATURE, CPI_FEATURE, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, PINOCCHIO_CRATE,
+        RUNTIME_FEATURE, SERDE_CRATE, SERDE_WITH_CRATE, SOLANA_RPC_CLIENT_API_CRATE,
+        SOLORES_RUNTIME_CRATE, THISERROR_CRATE,
+    },
 };
 
 use super::{IdlCodegenModule, IdlFormat};
@@ -11,9 +26,9 @@ use super::{IdlCodegenModule, IdlFormat};
 use self::{
     accounts::{AccountsCodegenModule, NamedAccount},
     errors::{ErrorEnumVariant, ErrorsCodegenModule},
-    events::{Event, EventsCodegenModule},
+    events::{Event, EventType, EventsCodegenModule},
     instructions::{IxCodegenModule, NamedInstruction},
-    typedefs::{NamedType, TypedefsCodegenModule},
+    typedefs::{NamedType, TypedefFieldType, TypedefsCodegenModule},
 };
 
 pub mod accounts;
@@ -32,6 +47,15 @@ pub struct AnchorIdl {
     pub instructions: Option<Vec<NamedInstruction>>,
     pub errors: Option<Vec<ErrorEnumVariant>>,
     pub events: Option<Vec<Event>>,
+    /// The IDL's JSON as loaded, set by [`crate::load_idl_str`] and [`crate::load_idl_value`]
+    #[serde(skip)]
+    pub json: String,
+    /// Rust types of the `--map-type` mappings used by the IDL, set by `apply_item_configs()`
+    #[serde(skip)]
+    pub mapped_types: BTreeSet<String>,
+    /// Whether any instructions were removed by `apply_filters()`
+    #[serde(skip)]
+    pub has_filtered_instructions: bool,
 }
 
 #[derive(Deserialize)]
@@ -39,11 +63,100 @@ pub struct Metadata {
     pub address: String,
 }
 
+impl AnchorIdl {
+    /// Checks that all of the IDL's items can be generated
+    pub fn validate(&self) -> crate::Result<()> {
+        check_ident("program", &pascal_case(&self.name))?;
+        for t in self.types.iter().flatten() {
+            t.validate("typedef")?;
+        }
+        for a in self.accounts.iter().flatten() {
+            a.0.validate("account")?;
+        }
+        for ix in self.instructions.iter().flatten() {
+            ix.validate()?;
+        }
+        for e in self.errors.iter().flatten() {
+            e.validate()?;
+        }
+        for e in self.events.iter().flatten() {
+            e.0.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Whether any serde-derived generated struct has an array field
+    /// that requires a serde_with adapter
+    pub fn has_large_array(&self) -> bool {
+        self.accounts
+            .iter()
+            .flatten()
+            .any(|a| a.0.r#type.has_large_array_field())
+            || self
+                .types
+                .iter()
+                .flatten()
+                .any(|t| t.r#type.has_large_array_field())
+            || self
+                .instructions
+                .iter()
+                .flatten()
+                .any(|ix| ix.args_has_large_array())
+    }
+
+    /// What the generated code uses from the solana crates
+    pub fn solana_crates_usage(&self) -> SolanaCratesUsage {
+        let instructions = self.instructions.iter().flatten();
+        SolanaCratesUsage {
+            has_instructions: self.instructions.is_some(),
+            has_ix_accounts: instructions.clone().any(|ix| ix.has_accounts()),
+            has_errors: self.errors.is_some(),
+            has_pubkey_fields: self
+                .types
+                .iter()
+                .flatten()
+                .chain(self.accounts.iter().flatten().map(|a| &a.0))
+                .any(|t| t.r#type.has_pubkey_field())
+                || self
+                    .events
+                    .iter()
+                    .flatten()
+                    .any(|e| e.0.fields.iter().any(|f| f.r#type.is_or_has_pubkey()))
+                || instructions.clone().any(|ix| ix.args_has_pubkeys()),
+            is_bincode: false,
+        }
+    }
+
+    /// Max borsh-serialized length of the typedef or account generated as `name`, `None` if
+    /// unbounded, not generated from the IDL or nested deeper than [`MAX_TYPE_DEPTH`]
+    pub fn defined_borsh_max_len(&self, name: &str, depth: usize) -> Option<usize> {
+        if depth > MAX_TYPE_DEPTH {
+            return None;
+        }
+        let ty = self
+            .types
+            .iter()
+            .flatten()
+            .chain(self.accounts.iter().flatten().map(|a| &a.0))
+            .find(|t| t.rust_name() == name)?;
+        ty.r#type
+            .borsh_max_len(&|name| self.defined_borsh_max_len(name, depth + 1))
+    }
+}
+
 impl IdlFormat for AnchorIdl {
     fn program_name(&self) -> &str {
         &self.name
     }
 
+    fn json(&self) -> &str {
+        &self.json
+    }
+
+    fn set_json(&mut self, json: String) {
+        self.json = json;
+    }
+
     fn program_version(&self) -> &str {
         &self.version
     }
@@ -52,14 +165,198 @@ impl IdlFormat for AnchorIdl {
         self.metadata.as_ref().map(|m| m.address.as_ref())
     }
 
-    /// Anchor IDLs dont seem to have an identifier,
-    /// assume unindentified IDLs are anchor by default.
-    /// -> Make sure to try deserializing Anchor last
-    fn is_correct_idl_format(&self) -> bool {
-        true
+    fn apply_filters(&mut self, filters: &Filters) {
+        if !filters.is_set() {
+            return;
+        }
+        self.has_filtered_instructions = retain_items(&mut self.instructions, |ix| {
+            filters.instructions.matches(&ix.name)
+        }) > 0;
+        retain_items(&mut self.events, |e| filters.events.matches(&e.0.name));
+
+        let ix_args = self
+            .instructions
+            .iter()
+            .flatten()
+            .flat_map(|ix| ix.args.iter().flatten());
+        let event_fields = self.events.iter().flatten().flat_map(|e| e.0.fields.iter());
+        let roots = ix_args
+            .chain(event_fields)
+            .filter_map(|field| field.r#type.defined_type())
+            .chain(
+                self.accounts
+                    .iter()
+                    .flatten()
+                    .filter(|a| filters.accounts.matches(&a.0.name))
+                    .map(|a| a.0.name.as_str()),
+            )
+            .chain(
+                self.types
+                    .iter()
+                    .flatten()
+                    .filter(|t| filters.is_typedef_root(&t.name))
+                    .map(|t| t.name.as_str()),
+            )
+            .map(ToOwned::to_owned);
+        let referenced = referenced_types(roots, |name| {
+            self.types
+                .iter()
+                .flatten()
+                .chain(self.accounts.iter().flatten().map(|a| &a.0))
+                .filter(|t| t.name == name)
+                .flat_map(|t| t.r#type.defined_types())
+                .map(ToOwned::to_owned)
+                .collect()
+        });
+
+        retain_items(&mut self.accounts, |a| {
+            let keep = referenced.contains(&a.0.name);
+            if keep && !filters.accounts.matches(&a.0.name) {
+                log::warn!(
+                    "Generating filtered out account {} since generated items reference it",
+                    a.0.name
+                );
+            }
+            keep
+        });
+        retain_items(&mut self.types, |t| referenced.contains(&t.name));
+    }
+
+    fn apply_item_configs(&mut self, args: &crate::Args) {
+        warn_unmatched(
+            "typedef",
+            &args.typedef_configs,
+            self.types.iter().flatten().map(|t| t.name.as_str()),
+        );
+        warn_unmatched(
+            "account",
+            &args.account_configs,
+            self.accounts.iter().flatten().map(|a| a.0.name.as_str()),
+        );
+        warn_unmatched(
+            "instruction",
+            &args.instruction_configs,
+            self.instructions
+                .iter()
+                .flatten()
+                .map(|ix| ix.name.as_str()),
+        );
+
+        // typedefs mapped to external types are not generated
+        let mappings: HashMap<&str, &str> = args
+            .map_type
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_str()))
+            .collect();
+        if let Some(types) = self.types.as_mut() {
+            let n_types = types.len();
+            types.retain(|t| !mappings.contains_key(t.name.as_str()));
+            if n_types > 0 && types.is_empty() {
+                self.types = None;
+            }
+        }
+
+        for t in self.types.iter_mut().flatten() {
+            if let Some(config) = args.typedef_configs.get(&t.name) {
+                t.apply_config(config);
+            }
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            if let Some(config) = args.account_configs.get(&a.0.name) {
+                a.0.apply_config(config);
+            }
+        }
+        for ix in self.instructions.iter_mut().flatten() {
+            if let Some(config) = args.instruction_configs.get(&ix.name) {
+                ix.apply_config(config);
+            }
+        }
+
+        // Disambiguate items whose generated names collide. Instructions go first
+        // so that their helpers keep the names derived from the IDL.
+        let mut item_names = ItemNames::new(self.program_name());
+        for ix in self.instructions.iter_mut().flatten() {
+            ix.warn_duplicate_accounts();
+            if let Some(rename) = item_names.reserve(
+                &format!("instruction {}", ix.name),
+                ix.rust_name(),
+                NamedInstruction::item_names,
+            ) {
+                ix.config.rename = Some(rename);
+            }
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            if let Some(rename) = item_names.reserve(
+                &format!("account {}", a.0.name),
+                &a.0.rust_name(),
+                NamedAccount::item_names,
+            ) {
+                a.0.config.rename = Some(rename);
+            }
+        }
+        for e in self.events.iter_mut().flatten() {
+            if let Some(rename) = item_names.reserve(
+                &format!("event {}", e.0.name),
+                e.0.rust_name(),
+                EventType::item_names,
+            ) {
+                e.0.rename = Some(rename);
+            }
+        }
+        for t in self.types.iter_mut().flatten() {
+            if let Some(rename) =
+                item_names.reserve(&format!("typedef {}", t.name), &t.rust_name(), |n| {
+                    vec![n.to_owned()]
+                })
+            {
+                t.config.rename = Some(rename);
+            }
+        }
+
+        // `defined` types are IDL names. Replace them with the generated names,
+        // which differ if renamed or converted to valid identifiers
+        let rust_names = self.defined_types();
+        // the first of any types with duplicate IDL names is the one referenced
+        let mut renames: HashMap<&str, &str> = HashMap::new();
+        for (name, rust_name) in rust_names.iter() {
+            renames.entry(name.as_str()).or_insert(rust_name.as_str());
+        }
+        renames.retain(|name, rust_name| name != rust_name);
+        let mut mapped_types = BTreeSet::new();
+        let mut map_and_rename = |ty: &mut TypedefFieldType| {
+            ty.map_types(&mappings, &mut mapped_types);
+            ty.rename_defined(&renames);
+        };
+        for t in self.types.iter_mut().flatten() {
+            t.r#type.for_each_field_type_mut(&mut map_and_rename);
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            a.0.r#type.for_each_field_type_mut(&mut map_and_rename);
+        }
+        for arg in self
+            .instructions
+            .iter_mut()
+            .flatten()
+            .flat_map(|ix| ix.args.iter_mut().flatten())
+        {
+            map_and_rename(&mut arg.r#type);
+        }
+        for field in self
+            .events
+            .iter_mut()
+            .flatten()
+            .flat_map(|e| e.0.fields.iter_mut())
+        {
+            map_and_rename(&mut field.r#type);
+        }
+        self.mapped_types = mapped_types;
     }
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+    ) -> crate::Result<Vec<Box<dyn IdlCodegenModule + 'me>>> {
+        self.validate()?;
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.accounts {
             res.push(Box::new(AccountsCodegenModule {
@@ -77,23 +374,116 @@ impl IdlFormat for AnchorIdl {
             res.push(Box::new(IxCodegenModule {
                 program_name: self.program_name(),
                 instructions: v,
+                has_unknown_variant: self.has_filtered_instructions,
+                target: args.target,
+                data_max_lens: match args.target {
+                    Target::SolanaProgram => Vec::new(),
+                    Target::Pinocchio => v
+                        .iter()
+                        .map(|ix| ix.data_max_len(&|name| self.defined_borsh_max_len(name, 0)))
+                        .collect(),
+                },
+                compact: args.compact,
             }));
         }
         if let Some(v) = &self.errors {
             res.push(Box::new(ErrorsCodegenModule {
                 program_name: self.program_name(),
                 variants: v,
+                no_std: args.no_std,
+                target: args.target,
             }));
         }
         if let Some(v) = &self.events {
             res.push(Box::new(EventsCodegenModule(v)));
         }
-        res
+        Ok(res)
+    }
+
+    fn defined_types(&self) -> Vec<(String, String)> {
+        self.types
+            .iter()
+            .flatten()
+            .chain(self.accounts.iter().flatten().map(|a| &a.0))
+            .map(|t| (t.name.clone(), t.rust_name()))
+            .collect()
+    }
+
+    fn lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        check_keyword(&mut lints, "program", &pascal_case(&self.name));
+
+        let types = self.types.iter().flatten();
+        let accounts = self.accounts.iter().flatten().map(|a| &a.0);
+        let instructions = self.instructions.iter().flatten();
+        let errors = self.errors.iter().flatten();
+        let events = self.events.iter().flatten();
+
+        let type_names: HashSet<&str> = types
+            .clone()
+            .chain(accounts.clone())
+            .map(|t| t.name.as_str())
+            .collect();
+
+        check_duplicate_names(
+            &mut lints,
+            "typedef",
+            types.clone().map(|t| t.name.as_str()),
+        );
+        check_duplicate_names(
+            &mut lints,
+            "account",
+            accounts.clone().map(|a| a.name.as_str()),
+        );
+        check_duplicate_names(
+            &mut lints,
+            "instruction",
+            instructions.clone().map(|ix| ix.name.as_str()),
+        );
+        check_duplicate_names(&mut lints, "error", errors.clone().map(|e| e.name.as_str()));
+        check_duplicate_names(
+            &mut lints,
+            "event",
+            events.clone().map(|e| e.0.name.as_str()),
+        );
+
+        types.for_each(|t| t.lint("typedef", &type_names, &mut lints));
+        accounts.for_each(|a| a.lint("account", &type_names, &mut lints));
+        instructions
+            .clone()
+            .for_each(|ix| ix.lint(&type_names, &mut lints));
+        events
+            .clone()
+            .for_each(|e| e.0.lint(&type_names, &mut lints));
+        for e in errors {
+            e.lint(&mut lints);
+            if e.code < ANCHOR_ERROR_CODE_OFFSET {
+                lints.push(Lint::ReservedErrorCode {
+                    name: e.name.clone(),
+                    code: e.code,
+                });
+            }
+        }
+
+        let discms = instructions
+            .map(|ix| (ix.discm(), format!("instruction {}", ix.name)))
+            .chain(
+                self.accounts
+                    .iter()
+                    .flatten()
+                    .map(|a| (a.discm(), format!("account {}", a.0.name))),
+            )
+            .chain(events.map(|e| (e.0.discm(), format!("event {}", e.0.name))));
+        for (discm, items) in collisions(discms) {
+            lints.push(Lint::DiscriminatorCollision { discm, items });
+        }
+
+        lints
     }
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
         let mut map = Map::new();
-        map.insert(BORSH_CRATE.into(), DependencyValue(&args.borsh_vers).into());
+        map.insert(BORSH_CRATE.into(), borsh_dependency(args));
         if !args.zero_copy.is_empty() {
             map.insert(
                 BYTEMUCK_CRATE.into(),
@@ -104,28 +494,104 @@ impl IdlFormat for AnchorIdl {
                 .into(),
             );
         }
+        map.extend(self.solana_crates_usage().dependencies(args));
         map.insert(
-            SOLANA_PROGRAM_CRATE.into(),
-            DependencyValue(&args.solana_program_vers).into(),
+            SERDE_CRATE.into(),
+            OptionalDependencyValue(serde_dependency(args)).into(),
         );
+        if args.target == Target::Pinocchio {
+            map.insert(
+                PINOCCHIO_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.pinocchio_vers)).into(),
+            );
+        }
+        if !is_anchor_lang_solana_program(args) {
+            map.insert(
+                ANCHOR_LANG_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.anchor_lang_vers)).into(),
+            );
+        }
         map.insert(
-            SERDE_CRATE.into(),
-            OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
+            SOLORES_RUNTIME_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.solores_runtime_vers)).into(),
         );
-        if self.errors.is_some() {
+        if self.has_large_array() {
             map.insert(
-                THISERROR_CRATE.into(),
-                DependencyValue(&args.thiserror_vers).into(),
+                SERDE_WITH_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.serde_with_vers)).into(),
             );
+        }
+        if self.accounts.is_some() {
             map.insert(
-                NUM_DERIVE_CRATE.into(),
-                DependencyValue(&args.num_derive_vers).into(),
+                SOLANA_RPC_CLIENT_API_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.solana_rpc_client_api_vers)).into(),
             );
+        }
+        if self.errors.is_some() {
+            // no_std crates implement the error traits without thiserror
+            if !args.no_std {
+                map.insert(
+                    THISERROR_CRATE.into(),
+                    DependencyValue(&args.thiserror_vers).into(),
+                );
+            }
             map.insert(
-                NUM_TRAITS_CRATE.into(),
-                DependencyValue(&args.num_traits_vers).into(),
+                NUM_DERIVE_CRATE.into(),
+                DependencyValue(&args.num_derive_vers).into(),
             );
+            map.insert(NUM_TRAITS_CRATE.into(), num_traits_dependency(args));
+        }
+        for path in self.mapped_types.iter() {
+            if let Some((name, vers)) = mapped_type_dependency(args, path) {
+                map.insert(name, DependencyValue(vers).into());
+            }
         }
         map
     }
+
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        let solana_crates_usage = self.solana_crates_usage();
+        let mut cpi_dependencies = match args.target {
+            Target::SolanaProgram => Vec::new(),
+            Target::Pinocchio => vec![format!("dep:{PINOCCHIO_CRATE}")],
+        };
+        cpi_dependencies.extend(solana_crates_usage.cpi_features(args));
+        map.insert(CPI_FEATURE.into(), cpi_dependencies.into());
+        // accounts' RPC filters
+        let client_dependencies = if self.accounts.is_some() {
+            vec![format!("dep:{SOLANA_RPC_CLIENT_API_CRATE}")]
+        } else {
+            Vec::new()
+        };
+        map.insert(CLIENT_FEATURE.into(), client_dependencies.into());
+        let mut anchor_dependencies = Vec::new();
+        if !is_anchor_lang_solana_program(args) {
+            anchor_dependencies.push(format!("dep:{ANCHOR_LANG_CRATE}"));
+        }
+        if args.no_std {
+            // anchor-lang's traits use std::io
+            anchor_dependencies.push(format!("{BORSH_CRATE}/std"));
+        }
+        map.insert(ANCHOR_FEATURE.into(), anchor_dependencies.into());
+        map.insert(
+            RUNTIME_FEATURE.into(),
+            vec![format!("dep:{SOLORES_RUNTIME_CRATE}")].into(),
+        );
+        let serde_features = solana_crates_usage.serde_features(args);
+        if self.has_large_array() || !serde_features.is_empty() {
+            let mut serde_dependencies = vec![format!("dep:{SERDE_CRATE}")];
+            if self.has_large_array() {
+                serde_dependencies.push(format!("dep:{SERDE_WITH_CRATE}"));
+            }
+            serde_dependencies.extend(serde_features);
+            map.insert(SERDE_CRATE.into(), serde_dependencies.into());
+        }
+        map
+    }
+}
+
+/// Whether `--solana-program-path` is in anchor-lang, which is then always a dependency
+fn is_anchor_lang_solana_program(args: &crate::Args) -> bool {
+    solana_program_dependency(args).is_some_and(|(name, _)| name == ANCHOR_LANG_CRATE)
 }
@@ -1,8 +1,11 @@
-use heck::ToPascalCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    naming::pascal_case,
+    target::Target,
+    {idl_format::IdlCodegenModule, Result},
+};
 
 mod error;
 pub use error::*;
@@ -10,6 +13,10 @@ pub use error::*;
 pub struct ErrorsCodegenModule<'a> {
     pub program_name: &'a str,
     pub variants: &'a [ErrorEnumVariant],
+    /// Implements `Display` and `Error` by hand instead of deriving `thiserror::Error`
+    pub no_std: bool,
+    /// Also converts into pinocchio's `ProgramError` for [`Target::Pinocchio`]
+    pub target: Target,
 }
 
 impl IdlCodegenModule for ErrorsCodegenModule<'_> {
@@ -17,31 +24,77 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
         "errors"
     }
 
-    fn gen_head(&self) -> TokenStream {
-        quote! {
+    fn gen_head(&self) -> Result<TokenStream> {
+        let mut res = quote! {
             use solana_program::{
                 decode_error::DecodeError,
                 msg,
                 program_error::{PrintProgramError, ProgramError},
             };
-            use thiserror::Error;
+        };
+        if !self.no_std {
+            res.extend(quote! {
+                use thiserror::Error;
+            });
         }
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
+    fn gen_body(&self) -> Result<TokenStream> {
         let error_enum_variants: TokenStream = self
             .variants
             .iter()
             .map(|e| e.into_token_stream())
             .collect();
 
-        let error_enum_ident_str = format!("{}Error", self.program_name.to_pascal_case());
+        let error_enum_ident_str = format!("{}Error", pascal_case(self.program_name));
         let error_enum_ident = format_ident!("{}", &error_enum_ident_str);
-        quote! {
-            #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
-            pub enum #error_enum_ident {
-                #error_enum_variants
-            }
+        let (error_enum, error_trait) = if self.no_std {
+            let error_enum_variants = self.variants.iter().map(ErrorEnumVariant::no_std_variant);
+            let display_arms = self.variants.iter().map(ErrorEnumVariant::display_arm);
+            (
+                quote! {
+                    #[derive(Clone, Copy, Debug, Eq, num_derive::FromPrimitive, PartialEq)]
+                    pub enum #error_enum_ident {
+                        #(#error_enum_variants)*
+                    }
+
+                    impl core::fmt::Display for #error_enum_ident {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                            f.write_str(match self {
+                                #(#display_arms)*
+                            })
+                        }
+                    }
+
+                    impl core::error::Error for #error_enum_ident {}
+                },
+                quote! { core::error::Error },
+            )
+        } else {
+            (
+                quote! {
+                    #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+                    pub enum #error_enum_ident {
+                        #error_enum_variants
+                    }
+                },
+                quote! { std::error::Error },
+            )
+        };
+        let pinocchio_from = match self.target {
+            Target::SolanaProgram => quote! {},
+            Target::Pinocchio => quote! {
+                #[cfg(feature = "cpi")]
+                impl From<#error_enum_ident> for pinocchio::program_error::ProgramError {
+                    fn from(e: #error_enum_ident) -> Self {
+                        Self::Custom(e as u32)
+                    }
+                }
+            },
+        };
+        Ok(quote! {
+            #error_enum
 
             impl From<#error_enum_ident> for ProgramError {
                 fn from(e: #error_enum_ident) -> Self {
@@ -49,6 +102,19 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
                 }
             }
 
+            #pinocchio_from
+
+            #[cfg(feature = "runtime")]
+            impl solores_runtime::ProgramErrorCode for #error_enum_ident {
+                fn code(&self) -> u32 {
+                    *self as u32
+                }
+
+                fn from_code(code: u32) -> Option<Self> {
+                    num_traits::FromPrimitive::from_u32(code)
+                }
+            }
+
             impl<T> DecodeError<T> for #error_enum_ident {
                 fn type_of() -> &'static str {
                     #error_enum_ident_str
@@ -59,7 +125,7 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
                 fn print<E>(&self)
                 where
                     E: 'static
-                        + std::error::Error
+                        + #error_trait
                         + DecodeError<E>
                         + PrintProgramError
                         + num_traits::FromPrimitive,
@@ -67,6 +133,6 @@ impl IdlCodegenModule for ErrorsCodegenModule<'_> {
                     msg!(&self.to_string());
                 }
             }
-        }
+        })
     }
 }
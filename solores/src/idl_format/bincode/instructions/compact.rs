@@ -0,0 +1,264 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::{
+    compact::{
+        ix_accounts_invocations, ix_accounts_macro, ix_fn_idents, ix_privileges_macro, MacroAccount,
+    },
+    target::Target,
+    utils::unique_by_report_dups,
+};
+
+use super::{NamedInstruction, NamedInstructionFull};
+
+const IX_FNS_SOLANA_PROGRAM: &str = r#"/// Defines an instruction's `*_ix()`, `*_invoke()` and `*_invoke_signed()`,
+/// and their `*_with_program_id()` variants. The data is the instruction's `PROGRAM_IX` variant.
+macro_rules! ix_fns {
+    (
+        $variant:ident $(($ix_args:ident))?,
+        [
+            $ix:ident,
+            $ix_with_program_id:ident,
+            $invoke:ident,
+            $invoke_with_program_id:ident,
+            $invoke_signed:ident,
+            $invoke_signed_with_program_id:ident $(,)?
+        ]
+        $(, $accounts:ident, $keys:ident, $len:ident)?
+    ) => {
+        ix_fns!(
+            @impl $variant,
+            [$ix, $ix_with_program_id, $invoke, $invoke_with_program_id, $invoke_signed, $invoke_signed_with_program_id],
+            [$(keys $keys accounts $accounts $len)?],
+            [$(args $ix_args)?]
+        );
+    };
+    (
+        @impl $variant:ident,
+        [$ix:ident, $ix_with_program_id:ident, $invoke:ident, $invoke_with_program_id:ident, $invoke_signed:ident, $invoke_signed_with_program_id:ident],
+        [$($keys_var:ident $keys:ident $accounts_var:ident $accounts:ident $len:ident)?],
+        [$($args_var:ident $ix_args:ident)?]
+    ) => {
+        pub fn $ix_with_program_id(
+            program_id: Pubkey,
+            $($keys_var: $keys,)?
+            $($args_var: $ix_args,)?
+        ) -> Instruction {
+            Instruction::new_with_bincode(
+                program_id,
+                &PROGRAM_IX::$variant$(($args_var))?,
+                ix_fns!(@or (Vec::new()) $((Vec::from(<[AccountMeta; $len]>::from($keys_var))))?),
+            )
+        }
+        pub fn $ix($($keys_var: $keys,)? $($args_var: $ix_args,)?) -> Instruction {
+            $ix_with_program_id(crate::ID, $($keys_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?);
+            ix_fns!(@or (invoke(&ix, &[])) $((invoke_instruction(&ix, $accounts_var)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke($($accounts_var: $accounts<'_, '_>,)? $($args_var: $ix_args,)?) -> ProgramResult {
+            $invoke_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?);
+            ix_fns!(@or (invoke_signed(&ix, &[], seeds)) $((invoke_instruction_signed(&ix, $accounts_var, seeds)))?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed(
+            $($accounts_var: $accounts<'_, '_>,)?
+            $($args_var: $ix_args,)?
+            seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)? seeds)
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+"#;
+
+const IX_FNS_PINOCCHIO: &str = r#"/// Defines an instruction's `*_ix()`, `*_invoke()` and `*_invoke_signed()`,
+/// and their `*_with_program_id()` variants. The data is the instruction's `PROGRAM_IX` variant,
+/// and pinocchio CPIs take it from the `*_ix_with_program_id()` instruction.
+macro_rules! ix_fns {
+    (
+        $variant:ident $(($ix_args:ident))?,
+        [
+            $ix:ident,
+            $ix_with_program_id:ident,
+            $invoke:ident,
+            $invoke_with_program_id:ident,
+            $invoke_signed:ident,
+            $invoke_signed_with_program_id:ident $(,)?
+        ]
+        $(, $accounts:ident, $keys:ident, $len:ident)?
+    ) => {
+        ix_fns!(
+            @impl $variant,
+            [$ix, $ix_with_program_id, $invoke, $invoke_with_program_id, $invoke_signed, $invoke_signed_with_program_id],
+            [$(keys $keys accounts $accounts $len)?],
+            [$(args $ix_args)?]
+        );
+    };
+    (
+        @impl $variant:ident,
+        [$ix:ident, $ix_with_program_id:ident, $invoke:ident, $invoke_with_program_id:ident, $invoke_signed:ident, $invoke_signed_with_program_id:ident],
+        [$($keys_var:ident $keys:ident $accounts_var:ident $accounts:ident $len:ident)?],
+        [$($args_var:ident $ix_args:ident)?]
+    ) => {
+        pub fn $ix_with_program_id(
+            program_id: Pubkey,
+            $($keys_var: $keys,)?
+            $($args_var: $ix_args,)?
+        ) -> Instruction {
+            Instruction::new_with_bincode(
+                program_id,
+                &PROGRAM_IX::$variant$(($args_var))?,
+                ix_fns!(@or (Vec::new()) $((Vec::from(<[AccountMeta; $len]>::from($keys_var))))?),
+            )
+        }
+        pub fn $ix($($keys_var: $keys,)? $($args_var: $ix_args,)?) -> Instruction {
+            $ix_with_program_id(crate::ID, $($keys_var,)? $($args_var,)?)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+            signers: &[Signer],
+        ) -> ProgramResult {
+            let ix = $ix_with_program_id(program_id, $($keys::from($accounts_var),)? $($args_var,)?);
+            ix_fns!(
+                @or (invoke_signed(
+                    &CpiInstruction {
+                        program_id: &program_id.to_bytes(),
+                        accounts: &[],
+                        data: &ix.data,
+                    },
+                    &[],
+                    signers,
+                ))
+                $((invoke_instruction_signed(&program_id, $accounts_var, &ix.data, signers)))?
+            )
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_signed(
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+            signers: &[Signer],
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)? signers)
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke_with_program_id(
+            program_id: Pubkey,
+            $($accounts_var: $accounts<'_>,)?
+            $($args_var: $ix_args,)?
+        ) -> ProgramResult {
+            $invoke_signed_with_program_id(program_id, $($accounts_var,)? $($args_var,)? &[])
+        }
+        #[cfg(feature = "cpi")]
+        pub fn $invoke($($accounts_var: $accounts<'_>,)? $($args_var: $ix_args,)?) -> ProgramResult {
+            $invoke_with_program_id(crate::ID, $($accounts_var,)? $($args_var,)?)
+        }
+    };
+    (@or ($($default:tt)*)) => { $($default)* };
+    (@or ($($default:tt)*) ($($value:tt)*)) => { $($value)* };
+}
+"#;
+
+/// The `macro_rules!` that [`NamedInstructionFull::write_compact_items`] invokes
+pub fn compact_macros(
+    instructions: &[NamedInstruction],
+    program_ix_enum_ident: &Ident,
+    target: Target,
+) -> String {
+    let compacted: Vec<_> = instructions
+        .iter()
+        .filter(|ix| !ix.has_duplicate_accounts())
+        .collect();
+    let mut res = String::new();
+    if compacted.is_empty() {
+        return res;
+    }
+    if compacted.iter().any(|ix| ix.has_accounts()) {
+        res.push_str(&ix_accounts_macro(target, "#[derive(Copy, Clone, Debug)]"));
+    }
+    if compacted.iter().any(|ix| ix.has_privileged_accounts()) {
+        res.push_str(ix_privileges_macro(target));
+    }
+    let ix_fns = match target {
+        Target::SolanaProgram => IX_FNS_SOLANA_PROGRAM,
+        Target::Pinocchio => IX_FNS_PINOCCHIO,
+    };
+    res.push_str(&ix_fns.replace("PROGRAM_IX", &program_ix_enum_ident.to_string()));
+    res
+}
+
+impl NamedInstruction {
+    /// Accounts with the same name are collapsed into one field, which the macros can't do,
+    /// so such instructions' items are expanded even with `--compact`
+    pub fn has_duplicate_accounts(&self) -> bool {
+        let accounts = self.ix_accounts();
+        !unique_by_report_dups(accounts.iter(), |acc| acc.name.clone())
+            .duplicates
+            .is_empty()
+    }
+}
+
+impl NamedInstructionFull<'_> {
+    /// [`Self::write_items`] for `--compact`: the discriminant, the `*IxArgs` struct
+    /// and its runtime impl, and invocations of the [`compact_macros`] for the rest of the items.
+    pub fn write_compact_items(&self, tokens: &mut TokenStream, target: Target) {
+        if self.ix.has_duplicate_accounts() {
+            self.write_items(tokens, target);
+            return;
+        }
+        self.write_discm(tokens);
+        let rust_name = self.ix.rust_name();
+        if self.ix.has_accounts() {
+            let accounts = self.ix.ix_accounts();
+            let accounts: Vec<_> = accounts
+                .iter()
+                .map(|acc| MacroAccount {
+                    name: &acc.name,
+                    desc: acc.desc.as_deref(),
+                    is_signer: acc.is_signer,
+                    is_writable: acc.is_mut,
+                })
+                .collect();
+            tokens.extend(ix_accounts_invocations(rust_name, &accounts));
+        }
+        self.ix.write_ix_args_struct(tokens);
+
+        let variant_ident = self.ix.enum_variant_ident();
+        let ix_args = self.ix.has_ix_args().then(|| {
+            let ix_args_ident = self.ix.ix_args_ident();
+            quote! { (#ix_args_ident) }
+        });
+        let fn_idents = ix_fn_idents(rust_name);
+        let accounts = self.ix.has_accounts().then(|| {
+            let accounts_ident = self.ix.accounts_ident();
+            let keys_ident = self.ix.keys_ident();
+            let accounts_len_ident = self.ix.accounts_len_ident();
+            quote! { , #accounts_ident, #keys_ident, #accounts_len_ident }
+        });
+        tokens.extend(quote! {
+            ix_fns!(#variant_ident #ix_args, #fn_idents #accounts);
+        });
+        self.write_runtime_ix_impl(tokens);
+    }
+}
@@ -1,17 +1,31 @@
-use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+use std::collections::HashSet;
+
 use itertools::Itertools;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
 use syn::{LitBool, LitInt};
 
-use crate::{idl_format::bincode::typedefs::TypedefField, utils::unique_by_report_dups};
+use crate::{
+    config::ItemConfig,
+    idl_format::bincode::typedefs::TypedefField,
+    lint::{check_keyword, Lint},
+    naming::{pascal_case, pascal_ident, shouty_snake_case, snake_case, snake_ident},
+    target::Target,
+    utils::{check_derive, check_ident, unique_by_report_dups},
+};
 
 #[derive(Deserialize)]
 pub struct NamedInstruction {
     pub name: String,
     pub accounts: Option<Vec<IxAccount>>,
     pub args: Option<Vec<TypedefField>>,
+    #[serde(skip)]
+    pub config: ItemConfig,
+    /// Index in the IDL's instructions, which is the discriminant.
+    /// Set by `apply_filters()` since it differs from the index in the filtered instructions.
+    #[serde(skip)]
+    pub index: Option<usize>,
 }
 
 pub struct NamedInstructionFull<'a> {
@@ -21,8 +35,12 @@ pub struct NamedInstructionFull<'a> {
 }
 
 impl<'a> NamedInstructionFull<'a> {
+    fn discm_ident(&self) -> Ident {
+        format_ident!("{}_IX_DISCM", shouty_snake_case(self.ix.rust_name()))
+    }
+
     pub fn write_discm(&self, tokens: &mut TokenStream) {
-        let discm_ident = format_ident!("{}_IX_DISCM", &self.ix.name.to_shouty_snake_case());
+        let discm_ident = self.discm_ident();
         let discm_value: u32 = self.index.try_into().unwrap();
         let discm_arr = discm_value.to_le_bytes();
         let discm_tokens: TokenStream = format!("{:?}", discm_arr).parse().unwrap();
@@ -32,6 +50,35 @@ impl<'a> NamedInstructionFull<'a> {
         });
     }
 
+    /// solores-runtime `Instruction` impl behind the `runtime` feature.
+    /// Implemented by the `*IxArgs` struct since there is no ix data struct,
+    /// so instructions without args do not implement it.
+    pub fn write_runtime_ix_impl(&self, tokens: &mut TokenStream) {
+        if !self.ix.has_ix_args() {
+            return;
+        }
+        let name = &self.ix.name;
+        let discm_ident = self.discm_ident();
+        let ix_args_ident = self.ix.ix_args_ident();
+        let (accounts_len, keys) = if self.ix.has_accounts() {
+            let accounts_len_ident = self.ix.accounts_len_ident();
+            let keys_ident = self.ix.keys_ident();
+            (quote! { #accounts_len_ident }, quote! { #keys_ident })
+        } else {
+            (quote! { 0 }, quote! { () })
+        };
+        tokens.extend(quote! {
+            #[cfg(feature = "runtime")]
+            impl solores_runtime::Instruction for #ix_args_ident {
+                const NAME: &'static str = #name;
+                const DISCM: &'static [u8] = &#discm_ident;
+                const ACCOUNTS_LEN: usize = #accounts_len;
+                type Keys = #keys;
+                type Args = Self;
+            }
+        });
+    }
+
     /// _ix()
     /// _ix_with_program_id()
     pub fn write_ix_fn(&self, tokens: &mut TokenStream) {
@@ -95,32 +142,123 @@ impl<'a> NamedInstructionFull<'a> {
 }
 
 impl NamedInstruction {
+    /// The name used for generated items, which may differ from the IDL name.
+    /// Discriminants are always derived from the IDL name.
+    pub fn rust_name(&self) -> &str {
+        self.config.rename.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Checks that the instruction's items can be generated
+    pub fn validate(&self) -> crate::Result<()> {
+        let item = format!("instruction {}", self.name);
+        check_ident(&item, &pascal_case(self.rust_name()))?;
+        check_ident(&item, &snake_case(self.rust_name()))?;
+        for derive in self.config.derives.iter() {
+            check_derive(&item, derive)?;
+        }
+        for arg in self.args.iter().flatten() {
+            arg.validate(&item)?;
+        }
+
+        for acc in self.ix_accounts().iter() {
+            check_ident(&format!("{item} account"), &snake_case(&acc.name))?;
+        }
+        Ok(())
+    }
+
+    /// The instruction's accounts, renamed by the config's `accounts`.
+    /// Accounts with the same name are the same account so they are not disambiguated.
+    pub fn ix_accounts(&self) -> Vec<IxAccount> {
+        let mut accounts = self.accounts.clone().unwrap_or_default();
+        for acc in accounts.iter_mut() {
+            if let Some(rename) = self.config.accounts.get(&acc.name) {
+                acc.name = rename.clone();
+            }
+        }
+        accounts
+    }
+
+    /// The crate-level names of the items generated for the instruction if named `rust_name`
+    pub fn item_names(rust_name: &str) -> Vec<String> {
+        let pascal = pascal_case(rust_name);
+        let shouty = shouty_snake_case(rust_name);
+        let snake = snake_case(rust_name);
+        ["Accounts", "Keys", "IxArgs"]
+            .iter()
+            .map(|suffix| format!("{pascal}{suffix}"))
+            .chain(
+                ["_IX_DISCM", "_IX_ACCOUNTS_LEN"]
+                    .iter()
+                    .map(|suffix| format!("{shouty}{suffix}")),
+            )
+            .chain(
+                [
+                    "_ix",
+                    "_ix_with_program_id",
+                    "_invoke",
+                    "_invoke_with_program_id",
+                    "_invoke_signed",
+                    "_invoke_signed_with_program_id",
+                    "_verify_account_keys",
+                    "_verify_account_privileges",
+                    "_verify_writable_privileges",
+                    "_verify_signer_privileges",
+                ]
+                .iter()
+                .map(|suffix| format!("{snake}{suffix}")),
+            )
+            .collect()
+    }
+
+    /// Adds the instruction's lints
+    pub fn lint(&self, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("instruction {}", self.name);
+        check_keyword(lints, &item, &pascal_case(self.rust_name()));
+        for arg in self.args.iter().flatten() {
+            arg.lint(&item, type_names, lints);
+        }
+        for acc in self.accounts.iter().flatten() {
+            check_keyword(
+                lints,
+                &format!("{item} account {}", acc.name),
+                &snake_case(&acc.name),
+            );
+        }
+    }
+
+    pub fn apply_config(&mut self, config: &ItemConfig) {
+        self.config = config.clone();
+        for arg in self.args.iter_mut().flatten() {
+            arg.override_type(&config.types);
+        }
+    }
+
     pub fn enum_variant_ident(&self) -> Ident {
-        format_ident!("{}", self.name.to_pascal_case())
+        pascal_ident(self.rust_name())
     }
 
     pub fn ix_args_ident(&self) -> Ident {
-        format_ident!("{}IxArgs", self.name.to_pascal_case())
+        format_ident!("{}IxArgs", pascal_case(self.rust_name()))
     }
 
     pub fn ix_fn_ident(&self) -> Ident {
-        format_ident!("{}_ix", self.name.to_snake_case())
+        format_ident!("{}_ix", snake_case(self.rust_name()))
     }
 
     pub fn ix_fn_with_program_id_ident(&self) -> Ident {
-        format_ident!("{}_ix_with_program_id", self.name.to_snake_case())
+        format_ident!("{}_ix_with_program_id", snake_case(self.rust_name()))
     }
 
     pub fn accounts_ident(&self) -> Ident {
-        format_ident!("{}Accounts", self.name.to_pascal_case())
+        format_ident!("{}Accounts", pascal_case(self.rust_name()))
     }
 
     pub fn keys_ident(&self) -> Ident {
-        format_ident!("{}Keys", self.name.to_pascal_case())
+        format_ident!("{}Keys", pascal_case(self.rust_name()))
     }
 
     pub fn accounts_len_ident(&self) -> Ident {
-        format_ident!("{}_IX_ACCOUNTS_LEN", self.name.to_shouty_snake_case())
+        format_ident!("{}_IX_ACCOUNTS_LEN", shouty_snake_case(self.rust_name()))
     }
 
     pub fn has_ix_args(&self) -> bool {
@@ -145,7 +283,16 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_defined()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_defined())
+    }
+
+    pub fn args_has_large_array(&self) -> bool {
+        let args = if !self.has_ix_args() {
+            return false;
+        } else {
+            self.args.as_ref().unwrap()
+        };
+        args.iter().any(|a| a.r#type.is_or_has_large_array())
     }
 
     pub fn args_has_pubkeys(&self) -> bool {
@@ -154,7 +301,7 @@ impl NamedInstruction {
         } else {
             self.args.as_ref().unwrap()
         };
-        args.iter().map(|a| a.r#type.is_or_has_pubkey()).any(|b| b)
+        args.iter().any(|a| a.r#type.is_or_has_pubkey())
     }
 
     pub fn has_privileged_accounts(&self) -> bool {
@@ -163,7 +310,7 @@ impl NamedInstruction {
         } else {
             self.accounts.as_ref().unwrap()
         };
-        accounts.iter().map(|a| a.is_privileged()).any(|b| b)
+        accounts.iter().any(|a| a.is_privileged())
     }
 
     /// export accounts_len as const
@@ -178,13 +325,19 @@ impl NamedInstruction {
         });
     }
 
-    pub fn write_accounts_struct(&self, tokens: &mut TokenStream, unique_accounts: &[&IxAccount]) {
+    pub fn write_accounts_struct(
+        &self,
+        tokens: &mut TokenStream,
+        unique_accounts: &[&IxAccount],
+        target: Target,
+    ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
+        let account_info = target.account_info();
         let accounts_fields = unique_accounts.iter().map(|acc| {
-            let account_name = format_ident!("{}", &acc.name.to_snake_case());
+            let account_name = snake_ident(&acc.name);
             let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
                 quote! {
                     #[doc = #desc]
@@ -192,12 +345,15 @@ impl NamedInstruction {
             });
             quote! {
                 #maybe_doc_comment
-                pub #account_name: &'me AccountInfo<'info>
+                pub #account_name: &'me #account_info
             }
         });
+        let derives = target.accounts_derives();
+        let lifetimes = target.accounts_lifetimes();
         tokens.extend(quote! {
-            #[derive(Copy, Clone, Debug)]
-            pub struct #accounts_ident<'me, 'info> {
+            #derives
+            #[cfg(feature = "cpi")]
+            pub struct #accounts_ident #lifetimes {
                 #(#accounts_fields),*
             }
         });
@@ -209,7 +365,7 @@ impl NamedInstruction {
         }
         let keys_ident = self.keys_ident();
         let keys_fields = unique_accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             let maybe_doc_comment = acc.desc.as_ref().map_or(quote! {}, |desc| {
                 quote! {
                     #[doc = #desc]
@@ -233,6 +389,7 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         unique_accounts: &[&IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
@@ -240,13 +397,16 @@ impl NamedInstruction {
         let accounts_ident = self.accounts_ident();
         let keys_ident = self.keys_ident();
         let from_keys_fields = unique_accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
+            let key = target.account_key(quote! { accounts.#account_ident });
             quote! {
-                #account_ident: *accounts.#account_ident.key
+                #account_ident: #key
             }
         });
+        let elided_lifetimes = target.accounts_elided_lifetimes();
         tokens.extend(quote! {
-            impl From<#accounts_ident<'_, '_>> for #keys_ident {
+            #[cfg(feature = "cpi")]
+            impl From<#accounts_ident #elided_lifetimes> for #keys_ident {
                 fn from(accounts: #accounts_ident) -> Self {
                     Self {
                         #(#from_keys_fields),*
@@ -287,7 +447,7 @@ impl NamedInstruction {
         let accounts_len_ident = self.accounts_len_ident();
         let keys_ident = self.keys_ident();
         let from_pubkey_arr_fields = unique_accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
             quote! {
                 #account_ident: pubkeys[#index_lit]
@@ -309,23 +469,71 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
         }
         let accounts_ident = self.accounts_ident();
         let accounts_len_ident = self.accounts_len_ident();
-        let account_info_clone = accounts.iter().map(|acc| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
-            quote! {
-               accounts.#account_ident.clone()
+        match target {
+            Target::SolanaProgram => {
+                let account_info_clone = accounts.iter().map(|acc| {
+                    let account_ident = snake_ident(&acc.name);
+                    quote! {
+                       accounts.#account_ident.clone()
+                    }
+                });
+                tokens.extend(quote! {
+                    #[cfg(feature = "cpi")]
+                    impl<'info> From<#accounts_ident<'_, 'info>> for [AccountInfo<'info>; #accounts_len_ident] {
+                        fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
+                            [
+                                #(#account_info_clone),*
+                            ]
+                        }
+                    }
+                });
             }
-        });
+            Target::Pinocchio => {
+                let account_infos = accounts.iter().map(|acc| {
+                    let account_ident = snake_ident(&acc.name);
+                    quote! {
+                       accounts.#account_ident
+                    }
+                });
+                tokens.extend(quote! {
+                    #[cfg(feature = "cpi")]
+                    impl<'me> From<#accounts_ident<'me>> for [&'me AccountInfo; #accounts_len_ident] {
+                        fn from(accounts: #accounts_ident<'me>) -> Self {
+                            [
+                                #(#account_infos),*
+                            ]
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    /// From <XAccounts> for [pinocchio::instruction::AccountMeta]
+    pub fn write_from_accounts_for_cpi_meta_arr(
+        &self,
+        tokens: &mut TokenStream,
+        accounts: &[IxAccount],
+    ) {
+        if !self.has_accounts() {
+            return;
+        }
+        let accounts_ident = self.accounts_ident();
+        let accounts_len_ident = self.accounts_len_ident();
+        let metas = accounts.iter().map(IxAccount::to_cpi_account_meta_tokens);
         tokens.extend(quote! {
-            impl<'info> From<#accounts_ident<'_, 'info>> for [AccountInfo<'info>; #accounts_len_ident] {
-                fn from(accounts: #accounts_ident<'_, 'info>) -> Self {
+            #[cfg(feature = "cpi")]
+            impl<'me> From<#accounts_ident<'me>> for [CpiAccountMeta<'me>; #accounts_len_ident] {
+                fn from(accounts: #accounts_ident<'me>) -> Self {
                     [
-                        #(#account_info_clone),*
+                        #(#metas),*
                     ]
                 }
             }
@@ -337,6 +545,7 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         accounts: &[IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
@@ -344,15 +553,18 @@ impl NamedInstruction {
         let accounts_len_ident = self.accounts_len_ident();
         let accounts_ident = self.accounts_ident();
         let from_account_info_fields = accounts.iter().enumerate().map(|(i, acc)| {
-            let account_ident = format_ident!("{}", &acc.name.to_snake_case());
+            let account_ident = snake_ident(&acc.name);
             let index_lit = LitInt::new(&i.to_string(), Span::call_site());
             quote! {
                #account_ident: &arr[#index_lit]
             }
         });
+        let lifetimes = target.accounts_lifetimes();
+        let account_info = target.account_info();
         tokens.extend(quote! {
-            impl<'me, 'info> From<&'me [AccountInfo<'info>; #accounts_len_ident]> for #accounts_ident<'me, 'info> {
-                fn from(arr: &'me [AccountInfo<'info>; #accounts_len_ident]) -> Self {
+            #[cfg(feature = "cpi")]
+            impl #lifetimes From<&'me [#account_info; #accounts_len_ident]> for #accounts_ident #lifetimes {
+                fn from(arr: &'me [#account_info; #accounts_len_ident]) -> Self {
                     Self {
                         #(#from_account_info_fields),*
                     }
@@ -368,9 +580,25 @@ impl NamedInstruction {
             self.args.as_ref().unwrap()
         };
         let ix_args_ident = self.ix_args_ident();
-        let args_fields = args.iter().map(|a| quote! { pub #a });
+        let extra_derives = if self.config.derives.is_empty() {
+            quote! {}
+        } else {
+            let derives = self
+                .config
+                .derives
+                .iter()
+                .map(|d| d.parse::<TokenStream>().unwrap());
+            quote! {
+                #[derive(#(#derives),*)]
+            }
+        };
+        let args_fields = args.iter().map(|a| {
+            let serde_with_attr = a.r#type.serde_with_attr();
+            quote! { #serde_with_attr pub #a }
+        });
         tokens.extend(quote! {
             #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+            #extra_derives
             pub struct #ix_args_ident {
                 #(#args_fields),*
             }
@@ -378,12 +606,13 @@ impl NamedInstruction {
     }
 
     /// accounts: XAccounts, args: XIxArgs
-    fn invoke_fn_params_prefix(&self) -> TokenStream {
+    fn invoke_fn_params_prefix(&self, target: Target) -> TokenStream {
         let accounts_ident = self.accounts_ident();
         let ix_args_ident = self.ix_args_ident();
+        let elided_lifetimes = target.accounts_elided_lifetimes();
         let mut fn_params = quote! {};
         if self.has_accounts() {
-            fn_params.extend(quote! { accounts: #accounts_ident<'_, '_>, });
+            fn_params.extend(quote! { accounts: #accounts_ident #elided_lifetimes, });
         }
         if self.has_ix_args() {
             fn_params.extend(quote! { args: #ix_args_ident, })
@@ -428,10 +657,10 @@ impl NamedInstruction {
     /// _invoke()
     /// _invoke_with_program_id()
     pub fn write_invoke_fn(&self, tokens: &mut TokenStream) {
-        let invoke_fn_ident = format_ident!("{}_invoke", self.name.to_snake_case());
+        let invoke_fn_ident = format_ident!("{}_invoke", snake_case(self.rust_name()));
         let invoke_with_program_id_fn_ident =
-            format_ident!("{}_invoke_with_program_id", self.name.to_snake_case());
-        let fn_params = self.invoke_fn_params_prefix();
+            format_ident!("{}_invoke_with_program_id", snake_case(self.rust_name()));
+        let fn_params = self.invoke_fn_params_prefix(Target::SolanaProgram);
         let fn_args = self.invoke_fn_args_prefix();
         let call_assign = self.ix_call_assign();
         let invoke = if self.has_accounts() {
@@ -444,11 +673,13 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
+            #[cfg(feature = "cpi")]
             pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
                 #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
             }
@@ -458,12 +689,13 @@ impl NamedInstruction {
     /// _invoke_signed()
     /// _invoke_signed_with_program_id()
     pub fn write_invoke_signed_fn(&self, tokens: &mut TokenStream) {
-        let invoke_signed_fn_ident = format_ident!("{}_invoke_signed", self.name.to_snake_case());
+        let invoke_signed_fn_ident =
+            format_ident!("{}_invoke_signed", snake_case(self.rust_name()));
         let invoke_signed_with_program_id_fn_ident = format_ident!(
             "{}_invoke_signed_with_program_id",
-            self.name.to_snake_case()
+            snake_case(self.rust_name())
         );
-        let mut fn_params = self.invoke_fn_params_prefix();
+        let mut fn_params = self.invoke_fn_params_prefix(Target::SolanaProgram);
         fn_params.extend(quote! { seeds: &[&[&[u8]]], });
         let mut fn_args = self.invoke_fn_args_prefix();
         fn_args.extend(quote! { seeds, });
@@ -478,33 +710,99 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #invoke_signed_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
                 #call_assign
                 #invoke
             }
 
+            #[cfg(feature = "cpi")]
             pub fn #invoke_signed_fn_ident(#fn_params) -> ProgramResult {
                 #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args)
             }
         });
     }
 
+    /// pinocchio _invoke()
+    /// _invoke_with_program_id()
+    /// _invoke_signed()
+    /// _invoke_signed_with_program_id()
+    ///
+    /// The data is that of the `*_ix_with_program_id()` instruction,
+    /// since bincode data can't be serialized without allocating.
+    pub fn write_pinocchio_invoke_fns(&self, tokens: &mut TokenStream) {
+        let snake = snake_case(self.rust_name());
+        let invoke_fn_ident = format_ident!("{snake}_invoke");
+        let invoke_with_program_id_fn_ident = format_ident!("{snake}_invoke_with_program_id");
+        let invoke_signed_fn_ident = format_ident!("{snake}_invoke_signed");
+        let invoke_signed_with_program_id_fn_ident =
+            format_ident!("{snake}_invoke_signed_with_program_id");
+        let fn_params = self.invoke_fn_params_prefix(Target::Pinocchio);
+        let fn_args = self.invoke_fn_args_prefix();
+        let call_assign = self.ix_call_assign();
+        let invoke = if self.has_accounts() {
+            quote! {
+                invoke_instruction_signed(&program_id, accounts, &ix.data, signers)
+            }
+        } else {
+            quote! {
+                invoke_signed(
+                    &CpiInstruction {
+                        program_id: &program_id.to_bytes(),
+                        accounts: &[],
+                        data: &ix.data,
+                    },
+                    &[],
+                    signers,
+                )
+            }
+        };
+        tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_signed_with_program_id_fn_ident(
+                program_id: Pubkey,
+                #fn_params
+                signers: &[Signer],
+            ) -> ProgramResult {
+                #call_assign
+                #invoke
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_signed_fn_ident(#fn_params signers: &[Signer]) -> ProgramResult {
+                #invoke_signed_with_program_id_fn_ident(crate::ID, #fn_args signers)
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_with_program_id_fn_ident(program_id: Pubkey, #fn_params) -> ProgramResult {
+                #invoke_signed_with_program_id_fn_ident(program_id, #fn_args &[])
+            }
+
+            #[cfg(feature = "cpi")]
+            pub fn #invoke_fn_ident(#fn_params) -> ProgramResult {
+                #invoke_with_program_id_fn_ident(crate::ID, #fn_args)
+            }
+        });
+    }
+
     /// _verify_account_keys()
     pub fn write_verify_account_keys_fn(
         &self,
         tokens: &mut TokenStream,
         unique_accounts: &[&IxAccount],
+        target: Target,
     ) {
         if !self.has_accounts() {
             return;
         }
         let verify_account_keys_fn_ident =
-            format_ident!("{}_verify_account_keys", self.name.to_snake_case());
+            format_ident!("{}_verify_account_keys", snake_case(self.rust_name()));
         let accounts_ident = self.accounts_ident();
         let keys_ident = self.keys_ident();
+        let elided_lifetimes = target.accounts_elided_lifetimes();
         let key_tups = unique_accounts
             .iter()
-            .map(|a| IxAccount::to_verify_account_keys_tuple(a));
+            .map(|a| a.to_verify_account_keys_tuple(target));
         // edge-case of accounts and keys being empty
         let pubkeys_loop_check = if unique_accounts.is_empty() {
             quote! {}
@@ -520,8 +818,9 @@ impl NamedInstruction {
             }
         };
         tokens.extend(quote! {
+            #[cfg(feature = "cpi")]
             pub fn #verify_account_keys_fn_ident(
-                accounts: #accounts_ident<'_, '_>,
+                accounts: #accounts_ident #elided_lifetimes,
                 keys: #keys_ident
             ) -> Result<(), (Pubkey, Pubkey)> {
                 #pubkeys_loop_check
@@ -537,17 +836,22 @@ impl NamedInstruction {
         &self,
         tokens: &mut TokenStream,
         unique_accounts: &[&IxAccount],
+        target: Target,
     ) {
         if !self.has_privileged_accounts() {
             return;
         }
         let verify_account_privileges_fn_ident =
-            format_ident!("{}_verify_account_privileges", self.name.to_snake_case());
-        let verify_writable_privileges_fn_ident =
-            format_ident!("{}_verify_writable_privileges", self.name.to_snake_case());
+            format_ident!("{}_verify_account_privileges", snake_case(self.rust_name()));
+        let verify_writable_privileges_fn_ident = format_ident!(
+            "{}_verify_writable_privileges",
+            snake_case(self.rust_name())
+        );
         let verify_signer_privileges_fn_ident =
-            format_ident!("{}_verify_signer_privileges", self.name.to_snake_case());
+            format_ident!("{}_verify_signer_privileges", snake_case(self.rust_name()));
         let accounts_ident = self.accounts_ident();
+        let lifetimes = target.accounts_lifetimes();
+        let account_info = target.account_info();
 
         let mut verify_fn_body = quote! {};
 
@@ -566,14 +870,16 @@ impl NamedInstruction {
             .peekable();
         let has_writables = writables.peek().is_some();
         if has_writables {
+            let should_be_writable_is_writable = target.is_writable(quote! { should_be_writable });
             tokens.extend(quote! {
-                pub fn #verify_writable_privileges_fn_ident<'me, 'info>(
-                    accounts: #accounts_ident<'me, 'info>,
-                ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                #[cfg(feature = "cpi")]
+                pub fn #verify_writable_privileges_fn_ident #lifetimes(
+                    accounts: #accounts_ident #lifetimes,
+                ) -> Result<(), (&'me #account_info, ProgramError)> {
                     for should_be_writable in [
                         #(#writables),*
                     ] {
-                        if !should_be_writable.is_writable {
+                        if !#should_be_writable_is_writable {
                             return Err((should_be_writable, ProgramError::InvalidAccountData));
                         }
                     }
@@ -600,14 +906,16 @@ impl NamedInstruction {
             .peekable();
         let has_signers = signers.peek().is_some();
         if has_signers {
+            let should_be_signer_is_signer = target.is_signer(quote! { should_be_signer });
             tokens.extend(quote! {
-                pub fn #verify_signer_privileges_fn_ident<'me, 'info>(
-                    accounts: #accounts_ident<'me, 'info>,
-                ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+                #[cfg(feature = "cpi")]
+                pub fn #verify_signer_privileges_fn_ident #lifetimes(
+                    accounts: #accounts_ident #lifetimes,
+                ) -> Result<(), (&'me #account_info, ProgramError)> {
                     for should_be_signer in [
                         #(#signers),*
                     ] {
-                        if !should_be_signer.is_signer {
+                        if !#should_be_signer_is_signer {
                             return Err((should_be_signer, ProgramError::MissingRequiredSignature));
                         }
                     }
@@ -620,9 +928,10 @@ impl NamedInstruction {
         }
 
         tokens.extend(quote! {
-            pub fn #verify_account_privileges_fn_ident<'me, 'info>(
-                accounts: #accounts_ident<'me, 'info>,
-            ) -> Result<(), (&'me AccountInfo<'info>, ProgramError)> {
+            #[cfg(feature = "cpi")]
+            pub fn #verify_account_privileges_fn_ident #lifetimes(
+                accounts: #accounts_ident #lifetimes,
+            ) -> Result<(), (&'me #account_info, ProgramError)> {
                 #verify_fn_body
                 Ok(())
             }
@@ -630,9 +939,12 @@ impl NamedInstruction {
     }
 }
 
-impl ToTokens for NamedInstruction {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let accounts: &[IxAccount] = self.accounts.as_ref().map_or(&[], |v| v.as_slice());
+impl NamedInstruction {
+    /// Writes the instruction's items other than its discriminant and `*_ix()`,
+    /// with the on-chain ones written against `target`
+    pub fn write_items(&self, tokens: &mut TokenStream, target: Target) {
+        let accounts = self.ix_accounts();
+        let accounts = accounts.as_slice();
         let n_accounts = accounts.len();
 
         let accounts_dedup = unique_by_report_dups(accounts.iter(), |acc| acc.name.clone());
@@ -646,33 +958,55 @@ impl ToTokens for NamedInstruction {
         let unique_accounts = &accounts_dedup.unique;
 
         self.write_accounts_len(tokens, n_accounts);
-        self.write_accounts_struct(tokens, unique_accounts);
+        self.write_accounts_struct(tokens, unique_accounts, target);
         self.write_keys_struct(tokens, unique_accounts);
-        self.write_from_accounts_for_keys(tokens, unique_accounts);
+        self.write_from_accounts_for_keys(tokens, unique_accounts, target);
         self.write_from_keys_for_meta_arr(tokens, accounts);
         self.write_from_pubkey_arr_for_keys(tokens, unique_accounts);
-        self.write_from_accounts_for_account_info_arr(tokens, accounts);
-        self.write_from_account_info_arr_for_accounts(tokens, accounts);
+        self.write_from_accounts_for_account_info_arr(tokens, accounts, target);
+        if target == Target::Pinocchio {
+            self.write_from_accounts_for_cpi_meta_arr(tokens, accounts);
+        }
+        self.write_from_account_info_arr_for_accounts(tokens, accounts, target);
 
         self.write_ix_args_struct(tokens);
 
-        self.write_invoke_fn(tokens);
-        self.write_invoke_signed_fn(tokens);
+        match target {
+            Target::SolanaProgram => {
+                self.write_invoke_fn(tokens);
+                self.write_invoke_signed_fn(tokens);
+            }
+            Target::Pinocchio => self.write_pinocchio_invoke_fns(tokens),
+        }
 
-        self.write_verify_account_keys_fn(tokens, unique_accounts);
-        self.write_verify_account_privileges_fns(tokens, unique_accounts);
+        self.write_verify_account_keys_fn(tokens, unique_accounts, target);
+        self.write_verify_account_privileges_fns(tokens, unique_accounts, target);
     }
 }
 
-impl<'a> ToTokens for NamedInstructionFull<'a> {
+impl ToTokens for NamedInstruction {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.write_items(tokens, Target::SolanaProgram);
+    }
+}
+
+impl NamedInstructionFull<'_> {
+    /// Writes all of the instruction's items, with the on-chain ones written against `target`
+    pub fn write_items(&self, tokens: &mut TokenStream, target: Target) {
         self.write_discm(tokens);
         self.write_ix_fn(tokens);
-        tokens.extend(self.ix.to_token_stream());
+        self.ix.write_items(tokens, target);
+        self.write_runtime_ix_impl(tokens);
     }
 }
 
-#[derive(Deserialize)]
+impl<'a> ToTokens for NamedInstructionFull<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.write_items(tokens, Target::SolanaProgram);
+    }
+}
+
+#[derive(Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IxAccount {
     pub name: String,
@@ -683,7 +1017,7 @@ pub struct IxAccount {
 
 impl IxAccount {
     pub fn field_ident(&self) -> Ident {
-        format_ident!("{}", self.name.to_snake_case())
+        snake_ident(&self.name)
     }
 
     pub fn is_privileged(&self) -> bool {
@@ -703,10 +1037,31 @@ impl IxAccount {
         }
     }
 
-    pub fn to_verify_account_keys_tuple(&self) -> TokenStream {
+    pub fn to_cpi_account_meta_tokens(&self) -> TokenStream {
+        let is_writable_arg = LitBool::new(self.is_mut, Span::call_site());
+        let is_signer_arg = LitBool::new(self.is_signer, Span::call_site());
         let name = self.field_ident();
         quote! {
-            (accounts.#name.key, &keys.#name)
+            CpiAccountMeta {
+                pubkey: accounts.#name.key(),
+                is_writable: #is_writable_arg,
+                is_signer: #is_signer_arg,
+            }
+        }
+    }
+
+    pub fn to_verify_account_keys_tuple(&self, target: Target) -> TokenStream {
+        let name = self.field_ident();
+        match target {
+            Target::SolanaProgram => quote! {
+                (accounts.#name.key, &keys.#name)
+            },
+            Target::Pinocchio => {
+                let key = target.account_key(quote! { accounts.#name });
+                quote! {
+                    (&#key, &keys.#name)
+                }
+            }
         }
     }
 }
@@ -1,20 +1,52 @@
-use heck::ToPascalCase;
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use quote::{format_ident, quote};
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{
+    naming::pascal_case,
+    target::Target,
+    {idl_format::IdlCodegenModule, Result},
+};
 
+mod compact;
 mod instruction;
 pub use instruction::*;
 
 pub struct IxCodegenModule<'a> {
     pub program_name: &'a str,
     pub instructions: &'a [NamedInstruction],
+    /// (index, IDL name) of the filtered out instructions
+    pub filtered_instructions: &'a [(usize, String)],
+    pub target: Target,
+    /// Whether instructions' items are written as invocations of [`compact::compact_macros`]
+    pub compact: bool,
 }
 
 impl<'a> IxCodegenModule<'a> {
     pub fn program_ix_enum_ident(&self) -> Ident {
-        format_ident!("{}ProgramIx", self.program_name.to_pascal_case())
+        format_ident!("{}ProgramIx", pascal_case(self.program_name))
+    }
+
+    /// (rust name, items) of each instruction
+    fn items(&self) -> Vec<(String, TokenStream)> {
+        let program_ix_enum_ident = self.program_ix_enum_ident();
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(i, ix)| {
+                let ix_full = NamedInstructionFull {
+                    ix,
+                    index: ix.index.unwrap_or(i),
+                    program_ix_enum_ident: &program_ix_enum_ident,
+                };
+                let mut tokens = TokenStream::new();
+                if self.compact {
+                    ix_full.write_compact_items(&mut tokens, self.target);
+                } else {
+                    ix_full.write_items(&mut tokens, self.target);
+                }
+                (ix.rust_name().to_owned(), tokens)
+            })
+            .collect()
     }
 }
 
@@ -23,49 +55,81 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
         "instructions"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {};
-        let has_accounts = self
-            .instructions
-            .iter()
-            .map(|ix| ix.has_accounts())
-            .any(|b| b);
-        let mut solana_program_imports = if has_accounts {
+        let has_accounts = self.instructions.iter().any(|ix| ix.has_accounts());
+        let solana_program_imports = if has_accounts {
             quote! {
-                account_info::AccountInfo,
-                entrypoint::ProgramResult,
                 instruction::{AccountMeta, Instruction},
-                program::{invoke, invoke_signed},
                 pubkey::Pubkey,
             }
         } else {
             quote! {
-                entrypoint::ProgramResult,
                 instruction::Instruction,
-                program::{invoke, invoke_signed},
                 pubkey::Pubkey,
             }
         };
         let has_privileged_accounts = self
             .instructions
             .iter()
-            .map(|ix| ix.has_privileged_accounts())
-            .any(|b| b);
-        if has_privileged_accounts {
-            solana_program_imports.extend(quote! {
-                program_error::ProgramError,
-            });
-        }
+            .any(|ix| ix.has_privileged_accounts());
+        let cpi_imports = match self.target {
+            Target::SolanaProgram => {
+                let mut cpi_imports = if has_accounts {
+                    quote! {
+                        account_info::AccountInfo,
+                        entrypoint::ProgramResult,
+                        program::{invoke, invoke_signed},
+                    }
+                } else {
+                    quote! {
+                        entrypoint::ProgramResult,
+                        program::{invoke, invoke_signed},
+                    }
+                };
+                if has_privileged_accounts {
+                    cpi_imports.extend(quote! {
+                        program_error::ProgramError,
+                    });
+                }
+                quote! {
+                    use solana_program::{#cpi_imports};
+                }
+            }
+            Target::Pinocchio => {
+                let mut cpi_imports = if has_accounts {
+                    quote! {
+                        account_info::AccountInfo,
+                        cpi::invoke_signed,
+                        instruction::{AccountMeta as CpiAccountMeta, Instruction as CpiInstruction, Signer},
+                    }
+                } else {
+                    quote! {
+                        cpi::invoke_signed,
+                        instruction::{Instruction as CpiInstruction, Signer},
+                    }
+                };
+                if has_privileged_accounts {
+                    cpi_imports.extend(quote! {
+                        program_error::ProgramError,
+                    });
+                }
+                quote! {
+                    use pinocchio::{#cpi_imports ProgramResult};
+                }
+            }
+        };
         res.extend(quote! {
             use serde::{Serialize, Deserialize};
             use solana_program::{#solana_program_imports};
+            #[cfg(feature = "cpi")]
+            #cpi_imports
         });
 
         let has_defined_type = self
             .instructions
             .iter()
-            .map(|ix| ix.args_has_defined_type())
-            .any(|b| b);
+            .any(|ix| ix.args_has_defined_type());
         if has_defined_type {
             res.extend(quote! {
                 use crate::*;
@@ -74,7 +138,20 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
 
         // program ix enum
         let program_ix_enum_ident = self.program_ix_enum_ident();
-        let program_ix_enum_variants = self.instructions.iter().map(enum_variant);
+        // placeholders for the filtered out instructions keep the variants' indices
+        let mut program_ix_enum_variants: Vec<_> = self
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(i, ix)| (ix.index.unwrap_or(i), enum_variant(ix)))
+            .chain(self.filtered_instructions.iter().map(|(i, name)| {
+                let doc = format!("Filtered out instruction `{name}`");
+                let variant_ident = format_ident!("Unknown{i}");
+                (*i, quote! { #[doc = #doc] #variant_ident })
+            }))
+            .collect();
+        program_ix_enum_variants.sort_by_key(|(i, _)| *i);
+        let program_ix_enum_variants = program_ix_enum_variants.into_iter().map(|(_, v)| v);
 
         res.extend(quote! {
             #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -83,8 +160,34 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             }
         });
 
-        if has_accounts {
+        if has_accounts && self.target == Target::Pinocchio {
             res.extend(quote! {
+                #[cfg(feature = "cpi")]
+                fn invoke_instruction_signed<'me, A, const N: usize>(
+                    program_id: &Pubkey,
+                    accounts: A,
+                    data: &[u8],
+                    signers: &[Signer],
+                ) -> ProgramResult
+                where
+                    A: Copy + Into<[CpiAccountMeta<'me>; N]> + Into<[&'me AccountInfo; N]>,
+                {
+                    let metas: [CpiAccountMeta<'me>; N] = accounts.into();
+                    let account_infos: [&'me AccountInfo; N] = accounts.into();
+                    invoke_signed(
+                        &CpiInstruction {
+                            program_id: &program_id.to_bytes(),
+                            accounts: &metas,
+                            data,
+                        },
+                        &account_infos,
+                        signers,
+                    )
+                }
+            });
+        } else if has_accounts {
+            res.extend(quote! {
+                #[cfg(feature = "cpi")]
                 fn invoke_instruction<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
                     ix: &Instruction,
                     accounts: A,
@@ -92,6 +195,7 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
                     let account_info: [AccountInfo<'info>; N] = accounts.into();
                     invoke(ix, &account_info)
                 }
+                #[cfg(feature = "cpi")]
                 fn invoke_instruction_signed<'info, A: Into<[AccountInfo<'info>; N]>, const N: usize>(
                     ix: &Instruction,
                     accounts: A,
@@ -103,23 +207,26 @@ impl IdlCodegenModule for IxCodegenModule<'_> {
             });
         }
 
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
-        let program_ix_enum_ident = self.program_ix_enum_ident();
-        self.instructions
-            .iter()
-            .enumerate()
-            .map(|(i, ix)| {
-                NamedInstructionFull {
-                    ix,
-                    index: i,
-                    program_ix_enum_ident: &program_ix_enum_ident,
-                }
-                .into_token_stream()
-            })
-            .collect()
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.items().into_iter().map(|(_, tokens)| tokens).collect())
+    }
+
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(Some(self.items()))
+    }
+
+    fn gen_macros(&self) -> String {
+        if !self.compact {
+            return String::new();
+        }
+        compact::compact_macros(
+            self.instructions,
+            &self.program_ix_enum_ident(),
+            self.target,
+        )
     }
 }
 
@@ -1,9 +1,22 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 use serde::Deserialize;
 use toml::{map::Map, Value};
 
-use crate::write_cargotoml::{
-    DependencyValue, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE,
-    THISERROR_CRATE,
+use crate::{
+    config::warn_unmatched,
+    filter::{referenced_types, retain_items, Filters},
+    lint::{check_duplicate_names, check_keyword, Lint},
+    naming::{pascal_case, ItemNames},
+    solana_crates::SolanaCratesUsage,
+    target::Target,
+    utils::check_ident,
+    write_cargotoml::{
+        mapped_type_dependency, num_traits_dependency, serde_dependency, DependencyValue,
+        OptionalDependencyValue, CLIENT_FEATURE, CPI_FEATURE, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE,
+        PINOCCHIO_CRATE, RUNTIME_FEATURE, SERDE_CRATE, SERDE_WITH_CRATE, SOLORES_RUNTIME_CRATE,
+        THISERROR_CRATE,
+    },
 };
 
 use super::{IdlCodegenModule, IdlFormat};
@@ -11,7 +24,7 @@ use super::{IdlCodegenModule, IdlFormat};
 use self::{
     errors::{ErrorEnumVariant, ErrorsCodegenModule},
     instructions::{IxCodegenModule, NamedInstruction},
-    typedefs::{NamedType, TypedefsCodegenModule},
+    typedefs::{NamedType, TypedefFieldType, TypedefsCodegenModule},
 };
 
 pub mod errors;
@@ -26,6 +39,16 @@ pub struct BincodeIdl {
     pub instructions: Option<Vec<NamedInstruction>>,
     pub types: Option<Vec<NamedType>>,
     pub errors: Option<Vec<ErrorEnumVariant>>,
+    /// The IDL's JSON as loaded, set by [`crate::load_idl_str`] and [`crate::load_idl_value`]
+    #[serde(skip)]
+    pub json: String,
+    /// Rust types of the `--map-type` mappings used by the IDL, set by `apply_item_configs()`
+    #[serde(skip)]
+    pub mapped_types: BTreeSet<String>,
+    /// (index, IDL name) of the instructions removed by `apply_filters()`.
+    /// Instruction discriminants are their indices, so `ProgramIx` keeps a placeholder variant for each.
+    #[serde(skip)]
+    pub filtered_instructions: Vec<(usize, String)>,
 }
 
 #[derive(Deserialize)]
@@ -34,11 +57,67 @@ pub struct Metadata {
     pub origin: String,
 }
 
+impl BincodeIdl {
+    /// Checks that all of the IDL's items can be generated
+    pub fn validate(&self) -> crate::Result<()> {
+        check_ident("program", &pascal_case(&self.name))?;
+        for t in self.types.iter().flatten() {
+            t.validate("typedef")?;
+        }
+        for ix in self.instructions.iter().flatten() {
+            ix.validate()?;
+        }
+        for e in self.errors.iter().flatten() {
+            e.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Whether any generated struct has an array field
+    /// that requires a serde_with adapter
+    pub fn has_large_array(&self) -> bool {
+        self.types
+            .iter()
+            .flatten()
+            .any(|t| t.r#type.has_large_array_field())
+            || self
+                .instructions
+                .iter()
+                .flatten()
+                .any(|ix| ix.args_has_large_array())
+    }
+
+    /// What the generated code uses from the solana crates
+    pub fn solana_crates_usage(&self) -> SolanaCratesUsage {
+        let instructions = self.instructions.iter().flatten();
+        SolanaCratesUsage {
+            has_instructions: self.instructions.is_some(),
+            has_ix_accounts: instructions.clone().any(|ix| ix.has_accounts()),
+            has_errors: self.errors.is_some(),
+            has_pubkey_fields: self
+                .types
+                .iter()
+                .flatten()
+                .any(|t| t.r#type.has_pubkey_field())
+                || instructions.clone().any(|ix| ix.args_has_pubkeys()),
+            is_bincode: true,
+        }
+    }
+}
+
 impl IdlFormat for BincodeIdl {
     fn program_name(&self) -> &str {
         &self.name
     }
 
+    fn json(&self) -> &str {
+        &self.json
+    }
+
+    fn set_json(&mut self, json: String) {
+        self.json = json;
+    }
+
     fn program_version(&self) -> &str {
         &self.version
     }
@@ -47,11 +126,146 @@ impl IdlFormat for BincodeIdl {
         Some(&self.metadata.address)
     }
 
-    fn is_correct_idl_format(&self) -> bool {
-        self.metadata.origin == "bincode"
+    fn apply_filters(&mut self, filters: &Filters) {
+        if !filters.is_set() {
+            return;
+        }
+        for (i, ix) in self.instructions.iter_mut().flatten().enumerate() {
+            ix.index = Some(i);
+        }
+        let mut filtered_instructions = Vec::new();
+        retain_items(&mut self.instructions, |ix| {
+            let keep = filters.instructions.matches(&ix.name);
+            if !keep {
+                filtered_instructions.push((ix.index.unwrap(), ix.name.clone()));
+            }
+            keep
+        });
+        self.filtered_instructions = filtered_instructions;
+
+        let roots = self
+            .instructions
+            .iter()
+            .flatten()
+            .flat_map(|ix| ix.args.iter().flatten())
+            .filter_map(|arg| arg.r#type.defined_type())
+            .chain(
+                self.types
+                    .iter()
+                    .flatten()
+                    .filter(|t| filters.is_typedef_root(&t.name))
+                    .map(|t| t.name.as_str()),
+            )
+            .map(ToOwned::to_owned);
+        let referenced = referenced_types(roots, |name| {
+            self.types
+                .iter()
+                .flatten()
+                .filter(|t| t.name == name)
+                .flat_map(|t| t.r#type.defined_types())
+                .map(ToOwned::to_owned)
+                .collect()
+        });
+        retain_items(&mut self.types, |t| referenced.contains(&t.name));
     }
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+    fn apply_item_configs(&mut self, args: &crate::Args) {
+        warn_unmatched(
+            "typedef",
+            &args.typedef_configs,
+            self.types.iter().flatten().map(|t| t.name.as_str()),
+        );
+        // no accounts in bincode IDLs
+        warn_unmatched("account", &args.account_configs, []);
+        warn_unmatched(
+            "instruction",
+            &args.instruction_configs,
+            self.instructions
+                .iter()
+                .flatten()
+                .map(|ix| ix.name.as_str()),
+        );
+
+        // typedefs mapped to external types are not generated
+        let mappings: HashMap<&str, &str> = args
+            .map_type
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_str()))
+            .collect();
+        if let Some(types) = self.types.as_mut() {
+            let n_types = types.len();
+            types.retain(|t| !mappings.contains_key(t.name.as_str()));
+            if n_types > 0 && types.is_empty() {
+                self.types = None;
+            }
+        }
+
+        for t in self.types.iter_mut().flatten() {
+            if let Some(config) = args.typedef_configs.get(&t.name) {
+                t.apply_config(config);
+            }
+        }
+        for ix in self.instructions.iter_mut().flatten() {
+            if let Some(config) = args.instruction_configs.get(&ix.name) {
+                ix.apply_config(config);
+            }
+        }
+
+        // Disambiguate items whose generated names collide. Instructions go first
+        // so that their helpers keep the names derived from the IDL.
+        let mut item_names = ItemNames::new(self.program_name());
+        for ix in self.instructions.iter_mut().flatten() {
+            if let Some(rename) = item_names.reserve(
+                &format!("instruction {}", ix.name),
+                ix.rust_name(),
+                NamedInstruction::item_names,
+            ) {
+                ix.config.rename = Some(rename);
+            }
+        }
+        for t in self.types.iter_mut().flatten() {
+            if let Some(rename) =
+                item_names.reserve(&format!("typedef {}", t.name), &t.rust_name(), |n| {
+                    vec![n.to_owned()]
+                })
+            {
+                t.config.rename = Some(rename);
+            }
+        }
+
+        // `defined` types are IDL names. Replace them with the generated names,
+        // which differ if renamed or converted to valid identifiers
+        let rust_names = self.defined_types();
+        // the first of any types with duplicate IDL names is the one referenced
+        let mut renames: HashMap<&str, &str> = HashMap::new();
+        for (name, rust_name) in rust_names.iter() {
+            renames.entry(name.as_str()).or_insert(rust_name.as_str());
+        }
+        renames.retain(|name, rust_name| name != rust_name);
+        let mut mapped_types = BTreeSet::new();
+        let mut map_and_rename = |ty: &mut TypedefFieldType| {
+            ty.map_types(&mappings, &mut mapped_types);
+            ty.rename_defined(&renames);
+        };
+        for t in self.types.iter_mut().flatten() {
+            t.r#type.for_each_field_type_mut(&mut map_and_rename);
+        }
+        for arg in self
+            .instructions
+            .iter_mut()
+            .flatten()
+            .flat_map(|ix| ix.args.iter_mut().flatten())
+        {
+            map_and_rename(&mut arg.r#type);
+        }
+        self.mapped_types = mapped_types;
+    }
+
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+    ) -> crate::Result<Vec<Box<dyn IdlCodegenModule + 'me>>> {
+        self.validate()?;
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.r#types {
             res.push(Box::new(TypedefsCodegenModule {
@@ -63,38 +277,114 @@ impl IdlFormat for BincodeIdl {
             res.push(Box::new(IxCodegenModule {
                 program_name: self.program_name(),
                 instructions: v,
+                filtered_instructions: &self.filtered_instructions,
+                target: args.target,
+                compact: args.compact,
             }));
         }
         if let Some(v) = &self.errors {
             res.push(Box::new(ErrorsCodegenModule {
                 program_name: self.program_name(),
                 variants: v,
+                no_std: args.no_std,
+                target: args.target,
             }));
         }
-        res
+        Ok(res)
+    }
+
+    fn defined_types(&self) -> Vec<(String, String)> {
+        self.types
+            .iter()
+            .flatten()
+            .map(|t| (t.name.clone(), t.rust_name()))
+            .collect()
+    }
+
+    fn lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        check_keyword(&mut lints, "program", &pascal_case(&self.name));
+
+        let types = self.types.iter().flatten();
+        let instructions = self.instructions.iter().flatten();
+        let errors = self.errors.iter().flatten();
+
+        let type_names: HashSet<&str> = types.clone().map(|t| t.name.as_str()).collect();
+
+        check_duplicate_names(
+            &mut lints,
+            "typedef",
+            types.clone().map(|t| t.name.as_str()),
+        );
+        check_duplicate_names(
+            &mut lints,
+            "instruction",
+            instructions.clone().map(|ix| ix.name.as_str()),
+        );
+        check_duplicate_names(&mut lints, "error", errors.clone().map(|e| e.name.as_str()));
+
+        types.for_each(|t| t.lint("typedef", &type_names, &mut lints));
+        instructions.for_each(|ix| ix.lint(&type_names, &mut lints));
+        errors.for_each(|e| e.lint(&mut lints));
+
+        lints
     }
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
         let mut map = Map::new();
+        map.extend(self.solana_crates_usage().dependencies(args));
+        map.insert(SERDE_CRATE.into(), serde_dependency(args).into());
+        if args.target == Target::Pinocchio {
+            map.insert(
+                PINOCCHIO_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.pinocchio_vers)).into(),
+            );
+        }
         map.insert(
-            SOLANA_PROGRAM_CRATE.into(),
-            DependencyValue(&args.solana_program_vers).into(),
+            SOLORES_RUNTIME_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.solores_runtime_vers)).into(),
         );
-        map.insert(SERDE_CRATE.into(), DependencyValue(&args.serde_vers).into());
-        if self.errors.is_some() {
+        if self.has_large_array() {
             map.insert(
-                THISERROR_CRATE.into(),
-                DependencyValue(&args.thiserror_vers).into(),
+                SERDE_WITH_CRATE.into(),
+                DependencyValue(&args.serde_with_vers).into(),
             );
+        }
+        if self.errors.is_some() {
+            // no_std crates implement the error traits without thiserror
+            if !args.no_std {
+                map.insert(
+                    THISERROR_CRATE.into(),
+                    DependencyValue(&args.thiserror_vers).into(),
+                );
+            }
             map.insert(
                 NUM_DERIVE_CRATE.into(),
                 DependencyValue(&args.num_derive_vers).into(),
             );
-            map.insert(
-                NUM_TRAITS_CRATE.into(),
-                DependencyValue(&args.num_traits_vers).into(),
-            );
+            map.insert(NUM_TRAITS_CRATE.into(), num_traits_dependency(args));
         }
+        for path in self.mapped_types.iter() {
+            if let Some((name, vers)) = mapped_type_dependency(args, path) {
+                map.insert(name, DependencyValue(vers).into());
+            }
+        }
+        map
+    }
+
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        let mut cpi_dependencies = match args.target {
+            Target::SolanaProgram => Vec::new(),
+            Target::Pinocchio => vec![format!("dep:{PINOCCHIO_CRATE}")],
+        };
+        cpi_dependencies.extend(self.solana_crates_usage().cpi_features(args));
+        map.insert(CPI_FEATURE.into(), cpi_dependencies.into());
+        map.insert(CLIENT_FEATURE.into(), Value::Array(Vec::new()));
+        map.insert(
+            RUNTIME_FEATURE.into(),
+            vec![format!("dep:{SOLORES_RUNTIME_CRATE}")].into(),
+        );
         map
     }
 }
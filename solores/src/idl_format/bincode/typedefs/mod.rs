@@ -4,7 +4,7 @@ use quote::quote;
 mod typedef;
 pub use typedef::*;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, Result};
 
 pub struct TypedefsCodegenModule<'a> {
     pub cli_args: &'a crate::Args,
@@ -16,7 +16,7 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
         "typedefs"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {
             use serde::{Deserialize, Serialize};
         };
@@ -36,13 +36,24 @@ impl IdlCodegenModule for TypedefsCodegenModule<'_> {
                 break;
             }
         }
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.items().into_iter().map(|(_, tokens)| tokens).collect())
+    }
+
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(Some(self.items()))
+    }
+}
+
+impl TypedefsCodegenModule<'_> {
+    /// (rust name, items) of each typedef
+    fn items(&self) -> Vec<(String, TokenStream)> {
         self.named_types
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .map(|e| (e.rust_name(), e.to_token_stream(self.cli_args)))
             .collect()
     }
 }
@@ -1,31 +1,95 @@
 #![allow(non_camel_case_types)]
 
-use std::str::FromStr;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    str::FromStr,
+};
 
-use heck::{ToPascalCase, ToSnakeCase};
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use quote::{quote, ToTokens};
 use serde::Deserialize;
 use syn::Index;
 use void::Void;
 
-use crate::utils::{primitive_or_pubkey_to_token, string_or_struct, PUBKEY_TOKEN};
+use crate::{
+    config::ItemConfig,
+    lint::{check_defined, check_keyword, Lint},
+    naming::{
+        defined_type, ident, pascal_case, pascal_ident, sanitize_type, snake_case, snake_ident,
+    },
+    utils::{
+        check_derive, check_ident, check_type, primitive_or_pubkey_to_token, string_or_struct,
+        PUBKEY_TOKEN, SERDE_MAX_ARRAY_LEN,
+    },
+};
 
 #[derive(Deserialize)]
 pub struct NamedType {
     pub name: String,
     pub r#type: TypedefType,
+    #[serde(skip)]
+    pub config: ItemConfig,
 }
 
 impl NamedType {
+    /// The generated struct/enum's name
+    pub fn rust_name(&self) -> String {
+        self.config
+            .rename
+            .clone()
+            .unwrap_or_else(|| sanitize_type(&self.name))
+    }
+
+    pub fn apply_config(&mut self, config: &ItemConfig) {
+        self.config = config.clone();
+        if let TypedefType::r#struct(s) = &mut self.r#type {
+            s.override_field_types(&config.types);
+        }
+    }
+
+    /// Checks that the struct/enum can be generated.
+    /// `kind` is the kind of IDL item e.g. "typedef", "account"
+    pub fn validate(&self, kind: &str) -> crate::Result<()> {
+        let item = format!("{kind} {}", self.name);
+        check_ident(&item, &self.rust_name())?;
+        for derive in self.config.derives.iter() {
+            check_derive(&item, derive)?;
+        }
+        self.r#type.validate(&item)
+    }
+
+    /// Adds the struct/enum's lints.
+    /// `kind` is the kind of IDL item e.g. "typedef", "account"
+    pub fn lint(&self, kind: &str, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("{kind} {}", self.name);
+        check_keyword(lints, &item, &self.rust_name());
+        self.r#type.lint(&item, type_names, lints);
+    }
+
+    fn extra_derives(&self) -> TokenStream {
+        if self.config.derives.is_empty() {
+            return quote! {};
+        }
+        let derives = self
+            .config
+            .derives
+            .iter()
+            .map(|d| d.parse::<TokenStream>().unwrap());
+        quote! {
+            #[derive(#(#derives),*)]
+        }
+    }
+
     pub fn to_token_stream(&self, cli_args: &crate::Args) -> TokenStream {
-        let name = format_ident!("{}", self.name);
+        let name = ident(&self.rust_name());
+        let extra_derives = self.extra_derives();
         // rust enums cannot impl Pod due to illegal bitpatterns
         let typedef_struct = match &self.r#type {
             TypedefType::r#struct(typedef_struct) => typedef_struct,
             TypedefType::r#enum(typedef_enum) => {
                 return quote! {
                     #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+                    #extra_derives
                     pub enum #name {
                         #typedef_enum
                     }
@@ -45,6 +109,7 @@ impl NamedType {
         };
         quote! {
             #derive
+            #extra_derives
             pub struct #name {
                 #typedef_struct
             }
@@ -142,6 +207,13 @@ impl EnumVariantFields {
             Self::Tuple(v) => v.iter().any(|f| f.0.is_or_has_defined()),
         }
     }
+
+    pub fn has_large_array(&self) -> bool {
+        match self {
+            Self::Struct(v) => v.iter().any(|f| f.r#type.is_or_has_large_array()),
+            Self::Tuple(v) => v.iter().any(|f| f.0.is_or_has_large_array()),
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -150,9 +222,47 @@ pub struct EnumVariant {
     pub fields: Option<EnumVariantFields>,
 }
 
+impl TypedefStruct {
+    pub fn validate(&self, item: &str) -> crate::Result<()> {
+        self.fields.iter().try_for_each(|f| f.validate(item))
+    }
+
+    /// Replaces the types of the fields that are keys of `overrides`
+    /// with the rust type given by the corresponding value
+    pub fn override_field_types(&mut self, overrides: &HashMap<String, String>) {
+        for f in self.fields.iter_mut() {
+            f.override_type(overrides);
+        }
+    }
+}
+
+impl TypedefField {
+    pub fn validate(&self, item: &str) -> crate::Result<()> {
+        let item = format!("{item} field {}", self.name);
+        check_ident(&item, &snake_case(&self.name))?;
+        self.r#type.validate(&item)
+    }
+
+    pub fn lint(&self, item: &str, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("{item} field {}", self.name);
+        check_keyword(lints, &item, &snake_case(&self.name));
+        self.r#type.lint(&item, type_names, lints);
+    }
+
+    /// Replaces this field's type with the rust type in `overrides`, if present
+    pub fn override_type(&mut self, overrides: &HashMap<String, String>) {
+        if let Some(ty) = overrides.get(&self.name) {
+            self.r#type = TypedefFieldType::PrimitiveOrPubkey(ty.clone());
+        }
+    }
+}
+
 impl ToTokens for TypedefStruct {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let typedef_fields = self.fields.iter().map(|f| quote! { pub #f });
+        let typedef_fields = self.fields.iter().map(|f| {
+            let serde_with_attr = f.r#type.serde_with_attr();
+            quote! { #serde_with_attr pub #f }
+        });
         tokens.extend(quote! {
             #(#typedef_fields),*
         })
@@ -161,7 +271,7 @@ impl ToTokens for TypedefStruct {
 
 impl ToTokens for TypedefField {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let name = format_ident!("{}", self.name.to_snake_case());
+        let name = snake_ident(&self.name);
         let ty = &self.r#type;
         tokens.extend(quote! {
             #name: #ty
@@ -173,7 +283,7 @@ impl ToTokens for TypedefFieldType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let ty: TokenStream = match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s).parse().unwrap(),
-            Self::defined(s) => s.parse().unwrap(),
+            Self::defined(s) => defined_type(s),
             Self::array(a) => a.to_token_stream(),
             Self::vec(v) => quote! {
                 Vec<#v>
@@ -207,19 +317,26 @@ impl ToTokens for TypedefEnum {
 
 impl ToTokens for EnumVariant {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let v = format_ident!("{}", self.name.to_pascal_case());
+        let v = pascal_ident(&self.name);
         let maybe_inner_fields = self
             .fields
             .as_ref()
             .map_or(quote! {}, |fields| match fields {
                 EnumVariantFields::Struct(v) => {
-                    let typedef_fields = v.iter();
+                    let typedef_fields = v.iter().map(|f| {
+                        let serde_with_attr = f.r#type.serde_with_attr();
+                        quote! { #serde_with_attr #f }
+                    });
                     quote! {
                         { #(#typedef_fields),* }
                     }
                 }
                 EnumVariantFields::Tuple(v) => {
-                    let unnamed_fields = v.iter().map(|wrap| &wrap.0);
+                    let unnamed_fields = v.iter().map(|wrap| {
+                        let ty = &wrap.0;
+                        let serde_with_attr = ty.serde_with_attr();
+                        quote! { #serde_with_attr #ty }
+                    });
                     quote! {
                         ( #(#unnamed_fields),* )
                     }
@@ -232,6 +349,26 @@ impl ToTokens for EnumVariant {
 }
 
 impl TypedefType {
+    pub fn validate(&self, item: &str) -> crate::Result<()> {
+        match self {
+            Self::r#enum(e) => e.variants.iter().try_for_each(|v| v.validate(item)),
+            Self::r#struct(s) => s.validate(item),
+        }
+    }
+
+    pub fn lint(&self, item: &str, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        match self {
+            Self::r#enum(e) => e
+                .variants
+                .iter()
+                .for_each(|v| v.lint(item, type_names, lints)),
+            Self::r#struct(s) => s
+                .fields
+                .iter()
+                .for_each(|f| f.lint(item, type_names, lints)),
+        }
+    }
+
     pub fn has_pubkey_field(&self) -> bool {
         match self {
             Self::r#enum(e) => e.variants.iter().any(|e| e.has_pubkey()),
@@ -245,9 +382,78 @@ impl TypedefType {
             Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_defined()),
         }
     }
+
+    /// Calls `f` on the type of every field
+    pub fn for_each_field_type_mut(&mut self, f: &mut impl FnMut(&mut TypedefFieldType)) {
+        match self {
+            Self::r#enum(e) => e
+                .variants
+                .iter_mut()
+                .filter_map(|v| v.fields.as_mut())
+                .for_each(|fields| match fields {
+                    EnumVariantFields::Struct(v) => {
+                        v.iter_mut().for_each(|field| f(&mut field.r#type))
+                    }
+                    EnumVariantFields::Tuple(v) => v.iter_mut().for_each(|field| f(&mut field.0)),
+                }),
+            Self::r#struct(s) => s.fields.iter_mut().for_each(|field| f(&mut field.r#type)),
+        }
+    }
+
+    /// The `defined` types of all fields
+    pub fn defined_types(&self) -> Vec<&str> {
+        match self {
+            Self::r#enum(e) => e
+                .variants
+                .iter()
+                .filter_map(|v| v.fields.as_ref())
+                .flat_map(|fields| match fields {
+                    EnumVariantFields::Struct(v) => {
+                        v.iter().filter_map(|f| f.r#type.defined_type()).collect()
+                    }
+                    EnumVariantFields::Tuple(v) => v
+                        .iter()
+                        .filter_map(|f| f.0.defined_type())
+                        .collect::<Vec<_>>(),
+                })
+                .collect(),
+            Self::r#struct(s) => s
+                .fields
+                .iter()
+                .filter_map(|f| f.r#type.defined_type())
+                .collect(),
+        }
+    }
+
+    pub fn has_large_array_field(&self) -> bool {
+        match self {
+            Self::r#enum(e) => e.variants.iter().any(|e| e.has_large_array()),
+            Self::r#struct(s) => s.fields.iter().any(|f| f.r#type.is_or_has_large_array()),
+        }
+    }
 }
 
 impl TypedefFieldType {
+    pub fn validate(&self, item: &str) -> crate::Result<()> {
+        match self {
+            Self::PrimitiveOrPubkey(s) => check_type(item, &primitive_or_pubkey_to_token(s)),
+            // always valid, see naming::defined_type()
+            Self::defined(_) => Ok(()),
+            Self::array(a) => a.0.validate(item),
+            Self::option(t) | Self::vec(t) => t.validate(item),
+        }
+    }
+
+    /// Adds a lint for each `defined` type that is not one of `type_names`
+    pub fn lint(&self, item: &str, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        match self {
+            Self::PrimitiveOrPubkey(_) => {}
+            Self::defined(s) => check_defined(lints, item, s, type_names),
+            Self::array(a) => a.0.lint(item, type_names, lints),
+            Self::option(t) | Self::vec(t) => t.lint(item, type_names, lints),
+        }
+    }
+
     pub fn is_or_has_pubkey(&self) -> bool {
         match self {
             Self::PrimitiveOrPubkey(s) => primitive_or_pubkey_to_token(s) == PUBKEY_TOKEN,
@@ -267,9 +473,107 @@ impl TypedefFieldType {
             Self::defined(_) => true,
         }
     }
+
+    /// The `defined` type this is or contains
+    pub fn defined_type(&self) -> Option<&str> {
+        match self {
+            Self::PrimitiveOrPubkey(_) => None,
+            Self::array(a) => a.0.defined_type(),
+            Self::option(o) => o.defined_type(),
+            Self::vec(v) => v.defined_type(),
+            Self::defined(s) => Some(s),
+        }
+    }
+
+    /// Replaces `defined` type names that are keys of `renames`
+    pub fn rename_defined(&mut self, renames: &HashMap<&str, &str>) {
+        match self {
+            Self::PrimitiveOrPubkey(_) => {}
+            Self::defined(s) => {
+                if let Some(rename) = renames.get(s.as_str()) {
+                    *s = (*rename).to_owned();
+                }
+            }
+            Self::array(a) => a.0.rename_defined(renames),
+            Self::option(o) => o.rename_defined(renames),
+            Self::vec(v) => v.rename_defined(renames),
+        }
+    }
+
+    /// Replaces `defined` and primitive types that are keys of `mappings` with the mapped rust type,
+    /// adding the rust types used to `used`
+    pub fn map_types(&mut self, mappings: &HashMap<&str, &str>, used: &mut BTreeSet<String>) {
+        match self {
+            Self::PrimitiveOrPubkey(s) | Self::defined(s) => {
+                if let Some(path) = mappings.get(s.as_str()) {
+                    used.insert((*path).to_owned());
+                    *self = Self::PrimitiveOrPubkey((*path).to_owned());
+                }
+            }
+            Self::array(a) => a.0.map_types(mappings, used),
+            Self::option(o) => o.map_types(mappings, used),
+            Self::vec(v) => v.map_types(mappings, used),
+        }
+    }
+
+    pub fn is_or_has_large_array(&self) -> bool {
+        match self {
+            Self::PrimitiveOrPubkey(_) | Self::defined(_) => false,
+            Self::array(a) => a.1 > SERDE_MAX_ARRAY_LEN || a.0.is_or_has_large_array(),
+            Self::option(o) => o.is_or_has_large_array(),
+            Self::vec(v) => v.is_or_has_large_array(),
+        }
+    }
+
+    /// The `serde_with::As` type param for this type,
+    /// e.g. `Option<[[serde_with::Same; 64]; 2]>`
+    pub fn serde_with_as_type(&self) -> String {
+        match self {
+            Self::PrimitiveOrPubkey(_) | Self::defined(_) => "serde_with::Same".to_owned(),
+            Self::array(a) => format!("[{}; {}]", a.0.serde_with_as_type(), a.1),
+            Self::option(o) => format!("Option<{}>", o.serde_with_as_type()),
+            Self::vec(v) => format!("Vec<{}>", v.serde_with_as_type()),
+        }
+    }
+
+    /// `serde(with = ...)` field attribute for arrays longer than serde's derive supports.
+    /// Empty if no adapter is required.
+    pub fn serde_with_attr(&self) -> TokenStream {
+        if !self.is_or_has_large_array() {
+            return quote! {};
+        }
+        let with = format!("serde_with::As::<{}>", self.serde_with_as_type());
+        quote! {
+            #[serde(with = #with)]
+        }
+    }
 }
 
 impl EnumVariant {
+    pub fn validate(&self, item: &str) -> crate::Result<()> {
+        let item = format!("{item} variant {}", self.name);
+        check_ident(&item, &pascal_case(&self.name))?;
+        match &self.fields {
+            None => Ok(()),
+            Some(EnumVariantFields::Struct(v)) => v.iter().try_for_each(|f| f.validate(&item)),
+            Some(EnumVariantFields::Tuple(v)) => v.iter().try_for_each(|f| f.0.validate(&item)),
+        }
+    }
+
+    pub fn lint(&self, item: &str, type_names: &HashSet<&str>, lints: &mut Vec<Lint>) {
+        let item = format!("{item} variant {}", self.name);
+        check_keyword(lints, &item, &pascal_case(&self.name));
+        match &self.fields {
+            None => {}
+            Some(EnumVariantFields::Struct(v)) => {
+                v.iter().for_each(|f| f.lint(&item, type_names, lints))
+            }
+            Some(EnumVariantFields::Tuple(v)) => {
+                v.iter().for_each(|f| f.0.lint(&item, type_names, lints))
+            }
+        }
+    }
+
     pub fn has_pubkey(&self) -> bool {
         match &self.fields {
             None => false,
@@ -283,4 +587,11 @@ impl EnumVariant {
             Some(fields) => fields.has_defined(),
         }
     }
+
+    pub fn has_large_array(&self) -> bool {
+        match &self.fields {
+            None => false,
+            Some(fields) => fields.has_large_array(),
+        }
+    }
 }
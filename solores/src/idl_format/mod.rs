@@ -1,8 +1,15 @@
 #![doc = include_str!("./README.md")]
 
+use std::fmt;
+
 use proc_macro2::TokenStream;
+use serde::{Deserialize, Deserializer, Serialize};
 use toml::{map::Map, Value};
 
+use crate::{filter::Filters, lint::Lint, Error, Result};
+
+use self::{anchor::AnchorIdl, bincode::BincodeIdl, shank::ShankIdl};
+
 pub mod anchor;
 pub mod bincode;
 pub mod shank;
@@ -13,10 +20,24 @@ pub trait IdlCodegenModule {
 
     /// Generate the headers to prefix the module file with.
     /// Typically import statements
-    fn gen_head(&self) -> TokenStream;
+    fn gen_head(&self) -> Result<TokenStream>;
 
     /// Generate the main body content of the module file
-    fn gen_body(&self) -> TokenStream;
+    fn gen_body(&self) -> Result<TokenStream>;
+
+    /// Generate the main body content as one (item's rust name, contents) per item,
+    /// for modules that `--split-modules` writes as one file per item.
+    /// `None` if the module is always written as a single file.
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(None)
+    }
+
+    /// `macro_rules!` definitions that the body invokes, written verbatim between
+    /// the head and the body since prettyplease doesn't format macro bodies.
+    /// Empty if the body doesn't invoke any.
+    fn gen_macros(&self) -> String {
+        String::new()
+    }
 }
 
 pub trait IdlFormat {
@@ -26,9 +47,133 @@ pub trait IdlFormat {
 
     fn program_address(&self) -> Option<&str>;
 
-    fn is_correct_idl_format(&self) -> bool;
+    /// The IDL's JSON as loaded, which the generated crate embeds
+    fn json(&self) -> &str;
+
+    fn set_json(&mut self, json: String);
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value>;
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>>;
+    /// The generated crate's `[features]` table
+    fn features(&self, args: &crate::Args) -> Map<String, Value>;
+
+    /// Removes the instructions, accounts and events not selected by `filters`
+    /// and the typedefs that are no longer referenced. See [`crate::filter`].
+    /// Must be called before [`IdlFormat::apply_item_configs`].
+    fn apply_filters(&mut self, filters: &Filters);
+
+    /// Applies the per-item customisations in `args` to the deserialized IDL.
+    /// Must be called before codegen.
+    fn apply_item_configs(&mut self, args: &crate::Args);
+
+    /// Checks that all of the IDL's items can be generated
+    /// before returning their codegen modules
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+    ) -> Result<Vec<Box<dyn IdlCodegenModule + 'me>>>;
+
+    /// (IDL name, generated name) of the typedefs and accounts that `defined` types can refer to
+    fn defined_types(&self) -> Vec<(String, String)>;
+
+    /// Semantic problems with the IDL as deserialized,
+    /// before [`IdlFormat::apply_item_configs`]
+    fn lint(&self) -> Vec<Lint>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdlFormatKind {
+    /// Anchor's legacy (pre-0.30) IDL format
+    #[value(alias = "anchor-legacy")]
+    #[serde(alias = "anchor-legacy")]
+    Anchor,
+    Shank,
+    Bincode,
+}
+
+impl fmt::Display for IdlFormatKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Anchor => "anchor",
+            Self::Shank => "shank",
+            Self::Bincode => "bincode",
+        })
+    }
+}
+
+impl IdlFormatKind {
+    /// Shank and bincode IDLs are identified by `metadata.origin`.
+    /// Anchor IDLs dont seem to have an identifier,
+    /// so unidentified IDLs are assumed to be anchor.
+    pub fn detect(idl: &serde_json::Value) -> Self {
+        match idl.pointer("/metadata/origin").and_then(|o| o.as_str()) {
+            Some("shank") => Self::Shank,
+            Some("bincode") => Self::Bincode,
+            _ => Self::Anchor,
+        }
+    }
+
+    /// Deserializes an IDL of this format from `deserializer`.
+    ///
+    /// If `strict`, fields that are not used by solores are rejected
+    /// instead of being ignored.
+    pub fn deserialize<'de, D>(self, deserializer: D, strict: bool) -> Result<Box<dyn IdlFormat>>
+    where
+        D: Deserializer<'de, Error = serde_json::Error>,
+    {
+        let res: Box<dyn IdlFormat> = match self {
+            Self::Anchor => Box::new(self.deserialize_as::<AnchorIdl, _>(deserializer, strict)?),
+            Self::Shank => Box::new(self.deserialize_as::<ShankIdl, _>(deserializer, strict)?),
+            Self::Bincode => Box::new(self.deserialize_as::<BincodeIdl, _>(deserializer, strict)?),
+        };
+        log::info!("Successfully loaded {self} IDL");
+        Ok(res)
+    }
+
+    fn deserialize_as<'de, T, D>(self, deserializer: D, strict: bool) -> Result<T>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de, Error = serde_json::Error>,
+    {
+        let mut track = serde_path_to_error::Track::new();
+        let mut unknown_fields = Vec::new();
+        let res = serde_ignored::deserialize(
+            serde_path_to_error::Deserializer::new(deserializer, &mut track),
+            |path| unknown_fields.push(format_ignored_path(&path)),
+        );
+        let idl = res.map_err(|source| Error::InvalidIdl {
+            format: self,
+            path: track.path().to_string(),
+            source,
+        })?;
+        if strict && !unknown_fields.is_empty() {
+            return Err(Error::UnknownIdlFields {
+                format: self,
+                fields: unknown_fields,
+            });
+        }
+        Ok(idl)
+    }
+}
+
+/// Formats `path` the same way as [`serde_path_to_error::Path`], e.g. `instructions[0].args`
+fn format_ignored_path(path: &serde_ignored::Path) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => {
+            format!("{}[{index}]", format_ignored_path(parent))
+        }
+        serde_ignored::Path::Map { parent, key } => {
+            let parent = format_ignored_path(parent);
+            if parent.is_empty() {
+                key.clone()
+            } else {
+                format!("{parent}.{key}")
+            }
+        }
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => format_ignored_path(parent),
+    }
 }
@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::idl_format::IdlCodegenModule;
+use crate::{idl_format::IdlCodegenModule, naming::ident, Result};
 
 use super::typedefs::NamedType;
 
@@ -15,7 +15,7 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
         "accounts"
     }
 
-    fn gen_head(&self) -> TokenStream {
+    fn gen_head(&self) -> Result<TokenStream> {
         let mut res = quote! {
             use borsh::{BorshDeserialize, BorshSerialize};
         };
@@ -46,13 +46,37 @@ impl IdlCodegenModule for AccountsCodegenModule<'_> {
                 break;
             }
         }
-        res
+        Ok(res)
     }
 
-    fn gen_body(&self) -> TokenStream {
+    fn gen_body(&self) -> Result<TokenStream> {
+        Ok(self.items().into_iter().map(|(_, tokens)| tokens).collect())
+    }
+
+    fn gen_body_items(&self) -> Result<Option<Vec<(String, TokenStream)>>> {
+        Ok(Some(self.items()))
+    }
+}
+
+impl AccountsCodegenModule<'_> {
+    /// (rust name, items) of each account
+    fn items(&self) -> Vec<(String, TokenStream)> {
         self.named_types
             .iter()
-            .map(|e| e.to_token_stream(self.cli_args))
+            .map(|e| {
+                let mut res = e.to_token_stream(self.cli_args);
+                let name = &e.name;
+                let ident = ident(&e.rust_name());
+                // shank accounts have no discriminator
+                res.extend(quote! {
+                    #[cfg(feature = "runtime")]
+                    impl solores_runtime::ProgramAccount for #ident {
+                        const NAME: &'static str = #name;
+                        const DISCM: &'static [u8] = &[];
+                    }
+                });
+                (e.rust_name(), res)
+            })
             .collect()
     }
 }
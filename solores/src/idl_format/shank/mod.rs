@@ -1,9 +1,23 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 use serde::Deserialize;
 use toml::{map::Map, Value};
 
-use crate::write_cargotoml::{
-    DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE, BYTEMUCK_CRATE,
-    NUM_DERIVE_CRATE, NUM_TRAITS_CRATE, SERDE_CRATE, SOLANA_PROGRAM_CRATE, THISERROR_CRATE,
+use crate::{
+    config::warn_unmatched,
+    filter::{referenced_types, retain_items, Filters},
+    lint::{check_duplicate_names, check_keyword, collisions, Lint},
+    naming::{pascal_case, ItemNames},
+    solana_crates::SolanaCratesUsage,
+    target::Target,
+    utils::{check_ident, MAX_TYPE_DEPTH},
+    write_cargotoml::{
+        borsh_dependency, mapped_type_dependency, num_traits_dependency, serde_dependency,
+        DependencyValue, FeaturesDependencyValue, OptionalDependencyValue, BORSH_CRATE,
+        BYTEMUCK_CRATE, CLIENT_FEATURE, CPI_FEATURE, NUM_DERIVE_CRATE, NUM_TRAITS_CRATE,
+        PINOCCHIO_CRATE, RUNTIME_FEATURE, SERDE_CRATE, SERDE_WITH_CRATE, SOLORES_RUNTIME_CRATE,
+        THISERROR_CRATE,
+    },
 };
 
 use super::{IdlCodegenModule, IdlFormat};
@@ -12,7 +26,7 @@ use self::{
     accounts::AccountsCodegenModule,
     errors::{ErrorEnumVariant, ErrorsCodegenModule},
     instructions::{IxCodegenModule, NamedInstruction},
-    typedefs::{NamedType, TypedefsCodegenModule},
+    typedefs::{NamedType, TypedefFieldType, TypedefsCodegenModule},
 };
 
 pub mod accounts;
@@ -29,6 +43,15 @@ pub struct ShankIdl {
     pub types: Option<Vec<NamedType>>,
     pub instructions: Option<Vec<NamedInstruction>>,
     pub errors: Option<Vec<ErrorEnumVariant>>,
+    /// The IDL's JSON as loaded, set by [`crate::load_idl_str`] and [`crate::load_idl_value`]
+    #[serde(skip)]
+    pub json: String,
+    /// Rust types of the `--map-type` mappings used by the IDL, set by `apply_item_configs()`
+    #[serde(skip)]
+    pub mapped_types: BTreeSet<String>,
+    /// Whether any instructions were removed by `apply_filters()`
+    #[serde(skip)]
+    pub has_filtered_instructions: bool,
 }
 
 #[derive(Deserialize)]
@@ -37,11 +60,92 @@ pub struct Metadata {
     pub origin: String,
 }
 
+impl ShankIdl {
+    /// Checks that all of the IDL's items can be generated
+    pub fn validate(&self) -> crate::Result<()> {
+        check_ident("program", &pascal_case(&self.name))?;
+        for t in self.types.iter().flatten() {
+            t.validate("typedef")?;
+        }
+        for a in self.accounts.iter().flatten() {
+            a.validate("account")?;
+        }
+        for ix in self.instructions.iter().flatten() {
+            ix.validate()?;
+        }
+        for e in self.errors.iter().flatten() {
+            e.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Whether any serde-derived generated struct has an array field
+    /// that requires a serde_with adapter
+    pub fn has_large_array(&self) -> bool {
+        self.accounts
+            .iter()
+            .flatten()
+            .any(|a| a.r#type.has_large_array_field())
+            || self
+                .types
+                .iter()
+                .flatten()
+                .any(|t| t.r#type.has_large_array_field())
+            || self
+                .instructions
+                .iter()
+                .flatten()
+                .any(|ix| ix.args_has_large_array())
+    }
+
+    /// What the generated code uses from the solana crates
+    pub fn solana_crates_usage(&self) -> SolanaCratesUsage {
+        let instructions = self.instructions.iter().flatten();
+        SolanaCratesUsage {
+            has_instructions: self.instructions.is_some(),
+            has_ix_accounts: instructions.clone().any(|ix| ix.has_accounts()),
+            has_errors: self.errors.is_some(),
+            has_pubkey_fields: self
+                .types
+                .iter()
+                .flatten()
+                .chain(self.accounts.iter().flatten())
+                .any(|t| t.r#type.has_pubkey_field())
+                || instructions.clone().any(|ix| ix.args_has_pubkeys()),
+            is_bincode: false,
+        }
+    }
+
+    /// Max borsh-serialized length of the typedef or account generated as `name`, `None` if
+    /// unbounded, not generated from the IDL or nested deeper than [`MAX_TYPE_DEPTH`]
+    pub fn defined_borsh_max_len(&self, name: &str, depth: usize) -> Option<usize> {
+        if depth > MAX_TYPE_DEPTH {
+            return None;
+        }
+        let ty = self
+            .types
+            .iter()
+            .flatten()
+            .chain(self.accounts.iter().flatten())
+            .find(|t| t.rust_name() == name)?;
+        ty.r#type
+            .borsh_max_len(&|name| self.defined_borsh_max_len(name, depth + 1))
+    }
+}
+
 impl IdlFormat for ShankIdl {
     fn program_name(&self) -> &str {
         &self.name
     }
 
+    fn json(&self) -> &str {
+        &self.json
+    }
+
+    fn set_json(&mut self, json: String) {
+        self.json = json;
+    }
+
     fn program_version(&self) -> &str {
         &self.version
     }
@@ -50,11 +154,177 @@ impl IdlFormat for ShankIdl {
         Some(&self.metadata.address)
     }
 
-    fn is_correct_idl_format(&self) -> bool {
-        self.metadata.origin == "shank"
+    fn apply_filters(&mut self, filters: &Filters) {
+        if !filters.is_set() {
+            return;
+        }
+        self.has_filtered_instructions = retain_items(&mut self.instructions, |ix| {
+            filters.instructions.matches(&ix.name)
+        }) > 0;
+
+        let roots = self
+            .instructions
+            .iter()
+            .flatten()
+            .flat_map(|ix| ix.args.iter().flatten())
+            .filter_map(|arg| arg.r#type.defined_type())
+            .chain(
+                self.accounts
+                    .iter()
+                    .flatten()
+                    .filter(|a| filters.accounts.matches(&a.name))
+                    .map(|a| a.name.as_str()),
+            )
+            .chain(
+                self.types
+                    .iter()
+                    .flatten()
+                    .filter(|t| filters.is_typedef_root(&t.name))
+                    .map(|t| t.name.as_str()),
+            )
+            .map(ToOwned::to_owned);
+        let referenced = referenced_types(roots, |name| {
+            self.types
+                .iter()
+                .flatten()
+                .chain(self.accounts.iter().flatten())
+                .filter(|t| t.name == name)
+                .flat_map(|t| t.r#type.defined_types())
+                .map(ToOwned::to_owned)
+                .collect()
+        });
+
+        retain_items(&mut self.accounts, |a| {
+            let keep = referenced.contains(&a.name);
+            if keep && !filters.accounts.matches(&a.name) {
+                log::warn!(
+                    "Generating filtered out account {} since generated items reference it",
+                    a.name
+                );
+            }
+            keep
+        });
+        retain_items(&mut self.types, |t| referenced.contains(&t.name));
     }
 
-    fn modules<'me>(&'me self, args: &'me crate::Args) -> Vec<Box<dyn IdlCodegenModule + 'me>> {
+    fn apply_item_configs(&mut self, args: &crate::Args) {
+        warn_unmatched(
+            "typedef",
+            &args.typedef_configs,
+            self.types.iter().flatten().map(|t| t.name.as_str()),
+        );
+        warn_unmatched(
+            "account",
+            &args.account_configs,
+            self.accounts.iter().flatten().map(|a| a.name.as_str()),
+        );
+        warn_unmatched(
+            "instruction",
+            &args.instruction_configs,
+            self.instructions
+                .iter()
+                .flatten()
+                .map(|ix| ix.name.as_str()),
+        );
+
+        // typedefs mapped to external types are not generated
+        let mappings: HashMap<&str, &str> = args
+            .map_type
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_str()))
+            .collect();
+        if let Some(types) = self.types.as_mut() {
+            let n_types = types.len();
+            types.retain(|t| !mappings.contains_key(t.name.as_str()));
+            if n_types > 0 && types.is_empty() {
+                self.types = None;
+            }
+        }
+
+        for t in self.types.iter_mut().flatten() {
+            if let Some(config) = args.typedef_configs.get(&t.name) {
+                t.apply_config(config);
+            }
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            if let Some(config) = args.account_configs.get(&a.name) {
+                a.apply_config(config);
+            }
+        }
+        for ix in self.instructions.iter_mut().flatten() {
+            if let Some(config) = args.instruction_configs.get(&ix.name) {
+                ix.apply_config(config);
+            }
+        }
+
+        // Disambiguate items whose generated names collide. Instructions go first
+        // so that their helpers keep the names derived from the IDL.
+        let mut item_names = ItemNames::new(self.program_name());
+        for ix in self.instructions.iter_mut().flatten() {
+            ix.warn_duplicate_accounts();
+            if let Some(rename) = item_names.reserve(
+                &format!("instruction {}", ix.name),
+                ix.rust_name(),
+                NamedInstruction::item_names,
+            ) {
+                ix.config.rename = Some(rename);
+            }
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            if let Some(rename) =
+                item_names.reserve(&format!("account {}", a.name), &a.rust_name(), |n| {
+                    vec![n.to_owned()]
+                })
+            {
+                a.config.rename = Some(rename);
+            }
+        }
+        for t in self.types.iter_mut().flatten() {
+            if let Some(rename) =
+                item_names.reserve(&format!("typedef {}", t.name), &t.rust_name(), |n| {
+                    vec![n.to_owned()]
+                })
+            {
+                t.config.rename = Some(rename);
+            }
+        }
+
+        // `defined` types are IDL names. Replace them with the generated names,
+        // which differ if renamed or converted to valid identifiers
+        let rust_names = self.defined_types();
+        // the first of any types with duplicate IDL names is the one referenced
+        let mut renames: HashMap<&str, &str> = HashMap::new();
+        for (name, rust_name) in rust_names.iter() {
+            renames.entry(name.as_str()).or_insert(rust_name.as_str());
+        }
+        renames.retain(|name, rust_name| name != rust_name);
+        let mut mapped_types = BTreeSet::new();
+        let mut map_and_rename = |ty: &mut TypedefFieldType| {
+            ty.map_types(&mappings, &mut mapped_types);
+            ty.rename_defined(&renames);
+        };
+        for t in self.types.iter_mut().flatten() {
+            t.r#type.for_each_field_type_mut(&mut map_and_rename);
+        }
+        for a in self.accounts.iter_mut().flatten() {
+            a.r#type.for_each_field_type_mut(&mut map_and_rename);
+        }
+        for arg in self
+            .instructions
+            .iter_mut()
+            .flatten()
+            .flat_map(|ix| ix.args.iter_mut().flatten())
+        {
+            map_and_rename(&mut arg.r#type);
+        }
+        self.mapped_types = mapped_types;
+    }
+
+    fn modules<'me>(
+        &'me self,
+        args: &'me crate::Args,
+    ) -> crate::Result<Vec<Box<dyn IdlCodegenModule + 'me>>> {
+        self.validate()?;
         let mut res: Vec<Box<dyn IdlCodegenModule + 'me>> = Vec::new();
         if let Some(v) = &self.accounts {
             res.push(Box::new(AccountsCodegenModule {
@@ -72,20 +342,91 @@ impl IdlFormat for ShankIdl {
             res.push(Box::new(IxCodegenModule {
                 program_name: self.program_name(),
                 instructions: v,
+                has_unknown_variant: self.has_filtered_instructions,
+                target: args.target,
+                data_max_lens: match args.target {
+                    Target::SolanaProgram => Vec::new(),
+                    Target::Pinocchio => v
+                        .iter()
+                        .map(|ix| ix.data_max_len(&|name| self.defined_borsh_max_len(name, 0)))
+                        .collect(),
+                },
+                compact: args.compact,
             }));
         }
         if let Some(v) = &self.errors {
             res.push(Box::new(ErrorsCodegenModule {
                 program_name: self.program_name(),
                 variants: v,
+                no_std: args.no_std,
+                target: args.target,
             }));
         }
-        res
+        Ok(res)
+    }
+
+    fn defined_types(&self) -> Vec<(String, String)> {
+        self.types
+            .iter()
+            .flatten()
+            .chain(self.accounts.iter().flatten())
+            .map(|t| (t.name.clone(), t.rust_name()))
+            .collect()
+    }
+
+    fn lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        check_keyword(&mut lints, "program", &pascal_case(&self.name));
+
+        let types = self.types.iter().flatten();
+        let accounts = self.accounts.iter().flatten();
+        let instructions = self.instructions.iter().flatten();
+        let errors = self.errors.iter().flatten();
+
+        let type_names: HashSet<&str> = types
+            .clone()
+            .chain(accounts.clone())
+            .map(|t| t.name.as_str())
+            .collect();
+
+        check_duplicate_names(
+            &mut lints,
+            "typedef",
+            types.clone().map(|t| t.name.as_str()),
+        );
+        check_duplicate_names(
+            &mut lints,
+            "account",
+            accounts.clone().map(|a| a.name.as_str()),
+        );
+        check_duplicate_names(
+            &mut lints,
+            "instruction",
+            instructions.clone().map(|ix| ix.name.as_str()),
+        );
+        check_duplicate_names(&mut lints, "error", errors.clone().map(|e| e.name.as_str()));
+
+        types.for_each(|t| t.lint("typedef", &type_names, &mut lints));
+        accounts.for_each(|a| a.lint("account", &type_names, &mut lints));
+        instructions
+            .clone()
+            .for_each(|ix| ix.lint(&type_names, &mut lints));
+        errors.for_each(|e| e.lint(&mut lints));
+
+        let discms = instructions.map(|ix| (ix.discriminant.value, ix.name.clone()));
+        for (discm, instructions) in collisions(discms) {
+            lints.push(Lint::DuplicateDiscriminant {
+                discm,
+                instructions,
+            });
+        }
+
+        lints
     }
 
     fn dependencies(&self, args: &crate::Args) -> Map<String, Value> {
         let mut map = Map::new();
-        map.insert(BORSH_CRATE.into(), DependencyValue(&args.borsh_vers).into());
+        map.insert(BORSH_CRATE.into(), borsh_dependency(args));
         if !args.zero_copy.is_empty() {
             map.insert(
                 BYTEMUCK_CRATE.into(),
@@ -96,27 +437,71 @@ impl IdlFormat for ShankIdl {
                 .into(),
             );
         }
+        map.extend(self.solana_crates_usage().dependencies(args));
         map.insert(
-            SOLANA_PROGRAM_CRATE.into(),
-            DependencyValue(&args.solana_program_vers).into(),
+            SERDE_CRATE.into(),
+            OptionalDependencyValue(serde_dependency(args)).into(),
         );
+        if args.target == Target::Pinocchio {
+            map.insert(
+                PINOCCHIO_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.pinocchio_vers)).into(),
+            );
+        }
         map.insert(
-            SERDE_CRATE.into(),
-            OptionalDependencyValue(DependencyValue(&args.serde_vers)).into(),
+            SOLORES_RUNTIME_CRATE.into(),
+            OptionalDependencyValue(DependencyValue(&args.solores_runtime_vers)).into(),
         );
-        if self.errors.is_some() {
+        if self.has_large_array() {
             map.insert(
-                THISERROR_CRATE.into(),
-                DependencyValue(&args.thiserror_vers).into(),
+                SERDE_WITH_CRATE.into(),
+                OptionalDependencyValue(DependencyValue(&args.serde_with_vers)).into(),
             );
+        }
+        if self.errors.is_some() {
+            // no_std crates implement the error traits without thiserror
+            if !args.no_std {
+                map.insert(
+                    THISERROR_CRATE.into(),
+                    DependencyValue(&args.thiserror_vers).into(),
+                );
+            }
             map.insert(
                 NUM_DERIVE_CRATE.into(),
                 DependencyValue(&args.num_derive_vers).into(),
             );
-            map.insert(
-                NUM_TRAITS_CRATE.into(),
-                DependencyValue(&args.num_traits_vers).into(),
-            );
+            map.insert(NUM_TRAITS_CRATE.into(), num_traits_dependency(args));
+        }
+        for path in self.mapped_types.iter() {
+            if let Some((name, vers)) = mapped_type_dependency(args, path) {
+                map.insert(name, DependencyValue(vers).into());
+            }
+        }
+        map
+    }
+
+    fn features(&self, args: &crate::Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        let solana_crates_usage = self.solana_crates_usage();
+        let mut cpi_dependencies = match args.target {
+            Target::SolanaProgram => Vec::new(),
+            Target::Pinocchio => vec![format!("dep:{PINOCCHIO_CRATE}")],
+        };
+        cpi_dependencies.extend(solana_crates_usage.cpi_features(args));
+        map.insert(CPI_FEATURE.into(), cpi_dependencies.into());
+        map.insert(CLIENT_FEATURE.into(), Value::Array(Vec::new()));
+        map.insert(
+            RUNTIME_FEATURE.into(),
+            vec![format!("dep:{SOLORES_RUNTIME_CRATE}")].into(),
+        );
+        let serde_features = solana_crates_usage.serde_features(args);
+        if self.has_large_array() || !serde_features.is_empty() {
+            let mut serde_dependencies = vec![format!("dep:{SERDE_CRATE}")];
+            if self.has_large_array() {
+                serde_dependencies.push(format!("dep:{SERDE_WITH_CRATE}"));
+            }
+            serde_dependencies.extend(serde_features);
+            map.insert(SERDE_CRATE.into(), serde_dependencies.into());
         }
         map
     }
@@ -1,56 +1,120 @@
 #![doc = include_str!("../README.md")]
 
 use std::{
+    collections::{HashMap, HashSet},
     env,
     fs::{self, File, OpenOptions},
-    io::Seek,
-    path::PathBuf,
+    io::Read,
+    path::{Path, PathBuf},
 };
 
-use clap::{command, Parser};
-use idl_format::{bincode::BincodeIdl, IdlFormat};
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
+use config::{Config, ItemConfig, DEFAULT_CONFIG_FILE_NAME};
+use filter::Filters;
+use idl_format::{IdlFormat, IdlFormatKind};
+use metadata::{write_idl, GenerationMetadata};
+use output::Output;
+use target::Target;
 
-use crate::idl_format::{anchor::AnchorIdl, shank::ShankIdl};
+pub use error::{Error, Result};
+pub use generator::Generator;
 
 // Just make all mods pub to allow ppl to use the lib
 
+pub mod compact;
+pub mod config;
+pub mod error;
+pub mod extern_crate;
+pub mod filter;
+pub mod generator;
 pub mod idl_format;
+pub mod lint;
+pub mod metadata;
+pub mod module;
+pub mod naming;
+pub mod output;
+pub mod solana_crates;
+pub mod target;
 pub mod utils;
 pub mod write_cargotoml;
 pub mod write_gitignore;
 pub mod write_src;
+pub mod write_workspace;
 
-use write_cargotoml::write_cargotoml;
+use write_cargotoml::{write_cargotoml, ModuleManifest};
 use write_gitignore::write_gitignore;
 use write_src::*;
+use write_workspace::{workspace_member_args, write_workspace_cargotoml, GeneratedCrate};
 
 const DEFAULT_OUTPUT_CRATE_NAME_MSG: &str = "<name-of-program>_interface";
 const DEFAULT_PROGRAM_ID_MSG: &str = "program ID in IDL else system program ID if absent";
 const RUST_LOG_ENV_VAR: &str = "RUST_LOG";
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
-    pub idl_path: PathBuf,
+    #[arg(
+        help = "paths to IDL files or directories containing IDL files. Required if not set in config file. If more than one IDL is found, all crates are generated into a cargo workspace in output-dir"
+    )]
+    pub idl_paths: Vec<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
 
     #[arg(
         long,
         short,
-        help = "directory to output generated crate to",
+        help = "path to solores.toml config file. Defaults to ./solores.toml if it exists. Command-line args override values in the config file"
+    )]
+    pub config: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_enum,
+        global = true,
+        help = "IDL format. Detected from the IDL's metadata.origin field if not set, defaulting to anchor"
+    )]
+    pub format: Option<IdlFormatKind>,
+
+    #[arg(
+        long,
+        global = true,
+        help = "fail if the IDL contains fields that are not supported by solores instead of ignoring them"
+    )]
+    pub strict: bool,
+
+    #[arg(
+        long,
+        short,
+        help = "directory to output generated crate to, or workspace root if generating multiple crates",
         default_value = "./"
     )]
     pub output_dir: PathBuf,
 
     #[arg(
         long,
-        help = "output crate name",
+        help = "generate in memory and compare with the files in output-dir instead of writing them. Prints a diff and exits with a nonzero code if any file is outdated"
+    )]
+    pub check: bool,
+
+    #[arg(
+        long,
+        help = "output crate name. Cannot be used with multiple IDLs",
         default_value = DEFAULT_OUTPUT_CRATE_NAME_MSG,
     )]
     pub output_crate_name: String,
 
-    #[arg(long, short, help = "program ID / address / pubkey", default_value = DEFAULT_PROGRAM_ID_MSG)]
+    #[arg(long, short, help = "program ID / address / pubkey. Cannot be used with multiple IDLs", default_value = DEFAULT_PROGRAM_ID_MSG)]
     pub program_id: Option<String>,
 
+    #[arg(
+        long,
+        value_name = "FEATURE=PUBKEY",
+        value_parser = utils::parse_key_val,
+        help = "program ID when the generated crate's FEATURE is enabled e.g. devnet=<pubkey>, added to the generated Cargo.toml's features. The first enabled FEATURE takes precedence. Cannot be used with multiple IDLs"
+    )]
+    pub program_id_for: Vec<(String, String)>,
+
     #[arg(
         long,
         short,
@@ -108,6 +172,206 @@ pub struct Args {
         default_value = "^1.16"
     )]
     pub bytemuck_vers: String,
+
+    #[arg(
+        long,
+        help = "serde_with dependency version for generated crate",
+        default_value = "^3.9"
+    )]
+    pub serde_with_vers: String,
+
+    #[arg(
+        long,
+        help = "solana-rpc-client-api dependency version for generated crate's client feature",
+        default_value = "^2.0"
+    )]
+    pub solana_rpc_client_api_vers: String,
+
+    #[arg(
+        long,
+        help = "pinocchio dependency version for generated crate's cpi feature with --target pinocchio",
+        default_value = "^0.9"
+    )]
+    pub pinocchio_vers: String,
+
+    #[arg(
+        long,
+        help = "version of each of the generated crate's split solana crate dependencies with --split-solana-crates",
+        default_value = "^2.2"
+    )]
+    pub split_solana_crates_vers: String,
+
+    #[arg(
+        long,
+        help = "anchor-lang dependency version for generated crate's anchor feature. Only used for anchor IDLs",
+        default_value = "^0.31"
+    )]
+    pub anchor_lang_vers: String,
+
+    #[arg(
+        long,
+        help = "solores-runtime dependency version for generated crate's runtime feature",
+        default_value = "^0.1"
+    )]
+    pub solores_runtime_vers: String,
+
+    #[arg(
+        long,
+        value_name = "NAME=PATH",
+        value_parser = utils::parse_key_val,
+        help = "use the rust type at PATH for IDL defined or primitive type NAME instead of generating it e.g. I80F48=fixed::types::I80F48. The crate in PATH is added as a dependency"
+    )]
+    pub map_type: Vec<(String, String)>,
+
+    #[arg(
+        long,
+        value_name = "CRATE=VERSION",
+        value_parser = utils::parse_key_val,
+        help = "dependency version for a crate in --map-type paths. Defaults to *"
+    )]
+    pub map_type_vers: Vec<(String, String)>,
+
+    #[arg(
+        long,
+        help = "directory of a generated interface crate, or its IDL, to import the types of instead of generating them. The crate is added as a dependency, by path for directories or by the IDL's version"
+    )]
+    pub extern_crate: Vec<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "instructions to generate, as glob patterns matched against IDL names. Defaults to all"
+    )]
+    pub only_ix: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "instructions not to generate, as glob patterns matched against IDL names"
+    )]
+    pub exclude_ix: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "accounts to generate, as glob patterns matched against IDL names. Defaults to all. Accounts referenced by generated items are always generated"
+    )]
+    pub only_account: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "accounts not to generate, as glob patterns matched against IDL names"
+    )]
+    pub exclude_account: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "events to generate, as glob patterns matched against IDL names. Defaults to all"
+    )]
+    pub only_event: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "events not to generate, as glob patterns matched against IDL names"
+    )]
+    pub exclude_event: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "typedefs to generate in addition to the ones referenced by generated items, as glob patterns matched against IDL names. If any filter is set, only referenced typedefs are generated by default"
+    )]
+    pub only_type: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "PATTERN",
+        help = "typedefs not to generate unless referenced by generated items, as glob patterns matched against IDL names"
+    )]
+    pub exclude_type: Vec<String>,
+
+    #[arg(
+        long,
+        help = "generate a #![no_std] crate that uses alloc, borsh's no_std I/O types and an error type that doesn't depend on std"
+    )]
+    pub no_std: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        help = "SDK for the on-chain items behind the generated crate's cpi feature: *Accounts structs, *_invoke*() and *_verify_*() functions"
+    )]
+    pub target: Target,
+
+    #[arg(
+        long,
+        help = "depend on the split solana crates used by the generated code, such as solana-pubkey and solana-instruction, instead of solana-program"
+    )]
+    pub split_solana_crates: bool,
+
+    #[arg(
+        long,
+        help = "path that the generated code imports solana_program items from, e.g. anchor_lang::solana_program. The crate it is in replaces the solana-program dependency",
+        default_value = solana_crates::SOLANA_PROGRAM_PATH,
+        conflicts_with = "split_solana_crates"
+    )]
+    pub solana_program_path: String,
+
+    #[arg(
+        long,
+        help = "write instructions, accounts and typedefs as one file per item in src/<module>/, re-exported by src/<module>/mod.rs"
+    )]
+    pub split_modules: bool,
+
+    #[arg(
+        long,
+        help = "define each instruction's items by invoking macro_rules! defined once in the instructions module instead of expanding them, shrinking the generated source"
+    )]
+    pub compact: bool,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "write the modules to output-dir as the module at PATH of an existing crate e.g. crate::my_program, with a mod.rs instead of lib.rs and without Cargo.toml. Prints the dependencies and features the crate must have. Cannot be used with multiple IDLs"
+    )]
+    pub module: Option<String>,
+
+    /// Per-typedef customisations from the config file, keyed by IDL name
+    #[arg(skip)]
+    pub typedef_configs: HashMap<String, ItemConfig>,
+
+    /// Per-account customisations from the config file, keyed by IDL name
+    #[arg(skip)]
+    pub account_configs: HashMap<String, ItemConfig>,
+
+    /// Per-instruction customisations from the config file, keyed by IDL name
+    #[arg(skip)]
+    pub instruction_configs: HashMap<String, ItemConfig>,
+
+    /// Names of the `map_type` entries added by `--extern-crate`
+    #[arg(skip)]
+    pub extern_crate_types: HashSet<String>,
+
+    /// Packages of the `map_type_vers` entries added by `--extern-crate`
+    #[arg(skip)]
+    pub extern_crate_packages: HashSet<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    #[command(
+        about = "report semantic problems in IDLs without generating anything. Exits with a nonzero code if any are found"
+    )]
+    Lint {
+        #[arg(
+            help = "paths to IDL files or directories containing IDL files. Defaults to the IDL paths in the config file"
+        )]
+        idl_paths: Vec<PathBuf>,
+    },
 }
 
 /// The CLI entrypoint
@@ -118,15 +382,138 @@ pub fn main() {
     env_logger::init();
     log_panics::init();
 
-    let mut args = Args::parse();
+    let matches = Args::command().get_matches();
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Err(e) = run(args, &matches) {
+        log::error!("{e}");
+        std::process::exit(1);
+    }
+}
+
+fn run(mut args: Args, matches: &ArgMatches) -> Result<()> {
+    let config_path = args.config.clone().or_else(|| {
+        let default_path = Path::new(DEFAULT_CONFIG_FILE_NAME);
+        default_path.exists().then(|| default_path.to_owned())
+    });
+    if let Some(config_path) = config_path {
+        let config = Config::load(&config_path)?;
+        config.merge_into(&mut args, matches);
+        log::info!("Loaded config file {}", config_path.to_string_lossy());
+    }
+
+    let idl_paths = match &args.command {
+        Some(Command::Lint { idl_paths }) if !idl_paths.is_empty() => idl_paths,
+        _ => &args.idl_paths,
+    };
+    let idl_paths = expand_idl_paths(idl_paths)?;
+    if idl_paths.is_empty() {
+        return Err(Error::MissingIdlPath);
+    }
+    extern_crate::map_extern_types(&mut args)?;
+    if matches!(args.command, Some(Command::Lint { .. })) {
+        return lint(&args, &idl_paths);
+    }
+
+    let out = match idl_paths.as_slice() {
+        [idl_path] if args.idl_paths.iter().all(|p| !p.is_dir()) => {
+            let mut out = Output::default();
+            let idl = load_idl_path(idl_path, &args)?;
+            gen_crate(args.clone(), idl, &mut out).map_err(|e| e.in_idl(idl_path))?;
+            out
+        }
+        _ => gen_workspace(&args, &idl_paths)?,
+    };
+    finish(&args, &out)
+}
+
+/// Writes `out` to disk, or compares it with the files on disk if `--check`
+fn finish(args: &Args, out: &Output) -> Result<()> {
+    if !args.check {
+        return out.write();
+    }
+    let mismatched = out.check()?;
+    if !mismatched.is_empty() {
+        return Err(Error::OutdatedFiles {
+            paths: mismatched.into_iter().map(Path::to_owned).collect(),
+        });
+    }
+    log::info!("all {} generated files up to date", out.files.len());
+    Ok(())
+}
+
+/// Prints the lints of all `idl_paths`, failing if there are any
+fn lint(args: &Args, idl_paths: &[PathBuf]) -> Result<()> {
+    let mut count = 0;
+    for idl_path in idl_paths {
+        let idl = load_idl_path(idl_path, args)?;
+        // types mapped to external types don't need to be defined in the IDL
+        let lints = idl.lint().into_iter().filter(|lint| {
+            !matches!(lint, lint::Lint::MissingType { name, .. } if args.map_type.iter().any(|(n, _)| n == name))
+        });
+        for lint in lints {
+            println!("{}: {lint}", idl_path.to_string_lossy());
+            count += 1;
+        }
+    }
+    if count > 0 {
+        return Err(Error::LintFailed { count });
+    }
+    log::info!("no problems found in {} IDLs", idl_paths.len());
+    Ok(())
+}
 
-    let mut file = OpenOptions::new().read(true).open(&args.idl_path).unwrap();
+/// Replaces directories in `paths` with the IDL files they contain:
+/// all `*.json` files in the directory and all `*/idl.json` files in its subdirectories.
+pub fn expand_idl_paths(paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut res = Vec::new();
+    for path in paths {
+        if !path.is_dir() {
+            res.push(path.clone());
+            continue;
+        }
+        let mut dir_idls = Vec::new();
+        for entry in fs::read_dir(path).map_err(|e| Error::io(path, e))? {
+            let entry_path = entry.map_err(|e| Error::io(path, e))?.path();
+            if entry_path.is_dir() {
+                let idl_path = entry_path.join("idl.json");
+                if idl_path.is_file() {
+                    dir_idls.push(idl_path);
+                }
+            } else if entry_path.extension().is_some_and(|ext| ext == "json") {
+                dir_idls.push(entry_path);
+            }
+        }
+        dir_idls.sort();
+        res.extend(dir_idls);
+    }
+    Ok(res)
+}
 
-    let idl = load_idl(&mut file);
+fn load_idl_path(idl_path: &Path, args: &Args) -> Result<Box<dyn IdlFormat>> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .open(idl_path)
+        .map_err(|e| Error::io(idl_path, e))?;
+    load_idl(&mut file, args).map_err(|e| e.in_idl(idl_path))
+}
 
+/// Generates the crate for a single IDL in `args.output_dir` into `out`
+pub fn gen_crate(
+    mut args: Args,
+    mut idl: Box<dyn IdlFormat>,
+    out: &mut Output,
+) -> Result<GeneratedCrate> {
+    let metadata = GenerationMetadata::new(&args, idl.as_ref());
     if args.output_crate_name == DEFAULT_OUTPUT_CRATE_NAME_MSG {
         args.output_crate_name = format!("{}_interface", idl.program_name());
     }
+    // the crate's own types, which may be mapped when it is also an extern crate, are not imported
+    let own_crate_prefix = format!("{}::", args.output_crate_name.replace('-', "_"));
+    args.map_type
+        .retain(|(_, path)| !path.starts_with(&own_crate_prefix));
+
+    idl.apply_filters(&Filters::from_args(&args)?);
+    idl.apply_item_configs(&args);
 
     args.program_id = args.program_id.and_then(|s| {
         if s == DEFAULT_PROGRAM_ID_MSG {
@@ -136,44 +523,123 @@ pub fn main() {
         }
     });
 
-    args.output_dir.push(&args.output_crate_name);
-    fs::create_dir_all(args.output_dir.join("src/")).unwrap();
+    check_program_id_for(&args, idl.as_ref())?;
 
-    // TODO: multithread, 1 thread per generated file
-    write_gitignore(&args).unwrap();
-    write_cargotoml(&args, idl.as_ref()).unwrap();
-    write_lib(&args, idl.as_ref()).unwrap();
+    if let Some(module) = &args.module {
+        write_lib(&args, idl.as_ref(), &metadata, out)?;
+        write_idl(&args, idl.as_ref(), out);
+        let manifest =
+            toml::to_string(&ModuleManifest::from_args_and_idl(&args, idl.as_ref())).unwrap();
+        log::info!(
+            "{module} module generated for {}. Its crate must have:\n{manifest}",
+            args.output_dir.to_string_lossy()
+        );
+    } else {
+        args.output_dir.push(&args.output_crate_name);
 
-    log::info!(
-        "{} crate written to {}",
-        args.output_crate_name,
-        args.output_dir.to_string_lossy()
-    );
-}
+        write_gitignore(&args, out);
+        write_cargotoml(&args, idl.as_ref(), &metadata, out);
+        write_lib(&args, idl.as_ref(), &metadata, out)?;
+        write_idl(&args, idl.as_ref(), out);
 
-pub fn load_idl(file: &mut File) -> Box<dyn IdlFormat> {
-    if let Ok(shank_idl) = serde_json::from_reader::<&File, ShankIdl>(file) {
-        if shank_idl.is_correct_idl_format() {
-            log::info!("Successfully loaded shank IDL");
-            return Box::new(shank_idl);
-        }
+        log::info!(
+            "{} crate generated for {}",
+            args.output_crate_name,
+            args.output_dir.to_string_lossy()
+        );
     }
-    file.rewind().unwrap();
-    if let Ok(bincode_idl) = serde_json::from_reader::<&File, BincodeIdl>(file) {
-        if bincode_idl.is_correct_idl_format() {
-            log::info!("Successfully loaded bincode IDL");
-            return Box::new(bincode_idl);
-        }
+
+    Ok(GeneratedCrate {
+        dependencies: idl.dependencies(&args).keys().cloned().collect(),
+        name: args.output_crate_name,
+    })
+}
+
+/// Generates the crates for all `idl_paths` in parallel for the
+/// cargo workspace at `args.output_dir`, creating or updating the workspace's `Cargo.toml`
+pub fn gen_workspace(args: &Args, idl_paths: &[PathBuf]) -> Result<Output> {
+    if args.output_crate_name != DEFAULT_OUTPUT_CRATE_NAME_MSG
+        || args.program_id.as_deref() != Some(DEFAULT_PROGRAM_ID_MSG)
+        || !args.program_id_for.is_empty()
+        || args.module.is_some()
+    {
+        return Err(Error::ArgumentConflict(
+            "output-crate-name, program-id, program-id-for and module cannot be used with multiple IDLs",
+        ));
     }
-    file.rewind().unwrap();
-    // Assume anchor if unidentified
-    match serde_json::from_reader::<&File, AnchorIdl>(file) {
-        Ok(anchor_idl) => {
-            log::info!("Successfully loaded anchor IDL");
-            Box::new(anchor_idl)
-        }
-        Err(e) => {
-            panic!("Could not determine IDL format: {:?}", e);
+
+    let member_args = workspace_member_args(args);
+    let mut out = Output::default();
+    let crates = std::thread::scope(|s| {
+        let handles: Vec<_> = idl_paths
+            .iter()
+            .map(|idl_path| {
+                let member_args = member_args.clone();
+                s.spawn(move || -> Result<_> {
+                    let mut crate_out = Output::default();
+                    let idl = load_idl_path(idl_path, &member_args)?;
+                    let generated = gen_crate(member_args, idl, &mut crate_out)
+                        .map_err(|e| e.in_idl(idl_path))?;
+                    Ok((generated, crate_out))
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| {
+                let (generated, crate_out) =
+                    h.join().unwrap_or_else(|e| std::panic::resume_unwind(e))?;
+                out.extend(crate_out);
+                Ok(generated)
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut names = HashSet::new();
+    for (c, idl_path) in crates.iter().zip(idl_paths) {
+        if !names.insert(c.name.as_str()) {
+            return Err(Error::DuplicateCrate {
+                name: c.name.clone(),
+                idl_path: idl_path.clone(),
+            });
         }
     }
+
+    write_workspace_cargotoml(args, &crates, &mut out)?;
+    log::info!(
+        "workspace with {} crates generated for {}",
+        crates.len(),
+        args.output_dir.to_string_lossy()
+    );
+    Ok(out)
+}
+
+pub fn load_idl(file: &mut File, args: &Args) -> Result<Box<dyn IdlFormat>> {
+    let mut s = String::new();
+    file.read_to_string(&mut s).map_err(serde_json::Error::io)?;
+    load_idl_str(&s, args)
+}
+
+/// Loads an IDL of format `args.format`, or the detected format if not set.
+///
+/// Prefer this over [`load_idl_value`] where possible since
+/// parse errors then include the line and column of the offending value.
+pub fn load_idl_str(s: &str, args: &Args) -> Result<Box<dyn IdlFormat>> {
+    let format = match args.format {
+        Some(f) => f,
+        None => IdlFormatKind::detect(&serde_json::from_str(s)?),
+    };
+    let mut de = serde_json::Deserializer::from_str(s);
+    let mut idl = format.deserialize(&mut de, args.strict)?;
+    de.end()?;
+    idl.set_json(s.to_owned());
+    Ok(idl)
+}
+
+/// Loads an IDL of format `args.format`, or the detected format if not set.
+pub fn load_idl_value(idl: &serde_json::Value, args: &Args) -> Result<Box<dyn IdlFormat>> {
+    let format = args.format.unwrap_or_else(|| IdlFormatKind::detect(idl));
+    let mut res = format.deserialize(idl, args.strict)?;
+    res.set_json(serde_json::to_string_pretty(idl)?);
+    Ok(res)
 }
@@ -0,0 +1,211 @@
+//! Semantic checks for IDLs that parse fine but result in generated crates
+//! that fail to compile or misbehave at runtime, reported by `solores lint`.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+};
+
+use crate::naming::is_keyword;
+
+/// Error codes below this are reserved for anchor's framework errors
+pub const ANCHOR_ERROR_CODE_OFFSET: u32 = 6000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// `item` references `defined` type `name` that is not in the IDL
+    MissingType { item: String, name: String },
+
+    /// More than one `kind` item e.g. "instruction" is named `name`
+    DuplicateName { kind: &'static str, name: String },
+
+    /// Anchor 8-byte discriminator shared by `items`
+    DiscriminatorCollision { discm: [u8; 8], items: Vec<String> },
+
+    /// Shank instruction discriminant shared by `instructions`
+    DuplicateDiscriminant {
+        discm: u8,
+        instructions: Vec<String>,
+    },
+
+    /// Anchor error code in anchor's reserved range
+    ReservedErrorCode { name: String, code: u32 },
+
+    /// `item` generates `ident`, which is a rust keyword.
+    /// The generated crate escapes it, but it is awkward to use.
+    Keyword { item: String, ident: String },
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingType { item, name } => {
+                write!(f, "{item}: type {name} is not defined in IDL")
+            }
+            Self::DuplicateName { kind, name } => write!(f, "duplicate {kind} name {name}"),
+            Self::DiscriminatorCollision { discm, items } => {
+                write!(f, "discriminator {discm:?} shared by {}", items.join(", "))
+            }
+            Self::DuplicateDiscriminant {
+                discm,
+                instructions,
+            } => write!(
+                f,
+                "discriminant {discm} shared by instructions {}",
+                instructions.join(", ")
+            ),
+            Self::ReservedErrorCode { name, code } => write!(
+                f,
+                "error {name}: code {code} is in anchor's reserved range (< {ANCHOR_ERROR_CODE_OFFSET})"
+            ),
+            Self::Keyword { item, ident } => write!(f, "{item}: `{ident}` is a rust keyword"),
+        }
+    }
+}
+
+/// Adds a [`Lint::Keyword`] if `ident` is a rust keyword
+pub fn check_keyword(lints: &mut Vec<Lint>, item: &str, ident: &str) {
+    if is_keyword(ident) {
+        lints.push(Lint::Keyword {
+            item: item.to_owned(),
+            ident: ident.to_owned(),
+        });
+    }
+}
+
+/// Adds a [`Lint::MissingType`] if `name` is not one of `type_names`
+pub fn check_defined(lints: &mut Vec<Lint>, item: &str, name: &str, type_names: &HashSet<&str>) {
+    if !type_names.contains(name) {
+        lints.push(Lint::MissingType {
+            item: item.to_owned(),
+            name: name.to_owned(),
+        });
+    }
+}
+
+/// Adds a [`Lint::DuplicateName`] for each name that occurs more than once in `names`
+pub fn check_duplicate_names<'a>(
+    lints: &mut Vec<Lint>,
+    kind: &'static str,
+    names: impl IntoIterator<Item = &'a str>,
+) {
+    for (name, _) in collisions(names.into_iter().map(|n| (n, ()))) {
+        lints.push(Lint::DuplicateName {
+            kind,
+            name: name.to_owned(),
+        });
+    }
+}
+
+/// Groups `items` by key, returning the groups with more than one item
+/// in order of first occurrence
+pub fn collisions<K: Clone + Eq + Hash, V>(
+    items: impl IntoIterator<Item = (K, V)>,
+) -> Vec<(K, Vec<V>)> {
+    let mut groups: Vec<(K, Vec<V>)> = Vec::new();
+    let mut indices: HashMap<K, usize> = HashMap::new();
+    for (k, v) in items {
+        match indices.get(&k) {
+            Some(&i) => groups[i].1.push(v),
+            None => {
+                indices.insert(k.clone(), groups.len());
+                groups.push((k, vec![v]));
+            }
+        }
+    }
+    groups.into_iter().filter(|(_, v)| v.len() > 1).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::{load_idl_str, Args};
+
+    use super::*;
+
+    fn lint(idl: &str) -> Vec<Lint> {
+        load_idl_str(idl, &Args::parse_from(["solores"]))
+            .unwrap()
+            .lint()
+    }
+
+    #[test]
+    fn test_anchor_lints() {
+        let lints = lint(
+            r#"{
+                "version": "0.1.0",
+                "name": "my_program",
+                "instructions": [
+                    { "name": "doThing", "accounts": [], "args": [{ "name": "type", "type": { "defined": "Missing" } }] },
+                    { "name": "do_thing", "accounts": [], "args": [] }
+                ],
+                "types": [
+                    { "name": "Foo", "type": { "kind": "struct", "fields": [] } },
+                    { "name": "Foo", "type": { "kind": "struct", "fields": [] } }
+                ],
+                "errors": [{ "code": 100, "name": "Bad", "msg": "bad" }]
+            }"#,
+        );
+        assert_eq!(
+            lints,
+            [
+                Lint::DuplicateName {
+                    kind: "typedef",
+                    name: "Foo".into()
+                },
+                Lint::Keyword {
+                    item: "instruction doThing field type".into(),
+                    ident: "type".into()
+                },
+                Lint::MissingType {
+                    item: "instruction doThing field type".into(),
+                    name: "Missing".into()
+                },
+                Lint::ReservedErrorCode {
+                    name: "Bad".into(),
+                    code: 100
+                },
+                Lint::DiscriminatorCollision {
+                    discm: [72, 36, 181, 152, 19, 6, 103, 41],
+                    items: vec!["instruction doThing".into(), "instruction do_thing".into()]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shank_duplicate_discriminants() {
+        let lints = lint(
+            r#"{
+                "version": "0.1.0",
+                "name": "my_program",
+                "instructions": [
+                    { "name": "a", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 0 } },
+                    { "name": "b", "accounts": [], "args": [], "discriminant": { "type": "u8", "value": 0 } }
+                ],
+                "metadata": { "address": "11111111111111111111111111111111", "origin": "shank" }
+            }"#,
+        );
+        assert_eq!(
+            lints,
+            [Lint::DuplicateDiscriminant {
+                discm: 0,
+                instructions: vec!["a".into(), "b".into()]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_no_lints() {
+        let lints = lint(
+            r#"{
+                "version": "0.1.0",
+                "name": "my_program",
+                "instructions": [{ "name": "blankIx" }]
+            }"#,
+        );
+        assert!(lints.is_empty());
+    }
+}
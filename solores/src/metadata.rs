@@ -0,0 +1,123 @@
+//! How the crate was generated, which the generated crate embeds so that it can be traced back
+//! to its IDL and regenerated: the IDL itself as `idl.json`, the consts in [`GenerationMetadata::lib_consts`]
+//! and `[package.metadata.solores]` in its `Cargo.toml`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    config::Config, idl_format::IdlFormat, output::Output, write_cargotoml::IDL_FEATURE, Args,
+};
+
+/// The IDL's file, next to `Cargo.toml`, or to `mod.rs` with `--module`
+pub const IDL_FILE_NAME: &str = "idl.json";
+
+/// `[package.metadata.solores]` of the generated `Cargo.toml`
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct GenerationMetadata {
+    /// Version of solores that generated the crate
+    pub version: &'static str,
+
+    /// Hex-encoded SHA-256 of the IDL's JSON
+    pub idl_sha256: String,
+
+    /// The options the crate was generated with, in `solores.toml` format. See [`Config::from_args`].
+    pub options: toml::Table,
+}
+
+impl GenerationMetadata {
+    /// `args` must not have had its defaults filled in yet, which [`crate::gen_crate`] does
+    pub fn new(args: &Args, idl: &dyn IdlFormat) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            idl_sha256: format!("{:x}", Sha256::digest(idl.json().as_bytes())),
+            options: Config::from_args(args).to_toml(),
+        }
+    }
+
+    /// The generated `lib.rs`'s `SOLORES_VERSION`, `IDL_SHA256`, `SOLORES_OPTIONS`
+    /// and, behind the `idl` feature, `IDL_JSON`
+    pub fn lib_consts(&self, args: &Args) -> TokenStream {
+        let Self {
+            version,
+            idl_sha256,
+            options,
+        } = self;
+        let options = options.to_string();
+        // lib.rs is in src/, while mod.rs is next to idl.json
+        let idl_path = if args.module.is_some() {
+            IDL_FILE_NAME.to_owned()
+        } else {
+            format!("../{IDL_FILE_NAME}")
+        };
+        quote! {
+            /// Version of solores that generated this crate
+            pub const SOLORES_VERSION: &str = #version;
+            /// Hex-encoded SHA-256 of the IDL this crate was generated from
+            pub const IDL_SHA256: &str = #idl_sha256;
+            /// The solores options this crate was generated with, in `solores.toml` format
+            pub const SOLORES_OPTIONS: &str = #options;
+            /// The IDL this crate was generated from
+            #[cfg(feature = #IDL_FEATURE)]
+            pub const IDL_JSON: &str = include_str!(#idl_path);
+        }
+    }
+}
+
+/// `contents` without the version of solores in the `SOLORES_VERSION` const
+/// and `[package.metadata.solores]`, so that `--check` passes after upgrading solores
+/// if the crate is otherwise unchanged
+pub fn without_version(contents: &str) -> String {
+    let mut in_metadata = false;
+    contents
+        .lines()
+        .filter(|line| {
+            if line.starts_with('[') {
+                in_metadata = *line == "[package.metadata.solores]";
+            }
+            !(line.starts_with("pub const SOLORES_VERSION: &str = ")
+                || in_metadata && line.starts_with("version = "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes the IDL's JSON to [`IDL_FILE_NAME`] in `args.output_dir`.
+///
+/// It is always written, regardless of whether the crate enables its `idl` feature,
+/// since `IDL_JSON` includes it and the feature is up to the crate's dependents.
+pub fn write_idl(args: &Args, idl: &dyn IdlFormat, out: &mut Output) {
+    out.add(args.output_dir.join(IDL_FILE_NAME), idl.json().to_owned());
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::load_idl_str;
+
+    use super::*;
+
+    const IDL: &str = r#"{ "version": "0.1.0", "name": "my_program", "instructions": [] }"#;
+
+    #[test]
+    fn test_generation_metadata() {
+        let args = Args::parse_from(["solores", "--compact", "-z", "MyAccount"]);
+        let idl = load_idl_str(IDL, &args).unwrap();
+        let metadata = GenerationMetadata::new(&args, idl.as_ref());
+        assert_eq!(metadata.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(metadata.idl_sha256, format!("{:x}", Sha256::digest(IDL)));
+        // only the options that differ from the defaults, which round-trip through solores.toml
+        let config: Config = metadata.options.clone().try_into().unwrap();
+        assert_eq!(config.compact, Some(true));
+        assert_eq!(config.zero_copy, Some(vec!["MyAccount".to_owned()]));
+        assert!(config.program_id.is_none());
+        assert!(config.output_crate_name.is_none());
+
+        let lib_consts = metadata.lib_consts(&args).to_string();
+        assert!(lib_consts.contains(&quote!(include_str!("../idl.json")).to_string()));
+    }
+}
@@ -0,0 +1,156 @@
+//! `--module` output: the generated modules are written to `--output-dir` as a module of an
+//! existing crate, with a `mod.rs` in place of `lib.rs` and no `Cargo.toml` or `.gitignore`.
+//!
+//! Codegen modules refer to the crate root with `crate::` paths, such as `use crate::*;` and
+//! `crate::ID`. [`reroot_crate_paths`] rewrites those into paths from the module's `--module` path.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Group, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{parse::Parser, Path};
+
+use crate::{Args, Error, Result};
+
+/// `--module`, which must be a path from the crate root
+pub fn module_path(args: &Args) -> Result<Option<Path>> {
+    let Some(module) = args.module.as_deref() else {
+        return Ok(None);
+    };
+    let invalid_path = |source| Error::InvalidItem {
+        item: "--module".to_owned(),
+        kind: "path",
+        value: module.to_owned(),
+        source,
+    };
+    let path = Path::parse_mod_style
+        .parse_str(module)
+        .map_err(invalid_path)?;
+    if path.leading_colon.is_some() || path.segments[0].ident != "crate" {
+        return Err(invalid_path(syn::Error::new_spanned(
+            &path,
+            "must start with `crate::`",
+        )));
+    }
+    Ok(Some(path))
+}
+
+/// Rewrites every `crate::` path in `tokens` into `#module_path::`,
+/// except for the `--map-type` paths, which are already paths in the crate
+pub fn reroot_crate_paths(tokens: TokenStream, module_path: &Path, args: &Args) -> TokenStream {
+    let mapped_paths: HashSet<String> = args
+        .map_type
+        .iter()
+        // without generics
+        .map(|(_, path)| {
+            path.split('<')
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect()
+        })
+        .collect();
+    reroot(tokens, &module_path.to_token_stream(), &mapped_paths)
+}
+
+fn reroot(
+    tokens: TokenStream,
+    module_path: &TokenStream,
+    mapped_paths: &HashSet<String>,
+) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let is_path_sep = |i: usize| {
+        matches!(
+            (tokens.get(i), tokens.get(i + 1)),
+            (Some(TokenTree::Punct(a)), Some(TokenTree::Punct(b)))
+                if a.as_char() == ':' && a.spacing() == Spacing::Joint && b.as_char() == ':'
+        )
+    };
+    let mut res = TokenStream::new();
+    for (i, tt) in tokens.iter().enumerate() {
+        match tt {
+            TokenTree::Group(g) => {
+                let mut group =
+                    Group::new(g.delimiter(), reroot(g.stream(), module_path, mapped_paths));
+                group.set_span(g.span());
+                res.extend([TokenTree::Group(group)]);
+            }
+            TokenTree::Ident(ident) if ident == "crate" && is_path_sep(i + 1) => {
+                // `crate` followed by `::ident` segments
+                let mut path = "crate".to_owned();
+                let mut j = i + 1;
+                while is_path_sep(j) {
+                    match tokens.get(j + 2) {
+                        Some(TokenTree::Ident(segment)) => path.push_str(&format!("::{segment}")),
+                        _ => break,
+                    }
+                    j += 3;
+                }
+                if mapped_paths.contains(&path) {
+                    res.extend([tt.clone()]);
+                } else {
+                    res.extend(module_path.clone());
+                }
+            }
+            _ => res.extend([tt.clone()]),
+        }
+    }
+    res
+}
+
+/// [`reroot_crate_paths`] for the verbatim `macro_rules!` definitions,
+/// which only refer to the crate root through `crate::ID`
+pub fn reroot_macros_crate_paths(macros: &str, module_path: &Path) -> String {
+    let module_path = module_path.to_token_stream().to_string().replace(' ', "");
+    macros.replace("crate::", &format!("{module_path}::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use quote::quote;
+
+    use super::*;
+
+    fn args(cli: &[&str]) -> Args {
+        Args::parse_from([&["solores"], cli].concat())
+    }
+
+    #[test]
+    fn test_reroot_crate_paths() {
+        let args = args(&[
+            "--module",
+            "crate::ifaces::my_program",
+            "--map-type",
+            "Fixed=crate::fixed::Fixed<u8>",
+        ]);
+        let module_path = module_path(&args).unwrap().unwrap();
+        let tokens = quote! {
+            use crate::*;
+            pub(crate) struct S(crate::fixed::Fixed<u8>);
+            fn f() { g(crate::ID) }
+        };
+        let expected = quote! {
+            use crate::ifaces::my_program::*;
+            pub(crate) struct S(crate::fixed::Fixed<u8>);
+            fn f() { g(crate::ifaces::my_program::ID) }
+        };
+        assert_eq!(
+            reroot_crate_paths(tokens, &module_path, &args).to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_module_path_must_be_from_crate_root() {
+        assert!(module_path(&args(&[])).unwrap().is_none());
+        for path in [
+            "my_program",
+            "super::my_program",
+            "::crate::my_program",
+            "crate::",
+        ] {
+            assert!(module_path(&args(&["--module", path])).is_err());
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Conversion of IDL names to valid rust identifiers, shared by all IDL formats.
+//!
+//! Names are first converted to the required case and sanitized with [`sanitize`],
+//! which guarantees that the result, along with any identifiers formed by adding a suffix to it,
+//! are valid identifiers or keywords. Keywords are then escaped by [`ident`]
+//! when the name is used as a standalone identifier.
+//!
+//! Serialization is unaffected: borsh is positional and serde strips the `r#` prefix of raw identifiers.
+
+use std::collections::{HashMap, HashSet};
+
+use heck::{ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, ToTokens};
+use syn::{ext::IdentExt, parse::Parser};
+
+/// Keywords that cannot be raw identifiers, which are suffixed with `_` instead
+const NON_RAW_KEYWORDS: [&str; 5] = ["_", "crate", "self", "Self", "super"];
+
+/// Replaces characters that cannot be in identifiers with `_`
+/// and prefixes `_` to names that do not start with a letter or `_`
+pub fn sanitize(name: &str) -> String {
+    let mut res: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if !res.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        res.insert(0, '_');
+    }
+    res
+}
+
+/// [`sanitize`] for type names: the first letter of names that start with a digit
+/// is also capitalized e.g. `_1Foo`, so that they stay camel case
+pub fn sanitize_type(name: &str) -> String {
+    let mut res = sanitize(name);
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Some((i, c)) = res
+            .char_indices()
+            .find(|(_, c)| *c != '_' && !c.is_ascii_digit())
+        {
+            res.replace_range(i..i + c.len_utf8(), &c.to_uppercase().to_string());
+        }
+    }
+    res
+}
+
+pub fn snake_case(name: &str) -> String {
+    sanitize(&name.to_snake_case())
+}
+
+pub fn pascal_case(name: &str) -> String {
+    sanitize_type(&name.to_pascal_case())
+}
+
+pub fn shouty_snake_case(name: &str) -> String {
+    sanitize(&name.to_shouty_snake_case())
+}
+
+/// Whether `name` is a rust keyword, including reserved and weak keywords
+/// that cannot be used as identifiers
+pub fn is_keyword(name: &str) -> bool {
+    Ident::parse_any.parse_str(name).is_ok() && syn::parse_str::<Ident>(name).is_err()
+}
+
+/// `name`, which should already be sanitized, as an identifier.
+///
+/// Keywords are escaped as raw identifiers e.g. `r#type`,
+/// except for those that cannot be raw identifiers e.g. `self`, which are suffixed with `_`.
+pub fn ident(name: &str) -> Ident {
+    if NON_RAW_KEYWORDS.contains(&name) {
+        format_ident!("{}_", name)
+    } else if is_keyword(name) {
+        Ident::new_raw(name, Span::call_site())
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
+pub fn snake_ident(name: &str) -> Ident {
+    ident(&snake_case(name))
+}
+
+pub fn pascal_ident(name: &str) -> Ident {
+    ident(&pascal_case(name))
+}
+
+/// The rust type for an IDL `defined` type name.
+///
+/// Identifiers are sanitized and escaped the same way as typedef names so that they match.
+/// Anything else that parses as a rust type e.g. a type path is used as is.
+pub fn defined_type(name: &str) -> TokenStream {
+    if Ident::parse_any.parse_str(name).is_err() {
+        if let Ok(ty) = syn::parse_str::<syn::Type>(name) {
+            return ty.into_token_stream();
+        }
+    }
+    ident(&sanitize_type(name)).into_token_stream()
+}
+
+/// Crate-level names that are always generated, reserved before any IDL item's
+const FIXED_ITEM_NAMES: [&str; 13] = [
+    "ID",
+    "id",
+    "check_id",
+    "ids",
+    "accounts",
+    "errors",
+    "events",
+    "instructions",
+    "typedefs",
+    "SOLORES_VERSION",
+    "IDL_SHA256",
+    "SOLORES_OPTIONS",
+    "IDL_JSON",
+];
+
+/// The crate-level names of generated items, mapped to the IDL item that generated them.
+///
+/// Used to detect collisions between the items generated for different IDL items
+/// e.g. typedef `SwapKeys` and instruction `swap`'s `SwapKeys`.
+/// Types and values are treated as a single namespace.
+#[derive(Debug)]
+pub struct ItemNames(HashMap<String, String>);
+
+impl ItemNames {
+    /// Starts with the names generated regardless of the IDL's items
+    /// and those of the program's `*ProgramIx` and `*Error` enums reserved
+    pub fn new(program_name: &str) -> Self {
+        let program = format!("program {program_name}");
+        let mut res = Self(HashMap::new());
+        for name in FIXED_ITEM_NAMES {
+            res.0.insert(name.to_owned(), program.clone());
+        }
+        for suffix in ["ProgramIx", "Error"] {
+            res.0.insert(
+                format!("{}{suffix}", pascal_case(program_name)),
+                program.clone(),
+            );
+        }
+        res
+    }
+
+    /// Reserves the names `generated(name)` for `item`.
+    ///
+    /// If any of them are already taken, digits are appended to `name` until none are
+    /// and the new name is returned after warning about the rename.
+    pub fn reserve(
+        &mut self,
+        item: &str,
+        name: &str,
+        generated: impl Fn(&str) -> Vec<String>,
+    ) -> Option<String> {
+        let taken = |n: &str| generated(n).into_iter().find(|g| self.0.contains_key(g));
+        let res = taken(name).map(|collision| {
+            let new_name = (2..)
+                .map(|i| format!("{name}{i}"))
+                .find(|n| taken(n).is_none())
+                .unwrap();
+            log::warn!(
+                "{item}: renamed to {new_name} since {collision} is also generated for {}",
+                self.0[&collision]
+            );
+            new_name
+        });
+        for g in generated(res.as_deref().unwrap_or(name)) {
+            self.0.insert(g, item.to_owned());
+        }
+        res
+    }
+}
+
+/// Appends digits to each name in `names` that is the same as an earlier one once converted with `case`
+/// until it is unique, returning the `(old, new)` names of those renamed
+pub fn dedup_names<'a>(
+    names: impl IntoIterator<Item = &'a mut String>,
+    case: fn(&str) -> String,
+) -> Vec<(String, String)> {
+    let names: Vec<&mut String> = names.into_iter().collect();
+    let mut taken: HashSet<String> = names.iter().map(|n| case(n)).collect();
+    let mut seen = HashSet::new();
+    let mut renames = Vec::new();
+    for name in names {
+        if !seen.insert(case(name)) {
+            let new_name = (2..)
+                .map(|i| format!("{name}{i}"))
+                .find(|n| !taken.contains(&case(n)))
+                .unwrap();
+            taken.insert(case(&new_name));
+            seen.insert(case(&new_name));
+            renames.push((std::mem::replace(name, new_name.clone()), new_name));
+        }
+    }
+    renames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keywords_escaped() {
+        assert_eq!(snake_ident("type").to_string(), "r#type");
+        assert_eq!(snake_ident("moveAll").to_string(), "move_all");
+        assert_eq!(snake_ident("self").to_string(), "self_");
+        assert_eq!(pascal_ident("self").to_string(), "Self_");
+        assert_eq!(ident("crate").to_string(), "crate_");
+    }
+
+    #[test]
+    fn test_invalid_names_sanitized() {
+        assert_eq!(snake_case("1stOwner"), "_1st_owner");
+        assert_eq!(pascal_case("2x"), "_2X");
+        assert_eq!(pascal_case("1stPlace"), "_1StPlace");
+        assert_eq!(shouty_snake_case("3d"), "_3D");
+        assert_eq!(sanitize(""), "_");
+        assert_eq!(ident(&sanitize("")).to_string(), "__");
+        assert_eq!(sanitize("a.b"), "a_b");
+    }
+
+    #[test]
+    fn test_defined_type() {
+        assert_eq!(defined_type("Foo").to_string(), "Foo");
+        assert_eq!(defined_type("type").to_string(), "r#type");
+        assert_eq!(defined_type("1Foo").to_string(), "_1Foo");
+        assert_eq!(defined_type("4thType").to_string(), "_4ThType");
+        assert_eq!(
+            defined_type("state::Foo").to_string(),
+            quote::quote!(state::Foo).to_string()
+        );
+    }
+
+    #[test]
+    fn test_item_name_collisions_disambiguated() {
+        let mut names = ItemNames::new("my_program");
+        let generated = |n: &str| vec![format!("{n}Keys"), format!("{n}IxArgs")];
+        assert_eq!(names.reserve("instruction swap", "Swap", generated), None);
+        assert_eq!(
+            names.reserve("typedef SwapKeys", "SwapKeys", |n| vec![n.to_owned()]),
+            Some("SwapKeys2".into())
+        );
+        assert_eq!(
+            names.reserve("typedef MyProgramError", "MyProgramError", |n| vec![
+                n.to_owned()
+            ]),
+            Some("MyProgramError2".into())
+        );
+        assert_eq!(
+            names.reserve("typedef ids", "ids", |n| vec![n.to_owned()]),
+            Some("ids2".into())
+        );
+        assert_eq!(
+            names.reserve("typedef IDL_JSON", "IDL_JSON", |n| vec![n.to_owned()]),
+            Some("IDL_JSON2".into())
+        );
+    }
+
+    #[test]
+    fn test_dedup_names() {
+        let mut names = ["a", "A", "a2", "b"].map(String::from);
+        let renames = dedup_names(names.iter_mut(), snake_case);
+        assert_eq!(names, ["a", "A3", "a2", "b"]);
+        assert_eq!(renames, [("A".to_owned(), "A3".to_owned())]);
+    }
+}
@@ -0,0 +1,195 @@
+//! In-memory generated files, which are either written to disk
+//! or compared against the files on disk in `--check` mode.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use similar::TextDiff;
+
+use crate::{metadata::without_version, utils::open_file_create_overwrite, Error, Result};
+
+/// Generated file path -> file contents
+#[derive(Debug, Default)]
+pub struct Output {
+    pub files: BTreeMap<PathBuf, String>,
+    /// Paths of previously generated files that must no longer exist,
+    /// such as `src/instructions.rs` once it is split into `src/instructions/`
+    pub stale: BTreeSet<PathBuf>,
+}
+
+impl Output {
+    pub fn add<P: Into<PathBuf>>(&mut self, path: P, contents: String) {
+        self.files.insert(path.into(), contents);
+    }
+
+    /// Marks the file at `path` as stale, to be deleted if it exists
+    pub fn remove<P: Into<PathBuf>>(&mut self, path: P) {
+        self.stale.insert(path.into());
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        self.files.extend(other.files);
+        self.stale.extend(other.stale);
+    }
+
+    /// Writes all files to disk, creating parent directories as required,
+    /// and deletes the stale files that exist
+    pub fn write(&self) -> Result<()> {
+        for (path, contents) in self.files.iter() {
+            write_file(path, contents).map_err(|e| Error::io(path, e))?;
+        }
+        for path in self.stale.iter() {
+            match fs::remove_file(path) {
+                Err(e) if e.kind() != ErrorKind::NotFound => return Err(Error::io(path, e)),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Compares all files against the files on disk without writing anything,
+    /// printing a unified diff for each file that differs.
+    /// The version of solores that generated them is ignored, see [`without_version`].
+    ///
+    /// Returns the paths of files that differ.
+    pub fn check(&self) -> Result<Vec<&Path>> {
+        let mut mismatched = Vec::new();
+        for (path, contents) in self.files.iter() {
+            let on_disk = match fs::read_to_string(path) {
+                Ok(s) => s,
+                Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+                Err(e) => return Err(Error::io(path, e)),
+            };
+            if on_disk == *contents || without_version(&on_disk) == without_version(contents) {
+                continue;
+            }
+            let path_str = path.to_string_lossy();
+            print!(
+                "{}",
+                TextDiff::from_lines(&on_disk, contents)
+                    .unified_diff()
+                    .header(&path_str, &path_str)
+            );
+            mismatched.push(path.as_path());
+        }
+        for path in self.stale.iter() {
+            let on_disk = match fs::read_to_string(path) {
+                Ok(s) => s,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(Error::io(path, e)),
+            };
+            let path_str = path.to_string_lossy();
+            print!(
+                "{}",
+                TextDiff::from_lines(on_disk.as_str(), "")
+                    .unified_diff()
+                    .header(&path_str, "/dev/null")
+            );
+            mismatched.push(path.as_path());
+        }
+        mismatched.sort();
+        Ok(mismatched)
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = open_file_create_overwrite(path)?;
+    file.write_all(contents.as_bytes())?;
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_reports_missing_and_stale_files() {
+        let dir = std::env::temp_dir().join(format!("solores-output-{}", std::process::id()));
+        let up_to_date = dir.join("up_to_date.rs");
+        let stale = dir.join("stale.rs");
+        let missing = dir.join("missing.rs");
+
+        let mut output = Output::default();
+        output.add(&up_to_date, "a\n".to_owned());
+        output.add(&stale, "b\n".to_owned());
+        output.write().unwrap();
+
+        output.add(&stale, "c\n".to_owned());
+        output.add(&missing, "d\n".to_owned());
+        let mismatched = output.check().unwrap();
+        assert_eq!(mismatched, [missing.as_path(), stale.as_path()]);
+        assert!(!missing.exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_ignores_solores_version() {
+        let dir =
+            std::env::temp_dir().join(format!("solores-output-version-{}", std::process::id()));
+        let lib_rs = dir.join("lib.rs");
+        let cargo_toml = dir.join("Cargo.toml");
+
+        let mut output = Output::default();
+        output.add(
+            &lib_rs,
+            "pub const SOLORES_VERSION: &str = \"0.1.0\";\n".to_owned(),
+        );
+        output.add(
+            &cargo_toml,
+            "[package]\nversion = \"1.0.0\"\n\n[package.metadata.solores]\nversion = \"0.1.0\"\n"
+                .to_owned(),
+        );
+        output.write().unwrap();
+
+        output.add(
+            &lib_rs,
+            "pub const SOLORES_VERSION: &str = \"0.2.0\";\n".to_owned(),
+        );
+        output.add(
+            &cargo_toml,
+            "[package]\nversion = \"1.0.0\"\n\n[package.metadata.solores]\nversion = \"0.2.0\"\n"
+                .to_owned(),
+        );
+        assert!(output.check().unwrap().is_empty());
+
+        output.add(
+            &cargo_toml,
+            "[package]\nversion = \"2.0.0\"\n\n[package.metadata.solores]\nversion = \"0.2.0\"\n"
+                .to_owned(),
+        );
+        assert_eq!(output.check().unwrap(), [cargo_toml.as_path()]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_deletes_removed_files() {
+        let dir =
+            std::env::temp_dir().join(format!("solores-output-removed-{}", std::process::id()));
+        let removed = dir.join("stale.rs");
+        let missing = dir.join("missing.rs");
+
+        let mut output = Output::default();
+        output.add(&removed, "a\n".to_owned());
+        output.write().unwrap();
+
+        let mut output = Output::default();
+        output.remove(&removed);
+        output.remove(&missing);
+        assert_eq!(output.check().unwrap(), [removed.as_path()]);
+        assert!(removed.exists());
+        output.write().unwrap();
+        assert!(!removed.exists());
+        assert!(output.check().unwrap().is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,381 @@
+//! Where the generated code's `solana_program` items come from:
+//! - `--split-solana-crates`: the split solana crates, such as `solana-pubkey` and
+//!   `solana-instruction`, instead of `solana-program`
+//! - `--solana-program-path`: a re-export of `solana_program`, such as `anchor_lang::solana_program`
+//!
+//! Codegen modules always import from `solana_program`. [`split_solana_imports`] and
+//! [`reroot_solana_imports`] rewrite those imports, and [`SolanaCratesUsage`] determines
+//! which crates the generated crate depends on.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse::Parser, Item, ItemUse, Path, UseTree};
+use toml::{map::Map, Value};
+
+use crate::{
+    target::Target,
+    write_cargotoml::{
+        solana_program_dependency, DependencyValue, FeaturesDependencyValue,
+        OptionalDependencyValue, SERDE_CRATE,
+    },
+    Args, Error, Result,
+};
+
+/// Default `--solana-program-path`
+pub const SOLANA_PROGRAM_PATH: &str = "solana_program";
+
+pub const SOLANA_PUBKEY_CRATE: &str = "solana-pubkey";
+pub const SOLANA_INSTRUCTION_CRATE: &str = "solana-instruction";
+pub const SOLANA_ACCOUNT_INFO_CRATE: &str = "solana-account-info";
+pub const SOLANA_PROGRAM_ERROR_CRATE: &str = "solana-program-error";
+pub const SOLANA_CPI_CRATE: &str = "solana-cpi";
+pub const SOLANA_DECODE_ERROR_CRATE: &str = "solana-decode-error";
+pub const SOLANA_MSG_CRATE: &str = "solana-msg";
+
+/// (path in `solana_program`, split crate) of every `solana_program` item that codegen modules import.
+/// The item has the same name at the root of the split crate.
+const SPLIT_PATHS: [(&str, &str); 11] = [
+    ("pubkey::Pubkey", SOLANA_PUBKEY_CRATE),
+    ("instruction::AccountMeta", SOLANA_INSTRUCTION_CRATE),
+    ("instruction::Instruction", SOLANA_INSTRUCTION_CRATE),
+    ("account_info::AccountInfo", SOLANA_ACCOUNT_INFO_CRATE),
+    ("entrypoint::ProgramResult", SOLANA_PROGRAM_ERROR_CRATE),
+    ("program_error::ProgramError", SOLANA_PROGRAM_ERROR_CRATE),
+    (
+        "program_error::PrintProgramError",
+        SOLANA_PROGRAM_ERROR_CRATE,
+    ),
+    ("program::invoke", SOLANA_CPI_CRATE),
+    ("program::invoke_signed", SOLANA_CPI_CRATE),
+    ("decode_error::DecodeError", SOLANA_DECODE_ERROR_CRATE),
+    ("msg", SOLANA_MSG_CRATE),
+];
+
+fn crate_ident(krate: &str) -> Ident {
+    Ident::new(&krate.replace('-', "_"), Span::call_site())
+}
+
+/// `--solana-program-path`
+pub fn solana_program_path(args: &Args) -> Result<Path> {
+    Path::parse_mod_style
+        .parse_str(&args.solana_program_path)
+        .map_err(|source| Error::InvalidItem {
+            item: "--solana-program-path".to_owned(),
+            kind: "path",
+            value: args.solana_program_path.clone(),
+            source,
+        })
+}
+
+/// The path that `declare_id!()` is invoked from
+pub fn declare_id_path(args: &Args) -> Result<TokenStream> {
+    if args.split_solana_crates {
+        let krate = crate_ident(SOLANA_PUBKEY_CRATE);
+        return Ok(quote! { #krate });
+    }
+    let path = solana_program_path(args)?;
+    Ok(quote! { #path })
+}
+
+/// Rewrites every `use solana_program::..;` in `head` into `use #path::..;`
+pub fn reroot_solana_imports(head: TokenStream, path: &Path) -> syn::Result<TokenStream> {
+    let file: syn::File = syn::parse2(head)?;
+    let items = file.items.into_iter().map(|item| match item {
+        Item::Use(ItemUse {
+            attrs,
+            vis,
+            leading_colon: None,
+            tree: UseTree::Path(root),
+            ..
+        }) if root.ident == "solana_program" => {
+            let subtree = root.tree;
+            syn::parse_quote! {
+                #(#attrs)*
+                #vis use #path::#subtree;
+            }
+        }
+        item => item,
+    });
+    Ok(quote! { #(#items)* })
+}
+
+/// Rewrites every `use solana_program::{..};` in `head` into one `use` per split crate,
+/// keeping the `use`'s attributes
+pub fn split_solana_imports(head: TokenStream) -> syn::Result<TokenStream> {
+    let file: syn::File = syn::parse2(head)?;
+    let mut items = Vec::new();
+    for item in file.items {
+        match item {
+            Item::Use(item_use) => match solana_program_paths(&item_use) {
+                Some(paths) => items.extend(split_use(&item_use, &paths?)?),
+                None => items.push(Item::Use(item_use)),
+            },
+            item => items.push(item),
+        }
+    }
+    Ok(quote! { #(#items)* })
+}
+
+/// Paths relative to `solana_program` of the items imported by `item_use`,
+/// `None` if it does not import from `solana_program`
+fn solana_program_paths(item_use: &ItemUse) -> Option<syn::Result<Vec<String>>> {
+    fn flatten(tree: &UseTree, prefix: &str, res: &mut Vec<String>) -> syn::Result<()> {
+        match tree {
+            UseTree::Path(p) => flatten(&p.tree, &format!("{prefix}{}::", p.ident), res)?,
+            UseTree::Name(n) => res.push(format!("{prefix}{}", n.ident)),
+            UseTree::Group(g) => {
+                for tree in g.items.iter() {
+                    flatten(tree, prefix, res)?;
+                }
+            }
+            UseTree::Rename(_) | UseTree::Glob(_) => {
+                return Err(syn::Error::new_spanned(
+                    tree,
+                    "solana_program imports cannot be renamed or globbed with split solana crates",
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    let UseTree::Path(root) = &item_use.tree else {
+        return None;
+    };
+    if item_use.leading_colon.is_some() || root.ident != "solana_program" {
+        return None;
+    }
+    let mut paths = Vec::new();
+    Some(flatten(&root.tree, "", &mut paths).map(|_| paths))
+}
+
+fn split_use(item_use: &ItemUse, paths: &[String]) -> syn::Result<Vec<Item>> {
+    let mut by_crate: Vec<(&str, Vec<Ident>)> = Vec::new();
+    for path in paths {
+        let Some((_, krate)) = SPLIT_PATHS.iter().find(|(p, _)| p == path) else {
+            return Err(syn::Error::new_spanned(
+                item_use,
+                format!("no split solana crate for solana_program::{path}"),
+            ));
+        };
+        let name = Ident::new(path.rsplit("::").next().unwrap(), Span::call_site());
+        match by_crate.iter_mut().find(|(k, _)| k == krate) {
+            Some((_, names)) => names.push(name),
+            None => by_crate.push((krate, vec![name])),
+        }
+    }
+    let attrs = &item_use.attrs;
+    Ok(by_crate
+        .into_iter()
+        .map(|(krate, names)| {
+            let krate = crate_ident(krate);
+            let tree = match names.as_slice() {
+                [name] => quote! { #name },
+                names => quote! { {#(#names),*} },
+            };
+            syn::parse_quote! {
+                #(#attrs)*
+                use #krate::#tree;
+            }
+        })
+        .collect())
+}
+
+/// What the generated code uses from the solana crates
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SolanaCratesUsage {
+    pub has_instructions: bool,
+    /// Whether any instruction has accounts
+    pub has_ix_accounts: bool,
+    pub has_errors: bool,
+    /// Whether any serialized typedef, account, event or instruction args field is or has a `Pubkey`
+    pub has_pubkey_fields: bool,
+    /// Whether data is serialized with serde and bincode instead of borsh
+    pub is_bincode: bool,
+}
+
+impl SolanaCratesUsage {
+    /// Whether the `cpi` feature's items use `solana_program`'s CPI API
+    fn has_cpi(&self, args: &Args) -> bool {
+        self.has_instructions && args.target == Target::SolanaProgram
+    }
+
+    /// The crate that `--solana-program-path` is in, or the split solana crates used by the generated code.
+    /// Split crates only used by the `cpi` feature's items are optional.
+    pub fn dependencies(&self, args: &Args) -> Map<String, Value> {
+        let mut map = Map::new();
+        if !args.split_solana_crates {
+            if let Some((name, vers)) = solana_program_dependency(args) {
+                map.insert(name, DependencyValue(vers).into());
+            }
+            return map;
+        }
+        let mut pubkey_features = Vec::new();
+        if self.has_pubkey_fields {
+            if self.is_bincode {
+                pubkey_features.push(SERDE_CRATE);
+            } else {
+                pubkey_features.push("borsh");
+                if !args.zero_copy.is_empty() {
+                    pubkey_features.push("bytemuck");
+                }
+            }
+        }
+        // (crate, features)
+        let mut crates = vec![(SOLANA_PUBKEY_CRATE, pubkey_features)];
+        if self.has_instructions {
+            let features = if self.is_bincode {
+                vec!["bincode"]
+            } else {
+                Vec::new()
+            };
+            crates.push((SOLANA_INSTRUCTION_CRATE, features));
+        }
+        if self.has_errors {
+            crates.push((SOLANA_DECODE_ERROR_CRATE, Vec::new()));
+            crates.push((SOLANA_MSG_CRATE, Vec::new()));
+        }
+        if self.has_errors || self.has_cpi(args) {
+            // `*_invoke*()` converts borsh's I/O errors into `ProgramError`s with `?`
+            let features = if self.has_cpi(args) && !self.is_bincode {
+                vec!["borsh"]
+            } else {
+                Vec::new()
+            };
+            crates.push((SOLANA_PROGRAM_ERROR_CRATE, features));
+        }
+        if self.has_cpi(args) {
+            crates.push((SOLANA_CPI_CRATE, Vec::new()));
+            if self.has_ix_accounts {
+                crates.push((SOLANA_ACCOUNT_INFO_CRATE, Vec::new()));
+            }
+        }
+
+        let optional_cpi_crates = self.optional_cpi_crates(args);
+        for (krate, features) in crates {
+            let mut dependency: Map<String, Value> =
+                DependencyValue(&args.split_solana_crates_vers).into();
+            if !features.is_empty() {
+                dependency = FeaturesDependencyValue {
+                    dependency,
+                    features: features.into_iter().map(Into::into).collect(),
+                }
+                .into();
+            }
+            let dependency = if optional_cpi_crates.contains(&krate) {
+                OptionalDependencyValue(dependency).into()
+            } else {
+                Value::Table(dependency)
+            };
+            map.insert(krate.into(), dependency);
+        }
+        map
+    }
+
+    /// Split crates that are only used by the `cpi` feature's items
+    fn optional_cpi_crates(&self, args: &Args) -> Vec<&'static str> {
+        let mut res = Vec::new();
+        if !args.split_solana_crates || !self.has_cpi(args) {
+            return res;
+        }
+        res.push(SOLANA_CPI_CRATE);
+        if self.has_ix_accounts {
+            res.push(SOLANA_ACCOUNT_INFO_CRATE);
+        }
+        if !self.has_errors {
+            res.push(SOLANA_PROGRAM_ERROR_CRATE);
+        }
+        res
+    }
+
+    /// Dependencies that the `cpi` feature enables
+    pub fn cpi_features(&self, args: &Args) -> Vec<String> {
+        self.optional_cpi_crates(args)
+            .into_iter()
+            .map(|krate| format!("dep:{krate}"))
+            .collect()
+    }
+
+    /// Dependency features that the optional `serde` feature enables
+    pub fn serde_features(&self, args: &Args) -> Vec<String> {
+        if args.split_solana_crates && self.has_pubkey_fields && !self.is_bincode {
+            vec![format!("{SOLANA_PUBKEY_CRATE}/{SERDE_CRATE}")]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_split_solana_imports() {
+        let head = quote! {
+            use borsh::BorshSerialize;
+            use solana_program::{instruction::{AccountMeta, Instruction}, pubkey::Pubkey};
+            #[cfg(feature = "cpi")]
+            use solana_program::{
+                entrypoint::ProgramResult,
+                program::{invoke, invoke_signed},
+                program_error::ProgramError,
+            };
+        };
+        let expected = quote! {
+            use borsh::BorshSerialize;
+            use solana_instruction::{AccountMeta, Instruction};
+            use solana_pubkey::Pubkey;
+            #[cfg(feature = "cpi")]
+            use solana_program_error::{ProgramResult, ProgramError};
+            #[cfg(feature = "cpi")]
+            use solana_cpi::{invoke, invoke_signed};
+        };
+        assert_eq!(
+            split_solana_imports(head).unwrap().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_split_solana_imports_unknown_item() {
+        let head = quote! { use solana_program::sysvar::clock::Clock; };
+        assert!(split_solana_imports(head).is_err());
+    }
+
+    #[test]
+    fn test_reroot_solana_imports() {
+        let mut args = Args::parse_from(["solores"]);
+        args.solana_program_path = "anchor_lang::solana_program".to_owned();
+        let path = solana_program_path(&args).unwrap();
+        let head = quote! {
+            use borsh::BorshSerialize;
+            #[cfg(feature = "cpi")]
+            use solana_program::{entrypoint::ProgramResult, program::invoke};
+        };
+        let expected = quote! {
+            use borsh::BorshSerialize;
+            #[cfg(feature = "cpi")]
+            use anchor_lang::solana_program::{entrypoint::ProgramResult, program::invoke};
+        };
+        assert_eq!(
+            reroot_solana_imports(head, &path).unwrap().to_string(),
+            expected.to_string()
+        );
+        assert_eq!(
+            declare_id_path(&args).unwrap().to_string(),
+            quote!(anchor_lang::solana_program).to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_solana_program_path() {
+        let mut args = Args::parse_from(["solores"]);
+        args.solana_program_path = "anchor_lang::".to_owned();
+        assert!(matches!(
+            solana_program_path(&args),
+            Err(Error::InvalidItem { item, kind: "path", .. }) if item == "--solana-program-path"
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+//! The SDK that the generated crate's on-chain items, behind its `cpi` feature, are written against.
+//!
+//! Client-side items such as `*Keys`, `*IxArgs` and `*_ix()` always use `solana_program`'s types.
+
+use std::fmt;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Target {
+    /// `solana_program`'s `AccountInfo`, `invoke()` and `invoke_signed()`
+    #[default]
+    SolanaProgram,
+    /// pinocchio's `AccountInfo` and `invoke_signed()`, with borsh instruction data serialized on the stack
+    Pinocchio,
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SolanaProgram => "solana-program",
+            Self::Pinocchio => "pinocchio",
+        })
+    }
+}
+
+impl Target {
+    /// Lifetime params of `*Accounts` structs
+    pub fn accounts_lifetimes(self) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { <'me, 'info> },
+            Self::Pinocchio => quote! { <'me> },
+        }
+    }
+
+    /// Lifetime args of `*Accounts` structs with all lifetimes elided
+    pub fn accounts_elided_lifetimes(self) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { <'_, '_> },
+            Self::Pinocchio => quote! { <'_> },
+        }
+    }
+
+    /// The `AccountInfo` type, with lifetime `'info` if it has one
+    pub fn account_info(self) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { AccountInfo<'info> },
+            Self::Pinocchio => quote! { AccountInfo },
+        }
+    }
+
+    /// The `solana_program` `Pubkey` of `account`, a `&AccountInfo`
+    pub fn account_key(self, account: TokenStream) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { *#account.key },
+            Self::Pinocchio => quote! { Pubkey::new_from_array(*#account.key()) },
+        }
+    }
+
+    pub fn is_writable(self, account: TokenStream) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { #account.is_writable },
+            Self::Pinocchio => quote! { #account.is_writable() },
+        }
+    }
+
+    pub fn is_signer(self, account: TokenStream) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { #account.is_signer },
+            Self::Pinocchio => quote! { #account.is_signer() },
+        }
+    }
+
+    /// Derives of `*Accounts` structs. pinocchio's `AccountInfo` is not `Debug`.
+    pub fn accounts_derives(self) -> TokenStream {
+        match self {
+            Self::SolanaProgram => quote! { #[derive(Copy, Clone, Debug)] },
+            Self::Pinocchio => quote! { #[derive(Copy, Clone)] },
+        }
+    }
+}
+
+/// Size of the stack buffer that pinocchio `*_invoke*()` functions serialize instruction data into
+/// for instructions whose data has no maximum length
+pub const PINOCCHIO_DEFAULT_DATA_BUF_LEN: usize = 1024;
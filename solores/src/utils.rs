@@ -13,10 +13,19 @@ use serde::{
     de::{self, MapAccess, Visitor},
     Deserialize, Deserializer,
 };
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, Parser},
+};
 use void::Void;
 
+use crate::Error;
+
 pub const PUBKEY_TOKEN: &str = "Pubkey";
 
+/// Max array length serde's derives support
+pub const SERDE_MAX_ARRAY_LEN: u32 = 32;
+
 pub fn primitive_or_pubkey_to_token(s: &str) -> String {
     match s {
         "publicKey" => PUBKEY_TOKEN.to_owned(),
@@ -35,6 +44,32 @@ pub fn primitive_or_pubkey_to_token(s: &str) -> String {
     }
 }
 
+/// Max nesting of `defined` types that type sizes are computed through,
+/// which also bounds recursive types
+pub const MAX_TYPE_DEPTH: usize = 32;
+
+/// Borsh-serialized length of IDL primitive or pubkey type `s`,
+/// `None` if variable-length or not a primitive
+pub fn primitive_borsh_len(s: &str) -> Option<usize> {
+    match s {
+        "bool" | "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" | "f32" => Some(4),
+        "u64" | "i64" | "f64" => Some(8),
+        "u128" | "i128" => Some(16),
+        "publicKey" | "pubkey" => Some(32),
+        _ => None,
+    }
+}
+
+/// Parses a `KEY=VALUE` command-line arg
+pub fn parse_key_val(s: &str) -> Result<(String, String), String> {
+    let (k, v) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{s}`"))?;
+    Ok((k.trim().to_owned(), v.trim().to_owned()))
+}
+
 pub fn open_file_create_overwrite<P: AsRef<Path>>(path: P) -> std::io::Result<File> {
     OpenOptions::new()
         .create(true)
@@ -117,13 +152,45 @@ where
 
 pub fn conditional_pascal_case(s: &str) -> String {
     // Only apply PascalCase if the string does not start with an uppercase letter.
-    if s.chars().next().map_or(false, |c| c.is_uppercase()) {
+    if s.chars().next().is_some_and(|c| c.is_uppercase()) {
         s.to_string()
     } else {
         s.to_pascal_case()
     }
 }
 
+fn check_parse<P: Parser>(
+    parser: P,
+    item: &str,
+    kind: &'static str,
+    value: &str,
+) -> crate::Result<()> {
+    parser
+        .parse_str(value)
+        .map(|_| ())
+        .map_err(|source| Error::InvalidItem {
+            item: item.to_owned(),
+            kind,
+            value: value.to_owned(),
+            source,
+        })
+}
+
+/// Errors if `ident` cannot be used with `format_ident!()`. Keywords are allowed.
+pub fn check_ident(item: &str, ident: &str) -> crate::Result<()> {
+    check_parse(syn::Ident::parse_any, item, "identifier", ident)
+}
+
+/// Errors if `ty` is not a valid rust type
+pub fn check_type(item: &str, ty: &str) -> crate::Result<()> {
+    check_parse(syn::Type::parse, item, "type", ty)
+}
+
+/// Errors if `derive` is not a valid path to use in `#[derive()]`
+pub fn check_derive(item: &str, derive: &str) -> crate::Result<()> {
+    check_parse(syn::Path::parse_mod_style, item, "derive", derive)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
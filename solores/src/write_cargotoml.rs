@@ -1,52 +1,287 @@
-use std::io::Write;
+use std::path::{self, Component, Path, PathBuf};
 
 use serde::Serialize;
 use toml::{map::Map, Value};
 
-use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
+use crate::{
+    idl_format::IdlFormat,
+    metadata::GenerationMetadata,
+    output::Output,
+    solana_crates::{
+        SOLANA_ACCOUNT_INFO_CRATE, SOLANA_CPI_CRATE, SOLANA_DECODE_ERROR_CRATE,
+        SOLANA_INSTRUCTION_CRATE, SOLANA_MSG_CRATE, SOLANA_PROGRAM_ERROR_CRATE,
+        SOLANA_PUBKEY_CRATE,
+    },
+    Args,
+};
 
 pub const BORSH_CRATE: &str = "borsh";
 pub const BYTEMUCK_CRATE: &str = "bytemuck";
 pub const SERDE_CRATE: &str = "serde";
+pub const SERDE_WITH_CRATE: &str = "serde_with";
 pub const SOLANA_PROGRAM_CRATE: &str = "solana-program";
 pub const THISERROR_CRATE: &str = "thiserror";
 pub const NUM_DERIVE_CRATE: &str = "num-derive";
 pub const NUM_TRAITS_CRATE: &str = "num-traits";
+pub const SOLANA_RPC_CLIENT_API_CRATE: &str = "solana-rpc-client-api";
+pub const PINOCCHIO_CRATE: &str = "pinocchio";
+pub const ANCHOR_LANG_CRATE: &str = "anchor-lang";
+pub const SOLORES_RUNTIME_CRATE: &str = "solores-runtime";
 
-pub fn write_cargotoml(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
-    let cargo_toml = CargoToml::from_args_and_idl(args, idl);
-    let cargo_toml_str = toml::to_string(&cargo_toml).unwrap();
+/// Feature gating the on-chain items: `*Accounts`, `*_invoke*()` and `*_verify_*()`
+pub const CPI_FEATURE: &str = "cpi";
+/// Feature gating the off-chain items, such as RPC filters
+pub const CLIENT_FEATURE: &str = "client";
+/// Feature gating the anchor-lang trait impls of anchor IDLs' accounts and instruction data
+pub const ANCHOR_FEATURE: &str = "anchor";
+/// Feature gating the solores-runtime trait impls
+pub const RUNTIME_FEATURE: &str = "runtime";
+/// Feature gating `IDL_JSON`, the IDL the crate was generated from
+pub const IDL_FEATURE: &str = "idl";
+
+/// (crate name, version arg) of every crate the generated crate can depend on
+pub fn dependency_versions(args: &Args) -> [(&'static str, &str); 19] {
+    [
+        (BORSH_CRATE, &args.borsh_vers),
+        (BYTEMUCK_CRATE, &args.bytemuck_vers),
+        (SERDE_CRATE, &args.serde_vers),
+        (SERDE_WITH_CRATE, &args.serde_with_vers),
+        (SOLANA_PROGRAM_CRATE, &args.solana_program_vers),
+        (THISERROR_CRATE, &args.thiserror_vers),
+        (NUM_DERIVE_CRATE, &args.num_derive_vers),
+        (NUM_TRAITS_CRATE, &args.num_traits_vers),
+        (
+            SOLANA_RPC_CLIENT_API_CRATE,
+            &args.solana_rpc_client_api_vers,
+        ),
+        (PINOCCHIO_CRATE, &args.pinocchio_vers),
+        (SOLANA_PUBKEY_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_INSTRUCTION_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_ACCOUNT_INFO_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_PROGRAM_ERROR_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_CPI_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_DECODE_ERROR_CRATE, &args.split_solana_crates_vers),
+        (SOLANA_MSG_CRATE, &args.split_solana_crates_vers),
+        (ANCHOR_LANG_CRATE, &args.anchor_lang_vers),
+        (SOLORES_RUNTIME_CRATE, &args.solores_runtime_vers),
+    ]
+}
+
+/// Crates whose default `std` feature is disabled for `--no-std` crates
+pub const NO_STD_CRATES: [&str; 2] = [BORSH_CRATE, NUM_TRAITS_CRATE];
+
+/// Crates in `--map-type` paths that are not dependencies
+const NON_DEPENDENCY_CRATES: [&str; 6] = ["crate", "self", "super", "std", "core", "alloc"];
+
+/// Default version of `--map-type` crates without a `--map-type-vers`
+pub const MAPPED_TYPE_DEFAULT_VERS: &str = "*";
+
+/// (crate name, version arg) of the crate in `--map-type` path `path`.
+///
+/// `None` if the path is not in an external crate, or is in one of the crates
+/// in [`dependency_versions`], which the generated crate already depends on.
+/// The crate name is the one passed to `--map-type-vers` if any, which may contain `-`.
+pub fn mapped_type_dependency<'a>(args: &'a Args, path: &str) -> Option<(String, &'a str)> {
+    let (krate, _) = path.trim_start_matches("::").split_once("::")?;
+    let krate = krate.trim();
+    if dependency_versions(args)
+        .iter()
+        .any(|(name, _)| normalized_crate_name(name) == krate)
+    {
+        return None;
+    }
+    external_crate_dependency(args, krate)
+}
+
+/// (crate name, version arg) of the crate that `--solana-program-path` is in,
+/// `None` if it is not in an external crate.
+///
+/// The version arg is the crate's own if it is one of the crates in [`dependency_versions`],
+/// such as `anchor-lang`, otherwise its `--map-type-vers`.
+pub fn solana_program_dependency(args: &Args) -> Option<(String, &str)> {
+    let path = args.solana_program_path.trim_start_matches("::");
+    let krate = path.split("::").next().unwrap_or_default().trim();
+    match dependency_versions(args)
+        .into_iter()
+        .find(|(name, _)| normalized_crate_name(name) == krate)
+    {
+        Some((name, vers)) => Some((name.to_owned(), vers)),
+        None => external_crate_dependency(args, krate),
+    }
+}
+
+/// (crate name, version arg) of crate `krate` from `--map-type-vers`,
+/// `None` if it is not an external crate
+fn external_crate_dependency<'a>(args: &'a Args, krate: &str) -> Option<(String, &'a str)> {
+    if NON_DEPENDENCY_CRATES.contains(&krate) {
+        return None;
+    }
+    Some(
+        args.map_type_vers
+            .iter()
+            .find(|(name, _)| normalized_crate_name(name) == krate)
+            .map_or(
+                (krate.to_owned(), MAPPED_TYPE_DEFAULT_VERS),
+                |(name, vers)| (name.clone(), vers.as_str()),
+            ),
+    )
+}
+
+fn normalized_crate_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// [`mapped_type_dependency`] of every `--map-type` path, as well as the crate that
+/// `--solana-program-path` is in if it is not in [`dependency_versions`], deduplicated
+pub fn mapped_type_dependencies(args: &Args) -> Vec<(String, &str)> {
+    let mut res: Vec<(String, &str)> = Vec::new();
+    let solana_program_dependency = solana_program_dependency(args).filter(|(name, _)| {
+        !dependency_versions(args)
+            .iter()
+            .any(|(known, _)| known == name)
+    });
+    for dep in args
+        .map_type
+        .iter()
+        .filter_map(|(_, path)| mapped_type_dependency(args, path))
+        .chain(solana_program_dependency)
+    {
+        if !res.iter().any(|(name, _)| name == &dep.0) {
+            res.push(dep);
+        }
+    }
+    res
+}
 
-    let path = args.output_dir.join("Cargo.toml");
-    let mut file = open_file_create_overwrite(path)?;
-    file.write_all(cargo_toml_str.as_bytes())?;
-    file.flush()
+pub fn write_cargotoml(
+    args: &Args,
+    idl: &dyn IdlFormat,
+    metadata: &GenerationMetadata,
+    out: &mut Output,
+) {
+    let cargo_toml = CargoToml::from_args_and_idl(args, idl, metadata);
+    let cargo_toml_str = toml::to_string(&cargo_toml).unwrap();
+    out.add(args.output_dir.join("Cargo.toml"), cargo_toml_str);
 }
 
 #[derive(Serialize)]
 pub struct CargoToml<'a> {
     pub package: Package<'a>,
     pub dependencies: Map<String, Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub features: Map<String, Value>,
 }
 
 impl<'a> CargoToml<'a> {
-    pub fn from_args_and_idl(args: &'a Args, idl: &'a dyn IdlFormat) -> Self {
+    pub fn from_args_and_idl(
+        args: &'a Args,
+        idl: &'a dyn IdlFormat,
+        metadata: &'a GenerationMetadata,
+    ) -> Self {
         Self {
             package: Package {
                 name: &args.output_crate_name,
                 version: idl.program_version(),
                 edition: "2021",
+                metadata: PackageMetadata { solores: metadata },
             },
+            dependencies: relativize_dependency_paths(idl.dependencies(args), &args.output_dir),
+            features: crate_features(args, idl),
+        }
+    }
+}
+
+/// The `[dependencies]` and `[features]` that the existing crate of a `--module` must have
+#[derive(Serialize)]
+pub struct ModuleManifest {
+    pub dependencies: Map<String, Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub features: Map<String, Value>,
+}
+
+impl ModuleManifest {
+    pub fn from_args_and_idl(args: &Args, idl: &dyn IdlFormat) -> Self {
+        Self {
             dependencies: idl.dependencies(args),
+            features: crate_features(args, idl),
+        }
+    }
+}
+
+/// The IDL's features, the `idl` feature and the `--program-id-for` features
+fn crate_features(args: &Args, idl: &dyn IdlFormat) -> Map<String, Value> {
+    let mut features = idl.features(args);
+    features.insert(IDL_FEATURE.to_owned(), Value::Array(Vec::new()));
+    for (feature, _) in args.program_id_for.iter() {
+        features.insert(feature.clone(), Value::Array(Vec::new()));
+    }
+    features
+}
+
+/// Rewrites absolute `path`s of `dependencies` relative to `manifest_dir`,
+/// the directory of the `Cargo.toml` they are written to
+pub fn relativize_dependency_paths(
+    mut dependencies: Map<String, Value>,
+    manifest_dir: &Path,
+) -> Map<String, Value> {
+    for (_, dep) in dependencies.iter_mut() {
+        relativize_dependency_path(dep, manifest_dir);
+    }
+    dependencies
+}
+
+/// Rewrites `dependency`'s `path`, if absolute, relative to `manifest_dir`
+pub fn relativize_dependency_path(dependency: &mut Value, manifest_dir: &Path) {
+    let Some(Value::String(dep_path)) = dependency.get_mut("path") else {
+        return;
+    };
+    if Path::new(dep_path).is_absolute() {
+        if let Some(rel) = relative_path(Path::new(dep_path), manifest_dir) {
+            *dep_path = rel.to_string_lossy().into_owned();
         }
     }
 }
 
+/// `path` relative to directory `base`, `None` if either cannot be made absolute
+/// or they are on different windows prefixes
+pub fn relative_path(path: &Path, base: &Path) -> Option<PathBuf> {
+    let path = path::absolute(path).ok()?;
+    let base = path::absolute(base).ok()?;
+    let path: Vec<Component> = path
+        .components()
+        .filter(|c| c != &Component::CurDir)
+        .collect();
+    let base: Vec<Component> = base
+        .components()
+        .filter(|c| c != &Component::CurDir)
+        .collect();
+    if path.first() != base.first() {
+        return None;
+    }
+    let common = path
+        .iter()
+        .zip(base.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut res: PathBuf = base[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .collect();
+    res.extend(&path[common..]);
+    Some(res)
+}
+
 #[derive(Serialize)]
 pub struct Package<'a> {
     pub name: &'a str,
     pub version: &'a str,
     pub edition: &'a str,
+    pub metadata: PackageMetadata<'a>,
+}
+
+#[derive(Serialize)]
+pub struct PackageMetadata<'a> {
+    pub solores: &'a GenerationMetadata,
 }
 
 /// Contained str value is the version string arg.
@@ -88,6 +323,66 @@ impl<T: Into<Map<String, Value>>> From<OptionalDependencyValue<T>> for Value {
     }
 }
 
+pub struct NoDefaultFeaturesDependencyValue<T>(pub T);
+
+impl<T: Into<Map<String, Value>>> From<NoDefaultFeaturesDependencyValue<T>> for Map<String, Value> {
+    fn from(value: NoDefaultFeaturesDependencyValue<T>) -> Self {
+        let mut map = value.0.into();
+        map.insert("default-features".into(), false.into());
+        map
+    }
+}
+
+impl<T: Into<Map<String, Value>>> From<NoDefaultFeaturesDependencyValue<T>> for Value {
+    fn from(value: NoDefaultFeaturesDependencyValue<T>) -> Self {
+        Value::Table(value.into())
+    }
+}
+
+/// `args.borsh_vers` dependency, without borsh's default `std` feature for `--no-std` crates.
+/// Its `derive` feature is otherwise enabled by `solana-program`, so must be explicit
+/// for `--no-std` and `--split-solana-crates` crates.
+pub fn borsh_dependency(args: &Args) -> Value {
+    if args.no_std {
+        FeaturesDependencyValue {
+            dependency: NoDefaultFeaturesDependencyValue(DependencyValue(&args.borsh_vers)),
+            features: vec!["derive".into()],
+        }
+        .into()
+    } else if args.split_solana_crates {
+        FeaturesDependencyValue {
+            dependency: DependencyValue(&args.borsh_vers),
+            features: vec!["derive".into()],
+        }
+        .into()
+    } else {
+        DependencyValue(&args.borsh_vers).into()
+    }
+}
+
+/// `args.serde_vers` dependency, with serde's `derive` feature for `--split-solana-crates` crates,
+/// where it is no longer enabled by `solana-program`
+pub fn serde_dependency(args: &Args) -> Map<String, Value> {
+    if args.split_solana_crates {
+        FeaturesDependencyValue {
+            dependency: DependencyValue(&args.serde_vers),
+            features: vec!["derive".into()],
+        }
+        .into()
+    } else {
+        DependencyValue(&args.serde_vers).into()
+    }
+}
+
+/// `args.num_traits_vers` dependency, without num-traits' default `std` feature for `--no-std` crates
+pub fn num_traits_dependency(args: &Args) -> Value {
+    if args.no_std {
+        NoDefaultFeaturesDependencyValue(DependencyValue(&args.num_traits_vers)).into()
+    } else {
+        DependencyValue(&args.num_traits_vers).into()
+    }
+}
+
 /// Contained str value is the version string arg.
 /// e.g. "^1.16", "workspace = true"
 pub struct FeaturesDependencyValue<T> {
@@ -113,3 +408,68 @@ impl<T: Into<Map<String, Value>>> From<FeaturesDependencyValue<T>> for Value {
         Value::Table(value.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[test]
+    fn test_mapped_type_dependencies() {
+        let mut args = Args::parse_from([
+            "solores",
+            "--map-type",
+            "I80F48=fixed::types::I80F48",
+            "--map-type",
+            "u128=spl_math::uint::U256",
+            "--map-type",
+            "Hash=::solana_program::hash::Hash",
+            "--map-type",
+            "Foo=crate::Foo",
+            "--map-type-vers",
+            "fixed=^1.27",
+        ]);
+        assert_eq!(
+            mapped_type_dependencies(&args),
+            [
+                ("fixed".to_owned(), "^1.27"),
+                ("spl_math".to_owned(), MAPPED_TYPE_DEFAULT_VERS)
+            ]
+        );
+
+        args.solana_program_path = "my_sdk::solana_program".to_owned();
+        args.map_type_vers = vec![("my-sdk".to_owned(), "^1.0".to_owned())];
+        assert_eq!(
+            mapped_type_dependencies(&args).last(),
+            Some(&("my-sdk".to_owned(), "^1.0"))
+        );
+    }
+
+    #[test]
+    fn test_solana_program_dependency() {
+        let mut args = Args::parse_from(["solores"]);
+        assert_eq!(
+            solana_program_dependency(&args),
+            Some((
+                SOLANA_PROGRAM_CRATE.to_owned(),
+                args.solana_program_vers.as_str()
+            ))
+        );
+        args.solana_program_path = "anchor_lang::solana_program".to_owned();
+        assert_eq!(
+            solana_program_dependency(&args),
+            Some((ANCHOR_LANG_CRATE.to_owned(), args.anchor_lang_vers.as_str()))
+        );
+        args.solana_program_path = "crate::solana_program".to_owned();
+        assert_eq!(solana_program_dependency(&args), None);
+    }
+
+    #[test]
+    fn test_no_std_dependencies() {
+        let args = Args::parse_from(["solores", "--no-std"]);
+        for dependency in [borsh_dependency(&args), num_traits_dependency(&args)] {
+            assert_eq!(dependency["default-features"].as_bool(), Some(false));
+        }
+    }
+}
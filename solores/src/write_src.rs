@@ -1,11 +1,38 @@
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::quote;
-use std::{io::Write, path::Path};
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::{Path, PathBuf},
+};
 
-use crate::{idl_format::IdlFormat, utils::open_file_create_overwrite, Args};
+use crate::{
+    idl_format::IdlFormat,
+    metadata::GenerationMetadata,
+    module::{module_path, reroot_crate_paths, reroot_macros_crate_paths},
+    naming::{snake_case, snake_ident},
+    output::Output,
+    solana_crates::{
+        declare_id_path, reroot_solana_imports, solana_program_path, split_solana_imports,
+        SOLANA_PROGRAM_PATH,
+    },
+    write_cargotoml::IDL_FEATURE,
+    Args, Error, Result,
+};
 
 const DEFAULT_PROGRAM_ID_STR: &str = "TH1S1SNoTAVAL1DPUBKEYDoNoTUSE11111111111111";
 
+/// (ident, path in `alloc`, is macro) of the std prelude items that
+/// `#![no_std]` crates must import from `alloc` to use
+const ALLOC_PRELUDE: [(&str, &str, bool); 7] = [
+    ("Box", "boxed::Box", false),
+    ("String", "string::String", false),
+    ("ToString", "string::ToString", false),
+    ("to_string", "string::ToString", false),
+    ("Vec", "vec::Vec", false),
+    ("format", "format", true),
+    ("vec", "vec", true),
+];
+
 const MAX_BASE58_LEN: usize = 44;
 const PUBKEY_BYTES_SIZE: usize = 32;
 
@@ -25,7 +52,102 @@ fn is_valid_pubkey(s: &str) -> bool {
     true
 }
 
-pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
+/// Checks that `--program-id-for`'s pubkeys are valid
+/// and that its features can be added to the generated crate
+pub fn check_program_id_for(args: &Args, idl: &dyn IdlFormat) -> Result<()> {
+    let features = idl.features(args);
+    let dependencies = idl.dependencies(args);
+    let mut mod_names = HashSet::new();
+    for (feature, program_id) in args.program_id_for.iter() {
+        let err = |reason| Error::InvalidProgramIdFor {
+            feature: feature.clone(),
+            program_id: program_id.clone(),
+            reason,
+        };
+        if !is_valid_pubkey(program_id) {
+            return Err(err("invalid pubkey"));
+        }
+        let is_valid_feature = feature
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && feature
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !is_valid_feature {
+            return Err(err("invalid feature name"));
+        }
+        // optional dependencies are implicit features
+        if feature == "default"
+            || feature == IDL_FEATURE
+            || features.contains_key(feature)
+            || dependencies.contains_key(feature)
+        {
+            return Err(err("feature already exists in the generated crate"));
+        }
+        if !mod_names.insert(snake_case(feature)) {
+            return Err(err("duplicate feature"));
+        }
+    }
+    Ok(())
+}
+
+/// `declare_id!(id)`, or with `--program-id-for`, a `declare_id!` for each feature that applies
+/// if it's the first enabled one, `declare_id!(id)` if none are enabled, and the `ids` module
+/// of every feature's `declare_id!`
+fn declare_ids(
+    declare_id_path: &TokenStream,
+    id: &str,
+    program_id_for: &[(String, String)],
+) -> TokenStream {
+    if program_id_for.is_empty() {
+        return quote! {
+            #declare_id_path::declare_id!(#id);
+        };
+    }
+    let mut res = TokenStream::new();
+    let mut earlier_features: Vec<&str> = Vec::new();
+    for (feature, program_id) in program_id_for.iter() {
+        let cfg = if earlier_features.is_empty() {
+            quote! { feature = #feature }
+        } else {
+            quote! { all(feature = #feature, not(any(#(feature = #earlier_features),*))) }
+        };
+        res.extend(quote! {
+            #[cfg(#cfg)]
+            #declare_id_path::declare_id!(#program_id);
+        });
+        earlier_features.push(feature);
+    }
+    res.extend(quote! {
+        #[cfg(not(any(#(feature = #earlier_features),*)))]
+        #declare_id_path::declare_id!(#id);
+    });
+    let id_mods = program_id_for.iter().map(|(feature, program_id)| {
+        let mod_ident = snake_ident(feature);
+        let doc = format!("Program ID with feature `{feature}`");
+        quote! {
+            #[doc = #doc]
+            pub mod #mod_ident {
+                #declare_id_path::declare_id!(#program_id);
+            }
+        }
+    });
+    res.extend(quote! {
+        /// Program IDs of every cluster feature, regardless of which are enabled
+        pub mod ids {
+            #(#id_mods)*
+        }
+    });
+    res
+}
+
+pub fn write_lib(
+    args: &Args,
+    idl: &dyn IdlFormat,
+    metadata: &GenerationMetadata,
+    out: &mut Output,
+) -> Result<()> {
     let user_provided_id_opt =
         args.program_id
             .as_ref()
@@ -42,35 +164,343 @@ pub fn write_lib(args: &Args, idl: &dyn IdlFormat) -> std::io::Result<()> {
             })
         });
 
-    let mut contents = quote! {
-        solana_program::declare_id!(#id);
+    let module_path = module_path(args)?;
+    // #![no_std] is the existing crate's concern with --module
+    let mut contents = if args.no_std && module_path.is_none() {
+        quote! {
+            #![no_std]
+
+            extern crate alloc;
+        }
+    } else {
+        TokenStream::new()
     };
+    let declare_id_path = declare_id_path(args)?;
+    contents.extend(declare_ids(&declare_id_path, id, &args.program_id_for));
+    contents.extend(metadata.lib_consts(args));
+    let solana_program_path = solana_program_path(args)?;
 
-    for module in idl.modules(args) {
+    for module in idl.modules(args)? {
         let module_name = module.name();
         let module_ident = Ident::new(module.name(), Span::call_site());
         contents.extend(quote! {
             pub mod #module_ident;
             pub use #module_ident::*;
         });
-        let mut module_contents = module.gen_head();
-        module_contents.extend(module.gen_body());
-        write_src_file(args, &format!("src/{module_name}.rs"), module_contents)?;
+        let mut head = module.gen_head()?;
+        let invalid_generated_code = |source| Error::InvalidGeneratedCode {
+            path: args
+                .output_dir
+                .join(src_path(args, format!("{module_name}.rs"))),
+            source,
+        };
+        if args.split_solana_crates {
+            head = split_solana_imports(head).map_err(invalid_generated_code)?;
+        } else if args.solana_program_path != SOLANA_PROGRAM_PATH {
+            head = reroot_solana_imports(head, &solana_program_path)
+                .map_err(invalid_generated_code)?;
+        }
+        let mut macros = module.gen_macros();
+        if let Some(module_path) = &module_path {
+            macros = reroot_macros_crate_paths(&macros, module_path);
+        }
+        let items = if args.split_modules {
+            module.gen_body_items()?
+        } else {
+            None
+        };
+        if let Some(items) = items {
+            write_split_module(args, out, module_name, head, &macros, items)?;
+            continue;
+        }
+        let body = module.gen_body()?;
+        let mut module_contents = if args.no_std {
+            alloc_imports(&body, &macros)
+        } else {
+            TokenStream::new()
+        };
+        module_contents.extend(head);
+        write_module_file(
+            args,
+            out,
+            src_path(args, format!("{module_name}.rs")),
+            module_contents,
+            &macros,
+            body,
+        )?;
+        // would otherwise be ambiguous with the module file if previously generated with --split-modules
+        out.remove(
+            args.output_dir
+                .join(src_path(args, format!("{module_name}/mod.rs"))),
+        );
+    }
+
+    let root_file = if module_path.is_some() {
+        "mod.rs"
+    } else {
+        "lib.rs"
+    };
+    write_src_file(args, out, src_path(args, root_file), contents)
+}
+
+/// `path` relative to the generated crate's `src/`, or `--output-dir` with `--module`
+fn src_path(args: &Args, path: impl AsRef<Path>) -> PathBuf {
+    if args.module.is_some() {
+        path.as_ref().to_owned()
+    } else {
+        Path::new("src").join(path)
     }
+}
 
-    write_src_file(args, "src/lib.rs", contents)
+/// Writes each of `items` to `{module_name}/{item}.rs`, which glob imports everything
+/// in `{module_name}/mod.rs`: `head`, `macros` and the re-exports of every item
+fn write_split_module(
+    args: &Args,
+    out: &mut Output,
+    module_name: &str,
+    head: TokenStream,
+    macros: &str,
+    items: Vec<(String, TokenStream)>,
+) -> Result<()> {
+    let mut mod_contents = if args.no_std {
+        alloc_imports(
+            &items.iter().map(|(_, body)| body.clone()).collect(),
+            macros,
+        )
+    } else {
+        TokenStream::new()
+    };
+    mod_contents.extend(head);
+    let mut mod_items = TokenStream::new();
+    // mod.rs is taken
+    let mut file_names = HashSet::from(["mod".to_owned()]);
+    for (name, body) in items {
+        let mut item_ident = snake_ident(&name);
+        let mut file_name = item_ident.to_string().trim_start_matches("r#").to_owned();
+        // items whose names only differ in case or separators
+        let mut i = 1;
+        while !file_names.insert(file_name.clone()) {
+            i += 1;
+            item_ident = snake_ident(&format!("{name}_{i}"));
+            file_name = item_ident.to_string().trim_start_matches("r#").to_owned();
+        }
+        mod_items.extend(quote! {
+            mod #item_ident;
+            pub use #item_ident::*;
+        });
+        write_src_file(
+            args,
+            out,
+            src_path(args, format!("{module_name}/{file_name}.rs")),
+            quote! {
+                use super::*;
+
+                #body
+            },
+        )?;
+    }
+    write_module_file(
+        args,
+        out,
+        src_path(args, format!("{module_name}/mod.rs")),
+        mod_contents,
+        macros,
+        mod_items,
+    )?;
+    out.remove(
+        args.output_dir
+            .join(src_path(args, format!("{module_name}.rs"))),
+    );
+    Ok(())
+}
+
+/// `use alloc::{..}` of the [`ALLOC_PRELUDE`] items used unqualified in `tokens`
+/// and the expansions of `macros`
+fn alloc_imports(tokens: &TokenStream, macros: &str) -> TokenStream {
+    fn collect(tokens: TokenStream, res: &mut BTreeSet<&'static str>) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, tt) in tokens.iter().enumerate() {
+            match tt {
+                TokenTree::Group(g) => collect(g.stream(), res),
+                TokenTree::Ident(ident) => {
+                    let is_colon =
+                        |j: usize| matches!(&tokens[j], TokenTree::Punct(p) if p.as_char() == ':');
+                    let is_qualified = i >= 2 && is_colon(i - 1) && is_colon(i - 2);
+                    let is_macro = matches!(tokens.get(i + 1), Some(TokenTree::Punct(p)) if p.as_char() == '!');
+                    let name = ident.to_string();
+                    if let Some((_, path, _)) = ALLOC_PRELUDE
+                        .iter()
+                        .find(|(n, _, m)| *n == name && *m == is_macro)
+                    {
+                        if !is_qualified {
+                            res.insert(*path);
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let mut paths = BTreeSet::new();
+    collect(tokens.clone(), &mut paths);
+    if !macros.is_empty() {
+        collect(
+            macros
+                .parse()
+                .expect("macro definitions should be valid tokens"),
+            &mut paths,
+        );
+    }
+    if paths.is_empty() {
+        return TokenStream::new();
+    }
+    let paths = paths.into_iter().map(|p| {
+        let segments = p.split("::").map(|s| Ident::new(s, Span::call_site()));
+        quote! { #(#segments)::* }
+    });
+    quote! {
+        use alloc::{#(#paths),*};
+    }
 }
 
 fn write_src_file<P: AsRef<Path>>(
     args: &Args,
+    out: &mut Output,
     src_file_path: P,
     contents: TokenStream,
-) -> std::io::Result<()> {
-    let unpretty = syn::parse2(contents).unwrap();
-    let formatted = prettyplease::unparse(&unpretty);
+) -> Result<()> {
+    let path = args.output_dir.join(src_file_path);
+    let formatted = format_src(&path, reroot_module(args, contents)?)?;
+    out.add(path, formatted);
+    Ok(())
+}
 
+/// Writes a module file made of `head`, the verbatim `macros` and `body`
+fn write_module_file<P: AsRef<Path>>(
+    args: &Args,
+    out: &mut Output,
+    src_file_path: P,
+    mut head: TokenStream,
+    macros: &str,
+    body: TokenStream,
+) -> Result<()> {
+    if macros.is_empty() {
+        head.extend(body);
+        return write_src_file(args, out, src_file_path, head);
+    }
     let path = args.output_dir.join(src_file_path);
-    let mut file = open_file_create_overwrite(path)?;
-    file.write_all(formatted.as_bytes())?;
-    file.flush()
+    let formatted = format_src(&path, reroot_module(args, head)?)?
+        + macros
+        + &format_src(&path, reroot_module(args, body)?)?;
+    out.add(path, formatted);
+    Ok(())
+}
+
+/// [`reroot_crate_paths`] with `--module`
+fn reroot_module(args: &Args, contents: TokenStream) -> Result<TokenStream> {
+    Ok(match module_path(args)? {
+        Some(module_path) => reroot_crate_paths(contents, &module_path, args),
+        None => contents,
+    })
+}
+
+fn format_src(path: &Path, contents: TokenStream) -> Result<String> {
+    match syn::parse2(contents) {
+        Ok(f) => Ok(prettyplease::unparse(&f)),
+        Err(source) => Err(Error::InvalidGeneratedCode {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::load_idl_str;
+
+    use super::*;
+
+    const DEVNET: &str = "Dev1111111111111111111111111111111111111111";
+
+    #[test]
+    fn test_check_program_id_for() {
+        let mut args = Args::parse_from(["solores"]);
+        let idl = load_idl_str(
+            r#"{ "version": "0.1.0", "name": "my_program", "instructions": [] }"#,
+            &args,
+        )
+        .unwrap();
+        args.program_id_for = vec![
+            ("devnet".to_owned(), DEVNET.to_owned()),
+            ("local-net".to_owned(), DEVNET.to_owned()),
+        ];
+        assert!(check_program_id_for(&args, idl.as_ref()).is_ok());
+
+        for (feature, program_id, expected_reason) in [
+            ("devnet", "xyz", "invalid pubkey"),
+            ("1devnet", DEVNET, "invalid feature name"),
+            (
+                "cpi",
+                DEVNET,
+                "feature already exists in the generated crate",
+            ),
+            (
+                "idl",
+                DEVNET,
+                "feature already exists in the generated crate",
+            ),
+        ] {
+            args.program_id_for = vec![(feature.to_owned(), program_id.to_owned())];
+            assert!(matches!(
+                check_program_id_for(&args, idl.as_ref()),
+                Err(Error::InvalidProgramIdFor { reason, .. }) if reason == expected_reason
+            ));
+        }
+
+        // local-net and local_net are both the `local_net` module
+        args.program_id_for = vec![
+            ("local-net".to_owned(), DEVNET.to_owned()),
+            ("local_net".to_owned(), DEVNET.to_owned()),
+        ];
+        assert!(matches!(
+            check_program_id_for(&args, idl.as_ref()),
+            Err(Error::InvalidProgramIdFor {
+                reason: "duplicate feature",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_write_split_module() {
+        let args = Args::parse_from(["solores", "-o", "my_program_interface"]);
+        let mut out = Output::default();
+        let items = vec![
+            ("Mod".to_owned(), quote! { pub struct Mod; }),
+            ("State".to_owned(), quote! { pub struct State; }),
+        ];
+        write_split_module(&args, &mut out, "typedefs", quote! {}, "", items).unwrap();
+        let dir = Path::new("my_program_interface/src/typedefs");
+        let paths: Vec<&Path> = out.files.keys().map(PathBuf::as_path).collect();
+        assert_eq!(
+            paths,
+            [
+                dir.join("mod.rs"),
+                dir.join("mod_2.rs"),
+                dir.join("state.rs")
+            ]
+        );
+        let mod_rs = &out.files[&dir.join("mod.rs")];
+        // mod.rs is taken
+        assert!(mod_rs.contains("mod mod_2;\npub use mod_2::*;"));
+        assert!(mod_rs.contains("mod state;\npub use state::*;"));
+        assert!(out.files[&dir.join("state.rs")].starts_with("use super::*;"));
+        assert_eq!(
+            out.stale,
+            BTreeSet::from([Path::new("my_program_interface/src/typedefs.rs").to_owned()])
+        );
+    }
 }
@@ -0,0 +1,289 @@
+//! Workspace `Cargo.toml` for batch mode, where multiple IDLs are generated
+//! into crates that share a single cargo workspace.
+
+use std::{fs, path::Path};
+
+use toml::Value;
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table};
+
+use crate::{
+    output::Output,
+    write_cargotoml::{
+        dependency_versions, mapped_type_dependencies, relativize_dependency_path, DependencyValue,
+        NoDefaultFeaturesDependencyValue, NO_STD_CRATES,
+    },
+    Args, Error, Result,
+};
+
+pub const WORKSPACE_DEPENDENCY: &str = "workspace = true";
+
+/// A crate generated in batch mode
+#[derive(Debug)]
+pub struct GeneratedCrate {
+    /// crate name, also the name of its directory in the workspace root
+    pub name: String,
+    /// names of the crate's dependencies
+    pub dependencies: Vec<String>,
+}
+
+/// Returns `args` with all dependency versions set to `workspace = true`
+/// so that the generated crate inherits them from the workspace `Cargo.toml`
+pub fn workspace_member_args(args: &Args) -> Args {
+    let mut res = args.clone();
+    for vers in [
+        &mut res.solana_program_vers,
+        &mut res.borsh_vers,
+        &mut res.thiserror_vers,
+        &mut res.num_derive_vers,
+        &mut res.num_traits_vers,
+        &mut res.serde_vers,
+        &mut res.bytemuck_vers,
+        &mut res.serde_with_vers,
+        &mut res.solana_rpc_client_api_vers,
+        &mut res.pinocchio_vers,
+        &mut res.split_solana_crates_vers,
+        &mut res.anchor_lang_vers,
+        &mut res.solores_runtime_vers,
+    ] {
+        *vers = WORKSPACE_DEPENDENCY.to_owned();
+    }
+    res.map_type_vers = mapped_type_dependencies(args)
+        .into_iter()
+        .map(|(name, _)| (name, WORKSPACE_DEPENDENCY.to_owned()))
+        .collect();
+    res
+}
+
+/// Creates or updates the `Cargo.toml` in `args.output_dir`:
+/// - adds `crates` to `workspace.members`
+/// - adds the dependencies used by `crates`, as well as `crates` themselves,
+///   to `workspace.dependencies`
+///
+/// Existing members and dependencies, as well as the rest of the file's contents and formatting,
+/// are left untouched.
+pub fn write_workspace_cargotoml(
+    args: &Args,
+    crates: &[GeneratedCrate],
+    out: &mut Output,
+) -> Result<()> {
+    let path = args.output_dir.join("Cargo.toml");
+    let mut cargo_toml = read_cargotoml(&path)?;
+    update_workspace(&path, &mut cargo_toml, args, crates)?;
+    out.add(path, cargo_toml.to_string());
+    Ok(())
+}
+
+fn read_cargotoml(path: &Path) -> Result<DocumentMut> {
+    if !path.exists() {
+        return Ok(DocumentMut::new());
+    }
+    let s = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    s.parse()
+        .map_err(|e: toml_edit::TomlError| Error::InvalidCargoToml {
+            path: path.to_owned(),
+            message: e.to_string(),
+        })
+}
+
+/// `path` is the path to `cargo_toml`, for error reporting
+pub fn update_workspace(
+    path: &Path,
+    cargo_toml: &mut DocumentMut,
+    args: &Args,
+    crates: &[GeneratedCrate],
+) -> Result<()> {
+    let invalid = |message: &str| Error::InvalidCargoToml {
+        path: path.to_owned(),
+        message: message.to_owned(),
+    };
+    let workspace = table_entry(cargo_toml.as_table_mut(), "workspace")
+        .ok_or_else(|| invalid("workspace is not a table"))?;
+    if !workspace.contains_key("resolver") {
+        workspace.insert("resolver", value("2"));
+    }
+
+    let members = workspace
+        .entry("members")
+        .or_insert_with(|| value(Array::new()))
+        .as_array_mut()
+        .ok_or_else(|| invalid("workspace.members is not an array"))?;
+    for c in crates {
+        if !members.iter().any(|m| m.as_str() == Some(c.name.as_str())) {
+            members.push(c.name.as_str());
+        }
+    }
+
+    let dependencies = table_entry(workspace, "dependencies")
+        .ok_or_else(|| invalid("workspace.dependencies is not a table"))?;
+    // generated crates first so that crates used as extern crates are depended on by path
+    for c in crates {
+        if !dependencies.contains_key(&c.name) {
+            let mut dependency = InlineTable::new();
+            dependency.insert("path", c.name.as_str().into());
+            dependencies.insert(&c.name, value(dependency));
+        }
+    }
+    let mapped_type_dependencies = mapped_type_dependencies(args);
+    for (name, vers) in dependency_versions(args).into_iter().chain(
+        mapped_type_dependencies
+            .iter()
+            .map(|(name, vers)| (name.as_str(), *vers)),
+    ) {
+        if !dependencies.contains_key(name)
+            && crates
+                .iter()
+                .any(|c| c.dependencies.iter().any(|d| d == name))
+        {
+            // members' default-features = false is ignored unless the workspace's is too
+            let mut dependency = if args.no_std && NO_STD_CRATES.contains(&name) {
+                NoDefaultFeaturesDependencyValue(DependencyValue(vers)).into()
+            } else {
+                DependencyValue(vers).into()
+            };
+            relativize_dependency_path(&mut dependency, &args.output_dir);
+            dependencies.insert(name, value(edit_value(&dependency)));
+        }
+    }
+    Ok(())
+}
+
+/// The table at `key` of `table`, inserting an empty one if absent.
+/// `None` if the existing value is not a table.
+fn table_entry<'a>(table: &'a mut Table, key: &str) -> Option<&'a mut Table> {
+    table
+        .entry(key)
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            Item::Table(table)
+        })
+        .as_table_mut()
+}
+
+/// `value` as an inline `toml_edit` value
+fn edit_value(value: &Value) -> toml_edit::Value {
+    match value {
+        Value::String(s) => s.as_str().into(),
+        Value::Integer(i) => (*i).into(),
+        Value::Float(f) => (*f).into(),
+        Value::Boolean(b) => (*b).into(),
+        Value::Datetime(d) => (*d).into(),
+        Value::Array(a) => a.iter().map(edit_value).collect::<Array>().into(),
+        Value::Table(t) => t
+            .iter()
+            .map(|(k, v)| (k.as_str(), edit_value(v)))
+            .collect::<InlineTable>()
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use clap::Parser;
+
+    use super::*;
+    use crate::gen_workspace;
+
+    fn generated(name: &str, dependencies: &[&str]) -> GeneratedCrate {
+        GeneratedCrate {
+            name: name.to_owned(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_update_existing_workspace() {
+        let mut cargo_toml: DocumentMut = r#"
+            [workspace]
+            members = ["existing"]
+
+            [workspace.dependencies]
+            borsh = "1.0.0"
+            "#
+        .parse()
+        .unwrap();
+        let args = Args::parse_from(["solores"]);
+        let crates = [
+            generated("a_interface", &["borsh", "solana-program"]),
+            generated("existing", &["borsh"]),
+        ];
+        update_workspace(Path::new("Cargo.toml"), &mut cargo_toml, &args, &crates).unwrap();
+
+        let cargo_toml: toml::Table = toml::from_str(&cargo_toml.to_string()).unwrap();
+        let workspace = cargo_toml["workspace"].as_table().unwrap();
+        assert_eq!(workspace["resolver"].as_str(), Some("2"));
+        assert_eq!(
+            workspace["members"].as_array().unwrap(),
+            &["existing", "a_interface"].map(Value::from)
+        );
+        let deps = workspace["dependencies"].as_table().unwrap();
+        assert_eq!(deps["borsh"].as_str(), Some("1.0.0"));
+        assert_eq!(
+            deps["solana-program"]["version"].as_str(),
+            Some(args.solana_program_vers.as_str())
+        );
+        assert_eq!(deps["a_interface"]["path"].as_str(), Some("a_interface"));
+        assert!(!deps.contains_key("bytemuck"));
+    }
+
+    #[test]
+    fn test_update_keeps_comments_and_formatting() {
+        const CARGO_TOML: &str = r#"# my workspace
+[workspace]
+resolver = "2"
+members = [
+    # hand-written
+    "app",
+]
+
+[workspace.dependencies]
+borsh = "1.0.0" # pinned
+
+[profile.release]
+lto = true
+"#;
+        let mut cargo_toml: DocumentMut = CARGO_TOML.parse().unwrap();
+        let args = Args::parse_from(["solores"]);
+        let crates = [generated("a_interface", &["borsh"])];
+        update_workspace(Path::new("Cargo.toml"), &mut cargo_toml, &args, &crates).unwrap();
+        assert_eq!(
+            cargo_toml.to_string(),
+            r#"# my workspace
+[workspace]
+resolver = "2"
+members = [
+    # hand-written
+    "app", "a_interface",
+]
+
+[workspace.dependencies]
+borsh = "1.0.0" # pinned
+a_interface = { path = "a_interface" }
+
+[profile.release]
+lto = true
+"#
+        );
+    }
+
+    #[test]
+    fn test_invalid_workspace() {
+        let mut cargo_toml: DocumentMut = "workspace = 1".parse().unwrap();
+        let args = Args::parse_from(["solores"]);
+        let res = update_workspace(Path::new("Cargo.toml"), &mut cargo_toml, &args, &[]);
+        assert!(matches!(res, Err(Error::InvalidCargoToml { .. })));
+    }
+
+    #[test]
+    fn test_single_crate_args_conflict() {
+        let args = Args::parse_from([
+            "solores",
+            "--program-id",
+            "11111111111111111111111111111111",
+        ]);
+        let res = gen_workspace(&args, &[PathBuf::from("a.json"), PathBuf::from("b.json")]);
+        assert!(matches!(res, Err(Error::ArgumentConflict(_))));
+    }
+}
@@ -1,8 +1,41 @@
 #![cfg(feature = "test_gen_examples")]
 
-use test_utils::{check_example, gen_example};
+use test_utils::{check_example, check_example_features, gen_example, gen_example_in};
 
-const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
+/// Every feature of a crate generated from an anchor IDL, one at a time, then all together
+const ALL_FEATURES: [&str; 8] = [
+    "",
+    "cpi",
+    "client",
+    "serde",
+    "anchor",
+    "runtime",
+    "idl",
+    "cpi,client,serde,anchor,runtime,idl",
+];
+
+/// [`ALL_FEATURES`] of a crate generated from a shank IDL, which has no `anchor` feature
+const SHANK_FEATURES: [&str; 7] = [
+    "",
+    "cpi",
+    "client",
+    "serde",
+    "runtime",
+    "idl",
+    "cpi,client,serde,runtime,idl",
+];
+
+/// [`ALL_FEATURES`] of a crate generated from a bincode IDL, which has no `anchor` or `serde` feature
+const BINCODE_FEATURES: [&str; 6] = [
+    "",
+    "cpi",
+    "client",
+    "runtime",
+    "idl",
+    "cpi,client,runtime,idl",
+];
+
+const BASE_WORKSPACE_DEPS_ARGS: [&str; 18] = [
     "--solana-program-vers",
     "workspace=true",
     "--borsh-vers",
@@ -17,13 +50,22 @@ const BASE_WORKSPACE_DEPS_ARGS: [&str; 14] = [
     "workspace=true",
     "--bytemuck-vers",
     "workspace=true",
+    "--serde-with-vers",
+    "workspace=true",
+    "--solores-runtime-vers",
+    "workspace=true",
 ];
 
 #[test]
 fn test_token_metadata() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "shank/token_metadata";
     gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
-    check_example(EXAMPLE_PATH, "mpl_token_metadata_interface")
+    check_example(EXAMPLE_PATH, "mpl_token_metadata_interface")?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "mpl_token_metadata_interface",
+        &SHANK_FEATURES,
+    )
 }
 
 #[test]
@@ -50,14 +92,16 @@ fn test_phoenix_v1() -> Result<(), Box<dyn std::error::Error>> {
         ]
         .concat(),
     )?;
-    check_example(EXAMPLE_PATH, "phoenix_v1_interface")
+    check_example(EXAMPLE_PATH, "phoenix_v1_interface")?;
+    check_example_features(EXAMPLE_PATH, "phoenix_v1_interface", &SHANK_FEATURES)
 }
 
 #[test]
 fn test_unstake_it() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "anchor/unstake_it";
     gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
-    check_example(EXAMPLE_PATH, "unstake_interface")
+    check_example(EXAMPLE_PATH, "unstake_interface")?;
+    check_example_features(EXAMPLE_PATH, "unstake_interface", &ALL_FEATURES)
 }
 
 #[test]
@@ -102,6 +146,13 @@ fn test_anchor_ix_blank() -> Result<(), Box<dyn std::error::Error>> {
     check_example(EXAMPLE_PATH, "anchor_ix_blank_interface")
 }
 
+#[test]
+fn test_anchor_digit_names() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "anchor/digit_names";
+    gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
+    check_example(EXAMPLE_PATH, "anchor_digit_names_interface")
+}
+
 #[test]
 fn test_shank_ix_no_privilege() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "shank/ix_no_privilege";
@@ -158,12 +209,206 @@ fn test_drift() -> Result<(), Box<dyn std::error::Error>> {
 fn test_system_program() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "bincode/system";
     gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
-    check_example(EXAMPLE_PATH, "system_program_interface")
+    check_example(EXAMPLE_PATH, "system_program_interface")?;
+    check_example_features(EXAMPLE_PATH, "system_program_interface", &BINCODE_FEATURES)
 }
 
 #[test]
 fn test_stake_program() -> Result<(), Box<dyn std::error::Error>> {
     const EXAMPLE_PATH: &str = "bincode/stake";
     gen_example(EXAMPLE_PATH, &BASE_WORKSPACE_DEPS_ARGS)?;
-    check_example(EXAMPLE_PATH, "stake_program_interface")
+    check_example(EXAMPLE_PATH, "stake_program_interface")?;
+    check_example_features(EXAMPLE_PATH, "stake_program_interface", &BINCODE_FEATURES)
+}
+
+#[test]
+fn test_option_compact() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/compact";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "unstake_compact_interface",
+                "--compact",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(EXAMPLE_PATH, "unstake_compact_interface", &ALL_FEATURES)
+}
+
+#[test]
+fn test_option_split_modules() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/split_modules";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "unstake_split_modules_interface",
+                "--split-modules",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "unstake_split_modules_interface",
+        &ALL_FEATURES,
+    )
+}
+
+#[test]
+fn test_option_no_std() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/no_std";
+    // workspace deps can't disable default features, so borsh and num-traits are left at their defaults
+    let deps_args: Vec<&str> = BASE_WORKSPACE_DEPS_ARGS
+        .chunks(2)
+        .filter(|arg| !["--borsh-vers", "--num-traits-vers"].contains(&arg[0]))
+        .flatten()
+        .copied()
+        .collect();
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            deps_args.as_slice(),
+            &[
+                "--output-crate-name",
+                "unstake_no_std_interface",
+                "--no-std",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(EXAMPLE_PATH, "unstake_no_std_interface", &ALL_FEATURES)
+}
+
+#[test]
+fn test_option_pinocchio() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/pinocchio";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "unstake_pinocchio_interface",
+                "--target",
+                "pinocchio",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(EXAMPLE_PATH, "unstake_pinocchio_interface", &ALL_FEATURES)
+}
+
+#[test]
+fn test_option_pinocchio_shank() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/pinocchio_shank";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "mpl_token_metadata_pinocchio_interface",
+                "--target",
+                "pinocchio",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "mpl_token_metadata_pinocchio_interface",
+        &SHANK_FEATURES,
+    )
+}
+
+#[test]
+fn test_option_pinocchio_bincode() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/pinocchio_bincode";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "system_program_pinocchio_interface",
+                "--target",
+                "pinocchio",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "system_program_pinocchio_interface",
+        &BINCODE_FEATURES,
+    )
+}
+
+#[test]
+fn test_option_split_solana_crates() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/split_solana_crates";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "unstake_split_solana_crates_interface",
+                "--split-solana-crates",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "unstake_split_solana_crates_interface",
+        &ALL_FEATURES,
+    )
+}
+
+#[test]
+fn test_option_program_id_for() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/program_id_for";
+    gen_example(
+        EXAMPLE_PATH,
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &[
+                "--output-crate-name",
+                "unstake_program_id_for_interface",
+                "--program-id-for",
+                "devnet=DEVNET1111111111111111111111111111111111111",
+                "--program-id-for",
+                "localnet=LocaLNET11111111111111111111111111111111111",
+            ],
+        ]
+        .concat(),
+    )?;
+    check_example_features(
+        EXAMPLE_PATH,
+        "unstake_program_id_for_interface",
+        &ALL_FEATURES,
+    )
+}
+
+#[test]
+fn test_option_module() -> Result<(), Box<dyn std::error::Error>> {
+    const EXAMPLE_PATH: &str = "options/module";
+    gen_example_in(
+        EXAMPLE_PATH,
+        "unstake_module_host/src/unstake",
+        &[
+            BASE_WORKSPACE_DEPS_ARGS.as_ref(),
+            &["--module", "crate::unstake"],
+        ]
+        .concat(),
+    )?;
+    check_example_features(EXAMPLE_PATH, "unstake_module_host", &ALL_FEATURES)
 }
@@ -11,6 +11,16 @@ pub fn example_dir(example_path: &str) -> PathBuf {
 }
 
 pub fn gen_example(example_path: &str, args: &[&str]) -> Result<(), Box<dyn std::error::Error>> {
+    gen_example_in(example_path, "", args)
+}
+
+/// Like [`gen_example`], but outputs to `output_subdir` of the example's dir,
+/// e.g. a host crate's source dir for `--module`
+pub fn gen_example_in(
+    example_path: &str,
+    output_subdir: &str,
+    args: &[&str],
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut solores_cmd = Command::cargo_bin(BIN_NAME)?;
 
     let dir = example_dir(example_path);
@@ -18,7 +28,10 @@ pub fn gen_example(example_path: &str, args: &[&str]) -> Result<(), Box<dyn std:
     let mut idl_path = dir.clone();
     idl_path.push("idl.json");
 
-    solores_cmd.arg(idl_path).arg("-o").arg(&dir);
+    solores_cmd
+        .arg(idl_path)
+        .arg("-o")
+        .arg(dir.join(output_subdir));
     for arg in args {
         solores_cmd.arg(arg);
     }
@@ -44,6 +57,27 @@ pub fn check_example(
     Ok(())
 }
 
+/// `cargo check` a generated interface crate with each of `features` enabled,
+/// each entry being a comma-separated list of features
+pub fn check_example_features(
+    example_path: &str,
+    gen_package_name: &str,
+    features: &[&str],
+) -> Result<(), Box<dyn std::error::Error>> {
+    for features in features {
+        let mut cargo_check_cmd = Command::new("cargo");
+        cargo_check_cmd
+            .current_dir(example_dir(example_path))
+            .arg("check")
+            .arg("-p")
+            .arg(gen_package_name)
+            .arg("--features")
+            .arg(features);
+        cargo_check_cmd.assert().success();
+    }
+    Ok(())
+}
+
 /// `cargo test` a consumer crate of the generated interface crate.
 /// Currently unused, takes too long to run.
 pub fn test_consumer(